@@ -0,0 +1,68 @@
+//! Audit sink writing JSON lines.
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use futures::future::BoxFuture;
+use serde_json::{json, Value};
+
+use crate::core::usecases::ports::{AuditEvent, AuditSink};
+
+/// Audit sink appending one JSON object per event to a writer.
+///
+/// Lines are flushed as they are written so a crash loses at most the
+/// event being written. Writes run on the blocking thread pool, so a slow
+/// destination never stalls the async workers serving requests.
+pub struct JsonLinesAuditSink {
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl JsonLinesAuditSink {
+    /// Create a sink writing to `writer`.
+    pub fn new(writer: Box<dyn Write + Send>) -> Self {
+        Self { writer: Arc::new(Mutex::new(writer)) }
+    }
+
+    /// Create a sink writing to standard output.
+    pub fn stdout() -> Self {
+        Self::new(Box::new(std::io::stdout()))
+    }
+
+    /// JSON representation of an event, as written on one line.
+    pub fn to_json(event: &AuditEvent) -> Value {
+        let mut value = json!({
+            "actor": event.actor,
+            "event_type": event.event_type.as_str(),
+            "timestamp": event.timestamp.to_rfc3339(),
+            "outcome": event.outcome.as_str(),
+            "source_ip": event.source_ip,
+        });
+        if let Some(reason) = &event.reason {
+            value["reason"] = Value::from(reason.as_str());
+        }
+        value
+    }
+}
+
+impl AuditSink for JsonLinesAuditSink {
+    fn record(&self, event: AuditEvent) -> BoxFuture<'_, Result<(), String>> {
+        let line = Self::to_json(&event).to_string();
+        let writer = self.writer.clone();
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || {
+                let mut writer = writer.lock().map_err(|_| "audit writer poisoned".to_string())?;
+                writeln!(writer, "{}", line)
+                    .and_then(|()| writer.flush())
+                    .map_err(|e| format!("failed to write audit event: {}", e))
+            })
+            .await
+            .map_err(|e| format!("audit writer task failed: {}", e))?
+        })
+    }
+}
+
+impl std::fmt::Debug for JsonLinesAuditSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonLinesAuditSink").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,18 @@
+//! Audit sink adapters.
+//!
+//! This module provides concrete destinations for the security audit trail
+//! implementing the `AuditSink` port from the core domain.
+//!
+//! # Components
+//!
+//! - [`JsonLinesAuditSink`]: One JSON object per line on any writer (stdout by default)
+//!
+//! The database-backed sink lives with the other SQL adapters in
+//! `persistence::repositories`.
+
+pub mod json_lines_audit_sink;
+
+pub use json_lines_audit_sink::JsonLinesAuditSink;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,99 @@
+//! Tests for JsonLinesAuditSink.
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use chrono::{TimeZone, Utc};
+
+use crate::adapters::audit::JsonLinesAuditSink;
+use crate::core::usecases::ports::{AuditEvent, AuditEventType, AuditOutcome, AuditSink};
+
+/// Writer sharing its buffer with the test
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn lines(&self) -> Vec<serde_json::Value> {
+        String::from_utf8(self.0.lock().unwrap().clone())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("each line is a JSON object"))
+            .collect()
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writer that always fails
+struct BrokenWriter;
+
+impl Write for BrokenWriter {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn failed_login() -> AuditEvent {
+    AuditEvent::new(
+        "user-1",
+        AuditEventType::LoginFailed,
+        Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+        AuditOutcome::Failure,
+    )
+    .with_reason("invalid password")
+    .with_source_ip(Some("203.0.113.7".to_string()))
+}
+
+#[tokio::test]
+async fn test_json_lines_sink_writes_one_object_per_event() {
+    let buffer = SharedBuffer::default();
+    let sink = JsonLinesAuditSink::new(Box::new(buffer.clone()));
+
+    sink.record(failed_login()).await.unwrap();
+    sink.record(AuditEvent::new("user-1", AuditEventType::LoginSucceeded, Utc::now(), AuditOutcome::Success))
+        .await
+        .unwrap();
+
+    let lines = buffer.lines();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], serde_json::json!({
+        "actor": "user-1",
+        "event_type": "login_failed",
+        "timestamp": "2023-11-14T22:13:20+00:00",
+        "outcome": "failure",
+        "reason": "invalid password",
+        "source_ip": "203.0.113.7",
+    }));
+    assert_eq!(lines[1]["event_type"], "login_succeeded");
+    assert!(lines[1].get("reason").is_none());
+    assert!(lines[1]["source_ip"].is_null());
+}
+
+#[tokio::test]
+async fn test_json_lines_sink_reports_write_failure() {
+    let sink = JsonLinesAuditSink::new(Box::new(BrokenWriter));
+
+    let result = sink.record(failed_login()).await;
+
+    assert!(result.unwrap_err().contains("disk full"));
+}
+
+#[tokio::test]
+async fn test_record_or_log_swallows_write_failure() {
+    let sink = JsonLinesAuditSink::new(Box::new(BrokenWriter));
+
+    // Must complete without panicking or propagating
+    sink.record_or_log(failed_login()).await;
+}
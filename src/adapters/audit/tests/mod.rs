@@ -0,0 +1,3 @@
+//! Tests for the audit module.
+
+mod json_lines_audit_sink_tests;
@@ -0,0 +1,103 @@
+//! Have I Been Pwned breached-password checker.
+//!
+//! Uses the Pwned Passwords k-anonymity range API: the secret is SHA-1
+//! hashed locally and only the first 5 hex characters of that hash are sent.
+//! The service answers with every known hash suffix sharing the prefix and
+//! the match happens in-process, so neither the plaintext nor the full hash
+//! ever leaves this process.
+
+use std::time::Duration;
+use futures::future::BoxFuture;
+use reqwest::Client;
+use ring::digest;
+use tracing::{debug, warn};
+
+use crate::core::usecases::ports::BreachChecker;
+
+/// Length of the hash prefix sent to the range API.
+const PREFIX_LEN: usize = 5;
+
+/// Configuration for HibpBreachChecker.
+#[derive(Debug, Clone)]
+pub struct HibpBreachCheckerConfig {
+    /// Base URL of the Pwned Passwords API (without the `/range` path).
+    pub base_url: String,
+    pub timeout: Duration,
+}
+
+impl Default for HibpBreachCheckerConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "https://api.pwnedpasswords.com".to_string(),
+            timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Breach checker backed by the Pwned Passwords range API.
+pub struct HibpBreachChecker {
+    config: HibpBreachCheckerConfig,
+    http_client: Client,
+}
+
+impl HibpBreachChecker {
+    pub fn new(config: HibpBreachCheckerConfig, http_client: Client) -> Self {
+        Self { config, http_client }
+    }
+}
+
+/// Uppercase hex SHA-1 of `secret`, as used by the range API.
+fn sha1_hex(secret: &str) -> String {
+    let digest = digest::digest(&digest::SHA1_FOR_LEGACY_USE_ONLY, secret.as_bytes());
+    hex::encode_upper(digest.as_ref())
+}
+
+/// Whether `suffix` appears in a range response body.
+///
+/// Lines are `SUFFIX:COUNT`; padding entries carry a count of 0 and are
+/// ignored.
+fn range_contains(body: &str, suffix: &str) -> bool {
+    body.lines().any(|line| {
+        let Some((candidate, count)) = line.trim().split_once(':') else {
+            return false;
+        };
+        candidate.eq_ignore_ascii_case(suffix) && count.trim().parse::<u64>().map(|c| c > 0).unwrap_or(false)
+    })
+}
+
+impl BreachChecker for HibpBreachChecker {
+    fn is_compromised<'a>(&'a self, secret: &'a str) -> BoxFuture<'a, Result<bool, String>> {
+        let hash = sha1_hex(secret);
+
+        Box::pin(async move {
+            let (prefix, suffix) = hash.split_at(PREFIX_LEN);
+            let url = format!("{}/range/{}", self.config.base_url.trim_end_matches('/'), prefix);
+            debug!(prefix, "[HIBP_BREACH_CHECKER] Querying range");
+
+            let response = self
+                .http_client
+                .get(&url)
+                .timeout(self.config.timeout)
+                .header("Add-Padding", "true")
+                .send()
+                .await
+                .map_err(|e| {
+                    warn!(error = %e, "[HIBP_BREACH_CHECKER] Range request failed");
+                    format!("Breach check request failed: {}", e)
+                })?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                warn!(%status, "[HIBP_BREACH_CHECKER] Range request rejected");
+                return Err(format!("Breach check returned status {}", status));
+            }
+
+            let body = response
+                .text()
+                .await
+                .map_err(|e| format!("Failed to read breach check response: {}", e))?;
+
+            Ok(range_contains(&body, suffix))
+        })
+    }
+}
@@ -0,0 +1,7 @@
+pub mod hibp_breach_checker;
+
+pub use hibp_breach_checker::HibpBreachChecker;
+pub use hibp_breach_checker::HibpBreachCheckerConfig;
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,91 @@
+use crate::adapters::clients::hibp::{HibpBreachChecker, HibpBreachCheckerConfig};
+use crate::core::usecases::ports::BreachChecker;
+use std::time::Duration;
+use wiremock::{MockServer, Mock};
+use wiremock::matchers::{header, method, path};
+use wiremock::ResponseTemplate;
+
+// SHA-1("password") = 5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8
+const PASSWORD_PREFIX: &str = "5BAA6";
+const PASSWORD_SUFFIX: &str = "1E4C9B93F3F0682250B6CF8331B7EE68FD8";
+
+fn checker(server: &MockServer) -> HibpBreachChecker {
+    let config = HibpBreachCheckerConfig {
+        base_url: server.uri(),
+        timeout: Duration::from_secs(5),
+    };
+    HibpBreachChecker::new(config, reqwest::Client::new())
+}
+
+async fn stub_range(server: &MockServer, body: String) {
+    Mock::given(method("GET"))
+        .and(path(format!("/range/{}", PASSWORD_PREFIX)))
+        .and(header("Add-Padding", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_known_password_is_compromised() {
+    let server = MockServer::start().await;
+    stub_range(
+        &server,
+        format!("0018A45C4D1DEF81644B54AB7F969B88D65:1\r\n{}:9545824\r\n", PASSWORD_SUFFIX),
+    )
+    .await;
+
+    let result = checker(&server).is_compromised("password").await;
+
+    assert_eq!(result, Ok(true));
+}
+
+#[tokio::test]
+async fn test_absent_suffix_is_not_compromised() {
+    let server = MockServer::start().await;
+    stub_range(&server, "0018A45C4D1DEF81644B54AB7F969B88D65:1\r\n".to_string()).await;
+
+    let result = checker(&server).is_compromised("password").await;
+
+    assert_eq!(result, Ok(false));
+}
+
+#[tokio::test]
+async fn test_padding_entry_is_not_compromised() {
+    let server = MockServer::start().await;
+    stub_range(&server, format!("{}:0\r\n", PASSWORD_SUFFIX)).await;
+
+    let result = checker(&server).is_compromised("password").await;
+
+    assert_eq!(result, Ok(false));
+}
+
+#[tokio::test]
+async fn test_only_hash_prefix_is_sent() {
+    let server = MockServer::start().await;
+    stub_range(&server, String::new()).await;
+
+    checker(&server).is_compromised("password").await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    let url = requests[0].url.to_string();
+    assert!(url.ends_with(&format!("/range/{}", PASSWORD_PREFIX)));
+    assert!(!url.contains("password"));
+    assert!(!url.contains(PASSWORD_SUFFIX));
+    assert!(requests[0].body.is_empty());
+}
+
+#[tokio::test]
+async fn test_server_error_is_reported() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let result = checker(&server).is_compromised("password").await;
+
+    assert!(result.is_err());
+}
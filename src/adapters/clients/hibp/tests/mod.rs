@@ -0,0 +1 @@
+mod hibp_breach_checker_tests;
\ No newline at end of file
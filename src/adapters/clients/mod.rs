@@ -1,2 +1,3 @@
 pub mod google;
-pub mod user_service;
\ No newline at end of file
+pub mod hibp;
+pub mod user_service;
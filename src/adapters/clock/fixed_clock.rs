@@ -0,0 +1,42 @@
+//! Frozen time source for deterministic tests.
+
+use std::sync::RwLock;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::core::usecases::ports::Clock;
+
+/// Clock that always returns the same instant until explicitly moved.
+///
+/// Interior mutability lets a test hold the clock behind a shared reference
+/// (as use cases do) and still advance it between calls.
+#[derive(Debug)]
+pub struct FixedClock {
+    instant: RwLock<DateTime<Utc>>,
+}
+
+impl FixedClock {
+    /// Create a clock frozen at `instant`.
+    pub fn new(instant: DateTime<Utc>) -> Self {
+        Self {
+            instant: RwLock::new(instant),
+        }
+    }
+
+    /// Move the clock forward (or backward, for a negative duration).
+    pub fn advance(&self, duration: Duration) {
+        let mut instant = self.instant.write().unwrap_or_else(|e| e.into_inner());
+        *instant += duration;
+    }
+
+    /// Jump the clock to `instant`.
+    pub fn set(&self, instant: DateTime<Utc>) {
+        *self.instant.write().unwrap_or_else(|e| e.into_inner()) = instant;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.instant.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
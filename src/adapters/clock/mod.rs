@@ -0,0 +1,21 @@
+//! Clock adapters.
+//!
+//! This module provides concrete time sources implementing the `Clock` port
+//! from the core domain.
+//!
+//! # Components
+//!
+//! - [`SystemClock`]: Wall-clock time
+//! - [`FixedClock`]: Frozen time that only moves when advanced (tests)
+//! - [`OffsetClock`]: Another clock shifted by a constant offset
+
+pub mod fixed_clock;
+pub mod offset_clock;
+pub mod system_clock;
+
+pub use fixed_clock::FixedClock;
+pub use offset_clock::OffsetClock;
+pub use system_clock::SystemClock;
+
+#[cfg(test)]
+mod tests;
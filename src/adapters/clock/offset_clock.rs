@@ -0,0 +1,33 @@
+//! Time source shifted from another clock.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::core::usecases::ports::Clock;
+
+/// Clock reporting another clock's time plus a constant offset.
+///
+/// Useful for simulating skew between this service and its peers.
+pub struct OffsetClock {
+    inner: Arc<dyn Clock + Send + Sync>,
+    offset: Duration,
+}
+
+impl OffsetClock {
+    /// Shift `inner` by `offset` (negative offsets run behind).
+    pub fn new(inner: Arc<dyn Clock + Send + Sync>, offset: Duration) -> Self {
+        Self { inner, offset }
+    }
+
+    /// The configured offset.
+    pub fn offset(&self) -> Duration {
+        self.offset
+    }
+}
+
+impl Clock for OffsetClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.inner.now() + self.offset
+    }
+}
@@ -0,0 +1,15 @@
+//! Wall-clock time source.
+
+use chrono::{DateTime, Utc};
+
+use crate::core::usecases::ports::Clock;
+
+/// Clock reading the system's current UTC time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
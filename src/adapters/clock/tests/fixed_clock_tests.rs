@@ -0,0 +1,36 @@
+//! Tests for FixedClock.
+
+use chrono::{Duration, TimeZone, Utc};
+
+use crate::adapters::clock::FixedClock;
+use crate::core::usecases::ports::Clock;
+
+#[test]
+fn test_fixed_clock_is_frozen() {
+    let instant = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let clock = FixedClock::new(instant);
+
+    assert_eq!(clock.now(), instant);
+    assert_eq!(clock.now(), instant);
+}
+
+#[test]
+fn test_fixed_clock_advance() {
+    let instant = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let clock = FixedClock::new(instant);
+
+    clock.advance(Duration::seconds(90));
+    assert_eq!(clock.now(), instant + Duration::seconds(90));
+
+    clock.advance(Duration::seconds(-30));
+    assert_eq!(clock.now(), instant + Duration::seconds(60));
+}
+
+#[test]
+fn test_fixed_clock_set() {
+    let clock = FixedClock::new(Utc.timestamp_opt(1_700_000_000, 0).unwrap());
+    let later = Utc.timestamp_opt(1_800_000_000, 0).unwrap();
+
+    clock.set(later);
+    assert_eq!(clock.now(), later);
+}
@@ -0,0 +1,4 @@
+//! Tests for the clock module.
+
+mod fixed_clock_tests;
+mod offset_clock_tests;
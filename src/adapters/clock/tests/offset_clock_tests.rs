@@ -0,0 +1,30 @@
+//! Tests for OffsetClock.
+
+use std::sync::Arc;
+
+use chrono::{Duration, TimeZone, Utc};
+
+use crate::adapters::clock::{FixedClock, OffsetClock};
+use crate::core::usecases::ports::Clock;
+
+#[test]
+fn test_offset_clock_shifts_inner_time() {
+    let instant = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let inner = Arc::new(FixedClock::new(instant));
+    let ahead = OffsetClock::new(inner.clone(), Duration::minutes(5));
+    let behind = OffsetClock::new(inner.clone(), Duration::minutes(-5));
+
+    assert_eq!(ahead.now(), instant + Duration::minutes(5));
+    assert_eq!(behind.now(), instant - Duration::minutes(5));
+    assert_eq!(ahead.offset(), Duration::minutes(5));
+}
+
+#[test]
+fn test_offset_clock_follows_inner_clock() {
+    let instant = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let inner = Arc::new(FixedClock::new(instant));
+    let clock = OffsetClock::new(inner.clone(), Duration::seconds(10));
+
+    inner.advance(Duration::seconds(20));
+    assert_eq!(clock.now(), instant + Duration::seconds(30));
+}
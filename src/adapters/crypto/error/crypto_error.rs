@@ -241,6 +241,9 @@ impl From<JwtError> for TokenError {
             JwtError::AlgorithmMismatch { reason } => {
                 TokenError::unsupported_algorithm(reason)
             }
+            JwtError::TooLarge { reason } => {
+                TokenError::malformed(format!("too large: {}", reason))
+            }
         }
     }
 }
@@ -23,6 +23,7 @@ Design Principles:
 /// - `Expired`: Token has expired
 /// - `SignatureInvalid`: Signature verification failed
 /// - `AlgorithmMismatch`: Algorithm does not match expected
+/// - `TooLarge`: Token or claims exceed the configured size limit
 #[derive(Debug, Clone)]
 pub enum JwtError {
     /// Token encoding/signing failed
@@ -53,6 +54,10 @@ pub enum JwtError {
     AlgorithmMismatch {
         reason: String,
     },
+    /// Token or claims exceed the configured size limit
+    TooLarge {
+        reason: String,
+    },
 }
 
 impl JwtError {
@@ -104,6 +109,13 @@ impl JwtError {
             reason: reason.into(),
         }
     }
+
+    /// Create a size limit error
+    pub fn too_large(reason: impl Into<String>) -> Self {
+        Self::TooLarge {
+            reason: reason.into(),
+        }
+    }
 }
 
 impl std::fmt::Display for JwtError {
@@ -116,6 +128,7 @@ impl std::fmt::Display for JwtError {
             Self::Expired { reason } => write!(f, "Token expired: {}", reason),
             Self::SignatureInvalid { reason } => write!(f, "Invalid signature: {}", reason),
             Self::AlgorithmMismatch { reason } => write!(f, "Algorithm mismatch: {}", reason),
+            Self::TooLarge { reason } => write!(f, "Size limit exceeded: {}", reason),
         }
     }
 }
@@ -29,6 +29,13 @@ mod tests {
         assert!(err.to_string().contains("malformed header"));
     }
 
+    #[test]
+    fn test_too_large_error_creation() {
+        let err = JwtError::too_large("token is 20000 bytes, limit is 16384");
+        assert!(err.to_string().contains("Size limit exceeded"));
+        assert!(err.to_string().contains("limit is 16384"));
+    }
+
     #[test]
     fn test_error_clone() {
         let err = JwtError::encoding("test");
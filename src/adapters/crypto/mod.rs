@@ -1,3 +1,5 @@
 pub mod error;
 pub mod password;
-pub mod token;
\ No newline at end of file
+pub mod token;
+pub mod totp;
+pub mod webauthn;
\ No newline at end of file
@@ -6,9 +6,13 @@
 //! # Design Principles
 //!
 //! - **Pure cryptographic**: No policy logic, no version tracking
+//! - **Upgradable**: `needs_rehash` flags hashes encoded with outdated parameters
 //! - **Configurable**: All parameters injected via constructor
 //! - **PHC format**: Uses standard PHC string format for storage
 //! - **No secret leakage**: Passwords are never logged or exposed in errors
+//! - **Optional pepper**: A server-held secret can key every hash
+//! - **Timing-safe misses**: `dummy_verify` reuses one dummy hash computed
+//!   on first use with the hasher's own parameters and pepper
 //!
 //! # Example
 //!
@@ -29,7 +33,9 @@
 
 use crate::adapters::crypto::error::PasswordError;
 use crate::core::credentials::StoredCredential;
+use crate::core::usecases::ports::password_hasher::DUMMY_PASSWORD;
 use crate::core::usecases::ports::PasswordHasher;
+use std::sync::OnceLock;
 use argon2::{
     password_hash::{
         rand_core::OsRng,
@@ -42,10 +48,16 @@ use argon2::{
 ///
 /// This hasher uses the Argon2id algorithm with configurable parameters.
 /// All parameters are injected via constructor - no hardcoded defaults.
-#[derive(Debug, Clone)]
+///
+/// An optional pepper is fed to Argon2 as its secret input. It is held only
+/// in memory and never written into the PHC string, so hashes produced with
+/// a pepper can only be verified by a hasher configured with the same one.
+#[derive(Clone)]
 pub struct Argon2PasswordHasher {
-    argon2: Argon2<'static>,
+    params: Params,
+    pepper: Option<Vec<u8>>,
     salt_length: usize,
+    dummy: OnceLock<StoredCredential>,
 }
 
 impl Argon2PasswordHasher {
@@ -87,15 +99,50 @@ impl Argon2PasswordHasher {
         let params = Params::new(memory_cost, time_cost, parallelism, None)
             .map_err(|e| PasswordError::hashing(format!("invalid argon2 parameters: {}", e)))?;
 
-        // Create Argon2 instance with Argon2id variant
-        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
-
         Ok(Self {
-            argon2,
+            params,
+            pepper: None,
             salt_length,
+            dummy: OnceLock::new(),
         })
     }
 
+    /// Create a hasher that mixes a server-held pepper into every hash.
+    ///
+    /// The pepper is passed to Argon2 as its secret (keyed hashing). Both
+    /// `hash` and `verify` apply it, so a stolen hash cannot be attacked
+    /// offline without the pepper as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PasswordError` if the parameters are invalid or the pepper
+    /// is empty or too long.
+    pub fn new_with_pepper(
+        memory_cost: u32,
+        time_cost: u32,
+        parallelism: u32,
+        salt_length: usize,
+        pepper: impl Into<Vec<u8>>,
+    ) -> Result<Self, PasswordError> {
+        let pepper = pepper.into();
+        if pepper.is_empty() {
+            return Err(PasswordError::hashing("pepper must not be empty"));
+        }
+
+        let mut hasher = Self::new(memory_cost, time_cost, parallelism, salt_length)?;
+        // Reject unusable peppers up front rather than on first hash
+        Argon2::new_with_secret(&pepper, Algorithm::Argon2id, Version::V0x13, hasher.params.clone())
+            .map_err(|_| PasswordError::hashing("invalid pepper length"))?;
+        hasher.pepper = Some(pepper);
+
+        Ok(hasher)
+    }
+
+    /// Whether a pepper is applied to hashes.
+    pub fn has_pepper(&self) -> bool {
+        self.pepper.is_some()
+    }
+
     /// Get the configured salt length.
     pub fn salt_length(&self) -> usize {
         self.salt_length
@@ -111,13 +158,62 @@ impl Argon2PasswordHasher {
 
         // Hash the password
         let password_hash = self
-            .argon2
+            .argon2()?
             .hash_password(raw.as_bytes(), &salt)
             .map_err(|e| PasswordError::hashing(format!("argon2 hashing failed: {}", e)))?;
 
         Ok(password_hash.to_string())
     }
 
+    /// Verify against a list of candidate peppers, current one first.
+    ///
+    /// Returns the index of the first pepper that verifies, or `None` when
+    /// none does. Intended for pepper rotation: anything other than index 0
+    /// was hashed with a retired pepper and should be re-hashed.
+    pub fn verify_with_any_pepper(&self, peppers: &[&[u8]], raw: &str, stored: &StoredCredential) -> Option<usize> {
+        let parsed_hash = PasswordHash::new(stored.as_hash_str()).ok()?;
+
+        peppers.iter().position(|pepper| {
+            self.argon2_with(Some(*pepper))
+                .map(|argon2| argon2.verify_password(raw.as_bytes(), &parsed_hash).is_ok())
+                .unwrap_or(false)
+        })
+    }
+
+    /// Build the Argon2id context, keyed with the pepper when configured.
+    fn argon2(&self) -> Result<Argon2<'_>, PasswordError> {
+        self.argon2_with(self.pepper.as_deref())
+    }
+
+    /// Build the Argon2id context, keyed with `pepper` if given.
+    ///
+    /// Errors deliberately carry no detail about the pepper.
+    fn argon2_with<'p>(&self, pepper: Option<&'p [u8]>) -> Result<Argon2<'p>, PasswordError> {
+        match pepper {
+            Some(pepper) => Argon2::new_with_secret(
+                pepper,
+                Algorithm::Argon2id,
+                Version::V0x13,
+                self.params.clone(),
+            )
+            .map_err(|_| PasswordError::hashing("invalid pepper length")),
+            None => Ok(Argon2::new(
+                Algorithm::Argon2id,
+                Version::V0x13,
+                self.params.clone(),
+            )),
+        }
+    }
+}
+
+impl std::fmt::Debug for Argon2PasswordHasher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Argon2PasswordHasher")
+            .field("params", &self.params)
+            .field("pepper", &self.pepper.as_ref().map(|_| "[REDACTED]"))
+            .field("salt_length", &self.salt_length)
+            .finish_non_exhaustive()
+    }
 }
 
 impl PasswordHasher for Argon2PasswordHasher {
@@ -140,10 +236,44 @@ impl PasswordHasher for Argon2PasswordHasher {
             Err(_) => return false,
         };
 
-        // Verify the password
-        match self.argon2.verify_password(raw.as_bytes(), &parsed_hash) {
-            Ok(_) => true,
-            Err(_) => false,
+        // Verify the password (with the pepper, if configured)
+        let argon2 = match self.argon2() {
+            Ok(argon2) => argon2,
+            Err(_) => return false,
+        };
+        argon2.verify_password(raw.as_bytes(), &parsed_hash).is_ok()
+    }
+
+    fn dummy_verify(&self, raw: &str) {
+        let dummy = self.dummy.get_or_init(|| self.hash(DUMMY_PASSWORD));
+        let _ = self.verify(raw, dummy);
+    }
+
+    fn needs_rehash(&self, stored: &StoredCredential) -> bool {
+        // Anything that is not a parsable Argon2id PHC string (e.g. legacy
+        // bcrypt) must be upgraded
+        let parsed_hash = match PasswordHash::new(stored.as_hash_str()) {
+            Ok(hash) => hash,
+            Err(_) => return true,
+        };
+
+        if parsed_hash.algorithm != Algorithm::Argon2id.ident() {
+            return true;
         }
+
+        if parsed_hash.version != Some(Version::V0x13.into()) {
+            return true;
+        }
+
+        // Compare encoded m/t/p against the current configuration
+        let stored_params = match Params::try_from(&parsed_hash) {
+            Ok(params) => params,
+            Err(_) => return true,
+        };
+        let current = &self.params;
+
+        stored_params.m_cost() != current.m_cost()
+            || stored_params.t_cost() != current.t_cost()
+            || stored_params.p_cost() != current.p_cost()
     }
 }
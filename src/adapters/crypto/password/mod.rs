@@ -1,12 +1,15 @@
 //! Password hashing module for the crypto adapter.
 //!
 //! This module provides password hashing and verification implementations
-//! using the Argon2id algorithm. It implements the `PasswordHasher` port
-//! from the core domain.
+//! using the Argon2id and scrypt algorithms. They implement the
+//! `PasswordHasher` port from the core domain. Password strength estimation
+//! (the `StrengthEstimator` port) lives here too.
 //!
 //! # Components
 //!
 //! - [`Argon2PasswordHasher`]: Argon2id password hashing and verification
+//! - [`ScryptPasswordHasher`]: scrypt password hashing and verification
+//! - [`ZxcvbnStrengthEstimator`]: zxcvbn-based password strength scoring
 //!
 //! # Example
 //!
@@ -27,8 +30,12 @@
 //! ```
 
 pub mod argon2_hasher;
+pub mod scrypt_hasher;
+pub mod zxcvbn_strength_estimator;
 
 pub use argon2_hasher::Argon2PasswordHasher;
+pub use scrypt_hasher::ScryptPasswordHasher;
+pub use zxcvbn_strength_estimator::ZxcvbnStrengthEstimator;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,160 @@
+//! Scrypt password hasher implementation.
+//!
+//! This module provides a concrete implementation of the `PasswordHasher` port
+//! using the scrypt key derivation function via the scrypt crate, for
+//! deployments whose compliance requirements rule out Argon2.
+//!
+//! # Design Principles
+//!
+//! - **Pure cryptographic**: No policy logic, no version tracking
+//! - **Self-describing**: `verify` uses the parameters embedded in the stored hash
+//! - **Upgradable**: `needs_rehash` flags hashes encoded with outdated parameters
+//! - **Timing-safe misses**: `dummy_verify` reuses one dummy hash computed
+//!   on first use with the configured parameters
+//! - **PHC format**: Uses standard PHC string format for storage
+//! - **No secret leakage**: Passwords are never logged or exposed in errors
+//!
+//! # Example
+//!
+//! ```rust
+//! use auth::adapters::crypto::password::ScryptPasswordHasher;
+//! use auth::core::usecases::ports::PasswordHasher;
+//!
+//! // Create hasher with custom parameters
+//! let hasher = ScryptPasswordHasher::new(
+//!     15,  // log2(N) cost
+//!     8,   // block size
+//!     1,   // parallelism
+//! ).expect("Valid parameters");
+//!
+//! let credential = hasher.hash("user_password");
+//! ```
+
+use crate::adapters::crypto::error::PasswordError;
+use crate::core::credentials::StoredCredential;
+use crate::core::usecases::ports::password_hasher::DUMMY_PASSWORD;
+use crate::core::usecases::ports::PasswordHasher;
+use std::sync::OnceLock;
+use scrypt::{
+    password_hash::{
+        rand_core::OsRng,
+        PasswordHash, PasswordHasher as ScryptHasher, PasswordVerifier, SaltString,
+    },
+    Params, Scrypt, ALG_ID,
+};
+
+/// Scrypt password hasher implementation.
+///
+/// This hasher uses scrypt with configurable cost parameters.
+/// All parameters are injected via constructor - no hardcoded defaults.
+#[derive(Debug, Clone)]
+pub struct ScryptPasswordHasher {
+    params: Params,
+    dummy: OnceLock<StoredCredential>,
+}
+
+impl ScryptPasswordHasher {
+    /// Create a new scrypt password hasher with the specified parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `log_n` - CPU/memory cost as log2(N)
+    /// * `r` - Block size
+    /// * `p` - Parallelization
+    ///
+    /// # Errors
+    ///
+    /// Returns `PasswordError` if the parameters are invalid.
+    pub fn new(log_n: u8, r: u32, p: u32) -> Result<Self, PasswordError> {
+        let params = Params::new(log_n, r, p, Params::RECOMMENDED_LEN)
+            .map_err(|e| PasswordError::hashing(format!("invalid scrypt parameters: {}", e)))?;
+
+        Ok(Self { params, dummy: OnceLock::new() })
+    }
+
+    /// Get the configured log2(N) cost.
+    pub fn log_n(&self) -> u8 {
+        self.params.log_n()
+    }
+
+    /// Get the configured block size.
+    pub fn r(&self) -> u32 {
+        self.params.r()
+    }
+
+    /// Get the configured parallelization.
+    pub fn p(&self) -> u32 {
+        self.params.p()
+    }
+
+    /// Hash a password and return the PHC string.
+    ///
+    /// This is the internal implementation that returns the actual hash string.
+    /// The public `hash` method wraps this in a StoredCredential.
+    fn hash_to_string(&self, raw: &str) -> Result<String, PasswordError> {
+        // Generate a random salt using OsRng
+        let salt = SaltString::generate(&mut OsRng);
+
+        // Hash the password
+        let password_hash = Scrypt
+            .hash_password_customized(raw.as_bytes(), None, None, self.params, &salt)
+            .map_err(|e| PasswordError::hashing(format!("scrypt hashing failed: {}", e)))?;
+
+        Ok(password_hash.to_string())
+    }
+
+    /// Verify a password against a PHC string using its embedded parameters.
+    fn verify_str(&self, raw: &str, hash_str: &str) -> Result<(), PasswordError> {
+        let parsed_hash = PasswordHash::new(hash_str)
+            .map_err(|e| PasswordError::invalid_hash(format!("unparsable scrypt hash: {}", e)))?;
+
+        if parsed_hash.algorithm != ALG_ID {
+            return Err(PasswordError::invalid_hash("not a scrypt hash"));
+        }
+
+        Scrypt
+            .verify_password(raw.as_bytes(), &parsed_hash)
+            .map_err(|e| PasswordError::verification_failed(format!("scrypt verification failed: {}", e)))
+    }
+}
+
+impl PasswordHasher for ScryptPasswordHasher {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        // Hash the password and wrap in StoredCredential
+        let hash_str = self
+            .hash_to_string(raw)
+            .expect("scrypt hashing should not fail with valid parameters");
+
+        StoredCredential::from_hash(hash_str)
+    }
+
+    fn verify(&self, raw: &str, stored: &StoredCredential) -> bool {
+        self.verify_str(raw, stored.as_hash_str()).is_ok()
+    }
+
+    fn dummy_verify(&self, raw: &str) {
+        let dummy = self.dummy.get_or_init(|| self.hash(DUMMY_PASSWORD));
+        let _ = self.verify(raw, dummy);
+    }
+
+    fn needs_rehash(&self, stored: &StoredCredential) -> bool {
+        let parsed_hash = match PasswordHash::new(stored.as_hash_str()) {
+            Ok(hash) => hash,
+            Err(_) => return true,
+        };
+
+        if parsed_hash.algorithm != ALG_ID {
+            return true;
+        }
+
+        // Compare encoded ln/r/p against the current configuration
+        match Params::try_from(&parsed_hash) {
+            Ok(stored_params) => {
+                stored_params.log_n() != self.params.log_n()
+                    || stored_params.r() != self.params.r()
+                    || stored_params.p() != self.params.p()
+            }
+            Err(_) => true,
+        }
+    }
+}
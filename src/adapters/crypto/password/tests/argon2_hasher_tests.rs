@@ -1,6 +1,7 @@
 //! Tests for Argon2 password hasher.
 
 use crate::adapters::crypto::password::Argon2PasswordHasher;
+use crate::core::credentials::StoredCredential;
 use crate::core::usecases::ports::PasswordHasher;
 
 fn create_test_hasher() -> Argon2PasswordHasher {
@@ -200,3 +201,115 @@ fn test_different_parallelism() {
     assert!(credential1.is_non_empty());
     assert!(credential2.is_non_empty());
 }
+
+#[test]
+fn test_needs_rehash_for_weak_parameters() {
+    let weak = Argon2PasswordHasher::new(8192, 1, 1, 16).unwrap();
+    let current = Argon2PasswordHasher::new(16384, 2, 1, 16).unwrap();
+
+    let credential = weak.hash("upgrade_me");
+    assert!(current.needs_rehash(&credential));
+
+    // The weak hash still verifies, so login can succeed before the upgrade
+    assert!(current.verify("upgrade_me", &credential));
+}
+
+#[test]
+fn test_no_rehash_for_current_parameters() {
+    let hasher = Argon2PasswordHasher::new(8192, 1, 1, 16).unwrap();
+
+    let credential = hasher.hash("up_to_date");
+    assert!(!hasher.needs_rehash(&credential));
+}
+
+#[test]
+fn test_needs_rehash_for_unparsable_hash() {
+    let hasher = Argon2PasswordHasher::new(8192, 1, 1, 16).unwrap();
+
+    let credential = StoredCredential::from_hash("$2b$12$legacybcrypthashvalue");
+    assert!(hasher.needs_rehash(&credential));
+}
+
+#[test]
+fn test_peppered_hash_round_trip() {
+    let hasher = Argon2PasswordHasher::new_with_pepper(8192, 1, 1, 16, b"server-pepper".to_vec()).unwrap();
+    assert!(hasher.has_pepper());
+
+    let credential = hasher.hash("peppered_password");
+    assert!(hasher.verify("peppered_password", &credential));
+    assert!(!hasher.verify("wrong_password", &credential));
+}
+
+#[test]
+fn test_peppered_hash_fails_without_pepper() {
+    let peppered = Argon2PasswordHasher::new_with_pepper(8192, 1, 1, 16, b"server-pepper".to_vec()).unwrap();
+    let plain = Argon2PasswordHasher::new(8192, 1, 1, 16).unwrap();
+
+    let credential = peppered.hash("peppered_password");
+    assert!(!plain.verify("peppered_password", &credential));
+}
+
+#[test]
+fn test_peppered_hash_fails_with_different_pepper() {
+    let peppered = Argon2PasswordHasher::new_with_pepper(8192, 1, 1, 16, b"server-pepper".to_vec()).unwrap();
+    let rotated = Argon2PasswordHasher::new_with_pepper(8192, 1, 1, 16, b"other-pepper".to_vec()).unwrap();
+
+    let credential = peppered.hash("peppered_password");
+    assert!(!rotated.verify("peppered_password", &credential));
+}
+
+#[test]
+fn test_pepper_not_embedded_in_hash_or_debug() {
+    let hasher = Argon2PasswordHasher::new_with_pepper(8192, 1, 1, 16, b"server-pepper".to_vec()).unwrap();
+
+    let credential = hasher.hash("peppered_password");
+    assert!(!credential.as_hash_str().contains("server-pepper"));
+
+    let debug = format!("{:?}", hasher);
+    assert!(!debug.contains("server-pepper"));
+    assert!(debug.contains("[REDACTED]"));
+}
+
+#[test]
+fn test_new_with_empty_pepper_rejected() {
+    let result = Argon2PasswordHasher::new_with_pepper(8192, 1, 1, 16, Vec::new());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_verify_with_any_pepper_accepts_retired_pepper() {
+    let old = Argon2PasswordHasher::new_with_pepper(8192, 1, 1, 16, b"old-pepper".to_vec()).unwrap();
+    let current = Argon2PasswordHasher::new_with_pepper(8192, 1, 1, 16, b"new-pepper".to_vec()).unwrap();
+
+    let credential = old.hash("peppered_password");
+
+    // Only the new pepper: the old hash no longer verifies
+    assert!(!current.verify("peppered_password", &credential));
+    assert_eq!(current.verify_with_any_pepper(&[b"new-pepper".as_slice()], "peppered_password", &credential), None);
+
+    // Current and previous peppers: matched by the previous one, flagging a re-hash
+    let peppers: [&[u8]; 2] = [b"new-pepper", b"old-pepper"];
+    assert_eq!(current.verify_with_any_pepper(&peppers, "peppered_password", &credential), Some(1));
+}
+
+#[test]
+fn test_verify_with_any_pepper_prefers_current_pepper() {
+    let current = Argon2PasswordHasher::new_with_pepper(8192, 1, 1, 16, b"new-pepper".to_vec()).unwrap();
+    let credential = current.hash("peppered_password");
+
+    let peppers: [&[u8]; 2] = [b"new-pepper", b"old-pepper"];
+    assert_eq!(current.verify_with_any_pepper(&peppers, "peppered_password", &credential), Some(0));
+    assert_eq!(current.verify_with_any_pepper(&peppers, "wrong_password", &credential), None);
+}
+
+#[test]
+fn test_dummy_verify_is_repeatable() {
+    let hasher = create_test_hasher();
+
+    // Computes the dummy hash on first use, then reuses it
+    hasher.dummy_verify("any_password");
+    hasher.dummy_verify("another_password");
+
+    // A clone shares the already computed dummy hash and stays usable
+    hasher.clone().dummy_verify("any_password");
+}
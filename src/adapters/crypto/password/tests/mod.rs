@@ -1,11 +1,14 @@
 //! Tests for the password hashing module.
 //!
 //! These tests verify:
-//! - Argon2 hasher creation with various parameters
+//! - Argon2 and scrypt hasher creation with various parameters
 //! - Password hashing produces valid credentials
 //! - Password verification succeeds for correct passwords
 //! - Password verification fails for incorrect passwords
 //! - Corrupted hashes are rejected
 //! - Same password produces different hashes (due to random salt)
+//! - Strength scoring penalises weak and identifier-based passwords
 
 pub mod argon2_hasher_tests;
+pub mod scrypt_hasher_tests;
+pub mod zxcvbn_strength_estimator_tests;
@@ -0,0 +1,92 @@
+//! Tests for scrypt password hasher.
+
+use crate::adapters::crypto::password::{Argon2PasswordHasher, ScryptPasswordHasher};
+use crate::core::credentials::StoredCredential;
+use crate::core::usecases::ports::PasswordHasher;
+
+fn create_test_hasher() -> ScryptPasswordHasher {
+    // Low cost keeps the suite fast; production uses log_n >= 15
+    ScryptPasswordHasher::new(10, 8, 1).expect("Valid test parameters")
+}
+
+#[test]
+fn test_new_with_valid_parameters() {
+    let hasher = ScryptPasswordHasher::new(10, 8, 1).unwrap();
+    assert_eq!(hasher.log_n(), 10);
+    assert_eq!(hasher.r(), 8);
+    assert_eq!(hasher.p(), 1);
+}
+
+#[test]
+fn test_new_with_invalid_parameters() {
+    // log_n must be below 64
+    assert!(ScryptPasswordHasher::new(64, 8, 1).is_err());
+    // r and p must be non-zero
+    assert!(ScryptPasswordHasher::new(10, 0, 1).is_err());
+    assert!(ScryptPasswordHasher::new(10, 8, 0).is_err());
+}
+
+#[test]
+fn test_hash_produces_phc_string() {
+    let hasher = create_test_hasher();
+    let credential = hasher.hash("test_password");
+
+    assert!(credential.as_hash_str().starts_with("$scrypt$ln=10,r=8,p=1$"));
+}
+
+#[test]
+fn test_round_trip() {
+    let hasher = create_test_hasher();
+    let credential = hasher.hash("correct_password");
+
+    assert!(hasher.verify("correct_password", &credential));
+}
+
+#[test]
+fn test_wrong_password_rejected() {
+    let hasher = create_test_hasher();
+    let credential = hasher.hash("correct_password");
+
+    assert!(!hasher.verify("wrong_password", &credential));
+    assert!(!hasher.verify("", &credential));
+}
+
+#[test]
+fn test_verify_uses_embedded_parameters() {
+    let old_hasher = ScryptPasswordHasher::new(9, 4, 2).unwrap();
+    let current_hasher = create_test_hasher();
+
+    let credential = old_hasher.hash("legacy_password");
+    assert!(credential.as_hash_str().starts_with("$scrypt$ln=9,r=4,p=2$"));
+
+    assert!(current_hasher.verify("legacy_password", &credential));
+    assert!(!current_hasher.verify("wrong_password", &credential));
+}
+
+#[test]
+fn test_corrupted_and_foreign_hashes_rejected() {
+    let hasher = create_test_hasher();
+
+    assert!(!hasher.verify("password", &StoredCredential::from_hash("not-a-phc-string")));
+
+    let argon2 = Argon2PasswordHasher::new(8192, 1, 1, 16).unwrap();
+    let argon2_credential = argon2.hash("password");
+    assert!(!hasher.verify("password", &argon2_credential));
+}
+
+#[test]
+fn test_needs_rehash() {
+    let hasher = create_test_hasher();
+
+    assert!(!hasher.needs_rehash(&hasher.hash("password")));
+    assert!(hasher.needs_rehash(&ScryptPasswordHasher::new(9, 8, 1).unwrap().hash("password")));
+    assert!(hasher.needs_rehash(&StoredCredential::from_hash("not-a-phc-string")));
+}
+
+#[test]
+fn test_dummy_verify_is_repeatable() {
+    let hasher = create_test_hasher();
+
+    hasher.dummy_verify("any_password");
+    hasher.dummy_verify("another_password");
+}
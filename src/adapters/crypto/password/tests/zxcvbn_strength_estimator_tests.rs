@@ -0,0 +1,56 @@
+//! Tests for ZxcvbnStrengthEstimator.
+
+use std::sync::Arc;
+
+use crate::adapters::crypto::password::ZxcvbnStrengthEstimator;
+use crate::core::credentials::{CredentialPolicy, RawCredential};
+use crate::core::error::CredentialError;
+use crate::core::usecases::ports::StrengthEstimator;
+
+#[test]
+fn test_strong_passphrase_scores_high() {
+    let estimator = ZxcvbnStrengthEstimator::new();
+
+    assert_eq!(estimator.score("tundra-Lantern-93-violet-Quasar", &[]), 4);
+}
+
+#[test]
+fn test_keyboard_walk_scores_low() {
+    let estimator = ZxcvbnStrengthEstimator::new();
+
+    assert!(estimator.score("qwertyuiop", &[]) <= 1);
+    assert!(estimator.score("aaaaaaaaaaaa", &[]) <= 1);
+}
+
+#[test]
+fn test_identifier_lowers_score() {
+    let estimator = ZxcvbnStrengthEstimator::new();
+    let password = "margueritefontaine";
+
+    let without = estimator.score(password, &[]);
+    let with = estimator.score(password, &["marguerite.fontaine@example.com"]);
+
+    assert!(with < without, "identifier-based password scored {} vs {}", with, without);
+}
+
+#[test]
+fn test_empty_secret_scores_zero() {
+    let estimator = ZxcvbnStrengthEstimator::new();
+
+    assert_eq!(estimator.score("", &[]), 0);
+}
+
+#[test]
+fn test_policy_surfaces_score_for_keyboard_walk() {
+    let policy = CredentialPolicy::default().with_strength_estimator(Arc::new(ZxcvbnStrengthEstimator::new()), 3);
+
+    assert!(policy.validate_raw(&RawCredential::new("tundra-Lantern-93-violet-Quasar")).is_ok());
+
+    match policy.validate_raw(&RawCredential::new("qwertyuiop")) {
+        Err(CredentialError::TooWeak { score, required }) => {
+            assert!(score < 3);
+            assert_eq!(required, 3);
+        }
+        other => panic!("expected TooWeak, got {:?}", other),
+    }
+}
@@ -0,0 +1,67 @@
+//! zxcvbn password strength estimator.
+//!
+//! This module provides a concrete implementation of the `StrengthEstimator`
+//! port using the zxcvbn crate, which scores passwords by estimated guesses
+//! against dictionaries, keyboard patterns, repeats and sequences rather than
+//! by character classes.
+//!
+//! # Example
+//!
+//! ```rust
+//! use auth::adapters::crypto::password::ZxcvbnStrengthEstimator;
+//! use auth::core::usecases::ports::StrengthEstimator;
+//!
+//! let estimator = ZxcvbnStrengthEstimator::new();
+//!
+//! assert!(estimator.score("qwertyuiop", &[]) < 2);
+//! ```
+
+use crate::core::usecases::ports::StrengthEstimator;
+
+/// Minimum fragment length added to the user dictionary.
+const MIN_FRAGMENT_LEN: usize = 3;
+
+/// Password strength estimator backed by zxcvbn.
+///
+/// Each user input is added to zxcvbn's dictionary both whole and split into
+/// its alphanumeric fragments, so `jane.doe@example.com` also penalises
+/// passwords built around `jane` or `doe`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZxcvbnStrengthEstimator;
+
+impl ZxcvbnStrengthEstimator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Expand user inputs into the words zxcvbn should treat as known.
+fn user_dictionary(user_inputs: &[&str]) -> Vec<String> {
+    let mut words = Vec::new();
+    for input in user_inputs {
+        let input = input.trim().to_lowercase();
+        if input.is_empty() {
+            continue;
+        }
+        words.extend(
+            input
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|fragment| fragment.len() >= MIN_FRAGMENT_LEN && *fragment != input)
+                .map(str::to_string),
+        );
+        words.push(input);
+    }
+    words
+}
+
+impl StrengthEstimator for ZxcvbnStrengthEstimator {
+    fn score(&self, secret: &str, user_inputs: &[&str]) -> u8 {
+        if secret.is_empty() {
+            return 0;
+        }
+
+        let dictionary = user_dictionary(user_inputs);
+        let dictionary: Vec<&str> = dictionary.iter().map(String::as_str).collect();
+        zxcvbn::zxcvbn(secret, &dictionary).score() as u8
+    }
+}
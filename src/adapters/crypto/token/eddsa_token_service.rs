@@ -12,11 +12,22 @@
 //! - **JWT Standard Compliant**: Uses i64 timestamps, flattened claims
 
 use crate::adapters::crypto::error::JwtError;
-use crate::adapters::crypto::token::EddsaKey;
+use crate::adapters::crypto::token::{single_use, EddsaKey};
 use crate::core::token::{Token, TokenClaims};
 use crate::core::usecases::ports::TokenService;
 use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use futures::future::{ready, BoxFuture, FutureExt};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Reset token TTL when the caller does not request an expiry (15 minutes).
+const RESET_TOKEN_TTL_MINUTES: i64 = 15;
+/// Verification token TTL when the caller does not request an expiry (1 hour).
+const VERIFICATION_TOKEN_TTL_MINUTES: i64 = 60;
+/// Claims set by the service itself; application claims cannot override them.
+const RESERVED_CLAIMS: [&str; 12] = [
+    "iss", "sub", "aud", "exp", "nbf", "iat", "jti", "sid", "type", "token_type", "scope", "workspace_id",
+];
 
 /// Ed25519-EdDSA-based token service implementation.
 ///
@@ -95,6 +106,11 @@ impl EddsaTokenService {
 
     /// Encode TokenClaims into a JWT token.
     pub fn encode_token(&self, claims: &TokenClaims) -> Result<String, JwtError> {
+        self.encode_token_with_extra(claims, &Map::new())
+    }
+
+    /// Encode TokenClaims plus non-reserved application claims into a JWT token.
+    fn encode_token_with_extra(&self, claims: &TokenClaims, extra: &Map<String, Value>) -> Result<String, JwtError> {
         // Create a serialization struct that matches JWT format
         #[derive(Serialize)]
         struct JwtClaims<'a> {
@@ -107,6 +123,11 @@ impl EddsaTokenService {
             scope: Option<Vec<&'a str>>,
             #[serde(rename = "token_type")]
             token_type: &'a str,
+            jti: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            workspace_id: Option<&'a str>,
+            #[serde(flatten)]
+            extra: &'a Map<String, Value>,
         }
 
         let audience = claims.aud.as_ref().map(|aud| {
@@ -119,6 +140,9 @@ impl EddsaTokenService {
             Some(claims.scope.iter().map(|s| s.as_str()).collect::<Vec<&str>>())
         };
 
+        // Every issued token is identified; single-use tokens bring their own id
+        let jti = claims.jti.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
         let jwt_claims = JwtClaims {
             sub: &claims.sub,
             sid: claims.sid.as_deref(),
@@ -128,6 +152,9 @@ impl EddsaTokenService {
             nbf: claims.nbf,
             scope,
             token_type: &claims.token_type,
+            jti: &jti,
+            workspace_id: claims.workspace_id.as_deref(),
+            extra,
         };
 
         let header = Header::new(self.algorithm);
@@ -138,6 +165,12 @@ impl EddsaTokenService {
 
     /// Decode and validate a JWT token.
     fn decode_token(&self, token: &str) -> Result<TokenClaims, JwtError> {
+        self.decode_token_with_extra(token).map(|(claims, _)| claims)
+    }
+
+    /// Decode and validate a JWT token, keeping the non-reserved claims that
+    /// `TokenClaims` has no field for.
+    fn decode_token_with_extra(&self, token: &str) -> Result<(TokenClaims, Map<String, Value>), JwtError> {
         let validation = self.create_validation();
 
         // First decode to get raw claims, then map to our struct
@@ -153,6 +186,12 @@ impl EddsaTokenService {
             scope: Option<Vec<String>>,
             #[serde(rename = "token_type")]
             token_type: String,
+            #[serde(default)]
+            jti: Option<String>,
+            #[serde(default)]
+            workspace_id: Option<String>,
+            #[serde(flatten)]
+            extra: Map<String, Value>,
         }
 
         let token_data = decode::<RawJwtClaims>(token, &self.decoding_key, &validation)
@@ -180,7 +219,7 @@ impl EddsaTokenService {
         // Scope is now an array
         let scope = raw.scope.unwrap_or_default();
 
-        Ok(TokenClaims {
+        let claims = TokenClaims {
             sub: raw.sub,
             sid: raw.session_id,
             aud: raw.aud,
@@ -189,12 +228,45 @@ impl EddsaTokenService {
             nbf: raw.nbf,
             scope,
             token_type: raw.token_type,
-        })
+            jti: raw.jti,
+            workspace_id: raw.workspace_id,
+        };
+
+        Ok((claims, Self::without_reserved(raw.extra)))
+    }
+
+    fn without_reserved(mut claims: Map<String, Value>) -> Map<String, Value> {
+        claims.retain(|name, _| !RESERVED_CLAIMS.contains(&name.as_str()));
+        claims
+    }
+
+    /// Issue a single-use token of `token_type`, carrying the caller's `jti`.
+    fn issue_single_use_token(&self, claims: &str, token_type: &str, default_ttl: chrono::Duration) -> Token {
+        match self.encode_token(&single_use::issue_claims(claims, token_type, default_ttl)) {
+            Ok(token_value) => Token::new(token_value),
+            Err(_) => Token::new(""),
+        }
+    }
+
+    /// Validate a single-use token, rejecting any other token type.
+    fn validate_single_use_token(&self, token: &Token, token_type: &str) -> Result<String, ()> {
+        let token_str = token.value();
+
+        if token_str.is_empty() {
+            return Err(());
+        }
+
+        self.decode_token(token_str)
+            .ok()
+            .and_then(|claims| single_use::validated_claims(claims, token_type))
+            .ok_or(())
     }
 }
 
-impl TokenService for EddsaTokenService {
-    fn issue_access_token(&self, _subject: &str, claims: &str) -> Token {
+/// Token operations. Signing and verifying are CPU-bound, so these run
+/// synchronously; the `TokenService` impl answers with their results.
+impl EddsaTokenService {
+    pub(crate) fn issue_access_token(&self, _subject: &str, claims: &str) -> Token {
         // Parse the claims JSON to extract identity information
         // The claims JSON has format: {"sub":"user_id","type":"access","exp":123456,"sid":"session_id"}
         let claims_json: serde_json::Value = serde_json::from_str(claims).unwrap_or_default();
@@ -206,26 +278,43 @@ impl TokenService for EddsaTokenService {
         
         let session_id = claims_json.get("sid")
             .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string());
+
+        let workspace_id = claims_json.get("workspace_id")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
             .map(|s| s.to_string());
 
         let now = chrono::Utc::now();
         let expires = now + chrono::Duration::hours(1); // 1 hour for access tokens
 
-        let token_claims = TokenClaims::new(
+        let mut token_claims = TokenClaims::new(
             user_id,
             now.timestamp(),
             expires.timestamp(),
             "access".to_string(),
-        )
-        .with_sid(session_id.unwrap_or_default());
+        );
+        if let Some(sid) = session_id {
+            token_claims = token_claims.with_sid(sid);
+        }
+        if let Some(workspace_id) = workspace_id {
+            token_claims = token_claims.with_workspace_id(workspace_id);
+        }
 
-        match self.encode_token(&token_claims) {
+        // Carry the application claims (roles, ...) the caller assembled
+        let extra = match claims_json {
+            Value::Object(claims) => Self::without_reserved(claims),
+            _ => Map::new(),
+        };
+
+        match self.encode_token_with_extra(&token_claims, &extra) {
             Ok(token_value) => Token::new(token_value),
             Err(_) => Token::new(""),
         }
     }
 
-    fn issue_refresh_token(&self, _subject: &str, claims: &str) -> Token {
+    pub(crate) fn issue_refresh_token(&self, _subject: &str, claims: &str) -> Token {
         // Parse the claims JSON to extract identity information
         let claims_json: serde_json::Value = serde_json::from_str(claims).unwrap_or_default();
         
@@ -236,18 +325,21 @@ impl TokenService for EddsaTokenService {
         
         let session_id = claims_json.get("sid")
             .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
             .map(|s| s.to_string());
 
         let now = chrono::Utc::now();
         let expires = now + chrono::Duration::days(7); // 7 days for refresh tokens
 
-        let token_claims = TokenClaims::new(
+        let mut token_claims = TokenClaims::new(
             user_id,
             now.timestamp(),
             expires.timestamp(),
             "refresh".to_string(),
-        )
-        .with_sid(session_id.unwrap_or_default());
+        );
+        if let Some(sid) = session_id {
+            token_claims = token_claims.with_sid(sid);
+        }
 
         match self.encode_token(&token_claims) {
             Ok(token_value) => Token::new(token_value),
@@ -255,17 +347,17 @@ impl TokenService for EddsaTokenService {
         }
     }
 
-    fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
+    pub(crate) fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
         let token_str = token.value();
         
         if token_str.is_empty() {
             return Err(());
         }
 
-        match self.decode_token(token_str) {
-            Ok(claims) => {
-                // Build claims JSON for return
-                let mut claims_map = serde_json::Map::new();
+        match self.decode_token_with_extra(token_str) {
+            Ok((claims, extra)) => {
+                // Build claims JSON for return, starting from the application claims
+                let mut claims_map = extra;
                 
                 claims_map.insert("sub".to_string(), serde_json::Value::String(claims.sub));
                 claims_map.insert("type".to_string(), serde_json::Value::String(claims.token_type));
@@ -276,6 +368,14 @@ impl TokenService for EddsaTokenService {
                     claims_map.insert("sid".to_string(), serde_json::Value::String(sid));
                 }
                 
+                if let Some(workspace_id) = claims.workspace_id {
+                    claims_map.insert("workspace_id".to_string(), serde_json::Value::String(workspace_id));
+                }
+
+                if let Some(jti) = claims.jti {
+                    claims_map.insert("jti".to_string(), serde_json::Value::String(jti));
+                }
+                
                 if let Some(aud) = claims.aud {
                     claims_map.insert("aud".to_string(), serde_json::Value::Array(
                         aud.into_iter().map(serde_json::Value::String).collect()
@@ -295,7 +395,7 @@ impl TokenService for EddsaTokenService {
         }
     }
 
-    fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
+    pub(crate) fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
         let token_str = token.value();
         
         if token_str.is_empty() {
@@ -320,6 +420,10 @@ impl TokenService for EddsaTokenService {
                 if let Some(sid) = claims.sid {
                     claims_map.insert("sid".to_string(), serde_json::Value::String(sid));
                 }
+
+                if let Some(jti) = claims.jti {
+                    claims_map.insert("jti".to_string(), serde_json::Value::String(jti));
+                }
                 
                 let claims_json = serde_json::to_string(&claims_map).unwrap_or_default();
                 Ok(claims_json)
@@ -328,7 +432,7 @@ impl TokenService for EddsaTokenService {
         }
     }
 
-    fn issue_service_token(&self, subject: &str, claims: &str) -> Token {
+    pub(crate) fn issue_service_token(&self, subject: &str, claims: &str) -> Token {
         // Use service token key if configured, otherwise fall back to main key
         let encoding_key = self.service_encoding_key.as_ref()
             .unwrap_or(&self.encoding_key);
@@ -370,6 +474,7 @@ impl TokenService for EddsaTokenService {
             scope: Option<Vec<&'a str>>,
             #[serde(rename = "token_type")]
             token_type: &'a str,
+            jti: String,
         }
 
         let audience = token_claims.aud.as_ref().map(|aud| {
@@ -388,6 +493,7 @@ impl TokenService for EddsaTokenService {
             nbf: token_claims.nbf,
             scope: None,
             token_type: &token_claims.token_type,
+            jti: uuid::Uuid::new_v4().to_string(),
         };
 
         let header = Header::new(self.algorithm);
@@ -398,7 +504,7 @@ impl TokenService for EddsaTokenService {
         }
     }
 
-    fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
+    pub(crate) fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
         let token_str = token.value();
         
         if token_str.is_empty() {
@@ -425,6 +531,8 @@ impl TokenService for EddsaTokenService {
             _scope: Option<String>,
             #[serde(rename = "token_type")]
             token_type: String,
+            #[serde(default)]
+            jti: Option<String>,
         }
 
         match decode::<RawJwtClaims>(token_str, decoding_key, &validation) {
@@ -443,6 +551,9 @@ impl TokenService for EddsaTokenService {
                 claims_map.insert("type".to_string(), serde_json::Value::String("service".to_string()));
                 claims_map.insert("exp".to_string(), serde_json::Value::Number(claims.exp.into()));
                 claims_map.insert("iat".to_string(), serde_json::Value::Number(claims.iat.into()));
+                if let Some(jti) = claims.jti {
+                    claims_map.insert("jti".to_string(), serde_json::Value::String(jti));
+                }
                 
                 let claims_json = serde_json::to_string(&claims_map).unwrap_or_default();
                 Ok(claims_json)
@@ -450,6 +561,68 @@ impl TokenService for EddsaTokenService {
             Err(_) => Err(()),
         }
     }
+
+    pub(crate) fn issue_reset_token(&self, _subject: &str, claims: &str) -> Token {
+        self.issue_single_use_token(claims, "reset", chrono::Duration::minutes(RESET_TOKEN_TTL_MINUTES))
+    }
+
+    pub(crate) fn validate_reset_token(&self, token: &Token) -> Result<String, ()> {
+        self.validate_single_use_token(token, "reset")
+    }
+
+    pub(crate) fn issue_verification_token(&self, _subject: &str, claims: &str) -> Token {
+        self.issue_single_use_token(claims, "verify", chrono::Duration::minutes(VERIFICATION_TOKEN_TTL_MINUTES))
+    }
+
+    pub(crate) fn validate_verification_token(&self, token: &Token) -> Result<String, ()> {
+        self.validate_single_use_token(token, "verify")
+    }
+}
+
+impl TokenService for EddsaTokenService {
+    fn issue_access_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(EddsaTokenService::issue_access_token(self, subject, claims)).boxed()
+    }
+
+    fn issue_refresh_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(EddsaTokenService::issue_refresh_token(self, subject, claims)).boxed()
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(EddsaTokenService::validate_access_token(self, token)).boxed()
+    }
+
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(EddsaTokenService::validate_refresh_token(self, token)).boxed()
+    }
+
+    fn issue_service_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(EddsaTokenService::issue_service_token(self, subject, claims)).boxed()
+    }
+
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(EddsaTokenService::validate_service_token(self, token)).boxed()
+    }
+
+    fn issue_reset_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(EddsaTokenService::issue_reset_token(self, subject, claims)).boxed()
+    }
+
+    fn validate_reset_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(EddsaTokenService::validate_reset_token(self, token)).boxed()
+    }
+
+    fn issue_verification_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(EddsaTokenService::issue_verification_token(self, subject, claims)).boxed()
+    }
+
+    fn validate_verification_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(EddsaTokenService::validate_verification_token(self, token)).boxed()
+    }
+
+    fn supported_algorithms(&self) -> &[&str] {
+        &["EdDSA"]
+    }
 }
 
 
@@ -8,15 +8,141 @@
 //! - **Pure cryptographic**: No session awareness, no revocation checks
 //! - **Deterministic errors**: All failures map to specific error types
 //! - **No secret leakage**: Keys are never logged or exposed in errors
-//! - **Algorithm enforcement**: Only HS256 is supported
+//! - **Algorithm enforcement**: Only HS256 is supported; a header declaring
+//!   `none` or any other algorithm is rejected before a key is consulted
 //! - **JWT Standard Compliant**: Uses i64 timestamps, flattened claims
+//! - **Key rotation**: Tokens carry a `kid` header; several verification keys
+//!   may be active at once so old tokens survive a signing key rollover
+//! - **Two-key overlap**: without `kid`, a single secondary key may be tried
+//!   after the primary for a simple rotation window
+//! - **Policy-driven lifetimes**: TTLs come from `TokenPolicy` and expiry is
+//!   decided by the core `TokenLifetime`, not by jsonwebtoken's own checks
+//! - **Typed headers**: access tokens carry `typ: at+jwt` (RFC 9068), other
+//!   tokens `typ: JWT`; the header type can be enforced on decode
+//! - **Claim enrichment**: an optional enricher adds deployment-specific
+//!   claims (e.g. `tenant`, `roles`) to access tokens; claims the service
+//!   sets itself cannot be overridden
+//! - **Bounded sizes**: oversized claims are refused at issuance and
+//!   oversized tokens are refused before any decoding work
+//! - **Per-type issuer and audience**: access and refresh tokens each carry
+//!   their own `iss`/`aud` and decide separately whether to enforce them
 
 use crate::adapters::crypto::error::JwtError;
-use crate::adapters::crypto::token::HmacKey;
-use crate::core::token::{Token, TokenClaims};
+use crate::adapters::crypto::token::{single_use, HmacKey};
+use crate::core::error::TokenError;
+use crate::core::identity::IdentityClaims;
+use crate::core::token::{Token, TokenClaims, TokenLifetime, TokenValidationFailure};
+use crate::core::usecases::policies::TokenPolicy;
 use crate::core::usecases::ports::TokenService;
-use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use futures::future::{ready, BoxFuture, FutureExt};
+use jsonwebtoken::{decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Default access token TTL (1 hour).
+const DEFAULT_ACCESS_TTL_SECS: u64 = 3600;
+/// Default refresh token TTL (7 days).
+const DEFAULT_REFRESH_TTL_SECS: u64 = 7 * 24 * 3600;
+/// Service token TTL (1 hour).
+const SERVICE_TOKEN_TTL_SECS: u64 = 3600;
+/// Reset token TTL when the caller does not request an expiry (15 minutes).
+const RESET_TOKEN_TTL_MINUTES: i64 = 15;
+/// Verification token TTL when the caller does not request an expiry (1 hour).
+const VERIFICATION_TOKEN_TTL_MINUTES: i64 = 60;
+/// Default clock skew tolerance: none, so expiry is exact unless configured.
+const DEFAULT_LEEWAY_SECS: u64 = 0;
+/// Default header `typ` for access tokens (RFC 9068).
+const DEFAULT_ACCESS_TYP: &str = "at+jwt";
+/// Default header `typ` for every other token.
+const DEFAULT_TYP: &str = "JWT";
+/// Default limit on the claims JSON accepted by `issue_*` (8 KiB).
+const DEFAULT_MAX_CLAIMS_BYTES: usize = 8 * 1024;
+/// Default limit on the length of an incoming token (16 KiB).
+const DEFAULT_MAX_TOKEN_BYTES: usize = 16 * 1024;
+/// Claims set by the service itself; neither the caller's application claims
+/// nor an enricher can override them.
+const RESERVED_CLAIMS: [&str; 12] = [
+    "iss", "sub", "aud", "exp", "nbf", "iat", "jti", "sid", "type", "token_type", "scope", "workspace_id",
+];
+
+/// Callback adding deployment-specific claims to access tokens.
+///
+/// Receives the identity the token is issued for; the returned claims are
+/// merged into the payload before signing, minus any reserved claim.
+pub type ClaimEnricher = fn(&IdentityClaims) -> Map<String, Value>;
+
+/// Issuer and audience stamped on, and required of, one token type.
+///
+/// A configured value is always stamped at issuance; whether validation
+/// requires it is a separate switch, so e.g. access tokens can be held to a
+/// strict audience while refresh tokens are not.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenValidationRules {
+    issuer: Option<String>,
+    audience: Option<String>,
+    enforce_issuer: bool,
+    enforce_audience: bool,
+}
+
+impl TokenValidationRules {
+    /// Stamp `issuer` as `iss` and require it on validation.
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer = Some(issuer.into());
+        self.enforce_issuer = true;
+        self
+    }
+
+    /// Stamp `audience` as `aud` and require it on validation.
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.audience = Some(audience.into());
+        self.enforce_audience = true;
+        self
+    }
+
+    /// Whether validation requires the configured issuer.
+    pub fn with_issuer_enforced(mut self, enforce: bool) -> Self {
+        self.enforce_issuer = enforce;
+        self
+    }
+
+    /// Whether validation requires the configured audience.
+    ///
+    /// When off, any `aud` (or none) is accepted.
+    pub fn with_audience_enforced(mut self, enforce: bool) -> Self {
+        self.enforce_audience = enforce;
+        self
+    }
+
+    /// Issuer stamped on issued tokens.
+    pub fn issuer(&self) -> Option<&str> {
+        self.issuer.as_deref()
+    }
+
+    /// Audience stamped on issued tokens.
+    pub fn audience(&self) -> Option<&str> {
+        self.audience.as_deref()
+    }
+
+    /// Add these rules to a jsonwebtoken validation.
+    fn apply(&self, validation: &mut Validation) {
+        if let (Some(issuer), true) = (&self.issuer, self.enforce_issuer) {
+            validation.set_issuer(&[issuer]);
+            validation.required_spec_claims.insert("iss".to_string());
+        }
+
+        match (&self.audience, self.enforce_audience) {
+            (Some(audience), true) => {
+                validation.set_audience(&[audience]);
+                validation.required_spec_claims.insert("aud".to_string());
+            }
+            // Stamped but not enforced: accept whatever audience arrives
+            (Some(_), false) => validation.validate_aud = false,
+            (None, _) => {}
+        }
+    }
+}
 
 /// HMAC-SHA256-based token service implementation.
 ///
@@ -29,8 +155,20 @@ pub struct HmacTokenService {
     service_encoding_key: Option<EncodingKey>,
     service_decoding_key: Option<DecodingKey>,
     algorithm: Algorithm,
-    issuer: Option<String>,
-    audience: Option<String>,
+    access_rules: TokenValidationRules,
+    refresh_rules: TokenValidationRules,
+    key_id: Option<String>,
+    verification_keys: HashMap<String, DecodingKey>,
+    secondary_decoding_key: Option<DecodingKey>,
+    token_policy: TokenPolicy,
+    leeway_seconds: u64,
+    access_typ: String,
+    default_typ: String,
+    content_type: Option<String>,
+    enforce_typ: bool,
+    claim_enricher: Option<ClaimEnricher>,
+    max_claims_bytes: usize,
+    max_token_bytes: usize,
 }
 
 impl HmacTokenService {
@@ -42,15 +180,27 @@ impl HmacTokenService {
             service_encoding_key: None,
             service_decoding_key: None,
             algorithm: Algorithm::HS256,
-            issuer: None,
-            audience: None,
+            access_rules: TokenValidationRules::default(),
+            refresh_rules: TokenValidationRules::default(),
+            key_id: None,
+            verification_keys: HashMap::new(),
+            secondary_decoding_key: None,
+            token_policy: TokenPolicy::new(DEFAULT_ACCESS_TTL_SECS, DEFAULT_REFRESH_TTL_SECS, true),
+            leeway_seconds: DEFAULT_LEEWAY_SECS,
+            access_typ: DEFAULT_ACCESS_TYP.to_string(),
+            default_typ: DEFAULT_TYP.to_string(),
+            content_type: None,
+            enforce_typ: false,
+            claim_enricher: None,
+            max_claims_bytes: DEFAULT_MAX_CLAIMS_BYTES,
+            max_token_bytes: DEFAULT_MAX_TOKEN_BYTES,
         })
     }
 
     /// Create a new HMAC token service with the given raw key bytes.
     pub fn from_secret_key(key: &[u8]) -> Result<Self, JwtError> {
         let hmac_key = HmacKey::from_bytes(key)
-            .map_err(|e| JwtError::invalid_key(e))?;
+            .map_err(JwtError::invalid_key)?;
         
         Self::from_key(&hmac_key)
     }
@@ -58,7 +208,7 @@ impl HmacTokenService {
     /// Set the service token key for signing/validating service-to-service tokens.
     pub fn with_service_token_key(mut self, key: &[u8]) -> Result<Self, JwtError> {
         let hmac_key = HmacKey::from_bytes(key)
-            .map_err(|e| JwtError::invalid_key(e))?;
+            .map_err(JwtError::invalid_key)?;
         
         self.service_encoding_key = Some(hmac_key.encoding_key().clone());
         self.service_decoding_key = Some(hmac_key.decoding_key().clone());
@@ -66,28 +216,296 @@ impl HmacTokenService {
         Ok(self)
     }
 
-    /// Set the expected issuer for token validation.
+    /// Stamp and require `issuer` on access and refresh tokens alike.
     pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
-        self.issuer = Some(issuer.into());
+        let issuer = issuer.into();
+        self.access_rules = self.access_rules.with_issuer(issuer.clone());
+        self.refresh_rules = self.refresh_rules.with_issuer(issuer);
         self
     }
 
-    /// Set the expected audience for token validation.
+    /// Stamp and require `audience` on access and refresh tokens alike.
     pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
-        self.audience = Some(audience.into());
+        let audience = audience.into();
+        self.access_rules = self.access_rules.with_audience(audience.clone());
+        self.refresh_rules = self.refresh_rules.with_audience(audience);
         self
     }
 
-    /// Create a validation configuration for decoding tokens.
-    fn create_validation(&self) -> Validation {
-        let mut validation = Validation::new(self.algorithm);
+    /// Replace the issuer and audience rules for access tokens.
+    pub fn with_access_validation(mut self, rules: TokenValidationRules) -> Self {
+        self.access_rules = rules;
+        self
+    }
+
+    /// Replace the issuer and audience rules for refresh tokens.
+    pub fn with_refresh_validation(mut self, rules: TokenValidationRules) -> Self {
+        self.refresh_rules = rules;
+        self
+    }
+
+    /// Issuer and audience rules for `token_type`.
+    ///
+    /// Only access and refresh tokens have rules; service and single-use
+    /// tokens carry no configured `iss`/`aud`.
+    fn rules_for(&self, token_type: &str) -> Option<&TokenValidationRules> {
+        match token_type {
+            "access" => Some(&self.access_rules),
+            "refresh" => Some(&self.refresh_rules),
+            _ => None,
+        }
+    }
+
+    /// Add the claims returned by `enricher` to every access token.
+    ///
+    /// Reserved claims (`sub`, `exp`, `iat`, ...) are dropped from the
+    /// enricher's output, so it can extend the payload but never alter the
+    /// identity or lifetime of a token. Enriched claims are returned again
+    /// by `validate_access_token`.
+    pub fn with_claim_enricher(mut self, enricher: ClaimEnricher) -> Self {
+        self.claim_enricher = Some(enricher);
+        self
+    }
+
+    /// Set the token policy whose TTLs drive access and refresh token expiry.
+    ///
+    /// A zero TTL is not rejected here; issuance under it fails when the
+    /// token lifetime is constructed and yields an empty token.
+    pub fn with_token_policy(mut self, policy: TokenPolicy) -> Self {
+        self.token_policy = policy;
+        self
+    }
+
+    /// Tolerate clock skew between issuer and validator.
+    ///
+    /// Applies symmetrically to `exp` and `nbf`: a token is still accepted
+    /// `leeway_seconds` after it expires and `leeway_seconds` before it
+    /// becomes valid.
+    pub fn with_leeway(mut self, leeway_seconds: u64) -> Self {
+        self.leeway_seconds = leeway_seconds;
+        self
+    }
 
-        if let Some(ref issuer) = self.issuer {
-            validation.set_issuer(&[issuer.clone()]);
+    /// Set the header `typ` for access tokens and for all other tokens.
+    pub fn with_header_types(mut self, access_typ: impl Into<String>, default_typ: impl Into<String>) -> Self {
+        self.access_typ = access_typ.into();
+        self.default_typ = default_typ.into();
+        self
+    }
+
+    /// Set the header `cty` advertised on issued tokens.
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Reject tokens whose header `typ` does not match the configured type
+    /// for their `token_type` claim.
+    ///
+    /// Off by default so tokens issued before typed headers keep validating.
+    pub fn with_typ_enforcement(mut self, enforce: bool) -> Self {
+        self.enforce_typ = enforce;
+        self
+    }
+
+    /// Limit the serialized claims JSON accepted by the `issue_*` methods.
+    ///
+    /// Claims longer than `max_bytes` are refused and yield an empty token.
+    pub fn with_max_claims_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_claims_bytes = max_bytes;
+        self
+    }
+
+    /// Limit the length of incoming tokens.
+    ///
+    /// Longer tokens are rejected before their header or payload is decoded.
+    pub fn with_max_token_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_token_bytes = max_bytes;
+        self
+    }
+
+    /// Reject claims JSON longer than the configured limit.
+    pub fn check_claims_size(&self, claims: &str) -> Result<(), JwtError> {
+        if claims.len() > self.max_claims_bytes {
+            return Err(JwtError::too_large(format!(
+                "claims are {} bytes, limit is {}",
+                claims.len(),
+                self.max_claims_bytes
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reject tokens longer than the configured limit.
+    ///
+    /// Runs before any base64 or JSON decoding, so an oversized token costs
+    /// no more than a length comparison.
+    pub fn check_token_size(&self, token: &str) -> Result<(), JwtError> {
+        if token.len() > self.max_token_bytes {
+            return Err(JwtError::too_large(format!(
+                "token is {} bytes, limit is {}",
+                token.len(),
+                self.max_token_bytes
+            )));
+        }
+        Ok(())
+    }
+
+    /// Header `typ` issued for the given token type.
+    fn typ_for(&self, token_type: &str) -> &str {
+        if token_type == "access" {
+            &self.access_typ
+        } else {
+            &self.default_typ
+        }
+    }
+
+    /// Build the header for a token of the given type, without a `kid`.
+    fn header_for(&self, token_type: &str) -> Header {
+        let mut header = Header::new(self.algorithm);
+        header.typ = Some(self.typ_for(token_type).to_string());
+        header.cty = self.content_type.clone();
+        header
+    }
+
+    /// Check the header `typ` against the one configured for `token_type`.
+    ///
+    /// Only applies when enforcement is on. Compared case-insensitively, as
+    /// RFC 7515 recommends for media types.
+    fn check_header_typ(&self, token: &str, token_type: &str) -> Result<(), JwtError> {
+        if !self.enforce_typ {
+            return Ok(());
+        }
+
+        let header = decode_header(token)
+            .map_err(|e| JwtError::decoding(format!("Invalid token header: {}", e)))?;
+
+        match header.typ {
+            Some(typ) if typ.eq_ignore_ascii_case(self.typ_for(token_type)) => Ok(()),
+            _ => Err(JwtError::invalid_token("Unexpected token type header")),
         }
+    }
+
+    /// Set the key id (`kid`) advertised in the header of issued tokens.
+    ///
+    /// The signing key is also registered as a verification key under this id.
+    pub fn with_key_id(mut self, kid: impl Into<String>) -> Self {
+        let kid = kid.into();
+        self.verification_keys.insert(kid.clone(), self.decoding_key.clone());
+        self.key_id = Some(kid);
+        self
+    }
+
+    /// Accept tokens signed with an additional key, selected by `kid`.
+    ///
+    /// Used during rotation: register the retiring key here while issuance
+    /// moves to the new signing key, and drop it once old tokens have expired.
+    pub fn with_additional_verification_key(
+        mut self,
+        kid: impl Into<String>,
+        key: &[u8],
+    ) -> Result<Self, JwtError> {
+        let hmac_key = HmacKey::from_bytes(key)
+            .map_err(JwtError::invalid_key)?;
+
+        self.verification_keys.insert(kid.into(), hmac_key.decoding_key().clone());
+
+        Ok(self)
+    }
+
+    /// Accept tokens signed with a secondary key during a rotation overlap.
+    ///
+    /// Lighter-weight alternative to a `kid` keyring: tokens without a `kid`
+    /// are checked against the primary key first and against this key only
+    /// if the primary signature check fails. Issuance always uses the primary
+    /// key. Tokens carrying a `kid` are resolved through the keyring only.
+    pub fn with_secondary_decoding_key(mut self, key: &[u8]) -> Result<Self, JwtError> {
+        let hmac_key = HmacKey::from_bytes(key)
+            .map_err(JwtError::invalid_key)?;
+
+        self.secondary_decoding_key = Some(hmac_key.decoding_key().clone());
+
+        Ok(self)
+    }
+
+    /// Reject tokens whose header does not declare this service's algorithm.
+    ///
+    /// Reads the raw `alg` member before the header is parsed by
+    /// jsonwebtoken and before any key is selected, so `alg: none` and
+    /// asymmetric algorithms (e.g. `RS256` signed with the shared secret)
+    /// never reach signature verification.
+    fn check_header_algorithm(&self, token: &str) -> Result<(), JwtError> {
+        let encoded_header = token.split('.').next().unwrap_or_default();
+        let header: serde_json::Value = URL_SAFE_NO_PAD
+            .decode(encoded_header)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| JwtError::decoding("Invalid token header"))?;
+
+        match header.get("alg").and_then(|alg| alg.as_str()) {
+            Some(alg) if alg.parse::<Algorithm>().ok() == Some(self.algorithm) => Ok(()),
+            Some(alg) => Err(JwtError::algorithm_mismatch(format!("Unexpected algorithm '{}'", alg))),
+            None => Err(JwtError::algorithm_mismatch("Missing algorithm")),
+        }
+    }
+
+    /// Select the decoding keys for a token, in the order they should be tried.
+    ///
+    /// A `kid` resolves to exactly one keyring entry; an unknown `kid` is
+    /// rejected. Tokens without a `kid` (issued before key ids were
+    /// introduced) use the primary key, then the secondary key if configured.
+    fn select_decoding_keys(&self, token: &str) -> Result<Vec<&DecodingKey>, JwtError> {
+        let header = decode_header(token)
+            .map_err(|e| JwtError::decoding(format!("Invalid token header: {}", e)))?;
+
+        match header.kid {
+            Some(kid) => self.verification_keys
+                .get(&kid)
+                .map(|key| vec![key])
+                .ok_or_else(|| JwtError::signature_invalid("Unknown key id")),
+            None => Ok(std::iter::once(&self.decoding_key)
+                .chain(self.secondary_decoding_key.as_ref())
+                .collect()),
+        }
+    }
+
+    /// Build claims whose temporal bounds span `ttl_secs` from now.
+    fn claims_with_ttl(&self, sub: String, ttl_secs: u64, token_type: &str) -> Result<TokenClaims, TokenError> {
+        let ttl = chrono::Duration::try_seconds(i64::try_from(ttl_secs).unwrap_or(i64::MAX))
+            .ok_or_else(|| TokenError::invalid_claims("token TTL is out of range"))?;
+        let lifetime = TokenLifetime::from_ttl(chrono::Utc::now(), ttl)?;
+
+        TokenClaims::from_lifetime(sub, &lifetime, token_type.to_string())
+    }
+
+    /// Check decoded temporal bounds against the core lifetime rules.
+    fn check_lifetime(&self, lifetime: &TokenLifetime) -> Result<(), JwtError> {
+        let leeway = chrono::Duration::try_seconds(i64::try_from(self.leeway_seconds).unwrap_or(i64::MAX))
+            .unwrap_or(chrono::Duration::MAX);
 
-        if let Some(ref audience) = self.audience {
-            validation.set_audience(&[audience.clone()]);
+        lifetime
+            .validate_with_leeway(chrono::Utc::now(), leeway)
+            .map_err(|failure| match failure {
+                TokenValidationFailure::Expired { .. } => JwtError::expired("Token has expired"),
+                _ => JwtError::invalid_token("Token is not yet valid"),
+            })
+    }
+
+    /// Create a validation configuration for decoding tokens.
+    ///
+    /// Expiry and not-before are left to [`TokenLifetime::validate`], so
+    /// jsonwebtoken only verifies the signature and, per `rules`, the
+    /// issuer and audience.
+    fn create_validation(&self, rules: Option<&TokenValidationRules>) -> Validation {
+        let mut validation = Validation::new(self.algorithm);
+        // Pin the accepted algorithms explicitly; never widen this list
+        validation.algorithms = vec![self.algorithm];
+        validation.validate_exp = false;
+        validation.validate_nbf = false;
+        validation.leeway = self.leeway_seconds;
+
+        if let Some(rules) = rules {
+            rules.apply(&mut validation);
         }
 
         validation
@@ -95,9 +513,18 @@ impl HmacTokenService {
 
     /// Encode TokenClaims into a JWT token.
     pub fn encode_token(&self, claims: &TokenClaims) -> Result<String, JwtError> {
+        self.encode_token_with_extra(claims, &Map::new())
+    }
+
+    /// Encode TokenClaims together with `extra` claims into a JWT token.
+    ///
+    /// `extra` must not contain reserved claims; see [`Self::enrich`].
+    fn encode_token_with_extra(&self, claims: &TokenClaims, extra: &Map<String, Value>) -> Result<String, JwtError> {
         // Create a serialization struct that matches JWT format
         #[derive(Serialize)]
         struct JwtClaims<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            iss: Option<&'a str>,
             sub: &'a str,
             sid: Option<&'a str>,
             aud: Option<Vec<&'a str>>,
@@ -107,11 +534,20 @@ impl HmacTokenService {
             scope: Option<Vec<&'a str>>,
             #[serde(rename = "token_type")]
             token_type: &'a str,
+            jti: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            workspace_id: Option<&'a str>,
+            #[serde(flatten)]
+            extra: &'a Map<String, Value>,
         }
 
-        let audience = claims.aud.as_ref().map(|aud| {
-            aud.iter().map(|s| s.as_str()).collect::<Vec<&str>>()
-        });
+        // Stamp the issuer and, unless the claims name one, the audience
+        // configured for this token type
+        let rules = self.rules_for(&claims.token_type);
+        let audience = match &claims.aud {
+            Some(aud) => Some(aud.iter().map(|s| s.as_str()).collect::<Vec<&str>>()),
+            None => rules.and_then(|rules| rules.audience()).map(|aud| vec![aud]),
+        };
 
         let scope = if claims.scope.is_empty() {
             None
@@ -119,7 +555,11 @@ impl HmacTokenService {
             Some(claims.scope.iter().map(|s| s.as_str()).collect::<Vec<&str>>())
         };
 
+        // Every issued token is identified; single-use tokens bring their own id
+        let jti = claims.jti.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
         let jwt_claims = JwtClaims {
+            iss: rules.and_then(|rules| rules.issuer()),
             sub: &claims.sub,
             sid: claims.sid.as_deref(),
             aud: audience,
@@ -128,17 +568,33 @@ impl HmacTokenService {
             nbf: claims.nbf,
             scope,
             token_type: &claims.token_type,
+            jti: &jti,
+            workspace_id: claims.workspace_id.as_deref(),
+            extra,
         };
 
-        let header = Header::new(self.algorithm);
+        let mut header = self.header_for(&claims.token_type);
+        header.kid = self.key_id.clone();
 
         encode(&header, &jwt_claims, &self.encoding_key)
             .map_err(|e| JwtError::encoding(format!("Token encoding failed: {}", e)))
     }
 
-    /// Decode and validate a JWT token.
-    fn decode_token(&self, token: &str) -> Result<TokenClaims, JwtError> {
-        let validation = self.create_validation();
+    /// Decode and validate a JWT token against `rules`.
+    fn decode_token(&self, token: &str, rules: Option<&TokenValidationRules>) -> Result<TokenClaims, JwtError> {
+        self.decode_token_with_extra(token, rules).map(|(claims, _)| claims)
+    }
+
+    /// Decode and validate a JWT token against `rules`, keeping the
+    /// non-reserved claims that `TokenClaims` has no field for.
+    fn decode_token_with_extra(
+        &self,
+        token: &str,
+        rules: Option<&TokenValidationRules>,
+    ) -> Result<(TokenClaims, Map<String, Value>), JwtError> {
+        self.check_token_size(token)?;
+        self.check_header_algorithm(token)?;
+        let validation = self.create_validation(rules);
 
         // First decode to get raw claims, then map to our struct
         #[derive(Deserialize)]
@@ -153,9 +609,29 @@ impl HmacTokenService {
             scope: Option<Vec<String>>,
             #[serde(rename = "token_type")]
             token_type: String,
+            #[serde(default)]
+            jti: Option<String>,
+            #[serde(default)]
+            workspace_id: Option<String>,
+            #[serde(flatten)]
+            extra: Map<String, Value>,
         }
 
-        let token_data = decode::<RawJwtClaims>(token, &self.decoding_key, &validation)
+        let decoding_keys = self.select_decoding_keys(token)?;
+
+        // Only a signature mismatch moves on to the next key; any other
+        // failure (expiry, audience, ...) is final
+        let mut result = decode::<RawJwtClaims>(token, decoding_keys[0], &validation);
+        for decoding_key in &decoding_keys[1..] {
+            match &result {
+                Err(e) if matches!(e.kind(), jsonwebtoken::errors::ErrorKind::InvalidSignature) => {
+                    result = decode::<RawJwtClaims>(token, decoding_key, &validation);
+                }
+                _ => break,
+            }
+        }
+
+        let token_data = result
             .map_err(|e| match e.kind() {
                 jsonwebtoken::errors::ErrorKind::ExpiredSignature => {
                     JwtError::expired("Token has expired")
@@ -177,10 +653,13 @@ impl HmacTokenService {
 
         let raw = token_data.claims;
 
+        self.check_header_typ(token, &raw.token_type)?;
+        self.check_lifetime(&TokenLifetime::from_timestamps(raw.iat, raw.exp, raw.nbf))?;
+
         // Scope is now an array
         let scope = raw.scope.unwrap_or_default();
 
-        Ok(TokenClaims {
+        let claims = TokenClaims {
             sub: raw.sub,
             sid: raw.session_id,
             aud: raw.aud,
@@ -189,12 +668,61 @@ impl HmacTokenService {
             nbf: raw.nbf,
             scope,
             token_type: raw.token_type,
-        })
+            jti: raw.jti,
+            workspace_id: raw.workspace_id,
+        };
+
+        Ok((claims, Self::without_reserved(raw.extra)))
+    }
+
+    /// Claims the configured enricher adds for `identity`, minus reserved ones.
+    fn enrich(&self, identity: &IdentityClaims) -> Map<String, Value> {
+        match self.claim_enricher {
+            Some(enricher) => Self::without_reserved(enricher(identity)),
+            None => Map::new(),
+        }
+    }
+
+    fn without_reserved(mut claims: Map<String, Value>) -> Map<String, Value> {
+        claims.retain(|name, _| !RESERVED_CLAIMS.contains(&name.as_str()));
+        claims
+    }
+
+    /// Issue a single-use token of `token_type`, carrying the caller's `jti`.
+    fn issue_single_use_token(&self, claims: &str, token_type: &str, default_ttl: chrono::Duration) -> Token {
+        if self.check_claims_size(claims).is_err() {
+            return Token::new("");
+        }
+
+        match self.encode_token(&single_use::issue_claims(claims, token_type, default_ttl)) {
+            Ok(token_value) => Token::new(token_value),
+            Err(_) => Token::new(""),
+        }
+    }
+
+    /// Validate a single-use token, rejecting any other token type.
+    fn validate_single_use_token(&self, token: &Token, token_type: &str) -> Result<String, ()> {
+        let token_str = token.value();
+
+        if token_str.is_empty() {
+            return Err(());
+        }
+
+        self.decode_token(token_str, None)
+            .ok()
+            .and_then(|claims| single_use::validated_claims(claims, token_type))
+            .ok_or(())
     }
 }
 
-impl TokenService for HmacTokenService {
-    fn issue_access_token(&self, _subject: &str, claims: &str) -> Token {
+/// Token operations. Signing and verifying are CPU-bound, so these run
+/// synchronously; the `TokenService` impl answers with their results.
+impl HmacTokenService {
+    pub(crate) fn issue_access_token(&self, _subject: &str, claims: &str) -> Token {
+        if self.check_claims_size(claims).is_err() {
+            return Token::new("");
+        }
+
         // Parse the claims JSON to extract identity information
         // The claims JSON has format: {"sub":"user_id","type":"access","exp":123456,"sid":"session_id"}
         let claims_json: serde_json::Value = serde_json::from_str(claims).unwrap_or_default();
@@ -206,26 +734,47 @@ impl TokenService for HmacTokenService {
         
         let session_id = claims_json.get("sid")
             .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
             .map(|s| s.to_string());
 
-        let now = chrono::Utc::now();
-        let expires = now + chrono::Duration::hours(1); // 1 hour for access tokens
+        let workspace_id = claims_json.get("workspace_id")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string());
 
-        let token_claims = TokenClaims::new(
-            user_id,
-            now.timestamp(),
-            expires.timestamp(),
-            "access".to_string(),
-        )
-        .with_sid(session_id.unwrap_or_default());
+        let mut token_claims = match self.claims_with_ttl(user_id, self.token_policy.access_ttl(), "access") {
+            Ok(token_claims) => token_claims,
+            Err(_) => return Token::new(""),
+        };
+        if let Some(sid) = session_id {
+            token_claims = token_claims.with_sid(sid);
+        }
+        if let Some(workspace_id) = workspace_id {
+            token_claims = token_claims.with_workspace_id(workspace_id);
+        }
 
-        match self.encode_token(&token_claims) {
+        // Carry the application claims (roles, ...) the caller assembled;
+        // the enricher has the last word on any claim both supply
+        let mut extra = match claims_json {
+            Value::Object(claims) => Self::without_reserved(claims),
+            _ => Map::new(),
+        };
+        extra.extend(self.enrich(&IdentityClaims {
+            user_id: Some(token_claims.sub.clone()).filter(|s| !s.is_empty()),
+            workspace_id: token_claims.workspace_id.clone(),
+        }));
+
+        match self.encode_token_with_extra(&token_claims, &extra) {
             Ok(token_value) => Token::new(token_value),
             Err(_) => Token::new(""),
         }
     }
 
-    fn issue_refresh_token(&self, _subject: &str, claims: &str) -> Token {
+    pub(crate) fn issue_refresh_token(&self, _subject: &str, claims: &str) -> Token {
+        if self.check_claims_size(claims).is_err() {
+            return Token::new("");
+        }
+
         // Parse the claims JSON to extract identity information
         let claims_json: serde_json::Value = serde_json::from_str(claims).unwrap_or_default();
         
@@ -236,18 +785,16 @@ impl TokenService for HmacTokenService {
         
         let session_id = claims_json.get("sid")
             .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
             .map(|s| s.to_string());
 
-        let now = chrono::Utc::now();
-        let expires = now + chrono::Duration::days(7); // 7 days for refresh tokens
-
-        let token_claims = TokenClaims::new(
-            user_id,
-            now.timestamp(),
-            expires.timestamp(),
-            "refresh".to_string(),
-        )
-        .with_sid(session_id.unwrap_or_default());
+        let mut token_claims = match self.claims_with_ttl(user_id, self.token_policy.refresh_ttl(), "refresh") {
+            Ok(token_claims) => token_claims,
+            Err(_) => return Token::new(""),
+        };
+        if let Some(sid) = session_id {
+            token_claims = token_claims.with_sid(sid);
+        }
 
         match self.encode_token(&token_claims) {
             Ok(token_value) => Token::new(token_value),
@@ -255,17 +802,22 @@ impl TokenService for HmacTokenService {
         }
     }
 
-    fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
+    pub(crate) fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
         let token_str = token.value();
         
         if token_str.is_empty() {
             return Err(());
         }
 
-        match self.decode_token(token_str) {
-            Ok(claims) => {
-                // Build claims JSON for return
-                let mut claims_map = serde_json::Map::new();
+        match self.decode_token_with_extra(token_str, Some(&self.access_rules)) {
+            Ok((claims, extra)) => {
+                // Refuse refresh and other tokens presented as bearer access tokens
+                if claims.token_type != "access" {
+                    return Err(());
+                }
+
+                // Build claims JSON for return, starting from the enriched claims
+                let mut claims_map = extra;
                 
                 claims_map.insert("sub".to_string(), serde_json::Value::String(claims.sub));
                 claims_map.insert("type".to_string(), serde_json::Value::String(claims.token_type));
@@ -276,6 +828,14 @@ impl TokenService for HmacTokenService {
                     claims_map.insert("sid".to_string(), serde_json::Value::String(sid));
                 }
                 
+                if let Some(workspace_id) = claims.workspace_id {
+                    claims_map.insert("workspace_id".to_string(), serde_json::Value::String(workspace_id));
+                }
+
+                if let Some(jti) = claims.jti {
+                    claims_map.insert("jti".to_string(), serde_json::Value::String(jti));
+                }
+                
                 if let Some(aud) = claims.aud {
                     claims_map.insert("aud".to_string(), serde_json::Value::Array(
                         aud.into_iter().map(serde_json::Value::String).collect()
@@ -295,14 +855,14 @@ impl TokenService for HmacTokenService {
         }
     }
 
-    fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
+    pub(crate) fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
         let token_str = token.value();
         
         if token_str.is_empty() {
             return Err(());
         }
 
-        match self.decode_token(token_str) {
+        match self.decode_token(token_str, Some(&self.refresh_rules)) {
             Ok(claims) => {
                 // Validate that this is actually a refresh token
                 if claims.token_type != "refresh" {
@@ -320,6 +880,10 @@ impl TokenService for HmacTokenService {
                 if let Some(sid) = claims.sid {
                     claims_map.insert("sid".to_string(), serde_json::Value::String(sid));
                 }
+
+                if let Some(jti) = claims.jti {
+                    claims_map.insert("jti".to_string(), serde_json::Value::String(jti));
+                }
                 
                 let claims_json = serde_json::to_string(&claims_map).unwrap_or_default();
                 Ok(claims_json)
@@ -328,7 +892,11 @@ impl TokenService for HmacTokenService {
         }
     }
 
-    fn issue_service_token(&self, subject: &str, claims: &str) -> Token {
+    pub(crate) fn issue_service_token(&self, subject: &str, claims: &str) -> Token {
+        if self.check_claims_size(claims).is_err() {
+            return Token::new("");
+        }
+
         // Use service token key if configured, otherwise fall back to main key
         let encoding_key = self.service_encoding_key.as_ref()
             .unwrap_or(&self.encoding_key);
@@ -341,15 +909,10 @@ impl TokenService for HmacTokenService {
             .unwrap_or(subject)
             .to_string();
 
-        let now = chrono::Utc::now();
-        let expires = now + chrono::Duration::hours(1);
-
-        let mut token_claims = TokenClaims::new(
-            service_id,
-            now.timestamp(),
-            expires.timestamp(),
-            "service".to_string(),
-        );
+        let mut token_claims = match self.claims_with_ttl(service_id, SERVICE_TOKEN_TTL_SECS, "service") {
+            Ok(token_claims) => token_claims,
+            Err(_) => return Token::new(""),
+        };
 
         // Add audience if provided
         if let Some(aud_value) = claims_json.get("aud") {
@@ -370,6 +933,7 @@ impl TokenService for HmacTokenService {
             scope: Option<Vec<&'a str>>,
             #[serde(rename = "token_type")]
             token_type: &'a str,
+            jti: String,
         }
 
         let audience = token_claims.aud.as_ref().map(|aud| {
@@ -388,9 +952,10 @@ impl TokenService for HmacTokenService {
             nbf: token_claims.nbf,
             scope: None,
             token_type: &token_claims.token_type,
+            jti: uuid::Uuid::new_v4().to_string(),
         };
 
-        let header = Header::new(self.algorithm);
+        let header = self.header_for(&token_claims.token_type);
         
         match encode(&header, &jwt_claims, encoding_key) {
             Ok(token_value) => Token::new(token_value),
@@ -398,18 +963,22 @@ impl TokenService for HmacTokenService {
         }
     }
 
-    fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
+    pub(crate) fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
         let token_str = token.value();
         
         if token_str.is_empty() {
             return Err(());
         }
 
+        if self.check_token_size(token_str).is_err() || self.check_header_algorithm(token_str).is_err() {
+            return Err(());
+        }
+
         // Use service token key if configured, otherwise fall back to main key
         let decoding_key = self.service_decoding_key.as_ref()
             .unwrap_or(&self.decoding_key);
         
-        let mut validation = self.create_validation();
+        let mut validation = self.create_validation(None);
         // Don't validate audience/issuer for service tokens by default to allow flexibility
         validation.validate_aud = false;
 
@@ -425,6 +994,8 @@ impl TokenService for HmacTokenService {
             _scope: Option<String>,
             #[serde(rename = "token_type")]
             token_type: String,
+            #[serde(default)]
+            jti: Option<String>,
         }
 
         match decode::<RawJwtClaims>(token_str, decoding_key, &validation) {
@@ -435,6 +1006,14 @@ impl TokenService for HmacTokenService {
                 if claims.token_type != "service" {
                     return Err(());
                 }
+
+                if self.check_header_typ(token_str, &claims.token_type).is_err() {
+                    return Err(());
+                }
+
+                if self.check_lifetime(&TokenLifetime::from_timestamps(claims.iat, claims.exp, None)).is_err() {
+                    return Err(());
+                }
                 
                 // Build claims JSON for return
                 let mut claims_map = serde_json::Map::new();
@@ -443,6 +1022,9 @@ impl TokenService for HmacTokenService {
                 claims_map.insert("type".to_string(), serde_json::Value::String("service".to_string()));
                 claims_map.insert("exp".to_string(), serde_json::Value::Number(claims.exp.into()));
                 claims_map.insert("iat".to_string(), serde_json::Value::Number(claims.iat.into()));
+                if let Some(jti) = claims.jti {
+                    claims_map.insert("jti".to_string(), serde_json::Value::String(jti));
+                }
                 
                 let claims_json = serde_json::to_string(&claims_map).unwrap_or_default();
                 Ok(claims_json)
@@ -450,4 +1032,66 @@ impl TokenService for HmacTokenService {
             Err(_) => Err(()),
         }
     }
+
+    pub(crate) fn issue_reset_token(&self, _subject: &str, claims: &str) -> Token {
+        self.issue_single_use_token(claims, "reset", chrono::Duration::minutes(RESET_TOKEN_TTL_MINUTES))
+    }
+
+    pub(crate) fn validate_reset_token(&self, token: &Token) -> Result<String, ()> {
+        self.validate_single_use_token(token, "reset")
+    }
+
+    pub(crate) fn issue_verification_token(&self, _subject: &str, claims: &str) -> Token {
+        self.issue_single_use_token(claims, "verify", chrono::Duration::minutes(VERIFICATION_TOKEN_TTL_MINUTES))
+    }
+
+    pub(crate) fn validate_verification_token(&self, token: &Token) -> Result<String, ()> {
+        self.validate_single_use_token(token, "verify")
+    }
+}
+
+impl TokenService for HmacTokenService {
+    fn issue_access_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(HmacTokenService::issue_access_token(self, subject, claims)).boxed()
+    }
+
+    fn issue_refresh_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(HmacTokenService::issue_refresh_token(self, subject, claims)).boxed()
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(HmacTokenService::validate_access_token(self, token)).boxed()
+    }
+
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(HmacTokenService::validate_refresh_token(self, token)).boxed()
+    }
+
+    fn issue_service_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(HmacTokenService::issue_service_token(self, subject, claims)).boxed()
+    }
+
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(HmacTokenService::validate_service_token(self, token)).boxed()
+    }
+
+    fn issue_reset_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(HmacTokenService::issue_reset_token(self, subject, claims)).boxed()
+    }
+
+    fn validate_reset_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(HmacTokenService::validate_reset_token(self, token)).boxed()
+    }
+
+    fn issue_verification_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(HmacTokenService::issue_verification_token(self, subject, claims)).boxed()
+    }
+
+    fn validate_verification_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(HmacTokenService::validate_verification_token(self, token)).boxed()
+    }
+
+    fn supported_algorithms(&self) -> &[&str] {
+        &["HS256"]
+    }
 }
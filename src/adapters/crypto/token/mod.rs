@@ -1,15 +1,21 @@
-//! JWT token signing and verification using EdDSA (Ed25519) and HMAC-SHA256.
+//! Token signing and verification: JWT (EdDSA, HMAC-SHA256) and PASETO v4.
 //!
 //! This module provides JWT token operations using two algorithms:
 //! - Ed25519-EdDSA: Asymmetric cryptographic signature scheme (recommended)
 //! - HMAC-SHA256: Symmetric key-based message authentication (legacy)
 //!
+//! PASETO v4 tokens (`v4.local` or `v4.public`) are an alternative for
+//! deployments that want no algorithm negotiation at all, and opaque
+//! reference tokens for those that want every token revocable.
+//!
 //! # Components
 //!
 //! - [`EddsaTokenService`]: JWT token issuance and validation using Ed25519-EdDSA
 //! - [`EddsaKey`]: Ed25519 key generation and management
 //! - [`HmacTokenService`]: JWT token issuance and validation using HMAC-SHA256
 //! - [`HmacKey`]: HMAC-SHA256 symmetric key generation and management
+//! - [`PasetoTokenService`]: PASETO v4 token issuance and validation
+//! - [`OpaqueTokenService`]: Opaque reference tokens backed by an `OpaqueTokenStore`
 //!
 //! # Example
 //!
@@ -34,9 +40,12 @@ pub mod eddsa_keys;
 pub mod eddsa_token_service;
 pub mod hmac_keys;
 pub mod hmac_token_service;
+pub mod paseto_token_service;
+pub mod opaque_token_service;
 pub mod google_validator_config;
 pub mod jwks_provider;
 pub mod google_rs256_validator;
+mod single_use;
 
 pub use google_validator_config::GoogleValidatorConfig;
 pub use google_rs256_validator::GoogleRs256Validator;
@@ -45,7 +54,9 @@ pub use jwks_provider::JwksProvider;
 pub use eddsa_keys::{EddsaKey, ED25519_KEY_SIZE};
 pub use eddsa_token_service::EddsaTokenService;
 pub use hmac_keys::{HmacKey, HMAC_KEY_SIZE};
-pub use hmac_token_service::HmacTokenService;
+pub use hmac_token_service::{ClaimEnricher, HmacTokenService, TokenValidationRules};
+pub use paseto_token_service::{PasetoTokenService, PASETO_LOCAL_KEY_SIZE};
+pub use opaque_token_service::OpaqueTokenService;
 
 #[cfg(test)]
 mod tests;
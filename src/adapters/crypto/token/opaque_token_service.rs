@@ -0,0 +1,258 @@
+//! Opaque reference token service implementation.
+//!
+//! This module provides a concrete implementation of the `TokenService` port
+//! that issues random, non-self-describing tokens instead of JWTs.
+//!
+//! # Design Principles
+//!
+//! - **Nothing in the token**: A token is 32 random bytes from the system
+//!   CSPRNG, base64url-encoded; its claims and expiry live in an
+//!   `OpaqueTokenStore`
+//! - **Hashes only**: The store is keyed by the SHA-256 of the token, so a
+//!   leaked store yields no usable tokens
+//! - **Timing-safe lookup**: Lookups go by hash, which a caller cannot steer
+//!   byte by byte, and the stored hash is then compared in constant time
+//! - **Revocable**: Deleting the record revokes the token immediately
+//! - **Same claim set**: Validated claims look the same as with the JWT
+//!   services (`sub`, `type`, `iat`, `exp`, `jti` plus custom claims)
+
+use std::fmt;
+use std::sync::Arc;
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::Utc;
+use futures::future::{BoxFuture, FutureExt};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::core::token::Token;
+use crate::core::usecases::policies::TokenPolicy;
+use crate::core::usecases::ports::{OpaqueTokenRecord, OpaqueTokenStore, TokenService};
+
+/// Random bytes per token (256 bits).
+const TOKEN_BYTES: usize = 32;
+
+/// Default access token TTL (1 hour).
+const DEFAULT_ACCESS_TTL_SECS: u64 = 3600;
+/// Default refresh token TTL (7 days).
+const DEFAULT_REFRESH_TTL_SECS: u64 = 7 * 24 * 3600;
+/// Service token TTL (1 hour).
+const SERVICE_TOKEN_TTL_SECS: u64 = 3600;
+/// Reset token TTL when the caller does not request an expiry (15 minutes).
+const RESET_TOKEN_TTL_SECS: u64 = 15 * 60;
+/// Verification token TTL when the caller does not request an expiry (1 hour).
+const VERIFICATION_TOKEN_TTL_SECS: u64 = 60 * 60;
+
+/// Claims set by the service itself; caller-supplied values are replaced.
+const RESERVED_CLAIMS: [&str; 5] = ["sub", "type", "iat", "exp", "nbf"];
+
+/// Opaque reference token service implementation.
+///
+/// This service issues and validates opaque tokens backed by an
+/// `OpaqueTokenStore`. It implements the `TokenService` port from the core
+/// domain, so callers cannot tell it apart from the JWT services.
+pub struct OpaqueTokenService {
+    store: Arc<dyn OpaqueTokenStore + Send + Sync>,
+    token_policy: TokenPolicy,
+    rng: SystemRandom,
+}
+
+impl OpaqueTokenService {
+    /// Create a service keeping its tokens in `store`.
+    pub fn new(store: Arc<dyn OpaqueTokenStore + Send + Sync>) -> Self {
+        Self {
+            store,
+            token_policy: TokenPolicy::new(DEFAULT_ACCESS_TTL_SECS, DEFAULT_REFRESH_TTL_SECS, true),
+            rng: SystemRandom::new(),
+        }
+    }
+
+    /// Set the token policy whose TTLs drive access and refresh token expiry.
+    pub fn with_token_policy(mut self, policy: TokenPolicy) -> Self {
+        self.token_policy = policy;
+        self
+    }
+
+    /// Revoke a token of any type by deleting its record.
+    ///
+    /// Returns whether the token was known.
+    pub async fn revoke(&self, token: &Token) -> bool {
+        if token.is_empty() {
+            return false;
+        }
+        self.store.remove(&hash_token(token.value())).await
+    }
+
+    /// Build the claims stored for a token from the caller's claims.
+    ///
+    /// Custom claims are kept as-is. The subject comes from the claims,
+    /// falling back to `subject`. An `exp` in the claims may shorten
+    /// `ttl_secs` but never extend it, as with the JWT services, except on
+    /// single-use tokens, whose requested expiry is honoured.
+    fn build_claims(subject: &str, claims: &str, token_type: &str, ttl_secs: u64) -> Map<String, Value> {
+        let mut source = match serde_json::from_str::<Value>(claims) {
+            Ok(Value::Object(map)) => map,
+            _ => Map::new(),
+        };
+
+        let sub = source
+            .get("sub")
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())
+            .unwrap_or(subject)
+            .to_string();
+
+        let now = Utc::now().timestamp();
+        let single_use = matches!(token_type, "reset" | "verify");
+        let ttl_exp = now.saturating_add(i64::try_from(ttl_secs).unwrap_or(i64::MAX));
+        let exp = match source.get("exp").and_then(Value::as_i64) {
+            Some(exp) if single_use => exp,
+            Some(exp) => exp.min(ttl_exp),
+            None => ttl_exp,
+        };
+
+        for claim in RESERVED_CLAIMS {
+            source.remove(claim);
+        }
+
+        source.insert("sub".to_string(), Value::String(sub));
+        source.insert("type".to_string(), Value::String(token_type.to_string()));
+        source.insert("iat".to_string(), Value::from(now));
+        source.insert("exp".to_string(), Value::from(exp));
+        // Every issued token is identified; single-use tokens bring their own id
+        if !single_use || !source.contains_key("jti") {
+            source.insert("jti".to_string(), Value::String(uuid::Uuid::new_v4().to_string()));
+        }
+
+        source
+    }
+
+    fn issue(&self, subject: &str, claims: &str, token_type: &'static str, ttl_secs: u64) -> BoxFuture<'_, Token> {
+        let mut bytes = [0u8; TOKEN_BYTES];
+        if self.rng.fill(&mut bytes).is_err() {
+            tracing::error!("[OPAQUE_TOKEN] System random source failed");
+            return async { Token::new("") }.boxed();
+        }
+        let token_value = URL_SAFE_NO_PAD.encode(bytes);
+
+        let claims = Self::build_claims(subject, claims, token_type, ttl_secs);
+        let expires_at = claims.get("exp").and_then(Value::as_i64).unwrap_or_default();
+        let Ok(claims) = serde_json::to_string(&claims) else {
+            return async { Token::new("") }.boxed();
+        };
+
+        let record = OpaqueTokenRecord {
+            token_hash: hash_token(&token_value),
+            token_type: token_type.to_string(),
+            claims,
+            expires_at,
+        };
+
+        async move {
+            match self.store.put(record).await {
+                Ok(()) => Token::new(token_value),
+                Err(e) => {
+                    tracing::error!("[OPAQUE_TOKEN] Failed to store {} token: {:?}", token_type, e);
+                    Token::new("")
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn validate(&self, token: &Token, expected_type: &'static str) -> BoxFuture<'_, Result<String, ()>> {
+        if token.is_empty() {
+            return async { Err(()) }.boxed();
+        }
+
+        let token_hash = hash_token(token.value());
+        async move { self.check(&token_hash, expected_type).await }.boxed()
+    }
+
+    async fn check(&self, token_hash: &str, expected_type: &str) -> Result<String, ()> {
+        let record = self.store.get(token_hash).await.ok_or(())?;
+
+        if !constant_time_eq(record.token_hash.as_bytes(), token_hash.as_bytes()) {
+            return Err(());
+        }
+        if record.token_type != expected_type {
+            return Err(());
+        }
+        if record.expires_at <= Utc::now().timestamp() {
+            return Err(());
+        }
+
+        Ok(record.claims)
+    }
+}
+
+impl fmt::Debug for OpaqueTokenService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OpaqueTokenService")
+            .field("access_ttl", &self.token_policy.access_ttl())
+            .field("refresh_ttl", &self.token_policy.refresh_ttl())
+            .finish_non_exhaustive()
+    }
+}
+
+/// SHA-256 of a token value, hex-encoded, as used for store keys.
+fn hash_token(value: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(value.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Compare two byte strings without an early exit on the first difference.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
+
+impl TokenService for OpaqueTokenService {
+    fn issue_access_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        self.issue(subject, claims, "access", self.token_policy.access_ttl())
+    }
+
+    fn issue_refresh_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        self.issue(subject, claims, "refresh", self.token_policy.refresh_ttl())
+    }
+
+    fn issue_service_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        self.issue(subject, claims, "service", SERVICE_TOKEN_TTL_SECS)
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        self.validate(token, "access")
+    }
+
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        self.validate(token, "refresh")
+    }
+
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        self.validate(token, "service")
+    }
+
+    fn issue_reset_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        self.issue(subject, claims, "reset", RESET_TOKEN_TTL_SECS)
+    }
+
+    fn validate_reset_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        self.validate(token, "reset")
+    }
+
+    fn issue_verification_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        self.issue(subject, claims, "verify", VERIFICATION_TOKEN_TTL_SECS)
+    }
+
+    fn validate_verification_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        self.validate(token, "verify")
+    }
+}
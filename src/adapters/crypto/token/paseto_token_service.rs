@@ -0,0 +1,430 @@
+//! PASETO v4 token service implementation.
+//!
+//! This module provides a concrete implementation of the `TokenService` port
+//! using PASETO version 4 tokens via the pasetors library, as an alternative
+//! to the JWT services.
+//!
+//! # Design Principles
+//!
+//! - **No algorithm negotiation**: The version and purpose are fixed by the
+//!   key (`v4.local` or `v4.public`), so there is no header to tamper with and
+//!   no `alg: none` downgrade
+//! - **Same claim set**: Tokens carry `sub`, `type`, `iat`, `exp` and every
+//!   custom claim supplied by the caller, including `scope`
+//! - **Spec-compliant timestamps**: `iat`, `exp` and `nbf` are RFC 3339
+//!   strings inside the token and are returned as Unix timestamps, so
+//!   validated claims look the same as with the JWT services
+//! - **Core lifetime rules**: Expiry is decided by the core `TokenLifetime`
+//! - **No secret leakage**: Keys are never logged or exposed in errors
+
+use std::fmt;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures::future::{ready, BoxFuture, FutureExt};
+use pasetors::keys::{AsymmetricPublicKey, AsymmetricSecretKey, SymmetricKey};
+use pasetors::token::UntrustedToken;
+use pasetors::version4::{LocalToken, PublicToken, V4};
+use serde_json::{Map, Value};
+
+use crate::adapters::crypto::error::JwtError;
+use crate::core::token::{Token, TokenLifetime, TokenValidationFailure};
+use crate::core::usecases::policies::TokenPolicy;
+use crate::core::usecases::ports::TokenService;
+
+/// Size of a `v4.local` symmetric key in bytes.
+pub const PASETO_LOCAL_KEY_SIZE: usize = 32;
+
+/// Default access token TTL (1 hour).
+const DEFAULT_ACCESS_TTL_SECS: u64 = 3600;
+/// Default refresh token TTL (7 days).
+const DEFAULT_REFRESH_TTL_SECS: u64 = 7 * 24 * 3600;
+/// Service token TTL (1 hour).
+const SERVICE_TOKEN_TTL_SECS: u64 = 3600;
+/// Reset token TTL when the caller does not request an expiry (15 minutes).
+const RESET_TOKEN_TTL_SECS: u64 = 15 * 60;
+/// Verification token TTL when the caller does not request an expiry (1 hour).
+const VERIFICATION_TOKEN_TTL_SECS: u64 = 60 * 60;
+
+/// Claims set by the service itself; caller-supplied values are replaced.
+const RESERVED_CLAIMS: [&str; 5] = ["sub", "type", "iat", "exp", "nbf"];
+
+/// Key material for one PASETO purpose.
+enum PasetoKey {
+    /// `v4.local`: XChaCha20 encryption with a BLAKE2b MAC
+    Local(SymmetricKey<V4>),
+    /// `v4.public`: Ed25519 signatures
+    Public {
+        secret: AsymmetricSecretKey<V4>,
+        public: AsymmetricPublicKey<V4>,
+    },
+}
+
+impl PasetoKey {
+    fn local(key: &[u8]) -> Result<Self, JwtError> {
+        if key.len() != PASETO_LOCAL_KEY_SIZE {
+            return Err(JwtError::invalid_key(format!(
+                "v4.local keys must be {} bytes",
+                PASETO_LOCAL_KEY_SIZE
+            )));
+        }
+
+        SymmetricKey::<V4>::from(key)
+            .map(Self::Local)
+            .map_err(|_| JwtError::invalid_key("Invalid v4.local key"))
+    }
+
+    fn public(private_key: &[u8]) -> Result<Self, JwtError> {
+        let seed: [u8; 32] = private_key
+            .try_into()
+            .map_err(|_| JwtError::invalid_key("Ed25519 private keys must be 32 bytes"))?;
+        let public_bytes = ed25519_dalek::SigningKey::from_bytes(&seed)
+            .verifying_key()
+            .to_bytes();
+
+        // pasetors expects the secret key as seed || public key
+        let mut keypair = [0u8; 64];
+        keypair[..32].copy_from_slice(&seed);
+        keypair[32..].copy_from_slice(&public_bytes);
+
+        let secret = AsymmetricSecretKey::<V4>::from(&keypair)
+            .map_err(|_| JwtError::invalid_key("Invalid v4.public secret key"))?;
+        let public = AsymmetricPublicKey::<V4>::from(&public_bytes)
+            .map_err(|_| JwtError::invalid_key("Invalid v4.public public key"))?;
+
+        Ok(Self::Public { secret, public })
+    }
+
+    fn purpose(&self) -> &'static str {
+        match self {
+            Self::Local(_) => "v4.local",
+            Self::Public { .. } => "v4.public",
+        }
+    }
+
+    /// Encrypt or sign a payload.
+    fn seal(&self, payload: &[u8]) -> Result<String, JwtError> {
+        match self {
+            Self::Local(key) => LocalToken::encrypt(key, payload, None, None),
+            Self::Public { secret, .. } => PublicToken::sign(secret, payload, None, None),
+        }
+        .map_err(|_| JwtError::encoding("PASETO token encoding failed"))
+    }
+
+    /// Decrypt or verify a token and return its payload.
+    ///
+    /// A token of another version or purpose fails to parse, so a `v4.local`
+    /// service never accepts a `v4.public` token and vice versa.
+    fn open(&self, token: &str) -> Result<String, JwtError> {
+        let trusted = match self {
+            Self::Local(key) => {
+                let untrusted = UntrustedToken::<pasetors::Local, V4>::try_from(token)
+                    .map_err(|_| JwtError::decoding("Malformed v4.local token"))?;
+                LocalToken::decrypt(key, &untrusted, None, None)
+            }
+            Self::Public { public, .. } => {
+                let untrusted = UntrustedToken::<pasetors::Public, V4>::try_from(token)
+                    .map_err(|_| JwtError::decoding("Malformed v4.public token"))?;
+                PublicToken::verify(public, &untrusted, None, None)
+            }
+        }
+        .map_err(|_| JwtError::signature_invalid("Token authentication failed"))?;
+
+        Ok(trusted.payload().to_string())
+    }
+}
+
+/// PASETO v4 token service implementation.
+///
+/// This service issues and validates `v4.local` or `v4.public` tokens.
+/// It implements the `TokenService` port from the core domain.
+pub struct PasetoTokenService {
+    key: PasetoKey,
+    service_key: Option<PasetoKey>,
+    token_policy: TokenPolicy,
+    leeway_seconds: u64,
+}
+
+impl PasetoTokenService {
+    /// Create a `v4.local` service from a 32-byte symmetric key.
+    pub fn local(key: &[u8]) -> Result<Self, JwtError> {
+        Ok(Self::with_key(PasetoKey::local(key)?))
+    }
+
+    /// Create a `v4.public` service from a 32-byte Ed25519 private key.
+    pub fn public(private_key: &[u8]) -> Result<Self, JwtError> {
+        Ok(Self::with_key(PasetoKey::public(private_key)?))
+    }
+
+    fn with_key(key: PasetoKey) -> Self {
+        Self {
+            key,
+            service_key: None,
+            token_policy: TokenPolicy::new(DEFAULT_ACCESS_TTL_SECS, DEFAULT_REFRESH_TTL_SECS, true),
+            leeway_seconds: 0,
+        }
+    }
+
+    /// Set the key for issuing and validating service-to-service tokens.
+    ///
+    /// The key has the same purpose as the main key.
+    pub fn with_service_token_key(mut self, key: &[u8]) -> Result<Self, JwtError> {
+        self.service_key = Some(match self.key {
+            PasetoKey::Local(_) => PasetoKey::local(key)?,
+            PasetoKey::Public { .. } => PasetoKey::public(key)?,
+        });
+        Ok(self)
+    }
+
+    /// Set the token policy whose TTLs drive access and refresh token expiry.
+    pub fn with_token_policy(mut self, policy: TokenPolicy) -> Self {
+        self.token_policy = policy;
+        self
+    }
+
+    /// Tolerate clock skew between issuer and validator.
+    pub fn with_leeway(mut self, leeway_seconds: u64) -> Self {
+        self.leeway_seconds = leeway_seconds;
+        self
+    }
+
+    /// The PASETO version and purpose of issued tokens (e.g. `"v4.local"`).
+    pub fn purpose(&self) -> &'static str {
+        self.key.purpose()
+    }
+
+    fn service_key(&self) -> &PasetoKey {
+        self.service_key.as_ref().unwrap_or(&self.key)
+    }
+
+    /// Build the token payload from the caller's claims.
+    ///
+    /// Custom claims are kept as-is. The subject comes from the claims,
+    /// falling back to `subject`; an `exp` in the claims overrides `ttl_secs`.
+    fn build_payload(
+        subject: &str,
+        claims: &str,
+        token_type: &str,
+        ttl_secs: u64,
+    ) -> Result<Map<String, Value>, JwtError> {
+        let mut source = match serde_json::from_str::<Value>(claims) {
+            Ok(Value::Object(map)) => map,
+            _ => Map::new(),
+        };
+
+        let sub = source
+            .get("sub")
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())
+            .unwrap_or(subject)
+            .to_string();
+
+        let now = Utc::now();
+        let exp = match source.get("exp").and_then(Value::as_i64) {
+            Some(exp) => exp,
+            None => now.timestamp().saturating_add(i64::try_from(ttl_secs).unwrap_or(i64::MAX)),
+        };
+
+        for claim in RESERVED_CLAIMS {
+            source.remove(claim);
+        }
+
+        source.insert("sub".to_string(), Value::String(sub));
+        source.insert("type".to_string(), Value::String(token_type.to_string()));
+        source.insert("iat".to_string(), Value::String(to_rfc3339(now.timestamp())?));
+        source.insert("exp".to_string(), Value::String(to_rfc3339(exp)?));
+        // Every issued token is identified; single-use tokens bring their own id
+        let single_use = matches!(token_type, "reset" | "verify");
+        if !single_use || !source.contains_key("jti") {
+            source.insert("jti".to_string(), Value::String(uuid::Uuid::new_v4().to_string()));
+        }
+
+        Ok(source)
+    }
+
+    fn issue(&self, key: &PasetoKey, subject: &str, claims: &str, token_type: &str, ttl_secs: u64) -> Token {
+        let sealed = Self::build_payload(subject, claims, token_type, ttl_secs)
+            .and_then(|payload| {
+                serde_json::to_vec(&payload).map_err(|e| JwtError::encoding(e.to_string()))
+            })
+            .and_then(|payload| key.seal(&payload));
+
+        match sealed {
+            Ok(token_value) => Token::new(token_value),
+            Err(_) => Token::new(""),
+        }
+    }
+
+    /// Open a token, check its type and lifetime, and return its claims.
+    ///
+    /// Timestamps are converted back to Unix seconds.
+    fn decode(&self, key: &PasetoKey, token: &str, expected_type: &str) -> Result<Map<String, Value>, JwtError> {
+        let payload = key.open(token)?;
+        let mut claims = match serde_json::from_str::<Value>(&payload) {
+            Ok(Value::Object(map)) => map,
+            _ => return Err(JwtError::decoding("Token payload is not a JSON object")),
+        };
+
+        if claims.get("type").and_then(Value::as_str) != Some(expected_type) {
+            return Err(JwtError::invalid_token("Unexpected token type"));
+        }
+
+        let iat = timestamp_claim(&claims, "iat")?
+            .ok_or_else(|| JwtError::invalid_token("Missing iat claim"))?;
+        let exp = timestamp_claim(&claims, "exp")?
+            .ok_or_else(|| JwtError::invalid_token("Missing exp claim"))?;
+        let nbf = timestamp_claim(&claims, "nbf")?;
+
+        self.check_lifetime(&TokenLifetime::from_timestamps(iat, exp, nbf))?;
+
+        claims.insert("iat".to_string(), Value::from(iat));
+        claims.insert("exp".to_string(), Value::from(exp));
+        if let Some(nbf) = nbf {
+            claims.insert("nbf".to_string(), Value::from(nbf));
+        }
+
+        Ok(claims)
+    }
+
+    /// Check decoded temporal bounds against the core lifetime rules.
+    fn check_lifetime(&self, lifetime: &TokenLifetime) -> Result<(), JwtError> {
+        let leeway = chrono::Duration::try_seconds(i64::try_from(self.leeway_seconds).unwrap_or(i64::MAX))
+            .unwrap_or(chrono::Duration::MAX);
+
+        lifetime
+            .validate_with_leeway(Utc::now(), leeway)
+            .map_err(|failure| match failure {
+                TokenValidationFailure::Expired { .. } => JwtError::expired("Token has expired"),
+                _ => JwtError::invalid_token("Token is not yet valid"),
+            })
+    }
+
+    fn validate(&self, key: &PasetoKey, token: &Token, expected_type: &str) -> Result<String, ()> {
+        if token.is_empty() {
+            return Err(());
+        }
+
+        let claims = self.decode(key, token.value(), expected_type).map_err(|_| ())?;
+        serde_json::to_string(&claims).map_err(|_| ())
+    }
+}
+
+impl fmt::Debug for PasetoTokenService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PasetoTokenService")
+            .field("purpose", &self.purpose())
+            .field("service_key", &self.service_key.is_some())
+            .field("leeway_seconds", &self.leeway_seconds)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Format Unix seconds as a PASETO (RFC 3339) timestamp.
+fn to_rfc3339(timestamp: i64) -> Result<String, JwtError> {
+    DateTime::<Utc>::from_timestamp(timestamp, 0)
+        .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .ok_or_else(|| JwtError::encoding("Timestamp is out of range"))
+}
+
+/// Read an optional RFC 3339 timestamp claim as Unix seconds.
+fn timestamp_claim(claims: &Map<String, Value>, name: &str) -> Result<Option<i64>, JwtError> {
+    match claims.get(name) {
+        None => Ok(None),
+        Some(Value::String(value)) => DateTime::parse_from_rfc3339(value)
+            .map(|dt| Some(dt.timestamp()))
+            .map_err(|_| JwtError::invalid_token(format!("Invalid {} claim", name))),
+        Some(_) => Err(JwtError::invalid_token(format!("Invalid {} claim", name))),
+    }
+}
+
+/// Token operations. Signing and verifying are CPU-bound, so these run
+/// synchronously; the `TokenService` impl answers with their results.
+impl PasetoTokenService {
+    pub(crate) fn issue_access_token(&self, subject: &str, claims: &str) -> Token {
+        self.issue(&self.key, subject, claims, "access", self.token_policy.access_ttl())
+    }
+
+    pub(crate) fn issue_refresh_token(&self, subject: &str, claims: &str) -> Token {
+        self.issue(&self.key, subject, claims, "refresh", self.token_policy.refresh_ttl())
+    }
+
+    pub(crate) fn issue_service_token(&self, subject: &str, claims: &str) -> Token {
+        self.issue(self.service_key(), subject, claims, "service", SERVICE_TOKEN_TTL_SECS)
+    }
+
+    pub(crate) fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
+        self.validate(&self.key, token, "access")
+    }
+
+    pub(crate) fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
+        self.validate(&self.key, token, "refresh")
+    }
+
+    pub(crate) fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
+        self.validate(self.service_key(), token, "service")
+    }
+
+    pub(crate) fn issue_reset_token(&self, subject: &str, claims: &str) -> Token {
+        self.issue(&self.key, subject, claims, "reset", RESET_TOKEN_TTL_SECS)
+    }
+
+    pub(crate) fn validate_reset_token(&self, token: &Token) -> Result<String, ()> {
+        self.validate(&self.key, token, "reset")
+    }
+
+    pub(crate) fn issue_verification_token(&self, subject: &str, claims: &str) -> Token {
+        self.issue(&self.key, subject, claims, "verify", VERIFICATION_TOKEN_TTL_SECS)
+    }
+
+    pub(crate) fn validate_verification_token(&self, token: &Token) -> Result<String, ()> {
+        self.validate(&self.key, token, "verify")
+    }
+}
+
+impl TokenService for PasetoTokenService {
+    fn issue_access_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(PasetoTokenService::issue_access_token(self, subject, claims)).boxed()
+    }
+
+    fn issue_refresh_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(PasetoTokenService::issue_refresh_token(self, subject, claims)).boxed()
+    }
+
+    fn issue_service_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(PasetoTokenService::issue_service_token(self, subject, claims)).boxed()
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(PasetoTokenService::validate_access_token(self, token)).boxed()
+    }
+
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(PasetoTokenService::validate_refresh_token(self, token)).boxed()
+    }
+
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(PasetoTokenService::validate_service_token(self, token)).boxed()
+    }
+
+    fn issue_reset_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(PasetoTokenService::issue_reset_token(self, subject, claims)).boxed()
+    }
+
+    fn validate_reset_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(PasetoTokenService::validate_reset_token(self, token)).boxed()
+    }
+
+    fn issue_verification_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        ready(PasetoTokenService::issue_verification_token(self, subject, claims)).boxed()
+    }
+
+    fn validate_verification_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        ready(PasetoTokenService::validate_verification_token(self, token)).boxed()
+    }
+
+    fn supported_algorithms(&self) -> &[&str] {
+        match self.key {
+            PasetoKey::Local(_) => &["v4.local"],
+            PasetoKey::Public { .. } => &["v4.public"],
+        }
+    }
+}
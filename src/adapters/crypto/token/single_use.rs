@@ -0,0 +1,59 @@
+//! Claims shared by the JWT services' single-use tokens.
+//!
+//! Password reset and email verification tokens carry the same claims
+//! whichever algorithm signs them; only the encoding differs, so each
+//! service signs and verifies while this module builds and reports the
+//! claims.
+
+use crate::core::token::TokenClaims;
+use serde_json::{Map, Value};
+
+/// Build the claims of a single-use token of `token_type` from the
+/// caller's claims JSON, carrying its `jti`.
+pub(crate) fn issue_claims(claims: &str, token_type: &str, default_ttl: chrono::Duration) -> TokenClaims {
+    let claims_json: Value = serde_json::from_str(claims).unwrap_or_default();
+
+    let user_id = claims_json.get("sub")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+
+    let now = chrono::Utc::now();
+    // Honour the requested expiry, defaulting to the token type's TTL
+    let expires_at = claims_json.get("exp")
+        .and_then(|v| v.as_i64())
+        .unwrap_or_else(|| (now + default_ttl).timestamp());
+
+    let token_claims = TokenClaims::new(
+        user_id,
+        now.timestamp(),
+        expires_at,
+        token_type.to_string(),
+    );
+
+    // Carry the single-use identifier so the token can be consumed once
+    match claims_json.get("jti").and_then(|v| v.as_str()) {
+        Some(jti) => token_claims.with_jti(jti),
+        None => token_claims,
+    }
+}
+
+/// Report the decoded claims of a single-use token as JSON, rejecting any
+/// token type other than `token_type`.
+pub(crate) fn validated_claims(claims: TokenClaims, token_type: &str) -> Option<String> {
+    if claims.token_type != token_type {
+        return None;
+    }
+
+    let mut claims_map = Map::new();
+
+    claims_map.insert("sub".to_string(), Value::String(claims.sub));
+    claims_map.insert("type".to_string(), Value::String(token_type.to_string()));
+    claims_map.insert("exp".to_string(), Value::Number(claims.exp.into()));
+    claims_map.insert("iat".to_string(), Value::Number(claims.iat.into()));
+    if let Some(jti) = claims.jti {
+        claims_map.insert("jti".to_string(), Value::String(jti));
+    }
+
+    Some(serde_json::to_string(&claims_map).unwrap_or_default())
+}
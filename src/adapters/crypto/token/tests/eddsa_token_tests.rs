@@ -344,3 +344,108 @@ fn test_different_tokens_for_same_claims() {
     assert!(service.validate_access_token(&token2).is_ok());
 }
 
+
+#[test]
+fn test_supported_algorithms_reports_eddsa() {
+    let service = create_test_service();
+    assert_eq!(service.supported_algorithms(), &["EdDSA"]);
+}
+
+#[test]
+fn test_unexpected_algorithm_refused() {
+    let service = create_test_service();
+
+    // An HS256 token must never be accepted by the EdDSA service
+    let exp = chrono::Utc::now().timestamp() + 3600;
+    let claims = serde_json::json!({
+        "sub": "user123",
+        "iat": chrono::Utc::now().timestamp(),
+        "exp": exp,
+        "token_type": "access",
+    });
+    let forged = jsonwebtoken::encode(
+        &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS256),
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(b"attacker-controlled-secret-0123456789"),
+    )
+    .unwrap();
+
+    assert!(service.validate_access_token(&Token::new(forged)).is_err());
+}
+
+#[test]
+fn test_sid_round_trips_through_access_and_refresh_tokens() {
+    let service = create_test_service();
+    let access_claims = r#"{"sub":"user123","type":"access","exp":9999999999,"sid":"session-123"}"#;
+    let refresh_claims = r#"{"sub":"user123","type":"refresh","exp":9999999999,"sid":"session-123"}"#;
+
+    let access = service.issue_access_token("user123", access_claims);
+    let refresh = service.issue_refresh_token("user123", refresh_claims);
+
+    let access: serde_json::Value =
+        serde_json::from_str(&service.validate_access_token(&access).unwrap()).unwrap();
+    let refresh: serde_json::Value =
+        serde_json::from_str(&service.validate_refresh_token(&refresh).unwrap()).unwrap();
+
+    assert_eq!(access["sid"], "session-123");
+    assert_eq!(refresh["sid"], "session-123");
+}
+
+#[test]
+fn test_token_without_sid_carries_no_sid_claim() {
+    let service = create_test_service();
+    let claims = r#"{"sub":"user123","type":"access","exp":9999999999}"#;
+
+    let token = service.issue_access_token("user123", claims);
+    let validated: serde_json::Value =
+        serde_json::from_str(&service.validate_access_token(&token).unwrap()).unwrap();
+
+    assert!(validated.get("sid").is_none());
+}
+
+#[test]
+fn test_access_token_carries_caller_application_claims() {
+    let service = create_test_service();
+    let claims = r#"{"sub":"user123","sid":"session-123","roles":["admin"],"type":"refresh"}"#;
+
+    let token = service.issue_access_token("user123", claims);
+    let validated: serde_json::Value =
+        serde_json::from_str(&service.validate_access_token(&token).unwrap()).unwrap();
+
+    assert_eq!(validated["roles"], serde_json::json!(["admin"]));
+    assert_eq!(validated["sid"], "session-123");
+    assert_eq!(validated["type"], "access");
+}
+
+#[test]
+fn test_access_tokens_carry_unique_jti() {
+    let service = create_test_service();
+    let claims = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    let first: serde_json::Value = serde_json::from_str(
+        &service.validate_access_token(&service.issue_access_token("user123", claims)).unwrap(),
+    ).unwrap();
+    let second: serde_json::Value = serde_json::from_str(
+        &service.validate_access_token(&service.issue_access_token("user123", claims)).unwrap(),
+    ).unwrap();
+
+    let first_jti = first["jti"].as_str().expect("access token should carry a jti");
+    assert!(!first_jti.is_empty());
+    assert_ne!(first["jti"], second["jti"]);
+}
+
+#[test]
+fn test_refresh_tokens_carry_unique_jti() {
+    let service = create_test_service();
+    let claims = r#"{"sub":"user123","sid":"session-123"}"#;
+
+    let first: serde_json::Value = serde_json::from_str(
+        &service.validate_refresh_token(&service.issue_refresh_token("user123", claims)).unwrap(),
+    ).unwrap();
+    let second: serde_json::Value = serde_json::from_str(
+        &service.validate_refresh_token(&service.issue_refresh_token("user123", claims)).unwrap(),
+    ).unwrap();
+
+    assert!(first["jti"].is_string());
+    assert_ne!(first["jti"], second["jti"]);
+}
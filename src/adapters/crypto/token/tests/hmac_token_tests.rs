@@ -1,6 +1,6 @@
 //! Tests for HMAC-SHA256 token service.
 
-use crate::adapters::crypto::token::{HmacKey, HmacTokenService};
+use crate::adapters::crypto::token::{HmacKey, HmacTokenService, TokenValidationRules};
 use crate::core::token::Token;
 use crate::core::usecases::ports::TokenService;
 
@@ -186,3 +186,745 @@ fn test_different_tokens_for_same_claims() {
     assert!(service.validate_access_token(&token1).is_ok());
     assert!(service.validate_access_token(&token2).is_ok());
 }
+
+#[test]
+fn test_issued_token_carries_kid_header() {
+    let key = HmacKey::generate().expect("Should generate key");
+    let service = HmacTokenService::from_secret_key(&key.as_bytes())
+        .expect("Should create service with valid key")
+        .with_key_id("key-a");
+    let claims = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    let token = service.issue_access_token("user123", claims);
+    let header = jsonwebtoken::decode_header(token.value()).expect("Should decode header");
+
+    assert_eq!(header.kid.as_deref(), Some("key-a"));
+}
+
+#[test]
+fn test_key_rotation_overlap_window() {
+    let key_a = HmacKey::generate().expect("Should generate key");
+    let key_b = HmacKey::generate().expect("Should generate key");
+    let claims = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    // Before rotation: issue with key A
+    let service_a = HmacTokenService::from_secret_key(&key_a.as_bytes())
+        .unwrap()
+        .with_key_id("key-a");
+    let token_a = service_a.issue_access_token("user123", claims);
+
+    // Rotate: sign with key B, keep verifying key A
+    let service_b = HmacTokenService::from_secret_key(&key_b.as_bytes())
+        .unwrap()
+        .with_key_id("key-b")
+        .with_additional_verification_key("key-a", &key_a.as_bytes())
+        .unwrap();
+    let token_b = service_b.issue_access_token("user123", claims);
+
+    assert!(service_b.validate_access_token(&token_a).is_ok());
+    assert!(service_b.validate_access_token(&token_b).is_ok());
+
+    // Key A alone does not know key B
+    assert!(service_a.validate_access_token(&token_b).is_err());
+}
+
+#[test]
+fn test_unknown_kid_rejected() {
+    let key_a = HmacKey::generate().expect("Should generate key");
+    let claims = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    let issuer = HmacTokenService::from_secret_key(&key_a.as_bytes())
+        .unwrap()
+        .with_key_id("retired");
+    let token = issuer.issue_access_token("user123", claims);
+
+    // Same key material, but the "retired" kid is no longer registered
+    let validator = HmacTokenService::from_secret_key(&key_a.as_bytes())
+        .unwrap()
+        .with_key_id("current");
+
+    assert!(validator.validate_access_token(&token).is_err());
+}
+
+#[test]
+fn test_token_without_kid_uses_primary_key() {
+    let key = HmacKey::generate().expect("Should generate key");
+    let claims = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    let legacy = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap();
+    let token = legacy.issue_access_token("user123", claims);
+
+    let rotated = HmacTokenService::from_secret_key(&key.as_bytes())
+        .unwrap()
+        .with_key_id("key-a");
+
+    assert!(rotated.validate_access_token(&token).is_ok());
+}
+
+#[test]
+fn test_secondary_key_accepts_tokens_from_either_key() {
+    let old_key = HmacKey::generate().expect("Should generate key");
+    let new_key = HmacKey::generate().expect("Should generate key");
+    let claims = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    let old_service = HmacTokenService::from_secret_key(&old_key.as_bytes()).unwrap();
+    let old_token = old_service.issue_access_token("user123", claims);
+
+    let rotated = HmacTokenService::from_secret_key(&new_key.as_bytes())
+        .unwrap()
+        .with_secondary_decoding_key(&old_key.as_bytes())
+        .unwrap();
+    let new_token = rotated.issue_access_token("user123", claims);
+
+    assert!(rotated.validate_access_token(&old_token).is_ok());
+    assert!(rotated.validate_access_token(&new_token).is_ok());
+
+    // Issuance uses the primary key only
+    assert!(old_service.validate_access_token(&new_token).is_err());
+}
+
+#[test]
+fn test_secondary_key_rejects_third_key() {
+    let primary = HmacKey::generate().expect("Should generate key");
+    let secondary = HmacKey::generate().expect("Should generate key");
+    let stranger = HmacKey::generate().expect("Should generate key");
+    let claims = r#"{"sub":"user123","type":"refresh","sid":"session-123"}"#;
+
+    let foreign = HmacTokenService::from_secret_key(&stranger.as_bytes()).unwrap();
+    let token = foreign.issue_refresh_token("user123", claims);
+
+    let service = HmacTokenService::from_secret_key(&primary.as_bytes())
+        .unwrap()
+        .with_secondary_decoding_key(&secondary.as_bytes())
+        .unwrap();
+
+    assert!(service.validate_access_token(&token).is_err());
+    assert!(service.validate_refresh_token(&token).is_err());
+}
+
+#[test]
+fn test_secondary_key_must_be_valid_length() {
+    let primary = HmacKey::generate().expect("Should generate key");
+
+    let result = HmacTokenService::from_secret_key(&primary.as_bytes())
+        .unwrap()
+        .with_secondary_decoding_key(b"too-short");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_reset_token_is_distinct_from_session_tokens() {
+    let service = create_test_service();
+    let exp = chrono::Utc::now().timestamp() + 900;
+    let claims = format!(r#"{{"sub":"user123","type":"reset","exp":{},"jti":"reset-jti-1"}}"#, exp);
+
+    let reset = service.issue_reset_token("user123", &claims);
+    let validated = service.validate_reset_token(&reset).expect("reset token should validate");
+    assert!(validated.contains(r#""type":"reset""#));
+    assert!(validated.contains(r#""jti":"reset-jti-1""#));
+
+    // A reset token is not a refresh token, and an access token is not a reset token
+    assert!(service.validate_refresh_token(&reset).is_err());
+    let access = service.issue_access_token("user123", r#"{"sub":"user123","sid":"s1"}"#);
+    assert!(service.validate_reset_token(&access).is_err());
+}
+
+#[test]
+fn test_verification_token_is_distinct_from_other_tokens() {
+    let service = create_test_service();
+    let exp = chrono::Utc::now().timestamp() + 3600;
+    let claims = format!(r#"{{"sub":"user123","type":"verify","exp":{},"jti":"verify-jti-1"}}"#, exp);
+
+    let verify = service.issue_verification_token("user123", &claims);
+    let validated = service.validate_verification_token(&verify).expect("verification token should validate");
+    assert!(validated.contains(r#""type":"verify""#));
+    assert!(validated.contains(r#""jti":"verify-jti-1""#));
+
+    // Never accepted as a reset, refresh or access token
+    assert!(service.validate_reset_token(&verify).is_err());
+    assert!(service.validate_refresh_token(&verify).is_err());
+    assert!(service.validate_access_token(&verify).is_err());
+
+    let reset = service.issue_reset_token("user123", &claims);
+    assert!(service.validate_verification_token(&reset).is_err());
+}
+
+#[test]
+fn test_supported_algorithms_reports_hs256() {
+    let service = create_test_service();
+    assert_eq!(service.supported_algorithms(), &["HS256"]);
+}
+
+#[test]
+fn test_unexpected_algorithm_refused() {
+    let key = HmacKey::generate().expect("Should generate key");
+    let service = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap();
+
+    // Same secret, but signed with HS512 instead of the advertised HS256
+    let exp = chrono::Utc::now().timestamp() + 3600;
+    let claims = serde_json::json!({
+        "sub": "user123",
+        "iat": chrono::Utc::now().timestamp(),
+        "exp": exp,
+        "token_type": "access",
+    });
+    let forged = jsonwebtoken::encode(
+        &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS512),
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(&key.as_bytes()),
+    )
+    .unwrap();
+
+    assert!(service.validate_access_token(&Token::new(forged)).is_err());
+}
+
+#[test]
+fn test_sid_round_trips_through_access_and_refresh_tokens() {
+    let service = create_test_service();
+    let access_claims = r#"{"sub":"user123","type":"access","exp":9999999999,"sid":"session-123"}"#;
+    let refresh_claims = r#"{"sub":"user123","type":"refresh","exp":9999999999,"sid":"session-123"}"#;
+
+    let access = service.issue_access_token("user123", access_claims);
+    let refresh = service.issue_refresh_token("user123", refresh_claims);
+
+    let access: serde_json::Value =
+        serde_json::from_str(&service.validate_access_token(&access).unwrap()).unwrap();
+    let refresh: serde_json::Value =
+        serde_json::from_str(&service.validate_refresh_token(&refresh).unwrap()).unwrap();
+
+    assert_eq!(access["sid"], "session-123");
+    assert_eq!(refresh["sid"], "session-123");
+}
+
+#[test]
+fn test_token_without_sid_carries_no_sid_claim() {
+    let service = create_test_service();
+    let claims = r#"{"sub":"user123","type":"access","exp":9999999999}"#;
+
+    let token = service.issue_access_token("user123", claims);
+    let validated: serde_json::Value =
+        serde_json::from_str(&service.validate_access_token(&token).unwrap()).unwrap();
+
+    assert!(validated.get("sid").is_none());
+}
+
+#[test]
+fn test_issued_token_expiry_follows_token_policy() {
+    use crate::core::usecases::policies::TokenPolicy;
+
+    let service = create_test_service().with_token_policy(TokenPolicy::new(900, 3 * 24 * 3600, true));
+    let claims = r#"{"sub":"user123","sid":"session-123"}"#;
+
+    let access: serde_json::Value = serde_json::from_str(
+        &service.validate_access_token(&service.issue_access_token("user123", claims)).unwrap(),
+    )
+    .unwrap();
+    let refresh: serde_json::Value = serde_json::from_str(
+        &service.validate_refresh_token(&service.issue_refresh_token("user123", claims)).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(access["exp"].as_i64().unwrap() - access["iat"].as_i64().unwrap(), 900);
+    assert_eq!(refresh["exp"].as_i64().unwrap() - refresh["iat"].as_i64().unwrap(), 3 * 24 * 3600);
+}
+
+#[test]
+fn test_zero_ttl_policy_issues_no_token() {
+    use crate::core::usecases::policies::TokenPolicy;
+
+    let service = create_test_service().with_token_policy(TokenPolicy::new(0, 0, true));
+    let claims = r#"{"sub":"user123","sid":"session-123"}"#;
+
+    assert!(service.issue_access_token("user123", claims).value().is_empty());
+    assert!(service.issue_refresh_token("user123", claims).value().is_empty());
+}
+
+#[test]
+fn test_expiry_is_decided_by_token_lifetime() {
+    use crate::core::token::TokenClaims;
+
+    let service = create_test_service();
+    let now = chrono::Utc::now().timestamp();
+
+    // Expired a few seconds ago: inside jsonwebtoken's default leeway, but
+    // the core lifetime rules reject it
+    let expired = TokenClaims::new("user123".to_string(), now - 60, now - 5, "access".to_string());
+    let token = Token::new(service.encode_token(&expired).unwrap());
+    assert!(service.validate_access_token(&token).is_err());
+
+    // Not valid until later
+    let future = TokenClaims::new("user123".to_string(), now, now + 3600, "access".to_string())
+        .with_not_before(now + 600);
+    let token = Token::new(service.encode_token(&future).unwrap());
+    assert!(service.validate_access_token(&token).is_err());
+}
+
+#[test]
+fn test_leeway_tolerates_recent_expiry() {
+    use crate::core::token::TokenClaims;
+
+    let key = HmacKey::generate().expect("Should generate key");
+    let now = chrono::Utc::now().timestamp();
+    let expired = TokenClaims::new("user123".to_string(), now - 600, now - 10, "access".to_string());
+
+    let strict = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap().with_leeway(0);
+    let lenient = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap().with_leeway(30);
+    let token = Token::new(strict.encode_token(&expired).unwrap());
+
+    assert!(strict.validate_access_token(&token).is_err());
+    assert!(lenient.validate_access_token(&token).is_ok());
+}
+
+#[test]
+fn test_leeway_applies_to_not_before() {
+    use crate::core::token::TokenClaims;
+
+    let key = HmacKey::generate().expect("Should generate key");
+    let now = chrono::Utc::now().timestamp();
+    let early = TokenClaims::new("user123".to_string(), now, now + 3600, "access".to_string())
+        .with_not_before(now + 10);
+
+    let strict = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap().with_leeway(0);
+    let lenient = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap().with_leeway(30);
+    let token = Token::new(strict.encode_token(&early).unwrap());
+
+    assert!(strict.validate_access_token(&token).is_err());
+    assert!(lenient.validate_access_token(&token).is_ok());
+}
+
+#[test]
+fn test_header_typ_defaults_by_token_type() {
+    let service = create_test_service();
+    let claims = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    let access = service.issue_access_token("user123", claims);
+    let refresh = service.issue_refresh_token("user123", claims);
+    let service_token = service.issue_service_token("svc", r#"{"sub":"svc"}"#);
+
+    let typ = |token: &Token| jsonwebtoken::decode_header(token.value()).unwrap().typ;
+    assert_eq!(typ(&access).as_deref(), Some("at+jwt"));
+    assert_eq!(typ(&refresh).as_deref(), Some("JWT"));
+    assert_eq!(typ(&service_token).as_deref(), Some("JWT"));
+    assert!(jsonwebtoken::decode_header(access.value()).unwrap().cty.is_none());
+}
+
+#[test]
+fn test_header_typ_and_cty_follow_configuration() {
+    let key = HmacKey::generate().expect("Should generate key");
+    let service = HmacTokenService::from_secret_key(&key.as_bytes())
+        .unwrap()
+        .with_header_types("JWT", "example+jwt")
+        .with_content_type("JWT");
+    let claims = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    let access = jsonwebtoken::decode_header(service.issue_access_token("user123", claims).value()).unwrap();
+    let refresh = jsonwebtoken::decode_header(service.issue_refresh_token("user123", claims).value()).unwrap();
+
+    assert_eq!(access.typ.as_deref(), Some("JWT"));
+    assert_eq!(access.cty.as_deref(), Some("JWT"));
+    assert_eq!(refresh.typ.as_deref(), Some("example+jwt"));
+}
+
+#[test]
+fn test_typ_enforcement_rejects_mismatched_header() {
+    let key = HmacKey::generate().expect("Should generate key");
+    let claims = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    // Same key, but the issuer labels access tokens as plain JWTs
+    let issuer = HmacTokenService::from_secret_key(&key.as_bytes())
+        .unwrap()
+        .with_header_types("JWT", "JWT");
+    let token = issuer.issue_access_token("user123", claims);
+
+    let lenient = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap();
+    let strict = HmacTokenService::from_secret_key(&key.as_bytes())
+        .unwrap()
+        .with_typ_enforcement(true);
+
+    assert!(lenient.validate_access_token(&token).is_ok());
+    assert!(strict.validate_access_token(&token).is_err());
+}
+
+#[test]
+fn test_typ_enforcement_accepts_matching_headers() {
+    let service = create_test_service().with_typ_enforcement(true);
+    let claims = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    let access = service.issue_access_token("user123", claims);
+    let refresh = service.issue_refresh_token("user123", claims);
+    let service_token = service.issue_service_token("svc", r#"{"sub":"svc"}"#);
+
+    assert!(service.validate_access_token(&access).is_ok());
+    assert!(service.validate_refresh_token(&refresh).is_ok());
+    assert!(service.validate_service_token(&service_token).is_ok());
+}
+
+#[test]
+fn test_workspace_id_round_trips_through_access_token() {
+    let service = create_test_service();
+    let scoped = r#"{"sub":"user123","type":"access","sid":"session-123","workspace_id":"ws-acme"}"#;
+    let global = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    let scoped_claims: serde_json::Value = serde_json::from_str(
+        &service.validate_access_token(&service.issue_access_token("user123", scoped)).unwrap(),
+    ).unwrap();
+    let global_claims: serde_json::Value = serde_json::from_str(
+        &service.validate_access_token(&service.issue_access_token("user123", global)).unwrap(),
+    ).unwrap();
+
+    assert_eq!(scoped_claims["sub"], "user123");
+    assert_eq!(scoped_claims["workspace_id"], "ws-acme");
+    assert!(global_claims.get("workspace_id").is_none());
+}
+
+#[test]
+fn test_access_tokens_carry_unique_jti() {
+    let service = create_test_service();
+    let claims = r#"{"sub":"user123","type":"access","sid":"session-123"}"#;
+
+    let first: serde_json::Value = serde_json::from_str(
+        &service.validate_access_token(&service.issue_access_token("user123", claims)).unwrap(),
+    ).unwrap();
+    let second: serde_json::Value = serde_json::from_str(
+        &service.validate_access_token(&service.issue_access_token("user123", claims)).unwrap(),
+    ).unwrap();
+
+    let first_jti = first["jti"].as_str().expect("access token should carry a jti");
+    assert!(!first_jti.is_empty());
+    assert_ne!(first["jti"], second["jti"]);
+}
+
+#[test]
+fn test_refresh_and_service_tokens_carry_unique_jti() {
+    let service = create_test_service();
+    let claims = r#"{"sub":"user123","sid":"session-123"}"#;
+
+    let first: serde_json::Value = serde_json::from_str(
+        &service.validate_refresh_token(&service.issue_refresh_token("user123", claims)).unwrap(),
+    ).unwrap();
+    let second: serde_json::Value = serde_json::from_str(
+        &service.validate_refresh_token(&service.issue_refresh_token("user123", claims)).unwrap(),
+    ).unwrap();
+    assert!(first["jti"].is_string());
+    assert_ne!(first["jti"], second["jti"]);
+
+    let service_token = service.issue_service_token("billing", r#"{"sub":"billing"}"#);
+    let validated: serde_json::Value =
+        serde_json::from_str(&service.validate_service_token(&service_token).unwrap()).unwrap();
+    assert!(validated["jti"].is_string());
+}
+
+// ============================================================================
+// Algorithm confusion
+// ============================================================================
+
+/// Build a token with an arbitrary header `alg`, HMAC-signed with `secret`
+/// unless `signed` is false.
+fn forge_token(secret: &[u8], alg: Option<&str>, token_type: &str, signed: bool) -> Token {
+    use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+
+    let header = match alg {
+        Some(alg) => serde_json::json!({ "alg": alg, "typ": "JWT" }),
+        None => serde_json::json!({ "typ": "JWT" }),
+    };
+    let now = chrono::Utc::now().timestamp();
+    let claims = serde_json::json!({
+        "sub": "user123",
+        "iat": now,
+        "exp": now + 3600,
+        "token_type": token_type,
+    });
+
+    let signing_input = format!(
+        "{}.{}",
+        URL_SAFE_NO_PAD.encode(header.to_string()),
+        URL_SAFE_NO_PAD.encode(claims.to_string()),
+    );
+    let signature = if signed {
+        let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, secret);
+        URL_SAFE_NO_PAD.encode(ring::hmac::sign(&key, signing_input.as_bytes()).as_ref())
+    } else {
+        String::new()
+    };
+
+    Token::new(format!("{}.{}", signing_input, signature))
+}
+
+#[test]
+fn test_forged_hs256_token_is_accepted() {
+    // Control: the forging helper produces tokens the service accepts
+    let key = HmacKey::generate().expect("Should generate key");
+    let service = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap();
+
+    let token = forge_token(&key.as_bytes(), Some("HS256"), "access", true);
+
+    assert!(service.validate_access_token(&token).is_ok());
+}
+
+#[test]
+fn test_token_without_jti_still_validates() {
+    // Tokens issued before every token carried a jti remain valid
+    let key = HmacKey::generate().expect("Should generate key");
+    let service = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap();
+
+    let token = forge_token(&key.as_bytes(), Some("HS256"), "access", true);
+    let validated: serde_json::Value =
+        serde_json::from_str(&service.validate_access_token(&token).expect("token should validate")).unwrap();
+    assert!(validated.get("jti").is_none());
+}
+
+#[test]
+fn test_alg_none_token_is_rejected() {
+    let key = HmacKey::generate().expect("Should generate key");
+    let service = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap();
+
+    let unsigned = forge_token(&key.as_bytes(), Some("none"), "access", false);
+    let signed = forge_token(&key.as_bytes(), Some("none"), "access", true);
+
+    assert!(service.validate_access_token(&unsigned).is_err());
+    assert!(service.validate_access_token(&signed).is_err());
+    assert!(service.validate_refresh_token(&forge_token(&key.as_bytes(), Some("none"), "refresh", false)).is_err());
+    assert!(service.validate_service_token(&forge_token(&key.as_bytes(), Some("none"), "service", false)).is_err());
+}
+
+#[test]
+fn test_asymmetric_alg_token_is_rejected() {
+    // Classic confusion: an RS256 header with the shared secret as HMAC key
+    let key = HmacKey::generate().expect("Should generate key");
+    let service = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap();
+
+    for alg in ["RS256", "ES256", "EdDSA", "HS512"] {
+        let token = forge_token(&key.as_bytes(), Some(alg), "access", true);
+        assert!(service.validate_access_token(&token).is_err(), "{} must be rejected", alg);
+
+        let service_token = forge_token(&key.as_bytes(), Some(alg), "service", true);
+        assert!(service.validate_service_token(&service_token).is_err(), "{} must be rejected", alg);
+    }
+}
+
+#[test]
+fn test_token_without_alg_is_rejected() {
+    let key = HmacKey::generate().expect("Should generate key");
+    let service = HmacTokenService::from_secret_key(&key.as_bytes()).unwrap();
+
+    let token = forge_token(&key.as_bytes(), None, "access", true);
+
+    assert!(service.validate_access_token(&token).is_err());
+}
+
+fn tenant_enricher(identity: &crate::core::identity::IdentityClaims) -> serde_json::Map<String, serde_json::Value> {
+    let mut claims = serde_json::Map::new();
+    claims.insert("tenant".to_string(), serde_json::json!("acme"));
+    claims.insert("roles".to_string(), serde_json::json!(["admin"]));
+    claims.insert(
+        "enriched_for".to_string(),
+        serde_json::json!(identity.user_id.clone().unwrap_or_default()),
+    );
+    claims
+}
+
+fn overriding_enricher(_identity: &crate::core::identity::IdentityClaims) -> serde_json::Map<String, serde_json::Value> {
+    let mut claims = serde_json::Map::new();
+    claims.insert("exp".to_string(), serde_json::json!(1));
+    claims.insert("iat".to_string(), serde_json::json!(1));
+    claims.insert("sub".to_string(), serde_json::json!("someone-else"));
+    claims.insert("tenant".to_string(), serde_json::json!("acme"));
+    claims
+}
+
+/// Decode the JWT payload without verifying it.
+fn payload_of(token: &Token) -> serde_json::Value {
+    use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+
+    let payload = token.value().split('.').nth(1).expect("JWT has a payload");
+    serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap()
+}
+
+#[test]
+fn test_claim_enricher_adds_claims_to_access_token() {
+    let service = create_test_service().with_claim_enricher(tenant_enricher);
+
+    let token = service.issue_access_token("user123", r#"{"sub":"user123","sid":"s1"}"#);
+
+    let payload = payload_of(&token);
+    assert_eq!(payload["tenant"], "acme");
+    assert_eq!(payload["roles"], serde_json::json!(["admin"]));
+    assert_eq!(payload["enriched_for"], "user123");
+
+    let validated: serde_json::Value =
+        serde_json::from_str(&service.validate_access_token(&token).expect("token should validate")).unwrap();
+    assert_eq!(validated["tenant"], "acme");
+    assert_eq!(validated["roles"], serde_json::json!(["admin"]));
+    assert_eq!(validated["sub"], "user123");
+    assert_eq!(validated["sid"], "s1");
+}
+
+#[test]
+fn test_claim_enricher_cannot_override_reserved_claims() {
+    let service = create_test_service().with_claim_enricher(overriding_enricher);
+    let before = chrono::Utc::now().timestamp();
+
+    let token = service.issue_access_token("user123", r#"{"sub":"user123","sid":"s1"}"#);
+
+    let payload = payload_of(&token);
+    assert_eq!(payload["sub"], "user123");
+    assert!(payload["exp"].as_i64().unwrap() > before);
+    assert!(payload["iat"].as_i64().unwrap() >= before);
+    assert_eq!(payload["tenant"], "acme");
+
+    // Still valid, since the real expiry survived
+    let validated: serde_json::Value =
+        serde_json::from_str(&service.validate_access_token(&token).expect("token should validate")).unwrap();
+    assert_eq!(validated["sub"], "user123");
+    assert_eq!(validated["exp"], payload["exp"]);
+}
+
+#[test]
+fn test_access_token_carries_caller_application_claims() {
+    let service = create_test_service();
+
+    let token = service.issue_access_token(
+        "user123",
+        r#"{"sub":"user123","sid":"s1","tenant":"acme","roles":["admin"],"token_type":"refresh"}"#,
+    );
+
+    // Application claims are signed in; reserved ones stay the service's own
+    let payload = payload_of(&token);
+    assert_eq!(payload["tenant"], "acme");
+    assert_eq!(payload["roles"], serde_json::json!(["admin"]));
+    assert_eq!(payload["token_type"], "access");
+}
+
+#[test]
+fn test_oversized_claims_are_refused_at_issuance() {
+    use crate::adapters::crypto::error::JwtError;
+
+    let service = create_test_service().with_max_claims_bytes(256);
+    let claims = serde_json::json!({ "sub": "user123", "blob": "x".repeat(512) }).to_string();
+
+    assert!(matches!(service.check_claims_size(&claims), Err(JwtError::TooLarge { .. })));
+    assert!(service.issue_access_token("user123", &claims).value().is_empty());
+    assert!(service.issue_refresh_token("user123", &claims).value().is_empty());
+    assert!(service.issue_reset_token("user123", &claims).value().is_empty());
+
+    // Claims within the limit still issue
+    assert!(!service.issue_access_token("user123", r#"{"sub":"user123"}"#).value().is_empty());
+}
+
+#[test]
+fn test_over_length_token_is_rejected_before_decode() {
+    use crate::adapters::crypto::error::JwtError;
+
+    let service = create_test_service();
+    let token = service.issue_access_token("user123", r#"{"sub":"user123","sid":"s1"}"#);
+    assert!(service.validate_access_token(&token).is_ok());
+
+    // The same correctly signed token is refused once it exceeds the limit
+    let limited = service.clone().with_max_token_bytes(token.value().len() - 1);
+    assert!(matches!(limited.check_token_size(token.value()), Err(JwtError::TooLarge { .. })));
+    assert!(limited.validate_access_token(&token).is_err());
+
+    // A huge string that is not even a token fails on length alone
+    let garbage = "a".repeat(64 * 1024);
+    assert!(matches!(service.check_token_size(&garbage), Err(JwtError::TooLarge { .. })));
+    assert!(service.validate_access_token(&Token::new(garbage)).is_err());
+}
+
+fn service_with_rules(key: &HmacKey, access: TokenValidationRules, refresh: TokenValidationRules) -> HmacTokenService {
+    HmacTokenService::from_secret_key(&key.as_bytes())
+        .expect("Should create service")
+        .with_access_validation(access)
+        .with_refresh_validation(refresh)
+}
+
+#[test]
+fn test_access_token_with_expected_audience_validates() {
+    let key = HmacKey::generate().expect("Should generate key");
+    let service = service_with_rules(
+        &key,
+        TokenValidationRules::default().with_issuer("auth").with_audience("orders"),
+        TokenValidationRules::default(),
+    );
+
+    let token = service.issue_access_token("user123", r#"{"sub":"user123","sid":"s1"}"#);
+
+    let payload = payload_of(&token);
+    assert_eq!(payload["iss"], "auth");
+    assert_eq!(payload["aud"], serde_json::json!(["orders"]));
+    let validated: serde_json::Value =
+        serde_json::from_str(&service.validate_access_token(&token).expect("token should validate")).unwrap();
+    assert_eq!(validated["aud"], serde_json::json!(["orders"]));
+}
+
+#[test]
+fn test_access_token_for_other_audience_is_rejected() {
+    let key = HmacKey::generate().expect("Should generate key");
+    let issuer = service_with_rules(
+        &key,
+        TokenValidationRules::default().with_audience("orders"),
+        TokenValidationRules::default(),
+    );
+    let validator = service_with_rules(
+        &key,
+        TokenValidationRules::default().with_audience("billing"),
+        TokenValidationRules::default(),
+    );
+
+    let token = issuer.issue_access_token("user123", r#"{"sub":"user123","sid":"s1"}"#);
+
+    assert!(issuer.validate_access_token(&token).is_ok());
+    assert!(validator.validate_access_token(&token).is_err());
+}
+
+#[test]
+fn test_refresh_tokens_follow_their_own_audience_rules() {
+    let key = HmacKey::generate().expect("Should generate key");
+    let strict_access = TokenValidationRules::default().with_audience("orders");
+    let issuer = service_with_rules(
+        &key,
+        strict_access.clone(),
+        TokenValidationRules::default().with_audience("refresh-a"),
+    );
+    // Strict on access, lax on refresh: any refresh audience is accepted
+    let lax = service_with_rules(
+        &key,
+        strict_access.clone(),
+        TokenValidationRules::default().with_audience("refresh-b").with_audience_enforced(false),
+    );
+    let strict = service_with_rules(
+        &key,
+        strict_access,
+        TokenValidationRules::default().with_audience("refresh-b"),
+    );
+
+    let token = issuer.issue_refresh_token("user123", r#"{"sub":"user123","sid":"s1"}"#);
+
+    assert_eq!(payload_of(&token)["aud"], serde_json::json!(["refresh-a"]));
+    assert!(issuer.validate_refresh_token(&token).is_ok());
+    assert!(lax.validate_refresh_token(&token).is_ok());
+    assert!(strict.validate_refresh_token(&token).is_err());
+}
+
+#[test]
+fn test_refresh_token_is_not_accepted_as_access_token() {
+    let service = create_test_service();
+    let claims = r#"{"sub":"user123","sid":"session-123"}"#;
+
+    let refresh = service.issue_refresh_token("user123", claims);
+
+    assert!(service.validate_refresh_token(&refresh).is_ok());
+    assert!(service.validate_access_token(&refresh).is_err());
+}
+
+#[test]
+fn test_access_token_is_not_accepted_as_refresh_token() {
+    let service = create_test_service();
+    let claims = r#"{"sub":"user123","sid":"session-123"}"#;
+
+    let access = service.issue_access_token("user123", claims);
+
+    assert_eq!(payload_of(&access)["token_type"], "access");
+    assert!(service.validate_access_token(&access).is_ok());
+    assert!(service.validate_refresh_token(&access).is_err());
+}
@@ -1,4 +1,4 @@
-//! Tests for token module (HMAC-SHA256 and EdDSA).
+//! Tests for token module (HMAC-SHA256, EdDSA, PASETO and opaque tokens).
 //!
 //! These tests verify:
 //! - Key generation and encoding/decoding
@@ -10,6 +10,8 @@
 pub mod eddsa_token_tests;
 pub mod hmac_keys_tests;
 pub mod hmac_token_tests;
+pub mod paseto_token_tests;
+pub mod opaque_token_tests;
 pub mod jwks_provider_tests;
 pub mod google_validator_configuration_tests;
 pub mod google_rsa256_validator_tests;
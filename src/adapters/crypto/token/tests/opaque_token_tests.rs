@@ -0,0 +1,136 @@
+//! Tests for opaque reference token service.
+
+use std::sync::Arc;
+
+use crate::adapters::crypto::token::OpaqueTokenService;
+use crate::adapters::memory::{MemoryStore, OpaqueTokenStoreMemory};
+use crate::core::token::Token;
+use crate::core::usecases::policies::TokenPolicy;
+use crate::core::usecases::ports::TokenService;
+
+fn service() -> (OpaqueTokenService, Arc<OpaqueTokenStoreMemory>) {
+    let store = Arc::new(OpaqueTokenStoreMemory::new(MemoryStore::new()));
+    (OpaqueTokenService::new(store.clone()), store)
+}
+
+fn parse(claims: &str) -> serde_json::Value {
+    serde_json::from_str(claims).expect("Claims should be JSON")
+}
+
+#[tokio::test]
+async fn test_issue_validate_revoke() {
+    let (service, _) = service();
+
+    let token = service.issue_access_token("user123", r#"{"sub":"user123","sid":"session-1","scope":["user:read"]}"#).await;
+    assert!(!token.is_empty());
+
+    let claims = parse(&service.validate_access_token(&token).await.expect("Access token should validate"));
+    assert_eq!(claims["sub"], "user123");
+    assert_eq!(claims["type"], "access");
+    assert_eq!(claims["sid"], "session-1");
+    assert_eq!(claims["scope"], serde_json::json!(["user:read"]));
+    assert!(claims["exp"].as_i64().unwrap() > chrono::Utc::now().timestamp());
+    assert!(claims["jti"].is_string());
+
+    assert!(service.revoke(&token).await);
+    assert!(service.validate_access_token(&token).await.is_err());
+    assert!(!service.revoke(&token).await);
+}
+
+#[tokio::test]
+async fn test_unknown_token_fails_validation() {
+    let (service, _) = service();
+    service.issue_access_token("user123", r#"{"sub":"user123"}"#).await;
+
+    assert!(service.validate_access_token(&Token::new("not-an-issued-token")).await.is_err());
+    assert!(service.validate_access_token(&Token::new("")).await.is_err());
+}
+
+#[tokio::test]
+async fn test_tokens_are_opaque_and_unique() {
+    let (service, store) = service();
+    let claims = r#"{"sub":"user123","sid":"session-1"}"#;
+
+    let first = service.issue_access_token("user123", claims).await;
+    let second = service.issue_access_token("user123", claims).await;
+
+    assert_ne!(first.value(), second.value());
+    // 32 random bytes, base64url without padding; nothing readable inside
+    assert_eq!(first.value().len(), 43);
+    assert!(!first.value().contains('.'));
+    assert!(!first.value().contains("user123"));
+    assert_eq!(store.len(), 2);
+}
+
+#[tokio::test]
+async fn test_store_holds_hashes_not_tokens() {
+    let store = MemoryStore::new();
+    let service = OpaqueTokenService::new(Arc::new(OpaqueTokenStoreMemory::new(store.clone())));
+
+    let token = service.issue_access_token("user123", r#"{"sub":"user123"}"#).await;
+
+    let records = store.opaque_tokens();
+    let record = records.values().next().unwrap();
+    assert_ne!(record.token_hash, token.value());
+    assert_eq!(record.token_hash.len(), 64);
+}
+
+#[tokio::test]
+async fn test_token_types_are_not_interchangeable() {
+    let (service, _) = service();
+
+    let access = service.issue_access_token("user123", r#"{"sub":"user123"}"#).await;
+    let refresh = service.issue_refresh_token("user123", r#"{"sub":"user123","sid":"session-1"}"#).await;
+
+    assert!(service.validate_refresh_token(&access).await.is_err());
+    assert!(service.validate_access_token(&refresh).await.is_err());
+    assert_eq!(parse(&service.validate_refresh_token(&refresh).await.unwrap())["sid"], "session-1");
+}
+
+#[tokio::test]
+async fn test_expired_token_fails_validation() {
+    let (service, _) = service();
+    let exp = chrono::Utc::now().timestamp() - 1;
+
+    let token = service.issue_reset_token("user123", &format!(r#"{{"sub":"user123","exp":{},"jti":"reset-1"}}"#, exp)).await;
+
+    assert!(service.validate_reset_token(&token).await.is_err());
+}
+
+#[tokio::test]
+async fn test_reset_token_keeps_caller_jti() {
+    let (service, _) = service();
+
+    let token = service.issue_reset_token("user123", r#"{"sub":"user123","jti":"reset-1"}"#).await;
+
+    let claims = parse(&service.validate_reset_token(&token).await.expect("Reset token should validate"));
+    assert_eq!(claims["type"], "reset");
+    assert_eq!(claims["jti"], "reset-1");
+}
+
+#[tokio::test]
+async fn test_requested_expiry_is_capped_by_policy_ttl() {
+    let store = Arc::new(OpaqueTokenStoreMemory::new(MemoryStore::new()));
+    let service = OpaqueTokenService::new(store).with_token_policy(TokenPolicy::new(60, 3600, true));
+    let now = chrono::Utc::now().timestamp();
+    let far = now + 365 * 24 * 3600;
+
+    let token = service.issue_access_token("user123", &format!(r#"{{"sub":"user123","exp":{}}}"#, far)).await;
+    let claims = parse(&service.validate_access_token(&token).await.expect("Access token should validate"));
+    assert!(claims["exp"].as_i64().unwrap() <= chrono::Utc::now().timestamp() + 60);
+
+    let token = service.issue_refresh_token("user123", &format!(r#"{{"sub":"user123","exp":{}}}"#, far)).await;
+    let claims = parse(&service.validate_refresh_token(&token).await.expect("Refresh token should validate"));
+    assert!(claims["exp"].as_i64().unwrap() <= chrono::Utc::now().timestamp() + 3600);
+}
+
+#[tokio::test]
+async fn test_shorter_requested_expiry_is_kept() {
+    let (service, _) = service();
+    let exp = chrono::Utc::now().timestamp() + 30;
+
+    let token = service.issue_access_token("user123", &format!(r#"{{"sub":"user123","exp":{}}}"#, exp)).await;
+    let claims = parse(&service.validate_access_token(&token).await.expect("Access token should validate"));
+
+    assert_eq!(claims["exp"].as_i64(), Some(exp));
+}
@@ -0,0 +1,244 @@
+//! Tests for PASETO v4 token service.
+
+use crate::adapters::crypto::token::{PasetoTokenService, PASETO_LOCAL_KEY_SIZE};
+use crate::core::token::Token;
+use crate::core::usecases::ports::TokenService;
+
+const LOCAL_KEY: [u8; PASETO_LOCAL_KEY_SIZE] = [7u8; PASETO_LOCAL_KEY_SIZE];
+const ED25519_SEED: [u8; 32] = [42u8; 32];
+
+fn local_service() -> PasetoTokenService {
+    PasetoTokenService::local(&LOCAL_KEY).expect("Should create v4.local service")
+}
+
+fn public_service() -> PasetoTokenService {
+    PasetoTokenService::public(&ED25519_SEED).expect("Should create v4.public service")
+}
+
+fn parse(claims: &str) -> serde_json::Value {
+    serde_json::from_str(claims).expect("Claims should be JSON")
+}
+
+/// Flip one character in the middle of the token body.
+fn tamper(token: &Token) -> Token {
+    let mut chars: Vec<char> = token.value().chars().collect();
+    let index = chars.len() / 2;
+    chars[index] = if chars[index] == 'A' { 'B' } else { 'A' };
+    Token::new(chars.into_iter().collect::<String>())
+}
+
+#[test]
+fn test_local_tokens_have_v4_local_header() {
+    let token = local_service().issue_access_token("user123", r#"{"sub":"user123"}"#);
+
+    assert!(token.value().starts_with("v4.local."));
+    assert_eq!(local_service().supported_algorithms(), &["v4.local"]);
+}
+
+#[test]
+fn test_public_tokens_have_v4_public_header() {
+    let token = public_service().issue_access_token("user123", r#"{"sub":"user123"}"#);
+
+    assert!(token.value().starts_with("v4.public."));
+    assert_eq!(public_service().supported_algorithms(), &["v4.public"]);
+}
+
+#[test]
+fn test_access_token_round_trips_claims() {
+    for service in [local_service(), public_service()] {
+        let token = service.issue_access_token(
+            "user123",
+            r#"{"sub":"user123","sid":"session-1","workspace_id":"ws-1","role":"admin","scope":["read","write"]}"#,
+        );
+
+        let claims = parse(&service.validate_access_token(&token).expect("Token should validate"));
+
+        assert_eq!(claims["sub"], "user123");
+        assert_eq!(claims["type"], "access");
+        assert_eq!(claims["sid"], "session-1");
+        assert_eq!(claims["workspace_id"], "ws-1");
+        assert_eq!(claims["role"], "admin");
+        assert_eq!(claims["scope"], serde_json::json!(["read", "write"]));
+        assert!(claims["iat"].is_i64());
+        assert!(claims["exp"].as_i64().unwrap() > claims["iat"].as_i64().unwrap());
+    }
+}
+
+#[test]
+fn test_subject_falls_back_to_argument() {
+    let service = local_service();
+    let token = service.issue_access_token("user123", "{}");
+
+    let claims = parse(&service.validate_access_token(&token).expect("Token should validate"));
+
+    assert_eq!(claims["sub"], "user123");
+}
+
+#[test]
+fn test_requested_expiry_is_honoured() {
+    let service = local_service();
+    let exp = chrono::Utc::now().timestamp() + 120;
+    let token = service.issue_access_token("user123", &format!(r#"{{"sub":"user123","exp":{}}}"#, exp));
+
+    let claims = parse(&service.validate_access_token(&token).expect("Token should validate"));
+
+    assert_eq!(claims["exp"], exp);
+}
+
+#[test]
+fn test_caller_cannot_override_token_type() {
+    let service = local_service();
+    let token = service.issue_access_token("user123", r#"{"sub":"user123","type":"service"}"#);
+
+    assert!(service.validate_service_token(&token).is_err());
+    assert!(service.validate_access_token(&token).is_ok());
+}
+
+#[test]
+fn test_tampered_token_is_rejected() {
+    for service in [local_service(), public_service()] {
+        let token = service.issue_access_token("user123", r#"{"sub":"user123"}"#);
+
+        assert!(service.validate_access_token(&tamper(&token)).is_err());
+    }
+}
+
+#[test]
+fn test_token_from_other_key_is_rejected() {
+    let other_local = PasetoTokenService::local(&[9u8; PASETO_LOCAL_KEY_SIZE]).unwrap();
+    let other_public = PasetoTokenService::public(&[9u8; 32]).unwrap();
+
+    let local_token = local_service().issue_access_token("user123", r#"{"sub":"user123"}"#);
+    let public_token = public_service().issue_access_token("user123", r#"{"sub":"user123"}"#);
+
+    assert!(other_local.validate_access_token(&local_token).is_err());
+    assert!(other_public.validate_access_token(&public_token).is_err());
+}
+
+#[test]
+fn test_purposes_are_not_interchangeable() {
+    let local_token = local_service().issue_access_token("user123", r#"{"sub":"user123"}"#);
+    let public_token = public_service().issue_access_token("user123", r#"{"sub":"user123"}"#);
+
+    assert!(public_service().validate_access_token(&local_token).is_err());
+    assert!(local_service().validate_access_token(&public_token).is_err());
+}
+
+#[test]
+fn test_jwt_with_alg_none_is_rejected() {
+    // {"alg":"none","typ":"JWT"}.{"sub":"user123","type":"access","exp":9999999999}.
+    let token = Token::new(
+        "eyJhbGciOiJub25lIiwidHlwIjoiSldUIn0.eyJzdWIiOiJ1c2VyMTIzIiwidHlwZSI6ImFjY2VzcyIsImV4cCI6OTk5OTk5OTk5OX0.",
+    );
+
+    assert!(local_service().validate_access_token(&token).is_err());
+    assert!(public_service().validate_access_token(&token).is_err());
+}
+
+#[test]
+fn test_expired_token_is_rejected() {
+    let service = local_service();
+    let exp = chrono::Utc::now().timestamp() - 60;
+    let token = service.issue_access_token("user123", &format!(r#"{{"sub":"user123","exp":{}}}"#, exp));
+
+    assert!(!token.is_empty());
+    assert!(service.validate_access_token(&token).is_err());
+}
+
+#[test]
+fn test_leeway_accepts_recently_expired_token() {
+    let service = local_service().with_leeway(120);
+    let exp = chrono::Utc::now().timestamp() - 60;
+    let token = service.issue_access_token("user123", &format!(r#"{{"sub":"user123","exp":{}}}"#, exp));
+
+    assert!(service.validate_access_token(&token).is_ok());
+}
+
+#[test]
+fn test_refresh_token_is_not_an_access_token() {
+    let service = local_service();
+    let token = service.issue_refresh_token("user123", r#"{"sub":"user123","sid":"session-1"}"#);
+
+    let claims = parse(&service.validate_refresh_token(&token).expect("Refresh token should validate"));
+    assert_eq!(claims["type"], "refresh");
+    assert_eq!(claims["sid"], "session-1");
+
+    assert!(service.validate_access_token(&token).is_err());
+}
+
+#[test]
+fn test_access_tokens_carry_unique_jti() {
+    let service = local_service();
+    // A caller-supplied jti does not survive; every access token gets its own
+    let claims = r#"{"sub":"user123","jti":"chosen-by-caller"}"#;
+
+    let first = parse(&service.validate_access_token(&service.issue_access_token("user123", claims)).unwrap());
+    let second = parse(&service.validate_access_token(&service.issue_access_token("user123", claims)).unwrap());
+
+    assert!(first["jti"].is_string());
+    assert_ne!(first["jti"], "chosen-by-caller");
+    assert_ne!(first["jti"], second["jti"]);
+}
+
+#[test]
+fn test_refresh_and_service_tokens_carry_unique_jti() {
+    let service = local_service();
+
+    let first = parse(&service.validate_refresh_token(&service.issue_refresh_token("user123", r#"{"sub":"user123"}"#)).unwrap());
+    let second = parse(&service.validate_refresh_token(&service.issue_refresh_token("user123", r#"{"sub":"user123"}"#)).unwrap());
+    assert!(first["jti"].is_string());
+    assert_ne!(first["jti"], second["jti"]);
+
+    let service_token = service.issue_service_token("billing", r#"{"sub":"billing"}"#);
+    assert!(parse(&service.validate_service_token(&service_token).unwrap())["jti"].is_string());
+}
+
+#[test]
+fn test_service_token_uses_service_key() {
+    let service = local_service()
+        .with_service_token_key(&[3u8; PASETO_LOCAL_KEY_SIZE])
+        .unwrap();
+    let token = service.issue_service_token("billing", r#"{"sub":"billing","aud":"auth"}"#);
+
+    let claims = parse(&service.validate_service_token(&token).expect("Service token should validate"));
+    assert_eq!(claims["sub"], "billing");
+    assert_eq!(claims["aud"], "auth");
+
+    // Not valid under the main key
+    assert!(local_service().validate_service_token(&token).is_err());
+}
+
+#[test]
+fn test_reset_token_round_trip() {
+    let service = public_service();
+    let token = service.issue_reset_token("user123", r#"{"sub":"user123","jti":"reset-1"}"#);
+
+    let claims = parse(&service.validate_reset_token(&token).expect("Reset token should validate"));
+    assert_eq!(claims["type"], "reset");
+    assert_eq!(claims["jti"], "reset-1");
+}
+
+#[test]
+fn test_verification_token_round_trip() {
+    let service = public_service();
+    let token = service.issue_verification_token("user123", r#"{"sub":"user123","jti":"verify-1"}"#);
+
+    let claims = parse(&service.validate_verification_token(&token).expect("Verification token should validate"));
+    assert_eq!(claims["type"], "verify");
+    assert_eq!(claims["jti"], "verify-1");
+
+    // Not usable as a bearer or reset token
+    assert!(service.validate_access_token(&token).is_err());
+    assert!(service.validate_reset_token(&token).is_err());
+}
+
+#[test]
+fn test_invalid_key_lengths_are_rejected() {
+    assert!(PasetoTokenService::local(&[1u8; 16]).is_err());
+    assert!(PasetoTokenService::public(&[1u8; 31]).is_err());
+}
+
+#[test]
+fn test_empty_token_is_rejected() {
+    assert!(local_service().validate_access_token(&Token::new("")).is_err());
+}
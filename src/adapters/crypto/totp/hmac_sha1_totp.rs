@@ -0,0 +1,68 @@
+//! HMAC-SHA1 TOTP verifier implementation.
+//!
+//! This module provides a concrete implementation of the `TotpVerifier` port
+//! following RFC 6238 with the RFC 4226 dynamic truncation.
+//!
+//! # Design Principles
+//!
+//! - **Standard compatible**: SHA1 and 6 digits, the defaults used by authenticator apps
+//! - **Constant-time comparison**: Codes are compared without early exit
+//! - **No secret leakage**: Secrets and codes are never logged
+
+use ring::hmac;
+
+use crate::core::usecases::ports::TotpVerifier;
+
+/// HMAC-SHA1 TOTP verifier.
+#[derive(Debug, Clone)]
+pub struct HmacSha1TotpVerifier {
+    digits: u32,
+}
+
+impl HmacSha1TotpVerifier {
+    /// Create a verifier producing codes with the given number of digits (6 to 8).
+    pub fn new(digits: u32) -> Self {
+        Self {
+            digits: digits.clamp(6, 8),
+        }
+    }
+
+    /// Compute the code for a secret at a given time step (RFC 4226 HOTP).
+    pub fn generate(&self, secret: &[u8], step: u64) -> String {
+        let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret);
+        let tag = hmac::sign(&key, &step.to_be_bytes());
+        let digest = tag.as_ref();
+
+        // Dynamic truncation
+        let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+        let binary = ((digest[offset] as u32 & 0x7f) << 24)
+            | ((digest[offset + 1] as u32) << 16)
+            | ((digest[offset + 2] as u32) << 8)
+            | (digest[offset + 3] as u32);
+
+        let code = binary % 10u32.pow(self.digits);
+        format!("{:0width$}", code, width = self.digits as usize)
+    }
+}
+
+impl Default for HmacSha1TotpVerifier {
+    fn default() -> Self {
+        Self::new(6)
+    }
+}
+
+impl TotpVerifier for HmacSha1TotpVerifier {
+    fn verify(&self, secret: &[u8], code: &str, step: u64) -> bool {
+        let expected = self.generate(secret, step);
+
+        if expected.len() != code.len() {
+            return false;
+        }
+
+        expected
+            .bytes()
+            .zip(code.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+    }
+}
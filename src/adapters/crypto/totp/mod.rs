@@ -0,0 +1,16 @@
+//! TOTP module for the crypto adapter.
+//!
+//! This module provides the HMAC-based one-time-password computation
+//! (RFC 4226 / RFC 6238). It implements the `TotpVerifier` port from the
+//! core domain.
+//!
+//! # Components
+//!
+//! - [`HmacSha1TotpVerifier`]: HMAC-SHA1 TOTP code verification
+
+pub mod hmac_sha1_totp;
+
+pub use hmac_sha1_totp::HmacSha1TotpVerifier;
+
+#[cfg(test)]
+mod tests;
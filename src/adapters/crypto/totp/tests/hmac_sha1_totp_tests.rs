@@ -0,0 +1,45 @@
+//! Tests for HMAC-SHA1 TOTP verifier.
+
+use crate::adapters::crypto::totp::HmacSha1TotpVerifier;
+use crate::core::usecases::ports::TotpVerifier;
+
+/// RFC 6238 Appendix B SHA1 seed
+const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+#[test]
+fn test_rfc6238_vectors_eight_digits() {
+    let verifier = HmacSha1TotpVerifier::new(8);
+
+    // (unix time, expected code) from RFC 6238 Appendix B
+    let vectors = [
+        (59u64, "94287082"),
+        (1111111109, "07081804"),
+        (1111111111, "14050471"),
+        (1234567890, "89005924"),
+        (2000000000, "69279037"),
+    ];
+
+    for (time, expected) in vectors {
+        assert_eq!(verifier.generate(RFC_SECRET, time / 30), expected, "time {}", time);
+    }
+}
+
+#[test]
+fn test_six_digit_code_is_truncation_of_rfc_vector() {
+    let verifier = HmacSha1TotpVerifier::default();
+    assert_eq!(verifier.generate(RFC_SECRET, 59 / 30), "287082");
+}
+
+#[test]
+fn test_verify_accepts_matching_code() {
+    let verifier = HmacSha1TotpVerifier::default();
+    assert!(verifier.verify(RFC_SECRET, "287082", 1));
+}
+
+#[test]
+fn test_verify_rejects_wrong_step_or_code() {
+    let verifier = HmacSha1TotpVerifier::default();
+    assert!(!verifier.verify(RFC_SECRET, "287082", 2));
+    assert!(!verifier.verify(RFC_SECRET, "287083", 1));
+    assert!(!verifier.verify(RFC_SECRET, "28708", 1));
+}
@@ -0,0 +1,3 @@
+//! Tests for the TOTP module.
+
+mod hmac_sha1_totp_tests;
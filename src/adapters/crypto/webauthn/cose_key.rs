@@ -0,0 +1,176 @@
+//! COSE key decoding for attested credential data.
+//!
+//! Authenticator data carries the new credential's public key as a
+//! CBOR-encoded `COSE_Key` (RFC 9052, section 7). Only what the supported
+//! algorithms need is read: a map with integer labels whose values are
+//! integers or byte strings. Anything else, including indefinite lengths and
+//! repeated labels, is rejected.
+
+use std::collections::HashMap;
+
+/// CBOR major type: unsigned integer.
+const MAJOR_UNSIGNED: u8 = 0;
+/// CBOR major type: negative integer.
+const MAJOR_NEGATIVE: u8 = 1;
+/// CBOR major type: byte string.
+const MAJOR_BYTES: u8 = 2;
+/// CBOR major type: text string.
+const MAJOR_TEXT: u8 = 3;
+/// CBOR major type: map.
+const MAJOR_MAP: u8 = 5;
+
+/// COSE key label: key type.
+const LABEL_KTY: i64 = 1;
+/// COSE key label: algorithm.
+const LABEL_ALG: i64 = 3;
+
+/// COSE key type: elliptic curve with x and y coordinates.
+const KTY_EC2: i64 = 2;
+/// COSE key type: RSA.
+const KTY_RSA: i64 = 3;
+
+/// EC2 key label: curve.
+const LABEL_EC2_CRV: i64 = -1;
+/// EC2 key label: x coordinate.
+const LABEL_EC2_X: i64 = -2;
+/// EC2 key label: y coordinate.
+const LABEL_EC2_Y: i64 = -3;
+/// COSE curve identifier for P-256.
+const CRV_P256: i64 = 1;
+/// Length of a P-256 coordinate.
+const P256_COORDINATE_LEN: usize = 32;
+
+/// RSA key label: modulus.
+const LABEL_RSA_N: i64 = -1;
+/// RSA key label: public exponent.
+const LABEL_RSA_E: i64 = -2;
+
+/// A credential public key read from a `COSE_Key`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CoseKey {
+    /// P-256 key, as its uncompressed point `0x04 || x || y`
+    P256 { alg: i64, point: Vec<u8> },
+    /// RSA key, as its big-endian modulus and exponent
+    Rsa { alg: i64, n: Vec<u8>, e: Vec<u8> },
+}
+
+impl CoseKey {
+    /// COSE algorithm the key is meant for.
+    pub(crate) fn alg(&self) -> i64 {
+        match self {
+            CoseKey::P256 { alg, .. } | CoseKey::Rsa { alg, .. } => *alg,
+        }
+    }
+}
+
+/// A decoded CBOR value, as far as a COSE key needs one.
+enum Value<'a> {
+    Int(i64),
+    Bytes(&'a [u8]),
+    Text,
+}
+
+/// Cursor over CBOR-encoded bytes.
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if self.data.len() < len {
+            return Err("COSE key is truncated".to_string());
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(taken)
+    }
+
+    /// Read an item head: its major type and argument.
+    fn head(&mut self) -> Result<(u8, u64), String> {
+        let initial = self.take(1)?[0];
+        let major = initial >> 5;
+        let argument = match initial & 0x1f {
+            info @ 0..=23 => u64::from(info),
+            24 => u64::from(self.take(1)?[0]),
+            25 => u64::from(u16::from_be_bytes(self.take(2)?.try_into().unwrap_or_default())),
+            26 => u64::from(u32::from_be_bytes(self.take(4)?.try_into().unwrap_or_default())),
+            27 => u64::from_be_bytes(self.take(8)?.try_into().unwrap_or_default()),
+            _ => return Err("unsupported CBOR length in COSE key".to_string()),
+        };
+        Ok((major, argument))
+    }
+
+    fn value(&mut self) -> Result<Value<'a>, String> {
+        let (major, argument) = self.head()?;
+        let int = |argument: u64| i64::try_from(argument).map_err(|_| "COSE key integer out of range".to_string());
+
+        match major {
+            MAJOR_UNSIGNED => Ok(Value::Int(int(argument)?)),
+            MAJOR_NEGATIVE => Ok(Value::Int(-1 - int(argument)?)),
+            MAJOR_BYTES => Ok(Value::Bytes(self.take(length(argument)?)?)),
+            MAJOR_TEXT => {
+                self.take(length(argument)?)?;
+                Ok(Value::Text)
+            }
+            _ => Err("unsupported CBOR item in COSE key".to_string()),
+        }
+    }
+}
+
+fn length(argument: u64) -> Result<usize, String> {
+    usize::try_from(argument).map_err(|_| "COSE key is truncated".to_string())
+}
+
+/// Decode the `COSE_Key` at the start of `data`.
+///
+/// Bytes after the key, such as authenticator extensions, are ignored.
+pub(crate) fn parse(data: &[u8]) -> Result<CoseKey, String> {
+    let mut reader = Reader { data };
+    let (major, entries) = reader.head()?;
+    if major != MAJOR_MAP {
+        return Err("COSE key is not a map".to_string());
+    }
+
+    let mut fields = HashMap::new();
+    for _ in 0..entries {
+        let Value::Int(label) = reader.value()? else {
+            return Err("COSE key label is not an integer".to_string());
+        };
+        if fields.insert(label, reader.value()?).is_some() {
+            return Err(format!("COSE key repeats label {}", label));
+        }
+    }
+
+    let int = |label: i64| match fields.get(&label) {
+        Some(Value::Int(value)) => Ok(*value),
+        _ => Err(format!("COSE key has no integer under label {}", label)),
+    };
+    let bytes = |label: i64| match fields.get(&label) {
+        Some(Value::Bytes(value)) => Ok(value.to_vec()),
+        _ => Err(format!("COSE key has no byte string under label {}", label)),
+    };
+
+    let alg = int(LABEL_ALG)?;
+    match int(LABEL_KTY)? {
+        KTY_EC2 => {
+            if int(LABEL_EC2_CRV)? != CRV_P256 {
+                return Err("COSE key is not on P-256".to_string());
+            }
+            let (x, y) = (bytes(LABEL_EC2_X)?, bytes(LABEL_EC2_Y)?);
+            if x.len() != P256_COORDINATE_LEN || y.len() != P256_COORDINATE_LEN {
+                return Err("COSE key has malformed P-256 coordinates".to_string());
+            }
+
+            let mut point = vec![0x04];
+            point.extend_from_slice(&x);
+            point.extend_from_slice(&y);
+            Ok(CoseKey::P256 { alg, point })
+        }
+        KTY_RSA => Ok(CoseKey::Rsa {
+            alg,
+            n: bytes(LABEL_RSA_N)?,
+            e: bytes(LABEL_RSA_E)?,
+        }),
+        kty => Err(format!("unsupported COSE key type {}", kty)),
+    }
+}
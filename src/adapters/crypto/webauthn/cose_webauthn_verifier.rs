@@ -0,0 +1,329 @@
+//! COSE-key WebAuthn verifier implementation.
+//!
+//! This module provides a concrete implementation of the `WebAuthnVerifier`
+//! port for passkeys whose attested COSE key signs with ECDSA P-256 /
+//! SHA-256 (COSE algorithm -7), the algorithm every platform authenticator
+//! supports, or with RSASSA-PKCS1 v1.5 / SHA-256 (COSE algorithm -257),
+//! which Windows Hello uses.
+//!
+//! # Design Principles
+//!
+//! - **Relying party owned here**: The RP ID and allowed origin are adapter
+//!   configuration; the core only sees verified results
+//! - **JSON responses**: Responses are the WebAuthn Level 3 JSON form of
+//!   `PublicKeyCredential`. The public key its registration response carries
+//!   as SPKI must match the COSE key in the authenticator data, which is what
+//!   the authenticator actually attested
+//! - **Stored keys tell the algorithm**: ES256 keys are kept as an
+//!   uncompressed point, RS256 keys as a DER `RSAPublicKey`
+//! - **No attestation**: Registrations are expected with
+//!   `attestation: "none"`; attestation statements are not checked
+//! - **Fail closed**: Anything malformed, mismatched or unsigned is rejected
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use pkcs1::EncodeRsaPublicKey;
+use ring::signature::{ECDSA_P256_SHA256_ASN1, RSA_PKCS1_2048_8192_SHA256, UnparsedPublicKey, VerificationAlgorithm};
+use rsa::pkcs8::DecodePublicKey;
+use rsa::traits::PublicKeyParts;
+use rsa::{BigUint, RsaPublicKey};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use super::cose_key::{self, CoseKey};
+use crate::core::usecases::ports::{AttestedPasskey, VerifiedAssertion, WebAuthnVerifier};
+
+/// COSE algorithm identifier for ES256.
+const COSE_ALG_ES256: i64 = -7;
+/// COSE algorithm identifier for RS256.
+const COSE_ALG_RS256: i64 = -257;
+
+/// DER prefix of a P-256 `SubjectPublicKeyInfo` holding an uncompressed point.
+const P256_SPKI_PREFIX: [u8; 26] = [
+    0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48,
+    0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00,
+];
+
+/// Length of an uncompressed P-256 point (`0x04 || x || y`).
+const P256_POINT_LEN: usize = 65;
+
+/// Authenticator data flag: user present.
+const FLAG_USER_PRESENT: u8 = 0x01;
+/// Authenticator data flag: user verified.
+const FLAG_USER_VERIFIED: u8 = 0x04;
+/// Authenticator data flag: attested credential data included.
+const FLAG_ATTESTED_CREDENTIAL: u8 = 0x40;
+
+/// Length of the fixed authenticator data header (RP ID hash, flags, counter).
+const AUTH_DATA_HEADER_LEN: usize = 37;
+/// Length of the AAGUID preceding the credential ID in attested credential data.
+const AAGUID_LEN: usize = 16;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RegistrationResponse {
+    raw_id: String,
+    #[serde(rename = "type")]
+    credential_type: String,
+    response: AttestationResponse,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AttestationResponse {
+    #[serde(rename = "clientDataJSON")]
+    client_data_json: String,
+    authenticator_data: String,
+    public_key: Option<String>,
+    public_key_algorithm: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AssertionResponse {
+    raw_id: String,
+    #[serde(rename = "type")]
+    credential_type: String,
+    response: AssertionResponseData,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AssertionResponseData {
+    #[serde(rename = "clientDataJSON")]
+    client_data_json: String,
+    authenticator_data: String,
+    signature: String,
+}
+
+#[derive(Deserialize)]
+struct ClientData {
+    #[serde(rename = "type")]
+    ceremony: String,
+    challenge: String,
+    origin: String,
+}
+
+/// The fixed header of authenticator data.
+struct AuthenticatorData<'a> {
+    rp_id_hash: &'a [u8],
+    flags: u8,
+    sign_count: u32,
+    rest: &'a [u8],
+}
+
+/// WebAuthn verifier for passkeys with an ES256 or RS256 COSE key.
+#[derive(Debug, Clone)]
+pub struct CoseWebAuthnVerifier {
+    rp_id: String,
+    origin: String,
+    require_user_verification: bool,
+}
+
+impl CoseWebAuthnVerifier {
+    /// Create a verifier for the relying party `rp_id` (e.g. `example.com`),
+    /// accepting ceremonies from `origin` (e.g. `https://login.example.com`).
+    pub fn new(rp_id: impl Into<String>, origin: impl Into<String>) -> Self {
+        Self {
+            rp_id: rp_id.into(),
+            origin: origin.into().trim_end_matches('/').to_string(),
+            require_user_verification: false,
+        }
+    }
+
+    /// Reject ceremonies in which the authenticator did not verify the user.
+    pub fn with_user_verification_required(mut self, required: bool) -> Self {
+        self.require_user_verification = required;
+        self
+    }
+
+    /// Decode and check the client data of a ceremony of type `ceremony`.
+    fn client_data(&self, encoded: &str, ceremony: &str) -> Result<(Vec<u8>, ClientData), String> {
+        let raw = decode(encoded, "clientDataJSON")?;
+        let client_data: ClientData =
+            serde_json::from_slice(&raw).map_err(|e| format!("malformed clientDataJSON: {}", e))?;
+
+        if client_data.ceremony != ceremony {
+            return Err(format!("unexpected ceremony type '{}'", client_data.ceremony));
+        }
+        if client_data.origin != self.origin {
+            return Err(format!("unexpected origin '{}'", client_data.origin));
+        }
+
+        Ok((raw, client_data))
+    }
+
+    /// Check the RP ID hash and flags of authenticator data.
+    fn check_authenticator_data(&self, auth_data: &AuthenticatorData<'_>) -> Result<(), String> {
+        let expected = Sha256::digest(self.rp_id.as_bytes());
+        if auth_data.rp_id_hash != expected.as_slice() {
+            return Err("authenticator data is for another RP ID".to_string());
+        }
+        if auth_data.flags & FLAG_USER_PRESENT == 0 {
+            return Err("user presence flag not set".to_string());
+        }
+        if self.require_user_verification && auth_data.flags & FLAG_USER_VERIFIED == 0 {
+            return Err("user verification flag not set".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Decode a base64url field, accepting optional padding.
+fn decode(value: &str, field: &str) -> Result<Vec<u8>, String> {
+    URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .map_err(|_| format!("{} is not base64url", field))
+}
+
+/// Split authenticator data into its fixed header and the remainder.
+fn parse_authenticator_data(raw: &[u8]) -> Result<AuthenticatorData<'_>, String> {
+    if raw.len() < AUTH_DATA_HEADER_LEN {
+        return Err("authenticator data is truncated".to_string());
+    }
+
+    Ok(AuthenticatorData {
+        rp_id_hash: &raw[..32],
+        flags: raw[32],
+        sign_count: u32::from_be_bytes([raw[33], raw[34], raw[35], raw[36]]),
+        rest: &raw[AUTH_DATA_HEADER_LEN..],
+    })
+}
+
+/// Credential ID and COSE key from attested credential data (AAGUID,
+/// length, ID, key).
+fn attested_credential(rest: &[u8]) -> Result<(&[u8], CoseKey), String> {
+    let id_start = AAGUID_LEN + 2;
+    if rest.len() < id_start {
+        return Err("attested credential data is truncated".to_string());
+    }
+
+    let id_len = u16::from_be_bytes([rest[AAGUID_LEN], rest[AAGUID_LEN + 1]]) as usize;
+    let credential_id = rest
+        .get(id_start..id_start + id_len)
+        .ok_or_else(|| "attested credential data is truncated".to_string())?;
+    Ok((credential_id, cose_key::parse(&rest[id_start + id_len..])?))
+}
+
+/// The uncompressed point inside a P-256 `SubjectPublicKeyInfo`.
+fn p256_point(spki: &[u8]) -> Result<Vec<u8>, String> {
+    match spki.strip_prefix(P256_SPKI_PREFIX.as_slice()) {
+        Some(point) if point.len() == P256_POINT_LEN && point[0] == 0x04 => Ok(point.to_vec()),
+        _ => Err("public key is not an uncompressed P-256 key".to_string()),
+    }
+}
+
+/// The key to store for `spki`, once it is checked to be `attested`.
+fn stored_public_key(spki: &[u8], attested: &CoseKey) -> Result<Vec<u8>, String> {
+    match attested {
+        CoseKey::P256 { point, .. } => {
+            if p256_point(spki)? != *point {
+                return Err("public key does not match authenticator data".to_string());
+            }
+            Ok(point.clone())
+        }
+        CoseKey::Rsa { n, e, .. } => {
+            let key = RsaPublicKey::from_public_key_der(spki)
+                .map_err(|_| "public key is not an RSA key".to_string())?;
+            if *key.n() != BigUint::from_bytes_be(n) || *key.e() != BigUint::from_bytes_be(e) {
+                return Err("public key does not match authenticator data".to_string());
+            }
+            key.to_pkcs1_der()
+                .map(|der| der.as_bytes().to_vec())
+                .map_err(|e| format!("public key cannot be encoded: {}", e))
+        }
+    }
+}
+
+/// Signature algorithm for a stored key, told apart by its encoding.
+fn signature_algorithm(public_key: &[u8]) -> &'static dyn VerificationAlgorithm {
+    if public_key.len() == P256_POINT_LEN && public_key[0] == 0x04 {
+        &ECDSA_P256_SHA256_ASN1
+    } else {
+        &RSA_PKCS1_2048_8192_SHA256
+    }
+}
+
+impl WebAuthnVerifier for CoseWebAuthnVerifier {
+    fn verify_registration(&self, response: &str) -> Result<AttestedPasskey, String> {
+        let response: RegistrationResponse =
+            serde_json::from_str(response).map_err(|e| format!("malformed registration response: {}", e))?;
+        if response.credential_type != "public-key" {
+            return Err(format!("unexpected credential type '{}'", response.credential_type));
+        }
+
+        let (_, client_data) = self.client_data(&response.response.client_data_json, "webauthn.create")?;
+
+        let raw_auth_data = decode(&response.response.authenticator_data, "authenticatorData")?;
+        let auth_data = parse_authenticator_data(&raw_auth_data)?;
+        self.check_authenticator_data(&auth_data)?;
+        if auth_data.flags & FLAG_ATTESTED_CREDENTIAL == 0 {
+            return Err("authenticator data carries no credential".to_string());
+        }
+
+        let raw_id = decode(&response.raw_id, "rawId")?;
+        let (credential_id, attested_key) = attested_credential(auth_data.rest)?;
+        if credential_id != raw_id.as_slice() {
+            return Err("credential ID does not match authenticator data".to_string());
+        }
+
+        let algorithm = response.response.public_key_algorithm;
+        if algorithm != COSE_ALG_ES256 && algorithm != COSE_ALG_RS256 {
+            return Err(format!("unsupported public key algorithm {}", algorithm));
+        }
+        let key_matches_algorithm = matches!(
+            (&attested_key, algorithm),
+            (CoseKey::P256 { .. }, COSE_ALG_ES256) | (CoseKey::Rsa { .. }, COSE_ALG_RS256)
+        );
+        if attested_key.alg() != algorithm || !key_matches_algorithm {
+            return Err("public key algorithm does not match authenticator data".to_string());
+        }
+        let spki = response
+            .response
+            .public_key
+            .as_deref()
+            .ok_or_else(|| "registration response carries no public key".to_string())?;
+        let public_key = stored_public_key(&decode(spki, "publicKey")?, &attested_key)?;
+
+        Ok(AttestedPasskey {
+            credential_id: URL_SAFE_NO_PAD.encode(raw_id),
+            public_key,
+            sign_count: auth_data.sign_count,
+            challenge: client_data.challenge,
+        })
+    }
+
+    fn assertion_credential_id(&self, response: &str) -> Result<String, String> {
+        let response: AssertionResponse =
+            serde_json::from_str(response).map_err(|e| format!("malformed assertion response: {}", e))?;
+        Ok(URL_SAFE_NO_PAD.encode(decode(&response.raw_id, "rawId")?))
+    }
+
+    fn verify_assertion(&self, response: &str, public_key: &[u8]) -> Result<VerifiedAssertion, String> {
+        let response: AssertionResponse =
+            serde_json::from_str(response).map_err(|e| format!("malformed assertion response: {}", e))?;
+        if response.credential_type != "public-key" {
+            return Err(format!("unexpected credential type '{}'", response.credential_type));
+        }
+
+        let (raw_client_data, client_data) = self.client_data(&response.response.client_data_json, "webauthn.get")?;
+
+        let raw_auth_data = decode(&response.response.authenticator_data, "authenticatorData")?;
+        let auth_data = parse_authenticator_data(&raw_auth_data)?;
+        self.check_authenticator_data(&auth_data)?;
+
+        // The signature covers authenticatorData || SHA-256(clientDataJSON)
+        let signature = decode(&response.response.signature, "signature")?;
+        let mut signed = raw_auth_data.clone();
+        signed.extend_from_slice(&Sha256::digest(&raw_client_data));
+        UnparsedPublicKey::new(signature_algorithm(public_key), public_key)
+            .verify(&signed, &signature)
+            .map_err(|_| "signature does not verify".to_string())?;
+
+        Ok(VerifiedAssertion {
+            credential_id: URL_SAFE_NO_PAD.encode(decode(&response.raw_id, "rawId")?),
+            sign_count: auth_data.sign_count,
+            challenge: client_data.challenge,
+            user_verified: auth_data.flags & FLAG_USER_VERIFIED != 0,
+        })
+    }
+}
@@ -0,0 +1,18 @@
+//! WebAuthn module for the crypto adapter.
+//!
+//! This module verifies passkey registration and assertion ceremonies. It
+//! implements the `WebAuthnVerifier` port from the core domain and holds the
+//! relying-party configuration.
+//!
+//! # Components
+//!
+//! - [`CoseWebAuthnVerifier`]: Passkey verification for ES256 (P-256) and
+//!   RS256 COSE keys
+
+mod cose_key;
+pub mod cose_webauthn_verifier;
+
+pub use cose_webauthn_verifier::CoseWebAuthnVerifier;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,179 @@
+//! Tests for COSE key decoding.
+
+use crate::adapters::crypto::webauthn::cose_key::{self, CoseKey};
+
+/// An ES256 COSE key with the given coordinates.
+fn ec2_key(x: &[u8], y: &[u8]) -> Vec<u8> {
+    let mut key = vec![0xa5, 0x01, 0x02, 0x03, 0x26, 0x20, 0x01];
+    key.extend([0x21, 0x58, x.len() as u8]);
+    key.extend_from_slice(x);
+    key.extend([0x22, 0x58, y.len() as u8]);
+    key.extend_from_slice(y);
+    key
+}
+
+#[test]
+fn test_ec2_key_yields_uncompressed_point() {
+    let mut data = ec2_key(&[1; 32], &[2; 32]);
+    // Extensions may follow the key in authenticator data
+    data.extend([0xa0]);
+
+    let key = cose_key::parse(&data).expect("Key should decode");
+
+    assert_eq!(key.alg(), -7);
+    assert_eq!(key, CoseKey::P256 { alg: -7, point: [vec![0x04], vec![1; 32], vec![2; 32]].concat() });
+}
+
+#[test]
+fn test_rsa_key_yields_modulus_and_exponent() {
+    let data = [0xa4, 0x01, 0x03, 0x03, 0x39, 0x01, 0x00, 0x20, 0x42, 0xc0, 0x01, 0x21, 0x43, 0x01, 0x00, 0x01];
+
+    let key = cose_key::parse(&data).expect("Key should decode");
+
+    assert_eq!(key, CoseKey::Rsa { alg: -257, n: vec![0xc0, 0x01], e: vec![0x01, 0x00, 0x01] });
+}
+
+#[test]
+fn test_truncated_key_is_rejected() {
+    let data = ec2_key(&[1; 32], &[2; 32]);
+
+    assert!(cose_key::parse(&data[..data.len() - 1]).is_err());
+}
+
+#[test]
+fn test_key_off_p256_is_rejected() {
+    let mut data = ec2_key(&[1; 32], &[2; 32]);
+    // crv: P-384
+    data[6] = 0x02;
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_unsupported_key_type_is_rejected() {
+    // kty: OKP (Ed25519)
+    let data = [0xa2, 0x01, 0x01, 0x03, 0x27];
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_empty_input_is_rejected() {
+    assert!(cose_key::parse(&[]).is_err());
+}
+
+#[test]
+fn test_key_that_is_not_a_map_is_rejected() {
+    // An array holding the integer 2
+    let data = [0x81, 0x02];
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_indefinite_length_map_is_rejected() {
+    let mut data = ec2_key(&[1; 32], &[2; 32]);
+    data[0] = 0xbf;
+    data.push(0xff);
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_text_label_is_rejected() {
+    // {"kty": 2}
+    let data = [0xa1, 0x63, b'k', b't', b'y', 0x02];
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_repeated_label_is_rejected() {
+    let mut data = ec2_key(&[1; 32], &[2; 32]);
+    // A sixth entry repeating alg, as RS256
+    data[0] = 0xa6;
+    data.extend([0x03, 0x39, 0x01, 0x00]);
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_nested_value_is_rejected() {
+    // {1: 2, 3: -7, -1: {}}
+    let data = [0xa3, 0x01, 0x02, 0x03, 0x26, 0x20, 0xa0];
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_byte_string_longer_than_input_is_rejected() {
+    // {-2: h'...'} claiming 2^64 - 1 bytes
+    let data = [0xa1, 0x21, 0x5b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00];
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_map_with_more_entries_than_input_is_rejected() {
+    // A map claiming 2^32 - 1 entries, holding one
+    let data = [0xba, 0xff, 0xff, 0xff, 0xff, 0x01, 0x02];
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_negative_integer_out_of_range_is_rejected() {
+    // {3: -2^64}
+    let data = [0xa1, 0x03, 0x3b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_key_without_algorithm_is_rejected() {
+    let mut data = ec2_key(&[1; 32], &[2; 32]);
+    // Relabel alg as an unused label
+    data[3] = 0x04;
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_short_coordinate_is_rejected() {
+    assert!(cose_key::parse(&ec2_key(&[1; 31], &[2; 32])).is_err());
+    assert!(cose_key::parse(&ec2_key(&[1; 32], &[2; 33])).is_err());
+}
+
+#[test]
+fn test_rsa_key_without_exponent_is_rejected() {
+    let data = [0xa3, 0x01, 0x03, 0x03, 0x39, 0x01, 0x00, 0x20, 0x42, 0xc0, 0x01];
+
+    assert!(cose_key::parse(&data).is_err());
+}
+
+#[test]
+fn test_every_truncation_is_rejected() {
+    let data = ec2_key(&[1; 32], &[2; 32]);
+
+    for len in 0..data.len() {
+        assert!(cose_key::parse(&data[..len]).is_err(), "Truncation to {} bytes should be rejected", len);
+    }
+}
+
+#[test]
+fn test_corrupted_keys_never_panic() {
+    let keys = [
+        ec2_key(&[1; 32], &[2; 32]),
+        vec![0xa4, 0x01, 0x03, 0x03, 0x39, 0x01, 0x00, 0x20, 0x42, 0xc0, 0x01, 0x21, 0x43, 0x01, 0x00, 0x01],
+    ];
+
+    for key in keys {
+        for index in 0..key.len() {
+            for byte in 0..=u8::MAX {
+                let mut data = key.clone();
+                data[index] = byte;
+                let _ = cose_key::parse(&data);
+            }
+        }
+    }
+}
@@ -0,0 +1,515 @@
+//! Tests for the COSE-key WebAuthn verifier.
+
+use base64::{Engine as _, engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD}};
+use ring::rand::SystemRandom;
+use ring::rsa::PublicKeyComponents;
+use ring::signature::{ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair, KeyPair, RSA_PKCS1_SHA256, RsaKeyPair};
+use rsa::pkcs8::EncodePublicKey;
+use rsa::{BigUint, RsaPublicKey};
+use sha2::{Digest, Sha256};
+
+use crate::adapters::crypto::webauthn::CoseWebAuthnVerifier;
+use crate::core::usecases::ports::WebAuthnVerifier;
+
+const RP_ID: &str = "example.com";
+const ORIGIN: &str = "https://login.example.com";
+const CHALLENGE: &str = "c2VydmVyLWNoYWxsZW5nZQ";
+const CREDENTIAL_ID: &[u8] = b"credential-1";
+
+/// DER prefix of a P-256 `SubjectPublicKeyInfo`, as browsers return it.
+const SPKI_PREFIX: [u8; 26] = [
+    0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a, 0x86, 0x48,
+    0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00,
+];
+
+/// A 2048-bit RSA test key, PKCS#1 DER, base64-encoded.
+const RSA_PRIVATE_KEY: &str = concat!(
+    "MIIEpAIBAAKCAQEAoU2ZmOqjPoutaK6X0kzqH9Lj/YbM1JqtmgaL52ekv0zlZt3YRclKoiHwiMiFH8knw8R99z01/hW7qIllVD7N",
+    "VK4d/+jOmSjK4rno690o++t4jj8nFbiBV7ch9Upf6Bm6jPQAUGEgl8MJFfuV99hCnUFHiaTOjNQd8eCnDNlqzX1GvISYhXRK5qb4",
+    "ZQGvZqRFcmJCyqUL5Il8nbRYr7VrwYMMvuLYh/FvG0omhef665/5dgdMOnqyWNHpFQ1PCvYKZLPyKbHatEZXSED8DgwQFbkBs93Q",
+    "AUB7nRsUVMAy78KT7cmi8NKBsfDi17QYtxxg5vQ+IeKOYV+EREh0lZaCzQIDAQABAoIBAAxe/ucVz9FYixiFPiBN79g4Uhi8AZz+",
+    "A/nVLgkLD5KbG4OpLhDOywo7n3ljek+hcOBN2KS2rXEVn5/hxxi6pwArSSJlwOVJlfiPmPKbXVeni/EfnNLIpNA3u8NWofETesBG",
+    "vSNtCj/kIHVEJ2YBJb1ufUXBkxSFTDVPLTYKEsE8ZDvX19yX9sBIXJxNJ2CF/ZJafuyZW9QvMWRF8YcC+K4sjyrMGTyGuHCXHCXh",
+    "ve1lK9EHAiMND3zB5uSHCMoWwEC6ZGR429ID8GjNK7St1HQmilqeXQ9bhZz6/Sag9XN4mn1LASNLrTNv3gkidwGiSwEQ05zOR4zb",
+    "C60D8/JSfkECgYEA1voevrra8NtAl8elx+CJuz0vevX1BBxibaOS61QrIJEvmHXc25h5QjA7B7WGEkP/VWYCEMQ0gXi+mkewuTlr",
+    "Q5N/Z5D2y6MpHoUKW+HXggdXJgcHv3SRzLJ+9kGe6Fkcf7dryzTq1JjiufhkuPTvXT+3VDN9uLX6czDdQWhUMt0CgYEAwBVyWBR1",
+    "fsVP41Kewp1gs7d2HX5CTTVH/73g0miSAAXnlJMmRuynRae7tR0xcs8G1UlYNW6bfb2XflnAEqEHDLjcaAVWn54bApFA9Lof9Gm8",
+    "ti5nbFwZB7/zBHEK6UlzTU/ovuGJOTA5zROBr/zrOpcimTTFJTwp1wcLVM/LOLECgYEAvkvoPVOwjTa4ReMP6A/mO92ckw74Fex+",
+    "YKlEW5ZFGu4KIL5sg+LDrAh/qIJSLQcOFe1XdLoJ9HYK2w0M7dlbDxWY2Pekz3wd4QtJsS4Ik+ylg18Dx8GLcpSq+Jf775c6OAAI",
+    "pnewTAMN6CtuFZ+T54qIYTu3VXOZQLCHmuecaO0CgYBFWTjgHfrRmxEdpF2XK9SW7dyiB0yOrXbiGT6LGJ/ljDwwwvOl1ZkKp+U4",
+    "HWMV7TKtXH6RQ+61dfa08lhLIYH3EOLaV1sP47gitCh8cppI1sgACj7iu1l6F834Q4vJ7+/F8Ny51ZmCYSvtI2bGEA0pKRmfsJKg",
+    "v9jnPi7UHvVi8QKBgQC+fX4C/9Zj7uWJP2d+OdbwpjqF9fTs4swFDS+AMySR0M0ubCbIyQ0W+Lfhw6O73lYsgKFXfFFi64fe6PDp",
+    "r862f6X7kyaf+eOj5GrTBt1UJH1oCsD0LdofTxDWDX1dgmoIoaTq892tkGMkiWT0nS/YuVG13UAzIfqJmcJobnUCqw==",
+);
+
+/// Key an authenticator signs with.
+enum Signer {
+    Es256(EcdsaKeyPair),
+    Rs256(RsaKeyPair),
+}
+
+/// A software authenticator producing ES256 or RS256 responses.
+struct Authenticator {
+    signer: Signer,
+    rng: SystemRandom,
+}
+
+impl Authenticator {
+    fn new() -> Self {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+        let key_pair = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng).unwrap();
+        Self { signer: Signer::Es256(key_pair), rng }
+    }
+
+    fn rs256() -> Self {
+        let key_pair = RsaKeyPair::from_der(&STANDARD.decode(RSA_PRIVATE_KEY).unwrap()).unwrap();
+        Self { signer: Signer::Rs256(key_pair), rng: SystemRandom::new() }
+    }
+
+    /// The public key as the verifier stores it.
+    fn public_key(&self) -> Vec<u8> {
+        match &self.signer {
+            Signer::Es256(key_pair) => key_pair.public_key().as_ref().to_vec(),
+            Signer::Rs256(key_pair) => key_pair.public().as_ref().to_vec(),
+        }
+    }
+
+    fn algorithm(&self) -> i64 {
+        match self.signer {
+            Signer::Es256(_) => -7,
+            Signer::Rs256(_) => -257,
+        }
+    }
+
+    /// The public key as a browser reports it.
+    fn spki(&self) -> Vec<u8> {
+        match &self.signer {
+            Signer::Es256(_) => [SPKI_PREFIX.as_slice(), &self.public_key()].concat(),
+            Signer::Rs256(key_pair) => {
+                let components = PublicKeyComponents::<Vec<u8>>::from(key_pair.public());
+                RsaPublicKey::new(BigUint::from_bytes_be(&components.n), BigUint::from_bytes_be(&components.e))
+                    .unwrap()
+                    .to_public_key_der()
+                    .unwrap()
+                    .as_bytes()
+                    .to_vec()
+            }
+        }
+    }
+
+    /// The public key as the authenticator attests it.
+    fn cose_key(&self) -> Vec<u8> {
+        match &self.signer {
+            Signer::Es256(_) => {
+                let point = self.public_key();
+                let mut key = vec![0xa5, 0x01, 0x02, 0x03, 0x26, 0x20, 0x01];
+                key.extend(cbor_bytes(0x21, &point[1..33]));
+                key.extend(cbor_bytes(0x22, &point[33..]));
+                key
+            }
+            Signer::Rs256(key_pair) => {
+                let components = PublicKeyComponents::<Vec<u8>>::from(key_pair.public());
+                let mut key = vec![0xa4, 0x01, 0x03, 0x03, 0x39, 0x01, 0x00];
+                key.extend(cbor_bytes(0x20, &components.n));
+                key.extend(cbor_bytes(0x21, &components.e));
+                key
+            }
+        }
+    }
+
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        match &self.signer {
+            Signer::Es256(key_pair) => key_pair.sign(&self.rng, message).unwrap().as_ref().to_vec(),
+            Signer::Rs256(key_pair) => {
+                let mut signature = vec![0; key_pair.public().modulus_len()];
+                key_pair.sign(&RSA_PKCS1_SHA256, &self.rng, message, &mut signature).unwrap();
+                signature
+            }
+        }
+    }
+
+    fn register(&self, rp_id: &str, origin: &str) -> String {
+        self.register_with(rp_id, origin, &self.spki(), self.algorithm())
+    }
+
+    fn register_with(&self, rp_id: &str, origin: &str, spki: &[u8], algorithm: i64) -> String {
+        let mut auth_data = auth_data(rp_id, 0x01 | 0x04 | 0x40, 0);
+        auth_data.extend_from_slice(&[0u8; 16]);
+        auth_data.extend_from_slice(&(CREDENTIAL_ID.len() as u16).to_be_bytes());
+        auth_data.extend_from_slice(CREDENTIAL_ID);
+        auth_data.extend(self.cose_key());
+
+        serde_json::json!({
+            "id": URL_SAFE_NO_PAD.encode(CREDENTIAL_ID),
+            "rawId": URL_SAFE_NO_PAD.encode(CREDENTIAL_ID),
+            "type": "public-key",
+            "response": {
+                "clientDataJSON": client_data("webauthn.create", origin),
+                "authenticatorData": URL_SAFE_NO_PAD.encode(auth_data),
+                "publicKey": URL_SAFE_NO_PAD.encode(spki),
+                "publicKeyAlgorithm": algorithm,
+                "attestationObject": "",
+            },
+        })
+        .to_string()
+    }
+
+    fn assert(&self, rp_id: &str, ceremony: &str, sign_count: u32) -> String {
+        let auth_data = auth_data(rp_id, 0x01 | 0x04, sign_count);
+        let client_data = client_data(ceremony, ORIGIN);
+
+        let mut signed = auth_data.clone();
+        signed.extend_from_slice(&Sha256::digest(URL_SAFE_NO_PAD.decode(&client_data).unwrap()));
+        let signature = self.sign(&signed);
+
+        serde_json::json!({
+            "id": URL_SAFE_NO_PAD.encode(CREDENTIAL_ID),
+            "rawId": URL_SAFE_NO_PAD.encode(CREDENTIAL_ID),
+            "type": "public-key",
+            "response": {
+                "clientDataJSON": client_data,
+                "authenticatorData": URL_SAFE_NO_PAD.encode(auth_data),
+                "signature": URL_SAFE_NO_PAD.encode(signature),
+            },
+        })
+        .to_string()
+    }
+}
+
+/// A CBOR map entry: the one-byte `label` and a byte string value.
+fn cbor_bytes(label: u8, value: &[u8]) -> Vec<u8> {
+    let mut entry = vec![label];
+    match value.len() {
+        len @ 0..=23 => entry.push(0x40 | len as u8),
+        len @ 24..=255 => entry.extend([0x58, len as u8]),
+        len => {
+            entry.push(0x59);
+            entry.extend((len as u16).to_be_bytes());
+        }
+    }
+    entry.extend_from_slice(value);
+    entry
+}
+
+fn auth_data(rp_id: &str, flags: u8, sign_count: u32) -> Vec<u8> {
+    let mut data = Sha256::digest(rp_id.as_bytes()).to_vec();
+    data.push(flags);
+    data.extend_from_slice(&sign_count.to_be_bytes());
+    data
+}
+
+fn client_data(ceremony: &str, origin: &str) -> String {
+    let json = serde_json::json!({ "type": ceremony, "challenge": CHALLENGE, "origin": origin });
+    URL_SAFE_NO_PAD.encode(json.to_string())
+}
+
+/// `response` with the field at JSON `pointer` replaced by `value`.
+fn with_field(response: &str, pointer: &str, value: serde_json::Value) -> String {
+    let mut response: serde_json::Value = serde_json::from_str(response).unwrap();
+    *response.pointer_mut(pointer).unwrap() = value;
+    response.to_string()
+}
+
+/// The decoded authenticator data of `response`.
+fn authenticator_data(response: &str) -> Vec<u8> {
+    let response: serde_json::Value = serde_json::from_str(response).unwrap();
+    URL_SAFE_NO_PAD
+        .decode(response["response"]["authenticatorData"].as_str().unwrap())
+        .unwrap()
+}
+
+fn verifier() -> CoseWebAuthnVerifier {
+    CoseWebAuthnVerifier::new(RP_ID, ORIGIN)
+}
+
+#[test]
+fn test_registration_yields_credential_and_key() {
+    let authenticator = Authenticator::new();
+
+    let attested = verifier()
+        .verify_registration(&authenticator.register(RP_ID, ORIGIN))
+        .expect("Registration should verify");
+
+    assert_eq!(attested.credential_id, URL_SAFE_NO_PAD.encode(CREDENTIAL_ID));
+    assert_eq!(attested.public_key, authenticator.public_key());
+    assert_eq!(attested.sign_count, 0);
+    assert_eq!(attested.challenge, CHALLENGE);
+}
+
+#[test]
+fn test_registration_from_other_origin_is_rejected() {
+    let authenticator = Authenticator::new();
+
+    let result = verifier().verify_registration(&authenticator.register(RP_ID, "https://evil.example.net"));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_registration_for_other_rp_id_is_rejected() {
+    let authenticator = Authenticator::new();
+
+    let result = verifier().verify_registration(&authenticator.register("evil.example.net", ORIGIN));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_assertion_verifies_with_registered_key() {
+    let authenticator = Authenticator::new();
+    let response = authenticator.assert(RP_ID, "webauthn.get", 7);
+
+    assert_eq!(
+        verifier().assertion_credential_id(&response).unwrap(),
+        URL_SAFE_NO_PAD.encode(CREDENTIAL_ID)
+    );
+    let assertion = verifier()
+        .verify_assertion(&response, &authenticator.public_key())
+        .expect("Assertion should verify");
+
+    assert_eq!(assertion.sign_count, 7);
+    assert_eq!(assertion.challenge, CHALLENGE);
+    assert!(assertion.user_verified);
+}
+
+#[test]
+fn test_assertion_under_other_key_is_rejected() {
+    let authenticator = Authenticator::new();
+    let other = Authenticator::new();
+
+    let result = verifier().verify_assertion(&authenticator.assert(RP_ID, "webauthn.get", 1), &other.public_key());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_assertion_for_other_rp_id_is_rejected() {
+    let authenticator = Authenticator::new();
+
+    let result = verifier().verify_assertion(
+        &authenticator.assert("evil.example.net", "webauthn.get", 1),
+        &authenticator.public_key(),
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_registration_client_data_is_not_an_assertion() {
+    let authenticator = Authenticator::new();
+
+    let result = verifier().verify_assertion(
+        &authenticator.assert(RP_ID, "webauthn.create", 1),
+        &authenticator.public_key(),
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_registration_with_key_other_than_attested_is_rejected() {
+    let authenticator = Authenticator::new();
+    let other = Authenticator::new();
+
+    let result = verifier().verify_registration(&authenticator.register_with(RP_ID, ORIGIN, &other.spki(), -7));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_registration_with_algorithm_other_than_attested_is_rejected() {
+    let authenticator = Authenticator::new();
+
+    let result = verifier().verify_registration(&authenticator.register_with(RP_ID, ORIGIN, &authenticator.spki(), -257));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_rs256_registration_and_assertion() {
+    let authenticator = Authenticator::rs256();
+
+    let attested = verifier()
+        .verify_registration(&authenticator.register(RP_ID, ORIGIN))
+        .expect("Registration should verify");
+    assert_eq!(attested.public_key, authenticator.public_key());
+
+    let assertion = verifier()
+        .verify_assertion(&authenticator.assert(RP_ID, "webauthn.get", 3), &attested.public_key)
+        .expect("Assertion should verify");
+    assert_eq!(assertion.sign_count, 3);
+}
+
+#[test]
+fn test_rs256_assertion_under_es256_key_is_rejected() {
+    let authenticator = Authenticator::rs256();
+    let other = Authenticator::new();
+
+    let result = verifier().verify_assertion(&authenticator.assert(RP_ID, "webauthn.get", 1), &other.public_key());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_malformed_responses_are_rejected() {
+    let authenticator = Authenticator::new();
+
+    for response in ["", "{", "[]", "null", "{\"rawId\": 1}"] {
+        assert!(verifier().verify_registration(response).is_err());
+        assert!(verifier().assertion_credential_id(response).is_err());
+        assert!(verifier().verify_assertion(response, &authenticator.public_key()).is_err());
+    }
+}
+
+#[test]
+fn test_credential_type_other_than_public_key_is_rejected() {
+    let authenticator = Authenticator::new();
+    let registration = with_field(&authenticator.register(RP_ID, ORIGIN), "/type", "password".into());
+    let assertion = with_field(&authenticator.assert(RP_ID, "webauthn.get", 1), "/type", "password".into());
+
+    assert!(verifier().verify_registration(&registration).is_err());
+    assert!(verifier().verify_assertion(&assertion, &authenticator.public_key()).is_err());
+}
+
+#[test]
+fn test_fields_that_are_not_base64url_are_rejected() {
+    let authenticator = Authenticator::new();
+    let registration = authenticator.register(RP_ID, ORIGIN);
+    let assertion = authenticator.assert(RP_ID, "webauthn.get", 1);
+
+    for pointer in ["/rawId", "/response/clientDataJSON", "/response/authenticatorData", "/response/publicKey"] {
+        let response = with_field(&registration, pointer, "not base64!".into());
+        assert!(verifier().verify_registration(&response).is_err(), "{} should be rejected", pointer);
+    }
+    for pointer in ["/rawId", "/response/clientDataJSON", "/response/authenticatorData", "/response/signature"] {
+        let response = with_field(&assertion, pointer, "not base64!".into());
+        assert!(
+            verifier().verify_assertion(&response, &authenticator.public_key()).is_err(),
+            "{} should be rejected",
+            pointer
+        );
+    }
+}
+
+#[test]
+fn test_registration_with_credential_id_other_than_attested_is_rejected() {
+    let authenticator = Authenticator::new();
+    let response = with_field(
+        &authenticator.register(RP_ID, ORIGIN),
+        "/rawId",
+        URL_SAFE_NO_PAD.encode(b"credential-2").into(),
+    );
+
+    assert!(verifier().verify_registration(&response).is_err());
+}
+
+#[test]
+fn test_registration_without_attested_credential_is_rejected() {
+    let authenticator = Authenticator::new();
+    let registration = authenticator.register(RP_ID, ORIGIN);
+    let mut auth_data = authenticator_data(&registration);
+    auth_data[32] &= !0x40;
+
+    let response = with_field(&registration, "/response/authenticatorData", URL_SAFE_NO_PAD.encode(auth_data).into());
+
+    assert!(verifier().verify_registration(&response).is_err());
+}
+
+#[test]
+fn test_registration_with_unsupported_algorithm_is_rejected() {
+    let authenticator = Authenticator::new();
+
+    let result = verifier().verify_registration(&authenticator.register_with(RP_ID, ORIGIN, &authenticator.spki(), -8));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_rs256_registration_declared_as_es256_is_rejected() {
+    let authenticator = Authenticator::rs256();
+
+    let result = verifier().verify_registration(&authenticator.register_with(RP_ID, ORIGIN, &authenticator.spki(), -7));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_assertion_with_tampered_signature_is_rejected() {
+    let authenticator = Authenticator::new();
+    let assertion = authenticator.assert(RP_ID, "webauthn.get", 1);
+    let response: serde_json::Value = serde_json::from_str(&assertion).unwrap();
+    let mut signature = URL_SAFE_NO_PAD
+        .decode(response["response"]["signature"].as_str().unwrap())
+        .unwrap();
+    let last = signature.len() - 1;
+    signature[last] ^= 0x01;
+
+    let response = with_field(&assertion, "/response/signature", URL_SAFE_NO_PAD.encode(signature).into());
+
+    assert!(verifier().verify_assertion(&response, &authenticator.public_key()).is_err());
+}
+
+#[test]
+fn test_assertion_with_tampered_authenticator_data_is_rejected() {
+    let authenticator = Authenticator::new();
+    let assertion = authenticator.assert(RP_ID, "webauthn.get", 1);
+    let mut auth_data = authenticator_data(&assertion);
+    // Raise the signature counter
+    auth_data[36] ^= 0x80;
+
+    let response = with_field(&assertion, "/response/authenticatorData", URL_SAFE_NO_PAD.encode(auth_data).into());
+
+    assert!(verifier().verify_assertion(&response, &authenticator.public_key()).is_err());
+}
+
+#[test]
+fn test_assertion_against_malformed_stored_key_is_rejected() {
+    let authenticator = Authenticator::new();
+    let assertion = authenticator.assert(RP_ID, "webauthn.get", 1);
+
+    for public_key in [vec![], vec![0x04], vec![0x04; 65], vec![0x30; 300]] {
+        assert!(verifier().verify_assertion(&assertion, &public_key).is_err());
+    }
+}
+
+#[test]
+fn test_every_truncated_authenticator_data_is_rejected() {
+    let authenticator = Authenticator::new();
+    let registration = authenticator.register(RP_ID, ORIGIN);
+    let auth_data = authenticator_data(&registration);
+
+    for len in 0..auth_data.len() {
+        let response = with_field(
+            &registration,
+            "/response/authenticatorData",
+            URL_SAFE_NO_PAD.encode(&auth_data[..len]).into(),
+        );
+        assert!(
+            verifier().verify_registration(&response).is_err(),
+            "Truncation to {} bytes should be rejected",
+            len
+        );
+    }
+}
+
+#[test]
+fn test_corrupted_authenticator_data_never_panics() {
+    for authenticator in [Authenticator::new(), Authenticator::rs256()] {
+        let registration = authenticator.register(RP_ID, ORIGIN);
+        let auth_data = authenticator_data(&registration);
+
+        for index in 0..auth_data.len() {
+            for mask in [0x01, 0x80, 0xff] {
+                let mut corrupted = auth_data.clone();
+                corrupted[index] ^= mask;
+                let response = with_field(
+                    &registration,
+                    "/response/authenticatorData",
+                    URL_SAFE_NO_PAD.encode(corrupted).into(),
+                );
+                let _ = verifier().verify_registration(&response);
+            }
+        }
+    }
+}
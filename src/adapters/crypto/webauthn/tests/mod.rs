@@ -0,0 +1,4 @@
+//! Tests for the WebAuthn module.
+
+mod cose_key_tests;
+mod cose_webauthn_verifier_tests;
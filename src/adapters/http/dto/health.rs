@@ -0,0 +1,49 @@
+// Health probe DTOs
+use serde::{Deserialize, Serialize};
+
+use crate::adapters::persistence::PoolStatus;
+
+/// Readiness probe body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadinessResponse {
+    /// `ready`, `degraded` (reachable but slow) or `not_ready`
+    pub status: String,
+    /// Database probe result; absent when no database is attached
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<DatabaseReadiness>,
+}
+
+/// Database section of the readiness probe
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseReadiness {
+    /// `up` or `down`
+    pub status: String,
+    /// Round-trip time of the probe query
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<f64>,
+    /// Whether the probe was slower than the degraded threshold
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub degraded: Option<bool>,
+    /// Connection pool occupancy
+    pub pool: PoolStatusResponse,
+}
+
+/// Connection pool occupancy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolStatusResponse {
+    pub size: u32,
+    pub idle: u32,
+    pub in_use: u32,
+    pub max_connections: u32,
+}
+
+impl From<PoolStatus> for PoolStatusResponse {
+    fn from(status: PoolStatus) -> Self {
+        Self {
+            size: status.size,
+            idle: status.idle,
+            in_use: status.in_use,
+            max_connections: status.max_connections,
+        }
+    }
+}
@@ -1,5 +1,8 @@
 // Internal credential creation DTO
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::adapters::http::error::{FieldError, ValidationError};
 
 /// Request to create a new credential (internal service)
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -15,29 +18,29 @@ pub struct CreateCredentialRequest {
 }
 
 impl CreateCredentialRequest {
-    /// Validate the request
-    pub fn validate(&self) -> Result<(), String> {
+    /// Validate the request, reporting every invalid field
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = Vec::new();
+
         if self.user_id.is_empty() {
-            return Err("User ID cannot be empty".to_string());
+            errors.push(FieldError::new("user_id", "required", "User ID cannot be empty"));
+        } else if Uuid::parse_str(&self.user_id).is_err() {
+            errors.push(FieldError::new("user_id", "invalid_format", "invalid user_id format"));
         }
 
         if self.identifier.is_empty() {
-            return Err("Identifier cannot be empty".to_string());
+            errors.push(FieldError::new("identifier", "required", "Identifier cannot be empty"));
+        } else if self.identifier.len() > 255 {
+            errors.push(FieldError::new("identifier", "too_long", "Identifier too long (max 255 characters)"));
         }
 
         if self.password.is_empty() {
-            return Err("Password cannot be empty".to_string());
-        }
-
-        if self.identifier.len() > 255 {
-            return Err("Identifier too long (max 255 characters)".to_string());
-        }
-
-        if self.password.len() < 8 {
-            return Err("Password too weak (min 8 characters)".to_string());
+            errors.push(FieldError::new("password", "required", "Password cannot be empty"));
+        } else if self.password.len() < 8 {
+            errors.push(FieldError::new("password", "too_short", "Password too weak (min 8 characters)"));
         }
 
-        Ok(())
+        ValidationError::check(errors)
     }
 }
 
@@ -0,0 +1,78 @@
+// Internal batch credential creation DTO
+use serde::{Deserialize, Serialize};
+
+use super::create_credential::CreateCredentialRequest;
+
+/// Largest number of credentials accepted in one batch
+pub const MAX_CREDENTIAL_BATCH_SIZE: usize = 100;
+
+fn default_atomic() -> bool {
+    true
+}
+
+/// Request to create many credentials at once (internal service)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CreateCredentialsBatchRequest {
+    /// Credentials to create, in order
+    pub items: Vec<CreateCredentialRequest>,
+    /// Create every item or none (default); `false` keeps each item that succeeds
+    #[serde(default = "default_atomic")]
+    pub atomic: bool,
+}
+
+impl CreateCredentialsBatchRequest {
+    /// Whether the batch is larger than [`MAX_CREDENTIAL_BATCH_SIZE`]
+    pub fn exceeds_size_limit(&self) -> bool {
+        self.items.len() > MAX_CREDENTIAL_BATCH_SIZE
+    }
+
+    /// Validate the batch envelope; items are validated one by one
+    pub fn validate(&self) -> Result<(), String> {
+        if self.items.is_empty() {
+            return Err("Batch cannot be empty".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Outcome of a single batch item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchItemStatus {
+    /// The credential was created
+    Created,
+    /// The item failed request validation and was never written
+    Invalid,
+    /// The identifier or user id already exists
+    Conflict,
+    /// Persistence failed for another reason
+    Failed,
+    /// Valid, but not written because another item failed an atomic batch
+    RolledBack,
+}
+
+/// Result for one item of the batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchItemResult {
+    /// Position of the item in the request
+    pub index: usize,
+    /// The identifier of the item
+    pub identifier: String,
+    /// What happened to the item
+    pub status: BatchItemStatus,
+    /// Why the item was not created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Response after batch credential creation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCredentialsBatchResponse {
+    /// Whether the batch ran in atomic mode
+    pub atomic: bool,
+    /// Number of credentials created
+    pub created: usize,
+    /// One result per requested item, in request order
+    pub results: Vec<BatchItemResult>,
+}
@@ -0,0 +1,56 @@
+// Internal token introspection DTOs
+use serde::{Deserialize, Serialize};
+
+/// Request to introspect an access token
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IntrospectRequest {
+    /// Access token to inspect
+    pub token: String,
+}
+
+impl IntrospectRequest {
+    /// Validate the request
+    pub fn validate(&self) -> Result<(), String> {
+        if self.token.is_empty() {
+            return Err("Token cannot be empty".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Introspection result; an inactive token carries no other field
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntrospectResponse {
+    /// Whether the token is currently valid
+    pub active: bool,
+    /// Subject (user ID)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    /// Expiration (unix seconds)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    /// Issued at (unix seconds)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iat: Option<i64>,
+    /// Space-separated granted scopes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Token type (e.g. "access")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
+}
+
+impl IntrospectResponse {
+    /// Response for a token that must not be trusted
+    pub fn inactive() -> Self {
+        Self {
+            active: false,
+            sub: None,
+            exp: None,
+            iat: None,
+            scope: None,
+            token_type: None,
+        }
+    }
+}
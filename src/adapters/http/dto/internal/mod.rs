@@ -1,11 +1,30 @@
 // Internal service DTOs
 pub mod create_credential;
+pub mod create_credentials_batch;
+pub mod introspect;
 pub mod issue_service_token;
 pub mod issue_session_tokens;
+pub mod revoke_access_token;
+pub mod revoke_credential;
+pub mod revoke_session;
+pub mod session_history;
+pub mod unlock_account;
 
 pub use create_credential::{CreateCredentialRequest, CreateCredentialResponse};
+pub use create_credentials_batch::{
+    BatchItemResult, BatchItemStatus, CreateCredentialsBatchRequest, CreateCredentialsBatchResponse,
+    MAX_CREDENTIAL_BATCH_SIZE,
+};
+pub use introspect::{IntrospectRequest, IntrospectResponse};
 pub use issue_service_token::{IssueServiceTokenRequest, IssueServiceTokenResponse};
 pub use issue_session_tokens::{IssueSessionTokensRequest, IssueSessionTokensResponse};
+pub use revoke_access_token::{RevokeAccessTokenRequest, RevokeAccessTokenResponse, RevokeAllTokensResponse};
+pub use revoke_credential::{RevokeCredentialRequest, RevokeCredentialResponse};
+pub use revoke_session::{RevokeSessionRequest, RevokeSessionResponse};
+pub use session_history::{
+    PaginationInfo, SessionHistoryEntry, SessionHistoryQuery, SessionHistoryResponse,
+};
+pub use unlock_account::{UnlockAccountRequest, UnlockAccountResponse};
 
 #[cfg(test)]
 pub mod tests;
@@ -0,0 +1,36 @@
+// Internal access token revocation DTO
+use serde::{Deserialize, Serialize};
+
+/// Request to revoke a single access token (internal service)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RevokeAccessTokenRequest {
+    /// Access token to deny
+    pub token: String,
+}
+
+impl RevokeAccessTokenRequest {
+    /// Validate the request
+    pub fn validate(&self) -> Result<(), String> {
+        if self.token.is_empty() {
+            return Err("Token cannot be empty".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Response after access token revocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevokeAccessTokenResponse {
+    /// Identifier of the denied token
+    pub jti: String,
+    /// When the denial lapses, the token's own expiry (unix seconds)
+    pub expires_at: i64,
+}
+
+/// Response after revoking every access token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevokeAllTokensResponse {
+    /// Latest issue time revoked; tokens issued at or before it are rejected (unix seconds)
+    pub revoked_through: i64,
+}
@@ -0,0 +1,40 @@
+// Internal credential revocation DTO
+use serde::{Deserialize, Serialize};
+
+/// Request to revoke a user's credential (internal service)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RevokeCredentialRequest {
+    /// User whose credential is revoked
+    pub user_id: String,
+    /// Revocation timestamp (RFC 3339); defaults to the current time
+    #[serde(default)]
+    pub revoked_at: Option<String>,
+}
+
+impl RevokeCredentialRequest {
+    /// Validate the request
+    pub fn validate(&self) -> Result<(), String> {
+        if self.user_id.is_empty() {
+            return Err("User ID cannot be empty".to_string());
+        }
+
+        let invalid_timestamp = self
+            .revoked_at
+            .as_deref()
+            .is_some_and(|revoked_at| chrono::DateTime::parse_from_rfc3339(revoked_at).is_err());
+        if invalid_timestamp {
+            return Err("revoked_at must be an RFC 3339 timestamp".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Response after credential revocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevokeCredentialResponse {
+    /// The user whose credential was revoked
+    pub user_id: String,
+    /// Revocation timestamp (ISO 8601)
+    pub revoked_at: String,
+}
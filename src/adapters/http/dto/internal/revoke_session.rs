@@ -0,0 +1,33 @@
+// Internal session revocation DTO
+use serde::{Deserialize, Serialize};
+
+/// Request to revoke a session by id (internal service)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RevokeSessionRequest {
+    /// Session to revoke
+    pub session_id: String,
+}
+
+impl RevokeSessionRequest {
+    /// Validate the request
+    pub fn validate(&self) -> Result<(), String> {
+        if self.session_id.is_empty() {
+            return Err("Session ID cannot be empty".to_string());
+        }
+
+        if uuid::Uuid::parse_str(&self.session_id).is_err() {
+            return Err("Session ID must be a valid UUID".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Response after session revocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevokeSessionResponse {
+    /// The session that is now revoked
+    pub session_id: String,
+    /// True if this request revoked the session, false if it was already revoked
+    pub revoked: bool,
+}
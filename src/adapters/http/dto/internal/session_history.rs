@@ -0,0 +1,77 @@
+// Internal session history DTO
+use serde::{Deserialize, Serialize};
+
+/// Query for a page of a user's session history (internal service)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SessionHistoryQuery {
+    /// User whose sessions to list
+    pub user_id: String,
+    /// Opaque cursor from a previous page's `next_cursor`
+    #[serde(default)]
+    pub cursor: Option<String>,
+    /// Maximum number of sessions to return
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl SessionHistoryQuery {
+    /// Validate the query
+    pub fn validate(&self) -> Result<(), String> {
+        if self.user_id.is_empty() {
+            return Err("User ID cannot be empty".to_string());
+        }
+
+        if uuid::Uuid::parse_str(&self.user_id).is_err() {
+            return Err("User ID must be a valid UUID".to_string());
+        }
+
+        if self.limit == Some(0) {
+            return Err("Limit must be at least 1".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// One session in the history, whatever its state
+///
+/// Never carries token material.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionHistoryEntry {
+    /// Session identifier
+    pub session_id: String,
+    /// One of `active`, `revoked` or `expired`
+    pub status: String,
+    /// When the session was created (RFC 3339)
+    pub created_at: String,
+    /// When the session expires or expired (RFC 3339)
+    pub expires_at: String,
+    /// When the session was revoked (RFC 3339), if it was
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revoked_at: Option<String>,
+    /// Client IP address recorded for the session
+    pub ip_address: String,
+    /// Client user agent recorded for the session
+    pub user_agent: String,
+}
+
+/// Pagination metadata for a history page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginationInfo {
+    /// Page size applied to this request
+    pub limit: usize,
+    /// Whether another page follows
+    pub has_more: bool,
+    /// Cursor for the next page; absent on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+/// Response with one page of a user's session history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionHistoryResponse {
+    /// Sessions, newest first
+    pub sessions: Vec<SessionHistoryEntry>,
+    /// Where to continue from
+    pub pagination: PaginationInfo,
+}
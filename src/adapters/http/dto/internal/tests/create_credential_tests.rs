@@ -74,6 +74,38 @@ fn test_create_credential_request_empty_user_id() {
     assert!(request.validate().is_err());
 }
 
+#[test]
+fn test_create_credential_request_malformed_user_id() {
+    let request = CreateCredentialRequest {
+        user_id: "not-a-uuid".to_string(),
+        identifier: "user@example.com".to_string(),
+        password: "ValidPassword123".to_string(),
+        credential_type: Some("password".to_string()),
+    };
+
+    let err = request.validate().unwrap_err();
+    assert_eq!(err.errors.len(), 1);
+    assert_eq!(err.errors[0].field, "user_id");
+    assert_eq!(err.errors[0].code, "invalid_format");
+}
+
+#[test]
+fn test_create_credential_request_reports_every_invalid_field() {
+    let request = CreateCredentialRequest {
+        user_id: "not-a-uuid".to_string(),
+        identifier: "".to_string(),
+        password: "weak".to_string(),
+        credential_type: None,
+    };
+
+    let err = request.validate().unwrap_err();
+    let fields: Vec<_> = err.errors.iter().map(|e| (e.field.as_str(), e.code.as_str())).collect();
+    assert_eq!(
+        fields,
+        vec![("user_id", "invalid_format"), ("identifier", "required"), ("password", "too_short")]
+    );
+}
+
 #[test]
 fn test_create_credential_response_serialization() {
     let response = CreateCredentialResponse {
@@ -1,2 +1,4 @@
 // Tests for CreateCredential DTO
-mod create_credential_tests;
\ No newline at end of file
+mod create_credential_tests;
+mod revoke_credential_tests;
+mod unlock_account_tests;
\ No newline at end of file
@@ -0,0 +1,53 @@
+use crate::adapters::http::dto::internal::revoke_credential::{
+    RevokeCredentialRequest, RevokeCredentialResponse,
+};
+
+#[test]
+fn test_revoke_credential_request_validation_success() {
+    let request = RevokeCredentialRequest {
+        user_id: "019c8723-9710-772e-a57f-3e02a584a6f0".to_string(),
+        revoked_at: Some("2024-03-01T12:00:00+00:00".to_string()),
+    };
+
+    assert!(request.validate().is_ok());
+}
+
+#[test]
+fn test_revoke_credential_request_timestamp_is_optional() {
+    let request: RevokeCredentialRequest =
+        serde_json::from_str(r#"{"user_id":"019c8723-9710-772e-a57f-3e02a584a6f0"}"#).unwrap();
+
+    assert!(request.revoked_at.is_none());
+    assert!(request.validate().is_ok());
+}
+
+#[test]
+fn test_revoke_credential_request_empty_user_id() {
+    let request = RevokeCredentialRequest {
+        user_id: "".to_string(),
+        revoked_at: None,
+    };
+
+    assert!(request.validate().is_err());
+}
+
+#[test]
+fn test_revoke_credential_request_invalid_timestamp() {
+    let request = RevokeCredentialRequest {
+        user_id: "019c8723-9710-772e-a57f-3e02a584a6f0".to_string(),
+        revoked_at: Some("yesterday".to_string()),
+    };
+
+    assert!(request.validate().is_err());
+}
+
+#[test]
+fn test_revoke_credential_response_serialization() {
+    let response = RevokeCredentialResponse {
+        user_id: "019c8723-9710-772e-a57f-3e02a584a6f0".to_string(),
+        revoked_at: "2024-03-01T12:00:00+00:00".to_string(),
+    };
+
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["revoked_at"], "2024-03-01T12:00:00+00:00");
+}
@@ -0,0 +1,29 @@
+use crate::adapters::http::dto::internal::unlock_account::{UnlockAccountRequest, UnlockAccountResponse};
+
+#[test]
+fn test_unlock_account_request_validation_success() {
+    let request: UnlockAccountRequest =
+        serde_json::from_str(r#"{"user_id":"019c8723-9710-772e-a57f-3e02a584a6f0"}"#).unwrap();
+
+    assert!(request.validate().is_ok());
+}
+
+#[test]
+fn test_unlock_account_request_empty_user_id() {
+    let request = UnlockAccountRequest {
+        user_id: "".to_string(),
+    };
+
+    assert!(request.validate().is_err());
+}
+
+#[test]
+fn test_unlock_account_response_serialization() {
+    let response = UnlockAccountResponse {
+        user_id: "019c8723-9710-772e-a57f-3e02a584a6f0".to_string(),
+        was_locked: true,
+    };
+
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["was_locked"], true);
+}
@@ -0,0 +1,29 @@
+// Internal account unlock DTO
+use serde::{Deserialize, Serialize};
+
+/// Request to unlock a locked-out account (internal service)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UnlockAccountRequest {
+    /// User whose account is unlocked
+    pub user_id: String,
+}
+
+impl UnlockAccountRequest {
+    /// Validate the request
+    pub fn validate(&self) -> Result<(), String> {
+        if self.user_id.is_empty() {
+            return Err("User ID cannot be empty".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Response after unlocking an account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnlockAccountResponse {
+    /// The user whose account was unlocked
+    pub user_id: String,
+    /// Whether the account had failed attempts or a lock to clear
+    pub was_locked: bool,
+}
@@ -6,6 +6,7 @@ This module defines all DTOs for HTTP requests and responses.
 DTOs are organized by visibility:
  - `internal`: DTOs for internal service-to-service communication (requires mTLS/service auth)
  - `public`: DTOs for public-facing endpoints
+ - `health`: Liveness and readiness probe bodies
 
 Design Principles:
  - **Transport only**: DTOs are never used in business logic
@@ -15,8 +16,12 @@ Design Principles:
  - **Clean separation**: Internal vs public DTOs are strictly separated
 */
 
+pub mod health;
 pub mod internal;
 pub mod public;
 
 pub use internal::{CreateCredentialRequest, CreateCredentialResponse};
 pub use public::{AuthenticateRequest, AuthenticateResponse, RefreshTokenRequest, RefreshTokenResponse};
+
+#[cfg(test)]
+mod tests;
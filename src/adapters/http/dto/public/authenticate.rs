@@ -1,6 +1,20 @@
 // Public authentication DTO
 use serde::{Deserialize, Serialize};
 
+use super::field_limits::FieldLimits;
+use crate::adapters::http::error::{FieldError, ValidationError};
+
+/// Default longest identifier accepted, in bytes (an RFC 5321 address fits in 254)
+pub const MAX_IDENTIFIER_LENGTH: usize = 320;
+
+/// Default longest password accepted, in bytes
+///
+/// Far beyond any real passphrase; bounds the work handed to the hasher.
+pub const MAX_PASSWORD_LENGTH: usize = 1024;
+
+/// Longest workspace id accepted, in bytes
+pub const MAX_WORKSPACE_ID_LENGTH: usize = 128;
+
 /// Request to authenticate a user
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AuthenticateRequest {
@@ -8,20 +22,55 @@ pub struct AuthenticateRequest {
     pub identifier: String,
     /// Password
     pub password: String,
+    /// Workspace to sign in to; a global login when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<String>,
 }
 
 impl AuthenticateRequest {
-    /// Validate the request
-    pub fn validate(&self) -> Result<(), String> {
+    /// Validate the request against the default field limits, reporting
+    /// every invalid field
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_within(&FieldLimits::default())
+    }
+
+    /// Validate the request against `limits`, reporting every invalid field
+    pub fn validate_within(&self, limits: &FieldLimits) -> Result<(), ValidationError> {
+        let mut errors = Vec::new();
+
         if self.identifier.is_empty() {
-            return Err("Identifier required".to_string());
+            errors.push(FieldError::new("identifier", "required", "Identifier required"));
+        } else if self.identifier.len() > limits.identifier {
+            errors.push(FieldError::new(
+                "identifier",
+                "too_long",
+                format!("Identifier must be at most {} bytes", limits.identifier),
+            ));
         }
 
         if self.password.is_empty() {
-            return Err("Password required".to_string());
+            errors.push(FieldError::new("password", "required", "Password required"));
+        } else if self.password.len() > limits.password {
+            errors.push(FieldError::new(
+                "password",
+                "too_long",
+                format!("Password must be at most {} bytes", limits.password),
+            ));
+        }
+
+        if let Some(workspace_id) = &self.workspace_id {
+            if workspace_id.is_empty() {
+                errors.push(FieldError::new("workspace_id", "required", "Workspace id must not be empty"));
+            } else if workspace_id.len() > MAX_WORKSPACE_ID_LENGTH {
+                errors.push(FieldError::new(
+                    "workspace_id",
+                    "too_long",
+                    format!("Workspace id must be at most {} bytes", MAX_WORKSPACE_ID_LENGTH),
+                ));
+            }
         }
 
-        Ok(())
+        ValidationError::check(errors)
     }
 }
 
@@ -38,4 +87,13 @@ pub struct AuthenticateResponse {
     pub expires_in: u64,
     /// Session ID
     pub session_id: String,
+    /// Failed sign-in attempts since the previous successful login
+    #[serde(default)]
+    pub recent_failed_attempts: u32,
+    /// Time of the previous successful login (RFC3339); absent on first login
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_login_at: Option<String>,
+    /// Workspace the session is scoped to; absent for a global login
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<String>,
 }
@@ -0,0 +1,45 @@
+//! Change password DTOs
+
+use serde::{Deserialize, Serialize};
+
+use super::authenticate::MAX_PASSWORD_LENGTH;
+
+/// Request to change the caller's password
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ChangePasswordRequest {
+    /// Password currently in use
+    pub current_password: String,
+    /// Password to switch to
+    pub new_password: String,
+    /// Sign out every other session, keeping the one making the request
+    #[serde(default)]
+    pub revoke_other_sessions: bool,
+}
+
+impl ChangePasswordRequest {
+    /// Validate the request
+    pub fn validate(&self) -> Result<(), String> {
+        if self.current_password.is_empty() {
+            return Err("Current password required".to_string());
+        }
+
+        if self.new_password.is_empty() {
+            return Err("New password required".to_string());
+        }
+
+        if self.current_password.len() > MAX_PASSWORD_LENGTH || self.new_password.len() > MAX_PASSWORD_LENGTH {
+            return Err(format!("Password must be at most {} bytes", MAX_PASSWORD_LENGTH));
+        }
+
+        Ok(())
+    }
+}
+
+/// Response after a password change
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChangePasswordResponse {
+    /// Whether the password was changed
+    pub success: bool,
+    /// Number of other sessions that were revoked
+    pub sessions_revoked: u64,
+}
@@ -0,0 +1,27 @@
+// Length limits for public request fields
+use super::authenticate::{MAX_IDENTIFIER_LENGTH, MAX_PASSWORD_LENGTH};
+use super::refresh_token::MAX_REFRESH_TOKEN_LENGTH;
+
+/// Longest values accepted for public request fields, in bytes
+///
+/// Checked by the request DTOs' `validate_within`; `validate` applies the
+/// defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldLimits {
+    /// Longest login identifier
+    pub identifier: usize,
+    /// Longest password
+    pub password: usize,
+    /// Longest refresh token
+    pub refresh_token: usize,
+}
+
+impl Default for FieldLimits {
+    fn default() -> Self {
+        Self {
+            identifier: MAX_IDENTIFIER_LENGTH,
+            password: MAX_PASSWORD_LENGTH,
+            refresh_token: MAX_REFRESH_TOKEN_LENGTH,
+        }
+    }
+}
@@ -32,3 +32,23 @@ pub struct LogoutResponse {
     /// Session ID that was revoked
     pub session_id: Option<String>,
 }
+
+/// Response after logging out all other sessions
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogoutOthersResponse {
+    /// Whether the operation was successful
+    pub success: bool,
+    /// Number of other sessions that were revoked
+    pub sessions_revoked: u64,
+    /// Session ID that remains active
+    pub session_id: String,
+}
+
+/// Response after logging out every session
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LogoutAllResponse {
+    /// Whether the operation was successful
+    pub success: bool,
+    /// Number of sessions that were revoked, including the current one
+    pub sessions_revoked: u64,
+}
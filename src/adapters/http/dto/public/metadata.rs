@@ -0,0 +1,18 @@
+// Public authorization server metadata DTO
+use serde::{Deserialize, Serialize};
+
+/// Metadata describing how tokens issued by this service are signed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthMetadataResponse {
+    /// Signing algorithms used for issued tokens (RFC 8414 naming)
+    pub token_signing_alg_values_supported: Vec<String>,
+}
+
+impl AuthMetadataResponse {
+    /// Build metadata from the token service's supported algorithms
+    pub fn from_algorithms(algorithms: &[&str]) -> Self {
+        Self {
+            token_signing_alg_values_supported: algorithms.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+}
@@ -1,15 +1,23 @@
 // Public DTOs
 pub mod authenticate;
+pub mod field_limits;
 pub mod logout;
 pub mod refresh_token;
 pub mod token_validation;
 pub mod google_oauth;
+pub mod metadata;
+pub mod sessions;
+pub mod change_password;
 
 pub use authenticate::{AuthenticateRequest, AuthenticateResponse};
-pub use logout::{LogoutRequest, LogoutResponse};
+pub use field_limits::FieldLimits;
+pub use logout::{LogoutAllResponse, LogoutOthersResponse, LogoutRequest, LogoutResponse};
 pub use refresh_token::{RefreshTokenRequest, RefreshTokenResponse};
 pub use token_validation::{TokenValidationRequest, TokenValidationResponse};
 pub use google_oauth::{GoogleCodeExchangeRequest, GoogleCodeExchangeResponse};
+pub use metadata::AuthMetadataResponse;
+pub use sessions::{ListSessionsResponse, SessionInfo};
+pub use change_password::{ChangePasswordRequest, ChangePasswordResponse};
 
 #[cfg(test)]
 pub mod tests;
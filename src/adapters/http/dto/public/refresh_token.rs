@@ -1,6 +1,12 @@
 // Public token refresh DTO
 use serde::{Deserialize, Serialize};
 
+use super::field_limits::FieldLimits;
+use crate::adapters::http::error::{FieldError, ValidationError};
+
+/// Default longest refresh token accepted, in bytes
+pub const MAX_REFRESH_TOKEN_LENGTH: usize = 4096;
+
 /// Request to refresh an access token
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RefreshTokenRequest {
@@ -9,13 +15,27 @@ pub struct RefreshTokenRequest {
 }
 
 impl RefreshTokenRequest {
-    /// Validate the request
-    pub fn validate(&self) -> Result<(), String> {
+    /// Validate the request against the default field limits, reporting
+    /// every invalid field
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_within(&FieldLimits::default())
+    }
+
+    /// Validate the request against `limits`, reporting every invalid field
+    pub fn validate_within(&self, limits: &FieldLimits) -> Result<(), ValidationError> {
+        let mut errors = Vec::new();
+
         if self.refresh_token.is_empty() {
-            return Err("Refresh token required".to_string());
+            errors.push(FieldError::new("refresh_token", "required", "Refresh token required"));
+        } else if self.refresh_token.len() > limits.refresh_token {
+            errors.push(FieldError::new(
+                "refresh_token",
+                "too_long",
+                format!("Refresh token must be at most {} bytes", limits.refresh_token),
+            ));
         }
 
-        Ok(())
+        ValidationError::check(errors)
     }
 }
 
@@ -0,0 +1,29 @@
+//! Session listing DTOs
+
+use serde::{Deserialize, Serialize};
+
+/// One active session, as shown to its owner
+///
+/// Never carries token material.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionInfo {
+    /// Session identifier
+    pub session_id: String,
+    /// When the session was created (RFC 3339)
+    pub created_at: String,
+    /// When the session expires (RFC 3339)
+    pub expires_at: String,
+    /// Client IP address recorded for the session
+    pub ip_address: String,
+    /// Client user agent recorded for the session
+    pub user_agent: String,
+    /// Whether this is the session making the request
+    pub current: bool,
+}
+
+/// Response listing the caller's active sessions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListSessionsResponse {
+    /// Active sessions, newest first
+    pub sessions: Vec<SessionInfo>,
+}
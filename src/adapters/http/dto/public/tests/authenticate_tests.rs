@@ -1,13 +1,15 @@
 // Tests for Authenticate DTO
 use crate::adapters::http::dto::public::authenticate::{
-    AuthenticateRequest, AuthenticateResponse,
+    AuthenticateRequest, AuthenticateResponse, MAX_IDENTIFIER_LENGTH, MAX_PASSWORD_LENGTH, MAX_WORKSPACE_ID_LENGTH,
 };
+use crate::adapters::http::dto::public::FieldLimits;
 
 #[test]
 fn test_authenticate_request_validation_success() {
     let request = AuthenticateRequest {
         identifier: "user@example.com".to_string(),
         password: "MyPassword123".to_string(),
+        workspace_id: None,
     };
 
     assert!(request.validate().is_ok());
@@ -18,6 +20,7 @@ fn test_authenticate_request_empty_identifier() {
     let request = AuthenticateRequest {
         identifier: "".to_string(),
         password: "MyPassword123".to_string(),
+        workspace_id: None,
     };
 
     assert!(request.validate().is_err());
@@ -28,11 +31,106 @@ fn test_authenticate_request_empty_password() {
     let request = AuthenticateRequest {
         identifier: "user@example.com".to_string(),
         password: "".to_string(),
+        workspace_id: None,
     };
 
     assert!(request.validate().is_err());
 }
 
+#[test]
+fn test_authenticate_request_password_too_long() {
+    let request = AuthenticateRequest {
+        identifier: "user@example.com".to_string(),
+        password: "a".repeat(MAX_PASSWORD_LENGTH + 1),
+        workspace_id: None,
+    };
+
+    let err = request.validate().unwrap_err();
+    assert!(err.to_string().contains("Password must be at most"));
+}
+
+#[test]
+fn test_authenticate_request_honours_configured_limits() {
+    let request = AuthenticateRequest {
+        identifier: "user@example.com".to_string(),
+        password: "MyPassword123".to_string(),
+        workspace_id: None,
+    };
+    let limits = FieldLimits { identifier: 8, password: 8, ..FieldLimits::default() };
+
+    let err = request.validate_within(&limits).unwrap_err();
+
+    let fields: Vec<&str> = err.errors.iter().map(|e| e.field.as_str()).collect();
+    assert_eq!(fields, vec!["identifier", "password"]);
+    assert!(err.errors[1].message.contains("at most 8 bytes"));
+    assert!(request.validate().is_ok());
+}
+
+#[test]
+fn test_authenticate_request_password_at_limit() {
+    let request = AuthenticateRequest {
+        identifier: "user@example.com".to_string(),
+        password: "a".repeat(MAX_PASSWORD_LENGTH),
+        workspace_id: None,
+    };
+
+    assert!(request.validate().is_ok());
+}
+
+#[test]
+fn test_authenticate_request_identifier_too_long() {
+    let request = AuthenticateRequest {
+        identifier: "a".repeat(MAX_IDENTIFIER_LENGTH + 1),
+        password: "MyPassword123".to_string(),
+        workspace_id: None,
+    };
+
+    let err = request.validate().unwrap_err();
+    assert!(err.to_string().contains("Identifier must be at most"));
+}
+
+#[test]
+fn test_authenticate_request_reports_every_invalid_field() {
+    let request = AuthenticateRequest {
+        identifier: "".to_string(),
+        password: "".to_string(),
+        workspace_id: None,
+    };
+
+    let err = request.validate().unwrap_err();
+    let fields: Vec<_> = err.errors.iter().map(|e| (e.field.as_str(), e.code.as_str())).collect();
+    assert_eq!(fields, vec![("identifier", "required"), ("password", "required")]);
+}
+
+#[test]
+fn test_authenticate_request_reports_length_and_presence_together() {
+    let request = AuthenticateRequest {
+        identifier: "a".repeat(MAX_IDENTIFIER_LENGTH + 1),
+        password: "".to_string(),
+        workspace_id: None,
+    };
+
+    let err = request.validate().unwrap_err();
+    let fields: Vec<_> = err.errors.iter().map(|e| (e.field.as_str(), e.code.as_str())).collect();
+    assert_eq!(fields, vec![("identifier", "too_long"), ("password", "required")]);
+}
+
+#[test]
+fn test_authenticate_request_workspace_id_must_not_be_blank_or_too_long() {
+    let mut request = AuthenticateRequest {
+        identifier: "user@example.com".to_string(),
+        password: "MyPassword123".to_string(),
+        workspace_id: Some("workspace-1".to_string()),
+    };
+    assert!(request.validate().is_ok());
+
+    request.workspace_id = Some(String::new());
+    assert!(request.validate().is_err());
+
+    request.workspace_id = Some("w".repeat(MAX_WORKSPACE_ID_LENGTH + 1));
+    assert!(request.validate().is_err());
+}
+
 #[test]
 fn test_authenticate_response_structure() {
     let response = AuthenticateResponse {
@@ -41,6 +139,9 @@ fn test_authenticate_response_structure() {
         token_type: "Bearer".to_string(),
         expires_in: 3600,
         session_id: "session123".to_string(),
+        recent_failed_attempts: 0,
+        previous_login_at: None,
+        workspace_id: None,
     };
 
     assert_eq!(response.token_type, "Bearer");
@@ -1,6 +1,6 @@
 //! Tests for logout DTOs
 
-use crate::adapters::http::dto::public::{LogoutRequest, LogoutResponse};
+use crate::adapters::http::dto::public::{LogoutOthersResponse, LogoutRequest, LogoutResponse};
 
 // ============================================================================
 // LogoutRequest Tests
@@ -132,3 +132,21 @@ fn test_logout_request_deserialization_partial() {
     assert_eq!(request.session_id, Some("sess-123".to_string()));
     assert_eq!(request.refresh_token, None);
 }
+
+// ============================================================================
+// LogoutOthersResponse Tests
+// ============================================================================
+
+#[test]
+fn test_logout_others_response_serialization() {
+    let response = LogoutOthersResponse {
+        success: true,
+        sessions_revoked: 3,
+        session_id: "session-123".to_string(),
+    };
+    
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["success"], true);
+    assert_eq!(json["sessions_revoked"], 3);
+    assert_eq!(json["session_id"], "session-123");
+}
@@ -0,0 +1,22 @@
+//! Tests for AuthMetadataResponse DTO
+
+use crate::adapters::http::dto::public::AuthMetadataResponse;
+
+#[test]
+fn test_metadata_from_algorithms() {
+    let metadata = AuthMetadataResponse::from_algorithms(&["HS256"]);
+    assert_eq!(metadata.token_signing_alg_values_supported, vec!["HS256".to_string()]);
+}
+
+#[test]
+fn test_metadata_serialization() {
+    let metadata = AuthMetadataResponse::from_algorithms(&["EdDSA"]);
+    let json = serde_json::to_string(&metadata).unwrap();
+    assert_eq!(json, r#"{"token_signing_alg_values_supported":["EdDSA"]}"#);
+}
+
+#[test]
+fn test_metadata_empty_algorithms() {
+    let metadata = AuthMetadataResponse::from_algorithms(&[]);
+    assert!(metadata.token_signing_alg_values_supported.is_empty());
+}
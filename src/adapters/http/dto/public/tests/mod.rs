@@ -4,3 +4,5 @@ mod logout_tests;
 mod refresh_token_tests;
 mod token_validation_tests;
 mod google_oauth_tests;
+mod metadata_tests;
+mod sessions_tests;
@@ -1,6 +1,6 @@
 // Tests for RefreshToken DTO
 use crate::adapters::http::dto::public::refresh_token::{
-    RefreshTokenRequest, RefreshTokenResponse,
+    RefreshTokenRequest, RefreshTokenResponse, MAX_REFRESH_TOKEN_LENGTH,
 };
 
 #[test]
@@ -21,6 +21,17 @@ fn test_refresh_token_request_empty_token() {
     assert!(request.validate().is_err());
 }
 
+#[test]
+fn test_refresh_token_request_token_too_long() {
+    let request = RefreshTokenRequest {
+        refresh_token: "a".repeat(MAX_REFRESH_TOKEN_LENGTH + 1),
+    };
+
+    let err = request.validate().unwrap_err();
+    assert_eq!(err.field.as_deref(), Some("refresh_token"));
+    assert_eq!(err.errors[0].code, "too_long");
+}
+
 #[test]
 fn test_refresh_token_response_structure() {
     let response = RefreshTokenResponse {
@@ -0,0 +1,25 @@
+//! Tests for session listing DTOs
+
+use crate::adapters::http::dto::public::{ListSessionsResponse, SessionInfo};
+
+#[test]
+fn test_list_sessions_response_serialization() {
+    let response = ListSessionsResponse {
+        sessions: vec![SessionInfo {
+            session_id: "session-123".to_string(),
+            created_at: "2024-01-01T00:00:00+00:00".to_string(),
+            expires_at: "2024-01-08T00:00:00+00:00".to_string(),
+            ip_address: "203.0.113.7".to_string(),
+            user_agent: "Mozilla/5.0".to_string(),
+            current: true,
+        }],
+    };
+
+    let json = serde_json::to_value(&response).unwrap();
+    let session = &json["sessions"][0];
+
+    assert_eq!(session["session_id"], "session-123");
+    assert_eq!(session["ip_address"], "203.0.113.7");
+    assert_eq!(session["current"], true);
+    assert!(session.get("refresh_token_hash").is_none());
+}
@@ -7,6 +7,7 @@ use crate::adapters::http::dto::public::token_validation::{
 fn test_token_validation_request_validation_success() {
     let request = TokenValidationRequest {
         token: "eyJ0eXAiOiJKV1QiLCJhbGciOiJIUzI1NiJ9".to_string(),
+        required_scopes: vec![],
     };
 
     assert!(request.validate().is_ok());
@@ -16,6 +17,7 @@ fn test_token_validation_request_validation_success() {
 fn test_token_validation_request_empty_token() {
     let request = TokenValidationRequest {
         token: "".to_string(),
+        required_scopes: vec![],
     };
 
     assert!(request.validate().is_err());
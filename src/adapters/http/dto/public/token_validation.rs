@@ -6,6 +6,9 @@ use serde::{Deserialize, Serialize};
 pub struct TokenValidationRequest {
     /// Access token to validate
     pub token: String,
+    /// Scopes the token must carry (optional)
+    #[serde(default)]
+    pub required_scopes: Vec<String>,
 }
 
 impl TokenValidationRequest {
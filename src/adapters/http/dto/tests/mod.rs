@@ -0,0 +1,2 @@
+// Tests spanning all DTOs
+mod wire_contract_tests;
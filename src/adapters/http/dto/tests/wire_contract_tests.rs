@@ -0,0 +1,391 @@
+// Golden-JSON wire contract for every DTO
+//
+// Each test pins the exact JSON a DTO produces. Renaming, adding or dropping a
+// field fails here, so wire changes have to be made on purpose and updated in
+// the golden value alongside the client-facing docs.
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+use crate::adapters::http::dto::internal::{
+    CreateCredentialRequest, CreateCredentialResponse, IntrospectRequest, IntrospectResponse, IssueServiceTokenRequest,
+    IssueServiceTokenResponse, IssueSessionTokensRequest, IssueSessionTokensResponse,
+};
+use crate::adapters::http::dto::public::{
+    AuthMetadataResponse, AuthenticateRequest, AuthenticateResponse, GoogleCodeExchangeRequest,
+    GoogleCodeExchangeResponse, ListSessionsResponse, LogoutAllResponse, LogoutOthersResponse, LogoutRequest,
+    LogoutResponse, RefreshTokenRequest, RefreshTokenResponse, SessionInfo, TokenValidationRequest,
+    TokenValidationResponse,
+};
+use crate::adapters::http::error::{ErrorResponse, HttpError, ValidationError};
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Assert `value` serializes to exactly `golden` and that `golden` round-trips
+fn assert_wire_contract<T: Serialize + DeserializeOwned>(value: &T, golden: Value) {
+    let actual = serde_json::to_value(value).unwrap();
+    assert_eq!(actual, golden, "wire shape changed; update clients before the golden value");
+
+    let parsed: T = serde_json::from_value(golden.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), golden, "golden value does not round-trip");
+}
+
+// ============================================================================
+// Public DTOs
+// ============================================================================
+
+#[test]
+fn test_authenticate_request_contract() {
+    let request = AuthenticateRequest {
+        identifier: "alice@example.com".to_string(),
+        password: "correct horse".to_string(),
+        workspace_id: None,
+    };
+
+    assert_wire_contract(&request, json!({
+        "identifier": "alice@example.com",
+        "password": "correct horse",
+    }));
+}
+
+#[test]
+fn test_authenticate_response_contract() {
+    let response = AuthenticateResponse {
+        access_token: "access".to_string(),
+        refresh_token: "refresh".to_string(),
+        token_type: "Bearer".to_string(),
+        expires_in: 3600,
+        session_id: "session-1".to_string(),
+        recent_failed_attempts: 2,
+        previous_login_at: None,
+        workspace_id: None,
+    };
+
+    assert_wire_contract(&response, json!({
+        "access_token": "access",
+        "refresh_token": "refresh",
+        "token_type": "Bearer",
+        "expires_in": 3600,
+        "session_id": "session-1",
+        "recent_failed_attempts": 2,
+    }));
+}
+
+#[test]
+fn test_authenticate_response_with_previous_login_contract() {
+    let response = AuthenticateResponse {
+        access_token: "access".to_string(),
+        refresh_token: "refresh".to_string(),
+        token_type: "Bearer".to_string(),
+        expires_in: 3600,
+        session_id: "session-1".to_string(),
+        recent_failed_attempts: 0,
+        previous_login_at: Some("2024-03-01T12:00:00+00:00".to_string()),
+        workspace_id: None,
+    };
+
+    assert_wire_contract(&response, json!({
+        "access_token": "access",
+        "refresh_token": "refresh",
+        "token_type": "Bearer",
+        "expires_in": 3600,
+        "session_id": "session-1",
+        "recent_failed_attempts": 0,
+        "previous_login_at": "2024-03-01T12:00:00+00:00",
+    }));
+}
+
+#[test]
+fn test_refresh_token_request_contract() {
+    let request = RefreshTokenRequest {
+        refresh_token: "refresh".to_string(),
+    };
+
+    assert_wire_contract(&request, json!({ "refresh_token": "refresh" }));
+}
+
+#[test]
+fn test_refresh_token_response_contract() {
+    let response = RefreshTokenResponse {
+        access_token: "access".to_string(),
+        token_type: "Bearer".to_string(),
+        expires_in: 3600,
+    };
+
+    assert_wire_contract(&response, json!({
+        "access_token": "access",
+        "token_type": "Bearer",
+        "expires_in": 3600,
+    }));
+}
+
+#[test]
+fn test_token_validation_contract() {
+    let request = TokenValidationRequest {
+        token: "access".to_string(),
+        required_scopes: vec!["user:read".to_string()],
+    };
+    assert_wire_contract(&request, json!({
+        "token": "access",
+        "required_scopes": ["user:read"],
+    }));
+
+    let response = TokenValidationResponse {
+        user_id: "user-1".to_string(),
+        session_id: "session-1".to_string(),
+    };
+    assert_wire_contract(&response, json!({
+        "user_id": "user-1",
+        "session_id": "session-1",
+    }));
+}
+
+#[test]
+fn test_logout_contract() {
+    let request = LogoutRequest {
+        session_id: Some("session-1".to_string()),
+        refresh_token: None,
+    };
+    assert_wire_contract(&request, json!({
+        "session_id": "session-1",
+        "refresh_token": null,
+    }));
+
+    let response = LogoutResponse {
+        success: true,
+        message: "Logged out".to_string(),
+        session_id: Some("session-1".to_string()),
+    };
+    assert_wire_contract(&response, json!({
+        "success": true,
+        "message": "Logged out",
+        "session_id": "session-1",
+    }));
+
+    let others = LogoutOthersResponse {
+        success: true,
+        sessions_revoked: 3,
+        session_id: "session-1".to_string(),
+    };
+    assert_wire_contract(&others, json!({
+        "success": true,
+        "sessions_revoked": 3,
+        "session_id": "session-1",
+    }));
+
+    let all = LogoutAllResponse {
+        success: true,
+        sessions_revoked: 4,
+    };
+    assert_wire_contract(&all, json!({
+        "success": true,
+        "sessions_revoked": 4,
+    }));
+}
+
+#[test]
+fn test_google_code_exchange_contract() {
+    let request = GoogleCodeExchangeRequest {
+        code: "auth-code".to_string(),
+        state: Some("csrf".to_string()),
+    };
+    assert_wire_contract(&request, json!({
+        "code": "auth-code",
+        "state": "csrf",
+    }));
+
+    let response = GoogleCodeExchangeResponse {
+        access_token: "access".to_string(),
+        refresh_token: "refresh".to_string(),
+        token_type: "Bearer".to_string(),
+        expires_in: 3600,
+        session_id: "session-1".to_string(),
+    };
+    assert_wire_contract(&response, json!({
+        "access_token": "access",
+        "refresh_token": "refresh",
+        "token_type": "Bearer",
+        "expires_in": 3600,
+        "session_id": "session-1",
+    }));
+}
+
+#[test]
+fn test_auth_metadata_contract() {
+    let response = AuthMetadataResponse::from_algorithms(&["HS256"]);
+
+    assert_wire_contract(&response, json!({
+        "token_signing_alg_values_supported": ["HS256"],
+    }));
+}
+
+#[test]
+fn test_list_sessions_contract() {
+    let response = ListSessionsResponse {
+        sessions: vec![SessionInfo {
+            session_id: "session-1".to_string(),
+            created_at: "2025-01-01T00:00:00+00:00".to_string(),
+            expires_at: "2025-01-08T00:00:00+00:00".to_string(),
+            ip_address: "203.0.113.7".to_string(),
+            user_agent: "Mozilla/5.0".to_string(),
+            current: true,
+        }],
+    };
+
+    assert_wire_contract(&response, json!({
+        "sessions": [{
+            "session_id": "session-1",
+            "created_at": "2025-01-01T00:00:00+00:00",
+            "expires_at": "2025-01-08T00:00:00+00:00",
+            "ip_address": "203.0.113.7",
+            "user_agent": "Mozilla/5.0",
+            "current": true,
+        }],
+    }));
+}
+
+#[test]
+fn test_error_response_contract() {
+    let error = HttpError::Validation(ValidationError::with_field("Required", "email"));
+    let response = ErrorResponse::from_http_error(&error).with_request_id("req-1");
+
+    assert_wire_contract(&response, json!({
+        "status": 400,
+        "code": "VALIDATION_ERROR",
+        "message": "email: Required",
+        "details": { "field": "email" },
+        "request_id": "req-1",
+    }));
+}
+
+#[test]
+fn test_field_errors_contract() {
+    let request = AuthenticateRequest {
+        identifier: String::new(),
+        password: String::new(),
+        workspace_id: None,
+    };
+    let error = HttpError::Validation(request.validate().unwrap_err());
+
+    assert_wire_contract(&ErrorResponse::from_http_error(&error), json!({
+        "status": 400,
+        "code": "VALIDATION_ERROR",
+        "message": "identifier: Identifier required; password: Password required",
+        "errors": [
+            { "field": "identifier", "code": "required", "message": "Identifier required" },
+            { "field": "password", "code": "required", "message": "Password required" },
+        ],
+    }));
+}
+
+// ============================================================================
+// Internal DTOs
+// ============================================================================
+
+#[test]
+fn test_create_credential_contract() {
+    let request = CreateCredentialRequest {
+        user_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+        identifier: "alice@example.com".to_string(),
+        password: "correct horse".to_string(),
+        credential_type: Some("password".to_string()),
+    };
+    assert_wire_contract(&request, json!({
+        "user_id": "550e8400-e29b-41d4-a716-446655440000",
+        "identifier": "alice@example.com",
+        "password": "correct horse",
+        "credential_type": "password",
+    }));
+
+    let response = CreateCredentialResponse {
+        user_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+        identifier: "alice@example.com".to_string(),
+        created_at: "2025-01-01T00:00:00Z".to_string(),
+    };
+    assert_wire_contract(&response, json!({
+        "user_id": "550e8400-e29b-41d4-a716-446655440000",
+        "identifier": "alice@example.com",
+        "created_at": "2025-01-01T00:00:00Z",
+    }));
+}
+
+#[test]
+fn test_issue_service_token_contract() {
+    let request = IssueServiceTokenRequest {
+        service_id: "user_service".to_string(),
+        service_secret: "secret".to_string(),
+    };
+    assert_wire_contract(&request, json!({
+        "service_id": "user_service",
+        "service_secret": "secret",
+    }));
+
+    let response = IssueServiceTokenResponse {
+        access_token: "service".to_string(),
+        expires_in: 900,
+        token_type: "Bearer".to_string(),
+    };
+    assert_wire_contract(&response, json!({
+        "access_token": "service",
+        "expires_in": 900,
+        "token_type": "Bearer",
+    }));
+}
+
+#[test]
+fn test_issue_session_tokens_contract() {
+    let request = IssueSessionTokensRequest {
+        user_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+    };
+    assert_wire_contract(&request, json!({
+        "user_id": "550e8400-e29b-41d4-a716-446655440000",
+    }));
+
+    let response = IssueSessionTokensResponse {
+        access_token: "access".to_string(),
+        refresh_token: "refresh".to_string(),
+        session_id: "session-1".to_string(),
+        expires_in: 3600,
+        token_type: "Bearer".to_string(),
+    };
+    assert_wire_contract(&response, json!({
+        "access_token": "access",
+        "refresh_token": "refresh",
+        "session_id": "session-1",
+        "expires_in": 3600,
+        "token_type": "Bearer",
+    }));
+}
+
+#[test]
+fn test_introspect_contract() {
+    let request = IntrospectRequest {
+        token: "access".to_string(),
+    };
+    assert_wire_contract(&request, json!({
+        "token": "access",
+    }));
+
+    let active = IntrospectResponse {
+        active: true,
+        sub: Some("user-1".to_string()),
+        exp: Some(1_700_000_900),
+        iat: Some(1_700_000_000),
+        scope: Some("profile:read sessions:write".to_string()),
+        token_type: Some("access".to_string()),
+    };
+    assert_wire_contract(&active, json!({
+        "active": true,
+        "sub": "user-1",
+        "exp": 1_700_000_900,
+        "iat": 1_700_000_000,
+        "scope": "profile:read sessions:write",
+        "token_type": "access",
+    }));
+
+    assert_wire_contract(&IntrospectResponse::inactive(), json!({
+        "active": false,
+    }));
+}
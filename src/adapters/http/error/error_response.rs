@@ -31,6 +31,15 @@ pub struct ErrorResponse {
     /// Additional error details (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<ErrorDetails>,
+    /// Correlation id for server-side log lookup (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Seconds to wait before retrying, mirrored in `Retry-After` (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after_seconds: Option<u64>,
+    /// One entry per invalid request field (validation errors only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
 }
 
 /// Additional error context
@@ -50,134 +59,224 @@ pub struct ErrorDetails {
 impl ErrorResponse {
     /// Create an error response from an HttpError
     pub fn from_http_error(error: &HttpError) -> Self {
-        match error {
-            HttpError::Validation(e) => Self::validation(e),
-            HttpError::Unauthorized(e) => Self::unauthorized(e),
-            HttpError::ServiceUnauthorized(e) => Self::service_unauthorized(e),
-            HttpError::Forbidden(e) => Self::forbidden(e),
-            HttpError::Conflict(e) => Self::conflict(e),
-            HttpError::NotFound(e) => Self::not_found(e),
-            HttpError::IdentityNotFound(e) => Self::identity_not_found(e),
-            HttpError::Locked(e) => Self::locked(e),
-            HttpError::Internal(e) => Self::internal(e),
+        let code = error.code();
+        let response = match error {
+            HttpError::Validation(e) => Self::validation(code, e),
+            HttpError::Unauthorized(e) => Self::unauthorized(code, e),
+            HttpError::TokenRevoked(e) => Self::token_revoked(code, e),
+            HttpError::ServiceUnauthorized(e) => Self::service_unauthorized(code, e),
+            HttpError::Forbidden(e) => Self::forbidden(code, e),
+            HttpError::Conflict(e) => Self::conflict(code, e),
+            HttpError::PayloadTooLarge(e) => Self::payload_too_large(code, e),
+            HttpError::NotFound(e) => Self::not_found(code, e),
+            HttpError::IdentityNotFound(e) => Self::identity_not_found(code, e),
+            HttpError::Locked(e) => Self::locked(code, e),
+            HttpError::TooManyRequests(e) => Self::too_many_requests(code, e),
+            HttpError::Internal(e) => Self::internal(code, e),
+            HttpError::ServiceUnavailable(e) => Self::service_unavailable(code, e),
+        };
+        Self {
+            retry_after_seconds: error.retry_after(),
+            ..response
         }
     }
 
+    /// Attach a request id so clients can quote it when reporting a failure
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
     /// Create a validation error response
-    fn validation(error: &ValidationError) -> Self {
+    fn validation(code: ErrorCode, error: &ValidationError) -> Self {
         Self {
             status: 400,
-            code: "VALIDATION_ERROR".to_string(),
+            code: code.to_string(),
             message: error.to_string(),
             details: error.field.as_ref().map(|field| ErrorDetails {
                 field: Some(field.clone()),
                 resource_type: None,
                 resource_id: None,
             }),
+            request_id: None,
+            retry_after_seconds: None,
+            errors: error.errors.clone(),
         }
     }
 
     /// Create an unauthorized error response
-    fn unauthorized(error: &UnauthorizedError) -> Self {
+    fn unauthorized(code: ErrorCode, error: &UnauthorizedError) -> Self {
+        Self {
+            status: 401,
+            code: code.to_string(),
+            message: error.to_string(),
+            details: None,
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Create a token revoked error response (401)
+    fn token_revoked(code: ErrorCode, error: &TokenRevokedError) -> Self {
         Self {
             status: 401,
-            code: "UNAUTHORIZED".to_string(),
+            code: code.to_string(),
             message: error.to_string(),
             details: None,
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
         }
     }
 
     /// Create a service unauthorized error response (401)
-    fn service_unauthorized(error: &ServiceUnauthorizedError) -> Self {
+    fn service_unauthorized(code: ErrorCode, error: &ServiceUnauthorizedError) -> Self {
         Self {
             status: 401,
-            code: "SERVICE_UNAUTHORIZED".to_string(),
+            code: code.to_string(),
             message: error.to_string(),
             details: error.service_id.as_ref().map(|id| ErrorDetails {
                 field: None,
                 resource_type: Some("service".to_string()),
                 resource_id: Some(id.clone()),
             }),
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
         }
     }
 
     /// Create a forbidden error response (403)
-    fn forbidden(error: &ForbiddenError) -> Self {
+    fn forbidden(code: ErrorCode, error: &ForbiddenError) -> Self {
         Self {
             status: 403,
-            code: "FORBIDDEN".to_string(),
+            code: code.to_string(),
             message: error.to_string(),
             details: error.required_permission.as_ref().map(|perm| ErrorDetails {
                 field: None,
                 resource_type: Some("permission".to_string()),
                 resource_id: Some(perm.clone()),
             }),
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
         }
     }
 
     /// Create an identity not found error response (404)
-    fn identity_not_found(error: &IdentityNotFoundError) -> Self {
+    fn identity_not_found(code: ErrorCode, error: &IdentityNotFoundError) -> Self {
         Self {
             status: 404,
-            code: "IDENTITY_NOT_FOUND".to_string(),
+            code: code.to_string(),
             message: error.to_string(),
             details: Some(ErrorDetails {
                 field: None,
                 resource_type: Some("identity".to_string()),
                 resource_id: Some(error.user_id.clone()),
             }),
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
         }
     }
 
     /// Create a conflict error response
-    fn conflict(error: &ConflictError) -> Self {
+    fn conflict(code: ErrorCode, error: &ConflictError) -> Self {
         Self {
             status: 409,
-            code: "CONFLICT".to_string(),
+            code: code.to_string(),
             message: error.to_string(),
             details: error.resource.as_ref().map(|resource| ErrorDetails {
                 field: None,
                 resource_type: Some(resource.clone()),
                 resource_id: None,
             }),
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
         }
     }
 
     /// Create a not found error response
-    fn not_found(error: &NotFoundError) -> Self {
+    fn not_found(code: ErrorCode, error: &NotFoundError) -> Self {
         Self {
             status: 404,
-            code: "NOT_FOUND".to_string(),
+            code: code.to_string(),
             message: error.to_string(),
             details: error.resource_type.as_ref().map(|resource_type| ErrorDetails {
                 field: None,
                 resource_type: Some(resource_type.clone()),
                 resource_id: None,
             }),
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
         }
     }
 
     /// Create an internal error response (hides details from client)
-    fn internal(_error: &InternalError) -> Self {
+    fn internal(code: ErrorCode, _error: &InternalError) -> Self {
         Self {
             status: 500,
-            code: "INTERNAL_SERVER_ERROR".to_string(),
+            code: code.to_string(),
             message: "An unexpected error occurred. Please try again later.".to_string(),
             details: None,
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Create a service unavailable error response (hides details from client)
+    fn service_unavailable(code: ErrorCode, _error: &ServiceUnavailableError) -> Self {
+        Self {
+            status: 503,
+            code: code.to_string(),
+            message: "The service is temporarily unavailable. Please try again later.".to_string(),
+            details: None,
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Create a payload too large error response (413 Payload Too Large)
+    fn payload_too_large(code: ErrorCode, error: &PayloadTooLargeError) -> Self {
+        Self {
+            status: 413,
+            code: code.to_string(),
+            message: error.to_string(),
+            details: None,
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Create a rate limit error response (429 Too Many Requests)
+    fn too_many_requests(code: ErrorCode, error: &TooManyRequestsError) -> Self {
+        Self {
+            status: 429,
+            code: code.to_string(),
+            message: error.to_string(),
+            details: None,
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
         }
     }
 
     /// Create a locked error response (423 Locked)
-    fn locked(error: &LockedError) -> Self {
+    fn locked(code: ErrorCode, error: &LockedError) -> Self {
         Self {
             status: 423,
-            code: "ACCOUNT_LOCKED".to_string(),
+            code: code.to_string(),
             message: error.to_string(),
-            details: error.retry_after.map(|_seconds| ErrorDetails {
-                field: None,
-                resource_type: None,
-                resource_id: None,
-            }),
+            details: None,
+            request_id: None,
+            retry_after_seconds: None,
+            errors: Vec::new(),
         }
     }
 }
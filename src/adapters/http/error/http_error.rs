@@ -16,49 +16,182 @@ Design Principles:
 Errors are organized by concern:
  - `ValidationError`: Input validation failures (400)
  - `AuthenticationError`: Authentication failures (401)
+ - `TokenRevokedError`: Token belongs to a revoked session (401)
+ - `BearerChallenge`: `WWW-Authenticate` error projected for bearer failures
  - `ConflictError`: Resource conflict (409)
+ - `PayloadTooLargeError`: Request body exceeds a size limit (413)
+ - `TooManyRequestsError`: Client exceeded its request rate (429)
  - `NotFoundError`: Resource not found (404)
  - `InternalError`: Unexpected server errors (500)
+ - `ServiceUnavailableError`: A backing store is unreachable (503)
  - `HttpError`: Top-level enum that wraps all of the above
+ - `ErrorCode`: Stable machine-readable code projected for each error
 */
 
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
+use crate::adapters::persistence::error::{ConstraintError, ExecutionError, PersistenceError};
+use crate::core::error::{CoreError, TokenError};
+
 #[derive(Debug, Clone)]
 pub enum HttpError {
     /// Input validation failed (400 Bad Request)
     Validation(ValidationError),
     /// Authentication failed (401 Unauthorized)
     Unauthorized(UnauthorizedError),
+    /// Token's session was revoked; the client must sign in again (401 Unauthorized)
+    TokenRevoked(TokenRevokedError),
     /// Service authentication failed (401 Unauthorized - for service-to-service)
     ServiceUnauthorized(ServiceUnauthorizedError),
     /// Forbidden - service lacks permissions (403 Forbidden)
     Forbidden(ForbiddenError),
     /// Resource conflict (409 Conflict)
     Conflict(ConflictError),
+    /// Request body exceeds a size limit (413 Payload Too Large)
+    PayloadTooLarge(PayloadTooLargeError),
     /// Resource not found (404 Not Found)
     NotFound(NotFoundError),
     /// Identity not found (404 Not Found - specific for identity lookups)
     IdentityNotFound(IdentityNotFoundError),
     /// Account locked (423 Locked)
     Locked(LockedError),
+    /// Client exceeded its request rate (429 Too Many Requests)
+    TooManyRequests(TooManyRequestsError),
     /// Unexpected server error (500 Internal Server Error)
     Internal(InternalError),
+    /// A backing store is unreachable (503 Service Unavailable)
+    ServiceUnavailable(ServiceUnavailableError),
+}
+
+/// Stable, machine-readable error code sent to clients
+///
+/// Lets clients tell failure reasons apart beyond the status code (e.g. a
+/// locked account from a wrong password). Values never change once
+/// published; new failure reasons get new codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    ValidationError,
+    Unauthorized,
+    InvalidCredentials,
+    CredentialExpired,
+    TokenRevoked,
+    ServiceUnauthorized,
+    Forbidden,
+    Conflict,
+    PayloadTooLarge,
+    NotFound,
+    IdentityNotFound,
+    AccountLocked,
+    TooManyRequests,
+    InternalServerError,
+    ServiceUnavailable,
+}
+
+impl ErrorCode {
+    /// Wire representation of the code
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::ValidationError => "VALIDATION_ERROR",
+            ErrorCode::Unauthorized => "UNAUTHORIZED",
+            ErrorCode::InvalidCredentials => "INVALID_CREDENTIALS",
+            ErrorCode::CredentialExpired => "CREDENTIAL_EXPIRED",
+            ErrorCode::TokenRevoked => "TOKEN_REVOKED",
+            ErrorCode::ServiceUnauthorized => "SERVICE_UNAUTHORIZED",
+            ErrorCode::Forbidden => "FORBIDDEN",
+            ErrorCode::Conflict => "CONFLICT",
+            ErrorCode::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
+            ErrorCode::NotFound => "NOT_FOUND",
+            ErrorCode::IdentityNotFound => "IDENTITY_NOT_FOUND",
+            ErrorCode::AccountLocked => "ACCOUNT_LOCKED",
+            ErrorCode::TooManyRequests => "TOO_MANY_REQUESTS",
+            ErrorCode::InternalServerError => "INTERNAL_SERVER_ERROR",
+            ErrorCode::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl HttpError {
+    /// Returns the machine-readable code for this error
+    ///
+    /// Deliberately has no catch-all arm: a new variant must pick its code.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            HttpError::Validation(_) => ErrorCode::ValidationError,
+            HttpError::Unauthorized(e) => match e.kind {
+                UnauthorizedKind::Unauthenticated => ErrorCode::Unauthorized,
+                UnauthorizedKind::InvalidCredentials => ErrorCode::InvalidCredentials,
+                UnauthorizedKind::CredentialExpired => ErrorCode::CredentialExpired,
+            },
+            HttpError::TokenRevoked(_) => ErrorCode::TokenRevoked,
+            HttpError::ServiceUnauthorized(_) => ErrorCode::ServiceUnauthorized,
+            HttpError::Forbidden(_) => ErrorCode::Forbidden,
+            HttpError::Conflict(_) => ErrorCode::Conflict,
+            HttpError::PayloadTooLarge(_) => ErrorCode::PayloadTooLarge,
+            HttpError::NotFound(_) => ErrorCode::NotFound,
+            HttpError::IdentityNotFound(_) => ErrorCode::IdentityNotFound,
+            HttpError::Locked(_) => ErrorCode::AccountLocked,
+            HttpError::TooManyRequests(_) => ErrorCode::TooManyRequests,
+            HttpError::Internal(_) => ErrorCode::InternalServerError,
+            HttpError::ServiceUnavailable(_) => ErrorCode::ServiceUnavailable,
+        }
+    }
+
+    /// Seconds the client should wait before retrying, if known
+    pub fn retry_after(&self) -> Option<u64> {
+        match self {
+            HttpError::Locked(e) => e.retry_after,
+            HttpError::TooManyRequests(e) => Some(e.retry_after),
+            _ => None,
+        }
+    }
+
     /// Returns the HTTP status code for this error
     pub fn status_code(&self) -> u16 {
         match self {
             HttpError::Validation(_) => 400,
             HttpError::Unauthorized(_) => 401,
+            HttpError::TokenRevoked(_) => 401,
             HttpError::ServiceUnauthorized(_) => 401,
             HttpError::Forbidden(_) => 403,
             HttpError::Conflict(_) => 409,
+            HttpError::PayloadTooLarge(_) => 413,
             HttpError::NotFound(_) => 404,
             HttpError::IdentityNotFound(_) => 404,
             HttpError::Locked(_) => 423,
+            HttpError::TooManyRequests(_) => 429,
             HttpError::Internal(_) => 500,
+            HttpError::ServiceUnavailable(_) => 503,
+        }
+    }
+
+    /// Bearer challenge to send in `WWW-Authenticate`, if any
+    pub fn challenge(&self) -> Option<BearerChallenge> {
+        match self {
+            HttpError::Unauthorized(e) => e.challenge,
+            HttpError::TokenRevoked(_) => Some(BearerChallenge::InvalidToken),
+            HttpError::Forbidden(e) => e.challenge,
+            _ => None,
+        }
+    }
+
+    /// Project a failed access token validation.
+    ///
+    /// A revoked token keeps its own status so clients re-authenticate
+    /// instead of refreshing; anything else is a server-side failure.
+    pub fn from_token_validation(err: CoreError) -> Self {
+        match err {
+            CoreError::Token(TokenError::Revoked { revoked_at }) => {
+                HttpError::TokenRevoked(TokenRevokedError::new(revoked_at))
+            }
+            other => HttpError::Internal(InternalError::new(format!("token validation failed: {}", other))),
         }
     }
 
@@ -69,7 +202,15 @@ impl HttpError {
 
     /// Returns true if this is an unauthorized error
     pub fn is_unauthorized(&self) -> bool {
-        matches!(self, HttpError::Unauthorized(_) | HttpError::ServiceUnauthorized(_))
+        matches!(
+            self,
+            HttpError::Unauthorized(_) | HttpError::TokenRevoked(_) | HttpError::ServiceUnauthorized(_)
+        )
+    }
+
+    /// Returns true if the token was revoked
+    pub fn is_token_revoked(&self) -> bool {
+        matches!(self, HttpError::TokenRevoked(_))
     }
 
     /// Returns true if this is a forbidden error
@@ -82,6 +223,11 @@ impl HttpError {
         matches!(self, HttpError::Conflict(_))
     }
 
+    /// Returns true if the request body was too large
+    pub fn is_payload_too_large(&self) -> bool {
+        matches!(self, HttpError::PayloadTooLarge(_))
+    }
+
     /// Returns true if this is a not found error
     pub fn is_not_found(&self) -> bool {
         matches!(self, HttpError::NotFound(_) | HttpError::IdentityNotFound(_))
@@ -92,10 +238,20 @@ impl HttpError {
         matches!(self, HttpError::Internal(_))
     }
 
+    /// Returns true if a backing store was unavailable
+    pub fn is_service_unavailable(&self) -> bool {
+        matches!(self, HttpError::ServiceUnavailable(_))
+    }
+
     /// Returns true if this is a locked error
     pub fn is_locked(&self) -> bool {
         matches!(self, HttpError::Locked(_))
     }
+
+    /// Returns true if this is a rate limit error
+    pub fn is_too_many_requests(&self) -> bool {
+        matches!(self, HttpError::TooManyRequests(_))
+    }
 }
 
 impl fmt::Display for HttpError {
@@ -103,19 +259,51 @@ impl fmt::Display for HttpError {
         match self {
             HttpError::Validation(e) => write!(f, "Validation error: {}", e),
             HttpError::Unauthorized(e) => write!(f, "Unauthorized: {}", e),
+            HttpError::TokenRevoked(e) => write!(f, "Token revoked: {}", e),
             HttpError::ServiceUnauthorized(e) => write!(f, "Service unauthorized: {}", e),
             HttpError::Forbidden(e) => write!(f, "Forbidden: {}", e),
             HttpError::Conflict(e) => write!(f, "Conflict: {}", e),
+            HttpError::PayloadTooLarge(e) => write!(f, "Payload too large: {}", e),
             HttpError::NotFound(e) => write!(f, "Not found: {}", e),
             HttpError::IdentityNotFound(e) => write!(f, "Identity not found: {}", e),
             HttpError::Locked(e) => write!(f, "Locked: {}", e),
+            HttpError::TooManyRequests(e) => write!(f, "Too many requests: {}", e),
             HttpError::Internal(e) => write!(f, "Internal error: {}", e),
+            HttpError::ServiceUnavailable(e) => write!(f, "Service unavailable: {}", e),
         }
     }
 }
 
 impl std::error::Error for HttpError {}
 
+/// Project a persistence failure for handlers that touch storage directly.
+///
+/// Only the category of the failure reaches the client; the underlying
+/// reason (SQL text, driver messages, constraint names) is logged here and
+/// never copied into the `HttpError`.
+impl From<PersistenceError> for HttpError {
+    fn from(err: PersistenceError) -> Self {
+        match &err {
+            PersistenceError::Execution(ExecutionError::NotFound { .. }) => {
+                tracing::debug!("[HTTP] Persistence lookup found nothing: {}", err);
+                HttpError::NotFound(NotFoundError::new("Resource not found"))
+            }
+            PersistenceError::Constraint(ConstraintError::UniqueViolation { .. }) => {
+                tracing::warn!("[HTTP] Persistence uniqueness conflict: {}", err);
+                HttpError::Conflict(ConflictError::new("Resource already exists"))
+            }
+            PersistenceError::Connection(_) => {
+                tracing::error!("[HTTP] Persistence unavailable: {}", err);
+                HttpError::ServiceUnavailable(ServiceUnavailableError::new("Storage is unavailable"))
+            }
+            _ => {
+                tracing::error!("[HTTP] Persistence failure: {}", err);
+                HttpError::Internal(InternalError::new("Persistence failure"))
+            }
+        }
+    }
+}
+
 impl axum::response::IntoResponse for HttpError {
     fn into_response(self) -> axum::response::Response {
         use axum::http::StatusCode;
@@ -126,7 +314,20 @@ impl axum::response::IntoResponse for HttpError {
         
         let error_response = crate::adapters::http::error::error_response::ErrorResponse::from_http_error(&self);
         
-        (status, Json(error_response)).into_response()
+        let mut response = (status, Json(error_response)).into_response();
+        if let Some(retry_after) = self.retry_after() {
+            response.headers_mut().insert(
+                axum::http::header::RETRY_AFTER,
+                axum::http::HeaderValue::from(retry_after),
+            );
+        }
+        if let Some(challenge) = self.challenge() {
+            response.headers_mut().insert(
+                axum::http::header::WWW_AUTHENTICATE,
+                axum::http::HeaderValue::from_static(challenge.header_value()),
+            );
+        }
+        response
     }
 }
 
@@ -134,10 +335,33 @@ impl axum::response::IntoResponse for HttpError {
 // Specific Error Types
 // ============================================================================
 
+/// One failed check on one request field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    /// Path of the offending field, e.g. `identifier`
+    pub field: String,
+    /// Machine-readable reason, e.g. `required` or `too_long`
+    pub code: String,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ValidationError {
     pub message: String,
     pub field: Option<String>,
+    /// Every field that failed validation, in check order
+    pub errors: Vec<FieldError>,
 }
 
 impl ValidationError {
@@ -145,6 +369,7 @@ impl ValidationError {
         Self {
             message: message.into(),
             field: None,
+            errors: Vec::new(),
         }
     }
 
@@ -152,8 +377,25 @@ impl ValidationError {
         Self {
             message: message.into(),
             field: Some(field.into()),
+            errors: Vec::new(),
         }
     }
+
+    /// Fail with every collected field error, or succeed if there are none
+    pub fn check(errors: Vec<FieldError>) -> Result<(), Self> {
+        let (message, field) = match errors.as_slice() {
+            [] => return Ok(()),
+            [only] => (only.message.clone(), Some(only.field.clone())),
+            all => (
+                all.iter()
+                    .map(|e| format!("{}: {}", e.field, e.message))
+                    .collect::<Vec<_>>()
+                    .join("; "),
+                None,
+            ),
+        };
+        Err(Self { message, field, errors })
+    }
 }
 
 impl fmt::Display for ValidationError {
@@ -166,15 +408,96 @@ impl fmt::Display for ValidationError {
     }
 }
 
+/// Cause of an unauthorized error, projected as its error code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnauthorizedKind {
+    /// Missing or unusable authentication
+    #[default]
+    Unauthenticated,
+    /// Identifier or password did not match
+    InvalidCredentials,
+    /// Credential is outside its validity window
+    CredentialExpired,
+}
+
+/// Error of a Bearer `WWW-Authenticate` challenge (RFC 6750, section 3.1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BearerChallenge {
+    /// Authorization header is missing or malformed
+    InvalidRequest,
+    /// Access token is expired, revoked, or otherwise invalid
+    InvalidToken,
+    /// Access token lacks a scope the request needs
+    InsufficientScope,
+}
+
+impl BearerChallenge {
+    /// Returns the `error` attribute of the challenge
+    pub fn error(&self) -> &'static str {
+        match self {
+            BearerChallenge::InvalidRequest => "invalid_request",
+            BearerChallenge::InvalidToken => "invalid_token",
+            BearerChallenge::InsufficientScope => "insufficient_scope",
+        }
+    }
+
+    /// Returns the full `WWW-Authenticate` header value
+    ///
+    /// Descriptions are fixed per error, so nothing from the request, least
+    /// of all the token, is ever echoed back.
+    pub fn header_value(&self) -> &'static str {
+        match self {
+            BearerChallenge::InvalidRequest => {
+                r#"Bearer error="invalid_request", error_description="missing or malformed Authorization header""#
+            }
+            BearerChallenge::InvalidToken => {
+                r#"Bearer error="invalid_token", error_description="the access token is invalid, expired, or revoked""#
+            }
+            BearerChallenge::InsufficientScope => {
+                r#"Bearer error="insufficient_scope", error_description="the access token lacks a required scope""#
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct UnauthorizedError {
     pub reason: String,
+    pub kind: UnauthorizedKind,
+    /// Bearer challenge sent in `WWW-Authenticate`, if any
+    pub challenge: Option<BearerChallenge>,
 }
 
 impl UnauthorizedError {
     pub fn new(reason: impl Into<String>) -> Self {
         Self {
             reason: reason.into(),
+            kind: UnauthorizedKind::Unauthenticated,
+            challenge: None,
+        }
+    }
+
+    pub fn with_kind(reason: impl Into<String>, kind: UnauthorizedKind) -> Self {
+        Self {
+            reason: reason.into(),
+            kind,
+            challenge: None,
+        }
+    }
+
+    /// Bearer credentials are missing or malformed
+    pub fn invalid_request(reason: impl Into<String>) -> Self {
+        Self {
+            challenge: Some(BearerChallenge::InvalidRequest),
+            ..Self::new(reason)
+        }
+    }
+
+    /// Bearer token was presented but is not acceptable
+    pub fn invalid_token(reason: impl Into<String>) -> Self {
+        Self {
+            challenge: Some(BearerChallenge::InvalidToken),
+            ..Self::new(reason)
         }
     }
 }
@@ -185,6 +508,29 @@ impl fmt::Display for UnauthorizedError {
     }
 }
 
+/// Token revoked error (401)
+///
+/// Distinct from a plain unauthorized response so clients know refreshing
+/// will not help and the user has to sign in again.
+#[derive(Debug, Clone)]
+pub struct TokenRevokedError {
+    pub revoked_at: String,
+}
+
+impl TokenRevokedError {
+    pub fn new(revoked_at: impl Into<String>) -> Self {
+        Self {
+            revoked_at: revoked_at.into(),
+        }
+    }
+}
+
+impl fmt::Display for TokenRevokedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "session was revoked, sign in again")
+    }
+}
+
 /// Service-to-service authentication error (401)
 #[derive(Debug, Clone)]
 pub struct ServiceUnauthorizedError {
@@ -219,6 +565,8 @@ impl fmt::Display for ServiceUnauthorizedError {
 pub struct ForbiddenError {
     pub message: String,
     pub required_permission: Option<String>,
+    /// Bearer challenge sent in `WWW-Authenticate`, if any
+    pub challenge: Option<BearerChallenge>,
 }
 
 impl ForbiddenError {
@@ -226,6 +574,7 @@ impl ForbiddenError {
         Self {
             message: message.into(),
             required_permission: None,
+            challenge: None,
         }
     }
 
@@ -233,6 +582,15 @@ impl ForbiddenError {
         Self {
             message: message.into(),
             required_permission: Some(permission.into()),
+            challenge: None,
+        }
+    }
+
+    /// Bearer token is valid but lacks the `missing` scopes
+    pub fn insufficient_scope(missing: impl Into<String>) -> Self {
+        Self {
+            challenge: Some(BearerChallenge::InsufficientScope),
+            ..Self::with_permission("insufficient scope", missing)
         }
     }
 }
@@ -351,6 +709,48 @@ impl fmt::Display for LockedError {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct PayloadTooLargeError {
+    pub message: String,
+    pub limit: usize,
+}
+
+impl PayloadTooLargeError {
+    pub fn new(message: impl Into<String>, limit: usize) -> Self {
+        Self {
+            message: message.into(),
+            limit,
+        }
+    }
+}
+
+impl fmt::Display for PayloadTooLargeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (limit {})", self.message, self.limit)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TooManyRequestsError {
+    pub message: String,
+    pub retry_after: u64,
+}
+
+impl TooManyRequestsError {
+    pub fn new(message: impl Into<String>, retry_after: u64) -> Self {
+        Self {
+            message: message.into(),
+            retry_after,
+        }
+    }
+}
+
+impl fmt::Display for TooManyRequestsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct InternalError {
     pub message: String,
@@ -378,3 +778,22 @@ impl fmt::Display for InternalError {
         write!(f, "{}", self.message)
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct ServiceUnavailableError {
+    pub message: String,
+}
+
+impl ServiceUnavailableError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceUnavailableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
@@ -22,7 +22,7 @@ pub mod http_error;
 pub mod error_response;
 
 pub use http_error::{
-    HttpError, ValidationError, UnauthorizedError, ConflictError, NotFoundError, LockedError, InternalError, ServiceUnauthorizedError
+    ErrorCode, UnauthorizedKind, BearerChallenge, HttpError, ValidationError, FieldError, UnauthorizedError, TokenRevokedError, ForbiddenError, ConflictError, NotFoundError, LockedError, InternalError, ServiceUnauthorizedError, TooManyRequestsError, PayloadTooLargeError, ServiceUnavailableError
 };
 pub use error_response::ErrorResponse;
 
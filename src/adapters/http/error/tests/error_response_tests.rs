@@ -12,6 +12,40 @@ fn test_error_response_from_validation_error() {
     assert_eq!(response.details.as_ref().unwrap().field, Some("email".to_string()));
 }
 
+#[test]
+fn test_error_response_lists_every_field_error() {
+    let error = HttpError::Validation(
+        ValidationError::check(vec![
+            FieldError::new("identifier", "required", "Identifier required"),
+            FieldError::new("password", "too_long", "Password must be at most 1024 bytes"),
+        ])
+        .unwrap_err(),
+    );
+    let response = ErrorResponse::from_http_error(&error);
+
+    assert_eq!(response.status, 400);
+    assert!(response.details.is_none(), "no single field to point at");
+    let fields: Vec<_> = response.errors.iter().map(|e| (e.field.as_str(), e.code.as_str())).collect();
+    assert_eq!(fields, vec![("identifier", "required"), ("password", "too_long")]);
+}
+
+#[test]
+fn test_error_response_single_field_error_keeps_details() {
+    let error = HttpError::Validation(
+        ValidationError::check(vec![FieldError::new("refresh_token", "required", "Refresh token required")])
+            .unwrap_err(),
+    );
+    let response = ErrorResponse::from_http_error(&error);
+
+    assert_eq!(response.details.as_ref().unwrap().field, Some("refresh_token".to_string()));
+    assert_eq!(response.errors.len(), 1);
+}
+
+#[test]
+fn test_validation_check_passes_without_field_errors() {
+    assert!(ValidationError::check(Vec::new()).is_ok());
+}
+
 #[test]
 fn test_error_response_from_unauthorized_error() {
     let error = HttpError::Unauthorized(UnauthorizedError::new("Invalid token"));
@@ -22,6 +56,16 @@ fn test_error_response_from_unauthorized_error() {
     assert!(response.details.is_none());
 }
 
+#[test]
+fn test_error_response_from_token_revoked_error() {
+    let error = HttpError::TokenRevoked(TokenRevokedError::new("2025-03-01T12:00:00+00:00"));
+    let response = ErrorResponse::from_http_error(&error);
+
+    assert_eq!(response.status, 401);
+    assert_eq!(response.code, "TOKEN_REVOKED");
+    assert!(!response.message.contains("2025"));
+}
+
 #[test]
 fn test_error_response_from_conflict_error() {
     let error = HttpError::Conflict(ConflictError::with_resource("Duplicate entry", "Credential"));
@@ -75,4 +119,150 @@ fn test_error_response_details_not_included_when_empty() {
 
     // details field should not be in JSON if None
     assert!(!json.contains("details"));
+    assert!(!json.contains("errors"));
+}
+
+#[test]
+fn test_error_response_code_for_every_variant() {
+    let cases = [
+        (HttpError::Validation(ValidationError::new("bad")), "VALIDATION_ERROR"),
+        (HttpError::Unauthorized(UnauthorizedError::new("missing token")), "UNAUTHORIZED"),
+        (
+            HttpError::Unauthorized(UnauthorizedError::with_kind("invalid credentials", UnauthorizedKind::InvalidCredentials)),
+            "INVALID_CREDENTIALS",
+        ),
+        (
+            HttpError::Unauthorized(UnauthorizedError::with_kind("credential expired", UnauthorizedKind::CredentialExpired)),
+            "CREDENTIAL_EXPIRED",
+        ),
+        (HttpError::TokenRevoked(TokenRevokedError::new("2025-03-01T12:00:00+00:00")), "TOKEN_REVOKED"),
+        (HttpError::ServiceUnauthorized(ServiceUnauthorizedError::new("bad key")), "SERVICE_UNAUTHORIZED"),
+        (HttpError::Forbidden(ForbiddenError::new("nope")), "FORBIDDEN"),
+        (HttpError::Conflict(ConflictError::new("exists")), "CONFLICT"),
+        (HttpError::PayloadTooLarge(PayloadTooLargeError::new("too big", 10)), "PAYLOAD_TOO_LARGE"),
+        (HttpError::NotFound(NotFoundError::new("missing")), "NOT_FOUND"),
+        (HttpError::IdentityNotFound(IdentityNotFoundError::new("user123")), "IDENTITY_NOT_FOUND"),
+        (HttpError::Locked(LockedError::new("locked")), "ACCOUNT_LOCKED"),
+        (HttpError::TooManyRequests(TooManyRequestsError::new("slow down", 5)), "TOO_MANY_REQUESTS"),
+        (HttpError::Internal(InternalError::new("boom")), "INTERNAL_SERVER_ERROR"),
+        (HttpError::ServiceUnavailable(ServiceUnavailableError::new("down")), "SERVICE_UNAVAILABLE"),
+    ];
+
+    for (error, code) in cases {
+        let response = ErrorResponse::from_http_error(&error);
+        assert_eq!(response.code, code, "wrong code for {}", error);
+        assert_eq!(response.code, error.code().as_str());
+        assert_eq!(response.status, error.status_code());
+    }
+}
+
+#[test]
+fn test_error_response_locked_includes_retry_after_seconds() {
+    let error = HttpError::Locked(LockedError::with_retry_after("account is locked", 90));
+    let response = ErrorResponse::from_http_error(&error);
+
+    assert_eq!(response.status, 423);
+    assert_eq!(response.retry_after_seconds, Some(90));
+    assert!(response.details.is_none());
+
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["retry_after_seconds"], 90);
+}
+
+#[test]
+fn test_error_response_locked_without_known_expiry_omits_retry_after() {
+    let error = HttpError::Locked(LockedError::new("account is locked"));
+    let json = serde_json::to_string(&ErrorResponse::from_http_error(&error)).unwrap();
+
+    assert!(!json.contains("retry_after_seconds"));
+}
+
+#[test]
+fn test_locked_error_sets_retry_after_header() {
+    use axum::response::IntoResponse;
+
+    let response = HttpError::Locked(LockedError::with_retry_after("account is locked", 90)).into_response();
+
+    assert_eq!(response.status(), axum::http::StatusCode::LOCKED);
+    assert_eq!(response.headers().get(axum::http::header::RETRY_AFTER).unwrap(), "90");
+}
+
+#[test]
+fn test_bearer_failures_set_www_authenticate_challenge() {
+    use axum::response::IntoResponse;
+
+    let cases = [
+        (HttpError::Unauthorized(UnauthorizedError::invalid_request("Missing Authorization header")), "invalid_request"),
+        (HttpError::Unauthorized(UnauthorizedError::invalid_token("token expired")), "invalid_token"),
+        (HttpError::TokenRevoked(TokenRevokedError::new("2025-03-01T12:00:00+00:00")), "invalid_token"),
+        (HttpError::Forbidden(ForbiddenError::insufficient_scope("admin:write")), "insufficient_scope"),
+    ];
+
+    for (error, expected) in cases {
+        let response = error.into_response();
+        let challenge = response.headers().get(axum::http::header::WWW_AUTHENTICATE).unwrap().to_str().unwrap();
+        assert!(challenge.starts_with(&format!(r#"Bearer error="{}", error_description=""#, expected)));
+    }
+}
+
+#[test]
+fn test_other_errors_omit_www_authenticate() {
+    use axum::response::IntoResponse;
+
+    let invalid_credentials = HttpError::Unauthorized(UnauthorizedError::with_kind(
+        "invalid credentials",
+        UnauthorizedKind::InvalidCredentials,
+    ));
+    let forbidden = HttpError::Forbidden(ForbiddenError::new("service lacks permission"));
+
+    for error in [invalid_credentials, forbidden] {
+        assert!(error.into_response().headers().get(axum::http::header::WWW_AUTHENTICATE).is_none());
+    }
+}
+
+#[test]
+fn test_persistence_errors_project_to_expected_status() {
+    use crate::adapters::persistence::error::{ConnectionError, ConstraintError, PersistenceError};
+
+    let cases = vec![
+        (PersistenceError::not_found("identity"), 404),
+        (PersistenceError::unique_violation("duplicate key value violates unique constraint \"identity_pkey\""), 409),
+        (PersistenceError::Connection(ConnectionError::unavailable("connection refused")), 503),
+        (PersistenceError::Connection(ConnectionError::timeout("pool timed out while waiting")), 503),
+        (PersistenceError::Connection(ConnectionError::pool_exhausted("no idle connections")), 503),
+        (PersistenceError::Constraint(ConstraintError::foreign_key_violation("violates fk_session_user")), 500),
+        (PersistenceError::query_failed("syntax error at or near \"SELEC\""), 500),
+        (PersistenceError::transaction_failed("could not serialize access"), 500),
+        (PersistenceError::corrupted_state("row count mismatch"), 500),
+        (PersistenceError::deserialization_failed("identity", "invalid uuid"), 500),
+    ];
+
+    for (err, status) in cases {
+        let debug = format!("{:?}", err);
+        let error: HttpError = err.into();
+        assert_eq!(error.status_code(), status, "wrong status for {}", debug);
+    }
+}
+
+#[test]
+fn test_persistence_error_body_carries_no_database_detail() {
+    use crate::adapters::persistence::error::{ConnectionError, PersistenceError};
+
+    let secret = "SELECT password_hash FROM credential WHERE user_id = $1";
+    let cases = vec![
+        PersistenceError::not_found(secret),
+        PersistenceError::unique_violation(secret),
+        PersistenceError::Connection(ConnectionError::timeout(secret)),
+        PersistenceError::query_failed(secret),
+        PersistenceError::deserialization_failed("credential", secret),
+    ];
+
+    for err in cases {
+        let error: HttpError = err.into();
+        let body = serde_json::to_string(&ErrorResponse::from_http_error(&error)).unwrap();
+
+        assert!(!body.contains("SELECT"), "database detail leaked: {}", body);
+        assert!(!body.contains("password_hash"), "database detail leaked: {}", body);
+        assert!(!error.to_string().contains("SELECT"), "database detail kept: {}", error);
+    }
 }
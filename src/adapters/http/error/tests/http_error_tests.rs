@@ -31,6 +31,34 @@ fn test_http_error_internal_status_code() {
     assert_eq!(error.status_code(), 500);
 }
 
+#[test]
+fn test_http_error_too_many_requests_status_code() {
+    let error = HttpError::TooManyRequests(TooManyRequestsError::new("Slow down", 30));
+    assert_eq!(error.status_code(), 429);
+    assert!(error.is_too_many_requests());
+}
+
+#[test]
+fn test_http_error_payload_too_large_status_code() {
+    let error = HttpError::PayloadTooLarge(PayloadTooLargeError::new("Batch too large", 100));
+    assert_eq!(error.status_code(), 413);
+    assert!(error.is_payload_too_large());
+    assert!(error.to_string().contains("limit 100"));
+}
+
+#[test]
+fn test_http_error_from_token_validation_keeps_revocation_distinct() {
+    use crate::core::error::{CoreError, TokenError};
+
+    let revoked = HttpError::from_token_validation(CoreError::Token(TokenError::revoked("2025-03-01T12:00:00+00:00")));
+    assert_eq!(revoked.status_code(), 401);
+    assert!(revoked.is_token_revoked());
+    assert!(revoked.is_unauthorized());
+
+    let expired = HttpError::from_token_validation(CoreError::Token(TokenError::expired("2025-03-01T12:00:00+00:00")));
+    assert!(expired.is_internal());
+}
+
 #[test]
 fn test_http_error_type_checks() {
     let validation_error = HttpError::Validation(ValidationError::new("Invalid"));
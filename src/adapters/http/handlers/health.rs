@@ -0,0 +1,109 @@
+// Health probe handlers
+use axum::{
+    extract::State,
+    http::StatusCode,
+    Json,
+};
+use std::time::Duration;
+
+use crate::adapters::http::{
+    dto::health::{DatabaseReadiness, PoolStatusResponse, ReadinessResponse},
+    health_check::HealthReport,
+    state::AppState,
+};
+use crate::adapters::persistence::Database;
+
+/// Probe latency above which the database is reported as degraded
+pub const DATABASE_DEGRADED_THRESHOLD: Duration = Duration::from_millis(250);
+
+/// Longest a readiness probe waits for the database before reporting it down
+pub const READINESS_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Liveness probe - always returns 200 if the process is serving requests
+///
+/// Never touches the database, so an outage does not get the service
+/// restarted; that is what readiness is for.
+pub async fn liveness() -> &'static str {
+    "OK"
+}
+
+/// Readiness probe - checks if service is ready to handle traffic
+///
+/// Runs `SELECT 1` against the pool, bounded by [`READINESS_PROBE_TIMEOUT`],
+/// and reports its latency and the pool occupancy. A reachable-but-slow
+/// database is flagged as degraded without failing the probe.
+///
+/// # Returns
+/// - 200 OK when ready or degraded
+/// - 503 Service Unavailable when the database is unreachable
+pub async fn readiness(State(state): State<AppState>) -> (StatusCode, Json<ReadinessResponse>) {
+    let (status, response) = probe_readiness(state.database.as_ref()).await;
+    (status, Json(response))
+}
+
+/// Component health report - probes every registered subsystem
+///
+/// Each component is probed concurrently, bounded by the checker's timeout.
+/// Without a registered checker the report is healthy and lists no checks.
+///
+/// # Returns
+/// - 200 OK when healthy or degraded
+/// - 503 Service Unavailable when a critical component is unhealthy
+pub async fn health_report(State(state): State<AppState>) -> (StatusCode, Json<HealthReport>) {
+    let report = match state.health_checker.as_deref() {
+        Some(checker) => checker.check().await,
+        None => HealthReport::from_checks(Vec::new()),
+    };
+    (report.status_code(), Json(report))
+}
+
+/// Run the readiness probe against an optional database.
+pub async fn probe_readiness(database: Option<&Database>) -> (StatusCode, ReadinessResponse) {
+    let Some(database) = database else {
+        return (StatusCode::OK, ReadinessResponse { status: "ready".to_string(), database: None });
+    };
+
+    let probe = tokio::time::timeout(READINESS_PROBE_TIMEOUT, database.health_with_latency()).await;
+    let pool = database.pool_status().into();
+
+    match probe {
+        Ok(Ok(health)) => {
+            let degraded = health.is_degraded(DATABASE_DEGRADED_THRESHOLD);
+            (
+                StatusCode::OK,
+                ReadinessResponse {
+                    status: if degraded { "degraded" } else { "ready" }.to_string(),
+                    database: Some(DatabaseReadiness {
+                        status: "up".to_string(),
+                        latency_ms: Some(health.latency_ms()),
+                        degraded: Some(degraded),
+                        pool,
+                    }),
+                },
+            )
+        }
+        Ok(Err(e)) => {
+            tracing::warn!("[READINESS] Database probe failed: {}", e);
+            not_ready(pool)
+        }
+        Err(_) => {
+            tracing::warn!("[READINESS] Database probe timed out after {:?}", READINESS_PROBE_TIMEOUT);
+            not_ready(pool)
+        }
+    }
+}
+
+fn not_ready(pool: PoolStatusResponse) -> (StatusCode, ReadinessResponse) {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        ReadinessResponse {
+            status: "not_ready".to_string(),
+            database: Some(DatabaseReadiness {
+                status: "down".to_string(),
+                latency_ms: None,
+                degraded: None,
+                pool,
+            }),
+        },
+    )
+}
@@ -1,13 +1,21 @@
-// Internal credential creation handler
+// Internal credential handlers
 use axum::{
     extract::State,
     http::StatusCode,
-    Json,
+    Extension, Json,
 };
 use uuid::Uuid;
+use crate::core::credentials::{CredentialStatus, RawCredential};
+use crate::core::error::CredentialError;
+use crate::core::usecases::ports::{AuditEvent, AuditEventType, AuditOutcome, BatchCreateOutcome, NewIdentity};
 use crate::adapters::http::{
-    dto::internal::{CreateCredentialRequest, CreateCredentialResponse},
-    error::{HttpError, ValidationError, ConflictError, InternalError},
+    dto::internal::{
+        BatchItemResult, BatchItemStatus, CreateCredentialRequest, CreateCredentialResponse,
+        CreateCredentialsBatchRequest, CreateCredentialsBatchResponse, RevokeCredentialRequest,
+        RevokeCredentialResponse, UnlockAccountRequest, UnlockAccountResponse, MAX_CREDENTIAL_BATCH_SIZE,
+    },
+    error::{HttpError, ValidationError, ConflictError, InternalError, NotFoundError, PayloadTooLargeError},
+    middleware::ServiceContext,
     router::CleanJson,
     state::AppState,
 };
@@ -24,18 +32,28 @@ pub async fn create_credential(
     CleanJson(request): CleanJson<CreateCredentialRequest>,
 ) -> Result<(StatusCode, Json<CreateCredentialResponse>), HttpError> {
     // Validate request structure
-    request.validate()
-        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+    request.validate().map_err(HttpError::Validation)?;
 
     // Parse the user_id provided by the User Service
     let user_id = Uuid::parse_str(&request.user_id)
         .map_err(|_| HttpError::Validation(ValidationError::new("invalid user_id format")))?;
 
+    // Store the identifier in the form logins will look it up in
+    let identifier = state.normalize_identifier(&request.identifier);
+    if identifier.is_empty() {
+        return Err(HttpError::Validation(ValidationError::with_field("Identifier cannot be empty", "identifier")));
+    }
+
     // Step 1: Check if identifier already exists
-    if state.identity_repo.find_by_identifier(&request.identifier).await.is_some() {
+    if state.identity_repo.exists(&identifier).await {
         return Err(HttpError::Conflict(ConflictError::new("identifier already exists")));
     }
 
+    // Reject passwords the credential policy or the breach checker refuses
+    check_password_policy(&state, &request.password, &identifier)
+        .await
+        .map_err(|e| HttpError::Validation(ValidationError::with_field(e.to_string(), "password")))?;
+
     // Step 2: Hash the password
     let hashed_credential = state.password_hasher.hash(&request.password);
 
@@ -44,7 +62,7 @@ pub async fn create_credential(
 
     state.identity_repo.create(
         &user_id,
-        &request.identifier,
+        &identifier,
         hashed_credential.as_hash_str(),
         "", // salt is embedded in the hash string (PHC format)
         "", // algorithm is embedded in the hash string
@@ -58,9 +76,243 @@ pub async fn create_credential(
     // Step 5: Return success response
     let response = CreateCredentialResponse {
         user_id: user_id.to_string(),
-        identifier: request.identifier,
+        identifier,
         created_at: created_at.to_rfc3339(),
     };
 
     Ok((StatusCode::CREATED, Json(response)))
 }
+
+/// Create many credentials at once (internal endpoint)
+///
+/// Every item is validated and reported on its own. In atomic mode (the
+/// default) either all items are created or none is: a single invalid or
+/// conflicting item leaves the store untouched and the remaining items
+/// report `rolled_back`.
+///
+/// # Returns
+/// - 201 Created if every item was created
+/// - 200 OK with per-item results otherwise
+/// - 400 Bad Request if the batch is empty
+/// - 413 Payload Too Large if the batch exceeds the size limit
+/// - 500 Internal Server Error on server failure
+pub async fn create_credentials_batch(
+    State(state): State<AppState>,
+    CleanJson(request): CleanJson<CreateCredentialsBatchRequest>,
+) -> Result<(StatusCode, Json<CreateCredentialsBatchResponse>), HttpError> {
+    if request.exceeds_size_limit() {
+        return Err(HttpError::PayloadTooLarge(PayloadTooLargeError::new(
+            "too many credentials in batch",
+            MAX_CREDENTIAL_BATCH_SIZE,
+        )));
+    }
+    request.validate()
+        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+
+    let mut results: Vec<BatchItemResult> = request
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| BatchItemResult {
+            index,
+            identifier: item.identifier.clone(),
+            status: BatchItemStatus::RolledBack,
+            error: None,
+        })
+        .collect();
+
+    // Step 1: Validate every item; invalid items are reported, never written
+    let mut valid = Vec::with_capacity(request.items.len());
+    for (index, item) in request.items.iter().enumerate() {
+        let parsed = item.validate().map_err(|e| e.to_string()).and_then(|()| {
+            Uuid::parse_str(&item.user_id).map_err(|_| "invalid user_id format".to_string())
+        });
+        let parsed = match parsed {
+            Ok(user_id) => check_password_policy(&state, &item.password, &state.normalize_identifier(&item.identifier))
+                .await
+                .map(|()| user_id)
+                .map_err(|e| e.to_string()),
+            Err(msg) => Err(msg),
+        };
+        match parsed {
+            Ok(user_id) => valid.push((index, user_id)),
+            Err(msg) => {
+                results[index].status = BatchItemStatus::Invalid;
+                results[index].error = Some(msg);
+            }
+        }
+    }
+
+    // Step 2: An atomic batch with an invalid item writes nothing
+    if request.atomic && valid.len() < request.items.len() {
+        return Ok(batch_response(request.atomic, results));
+    }
+
+    // Step 3: Hash the passwords and create the identities
+    let identities = valid
+        .iter()
+        .map(|(index, user_id)| {
+            let item = &request.items[*index];
+            NewIdentity {
+                user_id: *user_id,
+                identifier: state.normalize_identifier(&item.identifier),
+                password_hash: state.password_hasher.hash(&item.password).as_hash_str().to_string(),
+            }
+        })
+        .collect();
+
+    let outcomes = state.identity_repo
+        .create_batch(identities, request.atomic)
+        .await
+        .map_err(|e| HttpError::Internal(InternalError::new(format!("Failed to create credential batch: {}", e))))?;
+
+    // Step 4: Record outcomes; an atomic batch already initialized credential
+    // state in its transaction, other created items are initialized here
+    for ((index, user_id), outcome) in valid.iter().zip(outcomes) {
+        let result = &mut results[*index];
+        match outcome {
+            BatchCreateOutcome::Created if request.atomic => result.status = BatchItemStatus::Created,
+            BatchCreateOutcome::Created => {
+                match state.credential_repo.initialize_credential_state(&user_id.to_string()).await {
+                    Ok(()) => result.status = BatchItemStatus::Created,
+                    Err(e) => {
+                        tracing::error!("Failed to initialize credential state: {}", e);
+                        result.status = BatchItemStatus::Failed;
+                        result.error = Some("failed to initialize credential state".to_string());
+                    }
+                }
+            }
+            BatchCreateOutcome::Conflict(_) => {
+                result.status = BatchItemStatus::Conflict;
+                result.error = Some("identifier already exists".to_string());
+            }
+            BatchCreateOutcome::Failed(e) => {
+                tracing::error!("Failed to create identity in batch: {}", e);
+                result.status = BatchItemStatus::Failed;
+                result.error = Some("failed to create credential".to_string());
+            }
+            BatchCreateOutcome::RolledBack => {}
+        }
+    }
+
+    Ok(batch_response(request.atomic, results))
+}
+
+/// Check a new password against the credential policy for `identifier`
+/// and, when one is configured, the breach checker
+async fn check_password_policy(state: &AppState, password: &str, identifier: &str) -> Result<(), CredentialError> {
+    let raw = RawCredential::new(password);
+    match state.breach_checker.as_deref() {
+        Some(breach_checker) => {
+            state
+                .credential_policy
+                .validate_raw_with_breach_checker(&raw, identifier, breach_checker)
+                .await
+        }
+        None => state.credential_policy.validate_raw_for_identifier(&raw, identifier),
+    }
+}
+
+fn batch_response(
+    atomic: bool,
+    results: Vec<BatchItemResult>,
+) -> (StatusCode, Json<CreateCredentialsBatchResponse>) {
+    let created = results.iter().filter(|r| r.status == BatchItemStatus::Created).count();
+    let status = if created == results.len() { StatusCode::CREATED } else { StatusCode::OK };
+
+    (status, Json(CreateCredentialsBatchResponse { atomic, created, results }))
+}
+
+/// Revoke a user's credential (internal endpoint)
+///
+/// Marks the credential `Revoked` so later password authentication fails.
+/// Existing sessions are left alone; revoke them separately if required.
+///
+/// # Returns
+/// - 200 OK with the recorded revocation timestamp
+/// - 400 Bad Request if validation fails
+/// - 404 Not Found if the user has no credential
+/// - 500 Internal Server Error on server failure
+pub async fn revoke_credential(
+    State(state): State<AppState>,
+    CleanJson(request): CleanJson<RevokeCredentialRequest>,
+) -> Result<Json<RevokeCredentialResponse>, HttpError> {
+    request.validate()
+        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+
+    let user_id = Uuid::parse_str(&request.user_id)
+        .map_err(|_| HttpError::Validation(ValidationError::new("invalid user_id format")))?
+        .to_string();
+
+    // Step 1: Ensure the credential exists
+    if state.credential_repo.get_by_user_id(&user_id).await.is_none() {
+        return Err(HttpError::NotFound(NotFoundError::new("credential not found")));
+    }
+
+    // Step 2: Persist the revoked status with its timestamp
+    let revoked_at = request
+        .revoked_at
+        .unwrap_or_else(|| state.clock.now().to_rfc3339());
+
+    state.credential_repo
+        .set_status(&user_id, CredentialStatus::Revoked { revoked_at: Some(revoked_at.clone()) })
+        .await
+        .map_err(|e| HttpError::Internal(InternalError::new(format!("Failed to revoke credential: {}", e))))?;
+
+    Ok(Json(RevokeCredentialResponse { user_id, revoked_at }))
+}
+
+/// Unlock a locked-out account (internal endpoint)
+///
+/// Resets the failed attempts counter and clears the lock, for the account
+/// and every source address. Unlocking an account that is not locked
+/// succeeds and changes nothing.
+///
+/// # Returns
+/// - 200 OK with whether the account was locked
+/// - 400 Bad Request if validation fails
+/// - 404 Not Found if the user has no credential
+/// - 500 Internal Server Error on server failure
+pub async fn unlock_account(
+    State(state): State<AppState>,
+    Extension(service_context): Extension<ServiceContext>,
+    CleanJson(request): CleanJson<UnlockAccountRequest>,
+) -> Result<Json<UnlockAccountResponse>, HttpError> {
+    request.validate()
+        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+
+    let user_id = Uuid::parse_str(&request.user_id)
+        .map_err(|_| HttpError::Validation(ValidationError::new("invalid user_id format")))?
+        .to_string();
+
+    // Step 1: Ensure the credential exists
+    let Some(credential) = state.credential_repo.get_by_user_id(&user_id).await else {
+        return Err(HttpError::NotFound(NotFoundError::new("credential not found")));
+    };
+    let was_locked = credential.failed_attempts > 0
+        || credential.locked_until.is_some()
+        || state.credential_repo.has_source_lockouts(&user_id).await;
+
+    // Step 2: Clear the counter and the lock
+    state.credential_repo
+        .unlock(&user_id)
+        .await
+        .map_err(|e| HttpError::Internal(InternalError::new(format!("Failed to unlock account: {}", e))))?;
+
+    tracing::info!(
+        "[UNLOCK_ACCOUNT] Account {} unlocked by service {}",
+        user_id,
+        service_context.service_id
+    );
+
+    if let Some(audit_sink) = state.audit_sink.as_deref() {
+        audit_sink
+            .record_or_log(
+                AuditEvent::new(&user_id, AuditEventType::AccountUnlocked, state.clock.now(), AuditOutcome::Success)
+                    .with_reason(format!("unlocked by {}", service_context.service_id)),
+            )
+            .await;
+    }
+
+    Ok(Json(UnlockAccountResponse { user_id, was_locked }))
+}
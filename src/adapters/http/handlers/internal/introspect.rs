@@ -0,0 +1,63 @@
+// Internal token introspection handler
+// Handles POST /internal/introspect - reports whether an access token is active
+
+use axum::{
+    extract::State,
+    extract::Extension,
+    Json,
+};
+
+use crate::adapters::http::{
+    dto::internal::{IntrospectRequest, IntrospectResponse},
+    error::{HttpError, ValidationError},
+    middleware::ServiceContext,
+    state::AppState,
+};
+use crate::core::token::Token;
+use crate::core::usecases::{IntrospectTokenInput, IntrospectTokenOutput};
+
+/// Introspect an access token (internal endpoint)
+///
+/// Expired, malformed and revoked tokens all answer `{ "active": false }`
+/// with no claims, so callers learn nothing about tokens they must reject.
+///
+/// # Returns
+/// - 200 OK with the introspection result
+/// - 400 Bad Request if validation fails
+pub async fn introspect(
+    State(state): State<AppState>,
+    Extension(service_context): Extension<ServiceContext>,
+    Json(request): Json<IntrospectRequest>,
+) -> Result<Json<IntrospectResponse>, HttpError> {
+    // Validate request
+    request.validate()
+        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+
+    tracing::debug!(
+        "[INTROSPECT] Request received from service: {}",
+        service_context.service_id
+    );
+
+    let use_case = state.token_introspector();
+
+    let output = use_case
+        .execute(IntrospectTokenInput {
+            token: Token::new(request.token),
+        })
+        .await
+        .unwrap_or_else(|_| IntrospectTokenOutput::inactive());
+
+    let response = match output.claims {
+        Some(claims) => IntrospectResponse {
+            active: true,
+            sub: Some(claims.sub),
+            exp: Some(claims.exp),
+            iat: claims.iat,
+            scope: (!claims.scope.is_empty()).then(|| claims.scope.join(" ")),
+            token_type: Some(claims.token_type),
+        },
+        None => IntrospectResponse::inactive(),
+    };
+
+    Ok(Json(response))
+}
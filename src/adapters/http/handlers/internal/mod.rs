@@ -1,11 +1,15 @@
 // Internal handlers module
 pub mod credentials;
+pub mod introspect;
+pub mod revoke_token;
 pub mod service_token;
 pub mod session;
 
-pub use credentials::create_credential;
+pub use credentials::{create_credential, create_credentials_batch, revoke_credential, unlock_account};
+pub use introspect::introspect;
+pub use revoke_token::{revoke_access_token, revoke_all_tokens};
 pub use service_token::issue_service_token;
-pub use session::issue_session_tokens;
+pub use session::{issue_session_tokens, list_session_history, revoke_session};
 
 #[cfg(test)]
 pub mod tests;
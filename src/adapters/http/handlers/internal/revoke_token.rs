@@ -0,0 +1,101 @@
+// Internal access token revocation handlers
+// Handles POST /internal/token/revoke - denies a single access token until it expires
+// Handles POST /internal/token/revoke-all - rejects every access token issued so far
+
+use axum::{
+    extract::State,
+    extract::Extension,
+    Json,
+};
+
+use crate::adapters::http::{
+    dto::internal::{RevokeAccessTokenRequest, RevokeAccessTokenResponse, RevokeAllTokensResponse},
+    error::{HttpError, ValidationError, InternalError},
+    middleware::ServiceContext,
+    state::AppState,
+};
+use crate::core::error::CoreError;
+use crate::core::token::Token;
+use crate::core::usecases::{RevokeAccessToken, RevokeAccessTokenInput, RevokeAllTokens};
+
+/// Revoke a single access token (internal endpoint)
+///
+/// For security tooling responding to a leaked token. The token is denied
+/// until its own expiry; its session stays active, so revoke the session
+/// as well to also stop refreshes. Revoking a token twice succeeds again.
+///
+/// # Returns
+/// - 200 OK with the denied token's jti and expiry
+/// - 400 Bad Request if validation fails or the token is not a valid access token
+/// - 500 Internal Server Error if no deny-list is configured or on server failure
+pub async fn revoke_access_token(
+    State(state): State<AppState>,
+    Extension(service_context): Extension<ServiceContext>,
+    Json(request): Json<RevokeAccessTokenRequest>,
+) -> Result<Json<RevokeAccessTokenResponse>, HttpError> {
+    request.validate()
+        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+
+    let deny_list = state.token_deny_list.as_deref()
+        .ok_or_else(|| HttpError::Internal(InternalError::new("access token deny-list is not configured")))?;
+
+    let use_case = RevokeAccessToken::new(state.token_service.as_ref(), deny_list);
+    let output = use_case
+        .execute(RevokeAccessTokenInput {
+            access_token: Token::new(request.token),
+        })
+        .await
+        .map_err(|e| match e {
+            CoreError::Token(_) => HttpError::Validation(ValidationError::with_field(
+                "not a valid access token",
+                "token",
+            )),
+            other => HttpError::Internal(InternalError::new(format!("Failed to revoke access token: {}", other))),
+        })?;
+
+    tracing::info!(
+        "[REVOKE_TOKEN] Access token {} denied until {} by service {}",
+        output.jti,
+        output.expires_at,
+        service_context.service_id
+    );
+
+    Ok(Json(RevokeAccessTokenResponse {
+        jti: output.jti,
+        expires_at: output.expires_at,
+    }))
+}
+
+/// Revoke every access token issued so far (internal endpoint)
+///
+/// Global kill switch for an incident. Advances the persisted issued-at
+/// watermark to the current second; every access token issued at or before
+/// it fails validation from then on, on every instance and across restarts.
+/// Sessions stay active, so clients holding a refresh token can obtain new
+/// access tokens; revoke sessions as well to force users to sign in again.
+///
+/// # Returns
+/// - 200 OK with the new watermark
+/// - 500 Internal Server Error if no watermark store is configured or on server failure
+pub async fn revoke_all_tokens(
+    State(state): State<AppState>,
+    Extension(service_context): Extension<ServiceContext>,
+) -> Result<Json<RevokeAllTokensResponse>, HttpError> {
+    let watermark = state.token_watermark.as_deref()
+        .ok_or_else(|| HttpError::Internal(InternalError::new("token watermark is not configured")))?;
+
+    let output = RevokeAllTokens::new(watermark, state.clock.as_ref())
+        .execute()
+        .await
+        .map_err(|e| HttpError::Internal(InternalError::new(format!("Failed to revoke all tokens: {}", e))))?;
+
+    tracing::warn!(
+        "[REVOKE_TOKEN] All access tokens issued at or before {} revoked by service {}",
+        output.revoked_through,
+        service_context.service_id
+    );
+
+    Ok(Json(RevokeAllTokensResponse {
+        revoked_through: output.revoked_through,
+    }))
+}
@@ -37,7 +37,7 @@ pub async fn issue_service_token(
         state.service_registry.as_ref(),
         state.password_hasher.clone(),
         state.token_service.as_ref(),
-        state.service_token_ttl_seconds,
+        state.config.tokens.service_ttl_secs,
     );
 
     let input = crate::core::usecases::IssueServiceTokenInput {
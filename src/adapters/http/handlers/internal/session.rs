@@ -1,19 +1,31 @@
-// Internal session token handler
+// Internal session handlers
 // Handles POST /internal/token/issue - issues session tokens for a user
+// Handles POST /internal/sessions/revoke - revokes a session by id
+// Handles GET /internal/sessions/history - pages through a user's sessions
 
 use axum::{
     extract::State,
     extract::Extension,
+    extract::Query,
     Json,
 };
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::DateTime;
 
 use crate::adapters::http::{
-    dto::internal::{IssueSessionTokensRequest, IssueSessionTokensResponse},
-    error::{HttpError, ValidationError, InternalError},
+    dto::internal::{
+        IssueSessionTokensRequest, IssueSessionTokensResponse, PaginationInfo, RevokeSessionRequest,
+        RevokeSessionResponse, SessionHistoryEntry, SessionHistoryQuery, SessionHistoryResponse,
+    },
+    error::{HttpError, ValidationError, InternalError, NotFoundError},
     error::http_error::IdentityNotFoundError,
     middleware::ServiceContext,
     state::AppState,
 };
+use crate::core::error::CoreError;
+use crate::core::usecases::list_session_history::{ListSessionHistory, ListSessionHistoryInput};
+use crate::core::usecases::ports::{SessionCursor, SessionRecord};
+use crate::core::usecases::revoke_session::{RevokeSession, RevokeSessionInput};
 
 /// Issue session tokens for an identity (internal endpoint)
 ///
@@ -44,8 +56,9 @@ pub async fn issue_session_tokens(
         state.identity_repo.as_ref(),
         state.session_repo.as_ref(),
         state.token_service.as_ref(),
-        state.access_token_ttl_seconds,
-        state.refresh_token_ttl_days,
+        state.random.as_ref(),
+        state.config.tokens.access_ttl_secs,
+        state.config.tokens.refresh_ttl_days,
     );
 
     let input = crate::core::usecases::IssueSessionForIdentityInput {
@@ -91,3 +104,156 @@ pub async fn issue_session_tokens(
         }
     }
 }
+
+/// Revoke a session by id (internal endpoint)
+///
+/// For admin and security tooling. Revoking a session that is already
+/// revoked succeeds again with `revoked: false`, so retries are safe.
+///
+/// # Returns
+/// - 200 OK if the session is revoked (now or earlier)
+/// - 400 Bad Request if validation fails
+/// - 404 Not Found if no such session exists or it has expired
+/// - 500 Internal Server Error on server failure
+pub async fn revoke_session(
+    State(state): State<AppState>,
+    Extension(service_context): Extension<ServiceContext>,
+    Json(request): Json<RevokeSessionRequest>,
+) -> Result<Json<RevokeSessionResponse>, HttpError> {
+    request.validate()
+        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+
+    let use_case = RevokeSession::new(state.session_repo.as_ref());
+    let input = RevokeSessionInput {
+        session_id: Some(request.session_id.clone()),
+        refresh_token_hash: None,
+    };
+
+    let revoked = match use_case.execute(input).await {
+        Ok(output) => output.revoked,
+        // No active session: either revoked earlier or never existed
+        Err(CoreError::Authentication(_)) => {
+            if state.session_repo.revoked_at(&request.session_id).await.is_none() {
+                return Err(HttpError::NotFound(NotFoundError::new("session not found")));
+            }
+            false
+        }
+        Err(e) => {
+            return Err(HttpError::Internal(InternalError::new(format!(
+                "Failed to revoke session: {}",
+                e
+            ))));
+        }
+    };
+
+    tracing::info!(
+        "[REVOKE_SESSION] Session {} revoked by service {} (already revoked: {})",
+        request.session_id,
+        service_context.service_id,
+        !revoked
+    );
+
+    Ok(Json(RevokeSessionResponse {
+        session_id: request.session_id,
+        revoked,
+    }))
+}
+
+/// Page through a user's sessions, including revoked and expired ones (internal endpoint)
+///
+/// For support and security review. Pass `next_cursor` from one page as
+/// `cursor` to get the next; the last page has no `next_cursor`.
+///
+/// # Returns
+/// - 200 OK with one page of sessions, newest first
+/// - 400 Bad Request if validation fails or the cursor is malformed
+/// - 500 Internal Server Error on server failure
+pub async fn list_session_history(
+    State(state): State<AppState>,
+    Extension(service_context): Extension<ServiceContext>,
+    Query(query): Query<SessionHistoryQuery>,
+) -> Result<Json<SessionHistoryResponse>, HttpError> {
+    query.validate()
+        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+
+    let cursor = query
+        .cursor
+        .as_deref()
+        .map(decode_session_cursor)
+        .transpose()
+        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+
+    let use_case = ListSessionHistory::new(state.session_repo.as_ref());
+    let input = ListSessionHistoryInput {
+        user_id: query.user_id.clone(),
+        cursor,
+        limit: query.limit,
+    };
+
+    let output = use_case.execute(input).await.map_err(|e| {
+        HttpError::Internal(InternalError::new(format!(
+            "Failed to list session history: {}",
+            e
+        )))
+    })?;
+
+    tracing::info!(
+        "[SESSION_HISTORY] Service {} listed {} session(s) for user {}",
+        service_context.service_id,
+        output.page.sessions.len(),
+        query.user_id
+    );
+
+    let next_cursor = output.page.next_cursor.as_ref().map(encode_session_cursor);
+    Ok(Json(SessionHistoryResponse {
+        sessions: output.page.sessions.iter().map(history_entry).collect(),
+        pagination: PaginationInfo {
+            limit: output.limit,
+            has_more: next_cursor.is_some(),
+            next_cursor,
+        },
+    }))
+}
+
+fn history_entry(session: &SessionRecord) -> SessionHistoryEntry {
+    SessionHistoryEntry {
+        session_id: session.session_id.clone(),
+        status: session.status.as_str().to_string(),
+        created_at: session.created_at.to_rfc3339(),
+        expires_at: session.expires_at.to_rfc3339(),
+        revoked_at: session.revoked_at.map(|at| at.to_rfc3339()),
+        ip_address: session.ip_address.clone(),
+        user_agent: session.user_agent.clone(),
+    }
+}
+
+/// Encode a history cursor as an opaque URL-safe token.
+///
+/// Callers must treat the token as opaque; its layout may change.
+pub fn encode_session_cursor(cursor: &SessionCursor) -> String {
+    let raw = format!("{}:{}", cursor.created_at.timestamp_micros(), cursor.session_id);
+    URL_SAFE_NO_PAD.encode(raw)
+}
+
+/// Decode a token produced by [`encode_session_cursor`].
+pub fn decode_session_cursor(token: &str) -> Result<SessionCursor, String> {
+    const INVALID: &str = "Cursor is invalid";
+
+    let raw = URL_SAFE_NO_PAD.decode(token).map_err(|_| INVALID.to_string())?;
+    let raw = String::from_utf8(raw).map_err(|_| INVALID.to_string())?;
+    let (micros, session_id) = raw.split_once(':').ok_or_else(|| INVALID.to_string())?;
+
+    let created_at = micros
+        .parse::<i64>()
+        .ok()
+        .and_then(DateTime::from_timestamp_micros)
+        .ok_or_else(|| INVALID.to_string())?;
+    if uuid::Uuid::parse_str(session_id).is_err() {
+        return Err(INVALID.to_string());
+    }
+
+    Ok(SessionCursor {
+        created_at,
+        session_id: session_id.to_string(),
+    })
+}
@@ -32,7 +32,7 @@ fn test_create_credential_empty_user_id() {
 
     let validation_result = request.validate();
     assert!(validation_result.is_err(), "Empty user_id should fail validation");
-    assert!(validation_result.unwrap_err().contains("User ID"));
+    assert!(validation_result.unwrap_err().to_string().contains("User ID"));
 }
 
 #[test]
@@ -47,7 +47,7 @@ fn test_create_credential_empty_identifier() {
 
     let validation_result = request.validate();
     assert!(validation_result.is_err(), "Empty identifier should fail validation");
-    assert!(validation_result.unwrap_err().contains("Identifier"));
+    assert!(validation_result.unwrap_err().to_string().contains("Identifier"));
 }
 
 #[test]
@@ -62,7 +62,7 @@ fn test_create_credential_empty_password() {
 
     let validation_result = request.validate();
     assert!(validation_result.is_err(), "Empty password should fail validation");
-    assert!(validation_result.unwrap_err().contains("Password"));
+    assert!(validation_result.unwrap_err().to_string().contains("Password"));
 }
 
 #[test]
@@ -78,7 +78,7 @@ fn test_create_credential_identifier_too_long() {
 
     let validation_result = request.validate();
     assert!(validation_result.is_err(), "Identifier too long should fail validation");
-    assert!(validation_result.unwrap_err().contains("Identifier too long"));
+    assert!(validation_result.unwrap_err().to_string().contains("Identifier too long"));
 }
 
 #[test]
@@ -93,7 +93,7 @@ fn test_create_credential_password_too_short() {
 
     let validation_result = request.validate();
     assert!(validation_result.is_err(), "Password too short should fail validation");
-    assert!(validation_result.unwrap_err().contains("Password too weak"));
+    assert!(validation_result.unwrap_err().to_string().contains("Password too weak"));
 }
 
 #[test]
@@ -0,0 +1,480 @@
+// Tests for create_credentials_batch handler - commit, rollback and size cap
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::post,
+    Router,
+};
+use futures::future::BoxFuture;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+
+use crate::adapters::http::dto::internal::{
+    BatchItemStatus, CreateCredentialsBatchResponse, MAX_CREDENTIAL_BATCH_SIZE,
+};
+use crate::adapters::http::state::AppState;
+
+const TAKEN_IDENTIFIER: &str = "taken@example.com";
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn app(identities: Arc<InMemoryIdentityRepo>) -> Router {
+    app_with_credentials(identities, Arc::new(Stub))
+}
+
+fn app_with_credentials(
+    identities: Arc<InMemoryIdentityRepo>,
+    credentials: Arc<dyn CredentialRepository + Send + Sync>,
+) -> Router {
+    let state = AppState::new(
+        identities,
+        credentials,
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        900,
+        30,
+        true,
+        3600,
+    );
+
+    Router::new()
+        .route("/internal/credentials/batch", post(crate::adapters::http::handlers::create_credentials_batch))
+        .with_state(state)
+}
+
+fn item(identifier: &str) -> serde_json::Value {
+    serde_json::json!({
+        "user_id": Uuid::new_v4().to_string(),
+        "identifier": identifier,
+        "password": "password123",
+    })
+}
+
+async fn post_batch(app: Router, body: serde_json::Value) -> (StatusCode, Option<CreateCredentialsBatchResponse>) {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/internal/credentials/batch")
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).ok())
+}
+
+fn statuses(response: &CreateCredentialsBatchResponse) -> Vec<BatchItemStatus> {
+    response.results.iter().map(|r| r.status).collect()
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_all_valid_batch_commits() {
+    let identities = Arc::new(InMemoryIdentityRepo::default());
+    let body = serde_json::json!({ "items": [item("a@example.com"), item("b@example.com")] });
+
+    let (status, response) = post_batch(app(identities.clone()), body).await;
+    let response = response.expect("batch response");
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(response.atomic, "atomic mode is the default");
+    assert_eq!(response.created, 2);
+    assert_eq!(statuses(&response), vec![BatchItemStatus::Created, BatchItemStatus::Created]);
+    assert!(identities.contains("a@example.com"));
+    assert!(identities.contains("b@example.com"));
+}
+
+#[tokio::test]
+async fn test_atomic_batch_with_conflict_rolls_back() {
+    let identities = Arc::new(InMemoryIdentityRepo::with_identifier(TAKEN_IDENTIFIER));
+    let body = serde_json::json!({ "items": [item("a@example.com"), item(TAKEN_IDENTIFIER)] });
+
+    let (status, response) = post_batch(app(identities.clone()), body).await;
+    let response = response.expect("batch response");
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response.created, 0);
+    assert_eq!(statuses(&response), vec![BatchItemStatus::RolledBack, BatchItemStatus::Conflict]);
+    assert!(!identities.contains("a@example.com"), "rolled back item must not be persisted");
+}
+
+#[tokio::test]
+async fn test_non_atomic_batch_keeps_successful_items() {
+    let identities = Arc::new(InMemoryIdentityRepo::with_identifier(TAKEN_IDENTIFIER));
+    let body = serde_json::json!({
+        "atomic": false,
+        "items": [item("a@example.com"), item(TAKEN_IDENTIFIER)],
+    });
+
+    let (status, response) = post_batch(app(identities.clone()), body).await;
+    let response = response.expect("batch response");
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response.created, 1);
+    assert_eq!(statuses(&response), vec![BatchItemStatus::Created, BatchItemStatus::Conflict]);
+    assert!(identities.contains("a@example.com"));
+}
+
+#[tokio::test]
+async fn test_atomic_batch_initializes_credential_state_in_its_transaction() {
+    let identities = Arc::new(InMemoryIdentityRepo::default());
+    let credentials = Arc::new(FailingCredentialState::default());
+    let body = serde_json::json!({ "items": [item("a@example.com"), item("b@example.com")] });
+
+    let (status, response) = post_batch(app_with_credentials(identities, credentials.clone()), body).await;
+    let response = response.expect("batch response");
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(statuses(&response), vec![BatchItemStatus::Created, BatchItemStatus::Created]);
+    assert_eq!(credentials.calls.load(Ordering::SeqCst), 0, "no write outside the batch transaction");
+}
+
+#[tokio::test]
+async fn test_non_atomic_batch_reports_failed_credential_state() {
+    let identities = Arc::new(InMemoryIdentityRepo::default());
+    let credentials = Arc::new(FailingCredentialState::default());
+    let body = serde_json::json!({ "atomic": false, "items": [item("a@example.com")] });
+
+    let (status, response) = post_batch(app_with_credentials(identities, credentials.clone()), body).await;
+    let response = response.expect("batch response");
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(statuses(&response), vec![BatchItemStatus::Failed]);
+    assert_eq!(credentials.calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_invalid_item_is_distinguished_from_conflict() {
+    let identities = Arc::new(InMemoryIdentityRepo::default());
+    let mut weak = item("weak@example.com");
+    weak["password"] = serde_json::json!("short");
+    let body = serde_json::json!({ "items": [item("a@example.com"), weak] });
+
+    let (_, response) = post_batch(app(identities.clone()), body).await;
+    let response = response.expect("batch response");
+
+    assert_eq!(statuses(&response), vec![BatchItemStatus::RolledBack, BatchItemStatus::Invalid]);
+    assert!(response.results[1].error.as_deref().unwrap().contains("Password too weak"));
+    assert!(!identities.contains("a@example.com"));
+}
+
+#[tokio::test]
+async fn test_batch_exceeding_size_cap_is_rejected() {
+    let identities = Arc::new(InMemoryIdentityRepo::default());
+    let items: Vec<_> = (0..=MAX_CREDENTIAL_BATCH_SIZE)
+        .map(|i| item(&format!("user{}@example.com", i)))
+        .collect();
+
+    let (status, _) = post_batch(app(identities.clone()), serde_json::json!({ "items": items })).await;
+
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert!(!identities.contains("user0@example.com"));
+}
+
+#[tokio::test]
+async fn test_empty_batch_fails_validation() {
+    let (status, _) = post_batch(
+        app(Arc::new(InMemoryIdentityRepo::default())),
+        serde_json::json!({ "items": [] }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+use crate::core::credentials::StoredCredential;
+use crate::core::error::CoreError;
+use crate::core::identity::{ExternalIdentity, UserIdentity};
+use crate::core::token::Token;
+use crate::core::usecases::ports::session_repository::Session;
+use crate::core::usecases::ports::{
+    BatchCreateOutcome, CredentialRepository, ExchangeAuthorizationCode, ExternalIdentityRepository,
+    ExternalTokenValidator, IdentityRepository, NewIdentity, PasswordHasher, ServiceRegistry, SessionRepository,
+    TokenService, UserServiceClient,
+};
+use uuid::Uuid;
+
+/// Identity store whose batches honour atomic mode
+#[derive(Default)]
+struct InMemoryIdentityRepo {
+    identities: Mutex<HashMap<String, String>>, // identifier -> user_id
+}
+
+impl InMemoryIdentityRepo {
+    fn with_identifier(identifier: &str) -> Self {
+        let repo = Self::default();
+        repo.identities
+            .lock()
+            .unwrap()
+            .insert(identifier.to_string(), Uuid::new_v4().to_string());
+        repo
+    }
+
+    fn contains(&self, identifier: &str) -> bool {
+        self.identities.lock().unwrap().contains_key(identifier)
+    }
+}
+
+impl IdentityRepository for InMemoryIdentityRepo {
+    fn find_by_identifier(&self, identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = self.identities.lock().unwrap().get(identifier).map(UserIdentity::new);
+        Box::pin(async move { result })
+    }
+
+    fn find_by_id(&self, _id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn create(
+        &self,
+        user_id: &Uuid,
+        identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        self.identities
+            .lock()
+            .unwrap()
+            .insert(identifier.to_string(), user_id.to_string());
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn create_batch(
+        &self,
+        identities: Vec<NewIdentity>,
+        atomic: bool,
+    ) -> BoxFuture<'_, Result<Vec<BatchCreateOutcome>, String>> {
+        let mut store = self.identities.lock().unwrap();
+        let mut staged = store.clone();
+        let total = identities.len();
+        let mut outcomes = Vec::with_capacity(total);
+
+        for (index, identity) in identities.into_iter().enumerate() {
+            let conflict = BatchCreateOutcome::Conflict("identifier already exists".to_string());
+            match staged.entry(identity.identifier) {
+                Entry::Occupied(_) => {
+                    if atomic {
+                        // Discard the staged copy: nothing is written
+                        let mut outcomes = vec![BatchCreateOutcome::RolledBack; total];
+                        outcomes[index] = conflict;
+                        return Box::pin(async move { Ok(outcomes) });
+                    }
+                    outcomes.push(conflict);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(identity.user_id.to_string());
+                    outcomes.push(BatchCreateOutcome::Created);
+                }
+            }
+        }
+
+        *store = staged;
+        Box::pin(async move { Ok(outcomes) })
+    }
+}
+
+/// Credential store that cannot initialize credential state
+#[derive(Default)]
+struct FailingCredentialState {
+    calls: AtomicUsize,
+}
+
+impl CredentialRepository for FailingCredentialState {
+    fn get_by_user_id(&self, _user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        Box::pin(async move { None })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, _user_id: &str, _new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async move { Err("credential store unavailable".to_string()) })
+    }
+}
+
+/// Inert implementation of the ports this handler never touches
+struct Stub;
+
+impl ExternalIdentityRepository for Stub {
+    fn find_by_provider_user(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+    ) -> BoxFuture<'_, Result<Option<Uuid>, anyhow::Error>> {
+        Box::pin(async move { Ok(None) })
+    }
+
+    fn upsert(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+        _user_id: Uuid,
+        _email: Option<&str>,
+    ) -> BoxFuture<'_, Result<Uuid, anyhow::Error>> {
+        Box::pin(async move { Ok(Uuid::nil()) })
+    }
+
+    fn delete(&self, _provider: &str, _provider_user_id: &str) -> BoxFuture<'_, Result<(), anyhow::Error>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl UserServiceClient for Stub {
+    fn register_google_user(
+        &self,
+        _request: crate::core::usecases::ports::user_service_client::RegisterGoogleUserRequest,
+    ) -> BoxFuture<'static, Result<Uuid, CoreError>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+}
+
+impl CredentialRepository for Stub {
+    fn get_by_user_id(&self, _user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        Box::pin(async move { None })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, _user_id: &str, _new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl SessionRepository for Stub {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+impl PasswordHasher for Stub {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, _raw: &str, _stored: &StoredCredential) -> bool {
+        false
+    }
+}
+
+impl TokenService for Stub {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("access_token".to_string()) })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("refresh_token".to_string()) })
+    }
+
+    fn issue_service_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("service_token".to_string()) })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+impl ServiceRegistry for Stub {
+    fn validate_api_key(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn is_service_active(&self, _service_id: &str) -> bool {
+        false
+    }
+
+    fn validate_credentials(
+        &self,
+        _service_id: &str,
+        _service_secret: &str,
+        _password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        None
+    }
+}
+
+impl ExternalTokenValidator for Stub {
+    fn validate(&self, _token: &str) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl ExchangeAuthorizationCode for Stub {
+    fn exchange(&self, _code: &str, _state: Option<&str>) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
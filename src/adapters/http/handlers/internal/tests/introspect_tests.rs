@@ -0,0 +1,329 @@
+// Tests for introspect handler - active, expired and revoked tokens
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::post,
+    Extension, Router,
+};
+use chrono::{Duration, TimeZone, Utc};
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+use crate::adapters::clock::FixedClock;
+use crate::adapters::http::dto::internal::{IntrospectRequest, IntrospectResponse};
+use crate::adapters::http::middleware::ServiceContext;
+use crate::adapters::http::state::AppState;
+
+const ISSUED_AT: i64 = 1_700_000_000;
+const EXPIRES_AT: i64 = ISSUED_AT + 900;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn app(minutes_after_issue: i64) -> Router {
+    let now = Utc.timestamp_opt(ISSUED_AT, 0).unwrap() + Duration::minutes(minutes_after_issue);
+    let state = AppState::new(
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(SingleSessionRepo),
+        Arc::new(Stub),
+        Arc::new(ClaimsTokenService),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        3600,
+        30,
+        true,
+        3600,
+    )
+    .with_clock(Arc::new(FixedClock::new(now)));
+
+    Router::new()
+        .route("/internal/introspect", post(crate::adapters::http::handlers::introspect))
+        .layer(Extension(ServiceContext::new("user_service".to_string())))
+        .with_state(state)
+}
+
+async fn introspect(app: Router, token: &str) -> serde_json::Value {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/internal/introspect")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::json!({ "token": token }).to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_introspect_active_token() {
+    let json = introspect(app(5), "active_access_token").await;
+
+    assert_eq!(json, serde_json::json!({
+        "active": true,
+        "sub": "user-1",
+        "exp": EXPIRES_AT,
+        "iat": ISSUED_AT,
+        "scope": "profile:read",
+        "token_type": "access",
+    }));
+}
+
+#[tokio::test]
+async fn test_introspect_expired_token_is_inactive() {
+    let json = introspect(app(20), "active_access_token").await;
+
+    assert_eq!(json, serde_json::json!({ "active": false }));
+}
+
+#[tokio::test]
+async fn test_introspect_revoked_session_token_is_inactive() {
+    let json = introspect(app(5), "revoked_access_token").await;
+
+    assert_eq!(json, serde_json::json!({ "active": false }));
+}
+
+#[tokio::test]
+async fn test_introspect_malformed_token_is_inactive() {
+    let json = introspect(app(5), "forged_token").await;
+
+    assert_eq!(json, serde_json::json!({ "active": false }));
+}
+
+#[test]
+fn test_introspect_empty_token_fails_validation() {
+    let request = IntrospectRequest { token: "".to_string() };
+
+    assert!(request.validate().unwrap_err().contains("Token"));
+}
+
+#[test]
+fn test_inactive_response_serializes_only_active_flag() {
+    let json = serde_json::to_value(IntrospectResponse::inactive()).unwrap();
+
+    assert_eq!(json, serde_json::json!({ "active": false }));
+}
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+use crate::core::credentials::StoredCredential;
+use crate::core::error::CoreError;
+use crate::core::identity::{ExternalIdentity, UserIdentity};
+use crate::core::token::Token;
+use crate::core::usecases::ports::session_repository::Session;
+use crate::core::usecases::ports::{
+    CredentialRepository, ExchangeAuthorizationCode, ExternalIdentityRepository, ExternalTokenValidator,
+    IdentityRepository, PasswordHasher, ServiceRegistry, SessionRepository, TokenService, UserServiceClient,
+};
+use uuid::Uuid;
+
+/// Token service that maps known tokens to access claims
+struct ClaimsTokenService;
+
+impl TokenService for ClaimsTokenService {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("access_token_123".to_string()) })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("refresh_token_123".to_string()) })
+    }
+
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let token = token.value().to_string();
+        Box::pin(async move {
+            let sid = match token.as_str() {
+                "active_access_token" => "session-1",
+                "revoked_access_token" => "session-revoked",
+                _ => return Err(()),
+            };
+            Ok(format!(
+                r#"{{"sub":"user-1","type":"access","iat":{},"exp":{},"sid":"{}","scope":["profile:read"]}}"#,
+                ISSUED_AT, EXPIRES_AT, sid
+            ))
+        })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+/// Session repository where only `session-1` is still active
+struct SingleSessionRepo;
+
+impl SessionRepository for SingleSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        let result = (session_id == "session-1").then_some(Session {});
+        Box::pin(async move { result })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+/// Inert implementation of the ports this handler never touches
+struct Stub;
+
+impl IdentityRepository for Stub {
+    fn find_by_identifier(&self, _id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl ExternalIdentityRepository for Stub {
+    fn find_by_provider_user(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+    ) -> BoxFuture<'_, Result<Option<Uuid>, anyhow::Error>> {
+        Box::pin(async move { Ok(None) })
+    }
+
+    fn upsert(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+        _user_id: Uuid,
+        _email: Option<&str>,
+    ) -> BoxFuture<'_, Result<Uuid, anyhow::Error>> {
+        Box::pin(async move { Ok(Uuid::nil()) })
+    }
+
+    fn delete(&self, _provider: &str, _provider_user_id: &str) -> BoxFuture<'_, Result<(), anyhow::Error>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl UserServiceClient for Stub {
+    fn register_google_user(
+        &self,
+        _request: crate::core::usecases::ports::user_service_client::RegisterGoogleUserRequest,
+    ) -> BoxFuture<'static, Result<Uuid, CoreError>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+}
+
+impl CredentialRepository for Stub {
+    fn get_by_user_id(&self, _user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        Box::pin(async move { None })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, _user_id: &str, _new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl PasswordHasher for Stub {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, _raw: &str, _stored: &StoredCredential) -> bool {
+        false
+    }
+}
+
+impl ServiceRegistry for Stub {
+    fn validate_api_key(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn is_service_active(&self, _service_id: &str) -> bool {
+        false
+    }
+
+    fn validate_credentials(
+        &self,
+        _service_id: &str,
+        _service_secret: &str,
+        _password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        None
+    }
+}
+
+impl ExternalTokenValidator for Stub {
+    fn validate(&self, _token: &str) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl ExchangeAuthorizationCode for Stub {
+    fn exchange(&self, _code: &str, _state: Option<&str>) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
@@ -1,4 +1,10 @@
 // Internal handler tests
 mod create_credential_tests;
+mod create_credentials_batch_tests;
+mod introspect_tests;
+mod revoke_session_tests;
+mod revoke_token_tests;
+mod session_history_tests;
 mod service_token_tests;
-mod session_tests;
\ No newline at end of file
+mod session_tests;
+mod unlock_account_tests;
\ No newline at end of file
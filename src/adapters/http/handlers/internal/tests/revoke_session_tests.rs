@@ -0,0 +1,366 @@
+// Tests for revoke_session handler - revoked, unknown and already revoked sessions
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::post,
+    Extension, Router,
+};
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+
+use crate::adapters::http::dto::internal::{RevokeSessionRequest, RevokeSessionResponse};
+use crate::adapters::http::middleware::ServiceContext;
+use crate::adapters::http::router::protected_internal_routes;
+use crate::adapters::http::state::AppState;
+
+const ACTIVE_SESSION: &str = "6f1c2b1e-3a4d-4e5f-8a9b-0c1d2e3f4a5b";
+const UNKNOWN_SESSION: &str = "0b7e1c52-9d3f-4c8a-a1e2-5f6a7b8c9d0e";
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn state(session_repo: Arc<InMemorySessionRepo>) -> AppState {
+    AppState::new(
+        Arc::new(Stub),
+        Arc::new(Stub),
+        session_repo,
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        3600,
+        30,
+        true,
+        3600,
+    )
+}
+
+fn app(session_repo: Arc<InMemorySessionRepo>) -> Router {
+    Router::new()
+        .route("/internal/sessions/revoke", post(crate::adapters::http::handlers::revoke_session))
+        .layer(Extension(ServiceContext::new("admin_console".to_string())))
+        .with_state(state(session_repo))
+}
+
+fn revoke_request(session_id: &str) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/internal/sessions/revoke")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::json!({ "session_id": session_id }).to_string()))
+        .unwrap()
+}
+
+async fn revoke(app: Router, session_id: &str) -> (StatusCode, serde_json::Value) {
+    let response = app.oneshot(revoke_request(session_id)).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_revoke_active_session() {
+    let repo = Arc::new(InMemorySessionRepo::with_active(ACTIVE_SESSION));
+
+    let (status, json) = revoke(app(repo.clone()), ACTIVE_SESSION).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json, serde_json::json!({ "session_id": ACTIVE_SESSION, "revoked": true }));
+    assert!(repo.is_revoked(ACTIVE_SESSION));
+}
+
+#[tokio::test]
+async fn test_revoke_unknown_session_is_not_found() {
+    let repo = Arc::new(InMemorySessionRepo::with_active(ACTIVE_SESSION));
+
+    let (status, _) = revoke(app(repo.clone()), UNKNOWN_SESSION).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(!repo.is_revoked(ACTIVE_SESSION));
+}
+
+#[tokio::test]
+async fn test_revoke_twice_is_idempotent() {
+    let repo = Arc::new(InMemorySessionRepo::with_active(ACTIVE_SESSION));
+
+    let (first_status, first) = revoke(app(repo.clone()), ACTIVE_SESSION).await;
+    let (second_status, second) = revoke(app(repo.clone()), ACTIVE_SESSION).await;
+
+    assert_eq!(first_status, StatusCode::OK);
+    assert_eq!(first["revoked"], true);
+    assert_eq!(second_status, StatusCode::OK);
+    assert_eq!(second, serde_json::json!({ "session_id": ACTIVE_SESSION, "revoked": false }));
+}
+
+#[tokio::test]
+async fn test_revoke_invalid_session_id_is_bad_request() {
+    let repo = Arc::new(InMemorySessionRepo::with_active(ACTIVE_SESSION));
+
+    let (status, _) = revoke(app(repo), "not-a-uuid").await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_revoke_session_requires_service_token() {
+    let repo = Arc::new(InMemorySessionRepo::with_active(ACTIVE_SESSION));
+    let state = state(repo.clone());
+    let app = Router::new()
+        .nest("/internal", protected_internal_routes(state.clone()))
+        .with_state(state);
+
+    let response = app.oneshot(revoke_request(ACTIVE_SESSION)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert!(!repo.is_revoked(ACTIVE_SESSION));
+}
+
+#[test]
+fn test_revoke_session_request_validation() {
+    let valid = RevokeSessionRequest { session_id: ACTIVE_SESSION.to_string() };
+    let empty = RevokeSessionRequest { session_id: "".to_string() };
+
+    assert!(valid.validate().is_ok());
+    assert!(empty.validate().unwrap_err().contains("Session ID"));
+}
+
+#[test]
+fn test_revoke_session_response_serialization() {
+    let response = RevokeSessionResponse { session_id: ACTIVE_SESSION.to_string(), revoked: true };
+
+    let json = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(json, serde_json::json!({ "session_id": ACTIVE_SESSION, "revoked": true }));
+}
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+use crate::core::credentials::StoredCredential;
+use crate::core::error::CoreError;
+use crate::core::identity::{ExternalIdentity, UserIdentity};
+use crate::core::token::Token;
+use crate::core::usecases::ports::session_repository::Session;
+use crate::core::usecases::ports::{
+    CredentialRepository, ExchangeAuthorizationCode, ExternalIdentityRepository, ExternalTokenValidator,
+    IdentityRepository, PasswordHasher, ServiceRegistry, SessionRepository, TokenService, UserServiceClient,
+};
+use uuid::Uuid;
+
+/// Session store tracking which sessions exist and when they were revoked
+#[derive(Default)]
+struct InMemorySessionRepo {
+    sessions: Mutex<HashMap<String, Option<DateTime<Utc>>>>, // session_id -> revoked_at
+}
+
+impl InMemorySessionRepo {
+    fn with_active(session_id: &str) -> Self {
+        let repo = Self::default();
+        repo.sessions.lock().unwrap().insert(session_id.to_string(), None);
+        repo
+    }
+
+    fn is_revoked(&self, session_id: &str) -> bool {
+        matches!(self.sessions.lock().unwrap().get(session_id), Some(Some(_)))
+    }
+}
+
+impl SessionRepository for InMemorySessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        let active = matches!(self.sessions.lock().unwrap().get(session_id), Some(None));
+        Box::pin(async move { active.then_some(Session {}) })
+    }
+
+    fn revoked_at(&self, session_id: &str) -> BoxFuture<'_, Option<DateTime<Utc>>> {
+        let revoked_at = self.sessions.lock().unwrap().get(session_id).copied().flatten();
+        Box::pin(async move { revoked_at })
+    }
+
+    fn revoke_session(&self, session_id: &str) -> BoxFuture<'_, ()> {
+        if let Some(revoked_at) = self.sessions.lock().unwrap().get_mut(session_id) {
+            revoked_at.get_or_insert_with(Utc::now);
+        }
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+/// Inert implementation of the ports this handler never touches
+struct Stub;
+
+impl IdentityRepository for Stub {
+    fn find_by_identifier(&self, _id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl ExternalIdentityRepository for Stub {
+    fn find_by_provider_user(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+    ) -> BoxFuture<'_, Result<Option<Uuid>, anyhow::Error>> {
+        Box::pin(async move { Ok(None) })
+    }
+
+    fn upsert(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+        _user_id: Uuid,
+        _email: Option<&str>,
+    ) -> BoxFuture<'_, Result<Uuid, anyhow::Error>> {
+        Box::pin(async move { Ok(Uuid::nil()) })
+    }
+
+    fn delete(&self, _provider: &str, _provider_user_id: &str) -> BoxFuture<'_, Result<(), anyhow::Error>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl UserServiceClient for Stub {
+    fn register_google_user(
+        &self,
+        _request: crate::core::usecases::ports::user_service_client::RegisterGoogleUserRequest,
+    ) -> BoxFuture<'static, Result<Uuid, CoreError>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+}
+
+impl CredentialRepository for Stub {
+    fn get_by_user_id(&self, _user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        Box::pin(async move { None })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, _user_id: &str, _new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl PasswordHasher for Stub {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, _raw: &str, _stored: &StoredCredential) -> bool {
+        false
+    }
+}
+
+impl ServiceRegistry for Stub {
+    fn validate_api_key(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn is_service_active(&self, _service_id: &str) -> bool {
+        false
+    }
+
+    fn validate_credentials(
+        &self,
+        _service_id: &str,
+        _service_secret: &str,
+        _password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        None
+    }
+}
+
+impl ExternalTokenValidator for Stub {
+    fn validate(&self, _token: &str) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl ExchangeAuthorizationCode for Stub {
+    fn exchange(&self, _code: &str, _state: Option<&str>) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl TokenService for Stub {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_service_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
@@ -0,0 +1,236 @@
+// Tests for revoke_access_token and revoke_all_tokens handlers - denied, untouched and invalid tokens
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::post,
+    Extension, Router,
+};
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::adapters::crypto::password::Argon2PasswordHasher;
+use crate::adapters::crypto::token::{HmacKey, HmacTokenService};
+use crate::adapters::http::middleware::ServiceContext;
+use crate::adapters::http::state::AppState;
+use crate::adapters::memory::{
+    CredentialRepositoryMemory, IdentityRepositoryMemory, MemoryStore, SessionRepositoryMemory, TokenDenyListMemory,
+    TokenWatermarkStoreMemory,
+};
+use crate::core::error::CoreError;
+use crate::core::identity::ExternalIdentity;
+use crate::core::usecases::ports::{
+    ExchangeAuthorizationCode, ExternalIdentityRepository, ExternalTokenValidator, PasswordHasher, ServiceRegistry,
+    UserServiceClient,
+};
+
+const ACCESS_CLAIMS: &str = r#"{"sub":"user-1","type":"access"}"#;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn state(with_deny_list: bool) -> AppState {
+    let store = MemoryStore::new();
+    let key = HmacKey::generate().expect("Should generate key");
+    let tokens = HmacTokenService::from_secret_key(&key.as_bytes()).expect("Should create service with valid key");
+
+    let state = AppState::new(
+        Arc::new(IdentityRepositoryMemory::new(store.clone())),
+        Arc::new(CredentialRepositoryMemory::new(store.clone())),
+        Arc::new(SessionRepositoryMemory::new(store.clone())),
+        Arc::new(Argon2PasswordHasher::new(1024, 1, 1, 16).expect("valid parameters")),
+        Arc::new(tokens),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        900,
+        30,
+        true,
+        3600,
+    );
+
+    if with_deny_list {
+        state.with_token_deny_list(Arc::new(TokenDenyListMemory::new(store)))
+    } else {
+        state
+    }
+}
+
+fn app(state: AppState) -> Router {
+    Router::new()
+        .route("/internal/token/revoke", post(crate::adapters::http::handlers::revoke_access_token))
+        .route("/internal/token/revoke-all", post(crate::adapters::http::handlers::revoke_all_tokens))
+        .route("/internal/introspect", post(crate::adapters::http::handlers::introspect))
+        .layer(Extension(ServiceContext::new("security_console".to_string())))
+        .with_state(state)
+}
+
+async fn post_token(app: &Router, uri: &str, token: &str) -> (StatusCode, serde_json::Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::json!({ "token": token }).to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_revoked_token_is_inactive() {
+    let state = state(true);
+    let token = state.token_service.issue_access_token("user-1", ACCESS_CLAIMS).await;
+    let app = app(state);
+
+    let (status, revoked) = post_token(&app, "/internal/token/revoke", token.value()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!revoked["jti"].as_str().unwrap().is_empty());
+    assert!(revoked["expires_at"].is_i64());
+
+    let (_, introspection) = post_token(&app, "/internal/introspect", token.value()).await;
+    assert_eq!(introspection, serde_json::json!({ "active": false }));
+}
+
+#[tokio::test]
+async fn test_other_tokens_stay_active() {
+    let state = state(true);
+    let revoked = state.token_service.issue_access_token("user-1", ACCESS_CLAIMS).await;
+    let untouched = state.token_service.issue_access_token("user-1", ACCESS_CLAIMS).await;
+    let app = app(state);
+
+    let (status, _) = post_token(&app, "/internal/token/revoke", revoked.value()).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, introspection) = post_token(&app, "/internal/introspect", untouched.value()).await;
+    assert_eq!(introspection["active"], true);
+}
+
+#[tokio::test]
+async fn test_revoke_invalid_token_is_bad_request() {
+    let app = app(state(true));
+
+    let (status, json) = post_token(&app, "/internal/token/revoke", "not-a-token").await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["details"]["field"], "token");
+}
+
+#[tokio::test]
+async fn test_revoke_without_deny_list_fails() {
+    let state = state(false);
+    let token = state.token_service.issue_access_token("user-1", ACCESS_CLAIMS).await;
+
+    let (status, _) = post_token(&app(state), "/internal/token/revoke", token.value()).await;
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn test_revoke_all_deactivates_earlier_tokens() {
+    let watermark = Arc::new(TokenWatermarkStoreMemory::new(MemoryStore::new()));
+    let state = state(false).with_token_watermark(watermark.clone());
+    let token = state.token_service.issue_access_token("user-1", ACCESS_CLAIMS).await;
+    let app = app(state);
+
+    let (status, revoked) = post_token(&app, "/internal/token/revoke-all", "").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(revoked["revoked_through"].as_i64(), watermark.current().map(|at| at.timestamp()));
+
+    let (_, introspection) = post_token(&app, "/internal/introspect", token.value()).await;
+    assert_eq!(introspection, serde_json::json!({ "active": false }));
+}
+
+#[tokio::test]
+async fn test_revoke_all_without_watermark_fails() {
+    let (status, _) = post_token(&app(state(false)), "/internal/token/revoke-all", "").await;
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+// ============================================================================
+// Stubs
+// ============================================================================
+
+/// Inert implementation of the ports this flow never touches
+struct Stub;
+
+impl ExternalIdentityRepository for Stub {
+    fn find_by_provider_user(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+    ) -> BoxFuture<'_, Result<Option<Uuid>, anyhow::Error>> {
+        Box::pin(async move { Ok(None) })
+    }
+
+    fn upsert(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+        _user_id: Uuid,
+        _email: Option<&str>,
+    ) -> BoxFuture<'_, Result<Uuid, anyhow::Error>> {
+        Box::pin(async move { Ok(Uuid::nil()) })
+    }
+
+    fn delete(&self, _provider: &str, _provider_user_id: &str) -> BoxFuture<'_, Result<(), anyhow::Error>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl UserServiceClient for Stub {
+    fn register_google_user(
+        &self,
+        _request: crate::core::usecases::ports::user_service_client::RegisterGoogleUserRequest,
+    ) -> BoxFuture<'static, Result<Uuid, CoreError>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+}
+
+impl ServiceRegistry for Stub {
+    fn validate_api_key(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn is_service_active(&self, _service_id: &str) -> bool {
+        false
+    }
+
+    fn validate_credentials(
+        &self,
+        _service_id: &str,
+        _service_secret: &str,
+        _password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        None
+    }
+}
+
+impl ExternalTokenValidator for Stub {
+    fn validate(&self, _token: &str) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl ExchangeAuthorizationCode for Stub {
+    fn exchange(&self, _code: &str, _state: Option<&str>) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
@@ -0,0 +1,432 @@
+// Tests for list_session_history handler - paging, cursors and validation
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::get,
+    Extension, Router,
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use futures::future::BoxFuture;
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+
+use crate::adapters::http::handlers::internal::session::{decode_session_cursor, encode_session_cursor};
+use crate::adapters::http::middleware::ServiceContext;
+use crate::adapters::http::router::protected_internal_routes;
+use crate::adapters::http::state::AppState;
+use crate::core::usecases::ports::{SessionCursor, SessionPage, SessionRecord, SessionStatus};
+
+const USER_ID: &str = "8d3e6f2a-1b4c-4d5e-9f60-7a8b9c0d1e2f";
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn state(session_repo: Arc<InMemoryHistoryRepo>) -> AppState {
+    AppState::new(
+        Arc::new(Stub),
+        Arc::new(Stub),
+        session_repo,
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        3600,
+        30,
+        true,
+        3600,
+    )
+}
+
+fn app(session_repo: Arc<InMemoryHistoryRepo>) -> Router {
+    Router::new()
+        .route("/internal/sessions/history", get(crate::adapters::http::handlers::list_session_history))
+        .layer(Extension(ServiceContext::new("support_console".to_string())))
+        .with_state(state(session_repo))
+}
+
+fn history_request(query: &str) -> Request<Body> {
+    Request::builder()
+        .method("GET")
+        .uri(format!("/internal/sessions/history?{}", query))
+        .body(Body::empty())
+        .unwrap()
+}
+
+async fn history(app: Router, query: &str) -> (StatusCode, serde_json::Value) {
+    let response = app.oneshot(history_request(query)).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+fn created(minute: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(1_700_000_000, 0).unwrap() + Duration::minutes(minute)
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_session_history_walks_pages_with_cursor() {
+    let repo = Arc::new(InMemoryHistoryRepo::with_sessions(5));
+
+    let mut seen = Vec::new();
+    let mut query = format!("user_id={}&limit=2", USER_ID);
+    let mut pages = 0;
+    loop {
+        let (status, json) = history(app(repo.clone()), &query).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["pagination"]["limit"], 2);
+        pages += 1;
+
+        for session in json["sessions"].as_array().unwrap() {
+            seen.push(session["session_id"].as_str().unwrap().to_string());
+        }
+        match json["pagination"]["next_cursor"].as_str() {
+            Some(cursor) => {
+                assert_eq!(json["pagination"]["has_more"], true);
+                query = format!("user_id={}&limit=2&cursor={}", USER_ID, cursor);
+            }
+            None => {
+                assert_eq!(json["pagination"]["has_more"], false);
+                break;
+            }
+        }
+    }
+
+    assert_eq!(pages, 3);
+    let expected: Vec<String> = (0..5).rev().map(session_id).collect();
+    assert_eq!(seen, expected);
+}
+
+#[tokio::test]
+async fn test_session_history_last_page_omits_next_cursor() {
+    let repo = Arc::new(InMemoryHistoryRepo::with_sessions(2));
+
+    let (status, json) = history(app(repo), &format!("user_id={}&limit=2", USER_ID)).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["sessions"].as_array().unwrap().len(), 2);
+    assert_eq!(json["pagination"], serde_json::json!({ "limit": 2, "has_more": false }));
+}
+
+#[tokio::test]
+async fn test_session_history_exposes_status_and_revocation_time() {
+    let repo = Arc::new(InMemoryHistoryRepo::with_sessions(1));
+    repo.revoke(&session_id(0), created(30));
+
+    let (_, json) = history(app(repo), &format!("user_id={}", USER_ID)).await;
+
+    let session = &json["sessions"][0];
+    assert_eq!(session["status"], "revoked");
+    assert_eq!(session["revoked_at"], created(30).to_rfc3339());
+    assert!(session.get("refresh_token_hash").is_none());
+}
+
+#[tokio::test]
+async fn test_session_history_rejects_malformed_cursor() {
+    let repo = Arc::new(InMemoryHistoryRepo::with_sessions(3));
+
+    let (status, _) = history(app(repo), &format!("user_id={}&cursor=not-a-cursor", USER_ID)).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_session_history_rejects_invalid_user_id() {
+    let repo = Arc::new(InMemoryHistoryRepo::with_sessions(3));
+
+    let (status, _) = history(app(repo), "user_id=not-a-uuid").await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_session_history_requires_service_token() {
+    let repo = Arc::new(InMemoryHistoryRepo::with_sessions(3));
+    let state = state(repo);
+    let app = Router::new()
+        .nest("/internal", protected_internal_routes(state.clone()))
+        .with_state(state);
+
+    let response = app
+        .oneshot(history_request(&format!("user_id={}", USER_ID)))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[test]
+fn test_session_cursor_round_trips() {
+    let cursor = SessionCursor {
+        created_at: created(3) + Duration::microseconds(123_456),
+        session_id: session_id(3),
+    };
+
+    let token = encode_session_cursor(&cursor);
+
+    assert!(!token.contains(&cursor.session_id));
+    assert_eq!(decode_session_cursor(&token), Ok(cursor));
+}
+
+#[test]
+fn test_session_cursor_rejects_tampered_tokens() {
+    assert!(decode_session_cursor("not base64!").is_err());
+    assert!(decode_session_cursor("bm8tc2VwYXJhdG9y").is_err()); // "no-separator"
+    assert!(decode_session_cursor("MTIzOm5vdC1hLXV1aWQ").is_err()); // "123:not-a-uuid"
+}
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+use crate::core::credentials::StoredCredential;
+use crate::core::error::CoreError;
+use crate::core::identity::{ExternalIdentity, UserIdentity};
+use crate::core::token::Token;
+use crate::core::usecases::ports::session_repository::Session;
+use crate::core::usecases::ports::{
+    CredentialRepository, ExchangeAuthorizationCode, ExternalIdentityRepository, ExternalTokenValidator,
+    IdentityRepository, PasswordHasher, ServiceRegistry, SessionRepository, TokenService, UserServiceClient,
+};
+use uuid::Uuid;
+
+fn session_id(index: i64) -> String {
+    format!("00000000-0000-4000-8000-{:012}", index)
+}
+
+/// Session history for one user, paged by `(created_at, session_id)`
+struct InMemoryHistoryRepo {
+    sessions: Mutex<Vec<SessionRecord>>,
+}
+
+impl InMemoryHistoryRepo {
+    fn with_sessions(count: i64) -> Self {
+        let sessions = (0..count)
+            .map(|i| SessionRecord {
+                session_id: session_id(i),
+                created_at: created(i),
+                expires_at: created(i) + Duration::days(7),
+                revoked_at: None,
+                status: SessionStatus::Active,
+                ip_address: "203.0.113.7".to_string(),
+                user_agent: "Mozilla/5.0".to_string(),
+            })
+            .collect();
+        Self { sessions: Mutex::new(sessions) }
+    }
+
+    fn revoke(&self, session_id: &str, at: DateTime<Utc>) {
+        let mut sessions = self.sessions.lock().unwrap();
+        if let Some(session) = sessions.iter_mut().find(|s| s.session_id == session_id) {
+            session.revoked_at = Some(at);
+            session.status = SessionStatus::Revoked;
+        }
+    }
+}
+
+impl SessionRepository for InMemoryHistoryRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn list_for_user_paginated(
+        &self,
+        user_id: &str,
+        cursor: Option<&SessionCursor>,
+        limit: usize,
+    ) -> BoxFuture<'_, Result<SessionPage, CoreError>> {
+        let mut sessions = if user_id == USER_ID {
+            self.sessions.lock().unwrap().clone()
+        } else {
+            Vec::new()
+        };
+        let key = |s: &SessionRecord| (s.created_at, s.session_id.clone());
+        sessions.sort_by_key(|s| std::cmp::Reverse(key(s)));
+        sessions.retain(|s| cursor.is_none_or(|c| key(s) < (c.created_at, c.session_id.clone())));
+
+        let has_more = sessions.len() > limit;
+        sessions.truncate(limit);
+        let next_cursor = if has_more { sessions.last().map(SessionCursor::after) } else { None };
+        Box::pin(async move { Ok(SessionPage { sessions, next_cursor }) })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+/// Inert implementation of the ports this handler never touches
+struct Stub;
+
+impl IdentityRepository for Stub {
+    fn find_by_identifier(&self, _id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl ExternalIdentityRepository for Stub {
+    fn find_by_provider_user(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+    ) -> BoxFuture<'_, Result<Option<Uuid>, anyhow::Error>> {
+        Box::pin(async move { Ok(None) })
+    }
+
+    fn upsert(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+        _user_id: Uuid,
+        _email: Option<&str>,
+    ) -> BoxFuture<'_, Result<Uuid, anyhow::Error>> {
+        Box::pin(async move { Ok(Uuid::nil()) })
+    }
+
+    fn delete(&self, _provider: &str, _provider_user_id: &str) -> BoxFuture<'_, Result<(), anyhow::Error>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl UserServiceClient for Stub {
+    fn register_google_user(
+        &self,
+        _request: crate::core::usecases::ports::user_service_client::RegisterGoogleUserRequest,
+    ) -> BoxFuture<'static, Result<Uuid, CoreError>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+}
+
+impl CredentialRepository for Stub {
+    fn get_by_user_id(&self, _user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        Box::pin(async move { None })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, _user_id: &str, _new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl PasswordHasher for Stub {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, _raw: &str, _stored: &StoredCredential) -> bool {
+        false
+    }
+}
+
+impl ServiceRegistry for Stub {
+    fn validate_api_key(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn is_service_active(&self, _service_id: &str) -> bool {
+        false
+    }
+
+    fn validate_credentials(
+        &self,
+        _service_id: &str,
+        _service_secret: &str,
+        _password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        None
+    }
+}
+
+impl ExternalTokenValidator for Stub {
+    fn validate(&self, _token: &str) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl ExchangeAuthorizationCode for Stub {
+    fn exchange(&self, _code: &str, _state: Option<&str>) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl TokenService for Stub {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_service_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
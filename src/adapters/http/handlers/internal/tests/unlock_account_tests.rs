@@ -0,0 +1,280 @@
+// Tests for unlock_account handler - locked, unlocked and unknown accounts
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::post,
+    Extension, Router,
+};
+use chrono::{Duration, Utc};
+use futures::future::BoxFuture;
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::adapters::crypto::password::Argon2PasswordHasher;
+use crate::adapters::crypto::token::{HmacKey, HmacTokenService};
+use crate::adapters::http::middleware::ServiceContext;
+use crate::adapters::http::state::AppState;
+use crate::adapters::memory::{
+    CredentialRepositoryMemory, IdentityRepositoryMemory, MemoryStore, SessionRepositoryMemory,
+};
+use crate::core::error::CoreError;
+use crate::core::identity::ExternalIdentity;
+use crate::core::usecases::ports::{
+    AuditEvent, AuditEventType, AuditOutcome, AuditSink, CredentialRepository, ExchangeAuthorizationCode,
+    ExternalIdentityRepository, ExternalTokenValidator, IdentityRepository, PasswordHasher, ServiceRegistry,
+    UserServiceClient,
+};
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+struct Fixture {
+    app: Router,
+    credentials: Arc<CredentialRepositoryMemory>,
+    audit: Arc<RecordingAuditSink>,
+    user_id: String,
+}
+
+async fn fixture() -> Fixture {
+    let store = MemoryStore::new();
+    let key = HmacKey::generate().expect("Should generate key");
+    let tokens = HmacTokenService::from_secret_key(&key.as_bytes()).expect("Should create service with valid key");
+
+    let user_id = Uuid::new_v4();
+    IdentityRepositoryMemory::new(store.clone())
+        .create(&user_id, "alice@example.com", "hash", "", "", 0)
+        .await
+        .unwrap();
+
+    let credentials = Arc::new(CredentialRepositoryMemory::new(store.clone()));
+    let audit = Arc::new(RecordingAuditSink::default());
+
+    let state = AppState::new(
+        Arc::new(IdentityRepositoryMemory::new(store.clone())),
+        credentials.clone(),
+        Arc::new(SessionRepositoryMemory::new(store)),
+        Arc::new(Argon2PasswordHasher::new(1024, 1, 1, 16).expect("valid parameters")),
+        Arc::new(tokens),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        900,
+        30,
+        true,
+        3600,
+    )
+    .with_audit_sink(audit.clone());
+
+    let app = Router::new()
+        .route("/internal/credentials/unlock", post(crate::adapters::http::handlers::unlock_account))
+        .layer(Extension(ServiceContext::new("support_console".to_string())))
+        .with_state(state);
+
+    Fixture {
+        app,
+        credentials,
+        audit,
+        user_id: user_id.to_string(),
+    }
+}
+
+async fn unlock(app: &Router, user_id: &str) -> (StatusCode, serde_json::Value) {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/internal/credentials/unlock")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::json!({ "user_id": user_id }).to_string()))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_unlock_clears_failed_attempts_and_lock() {
+    let fixture = fixture().await;
+    fixture.credentials.update_failed_attempts(&fixture.user_id, 5).await;
+    fixture
+        .credentials
+        .lock_until(&fixture.user_id, &(Utc::now() + Duration::minutes(30)).to_rfc3339())
+        .await;
+
+    let (status, json) = unlock(&fixture.app, &fixture.user_id).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["user_id"], fixture.user_id.as_str());
+    assert_eq!(json["was_locked"], true);
+
+    let credential = fixture.credentials.get_by_user_id(&fixture.user_id).await.unwrap();
+    assert_eq!(credential.failed_attempts, 0);
+    assert!(credential.locked_until.is_none());
+}
+
+#[tokio::test]
+async fn test_unlock_reports_and_clears_source_lock() {
+    let fixture = fixture().await;
+    fixture
+        .credentials
+        .update_source_failed_attempts(&fixture.user_id, "203.0.113.7", 5)
+        .await;
+    fixture
+        .credentials
+        .lock_source_until(&fixture.user_id, "203.0.113.7", &(Utc::now() + Duration::minutes(30)).to_rfc3339())
+        .await;
+
+    let (status, json) = unlock(&fixture.app, &fixture.user_id).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["was_locked"], true);
+
+    let source = fixture.credentials.get_source_lockout(&fixture.user_id, "203.0.113.7").await;
+    assert_eq!(source.failed_attempts, 0);
+    assert!(source.locked_until.is_none());
+    assert!(!fixture.credentials.has_source_lockouts(&fixture.user_id).await);
+}
+
+#[tokio::test]
+async fn test_unlock_of_unlocked_account_succeeds() {
+    let fixture = fixture().await;
+
+    let (first, json) = unlock(&fixture.app, &fixture.user_id).await;
+    let (second, _) = unlock(&fixture.app, &fixture.user_id).await;
+
+    assert_eq!(first, StatusCode::OK);
+    assert_eq!(second, StatusCode::OK);
+    assert_eq!(json["was_locked"], false);
+}
+
+#[tokio::test]
+async fn test_unlock_is_audited() {
+    let fixture = fixture().await;
+    fixture.credentials.update_failed_attempts(&fixture.user_id, 3).await;
+
+    unlock(&fixture.app, &fixture.user_id).await;
+
+    let events = fixture.audit.events.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].actor, fixture.user_id);
+    assert_eq!(events[0].event_type, AuditEventType::AccountUnlocked);
+    assert_eq!(events[0].outcome, AuditOutcome::Success);
+    assert_eq!(events[0].reason.as_deref(), Some("unlocked by support_console"));
+}
+
+#[tokio::test]
+async fn test_unlock_unknown_user_is_not_found() {
+    let fixture = fixture().await;
+
+    let (status, _) = unlock(&fixture.app, &Uuid::new_v4().to_string()).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(fixture.audit.events.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_unlock_invalid_user_id_is_bad_request() {
+    let fixture = fixture().await;
+
+    let (status, _) = unlock(&fixture.app, "not-a-uuid").await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// Stubs
+// ============================================================================
+
+/// Audit sink keeping every recorded event
+#[derive(Default)]
+struct RecordingAuditSink {
+    events: Mutex<Vec<AuditEvent>>,
+}
+
+impl AuditSink for RecordingAuditSink {
+    fn record(&self, event: AuditEvent) -> BoxFuture<'_, Result<(), String>> {
+        self.events.lock().unwrap().push(event);
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+/// Inert implementation of the ports this flow never touches
+struct Stub;
+
+impl ExternalIdentityRepository for Stub {
+    fn find_by_provider_user(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+    ) -> BoxFuture<'_, Result<Option<Uuid>, anyhow::Error>> {
+        Box::pin(async move { Ok(None) })
+    }
+
+    fn upsert(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+        _user_id: Uuid,
+        _email: Option<&str>,
+    ) -> BoxFuture<'_, Result<Uuid, anyhow::Error>> {
+        Box::pin(async move { Ok(Uuid::nil()) })
+    }
+
+    fn delete(&self, _provider: &str, _provider_user_id: &str) -> BoxFuture<'_, Result<(), anyhow::Error>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl UserServiceClient for Stub {
+    fn register_google_user(
+        &self,
+        _request: crate::core::usecases::ports::user_service_client::RegisterGoogleUserRequest,
+    ) -> BoxFuture<'static, Result<Uuid, CoreError>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+}
+
+impl ServiceRegistry for Stub {
+    fn validate_api_key(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn is_service_active(&self, _service_id: &str) -> bool {
+        false
+    }
+
+    fn validate_credentials(
+        &self,
+        _service_id: &str,
+        _service_secret: &str,
+        _password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        None
+    }
+}
+
+impl ExternalTokenValidator for Stub {
+    fn validate(&self, _token: &str) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl ExchangeAuthorizationCode for Stub {
+    fn exchange(&self, _code: &str, _state: Option<&str>) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
@@ -22,8 +22,10 @@ Each handler is responsible for:
  5. Projecting errors to HTTP status codes
 */
 
+pub mod health;
 pub mod internal;
 pub mod public;
 
-pub use internal::{create_credential, issue_service_token, issue_session_tokens};
-pub use public::{authenticate, logout, refresh_token, validate_token};
+pub use health::{health_report, liveness, readiness};
+pub use internal::{create_credential, create_credentials_batch, introspect, issue_service_token, issue_session_tokens, list_session_history, revoke_access_token, revoke_all_tokens, revoke_credential, revoke_session, unlock_account};
+pub use public::{auth_metadata, authenticate, change_password, list_sessions, logout, logout_all, logout_others, refresh_token, validate_token};
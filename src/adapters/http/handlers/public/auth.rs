@@ -1,61 +1,110 @@
 // Public authentication handler
+use std::net::SocketAddr;
+
 use axum::{
-    extract::State,
-    http::{HeaderMap, header::USER_AGENT, StatusCode},
-    Json,
+    extract::{ConnectInfo, State},
+    http::{HeaderMap, StatusCode},
+    Extension, Json,
 };
-
+use tracing::Instrument;
 
 use crate::adapters::http::{
     dto::public::{AuthenticateRequest, AuthenticateResponse},
-    error::{HttpError, ValidationError, LockedError, UnauthorizedError, InternalError},
+    error::{ForbiddenError, HttpError, LockedError, UnauthorizedError, UnauthorizedKind, InternalError},
+    request_context::RequestContext,
     router::CleanJson,
     state::AppState,
+    telemetry::UsecaseSpan,
 };
-use crate::core::usecases::authenticate_user::{AuthenticateUser, AuthenticateUserInput};
+use crate::core::usecases::authenticate_in_workspace::{AuthenticateInWorkspace, AuthenticateInWorkspaceInput};
 use crate::core::usecases::issue_session::{IssueSession, IssueSessionInput};
 use crate::core::error::CoreError;
 
 /// Authenticate a user and return tokens
 ///
+/// With a `workspace_id`, the user must be a member of that workspace and
+/// the session and access token are scoped to it.
+///
 /// # Returns
 /// - 200 OK with access and refresh tokens
 /// - 400 Bad Request if validation fails
 /// - 401 Unauthorized if credentials are invalid
-/// - 423 Locked if account is locked
+/// - 403 Forbidden if the user is not a member of the requested workspace
+/// - 423 Locked if account is locked, unless `generic_auth_failures` is
+///   set; then a lockout gets the same 401 as an unknown identifier or a
+///   wrong password, without `Retry-After`, and shows only in the audit trail
 /// - 500 Internal Server Error on server failure
 pub async fn authenticate(
     headers: HeaderMap,
+    connect_info: Option<Extension<ConnectInfo<SocketAddr>>>,
     State(state): State<AppState>,
     CleanJson(body): CleanJson<AuthenticateRequest>,
 ) -> Result<(StatusCode, Json<AuthenticateResponse>), HttpError> {
     // Validate request structure
-    body.validate()
-        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+    body.validate_within(&state.field_limits).map_err(HttpError::Validation)?;
+
+    let peer = connect_info.map(|Extension(ConnectInfo(addr))| addr);
+    let context = RequestContext::from_parts(&headers, peer, &state.client_ip_resolver);
 
-    // Step 1: Authenticate the user
-    let auth_use_case = AuthenticateUser::new(
+    // Step 1: Authenticate the user, into the workspace when one is requested
+    let auth_use_case = AuthenticateInWorkspace::new(
         &*state.identity_repo,
         &*state.credential_repo,
         &*state.password_hasher,
-        5,  // max_attempts
-        30, // lockout_duration_minutes
+        &*state.clock,
+        state.config.lockout.clone(),
     );
+    let auth_use_case = match state.audit_sink.as_deref() {
+        Some(audit_sink) => auth_use_case.with_audit_sink(audit_sink),
+        None => auth_use_case,
+    };
+    let auth_use_case = match state.identifier_normalizer.as_deref() {
+        Some(normalizer) => auth_use_case.with_identifier_normalizer(normalizer),
+        None => auth_use_case,
+    };
+    // With a unit of work, the login is recorded together with the session
+    let auth_use_case = match state.unit_of_work {
+        Some(_) => auth_use_case.without_login_record(),
+        None => auth_use_case,
+    };
 
-    let auth_input = AuthenticateUserInput {
+    let auth_input = AuthenticateInWorkspaceInput {
         identifier: body.identifier,
         password: body.password,
+        workspace_id: body.workspace_id,
+        source_ip: Some(context.ip_address.clone()),
     };
 
-    let auth_result = auth_use_case.execute(auth_input).await;
+    let auth_span = UsecaseSpan::new("authenticate_user");
+    let auth_result = auth_use_case.execute(auth_input).instrument(auth_span.span()).await;
+    if let Some(user_id) = auth_result.as_ref().ok().and_then(|output| output.identity.user_id()) {
+        auth_span.record_user(user_id);
+    }
+    auth_span.record_result(&auth_result);
 
-    let user = match auth_result {
-        Ok(output) => output.user,
+    let (identity, recent_failed_attempts, previous_login_at) = match auth_result {
+        Ok(output) => (output.identity, output.recent_failed_attempts, output.previous_login_at),
         Err(CoreError::Authentication(auth_err)) => {
-            if auth_err.is_account_locked() {
-                return Err(HttpError::Locked(LockedError::new("account is locked")));
+            if auth_err.is_not_workspace_member() {
+                return Err(HttpError::Forbidden(ForbiddenError::new("not a member of this workspace")));
+            } else if auth_err.is_account_locked() && !state.generic_auth_failures {
+                let retry_after = auth_err
+                    .locked_until()
+                    .and_then(|until| seconds_until(until, state.clock.now()));
+                return Err(HttpError::Locked(match retry_after {
+                    Some(seconds) => LockedError::with_retry_after("account is locked", seconds),
+                    None => LockedError::new("account is locked"),
+                }));
+            } else if auth_err.is_credential_expired() {
+                return Err(HttpError::Unauthorized(UnauthorizedError::with_kind(
+                    "credential expired",
+                    UnauthorizedKind::CredentialExpired,
+                )));
             } else {
-                return Err(HttpError::Unauthorized(UnauthorizedError::new("invalid credentials")));
+                return Err(HttpError::Unauthorized(UnauthorizedError::with_kind(
+                    "invalid credentials",
+                    UnauthorizedKind::InvalidCredentials,
+                )));
             }
         }
         Err(e) => {
@@ -63,34 +112,43 @@ pub async fn authenticate(
         }
     };
 
-    let ip_address = headers
-        .get("x-forwarded-for")
-        .or(headers.get("x-real-ip"))
-        .and_then(|h| h.to_str().ok())
-.and_then(|ips| ips.split(',').next().map(|ip: &str| ip.trim().to_string()))
-        .unwrap_or_else(|| "0.0.0.0".to_string());
-
-    let user_agent = headers.get(USER_AGENT)
-        .and_then(|h| h.to_str().ok())
-        .unwrap_or("unknown")
-        .to_string();
+    let workspace_id = identity.workspace_id().map(str::to_string);
+    let user = identity
+        .user
+        .ok_or_else(|| HttpError::Internal(InternalError::new("authenticated identity has no user")))?;
 
     // Step 2: Issue session with tokens
     let session_use_case = IssueSession::new(
         &*state.session_repo,
         &*state.token_service,
-        state.access_token_ttl_seconds,
-        state.refresh_token_ttl_days,
+        &*state.clock,
+        &*state.random,
+        state.config.tokens.access_ttl_secs,
+        state.config.tokens.refresh_ttl_days,
     );
+    let session_use_case = match state.unit_of_work.as_deref() {
+        Some(unit_of_work) => session_use_case.with_unit_of_work(unit_of_work),
+        None => session_use_case,
+    };
+    let session_use_case = match state.session_metadata_enricher.as_deref() {
+        Some(enricher) => session_use_case.with_metadata_enricher(enricher),
+        None => session_use_case,
+    };
 
     let session_input = IssueSessionInput {
         user,
-        ip_address,
-        user_agent,
-        scopes: vec![]
+        ip_address: context.ip_address,
+        user_agent: context.user_agent,
+        scopes: vec![],
+        workspace_id: workspace_id.clone(),
     };
 
-    let session_output = session_use_case.execute(session_input).await
+    let session_span = UsecaseSpan::new("issue_session");
+    session_span.record_user(session_input.user.id());
+    let session_result = session_use_case.execute(session_input).instrument(session_span.span()).await;
+    session_span.record_result(&session_result);
+
+    let session_output = session_result
         .map_err(|e| HttpError::Internal(InternalError::new(format!("failed to issue session: {}", e))))?;
 
     // Step 3: Return response
@@ -100,7 +158,19 @@ pub async fn authenticate(
         token_type: "Bearer".to_string(),
         expires_in: session_output.expires_in,
         session_id: session_output.session_id,
+        recent_failed_attempts,
+        previous_login_at,
+        workspace_id,
     };
 
     Ok((StatusCode::OK, Json(response)))
 }
+
+/// Whole seconds from `now` until the RFC3339 instant `until`, rounded up
+///
+/// None if `until` cannot be parsed or has already passed.
+pub(super) fn seconds_until(until: &str, now: chrono::DateTime<chrono::Utc>) -> Option<u64> {
+    let until = chrono::DateTime::parse_from_rfc3339(until).ok()?;
+    let millis = (until.with_timezone(&chrono::Utc) - now).num_milliseconds();
+    (millis > 0).then(|| (millis as u64).div_ceil(1000))
+}
@@ -124,8 +124,9 @@ pub async fn exchange_google_code(
     let issue_usecase = IssueSessionForExternalIdentity::new(
         &*state.session_repo,
         &*state.token_service,
-        state.access_token_ttl_seconds,
-        state.refresh_token_ttl_days,
+        &*state.random,
+        state.config.tokens.access_ttl_secs,
+        state.config.tokens.refresh_ttl_days,
     );
 
     let issue_output = issue_usecase
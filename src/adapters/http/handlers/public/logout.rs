@@ -5,13 +5,15 @@ use axum::{
     Json,
 };
 use crate::adapters::http::{
-    dto::public::{LogoutRequest, LogoutResponse},
+    dto::public::{LogoutAllResponse, LogoutOthersResponse, LogoutRequest, LogoutResponse},
     error::{HttpError, UnauthorizedError, InternalError},
     router::CleanJson,
     state::AppState,
 };
+use crate::core::usecases::revoke_all_sessions::{RevokeAllSessions, RevokeAllSessionsInput};
+use crate::core::usecases::revoke_other_sessions::{RevokeOtherSessions, RevokeOtherSessionsInput};
 use crate::core::usecases::revoke_session::{RevokeSession, RevokeSessionInput};
-use crate::core::usecases::validate_access_token::{ValidateAccessToken, ValidateAccessTokenInput};
+use crate::core::usecases::validate_access_token::ValidateAccessTokenInput;
 use crate::core::token::Token;
 use crate::core::error::CoreError;
 
@@ -31,24 +33,21 @@ pub async fn logout(
     let access_token = Token::new(bearer_token);
     
     // Validate the access token to extract session_id
-    let use_case = ValidateAccessToken::new(
-        &*state.token_service,
-        &*state.session_repo,
-    );
+    let use_case = state.access_token_validator();
 
-    let output = use_case.execute(ValidateAccessTokenInput { access_token }).await
-        .map_err(|e| HttpError::Internal(InternalError::new(format!("token validation failed: {}", e))))?;
+    let output = use_case.execute(ValidateAccessTokenInput { access_token, required_scopes: Vec::new() }).await
+        .map_err(HttpError::from_token_validation)?;
 
     // Check if token is valid
     if !output.valid {
-        return Err(HttpError::Unauthorized(UnauthorizedError::new(
+        return Err(HttpError::Unauthorized(UnauthorizedError::invalid_token(
             output.reason.as_deref().unwrap_or("invalid token")
         )));
     }
 
     // Extract session_id from validated token claims
     let session_id = output.session_id
-        .ok_or_else(|| HttpError::Unauthorized(UnauthorizedError::new("session id not found in token")))?;
+        .ok_or_else(|| HttpError::Unauthorized(UnauthorizedError::invalid_token("session id not found in token")))?;
 
     // Execute revoke session use case
     let use_case = RevokeSession::new(&*state.session_repo);
@@ -76,3 +75,108 @@ pub async fn logout(
 
     Ok((StatusCode::OK, Json(response)))
 }
+
+/// Logout every other device by revoking all sessions except the current one
+///
+/// The current session is derived from the Bearer token.
+///
+/// # Returns
+/// - 200 OK with the number of revoked sessions
+/// - 401 Unauthorized if the token is invalid or its session is no longer active
+/// - 500 Internal Server Error on server failure
+pub async fn logout_others(
+    State(state): State<AppState>,
+    Extension(bearer_token): Extension<String>,
+) -> Result<(StatusCode, Json<LogoutOthersResponse>), HttpError> {
+    let access_token = Token::new(bearer_token);
+
+    // Validate the access token to extract user_id and session_id
+    let use_case = state.access_token_validator();
+
+    let output = use_case.execute(ValidateAccessTokenInput { access_token, required_scopes: Vec::new() }).await
+        .map_err(HttpError::from_token_validation)?;
+
+    if !output.valid {
+        return Err(HttpError::Unauthorized(UnauthorizedError::invalid_token(
+            output.reason.as_deref().unwrap_or("invalid token")
+        )));
+    }
+
+    let user_id = output.user_id
+        .ok_or_else(|| HttpError::Unauthorized(UnauthorizedError::invalid_token("user id not found in token")))?;
+    let session_id = output.session_id
+        .ok_or_else(|| HttpError::Unauthorized(UnauthorizedError::invalid_token("session id not found in token")))?;
+
+    // Execute revoke other sessions use case
+    let use_case = RevokeOtherSessions::new(&*state.session_repo);
+
+    let input = RevokeOtherSessionsInput {
+        user_id,
+        current_session_id: session_id,
+    };
+
+    let output = use_case.execute(input).await
+        .map_err(|e| match e {
+            CoreError::Authentication(auth_err) => {
+                HttpError::Unauthorized(UnauthorizedError::new(auth_err.to_string()))
+            }
+            _ => HttpError::Internal(InternalError::new(format!("logout others failed: {}", e))),
+        })?;
+
+    let response = LogoutOthersResponse {
+        success: true,
+        sessions_revoked: output.sessions_revoked,
+        session_id: output.current_session_id,
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// Logout everywhere by revoking every session of the token's user
+///
+/// The user is derived from the Bearer token only, so a caller can never
+/// revoke sessions belonging to someone else. The current session is
+/// revoked as well.
+///
+/// # Returns
+/// - 200 OK with the number of revoked sessions
+/// - 401 Unauthorized if the token is invalid or its session is no longer active
+/// - 500 Internal Server Error on server failure
+pub async fn logout_all(
+    State(state): State<AppState>,
+    Extension(bearer_token): Extension<String>,
+) -> Result<(StatusCode, Json<LogoutAllResponse>), HttpError> {
+    let access_token = Token::new(bearer_token);
+
+    // Validate the access token to extract user_id
+    let use_case = state.access_token_validator();
+
+    let output = use_case.execute(ValidateAccessTokenInput { access_token, required_scopes: Vec::new() }).await
+        .map_err(HttpError::from_token_validation)?;
+
+    if !output.valid {
+        return Err(HttpError::Unauthorized(UnauthorizedError::invalid_token(
+            output.reason.as_deref().unwrap_or("invalid token")
+        )));
+    }
+
+    let user_id = output.user_id
+        .ok_or_else(|| HttpError::Unauthorized(UnauthorizedError::invalid_token("user id not found in token")))?;
+
+    // Execute revoke all sessions use case
+    let use_case = RevokeAllSessions::new(&*state.session_repo);
+    let use_case = match state.audit_sink.as_deref() {
+        Some(audit_sink) => use_case.with_audit_sink(audit_sink),
+        None => use_case,
+    };
+
+    let output = use_case.execute(RevokeAllSessionsInput { user_id }).await
+        .map_err(|e| HttpError::Internal(InternalError::new(format!("logout all failed: {}", e))))?;
+
+    let response = LogoutAllResponse {
+        success: true,
+        sessions_revoked: output.sessions_revoked,
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
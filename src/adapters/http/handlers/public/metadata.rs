@@ -0,0 +1,25 @@
+// Public metadata handler
+use axum::{
+    extract::State,
+    http::StatusCode,
+    Json,
+};
+use crate::adapters::http::{
+    dto::public::AuthMetadataResponse,
+    state::AppState,
+};
+
+/// Advertise the token signing algorithms in use
+///
+/// Lets gateways and tooling confirm algorithm expectations before
+/// validating tokens issued by this service.
+///
+/// # Returns
+/// - 200 OK with the supported signing algorithms
+pub async fn auth_metadata(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<AuthMetadataResponse>) {
+    let response = AuthMetadataResponse::from_algorithms(state.token_service.supported_algorithms());
+
+    (StatusCode::OK, Json(response))
+}
@@ -4,12 +4,18 @@ pub mod logout;
 pub mod tokens;
 pub mod token_validation;
 pub mod google_oauth;
+pub mod metadata;
+pub mod sessions;
+pub mod password;
 
 pub use auth::authenticate;
-pub use logout::logout;
+pub use logout::{logout, logout_all, logout_others};
 pub use tokens::refresh_token;
 pub use token_validation::validate_token;
 pub use google_oauth::exchange_google_code;
+pub use metadata::auth_metadata;
+pub use sessions::list_sessions;
+pub use password::change_password;
 
 #[cfg(test)]
 pub mod tests;
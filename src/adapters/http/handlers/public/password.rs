@@ -0,0 +1,143 @@
+// Public change password handler
+use std::net::SocketAddr;
+
+use axum::{
+    extract::{ConnectInfo, State, Extension},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use crate::adapters::http::{
+    dto::public::{ChangePasswordRequest, ChangePasswordResponse},
+    error::{FieldError, HttpError, LockedError, ValidationError, UnauthorizedError, UnauthorizedKind, InternalError},
+    request_context::RequestContext,
+    router::CleanJson,
+    state::AppState,
+};
+use super::auth::seconds_until;
+use crate::core::usecases::change_password::{ChangePassword, ChangePasswordInput};
+use crate::core::usecases::validate_access_token::ValidateAccessTokenInput;
+use crate::core::token::Token;
+use crate::core::error::{CoreError, CredentialError};
+
+/// Change the caller's password
+///
+/// The user is derived from the Bearer token only. With
+/// `revoke_other_sessions` every other session is signed out and the one
+/// making the request stays active.
+///
+/// # Returns
+/// - 200 OK when the password was changed
+/// - 400 Bad Request if validation fails or the new password is rejected by
+///   policy; `errors` lists every rule the new password failed
+/// - 401 Unauthorized if the token is invalid or the current password is wrong
+/// - 423 Locked if failed attempts locked the account; answered as 401
+///   instead when `generic_auth_failures` is set
+/// - 500 Internal Server Error on server failure
+pub async fn change_password(
+    headers: HeaderMap,
+    connect_info: Option<Extension<ConnectInfo<SocketAddr>>>,
+    State(state): State<AppState>,
+    Extension(bearer_token): Extension<String>,
+    CleanJson(request): CleanJson<ChangePasswordRequest>,
+) -> Result<(StatusCode, Json<ChangePasswordResponse>), HttpError> {
+    request.validate()
+        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+
+    let peer = connect_info.map(|Extension(ConnectInfo(addr))| addr);
+    let context = RequestContext::from_parts(&headers, peer, &state.client_ip_resolver);
+    let access_token = Token::new(bearer_token);
+
+    // Validate the access token to extract user_id and session_id
+    let use_case = state.access_token_validator();
+
+    let output = use_case.execute(ValidateAccessTokenInput { access_token, required_scopes: Vec::new() }).await
+        .map_err(HttpError::from_token_validation)?;
+
+    if !output.valid {
+        return Err(HttpError::Unauthorized(UnauthorizedError::invalid_token(
+            output.reason.as_deref().unwrap_or("invalid token")
+        )));
+    }
+
+    let user_id = output.user_id
+        .ok_or_else(|| HttpError::Unauthorized(UnauthorizedError::invalid_token("user id not found in token")))?;
+
+    // Execute change password use case
+    let use_case = ChangePassword::new(
+        &*state.credential_repo,
+        &*state.password_hasher,
+        &*state.session_repo,
+        state.credential_policy.clone(),
+    )
+    .with_identity_repository(&*state.identity_repo)
+    .with_lockout_policy(state.config.lockout.clone());
+    let use_case = match state.audit_sink.as_deref() {
+        Some(audit_sink) => use_case.with_audit_sink(audit_sink),
+        None => use_case,
+    };
+    let use_case = match state.breach_checker.as_deref() {
+        Some(breach_checker) => use_case.with_breach_checker(breach_checker),
+        None => use_case,
+    };
+
+    let input = ChangePasswordInput {
+        user_id,
+        current_password: request.current_password,
+        new_password: request.new_password,
+        current_session_id: output.session_id,
+        revoke_other_sessions: request.revoke_other_sessions,
+        source_ip: Some(context.ip_address),
+    };
+
+    let output = use_case.execute(input).await
+        .map_err(|e| match e {
+            CoreError::Authentication(auth_err) if auth_err.is_account_locked() && !state.generic_auth_failures => {
+                let retry_after = auth_err
+                    .locked_until()
+                    .and_then(|until| seconds_until(until, state.clock.now()));
+                HttpError::Locked(match retry_after {
+                    Some(seconds) => LockedError::with_retry_after("account is locked", seconds),
+                    None => LockedError::new("account is locked"),
+                })
+            }
+            CoreError::Authentication(_) => HttpError::Unauthorized(UnauthorizedError::with_kind(
+                "invalid credentials",
+                UnauthorizedKind::InvalidCredentials,
+            )),
+            CoreError::Credential(credential_err) => HttpError::Validation(policy_feedback(&credential_err)),
+            _ => HttpError::Internal(InternalError::new(format!("password change failed: {}", e))),
+        })?;
+
+    let response = ChangePasswordResponse {
+        success: true,
+        sessions_revoked: output.sessions_revoked,
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// One `new_password` field error per policy rule the new password failed
+fn policy_feedback(error: &CredentialError) -> ValidationError {
+    let errors = error
+        .violations()
+        .iter()
+        .map(|violation| FieldError::new("new_password", rule_code(violation), violation.to_string()))
+        .collect();
+    ValidationError::check(errors)
+        .err()
+        .unwrap_or_else(|| ValidationError::with_field(error.to_string(), "new_password"))
+}
+
+/// Machine-readable code for a failed password rule
+fn rule_code(error: &CredentialError) -> &'static str {
+    match error {
+        CredentialError::MissingRequired { .. } => "required",
+        CredentialError::InsufficientStrength { .. } => "too_short",
+        CredentialError::InvalidFormat { .. } => "invalid_format",
+        CredentialError::Compromised => "compromised",
+        CredentialError::TooWeak { .. } => "too_weak",
+        CredentialError::ContainsIdentifier => "contains_identifier",
+        CredentialError::Reused => "reused",
+        _ => "rejected",
+    }
+}
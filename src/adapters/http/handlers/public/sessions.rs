@@ -0,0 +1,71 @@
+// Public session listing handler
+use axum::{
+    extract::{State, Extension},
+    http::StatusCode,
+    Json,
+};
+use crate::adapters::http::{
+    dto::public::{ListSessionsResponse, SessionInfo},
+    error::{HttpError, UnauthorizedError, InternalError},
+    state::AppState,
+};
+use crate::core::usecases::list_sessions::{ListSessions, ListSessionsInput};
+use crate::core::usecases::validate_access_token::ValidateAccessTokenInput;
+use crate::core::token::Token;
+
+/// List the caller's active sessions ("where you're logged in")
+///
+/// The user is derived from the Bearer token only, so a caller can never
+/// list sessions belonging to someone else.
+///
+/// # Returns
+/// - 200 OK with the active sessions, newest first
+/// - 401 Unauthorized if the token is invalid or its session is no longer active
+/// - 500 Internal Server Error on server failure
+pub async fn list_sessions(
+    State(state): State<AppState>,
+    Extension(bearer_token): Extension<String>,
+) -> Result<(StatusCode, Json<ListSessionsResponse>), HttpError> {
+    let access_token = Token::new(bearer_token);
+
+    // Validate the access token to extract user_id and session_id
+    let use_case = state.access_token_validator();
+
+    let output = use_case.execute(ValidateAccessTokenInput { access_token, required_scopes: Vec::new() }).await
+        .map_err(HttpError::from_token_validation)?;
+
+    if !output.valid {
+        return Err(HttpError::Unauthorized(UnauthorizedError::invalid_token(
+            output.reason.as_deref().unwrap_or("invalid token")
+        )));
+    }
+
+    let user_id = output.user_id
+        .ok_or_else(|| HttpError::Unauthorized(UnauthorizedError::invalid_token("user id not found in token")))?;
+
+    // Execute list sessions use case
+    let use_case = ListSessions::new(&*state.session_repo);
+
+    let input = ListSessionsInput {
+        user_id,
+        current_session_id: output.session_id,
+    };
+
+    let output = use_case.execute(input).await
+        .map_err(|e| HttpError::Internal(InternalError::new(format!("listing sessions failed: {}", e))))?;
+
+    let sessions = output
+        .sessions
+        .iter()
+        .map(|session| SessionInfo {
+            session_id: session.session_id.clone(),
+            created_at: session.created_at.to_rfc3339(),
+            expires_at: session.expires_at.to_rfc3339(),
+            ip_address: session.ip_address.clone(),
+            user_agent: session.user_agent.clone(),
+            current: output.is_current(session),
+        })
+        .collect();
+
+    Ok((StatusCode::OK, Json(ListSessionsResponse { sessions })))
+}
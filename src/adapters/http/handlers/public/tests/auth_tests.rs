@@ -12,6 +12,7 @@ fn test_authenticate_valid_request() {
     let request = AuthenticateRequest {
         identifier: "testuser".to_string(),
         password: "password123".to_string(),
+        workspace_id: None,
     };
 
     let validation_result = request.validate();
@@ -24,11 +25,12 @@ fn test_authenticate_empty_identifier() {
     let request = AuthenticateRequest {
         identifier: "".to_string(),
         password: "password123".to_string(),
+        workspace_id: None,
     };
 
     let validation_result = request.validate();
     assert!(validation_result.is_err(), "Empty identifier should fail validation");
-    assert!(validation_result.unwrap_err().contains("Identifier"));
+    assert!(validation_result.unwrap_err().to_string().contains("Identifier"));
 }
 
 #[test]
@@ -37,11 +39,12 @@ fn test_authenticate_empty_password() {
     let request = AuthenticateRequest {
         identifier: "testuser".to_string(),
         password: "".to_string(),
+        workspace_id: None,
     };
 
     let validation_result = request.validate();
     assert!(validation_result.is_err(), "Empty password should fail validation");
-    assert!(validation_result.unwrap_err().contains("Password"));
+    assert!(validation_result.unwrap_err().to_string().contains("Password"));
 }
 
 #[test]
@@ -50,6 +53,7 @@ fn test_authenticate_both_empty() {
     let request = AuthenticateRequest {
         identifier: "".to_string(),
         password: "".to_string(),
+        workspace_id: None,
     };
 
     let validation_result = request.validate();
@@ -62,6 +66,7 @@ fn test_authenticate_request_serialization() {
     let request = AuthenticateRequest {
         identifier: "testuser@example.com".to_string(),
         password: "password123".to_string(),
+        workspace_id: None,
     };
 
     // Serialize to JSON
@@ -94,6 +99,9 @@ fn test_authenticate_response_structure() {
         token_type: "Bearer".to_string(),
         expires_in: 3600,
         session_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+        recent_failed_attempts: 0,
+        previous_login_at: None,
+        workspace_id: None,
     };
 
     // Verify all fields are present
@@ -113,6 +121,9 @@ fn test_authenticate_response_serialization() {
         token_type: "Bearer".to_string(),
         expires_in: 1800,
         session_id: "session_123".to_string(),
+        recent_failed_attempts: 0,
+        previous_login_at: None,
+        workspace_id: None,
     };
 
     let json = serde_json::to_string(&response).unwrap();
@@ -154,6 +165,7 @@ fn test_authenticate_email_as_identifier() {
     let request = AuthenticateRequest {
         identifier: "user@example.com".to_string(),
         password: "password123".to_string(),
+        workspace_id: None,
     };
 
     let validation_result = request.validate();
@@ -166,6 +178,7 @@ fn test_authenticate_special_characters_in_password() {
     let request = AuthenticateRequest {
         identifier: "testuser".to_string(),
         password: "p@ssw0rd!#$%^&*()".to_string(),
+        workspace_id: None,
     };
 
     let validation_result = request.validate();
@@ -178,6 +191,7 @@ fn test_authenticate_long_identifier() {
     let request = AuthenticateRequest {
         identifier: "a".to_string().repeat(100),
         password: "password123".to_string(),
+        workspace_id: None,
     };
 
     let validation_result = request.validate();
@@ -244,8 +244,8 @@ impl SessionRepository for MockSessionRepo {
     fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
         Box::pin(async {})
     }
-    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, ()> {
-        Box::pin(async {})
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
     }
 fn delete_expired(&self) -> BoxFuture<'_, ()> {
         Box::pin(async {})
@@ -254,23 +254,23 @@ fn delete_expired(&self) -> BoxFuture<'_, ()> {
 
 struct MockTokenService;
 impl TokenService for MockTokenService {
-    fn issue_access_token(&self, _subject: &str, _claims: &str) -> crate::core::token::Token {
-        crate::core::token::Token::new("mock_access".to_string())
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, crate::core::token::Token> {
+        Box::pin(async move { crate::core::token::Token::new("mock_access".to_string()) })
     }
-    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> crate::core::token::Token {
-        crate::core::token::Token::new("mock_refresh".to_string())
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, crate::core::token::Token> {
+        Box::pin(async move { crate::core::token::Token::new("mock_refresh".to_string()) })
     }
-    fn issue_service_token(&self, _subject: &str, _claims: &str) -> crate::core::token::Token {
-        crate::core::token::Token::new("mock_service".to_string())
+    fn issue_service_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, crate::core::token::Token> {
+        Box::pin(async move { crate::core::token::Token::new("mock_service".to_string()) })
     }
-    fn validate_access_token(&self, _token: &crate::core::token::Token) -> Result<String, ()> {
-        Ok("valid".to_string())
+    fn validate_access_token(&self, _token: &crate::core::token::Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Ok("valid".to_string()) })
     }
-    fn validate_refresh_token(&self, _token: &crate::core::token::Token) -> Result<String, ()> {
-        Ok("valid".to_string())
+    fn validate_refresh_token(&self, _token: &crate::core::token::Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Ok("valid".to_string()) })
     }
-    fn validate_service_token(&self, _token: &crate::core::token::Token) -> Result<String, ()> {
-        Ok("valid".to_string())
+    fn validate_service_token(&self, _token: &crate::core::token::Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Ok("valid".to_string()) })
     }
 }
 
@@ -108,6 +108,135 @@ let state = AppState::new(
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
 
+#[tokio::test]
+async fn test_logout_others_rejects_token_without_active_session() {
+    let state = AppState::new(
+        Arc::new(MockIdentityRepo),
+        Arc::new(MockCredentialRepo),
+        Arc::new(MockSessionRepo),
+        Arc::new(MockPasswordHasher),
+        Arc::new(MockTokenService),
+        Arc::new(MockServiceRegistry),
+        Arc::new(MockTokenService),
+        Arc::new(MockTokenService),
+        Arc::new(MockIdentityRepo),
+        Arc::new(MockUserServiceClient),
+        3600,
+        30,
+        true,
+        3600,
+    );
+    
+    let app = Router::new()
+        .route("/auth/logout-others", post(crate::adapters::http::handlers::logout_others))
+        .layer(axum::middleware::from_fn(crate::adapters::http::middleware::bearer_auth))
+        .with_state(state);
+    
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/auth/logout-others")
+                .header("authorization", "Bearer valid_access_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    
+    // No session can be derived from the mock token, so nothing is revoked
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+// ============================================================================
+// Logout All
+// ============================================================================
+
+fn logout_all_app(session_repo: Arc<TrackingSessionRepo>) -> Router {
+    let state = AppState::new(
+        Arc::new(MockIdentityRepo),
+        Arc::new(MockCredentialRepo),
+        session_repo,
+        Arc::new(MockPasswordHasher),
+        Arc::new(ClaimsTokenService),
+        Arc::new(MockServiceRegistry),
+        Arc::new(MockTokenService),
+        Arc::new(MockTokenService),
+        Arc::new(MockIdentityRepo),
+        Arc::new(MockUserServiceClient),
+        3600,
+        30,
+        true,
+        3600,
+    );
+
+    Router::new()
+        .route("/auth/logout-all", post(crate::adapters::http::handlers::logout_all))
+        .layer(axum::middleware::from_fn(crate::adapters::http::middleware::bearer_auth))
+        .with_state(state)
+}
+
+fn logout_all_request(authorization: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder().method("POST").uri("/auth/logout-all");
+    if let Some(value) = authorization {
+        builder = builder.header("authorization", value);
+    }
+    builder.body(Body::empty()).unwrap()
+}
+
+#[tokio::test]
+async fn test_logout_all_revokes_every_session_of_token_user() {
+    let session_repo = Arc::new(TrackingSessionRepo::new());
+    session_repo.insert_session("session-1", "user-1");
+    session_repo.insert_session("session-2", "user-1");
+    session_repo.insert_session("session-3", "user-1");
+    session_repo.insert_session("session-9", "user-2");
+
+    let response = logout_all_app(session_repo.clone())
+        .oneshot(logout_all_request(Some("Bearer user_1_access_token")))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["success"], true);
+    assert_eq!(json["sessions_revoked"], 3);
+
+    assert!(session_repo.active_sessions("user-1").is_empty());
+    // Sessions of other users are never touched
+    assert_eq!(session_repo.active_sessions("user-2"), vec!["session-9".to_string()]);
+}
+
+#[tokio::test]
+async fn test_logout_all_missing_token_is_unauthorized() {
+    let session_repo = Arc::new(TrackingSessionRepo::new());
+    session_repo.insert_session("session-1", "user-1");
+
+    let response = logout_all_app(session_repo.clone())
+        .oneshot(logout_all_request(None))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(session_repo.active_sessions("user-1"), vec!["session-1".to_string()]);
+}
+
+#[tokio::test]
+async fn test_logout_all_invalid_token_is_unauthorized() {
+    let session_repo = Arc::new(TrackingSessionRepo::new());
+    session_repo.insert_session("session-1", "user-1");
+
+    let response = logout_all_app(session_repo.clone())
+        .oneshot(logout_all_request(Some("Bearer forged_token")))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(session_repo.active_sessions("user-1"), vec!["session-1".to_string()]);
+}
+
 // ============================================================================
 // Mock Implementations
 // ============================================================================
@@ -215,40 +344,44 @@ impl PasswordHasher for MockPasswordHasher {
 
 struct MockTokenService;
 impl TokenService for MockTokenService {
-    fn issue_access_token(&self, _subject: &str, _claims: &str) -> Token {
-        Token::new("access_token_123".to_string())
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("access_token_123".to_string()) })
     }
     
-    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> Token {
-        Token::new("refresh_token_123".to_string())
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("refresh_token_123".to_string()) })
     }
 
-    fn issue_service_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(format!("service_token_for_{}", subject))
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
     }
     
-    fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value() == "valid_access_token" {
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value() == "valid_access_token" {
             Ok("claims".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
     
-    fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value() == "valid_refresh_token" {
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value() == "valid_refresh_token" {
             Ok("claims".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 
-    fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().contains("service_token_for_") {
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().contains("service_token_for_") {
             Ok("claims".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 }
 
@@ -286,8 +419,8 @@ impl SessionRepository for MockSessionRepo {
         Box::pin(async move {})
     }
     
-    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, ()> {
-        Box::pin(async move {})
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
     }
     
     fn delete_expired(&self) -> BoxFuture<'_, ()> {
@@ -318,3 +451,104 @@ impl ServiceRegistry for MockServiceRegistry {
         None
     }
 }
+
+/// Token service that maps one known token to real access claims
+struct ClaimsTokenService;
+impl TokenService for ClaimsTokenService {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("access_token_123".to_string()) })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("refresh_token_123".to_string()) })
+    }
+
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value() == "user_1_access_token" {
+            let exp = chrono::Utc::now().timestamp() + 3600;
+            Ok(format!(r#"{{"sub":"user-1","type":"access","exp":{},"sid":"session-1"}}"#, exp))
+        } else {
+            Err(())
+        };
+        Box::pin(async move { claims })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+/// Session repository tracking ownership and revocation
+struct TrackingSessionRepo {
+    sessions: std::sync::RwLock<std::collections::HashMap<String, String>>, // session_id -> user_id
+    revoked: std::sync::RwLock<std::collections::HashSet<String>>,
+}
+
+impl TrackingSessionRepo {
+    fn new() -> Self {
+        Self {
+            sessions: std::sync::RwLock::new(std::collections::HashMap::new()),
+            revoked: std::sync::RwLock::new(std::collections::HashSet::new()),
+        }
+    }
+
+    fn insert_session(&self, session_id: &str, user_id: &str) {
+        self.sessions.write().unwrap().insert(session_id.to_string(), user_id.to_string());
+    }
+
+    fn active_sessions(&self, user_id: &str) -> Vec<String> {
+        let revoked = self.revoked.read().unwrap();
+        let mut active: Vec<String> = self
+            .sessions
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(id, owner)| owner.as_str() == user_id && !revoked.contains(*id))
+            .map(|(id, _)| id.clone())
+            .collect();
+        active.sort();
+        active
+    }
+}
+
+impl SessionRepository for TrackingSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        let exists = self.sessions.read().unwrap().contains_key(session_id);
+        let revoked = self.revoked.read().unwrap().contains(session_id);
+        let result = (exists && !revoked).then_some(Session {});
+        Box::pin(async move { result })
+    }
+
+    fn revoke_session(&self, session_id: &str) -> BoxFuture<'_, ()> {
+        self.revoked.write().unwrap().insert(session_id.to_string());
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, user_id: &str) -> BoxFuture<'_, u64> {
+        let targets = self.active_sessions(user_id);
+        let count = targets.len() as u64;
+        self.revoked.write().unwrap().extend(targets);
+        Box::pin(async move { count })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
@@ -4,3 +4,5 @@ mod logout_tests;
 mod tokens_tests;
 mod token_validation_tests;
 mod google_oauth_tests;
+mod sessions_tests;
+mod password_tests;
@@ -0,0 +1,497 @@
+//! Tests for change password handler
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::post,
+    Router,
+};
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use tower::ServiceExt;
+
+use crate::adapters::http::state::AppState;
+use crate::core::credentials::CredentialPolicy;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+struct Fixture {
+    credential_repo: Arc<MemoryCredentialRepo>,
+    session_repo: Arc<RevokingSessionRepo>,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            credential_repo: Arc::new(MemoryCredentialRepo::with_password("user-1", "old-strong-password")),
+            session_repo: Arc::new(RevokingSessionRepo::default()),
+        }
+    }
+
+    fn app(&self) -> Router {
+        self.app_with_policy(CredentialPolicy::default())
+    }
+
+    fn app_with_policy(&self, credential_policy: CredentialPolicy) -> Router {
+        let state = AppState::new(
+            Arc::new(Stub),
+            self.credential_repo.clone(),
+            self.session_repo.clone(),
+            Arc::new(PrefixPasswordHasher),
+            Arc::new(ClaimsTokenService),
+            Arc::new(Stub),
+            Arc::new(Stub),
+            Arc::new(Stub),
+            Arc::new(Stub),
+            Arc::new(Stub),
+            3600,
+            30,
+            true,
+            3600,
+        )
+        .with_credential_policy(credential_policy);
+
+        Router::new()
+            .route("/auth/password", post(crate::adapters::http::handlers::change_password))
+            .layer(axum::middleware::from_fn(crate::adapters::http::middleware::bearer_auth))
+            .with_state(state)
+    }
+}
+
+fn request(authorization: Option<&str>, body: serde_json::Value) -> Request<Body> {
+    let mut builder = Request::builder()
+        .method("POST")
+        .uri("/auth/password")
+        .header("content-type", "application/json");
+    if let Some(value) = authorization {
+        builder = builder.header("authorization", value);
+    }
+    builder.body(Body::from(body.to_string())).unwrap()
+}
+
+async fn json_body(response: axum::response::Response) -> serde_json::Value {
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_change_password_success() {
+    let fixture = Fixture::new();
+
+    let response = fixture
+        .app()
+        .oneshot(request(
+            Some("Bearer user_1_access_token"),
+            serde_json::json!({
+                "current_password": "old-strong-password",
+                "new_password": "new-strong-password"
+            }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = json_body(response).await;
+    assert_eq!(json["success"], true);
+    assert_eq!(json["sessions_revoked"], 0);
+    assert_eq!(
+        fixture.credential_repo.password_of("user-1").as_deref(),
+        Some("hashed_new-strong-password")
+    );
+    assert!(fixture.session_repo.kept.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_change_password_can_revoke_other_sessions() {
+    let fixture = Fixture::new();
+
+    let response = fixture
+        .app()
+        .oneshot(request(
+            Some("Bearer user_1_access_token"),
+            serde_json::json!({
+                "current_password": "old-strong-password",
+                "new_password": "new-strong-password",
+                "revoke_other_sessions": true
+            }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(json_body(response).await["sessions_revoked"], 3);
+    // The session making the request stays signed in
+    assert_eq!(*fixture.session_repo.kept.lock().unwrap(), vec!["session-1".to_string()]);
+}
+
+#[tokio::test]
+async fn test_change_password_wrong_current_password_is_unauthorized() {
+    let fixture = Fixture::new();
+
+    let response = fixture
+        .app()
+        .oneshot(request(
+            Some("Bearer user_1_access_token"),
+            serde_json::json!({
+                "current_password": "not-my-password",
+                "new_password": "new-strong-password"
+            }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(json_body(response).await["code"], "INVALID_CREDENTIALS");
+    assert_eq!(
+        fixture.credential_repo.password_of("user-1").as_deref(),
+        Some("hashed_old-strong-password")
+    );
+}
+
+#[tokio::test]
+async fn test_change_password_weak_new_password_is_bad_request() {
+    let fixture = Fixture::new();
+
+    let response = fixture
+        .app()
+        .oneshot(request(
+            Some("Bearer user_1_access_token"),
+            serde_json::json!({
+                "current_password": "old-strong-password",
+                "new_password": "short"
+            }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        fixture.credential_repo.password_of("user-1").as_deref(),
+        Some("hashed_old-strong-password")
+    );
+}
+
+#[tokio::test]
+async fn test_change_password_lists_every_failed_rule() {
+    let fixture = Fixture::new();
+    let policy = CredentialPolicy {
+        format_check: Some(|s| s.chars().any(|c| c.is_ascii_digit())),
+        ..CredentialPolicy::default()
+    };
+
+    let response = fixture
+        .app_with_policy(policy)
+        .oneshot(request(
+            Some("Bearer user_1_access_token"),
+            serde_json::json!({
+                "current_password": "old-strong-password",
+                "new_password": "short"
+            }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = json_body(response).await;
+    let codes: Vec<&str> = body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| {
+            assert_eq!(error["field"], "new_password");
+            error["code"].as_str().unwrap()
+        })
+        .collect();
+    assert_eq!(codes, ["too_short", "invalid_format"]);
+}
+
+#[tokio::test]
+async fn test_change_password_missing_fields_is_bad_request() {
+    let fixture = Fixture::new();
+
+    let response = fixture
+        .app()
+        .oneshot(request(
+            Some("Bearer user_1_access_token"),
+            serde_json::json!({
+                "current_password": "",
+                "new_password": "new-strong-password"
+            }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_change_password_missing_token_is_unauthorized() {
+    let fixture = Fixture::new();
+
+    let response = fixture
+        .app()
+        .oneshot(request(
+            None,
+            serde_json::json!({
+                "current_password": "old-strong-password",
+                "new_password": "new-strong-password"
+            }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+use crate::core::credentials::StoredCredential;
+use crate::core::error::CoreError;
+use crate::core::identity::{ExternalIdentity, UserIdentity};
+use crate::core::token::Token;
+use crate::core::usecases::ports::session_repository::Session;
+use crate::core::usecases::ports::{
+    CredentialRepository, ExchangeAuthorizationCode, ExternalIdentityRepository, ExternalTokenValidator,
+    IdentityRepository, PasswordHasher, ServiceRegistry, SessionRepository, TokenService, UserServiceClient,
+};
+use uuid::Uuid;
+
+/// Token service that maps `user_1_access_token` to user-1 in session-1
+struct ClaimsTokenService;
+
+impl TokenService for ClaimsTokenService {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("access_token_123".to_string()) })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("refresh_token_123".to_string()) })
+    }
+
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let token = token.value().to_string();
+        Box::pin(async move {
+            if token != "user_1_access_token" {
+                return Err(());
+            }
+            let exp = Utc::now().timestamp() + 3600;
+            Ok(format!(r#"{{"sub":"user-1","type":"access","exp":{},"sid":"session-1"}}"#, exp))
+        })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+/// Credential repository keeping password hashes in memory
+struct MemoryCredentialRepo {
+    passwords: RwLock<HashMap<String, String>>, // user_id -> hash
+}
+
+impl MemoryCredentialRepo {
+    fn with_password(user_id: &str, password: &str) -> Self {
+        let mut passwords = HashMap::new();
+        passwords.insert(user_id.to_string(), format!("hashed_{}", password));
+        Self {
+            passwords: RwLock::new(passwords),
+        }
+    }
+
+    fn password_of(&self, user_id: &str) -> Option<String> {
+        self.passwords.read().unwrap().get(user_id).cloned()
+    }
+}
+
+impl CredentialRepository for MemoryCredentialRepo {
+    fn get_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        let stored = self.password_of(user_id);
+        Box::pin(async move { stored.map(StoredCredential::from_hash) })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, user_id: &str, new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        self.passwords
+            .write()
+            .unwrap()
+            .insert(user_id.to_string(), new_credential.as_hash_str().to_string());
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+struct PrefixPasswordHasher;
+
+impl PasswordHasher for PrefixPasswordHasher {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, raw: &str, stored: &StoredCredential) -> bool {
+        stored.as_hash_str() == format!("hashed_{}", raw)
+    }
+}
+
+/// Session repository where session-1 is live and others can be revoked
+#[derive(Default)]
+struct RevokingSessionRepo {
+    kept: Mutex<Vec<String>>,
+}
+
+impl SessionRepository for RevokingSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        let result = (session_id == "session-1").then_some(Session {});
+        Box::pin(async move { result })
+    }
+
+    fn revoked_at(&self, _session_id: &str) -> BoxFuture<'_, Option<DateTime<Utc>>> {
+        Box::pin(async move { None })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn revoke_all_for_user_except(&self, _user_id: &str, keep_session_id: &str) -> BoxFuture<'_, u64> {
+        self.kept.lock().unwrap().push(keep_session_id.to_string());
+        Box::pin(async move { 3 })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+/// Inert implementation of the ports this handler never touches
+struct Stub;
+
+impl IdentityRepository for Stub {
+    fn find_by_identifier(&self, _id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl ExternalIdentityRepository for Stub {
+    fn find_by_provider_user(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+    ) -> BoxFuture<'_, Result<Option<Uuid>, anyhow::Error>> {
+        Box::pin(async move { Ok(None) })
+    }
+
+    fn upsert(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+        _user_id: Uuid,
+        _email: Option<&str>,
+    ) -> BoxFuture<'_, Result<Uuid, anyhow::Error>> {
+        Box::pin(async move { Ok(Uuid::nil()) })
+    }
+
+    fn delete(&self, _provider: &str, _provider_user_id: &str) -> BoxFuture<'_, Result<(), anyhow::Error>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl UserServiceClient for Stub {
+    fn register_google_user(
+        &self,
+        _request: crate::core::usecases::ports::user_service_client::RegisterGoogleUserRequest,
+    ) -> BoxFuture<'static, Result<Uuid, CoreError>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+}
+
+impl ServiceRegistry for Stub {
+    fn validate_api_key(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn is_service_active(&self, _service_id: &str) -> bool {
+        false
+    }
+
+    fn validate_credentials(
+        &self,
+        _service_id: &str,
+        _service_secret: &str,
+        _password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        None
+    }
+}
+
+impl ExternalTokenValidator for Stub {
+    fn validate(&self, _token: &str) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl ExchangeAuthorizationCode for Stub {
+    fn exchange(&self, _code: &str, _state: Option<&str>) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
@@ -0,0 +1,421 @@
+//! Tests for session listing handler
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+use crate::adapters::http::state::AppState;
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn app() -> Router {
+    let state = AppState::new(
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(ListingSessionRepo::new()),
+        Arc::new(Stub),
+        Arc::new(ClaimsTokenService),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        3600,
+        30,
+        true,
+        3600,
+    );
+
+    Router::new()
+        .route("/auth/sessions", get(crate::adapters::http::handlers::list_sessions))
+        .layer(axum::middleware::from_fn(crate::adapters::http::middleware::bearer_auth))
+        .with_state(state)
+}
+
+/// The `error` attribute of the response's Bearer challenge
+fn challenge_error(response: &axum::response::Response) -> String {
+    let challenge = response
+        .headers()
+        .get("www-authenticate")
+        .expect("401 should carry a WWW-Authenticate challenge")
+        .to_str()
+        .unwrap();
+    assert!(challenge.starts_with("Bearer "));
+    challenge
+        .split("error=\"")
+        .nth(1)
+        .and_then(|rest| rest.split('"').next())
+        .unwrap()
+        .to_string()
+}
+
+fn request(authorization: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder().method("GET").uri("/auth/sessions");
+    if let Some(value) = authorization {
+        builder = builder.header("authorization", value);
+    }
+    builder.body(Body::empty()).unwrap()
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_list_sessions_returns_callers_sessions() {
+    let response = app()
+        .oneshot(request(Some("Bearer user_1_access_token")))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let sessions = json["sessions"].as_array().unwrap();
+
+    let ids: Vec<&str> = sessions.iter().map(|s| s["session_id"].as_str().unwrap()).collect();
+    assert_eq!(ids, vec!["session-2", "session-1"]);
+
+    assert_eq!(sessions[0]["current"], false);
+    assert_eq!(sessions[1]["current"], true);
+    assert_eq!(sessions[1]["ip_address"], "203.0.113.7");
+    assert_eq!(sessions[1]["user_agent"], "Mozilla/5.0");
+    assert!(sessions[1]["created_at"].as_str().is_some());
+    assert!(sessions[1]["expires_at"].as_str().is_some());
+
+    // Token material never leaves the server
+    assert!(!String::from_utf8_lossy(&body).contains("hash"));
+}
+
+#[tokio::test]
+async fn test_list_sessions_missing_token_is_unauthorized() {
+    let response = app().oneshot(request(None)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_list_sessions_revoked_token_requires_reauthentication() {
+    let response = app()
+        .oneshot(request(Some("Bearer revoked_access_token")))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["code"], "TOKEN_REVOKED");
+}
+
+#[tokio::test]
+async fn test_list_sessions_invalid_token_is_unauthorized() {
+    let response = app()
+        .oneshot(request(Some("Bearer forged_token")))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_missing_authorization_challenges_invalid_request() {
+    let response = app().oneshot(request(None)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(challenge_error(&response), "invalid_request");
+}
+
+#[tokio::test]
+async fn test_malformed_authorization_challenges_invalid_request() {
+    let response = app()
+        .oneshot(request(Some("Token user_1_access_token")))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(challenge_error(&response), "invalid_request");
+}
+
+#[tokio::test]
+async fn test_expired_token_challenges_invalid_token() {
+    let response = app()
+        .oneshot(request(Some("Bearer expired_access_token")))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(challenge_error(&response), "invalid_token");
+
+    // The description is fixed text and never echoes the token
+    let challenge = response.headers().get("www-authenticate").unwrap().to_str().unwrap();
+    assert!(challenge.contains("error_description=\""));
+    assert!(!challenge.contains("expired_access_token"));
+}
+
+#[tokio::test]
+async fn test_revoked_and_forged_tokens_challenge_invalid_token() {
+    for token in ["Bearer revoked_access_token", "Bearer forged_token"] {
+        let response = app().oneshot(request(Some(token))).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(challenge_error(&response), "invalid_token");
+    }
+}
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+use crate::core::credentials::StoredCredential;
+use crate::core::error::CoreError;
+use crate::core::identity::{ExternalIdentity, UserIdentity};
+use crate::core::token::Token;
+use crate::core::usecases::ports::session_repository::Session;
+use crate::core::usecases::ports::{
+    CredentialRepository, ExchangeAuthorizationCode, ExternalIdentityRepository, ExternalTokenValidator,
+    IdentityRepository, PasswordHasher, ServiceRegistry, SessionRepository, SessionSummary, TokenService,
+    UserServiceClient,
+};
+use uuid::Uuid;
+
+/// Token service that maps known tokens to real access claims
+struct ClaimsTokenService;
+
+impl TokenService for ClaimsTokenService {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("access_token_123".to_string()) })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("refresh_token_123".to_string()) })
+    }
+
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let token = token.value().to_string();
+        Box::pin(async move {
+            let now = Utc::now().timestamp();
+            let (sid, exp) = match token.as_str() {
+                "user_1_access_token" => ("session-1", now + 3600),
+                "revoked_access_token" => ("session-revoked", now + 3600),
+                "expired_access_token" => ("session-1", now - 60),
+                _ => return Err(()),
+            };
+            Ok(format!(r#"{{"sub":"user-1","type":"access","exp":{},"sid":"{}"}}"#, exp, sid))
+        })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+/// Session repository holding active sessions for two users and one revoked session
+struct ListingSessionRepo {
+    sessions: HashMap<String, Vec<SessionSummary>>, // user_id -> active sessions, newest first
+}
+
+impl ListingSessionRepo {
+    fn new() -> Self {
+        let created = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let summary = |id: &str, age_hours: i64| SessionSummary {
+            session_id: id.to_string(),
+            created_at: created - Duration::hours(age_hours),
+            expires_at: created + Duration::days(7),
+            ip_address: "203.0.113.7".to_string(),
+            user_agent: "Mozilla/5.0".to_string(),
+        };
+
+        let mut sessions = HashMap::new();
+        sessions.insert("user-1".to_string(), vec![summary("session-2", 1), summary("session-1", 5)]);
+        sessions.insert("user-2".to_string(), vec![summary("session-9", 2)]);
+        Self { sessions }
+    }
+}
+
+impl SessionRepository for ListingSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        let exists = self
+            .sessions
+            .values()
+            .flatten()
+            .any(|session| session.session_id == session_id);
+        let result = exists.then_some(Session {});
+        Box::pin(async move { result })
+    }
+
+    fn revoked_at(&self, session_id: &str) -> BoxFuture<'_, Option<DateTime<Utc>>> {
+        let result = (session_id == "session-revoked").then(|| Utc.timestamp_opt(1_700_003_600, 0).unwrap());
+        Box::pin(async move { result })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn list_active_for_user(&self, user_id: &str) -> BoxFuture<'_, Vec<SessionSummary>> {
+        let result = self.sessions.get(user_id).cloned().unwrap_or_default();
+        Box::pin(async move { result })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+/// Inert implementation of the ports this handler never touches
+struct Stub;
+
+impl IdentityRepository for Stub {
+    fn find_by_identifier(&self, _id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl ExternalIdentityRepository for Stub {
+    fn find_by_provider_user(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+    ) -> BoxFuture<'_, Result<Option<Uuid>, anyhow::Error>> {
+        Box::pin(async move { Ok(None) })
+    }
+
+    fn upsert(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+        _user_id: Uuid,
+        _email: Option<&str>,
+    ) -> BoxFuture<'_, Result<Uuid, anyhow::Error>> {
+        Box::pin(async move { Ok(Uuid::nil()) })
+    }
+
+    fn delete(&self, _provider: &str, _provider_user_id: &str) -> BoxFuture<'_, Result<(), anyhow::Error>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl UserServiceClient for Stub {
+    fn register_google_user(
+        &self,
+        _request: crate::core::usecases::ports::user_service_client::RegisterGoogleUserRequest,
+    ) -> BoxFuture<'static, Result<Uuid, CoreError>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+}
+
+impl CredentialRepository for Stub {
+    fn get_by_user_id(&self, _user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        Box::pin(async move { None })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, _user_id: &str, _new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl PasswordHasher for Stub {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, _raw: &str, _stored: &StoredCredential) -> bool {
+        false
+    }
+}
+
+impl ServiceRegistry for Stub {
+    fn validate_api_key(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn is_service_active(&self, _service_id: &str) -> bool {
+        false
+    }
+
+    fn validate_credentials(
+        &self,
+        _service_id: &str,
+        _service_secret: &str,
+        _password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        None
+    }
+}
+
+impl ExternalTokenValidator for Stub {
+    fn validate(&self, _token: &str) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl ExchangeAuthorizationCode for Stub {
+    fn exchange(&self, _code: &str, _state: Option<&str>) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
@@ -26,7 +26,7 @@ fn test_refresh_token_empty_refresh_token() {
 
     let validation_result = request.validate();
     assert!(validation_result.is_err(), "Empty refresh token should fail validation");
-    assert!(validation_result.unwrap_err().contains("Refresh token"));
+    assert!(validation_result.unwrap_err().to_string().contains("Refresh token"));
 }
 
 #[test]
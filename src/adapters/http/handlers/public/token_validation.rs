@@ -6,11 +6,11 @@ use axum::{
 };
 use crate::adapters::http::{
     dto::public::{TokenValidationRequest, TokenValidationResponse},
-    error::{HttpError, UnauthorizedError, InternalError},
+    error::{HttpError, ForbiddenError, UnauthorizedError, InternalError},
     router::CleanJson,
     state::AppState,
 };
-use crate::core::usecases::validate_access_token::{ValidateAccessToken, ValidateAccessTokenInput};
+use crate::core::usecases::validate_access_token::{TokenValidationFailure, ValidateAccessTokenInput};
 use crate::core::token::Token;
 
 /// Validate an access token and extract claims
@@ -19,6 +19,8 @@ use crate::core::token::Token;
 /// - 200 OK with user_id and session_id
 /// - 400 Bad Request if validation fails
 /// - 401 Unauthorized if token is invalid/expired
+/// - 401 Unauthorized with code `TOKEN_REVOKED` if the token's session was revoked
+/// - 403 Forbidden if the token is valid but lacks a requested scope
 /// - 500 Internal Server Error on server failure
 pub async fn validate_token(
     State(state): State<AppState>,
@@ -29,7 +31,7 @@ pub async fn validate_token(
 
     // Validate token is present
     if token_str.is_empty() {
-        return Err(HttpError::Unauthorized(UnauthorizedError::new(
+        return Err(HttpError::Unauthorized(UnauthorizedError::invalid_request(
             "Missing or empty token"
         )));
     }
@@ -38,21 +40,22 @@ pub async fn validate_token(
     let access_token = Token::new(token_str);
 
     // Execute validate access token use case
-    let use_case = ValidateAccessToken::new(
-        &*state.token_service,
-        &*state.session_repo,
-    );
+    let use_case = state.access_token_validator();
 
     let input = ValidateAccessTokenInput {
         access_token,
+        required_scopes: request.required_scopes,
     };
 
     let output = use_case.execute(input).await
-        .map_err(|e| HttpError::Internal(InternalError::new(format!("token validation failed: {}", e))))?;
+        .map_err(HttpError::from_token_validation)?;
 
-    // Check if token is valid
+    // Check if token is valid; a sound token lacking scopes is forbidden, not unauthorized
     if !output.valid {
-        return Err(HttpError::Unauthorized(UnauthorizedError::new(
+        if let Some(TokenValidationFailure::InsufficientScope { missing }) = &output.failure {
+            return Err(HttpError::Forbidden(ForbiddenError::insufficient_scope(missing.join(" "))));
+        }
+        return Err(HttpError::Unauthorized(UnauthorizedError::invalid_token(
             output.reason.as_deref().unwrap_or("invalid token")
         )));
     }
@@ -1,17 +1,20 @@
 // Public token handler
+use std::net::SocketAddr;
+
 use axum::{
-    extract::{State, Extension},
-    http::StatusCode,
+    extract::{ConnectInfo, State, Extension},
+    http::{HeaderMap, StatusCode},
     Json,
 };
 use crate::adapters::http::{
     dto::public::{RefreshTokenRequest, RefreshTokenResponse},
-    error::{HttpError, ValidationError, UnauthorizedError, InternalError},
+    error::{HttpError, UnauthorizedError, InternalError},
+    request_context::RequestContext,
     router::CleanJson,
     state::AppState,
 };
 use crate::core::usecases::refresh_session::{RefreshSession, RefreshSessionInput};
-use crate::core::usecases::validate_access_token::{ValidateAccessToken, ValidateAccessTokenInput};
+use crate::core::usecases::validate_access_token::ValidateAccessTokenInput;
 use crate::core::token::Token;
 use crate::core::error::CoreError;
 
@@ -23,51 +26,57 @@ use crate::core::error::CoreError;
 /// - 401 Unauthorized if access token is invalid/expired
 /// - 500 Internal Server Error on server failure
 pub async fn refresh_token(
+    headers: HeaderMap,
+    connect_info: Option<Extension<ConnectInfo<SocketAddr>>>,
     State(state): State<AppState>,
     Extension(bearer_token): Extension<String>,
     CleanJson(request): CleanJson<RefreshTokenRequest>,
 ) -> Result<(StatusCode, Json<RefreshTokenResponse>), HttpError> {
     // Validate request structure
-    request.validate()
-        .map_err(|msg| HttpError::Validation(ValidationError::new(msg)))?;
+    request.validate_within(&state.field_limits).map_err(HttpError::Validation)?;
 
     // Validate the Bearer access token to get session_id
     let access_token = Token::new(bearer_token);
     
-    let validate_use_case = ValidateAccessToken::new(
-        &*state.token_service,
-        &*state.session_repo,
-    );
+    let validate_use_case = state.access_token_validator();
 
-    let validate_output = validate_use_case.execute(ValidateAccessTokenInput { access_token }).await
-        .map_err(|e| HttpError::Internal(InternalError::new(format!("token validation failed: {}", e))))?;
+    let validate_output = validate_use_case.execute(ValidateAccessTokenInput { access_token, required_scopes: Vec::new() }).await
+        .map_err(HttpError::from_token_validation)?;
 
     // Check if token is valid
     if !validate_output.valid {
-        return Err(HttpError::Unauthorized(UnauthorizedError::new(
+        return Err(HttpError::Unauthorized(UnauthorizedError::invalid_token(
             validate_output.reason.as_deref().unwrap_or("invalid access token")
         )));
     }
 
     // Extract session_id from validated token
     let _session_id = validate_output.session_id
-        .ok_or_else(|| HttpError::Unauthorized(UnauthorizedError::new("session id not found in token")))?;
+        .ok_or_else(|| HttpError::Unauthorized(UnauthorizedError::invalid_token("session id not found in token")))?;
 
     // Get the stored refresh token from the session repository using session_id
     // Then execute refresh session use case to rotate tokens
     let use_case = RefreshSession::new(
         &*state.session_repo,
         &*state.token_service,
-        state.access_token_ttl_seconds,
-        state.rotate_refresh_tokens,
-    );
+        &*state.clock,
+        state.config.tokens.access_ttl_secs,
+        state.config.tokens.rotate_refresh_tokens,
+    )
+    .with_binding(state.refresh_binding)
+    .with_rotation_grace(state.config.tokens.refresh_rotation_grace_secs);
 
     // We need to get the refresh token from the session - use the request's refresh_token
     // or we could fetch it from the session store
     let refresh_token = Token::new(request.refresh_token);
 
+    let peer = connect_info.map(|Extension(ConnectInfo(addr))| addr);
+    let context = RequestContext::from_parts(&headers, peer, &state.client_ip_resolver);
+
     let input = RefreshSessionInput {
         refresh_token,
+        ip_address: context.ip_address,
+        user_agent: context.user_agent,
     };
 
     let output = use_case.execute(input).await
@@ -0,0 +1,322 @@
+// Aggregated health report across registered subsystems
+
+/*
+Liveness and readiness answer one question each; the health report answers
+"which part is unwell". Subsystems register a `HealthProbe` with the shared
+`HealthChecker` at startup, and every report probes all of them
+concurrently, each bounded by the checker's timeout.
+
+Aggregation:
+ - `unhealthy` when any critical component is unhealthy (503)
+ - `degraded` when any component is degraded, or a non-critical one is
+   unhealthy (200)
+ - `healthy` otherwise (200)
+
+The JSON shape is a monitoring contract: field names and status values
+never change, every field is always present, and checks are listed in
+registration order.
+*/
+
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use axum::http::StatusCode;
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+
+use crate::adapters::persistence::Database;
+use crate::core::token::Token;
+use crate::core::usecases::ports::TokenService;
+
+/// Default bound on a single component probe
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Subject of the throwaway service token used to probe the signing key
+const TOKEN_PROBE_SUBJECT: &str = "health-check";
+
+/// Health of one component, or of the service as a whole
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+/// Result of probing one component
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProbeOutcome {
+    pub status: HealthStatus,
+    /// Short operator-facing explanation; never carries secrets
+    pub message: Option<String>,
+}
+
+impl ProbeOutcome {
+    pub fn healthy() -> Self {
+        Self { status: HealthStatus::Healthy, message: None }
+    }
+
+    pub fn degraded(message: impl Into<String>) -> Self {
+        Self { status: HealthStatus::Degraded, message: Some(message.into()) }
+    }
+
+    pub fn unhealthy(message: impl Into<String>) -> Self {
+        Self { status: HealthStatus::Unhealthy, message: Some(message.into()) }
+    }
+}
+
+/// A subsystem that can report its own health
+pub trait HealthProbe: Send + Sync {
+    /// Check the component once
+    ///
+    /// Does not need its own timeout; the checker bounds every probe.
+    fn probe(&self) -> BoxFuture<'_, ProbeOutcome>;
+}
+
+/// Outcome of one component in a [`HealthReport`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComponentCheck {
+    /// Registered component name, e.g. `database`
+    pub name: String,
+    pub status: HealthStatus,
+    /// Whether this component being unhealthy makes the service unhealthy
+    pub critical: bool,
+    /// Time the probe took, or the timeout if it did not finish
+    pub latency_ms: f64,
+    pub message: Option<String>,
+}
+
+/// Health of the service and of each registered component
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub checks: Vec<ComponentCheck>,
+}
+
+impl HealthReport {
+    /// Aggregate component checks into a report
+    pub fn from_checks(checks: Vec<ComponentCheck>) -> Self {
+        let critical_down = checks
+            .iter()
+            .any(|check| check.critical && check.status == HealthStatus::Unhealthy);
+        let any_unwell = checks.iter().any(|check| check.status != HealthStatus::Healthy);
+
+        let status = if critical_down {
+            HealthStatus::Unhealthy
+        } else if any_unwell {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+
+        Self { status, checks }
+    }
+
+    /// HTTP status to serve the report with: 503 only when unhealthy
+    pub fn status_code(&self) -> StatusCode {
+        match self.status {
+            HealthStatus::Unhealthy => StatusCode::SERVICE_UNAVAILABLE,
+            HealthStatus::Healthy | HealthStatus::Degraded => StatusCode::OK,
+        }
+    }
+}
+
+struct RegisteredProbe {
+    name: String,
+    critical: bool,
+    probe: Arc<dyn HealthProbe>,
+}
+
+/// Registry of component probes, shared by the health endpoint
+pub struct HealthChecker {
+    timeout: Duration,
+    probes: RwLock<Vec<RegisteredProbe>>,
+}
+
+impl Default for HealthChecker {
+    fn default() -> Self {
+        Self::new(DEFAULT_PROBE_TIMEOUT)
+    }
+}
+
+impl HealthChecker {
+    /// Create a checker bounding each probe by `timeout`
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            probes: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Register a component under `name`
+    ///
+    /// Takes `&self` so components started after wiring (background tasks)
+    /// can register themselves on the shared checker.
+    pub fn register(&self, name: impl Into<String>, critical: bool, probe: Arc<dyn HealthProbe>) {
+        self.probes
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(RegisteredProbe { name: name.into(), critical, probe });
+    }
+
+    /// Probe every registered component concurrently and aggregate the results
+    pub async fn check(&self) -> HealthReport {
+        // Snapshot the registry so no lock is held across the probes
+        let probes: Vec<(String, bool, Arc<dyn HealthProbe>)> = self
+            .probes
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .map(|registered| (registered.name.clone(), registered.critical, registered.probe.clone()))
+            .collect();
+
+        let checks = futures::future::join_all(
+            probes
+                .into_iter()
+                .map(|(name, critical, probe)| async move { self.run_probe(name, critical, probe.as_ref()).await }),
+        )
+        .await;
+
+        HealthReport::from_checks(checks)
+    }
+
+    async fn run_probe(&self, name: String, critical: bool, probe: &dyn HealthProbe) -> ComponentCheck {
+        let started = Instant::now();
+        let outcome = match tokio::time::timeout(self.timeout, probe.probe()).await {
+            Ok(outcome) => outcome,
+            Err(_) => {
+                tracing::warn!("[HEALTH] Probe '{}' timed out after {:?}", name, self.timeout);
+                ProbeOutcome::unhealthy(format!("timed out after {}ms", self.timeout.as_millis()))
+            }
+        };
+
+        ComponentCheck {
+            name,
+            status: outcome.status,
+            critical,
+            latency_ms: started.elapsed().as_secs_f64() * 1000.0,
+            message: outcome.message,
+        }
+    }
+}
+
+/// Probes the database with `SELECT 1`
+pub struct DatabaseProbe {
+    database: Database,
+    degraded_threshold: Duration,
+}
+
+impl DatabaseProbe {
+    /// Report the database degraded when the probe is slower than `degraded_threshold`
+    pub fn new(database: Database, degraded_threshold: Duration) -> Self {
+        Self { database, degraded_threshold }
+    }
+}
+
+impl HealthProbe for DatabaseProbe {
+    fn probe(&self) -> BoxFuture<'_, ProbeOutcome> {
+        Box::pin(async move {
+            match self.database.health_with_latency().await {
+                Ok(health) if health.is_degraded(self.degraded_threshold) => {
+                    ProbeOutcome::degraded(format!("slow probe ({:.1}ms)", health.latency_ms()))
+                }
+                Ok(_) => ProbeOutcome::healthy(),
+                Err(e) => {
+                    tracing::warn!("[HEALTH] Database probe failed: {}", e);
+                    ProbeOutcome::unhealthy("database unreachable")
+                }
+            }
+        })
+    }
+}
+
+/// Checks that the token service can still sign and verify
+///
+/// Issues a short-lived service token and validates it again, which fails
+/// when a signing or verification key is missing or unusable.
+pub struct TokenServiceProbe {
+    token_service: Arc<dyn TokenService + Send + Sync>,
+}
+
+impl TokenServiceProbe {
+    pub fn new(token_service: Arc<dyn TokenService + Send + Sync>) -> Self {
+        Self { token_service }
+    }
+}
+
+impl HealthProbe for TokenServiceProbe {
+    fn probe(&self) -> BoxFuture<'_, ProbeOutcome> {
+        Box::pin(async move {
+            let claims = serde_json::json!({ "sub": TOKEN_PROBE_SUBJECT }).to_string();
+            let token: Token = self.token_service.issue_service_token(TOKEN_PROBE_SUBJECT, &claims).await;
+
+            if token.value().is_empty() {
+                ProbeOutcome::unhealthy("signing key unavailable")
+            } else if self.token_service.validate_service_token(&token).await.is_err() {
+                ProbeOutcome::unhealthy("verification key unavailable")
+            } else {
+                ProbeOutcome::healthy()
+            }
+        })
+    }
+}
+
+/// Last sign of life from a background task
+///
+/// Cloning is cheap; all clones share the same timestamp.
+#[derive(Debug, Clone)]
+pub struct Heartbeat {
+    last_beat: Arc<AtomicI64>,
+}
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Heartbeat {
+    /// Create a heartbeat that counts its creation as the first beat
+    pub fn new() -> Self {
+        Self {
+            last_beat: Arc::new(AtomicI64::new(chrono::Utc::now().timestamp_millis())),
+        }
+    }
+
+    /// Record that the task is alive
+    pub fn beat(&self) {
+        self.last_beat.store(chrono::Utc::now().timestamp_millis(), Ordering::Relaxed);
+    }
+
+    /// Time since the last beat
+    pub fn silence(&self) -> Duration {
+        let elapsed = chrono::Utc::now().timestamp_millis() - self.last_beat.load(Ordering::Relaxed);
+        Duration::from_millis(u64::try_from(elapsed).unwrap_or(0))
+    }
+}
+
+/// Reports a background task unhealthy once its heartbeat goes quiet
+pub struct HeartbeatProbe {
+    heartbeat: Heartbeat,
+    max_silence: Duration,
+}
+
+impl HeartbeatProbe {
+    /// Tolerate up to `max_silence` between beats
+    pub fn new(heartbeat: Heartbeat, max_silence: Duration) -> Self {
+        Self { heartbeat, max_silence }
+    }
+}
+
+impl HealthProbe for HeartbeatProbe {
+    fn probe(&self) -> BoxFuture<'_, ProbeOutcome> {
+        let silence = self.heartbeat.silence();
+        let outcome = if silence > self.max_silence {
+            ProbeOutcome::unhealthy(format!("no heartbeat for {}s", silence.as_secs()))
+        } else {
+            ProbeOutcome::healthy()
+        };
+        Box::pin(async move { outcome })
+    }
+}
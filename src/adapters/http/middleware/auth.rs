@@ -10,7 +10,7 @@ use crate::adapters::http::error::{HttpError, UnauthorizedError};
 
 /// Extract Bearer token from Authorization header and store in request extensions
 /// 
-/// Returns 401 Unauthorized if:
+/// Returns 401 Unauthorized with an `invalid_request` Bearer challenge if:
 /// - Authorization header is missing
 /// - Header does not start with "Bearer "
 /// - Token is empty
@@ -29,13 +29,13 @@ pub async fn bearer_auth(
             Some(header) if header.starts_with("Bearer ") => {
                 let token_str = &header[7..];
                 if token_str.is_empty() {
-                    let error = HttpError::Unauthorized(UnauthorizedError::new("Token is empty"));
+                    let error = HttpError::Unauthorized(UnauthorizedError::invalid_request("Token is empty"));
                     return error.into_response();
                 }
                 token_str.to_string()
             }
             _ => {
-                let error = HttpError::Unauthorized(UnauthorizedError::new("Missing Authorization header"));
+                let error = HttpError::Unauthorized(UnauthorizedError::invalid_request("Missing Authorization header"));
                 return error.into_response();
             }
         }
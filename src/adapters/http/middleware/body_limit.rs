@@ -0,0 +1,78 @@
+// Request body size limit middleware
+
+use axum::{
+    body::{self, Body},
+    extract::{Request, State},
+    http::header,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http_body_util::LengthLimitError;
+
+use crate::adapters::http::error::{HttpError, PayloadTooLargeError, ValidationError};
+
+/// Default maximum request body size in bytes
+///
+/// Public requests carry an identifier, a password or a token; 16 KiB
+/// leaves ample headroom for all of them.
+pub const DEFAULT_MAX_BODY_BYTES: usize = 16 * 1024;
+
+/// Reject request bodies larger than `max_body_bytes`
+///
+/// Runs before any extractor, so an oversized body is never buffered past
+/// the limit nor handed to the JSON deserializer. A declared
+/// `Content-Length` over the limit is rejected without reading the body;
+/// otherwise the body is read up to the limit and any overflow is rejected.
+/// A body that fails to arrive for any other reason (a client disconnect,
+/// malformed chunked encoding) gets 400. Layered with the limit held in
+/// `AppState`.
+pub async fn body_limit(
+    State(max_body_bytes): State<usize>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let declared = request
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+
+    if declared.is_some_and(|length| length > max_body_bytes as u64) {
+        return too_large(max_body_bytes);
+    }
+
+    let (parts, body) = request.into_parts();
+    let bytes = match body::to_bytes(body, max_body_bytes).await {
+        Ok(bytes) => bytes,
+        Err(error) if is_length_limit(&error) => return too_large(max_body_bytes),
+        Err(error) => return unreadable(&error),
+    };
+
+    next.run(Request::from_parts(parts, Body::from(bytes))).await
+}
+
+fn too_large(max_body_bytes: usize) -> Response {
+    tracing::warn!(limit = max_body_bytes, "[BODY_LIMIT] Request body rejected");
+    HttpError::PayloadTooLarge(PayloadTooLargeError::new(
+        "request body too large",
+        max_body_bytes,
+    ))
+    .into_response()
+}
+
+fn unreadable(error: &axum::Error) -> Response {
+    tracing::debug!(error = %error, "[BODY_LIMIT] Request body could not be read");
+    HttpError::Validation(ValidationError::new("request body could not be read")).into_response()
+}
+
+/// Whether reading the body failed because it exceeded the limit
+fn is_length_limit(error: &axum::Error) -> bool {
+    let mut source: Option<&(dyn std::error::Error + 'static)> = Some(error);
+    while let Some(error) = source {
+        if error.is::<LengthLimitError>() {
+            return true;
+        }
+        source = error.source();
+    }
+    false
+}
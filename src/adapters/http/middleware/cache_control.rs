@@ -0,0 +1,30 @@
+// Cache-control middleware
+
+use axum::{
+    extract::Request,
+    http::{header, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+
+/// Mark every response as non-cacheable
+///
+/// Token-issuing responses (authenticate, refresh, service tokens) must
+/// never be stored by browsers or intermediaries (RFC 6749 §5.1). Handlers
+/// that set their own `Cache-Control` are left untouched.
+pub async fn no_store(
+    request: Request,
+    next: Next,
+) -> Response {
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+
+    if !headers.contains_key(header::CACHE_CONTROL) {
+        headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    }
+    if !headers.contains_key(header::PRAGMA) {
+        headers.insert(header::PRAGMA, HeaderValue::from_static("no-cache"));
+    }
+
+    response
+}
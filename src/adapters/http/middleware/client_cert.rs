@@ -0,0 +1,230 @@
+// Mutual TLS client-certificate verification middleware
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderMap, HeaderName},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::adapters::http::error::{HttpError, ServiceUnauthorizedError};
+use crate::core::usecases::ports::ServiceRegistry;
+
+/// Header in which a TLS-terminating proxy forwards the client certificate
+pub const FORWARDED_CLIENT_CERT_HEADER: &str = "x-forwarded-client-cert";
+
+/// Identity presented by the peer's TLS client certificate
+///
+/// Inserted into request extensions once the handshake verified the chain
+/// against the trusted CA: by [`forwarded_client_cert`] when a trusted proxy
+/// terminated TLS, or by any TLS listener placed in front of the router. Its
+/// absence means the peer presented no certificate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientCertificate {
+    /// Subject distinguished name, e.g. `CN=billing,O=Agora`
+    pub subject: Option<String>,
+    /// SPIFFE ID from the URI SAN, e.g. `spiffe://agora.internal/ns/prod/sa/billing`
+    pub spiffe_id: Option<String>,
+}
+
+impl ClientCertificate {
+    /// Create a certificate identity from its subject and SPIFFE ID
+    pub fn new(subject: Option<String>, spiffe_id: Option<String>) -> Self {
+        Self { subject, spiffe_id }
+    }
+
+    /// Identities to look up, most specific first
+    pub fn identities(&self) -> impl Iterator<Item = &str> {
+        self.spiffe_id
+            .as_deref()
+            .into_iter()
+            .chain(self.subject.as_deref())
+            .filter(|identity| !identity.is_empty())
+    }
+
+    /// Read the identity from an `X-Forwarded-Client-Cert` header value
+    ///
+    /// Follows the Envoy format: comma-separated elements of `;`-separated
+    /// `Key=Value` pairs, values optionally double-quoted. Each proxy appends
+    /// one element, so the last describes the certificate the nearest proxy
+    /// verified. Its `Subject` and its first `spiffe://` `URI` are used.
+    /// Returns `None` when neither is present.
+    pub fn from_forwarded(value: &str) -> Option<Self> {
+        let element = split_unquoted(value, ',').pop()?;
+
+        let mut certificate = Self::default();
+        for pair in split_unquoted(element, ';') {
+            let Some((key, value)) = pair.split_once('=') else {
+                continue;
+            };
+            let value = unquote(value);
+            match key.trim().to_ascii_lowercase().as_str() {
+                "subject" => certificate.subject = Some(value),
+                "uri" if certificate.spiffe_id.is_none() && value.starts_with("spiffe://") => {
+                    certificate.spiffe_id = Some(value)
+                }
+                _ => {}
+            }
+        }
+
+        let identified = certificate.identities().next().is_some();
+        identified.then_some(certificate)
+    }
+}
+
+/// Split `value` on `separator`, except inside double quotes
+fn split_unquoted(value: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut start, mut quoted, mut escaped) = (0, false, false);
+    for (index, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => quoted = !quoted,
+            c if c == separator && !quoted => {
+                parts.push(&value[start..index]);
+                start = index + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
+
+/// Strip surrounding double quotes and unescape embedded ones
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    match value.strip_prefix('"').and_then(|inner| inner.strip_suffix('"')) {
+        Some(inner) => inner.replace("\\\"", "\""),
+        None => value.to_string(),
+    }
+}
+
+/// Proxies trusted to forward the client certificate they verified
+///
+/// The proxy terminates mutual TLS and passes the verified identity on in a
+/// header. Only connections from a listed proxy address may carry it; the
+/// header is ignored on any other connection, so a caller reaching the
+/// service directly cannot claim an identity.
+#[derive(Debug, Clone)]
+pub struct ClientCertificateProxy {
+    header: HeaderName,
+    trusted_proxies: Vec<IpAddr>,
+}
+
+impl ClientCertificateProxy {
+    /// Trust `header` on connections from `trusted_proxies`
+    pub fn new(header: HeaderName, trusted_proxies: Vec<IpAddr>) -> Self {
+        let trusted_proxies = trusted_proxies.into_iter().map(|ip| ip.to_canonical()).collect();
+        Self { header, trusted_proxies }
+    }
+
+    /// Whether the connection from `peer` comes from a trusted proxy
+    pub fn is_trusted(&self, peer: Option<SocketAddr>) -> bool {
+        peer.is_some_and(|addr| self.trusted_proxies.contains(&addr.ip().to_canonical()))
+    }
+
+    /// The certificate forwarded on a connection from `peer`, if trusted
+    pub fn certificate(&self, headers: &HeaderMap, peer: Option<SocketAddr>) -> Option<ClientCertificate> {
+        if !self.is_trusted(peer) {
+            return None;
+        }
+
+        // A repeated header is ambiguous; trust none of its values
+        let mut values = headers.get_all(&self.header).iter();
+        let value = values.next()?;
+        if values.next().is_some() {
+            return None;
+        }
+        ClientCertificate::from_forwarded(value.to_str().ok()?)
+    }
+}
+
+impl Default for ClientCertificateProxy {
+    fn default() -> Self {
+        Self::new(HeaderName::from_static(FORWARDED_CLIENT_CERT_HEADER), Vec::new())
+    }
+}
+
+/// Take the client certificate from a trusted TLS-terminating proxy
+///
+/// Inserts the [`ClientCertificate`] forwarded by a proxy listed in the
+/// [`ClientCertificateProxy`] held in `AppState`, for [`client_cert_auth`]
+/// to check. A certificate already inserted by a TLS listener is kept.
+pub async fn forwarded_client_cert(
+    State(proxy): State<ClientCertificateProxy>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    if request.extensions().get::<ClientCertificate>().is_none() {
+        let peer = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| *addr);
+
+        match proxy.certificate(request.headers(), peer) {
+            Some(certificate) => {
+                request.extensions_mut().insert(certificate);
+            }
+            None if request.headers().contains_key(&proxy.header) && !proxy.is_trusted(peer) => {
+                tracing::warn!(peer = ?peer, "[CLIENT_CERT_AUTH] Forwarded certificate from untrusted peer ignored");
+            }
+            None => {}
+        }
+    }
+
+    next.run(request).await
+}
+
+/// Require a client certificate belonging to a registered, active service
+///
+/// The certificate's SPIFFE ID is tried first, then its subject; the first
+/// identity the registry recognises decides the service. Layered with the
+/// service registry held in `AppState`.
+///
+/// Returns 401 Unauthorized if:
+/// - No client certificate was presented
+/// - No identity of the certificate is registered
+/// - The service it belongs to is inactive
+pub async fn client_cert_auth(
+    State(registry): State<Arc<dyn ServiceRegistry + Send + Sync>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(certificate) = request.extensions().get::<ClientCertificate>() else {
+        return HttpError::ServiceUnauthorized(ServiceUnauthorizedError::new("Client certificate required"))
+            .into_response();
+    };
+
+    let service_name = match certificate
+        .identities()
+        .find_map(|identity| registry.service_for_certificate(identity))
+    {
+        Some(name) => name,
+        None => {
+            tracing::warn!(
+                subject = ?certificate.subject,
+                spiffe_id = ?certificate.spiffe_id,
+                "[CLIENT_CERT_AUTH] Unregistered client certificate"
+            );
+            return HttpError::ServiceUnauthorized(ServiceUnauthorizedError::new(
+                "Client certificate is not registered",
+            ))
+            .into_response();
+        }
+    };
+
+    if !registry.is_service_active(&service_name) {
+        return HttpError::ServiceUnauthorized(ServiceUnauthorizedError::with_service_id(
+            "Service is not active",
+            &service_name,
+        ))
+        .into_response();
+    }
+
+    next.run(request).await
+}
@@ -0,0 +1,84 @@
+// Cross-origin resource sharing middleware
+
+use axum::{
+    extract::Request,
+    http::{header, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+/// Which browser origins may call the public endpoints
+///
+/// Origins are matched exactly against an allowlist; a disallowed origin
+/// gets no `Access-Control-Allow-Origin` header rather than an echo of
+/// itself. The default allows no origin at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorsPolicy {
+    /// Exact origins, e.g. `https://app.example.com`
+    pub allowed_origins: Vec<String>,
+    /// Methods announced to preflight requests
+    pub allowed_methods: Vec<Method>,
+    /// Whether browsers may send cookies and `Authorization` cross-origin
+    pub allow_credentials: bool,
+}
+
+impl CorsPolicy {
+    /// Create a policy for the given origins, methods and credentials mode
+    pub fn new(allowed_origins: Vec<String>, allowed_methods: Vec<Method>, allow_credentials: bool) -> Self {
+        Self {
+            allowed_origins,
+            allowed_methods,
+            allow_credentials,
+        }
+    }
+
+    /// Returns true if at least one origin is allowed
+    pub fn is_enabled(&self) -> bool {
+        !self.allowed_origins.is_empty()
+    }
+}
+
+impl Default for CorsPolicy {
+    fn default() -> Self {
+        Self::new(Vec::new(), vec![Method::GET, Method::POST], false)
+    }
+}
+
+/// Build the `tower-http` layer enforcing `policy`
+///
+/// Preflight `OPTIONS` requests are answered by the layer itself, so they
+/// never reach handlers, authentication or rate limiting. Origins that are
+/// not valid header values, and the `*` wildcard, are never allowed.
+pub fn cors_layer(policy: &CorsPolicy) -> CorsLayer {
+    let origins: Vec<HeaderValue> = policy
+        .allowed_origins
+        .iter()
+        .filter(|origin| origin.as_str() != "*")
+        .filter_map(|origin| HeaderValue::from_str(origin).ok())
+        .collect();
+
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_methods(policy.allowed_methods.clone())
+        .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE])
+        .allow_credentials(policy.allow_credentials)
+}
+
+/// Answer preflight requests with `204 No Content`
+///
+/// `CorsLayer` replies to preflights with an empty `200 OK`; browsers accept
+/// either, but an empty body is what 204 means. Must wrap [`cors_layer`].
+pub async fn preflight_no_content(
+    request: Request,
+    next: Next,
+) -> Response {
+    let is_preflight = request.method() == Method::OPTIONS;
+    let mut response = next.run(request).await;
+
+    if is_preflight && response.status() == StatusCode::OK {
+        *response.status_mut() = StatusCode::NO_CONTENT;
+    }
+
+    response
+}
@@ -17,13 +17,32 @@ Design Principles:
 Middleware types:
  - `auth`: Validates Bearer tokens for public endpoints
  - `service_auth`: Validates service credentials for internal endpoints
+ - `client_cert`: Verifies mTLS client certificates, as forwarded by a
+   trusted TLS-terminating proxy, for internal endpoints
+ - `panic_guard`: Converts handler panics into sanitized 500 responses
+ - `cache_control`: Marks responses as non-cacheable
+ - `rate_limit`: Sliding-window request limits per client address
+ - `cors`: Origin allowlist and preflight handling for browser clients
+ - `body_limit`: Caps request body size before deserialization
 */
 
 pub mod auth;
 pub mod service_auth;
+pub mod client_cert;
+pub mod panic_guard;
+pub mod cache_control;
+pub mod rate_limit;
+pub mod cors;
+pub mod body_limit;
 
 pub use auth::bearer_auth;
 pub use service_auth::{service_auth, service_jwt_auth, ServiceContext};
+pub use client_cert::{client_cert_auth, forwarded_client_cert, ClientCertificate, ClientCertificateProxy};
+pub use panic_guard::{catch_panic, install_panic_hook};
+pub use cache_control::no_store;
+pub use rate_limit::{rate_limit, RateLimiter};
+pub use cors::{cors_layer, preflight_no_content, CorsPolicy};
+pub use body_limit::{body_limit, DEFAULT_MAX_BODY_BYTES};
 
 #[cfg(test)]
 pub mod tests;
\ No newline at end of file
@@ -0,0 +1,87 @@
+// Panic containment middleware
+
+use std::panic::AssertUnwindSafe;
+
+use axum::{
+    extract::Request,
+    http::{HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use futures::FutureExt;
+
+use crate::adapters::http::error::{ErrorResponse, HttpError, InternalError};
+
+/// Header used to correlate a client-visible error with server-side logs
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Convert a panic raised inside a handler into a clean 500 response
+///
+/// The panic payload is never inspected or echoed: it may contain request
+/// data (credentials, tokens) captured by an `unwrap`/`expect` message.
+/// The client only receives the generic internal error body plus a request
+/// id, which is also logged server-side together with the panic location
+/// recorded by the process panic hook.
+pub async fn catch_panic(
+    request: Request,
+    next: Next,
+) -> Response {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty() && value.len() <= 128)
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+
+    match AssertUnwindSafe(next.run(request)).catch_unwind().await {
+        Ok(response) => response,
+        Err(_payload) => {
+            tracing::error!(
+                request_id = %request_id,
+                method = %method,
+                path = %path,
+                "[PANIC] Handler panicked; returning 500"
+            );
+            panic_response(&request_id)
+        }
+    }
+}
+
+/// Build the sanitized 500 response returned after a caught panic
+fn panic_response(request_id: &str) -> Response {
+    let error = HttpError::Internal(InternalError::new("Handler panicked"));
+    let body = ErrorResponse::from_http_error(&error).with_request_id(request_id);
+
+    let mut response = (StatusCode::INTERNAL_SERVER_ERROR, Json(body)).into_response();
+    if let Ok(value) = HeaderValue::from_str(request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+/// Install a process-wide panic hook that logs only the panic location
+///
+/// Replaces the default hook, which prints the panic payload to stderr.
+/// Payloads are dropped on purpose so secrets formatted into panic messages
+/// never reach the logs.
+pub fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        let location = info
+            .location()
+            .map(|loc| format!("{}:{}:{}", loc.file(), loc.line(), loc.column()))
+            .unwrap_or_else(|| "unknown".to_string());
+        let thread = std::thread::current();
+        let thread_name = thread.name().unwrap_or("unnamed");
+
+        tracing::error!(
+            location = %location,
+            thread = %thread_name,
+            "[PANIC] Panic occurred (payload redacted)"
+        );
+    }));
+}
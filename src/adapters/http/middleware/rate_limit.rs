@@ -0,0 +1,163 @@
+// Per-client rate limiting middleware
+
+use std::collections::VecDeque;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Duration, Utc};
+use dashmap::DashMap;
+
+use crate::adapters::http::error::{HttpError, TooManyRequestsError};
+use crate::adapters::http::request_context::ClientIpResolver;
+use crate::adapters::clock::SystemClock;
+use crate::core::usecases::ports::Clock;
+
+pub use crate::adapters::http::request_context::FORWARDED_FOR_HEADER;
+
+/// Default number of requests allowed per client per window
+pub const DEFAULT_MAX_REQUESTS: u32 = 60;
+
+/// Default window length in seconds
+pub const DEFAULT_WINDOW_SECS: u64 = 60;
+
+/// Sliding-window rate limiter keyed by client address
+///
+/// Each client keeps the timestamps of its requests within the last window,
+/// so a burst at the end of one window cannot be followed by a full burst at
+/// the start of the next. Buckets whose newest request fell out of the window
+/// are pruned at most once per window.
+pub struct RateLimiter {
+    max_requests: u32,
+    window: Duration,
+    buckets: DashMap<String, VecDeque<DateTime<Utc>>>,
+    last_prune: Mutex<DateTime<Utc>>,
+    clock: Arc<dyn Clock + Send + Sync>,
+    ip_resolver: ClientIpResolver,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `max_requests` per `window_secs` per client
+    pub fn new(max_requests: u32, window_secs: u64) -> Self {
+        Self::with_clock(max_requests, window_secs, Arc::new(SystemClock))
+    }
+
+    /// Create a limiter reading time from the given clock
+    pub fn with_clock(
+        max_requests: u32,
+        window_secs: u64,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        let now = clock.now();
+        Self {
+            max_requests: max_requests.max(1),
+            window: Duration::seconds(window_secs.max(1) as i64),
+            buckets: DashMap::new(),
+            last_prune: Mutex::new(now),
+            clock,
+            ip_resolver: ClientIpResolver::default(),
+        }
+    }
+
+    /// Use the given resolver to identify clients behind proxies
+    pub fn with_ip_resolver(mut self, ip_resolver: ClientIpResolver) -> Self {
+        self.ip_resolver = ip_resolver;
+        self
+    }
+
+    /// Record a request for `key`
+    ///
+    /// Returns `Err(retry_after_secs)` when the client has exhausted its
+    /// window; rejected requests are not recorded.
+    pub fn check(&self, key: &str) -> Result<(), u64> {
+        let now = self.clock.now();
+        self.prune_if_due(now);
+
+        let cutoff = now - self.window;
+        let mut bucket = self.buckets.entry(key.to_string()).or_default();
+        while bucket.front().is_some_and(|seen| *seen <= cutoff) {
+            bucket.pop_front();
+        }
+
+        if bucket.len() >= self.max_requests as usize {
+            let oldest = *bucket.front().expect("bucket is non-empty when at limit");
+            let wait = (oldest + self.window - now).num_milliseconds().max(0) as u64;
+            return Err(wait.div_ceil(1000).max(1));
+        }
+
+        bucket.push_back(now);
+        Ok(())
+    }
+
+    /// Drop buckets with no requests inside the current window
+    pub fn prune(&self) {
+        let cutoff = self.clock.now() - self.window;
+        self.buckets
+            .retain(|_, bucket| bucket.back().is_some_and(|newest| *newest > cutoff));
+    }
+
+    /// Number of clients currently tracked
+    pub fn tracked_clients(&self) -> usize {
+        self.buckets.len()
+    }
+
+    fn prune_if_due(&self, now: DateTime<Utc>) {
+        let Ok(mut last_prune) = self.last_prune.try_lock() else {
+            // Another request is already pruning
+            return;
+        };
+        if now - *last_prune >= self.window {
+            *last_prune = now;
+            drop(last_prune);
+            self.prune();
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_REQUESTS, DEFAULT_WINDOW_SECS)
+    }
+}
+
+/// Reject clients that exceed the configured request rate
+///
+/// Clients are keyed by the address the limiter's `ClientIpResolver` derives
+/// from `X-Forwarded-For` and the socket address. Layered with the limiter
+/// held in `AppState`.
+pub async fn rate_limit(
+    State(limiter): State<Arc<RateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let client = client_key(&limiter, &request);
+
+    if let Err(retry_after) = limiter.check(&client) {
+        tracing::warn!(client = %client, retry_after, "[RATE_LIMIT] Request rejected");
+        return HttpError::TooManyRequests(TooManyRequestsError::new(
+            "too many requests",
+            retry_after,
+        ))
+        .into_response();
+    }
+
+    next.run(request).await
+}
+
+/// Resolve the rate-limit key for a request
+fn client_key(limiter: &RateLimiter, request: &Request) -> String {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| *addr);
+
+    limiter
+        .ip_resolver
+        .resolve(request.headers(), peer)
+        .map(|ip| ip.to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
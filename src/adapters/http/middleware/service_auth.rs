@@ -26,7 +26,9 @@ impl ServiceContext {
 /// Validate service authentication via X-Service-Key header
 ///
 /// For internal endpoints, validates that the request includes a valid service key
-/// registered in the service registry and that the service is active.
+/// registered in the service registry and that the service is active. With the
+/// database-backed registry the key is `service_id:secret`, verified against the
+/// stored hash.
 /// 
 /// Returns 401 Unauthorized if:
 /// - X-Service-Key header is missing
@@ -141,7 +143,7 @@ pub async fn service_jwt_auth(
 
     // Validate the service token
     let token = Token::new(token_string.as_str());
-    let claims = match token_service.validate_service_token(&token) {
+    let claims = match token_service.validate_service_token(&token).await {
         Ok(c) => c,
         Err(_) => {
             let error = HttpError::ServiceUnauthorized(ServiceUnauthorizedError::new("Invalid or expired service token"));
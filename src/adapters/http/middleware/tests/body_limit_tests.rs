@@ -0,0 +1,119 @@
+//! Tests for body_limit middleware
+
+use axum::{
+    body::{Body, Bytes},
+    http::{header, Request, StatusCode},
+    middleware,
+    routing::post,
+    Router,
+};
+use futures::stream;
+use tower::ServiceExt;
+
+use crate::adapters::http::error::ErrorResponse;
+use crate::adapters::http::middleware::body_limit;
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+const LIMIT: usize = 64;
+
+fn app() -> Router {
+    Router::new()
+        .route("/echo", post(|body: String| async move { body }))
+        .layer(middleware::from_fn_with_state(LIMIT, body_limit))
+}
+
+fn request(body: String) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/echo")
+        .header(header::CONTENT_LENGTH, body.len())
+        .body(Body::from(body))
+        .unwrap()
+}
+
+/// Body without a declared length, delivered in chunks
+fn streamed_request(chunks: Vec<&'static str>) -> Request<Body> {
+    let stream = stream::iter(
+        chunks
+            .into_iter()
+            .map(|chunk| Ok::<_, std::io::Error>(Bytes::from_static(chunk.as_bytes()))),
+    );
+    Request::builder()
+        .method("POST")
+        .uri("/echo")
+        .body(Body::from_stream(stream))
+        .unwrap()
+}
+
+async fn body_text(response: axum::response::Response) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_body_within_limit_reaches_handler() {
+    let body = "a".repeat(LIMIT);
+
+    let response = app().oneshot(request(body.clone())).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body_text(response).await, body);
+}
+
+#[tokio::test]
+async fn test_declared_length_over_limit_returns_413() {
+    let response = app().oneshot(request("a".repeat(LIMIT + 1))).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let error: ErrorResponse = serde_json::from_str(&body_text(response).await).unwrap();
+    assert_eq!(error.code, "PAYLOAD_TOO_LARGE");
+    assert!(error.message.contains(&LIMIT.to_string()));
+}
+
+#[tokio::test]
+async fn test_undeclared_body_over_limit_returns_413() {
+    let chunk = "0123456789abcdef0123456789abcdef";
+    let response = app()
+        .oneshot(streamed_request(vec![chunk, chunk, chunk]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn test_undeclared_body_within_limit_reaches_handler() {
+    let response = app()
+        .oneshot(streamed_request(vec!["{\"refresh_token\":", "\"abc\"}"]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body_text(response).await, "{\"refresh_token\":\"abc\"}");
+}
+
+#[tokio::test]
+async fn test_body_read_failure_returns_400() {
+    let stream = stream::iter(vec![
+        Ok(Bytes::from_static(b"{\"refresh_token\":")),
+        Err(std::io::Error::new(std::io::ErrorKind::ConnectionReset, "client went away")),
+    ]);
+    let request = Request::builder()
+        .method("POST")
+        .uri("/echo")
+        .body(Body::from_stream(stream))
+        .unwrap();
+
+    let response = app().oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let error: ErrorResponse = serde_json::from_str(&body_text(response).await).unwrap();
+    assert_eq!(error.code, "VALIDATION_ERROR");
+}
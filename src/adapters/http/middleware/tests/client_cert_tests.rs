@@ -0,0 +1,251 @@
+//! Tests for client_cert middleware
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::ConnectInfo,
+    http::{HeaderName, Request, StatusCode},
+    middleware,
+    routing::get,
+    Router,
+};
+use tower::ServiceExt;
+
+use crate::adapters::http::error::ErrorResponse;
+use crate::adapters::http::middleware::{
+    client_cert_auth, forwarded_client_cert, ClientCertificate, ClientCertificateProxy,
+};
+use crate::core::usecases::ports::service_registry::tests::MockServiceRegistry;
+use crate::core::usecases::ports::ServiceRegistry;
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+const REGISTERED_SPIFFE_ID: &str = "spiffe://agora.internal/ns/prod/sa/billing";
+const REGISTERED_SUBJECT: &str = "CN=internal,O=Agora";
+const UNKNOWN_SPIFFE_ID: &str = "spiffe://agora.internal/ns/prod/sa/unknown";
+const PROXY_ADDR: &str = "10.0.0.2:443";
+const FORWARDED_BILLING: &str =
+    "By=spiffe://agora.internal/ns/prod/sa/auth;Hash=9ba61d;URI=spiffe://agora.internal/ns/prod/sa/billing";
+
+fn registry() -> Arc<MockServiceRegistry> {
+    let registry = MockServiceRegistry::new();
+    registry.add_certificate_identity(REGISTERED_SPIFFE_ID, "test-service");
+    registry.add_certificate_identity(REGISTERED_SUBJECT, "internal-service");
+    Arc::new(registry)
+}
+
+fn app(registry: Arc<MockServiceRegistry>) -> Router {
+    let registry: Arc<dyn ServiceRegistry + Send + Sync> = registry;
+    Router::new()
+        .route("/internal/health", get(|| async { "ok" }))
+        .layer(middleware::from_fn_with_state(registry, client_cert_auth))
+}
+
+/// The app behind a proxy at `PROXY_ADDR` forwarding certificates
+fn proxied_app(registry: Arc<MockServiceRegistry>) -> Router {
+    let proxy = ClientCertificateProxy::new(
+        HeaderName::from_static("x-forwarded-client-cert"),
+        vec![PROXY_ADDR.parse::<SocketAddr>().unwrap().ip()],
+    );
+    app(registry).layer(middleware::from_fn_with_state(proxy, forwarded_client_cert))
+}
+
+/// A request arriving from `peer` with the given forwarded certificates
+fn forwarded_request(peer: Option<&str>, forwarded: &[&str]) -> Request<Body> {
+    let mut builder = Request::builder().uri("/internal/health");
+    for value in forwarded {
+        builder = builder.header("x-forwarded-client-cert", *value);
+    }
+    let mut request = builder.body(Body::empty()).unwrap();
+    if let Some(peer) = peer {
+        request.extensions_mut().insert(ConnectInfo(peer.parse::<SocketAddr>().unwrap()));
+    }
+    request
+}
+
+fn request(certificate: Option<ClientCertificate>) -> Request<Body> {
+    let mut request = Request::builder()
+        .uri("/internal/health")
+        .body(Body::empty())
+        .unwrap();
+    if let Some(certificate) = certificate {
+        request.extensions_mut().insert(certificate);
+    }
+    request
+}
+
+async fn error_body(response: axum::response::Response) -> ErrorResponse {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_registered_spiffe_id_is_accepted() {
+    let certificate = ClientCertificate::new(None, Some(REGISTERED_SPIFFE_ID.to_string()));
+
+    let response = app(registry()).oneshot(request(Some(certificate))).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_registered_subject_is_accepted() {
+    let certificate = ClientCertificate::new(Some(REGISTERED_SUBJECT.to_string()), None);
+
+    let response = app(registry()).oneshot(request(Some(certificate))).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_spiffe_id_is_preferred_over_subject() {
+    // An unknown SPIFFE ID falls back to a registered subject
+    let certificate = ClientCertificate::new(
+        Some(REGISTERED_SUBJECT.to_string()),
+        Some(UNKNOWN_SPIFFE_ID.to_string()),
+    );
+
+    let response = app(registry()).oneshot(request(Some(certificate))).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_unregistered_certificate_returns_401() {
+    let certificate = ClientCertificate::new(
+        Some("CN=unknown,O=Elsewhere".to_string()),
+        Some(UNKNOWN_SPIFFE_ID.to_string()),
+    );
+
+    let response = app(registry()).oneshot(request(Some(certificate))).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let error = error_body(response).await;
+    assert_eq!(error.code, "SERVICE_UNAUTHORIZED");
+}
+
+#[tokio::test]
+async fn test_absent_certificate_returns_401() {
+    let response = app(registry()).oneshot(request(None)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let error = error_body(response).await;
+    assert_eq!(error.code, "SERVICE_UNAUTHORIZED");
+}
+
+#[tokio::test]
+async fn test_certificate_without_identities_returns_401() {
+    let response = app(registry())
+        .oneshot(request(Some(ClientCertificate::default())))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_inactive_service_returns_401() {
+    let registry = registry();
+    registry.deactivate_service("test-service");
+    let certificate = ClientCertificate::new(None, Some(REGISTERED_SPIFFE_ID.to_string()));
+
+    let response = app(registry).oneshot(request(Some(certificate))).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[test]
+fn test_forwarded_certificate_yields_subject_and_spiffe_id() {
+    let certificate = ClientCertificate::from_forwarded(
+        "Hash=9ba61d;Subject=\"CN=internal,O=Agora\";URI=spiffe://agora.internal/ns/prod/sa/billing;DNS=billing",
+    )
+    .expect("Certificate should be read");
+
+    assert_eq!(certificate.subject.as_deref(), Some(REGISTERED_SUBJECT));
+    assert_eq!(certificate.spiffe_id.as_deref(), Some(REGISTERED_SPIFFE_ID));
+}
+
+#[test]
+fn test_forwarded_certificate_uses_nearest_proxy_element() {
+    let value = format!("URI=spiffe://agora.internal/ns/prod/sa/other,{}", FORWARDED_BILLING);
+
+    let certificate = ClientCertificate::from_forwarded(&value).expect("Certificate should be read");
+
+    // `By` names the proxy itself, not the client
+    assert_eq!(certificate.spiffe_id.as_deref(), Some(REGISTERED_SPIFFE_ID));
+}
+
+#[test]
+fn test_forwarded_subject_unescapes_quotes() {
+    let certificate = ClientCertificate::from_forwarded(r#"Subject="CN=\"quoted\",O=Agora""#)
+        .expect("Certificate should be read");
+
+    assert_eq!(certificate.subject.as_deref(), Some(r#"CN="quoted",O=Agora"#));
+}
+
+#[test]
+fn test_forwarded_certificate_without_identity_is_none() {
+    assert_eq!(ClientCertificate::from_forwarded(""), None);
+    assert_eq!(ClientCertificate::from_forwarded("Hash=9ba61d;URI=https://billing.example.com"), None);
+}
+
+#[tokio::test]
+async fn test_certificate_forwarded_by_trusted_proxy_is_accepted() {
+    let response = proxied_app(registry())
+        .oneshot(forwarded_request(Some(PROXY_ADDR), &[FORWARDED_BILLING]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_certificate_forwarded_by_untrusted_peer_returns_401() {
+    let response = proxied_app(registry())
+        .oneshot(forwarded_request(Some("203.0.113.9:51000"), &[FORWARDED_BILLING]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_certificate_forwarded_without_peer_address_returns_401() {
+    let response = proxied_app(registry())
+        .oneshot(forwarded_request(None, &[FORWARDED_BILLING]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_repeated_forwarded_certificate_header_returns_401() {
+    let response = proxied_app(registry())
+        .oneshot(forwarded_request(
+            Some(PROXY_ADDR),
+            &[FORWARDED_BILLING, "URI=spiffe://agora.internal/ns/prod/sa/unknown"],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_trusted_proxy_without_certificate_returns_401() {
+    let response = proxied_app(registry())
+        .oneshot(forwarded_request(Some(PROXY_ADDR), &[]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
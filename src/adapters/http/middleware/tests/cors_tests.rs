@@ -0,0 +1,183 @@
+//! Tests for cors middleware
+
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Router,
+};
+use tower::ServiceExt;
+
+use crate::adapters::http::middleware::{cors_layer, preflight_no_content, CorsPolicy};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+const ALLOWED_ORIGIN: &str = "https://app.example.com";
+const OTHER_ORIGIN: &str = "https://evil.example.com";
+
+fn policy() -> CorsPolicy {
+    CorsPolicy::new(
+        vec![ALLOWED_ORIGIN.to_string()],
+        vec![Method::GET, Method::POST],
+        false,
+    )
+}
+
+/// Stand-in for authentication: rejects everything it sees
+async fn reject_all(_request: Request<Body>, _next: Next) -> Response {
+    StatusCode::UNAUTHORIZED.into_response()
+}
+
+fn app(policy: &CorsPolicy) -> Router {
+    let protected = Router::new()
+        .route("/protected", post(|| async { "reached" }))
+        .layer(middleware::from_fn(reject_all));
+
+    Router::new()
+        .route("/open", get(|| async { "ok" }))
+        .merge(protected)
+        .layer(cors_layer(policy))
+        .layer(middleware::from_fn(preflight_no_content))
+}
+
+fn request(method: Method, path: &str, origin: &str) -> Request<Body> {
+    Request::builder()
+        .method(method)
+        .uri(path)
+        .header(header::ORIGIN, origin)
+        .body(Body::empty())
+        .unwrap()
+}
+
+fn preflight(path: &str, origin: &str) -> Request<Body> {
+    Request::builder()
+        .method(Method::OPTIONS)
+        .uri(path)
+        .header(header::ORIGIN, origin)
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "authorization,content-type")
+        .body(Body::empty())
+        .unwrap()
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_allowed_origin_gets_allow_origin_header() {
+    let response = app(&policy())
+        .oneshot(request(Method::GET, "/open", ALLOWED_ORIGIN))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+        ALLOWED_ORIGIN
+    );
+}
+
+#[tokio::test]
+async fn test_disallowed_origin_is_not_echoed() {
+    let response = app(&policy())
+        .oneshot(request(Method::GET, "/open", OTHER_ORIGIN))
+        .await
+        .unwrap();
+
+    assert!(response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+}
+
+#[tokio::test]
+async fn test_preflight_returns_no_content_with_allowed_methods() {
+    let response = app(&policy())
+        .oneshot(preflight("/protected", ALLOWED_ORIGIN))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(
+        response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+        ALLOWED_ORIGIN
+    );
+    assert_eq!(
+        response.headers().get(header::ACCESS_CONTROL_ALLOW_METHODS).unwrap(),
+        "GET,POST"
+    );
+    let allowed_headers = response
+        .headers()
+        .get(header::ACCESS_CONTROL_ALLOW_HEADERS)
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(allowed_headers.contains("authorization"));
+    assert!(allowed_headers.contains("content-type"));
+}
+
+#[tokio::test]
+async fn test_preflight_does_not_reach_auth() {
+    // reject_all would turn this into a 401 if the preflight got through
+    let response = app(&policy())
+        .oneshot(preflight("/protected", ALLOWED_ORIGIN))
+        .await
+        .unwrap();
+
+    assert_ne!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_preflight_from_disallowed_origin_is_not_allowed() {
+    let response = app(&policy())
+        .oneshot(preflight("/protected", OTHER_ORIGIN))
+        .await
+        .unwrap();
+
+    assert!(response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+}
+
+#[tokio::test]
+async fn test_credentials_header_follows_policy() {
+    let without = app(&policy())
+        .oneshot(request(Method::GET, "/open", ALLOWED_ORIGIN))
+        .await
+        .unwrap();
+    assert!(without.headers().get(header::ACCESS_CONTROL_ALLOW_CREDENTIALS).is_none());
+
+    let with_credentials = CorsPolicy {
+        allow_credentials: true,
+        ..policy()
+    };
+    let with = app(&with_credentials)
+        .oneshot(request(Method::GET, "/open", ALLOWED_ORIGIN))
+        .await
+        .unwrap();
+    assert_eq!(
+        with.headers().get(header::ACCESS_CONTROL_ALLOW_CREDENTIALS).unwrap(),
+        "true"
+    );
+}
+
+#[tokio::test]
+async fn test_wildcard_origin_is_never_allowed() {
+    let wildcard = CorsPolicy::new(vec!["*".to_string()], vec![Method::GET], false);
+
+    let response = app(&wildcard)
+        .oneshot(request(Method::GET, "/open", OTHER_ORIGIN))
+        .await
+        .unwrap();
+
+    assert!(response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+}
+
+#[test]
+fn test_default_policy_allows_no_origin() {
+    let policy = CorsPolicy::default();
+
+    assert!(!policy.is_enabled());
+    assert_eq!(policy.allowed_methods, vec![Method::GET, Method::POST]);
+    assert!(!policy.allow_credentials);
+}
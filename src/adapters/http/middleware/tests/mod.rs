@@ -1,3 +1,8 @@
 // Middleware tests
 mod bearer_auth_tests;
 mod service_auth_tests;
+mod client_cert_tests;
+mod panic_guard_tests;
+mod rate_limit_tests;
+mod cors_tests;
+mod body_limit_tests;
@@ -0,0 +1,99 @@
+//! Tests for catch_panic middleware
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    middleware,
+    routing::get,
+    Router,
+};
+use tower::ServiceExt;
+
+use crate::adapters::http::error::ErrorResponse;
+use crate::adapters::http::middleware::catch_panic;
+use crate::adapters::http::middleware::panic_guard::REQUEST_ID_HEADER;
+
+const SECRET: &str = "super-secret-password-123";
+
+async fn panicking_handler() -> String {
+    let parsed: u32 = SECRET.parse().expect(SECRET);
+    parsed.to_string()
+}
+
+async fn ok_handler() -> &'static str {
+    "fine"
+}
+
+fn test_router() -> Router {
+    Router::new()
+        .route("/boom", get(panicking_handler))
+        .route("/ok", get(ok_handler))
+        .layer(middleware::from_fn(catch_panic))
+}
+
+async fn body_string(response: axum::response::Response) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_catch_panic_returns_clean_500() {
+    let app = test_router();
+
+    let response = app
+        .oneshot(Request::builder().uri("/boom").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(response.headers().get(REQUEST_ID_HEADER).is_some());
+
+    let body = body_string(response).await;
+    assert!(!body.contains(SECRET), "panic payload leaked: {}", body);
+    assert!(!body.contains("panicked"));
+
+    let parsed: ErrorResponse = serde_json::from_str(&body).unwrap();
+    assert_eq!(parsed.status, 500);
+    assert_eq!(parsed.code, "INTERNAL_SERVER_ERROR");
+    assert!(parsed.details.is_none());
+    assert!(parsed.request_id.is_some());
+}
+
+#[tokio::test]
+async fn test_catch_panic_echoes_incoming_request_id() {
+    let app = test_router();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/boom")
+                .header(REQUEST_ID_HEADER, "req-42")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.headers().get(REQUEST_ID_HEADER).unwrap(), "req-42");
+    let parsed: ErrorResponse = serde_json::from_str(&body_string(response).await).unwrap();
+    assert_eq!(parsed.request_id.as_deref(), Some("req-42"));
+}
+
+#[tokio::test]
+async fn test_catch_panic_passes_through_normal_responses() {
+    let app = test_router();
+
+    let response = app
+        .oneshot(Request::builder().uri("/ok").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(body_string(response).await, "fine");
+}
@@ -0,0 +1,179 @@
+//! Tests for rate_limit middleware
+
+use std::sync::{Arc, RwLock};
+
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    middleware,
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use tower::ServiceExt;
+
+use crate::adapters::http::error::ErrorResponse;
+use crate::adapters::http::middleware::rate_limit::FORWARDED_FOR_HEADER;
+use crate::adapters::http::middleware::{rate_limit, RateLimiter};
+use crate::core::usecases::ports::Clock;
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+const MAX_REQUESTS: u32 = 3;
+const WINDOW_SECS: u64 = 60;
+
+/// Clock that only moves when the test advances it
+struct ManualClock {
+    now: RwLock<DateTime<Utc>>,
+}
+
+impl ManualClock {
+    fn new() -> Self {
+        Self {
+            now: RwLock::new(Utc.timestamp_opt(1_700_000_000, 0).unwrap()),
+        }
+    }
+
+    fn advance(&self, duration: Duration) {
+        *self.now.write().unwrap() += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.read().unwrap()
+    }
+}
+
+fn limiter_with_clock(clock: Arc<ManualClock>) -> Arc<RateLimiter> {
+    Arc::new(RateLimiter::with_clock(MAX_REQUESTS, WINDOW_SECS, clock))
+}
+
+fn test_router(limiter: Arc<RateLimiter>) -> Router {
+    Router::new()
+        .route("/ping", get(|| async { "pong" }))
+        .layer(middleware::from_fn_with_state(limiter, rate_limit))
+}
+
+async fn send(app: &Router, client_ip: &str) -> axum::response::Response {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .uri("/ping")
+                .header(FORWARDED_FOR_HEADER, client_ip)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_rate_limit_rejects_request_over_limit() {
+    let clock = Arc::new(ManualClock::new());
+    let app = test_router(limiter_with_clock(clock.clone()));
+
+    for _ in 0..MAX_REQUESTS {
+        assert_eq!(send(&app, "203.0.113.7").await.status(), StatusCode::OK);
+    }
+
+    let response = send(&app, "203.0.113.7").await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(
+        response.headers().get(header::RETRY_AFTER).unwrap(),
+        &WINDOW_SECS.to_string()
+    );
+
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: ErrorResponse = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body.status, 429);
+    assert_eq!(body.code, "TOO_MANY_REQUESTS");
+}
+
+#[tokio::test]
+async fn test_rate_limit_window_resets_after_expiry() {
+    let clock = Arc::new(ManualClock::new());
+    let app = test_router(limiter_with_clock(clock.clone()));
+
+    for _ in 0..MAX_REQUESTS {
+        send(&app, "203.0.113.7").await;
+    }
+    assert_eq!(send(&app, "203.0.113.7").await.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    clock.advance(Duration::seconds(WINDOW_SECS as i64 + 1));
+
+    assert_eq!(send(&app, "203.0.113.7").await.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_rate_limit_window_slides() {
+    let clock = Arc::new(ManualClock::new());
+    let app = test_router(limiter_with_clock(clock.clone()));
+
+    // One request early in the window, the rest near its end
+    send(&app, "203.0.113.7").await;
+    clock.advance(Duration::seconds(50));
+    send(&app, "203.0.113.7").await;
+    send(&app, "203.0.113.7").await;
+
+    // The first request has aged out; only one slot is free again
+    clock.advance(Duration::seconds(11));
+    assert_eq!(send(&app, "203.0.113.7").await.status(), StatusCode::OK);
+
+    let response = send(&app, "203.0.113.7").await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers().get(header::RETRY_AFTER).unwrap(), "49");
+}
+
+#[tokio::test]
+async fn test_rate_limit_is_per_client() {
+    let clock = Arc::new(ManualClock::new());
+    let app = test_router(limiter_with_clock(clock));
+
+    for _ in 0..MAX_REQUESTS {
+        send(&app, "203.0.113.7").await;
+    }
+    assert_eq!(send(&app, "203.0.113.7").await.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    // Another client has its own budget
+    assert_eq!(send(&app, "203.0.113.8").await.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_rate_limit_ignores_spoofed_forwarded_entries() {
+    let clock = Arc::new(ManualClock::new());
+    let app = test_router(limiter_with_clock(clock));
+
+    for _ in 0..MAX_REQUESTS {
+        send(&app, "203.0.113.7").await;
+    }
+
+    // With one trusted proxy only the entry it appended identifies the
+    // client; prepending a fake address does not buy a fresh budget
+    assert_eq!(
+        send(&app, "198.51.100.1, 203.0.113.7").await.status(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+}
+
+#[test]
+fn test_rate_limiter_prunes_stale_buckets() {
+    let clock = Arc::new(ManualClock::new());
+    let limiter = limiter_with_clock(clock.clone());
+
+    limiter.check("203.0.113.7").unwrap();
+    limiter.check("198.51.100.1").unwrap();
+    assert_eq!(limiter.tracked_clients(), 2);
+
+    clock.advance(Duration::seconds(WINDOW_SECS as i64 + 1));
+    limiter.check("192.0.2.10").unwrap();
+
+    // Pruning runs once per window on the request path
+    assert_eq!(limiter.tracked_clients(), 1);
+}
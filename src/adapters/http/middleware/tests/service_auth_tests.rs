@@ -313,6 +313,7 @@ async fn test_service_auth_unicode_in_key() {
 
 use crate::adapters::http::middleware::service_jwt_auth;
 use crate::core::usecases::ports::TokenService;
+use futures::future::BoxFuture;
 
 // Mock TokenService for testing JWT validation
 #[derive(Clone)]
@@ -343,33 +344,34 @@ impl MockTokenService {
 }
 
 impl TokenService for MockTokenService {
-    fn issue_access_token(&self, _subject: &str, _claims: &str) -> crate::core::token::Token {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, crate::core::token::Token> {
         unimplemented!()
     }
     
-    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> crate::core::token::Token {
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, crate::core::token::Token> {
         unimplemented!()
     }
     
-    fn issue_service_token(&self, _subject: &str, _claims: &str) -> crate::core::token::Token {
+    fn issue_service_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, crate::core::token::Token> {
         unimplemented!()
     }
     
-    fn validate_access_token(&self, _token: &crate::core::token::Token) -> Result<String, ()> {
+    fn validate_access_token(&self, _token: &crate::core::token::Token) -> BoxFuture<'_, Result<String, ()>> {
         unimplemented!()
     }
     
-    fn validate_refresh_token(&self, _token: &crate::core::token::Token) -> Result<String, ()> {
+    fn validate_refresh_token(&self, _token: &crate::core::token::Token) -> BoxFuture<'_, Result<String, ()>> {
         unimplemented!()
     }
     
-    fn validate_service_token(&self, token: &crate::core::token::Token) -> Result<String, ()> {
+    fn validate_service_token(&self, token: &crate::core::token::Token) -> BoxFuture<'_, Result<String, ()>> {
         let token_str = token.value();
-        if let Some(claims) = self.valid_tokens.get(token_str) {
+        let claims = if let Some(claims) = self.valid_tokens.get(token_str) {
             Ok(claims.clone())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 }
 
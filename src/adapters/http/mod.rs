@@ -23,23 +23,29 @@ HTTP errors are mapped to domain-level errors and back to response codes.
 
 - `/internal/ *` - Service-to-service endpoints (require service auth)
 - `/public/ *` - User-facing endpoints (require bearer auth or rate limiting)
-- `/health/ *` - Liveness and readiness probes (no auth required)
+- `/health/ *` - Liveness, readiness and component health (no auth required)
 
 # Architecture Layers
 
 - `dto`: HTTP Data Transfer Objects (request/response contracts)
 - `handlers`: HTTP request handlers (deserialization, validation, response)
+- `health_check`: Component health probes and the aggregated health report
 - `middleware`: Cross-cutting concerns (auth, logging, rate limiting)
 - `error`: HTTP error types and response projection
+- `request_context`: Client address and user agent resolution
 - `state`: Shared application state
+- `telemetry`: Tracing spans around use case execution
 - `router`: Route configuration and setup
 */
 
 pub mod dto;
 pub mod handlers;
+pub mod health_check;
 pub mod middleware;
 pub mod error;
+pub mod request_context;
 pub mod state;
+pub mod telemetry;
 pub mod router;
 
 pub use dto::{
@@ -51,6 +57,7 @@ pub use error::{
     HttpError, ErrorResponse,
     ValidationError, UnauthorizedError, ConflictError, NotFoundError, InternalError,
 };
+pub use health_check::{HealthChecker, HealthProbe, HealthReport, HealthStatus};
 pub use state::AppState;
 pub use router::create_router;
 
@@ -0,0 +1,126 @@
+// Per-request client metadata extracted from the transport
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::http::{header::USER_AGENT, HeaderMap};
+
+/// Header set by reverse proxies carrying the originating client address
+pub const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// Recorded when no client address can be determined
+pub const UNKNOWN_IP: &str = "0.0.0.0";
+
+/// Default number of reverse proxies in front of the service
+pub const DEFAULT_TRUSTED_PROXY_HOPS: usize = 1;
+
+/// Resolves the client IP from `X-Forwarded-For` and the socket address
+///
+/// Each trusted proxy appends the address it received the request from, so
+/// with `N` trusted hops the client is the `N`-th entry from the right of the
+/// chain. Anything further left was supplied by the client and is ignored,
+/// which keeps a spoofed leading entry from changing the resolved address.
+/// With zero trusted hops the header is ignored entirely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIpResolver {
+    trusted_proxy_hops: usize,
+}
+
+impl ClientIpResolver {
+    /// Create a resolver trusting `trusted_proxy_hops` proxies
+    pub fn new(trusted_proxy_hops: usize) -> Self {
+        Self { trusted_proxy_hops }
+    }
+
+    /// Number of trusted proxy hops
+    pub fn trusted_proxy_hops(&self) -> usize {
+        self.trusted_proxy_hops
+    }
+
+    /// Resolve the client address, falling back to the socket peer
+    ///
+    /// Returns `None` only when neither source yields a valid address.
+    pub fn resolve(&self, headers: &HeaderMap, peer: Option<SocketAddr>) -> Option<IpAddr> {
+        let peer_ip = peer.map(|addr| addr.ip().to_canonical());
+        if self.trusted_proxy_hops == 0 {
+            return peer_ip;
+        }
+
+        // Repeated headers form one logical chain, in order
+        let chain: Vec<&str> = headers
+            .get_all(FORWARDED_FOR_HEADER)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .collect();
+
+        if chain.is_empty() {
+            return peer_ip;
+        }
+
+        let index = chain.len().saturating_sub(self.trusted_proxy_hops);
+        parse_ip(chain[index]).or(peer_ip)
+    }
+}
+
+impl Default for ClientIpResolver {
+    fn default() -> Self {
+        Self::new(DEFAULT_TRUSTED_PROXY_HOPS)
+    }
+}
+
+/// Parse a forwarded address into its canonical form
+///
+/// Accepts bare addresses, `ip:port`, `[ipv6]` and `[ipv6]:port`. IPv6 is
+/// normalized to its compressed lowercase form and IPv4-mapped IPv6
+/// addresses collapse to plain IPv4.
+pub fn parse_ip(raw: &str) -> Option<IpAddr> {
+    let raw = raw.trim().trim_matches('"');
+
+    let ip = raw
+        .parse::<IpAddr>()
+        .ok()
+        .or_else(|| raw.parse::<SocketAddr>().ok().map(|addr| addr.ip()))
+        .or_else(|| {
+            raw.strip_prefix('[')
+                .and_then(|rest| rest.strip_suffix(']'))
+                .and_then(|inner| inner.parse::<IpAddr>().ok())
+        })?;
+
+    Some(ip.to_canonical())
+}
+
+/// Client metadata recorded alongside a session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    /// Resolved client address, or [`UNKNOWN_IP`]
+    pub ip_address: String,
+    /// Raw `User-Agent` header, or `"unknown"`
+    pub user_agent: String,
+}
+
+impl RequestContext {
+    /// Build the context for a request
+    pub fn from_parts(
+        headers: &HeaderMap,
+        peer: Option<SocketAddr>,
+        resolver: &ClientIpResolver,
+    ) -> Self {
+        let ip_address = resolver
+            .resolve(headers, peer)
+            .map(|ip| ip.to_string())
+            .unwrap_or_else(|| UNKNOWN_IP.to_string());
+
+        let user_agent = headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("unknown")
+            .to_string();
+
+        Self {
+            ip_address,
+            user_agent,
+        }
+    }
+}
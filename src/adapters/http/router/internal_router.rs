@@ -4,7 +4,7 @@ use axum::{
     extract::{Request, State},
     middleware::{self as axum_middleware, Next},
     response::Response,
-    routing::{get, post},
+    routing::{get, post, MethodRouter},
     Router,
 };
 
@@ -30,11 +30,35 @@ async fn inject_token_service(
     next.run(request).await
 }
 
+/// Internal endpoints that require service JWT authentication
+fn protected_internal_endpoints() -> Vec<(&'static str, MethodRouter<AppState>)> {
+    vec![
+        ("/credentials", post(handlers::create_credential)),
+        ("/credentials/batch", post(handlers::create_credentials_batch)),
+        ("/credentials/revoke", post(handlers::revoke_credential)),
+        ("/credentials/unlock", post(handlers::unlock_account)),
+        ("/token/issue", post(handlers::issue_session_tokens)),
+        ("/token/revoke", post(handlers::revoke_access_token)),
+        ("/token/revoke-all", post(handlers::revoke_all_tokens)),
+        ("/sessions/revoke", post(handlers::revoke_session)),
+        ("/sessions/history", get(handlers::list_session_history)),
+        ("/introspect", post(handlers::introspect)),
+    ]
+}
+
+/// Internal endpoints that require no authentication
+fn public_internal_endpoints() -> Vec<(&'static str, MethodRouter<AppState>)> {
+    vec![
+        ("/service/token", post(handlers::issue_service_token)),
+        ("/health", get(|| async { "OK" })),
+    ]
+}
+
 /// Protected internal routes (require service JWT authentication)
 pub fn protected_internal_routes(state: AppState) -> Router<AppState> {
-    Router::new()
-        .route("/credentials", post(handlers::create_credential))
-        .route("/token/issue", post(handlers::issue_session_tokens))
+    protected_internal_endpoints()
+        .into_iter()
+        .fold(Router::new(), |router, (path, endpoint)| router.route(path, endpoint))
         // Service JWT auth - validates Bearer token with typ:service claim
         .layer(axum_middleware::from_fn(middleware::service_jwt_auth))
         .layer(axum_middleware::from_fn_with_state(state.clone(), inject_token_service))
@@ -43,7 +67,16 @@ pub fn protected_internal_routes(state: AppState) -> Router<AppState> {
 
 /// Public internal routes (no authentication required)
 pub fn public_internal_routes() -> Router<AppState> {
-    Router::new()
-        .route("/service/token", post(handlers::issue_service_token))
-        .route("/health", get(|| async { "OK" }))
+    public_internal_endpoints()
+        .into_iter()
+        .fold(Router::new(), |router, (path, endpoint)| router.route(path, endpoint))
+}
+
+/// Paths registered by the internal routers, relative to their mount point
+pub fn internal_route_paths() -> Vec<&'static str> {
+    protected_internal_endpoints()
+        .into_iter()
+        .chain(public_internal_endpoints())
+        .map(|(path, _)| path)
+        .collect()
 }
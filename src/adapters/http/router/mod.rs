@@ -4,6 +4,6 @@ mod internal_router;
 mod public_router;
 mod router;
 
-pub use internal_router::{protected_internal_routes, public_internal_routes};
-pub use public_router::public_routes;
-pub use router::{create_router, CleanJson};
+pub use internal_router::{internal_route_paths, protected_internal_routes, public_internal_routes};
+pub use public_router::{public_route_paths, public_routes};
+pub use router::{create_router, route_paths, CleanJson};
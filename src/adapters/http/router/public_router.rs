@@ -1,24 +1,52 @@
 // Public user-facing routes
 
-use axum::{routing::post, Router};
+use axum::{routing::{get, post, MethodRouter}, Router};
 use crate::adapters::http::{handlers, middleware, state::AppState};
 use crate::adapters::http::handlers::public::exchange_google_code;
 
 
+/// Public endpoints - authentication without Bearer token (credentials in body)
+fn unauthenticated_endpoints() -> Vec<(&'static str, MethodRouter<AppState>)> {
+    vec![
+        ("/auth/authenticate", post(handlers::authenticate)),
+        ("/auth/google/callback", post(exchange_google_code)),
+        ("/.well-known/auth-metadata", get(handlers::auth_metadata)),
+    ]
+}
+
+/// Protected endpoints - require Bearer token in Authorization header
+fn protected_endpoints() -> Vec<(&'static str, MethodRouter<AppState>)> {
+    vec![
+        ("/auth/refresh", post(handlers::refresh_token)),
+        ("/auth/validate", post(handlers::validate_token)),
+        ("/auth/logout", post(handlers::logout)),
+        ("/auth/logout-others", post(handlers::logout_others)),
+        ("/auth/logout-all", post(handlers::logout_all)),
+        ("/auth/sessions", get(handlers::list_sessions)),
+        ("/auth/password", post(handlers::change_password)),
+    ]
+}
+
 pub fn public_routes() -> Router<AppState> {
-    // Public endpoint - authentication without Bearer token (credentials in body)
-    let authenticate = Router::new()
-        .route("/auth/authenticate", post(handlers::authenticate))
-        .route("/auth/google/callback", post(exchange_google_code));
-
-    // Protected endpoints - require Bearer token in Authorization header
-    let protected = Router::new()
-        .route("/auth/refresh", post(handlers::refresh_token))
-        .route("/auth/validate", post(handlers::validate_token))
-        .route("/auth/logout", post(handlers::logout))
+    let authenticate = unauthenticated_endpoints()
+        .into_iter()
+        .fold(Router::new(), |router, (path, endpoint)| router.route(path, endpoint));
+
+    let protected = protected_endpoints()
+        .into_iter()
+        .fold(Router::new(), |router, (path, endpoint)| router.route(path, endpoint))
         .layer(axum::middleware::from_fn(middleware::bearer_auth));
 
     Router::new()
         .merge(authenticate)
         .merge(protected)
 }
+
+/// Paths registered by [`public_routes`], relative to its mount point
+pub fn public_route_paths() -> Vec<&'static str> {
+    unauthenticated_endpoints()
+        .into_iter()
+        .chain(protected_endpoints())
+        .map(|(path, _)| path)
+        .collect()
+}
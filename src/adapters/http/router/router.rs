@@ -2,6 +2,7 @@
 
 use axum::{
     extract::rejection::JsonRejection,
+    middleware,
     routing::get,
     Json, Router,
 };
@@ -9,42 +10,91 @@ use tower_http::trace::TraceLayer;
 
 use crate::adapters::http::{
     error::{HttpError, ValidationError},
+    handlers,
+    middleware::{
+        body_limit, catch_panic, client_cert_auth, cors_layer, forwarded_client_cert, no_store,
+        preflight_no_content, rate_limit,
+    },
     state::AppState,
 };
 
-use super::{protected_internal_routes, public_internal_routes, public_routes};
+use super::{
+    internal_route_paths, protected_internal_routes, public_internal_routes, public_route_paths,
+    public_routes,
+};
 
 /// Build the complete HTTP router with all routes and middleware
 pub fn create_router(state: AppState) -> Router {
     Router::new()
-        // Internal routes - public (no auth required)
-        .nest("/internal", public_internal_routes())
-        // Internal routes - protected (require X-Service-Key header)
-        .nest("/internal", protected_internal_routes(state.clone()))
-        // Public routes
-        .nest("/public", public_routes())
+        // Internal routes - public (no auth required) and protected (require
+        // X-Service-Key header); optionally behind mTLS client certificates
+        .nest("/internal", internal_routes(&state))
+        // Public routes - rate limited per client, body size capped, open to
+        // allowlisted browser origins; preflights are answered before rate
+        // limiting and auth
+        .nest(
+            "/public",
+            public_routes()
+                .layer(middleware::from_fn_with_state(state.max_body_bytes, body_limit))
+                .layer(middleware::from_fn_with_state(state.rate_limiter.clone(), rate_limit))
+                .layer(cors_layer(&state.cors))
+                .layer(middleware::from_fn(preflight_no_content)),
+        )
         // Health check routes
         .nest("/health", health_routes())
+        // Auth responses carry tokens and credentials state; never cache them
+        .layer(middleware::from_fn(no_store))
+        // Panics inside handlers become sanitized 500 responses
+        .layer(middleware::from_fn(catch_panic))
         .layer(TraceLayer::new_for_http())
         .with_state(state)
 }
 
+/// Internal routes, behind client certificate verification when required
+fn internal_routes(state: &AppState) -> Router<AppState> {
+    let routes = Router::new()
+        .merge(public_internal_routes())
+        .merge(protected_internal_routes(state.clone()));
+
+    if state.require_client_cert {
+        // The proxy layer runs first and supplies the certificate to check
+        routes
+            .layer(middleware::from_fn_with_state(
+                state.service_registry.clone(),
+                client_cert_auth,
+            ))
+            .layer(middleware::from_fn_with_state(
+                state.client_cert_proxy.clone(),
+                forwarded_client_cert,
+            ))
+    } else {
+        routes
+    }
+}
+
 /// Health check routes (no authentication required)
 fn health_routes() -> Router<AppState> {
     Router::new()
-        .route("/", get(health_check))
-        .route("/ready", get(readiness_check))
+        .route("/", get(handlers::liveness))
+        .route("/live", get(handlers::liveness))
+        .route("/ready", get(handlers::readiness))
+        .route("/report", get(handlers::health_report))
 }
 
-/// Liveness probe - always returns 200 if service is running
-async fn health_check() -> &'static str {
-    "OK"
-}
+/// Full paths of every route registered by [`create_router`]
+///
+/// Derived from the same endpoint tables the routers are built from, so
+/// cross-cutting contract tests cover new routes automatically.
+pub fn route_paths() -> Vec<String> {
+    let internal = internal_route_paths()
+        .into_iter()
+        .map(|path| format!("/internal{}", path));
+    let public = public_route_paths()
+        .into_iter()
+        .map(|path| format!("/public{}", path));
+    let health = ["/health", "/health/live", "/health/ready", "/health/report"].into_iter().map(String::from);
 
-/// Readiness probe - checks if service is ready to handle traffic
-async fn readiness_check() -> &'static str {
-    // TODO: Check database connection, cache availability, etc.
-    "READY"
+    internal.chain(public).chain(health).collect()
 }
 
 /// Custom JSON extractor that provides clean error messages
@@ -1,17 +1,38 @@
 // HTTP server shared state
 
 use std::sync::Arc;
+use crate::adapters::clock::SystemClock;
+use crate::adapters::random::SystemRandomSource;
+use crate::adapters::http::dto::public::FieldLimits;
+use crate::adapters::http::health_check::HealthChecker;
+use crate::adapters::http::middleware::{ClientCertificateProxy, CorsPolicy, RateLimiter, DEFAULT_MAX_BODY_BYTES};
+use crate::adapters::http::request_context::ClientIpResolver;
+use crate::adapters::persistence::Database;
+use crate::core::credentials::CredentialPolicy;
+use crate::core::usecases::policies::{
+    AuthPolicyConfig, HasherParams, LockoutPolicy, SessionBindingPolicy, TokenLifetimes,
+};
 use crate::core::usecases::ports::UserServiceClient;
+use crate::core::usecases::{IntrospectToken, ValidateAccessToken};
 use crate::core::usecases::ports::{
+    AuditSink,
+    BreachChecker,
+    Clock,
     CredentialRepository, 
     ExchangeAuthorizationCode,
     ExternalIdentityRepository,
     ExternalTokenValidator,
+    IdentifierNormalizer,
     IdentityRepository, 
     PasswordHasher, 
+    RandomSource,
+    SessionMetadataEnricher,
     SessionRepository, 
     ServiceRegistry, 
+    TokenDenyList,
+    TokenWatermarkStore,
     TokenService,
+    UnitOfWork,
 };
 
 /// Application state shared across all HTTP handlers
@@ -21,6 +42,9 @@ use crate::core::usecases::ports::{
 /// - Database connections (injected into services)
 /// - Repositories (injected into services)
 /// - Infrastructure primitives
+///
+/// The only exception is an optional database handle used by the
+/// readiness probe; handlers must not query through it.
 #[derive(Clone)]
 pub struct AppState {
     /// Repository for identity operations
@@ -35,14 +59,10 @@ pub struct AppState {
     pub token_service: Arc<dyn TokenService + Send + Sync>,
     /// Service registry for validating API keys
     pub service_registry: Arc<dyn ServiceRegistry + Send + Sync>,
-    /// Access token TTL in seconds
-    pub access_token_ttl_seconds: u64,
-    /// Refresh token TTL in days
-    pub refresh_token_ttl_days: u64,
-    /// Whether to rotate refresh tokens
-    pub rotate_refresh_tokens: bool,
-    /// Service token TTL in seconds
-    pub service_token_ttl_seconds: u64,
+    /// Lockout policy, token lifetimes and hashing parameters for the use cases
+    pub config: AuthPolicyConfig,
+    /// Rules a new password must satisfy when a user changes it
+    pub credential_policy: CredentialPolicy,
     /// Google OAuth token validator
     pub google_token_validator: Arc<dyn ExternalTokenValidator + Send + Sync>,
     /// Google OAuth code exchanger
@@ -51,10 +71,57 @@ pub struct AppState {
     pub external_identity_repo: Arc<dyn ExternalIdentityRepository + Send + Sync>,
     /// User service client for registering users from Google OAuth
     pub user_service_client: Arc<dyn UserServiceClient + Send + Sync>,
+    /// Database handle for readiness probing (None in tests without a pool)
+    pub database: Option<Database>,
+    /// Component probes behind the health report (None reports no components)
+    pub health_checker: Option<Arc<HealthChecker>>,
+    /// Per-client request limiter shared by all public routes
+    pub rate_limiter: Arc<RateLimiter>,
+    /// Time source for lockout checks and token expiry
+    pub clock: Arc<dyn Clock + Send + Sync>,
+    /// Random source for session ids and token identifiers
+    pub random: Arc<dyn RandomSource + Send + Sync>,
+    /// Resolves client addresses behind trusted proxies
+    pub client_ip_resolver: ClientIpResolver,
+    /// Transaction scoping for session writes (None writes through `session_repo`)
+    pub unit_of_work: Option<Arc<dyn UnitOfWork + Send + Sync>>,
+    /// Binding of refresh tokens to the client they were issued to
+    pub refresh_binding: SessionBindingPolicy,
+    /// Security audit trail (None records nothing)
+    pub audit_sink: Option<Arc<dyn AuditSink + Send + Sync>>,
+    /// Lookup of new passwords in known data breaches (None skips the check)
+    pub breach_checker: Option<Arc<dyn BreachChecker + Send + Sync>>,
+    /// Structured client details stored with new sessions (None stores raw values only)
+    pub session_metadata_enricher: Option<Arc<dyn SessionMetadataEnricher + Send + Sync>>,
+    /// Individually revoked access tokens (None disables revocation)
+    pub token_deny_list: Option<Arc<dyn TokenDenyList + Send + Sync>>,
+    /// Earliest issue time accepted for access tokens (None disables revoke-all)
+    pub token_watermark: Option<Arc<dyn TokenWatermarkStore + Send + Sync>>,
+    /// Canonical form of login identifiers, applied on write and lookup
+    /// alike (None takes identifiers as given)
+    pub identifier_normalizer: Option<Arc<dyn IdentifierNormalizer + Send + Sync>>,
+    /// Browser origins allowed to call the public routes
+    pub cors: CorsPolicy,
+    /// Largest request body accepted by the public routes, in bytes
+    pub max_body_bytes: usize,
+    /// Longest values accepted for public request fields
+    pub field_limits: FieldLimits,
+    /// Require a registered mTLS client certificate on internal routes
+    pub require_client_cert: bool,
+    /// Proxies trusted to forward the client certificate they verified
+    pub client_cert_proxy: ClientCertificateProxy,
+    /// Answer lockouts with the same 401 as unknown identifiers and wrong passwords
+    pub generic_auth_failures: bool,
+    /// Reject access tokens whose `sid` session is no longer active
+    /// (false validates access tokens statelessly)
+    pub access_token_session_binding: bool,
 }
 
 impl AppState {
     /// Create a new application state with all required dependencies
+    ///
+    /// Token lifetimes are taken from the arguments; the lockout policy and
+    /// hashing parameters start at their defaults until `with_config`.
     pub fn new(
         identity_repo: Arc<dyn IdentityRepository + Send + Sync>,
         credential_repo: Arc<dyn CredentialRepository + Send + Sync>,
@@ -82,10 +149,218 @@ impl AppState {
             google_code_exchanger,
             external_identity_repo,
             user_service_client,
-            access_token_ttl_seconds,
-            refresh_token_ttl_days,
-            rotate_refresh_tokens,
-            service_token_ttl_seconds,
+            config: AuthPolicyConfig::new(
+                LockoutPolicy::new(5, 30 * 60, true).with_exponential_backoff(24 * 60 * 60),
+                TokenLifetimes::new(
+                    access_token_ttl_seconds,
+                    refresh_token_ttl_days,
+                    service_token_ttl_seconds,
+                    rotate_refresh_tokens,
+                ),
+                HasherParams::default(),
+            ),
+            credential_policy: CredentialPolicy::default(),
+            database: None,
+            health_checker: None,
+            rate_limiter: Arc::new(RateLimiter::default()),
+            clock: Arc::new(SystemClock),
+            random: Arc::new(SystemRandomSource),
+            client_ip_resolver: ClientIpResolver::default(),
+            unit_of_work: None,
+            refresh_binding: SessionBindingPolicy::disabled(),
+            audit_sink: None,
+            breach_checker: None,
+            session_metadata_enricher: None,
+            token_deny_list: None,
+            token_watermark: None,
+            identifier_normalizer: None,
+            cors: CorsPolicy::default(),
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            field_limits: FieldLimits::default(),
+            require_client_cert: false,
+            client_cert_proxy: ClientCertificateProxy::default(),
+            generic_auth_failures: false,
+            access_token_session_binding: true,
+        }
+    }
+
+    /// Replace the use case configuration
+    pub fn with_config(mut self, config: AuthPolicyConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Replace the rules applied to new passwords
+    pub fn with_credential_policy(mut self, credential_policy: CredentialPolicy) -> Self {
+        self.credential_policy = credential_policy;
+        self
+    }
+
+    /// Attach the database pool so readiness checks can probe it
+    pub fn with_database(mut self, database: Database) -> Self {
+        self.database = Some(database);
+        self
+    }
+
+    /// Serve the component health report from a shared checker
+    pub fn with_health_checker(mut self, health_checker: Arc<HealthChecker>) -> Self {
+        self.health_checker = Some(health_checker);
+        self
+    }
+
+    /// Persist sessions inside a unit of work
+    pub fn with_unit_of_work(mut self, unit_of_work: Arc<dyn UnitOfWork + Send + Sync>) -> Self {
+        self.unit_of_work = Some(unit_of_work);
+        self
+    }
+
+    /// Replace the default rate limiter
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Replace the default client IP resolver
+    pub fn with_client_ip_resolver(mut self, client_ip_resolver: ClientIpResolver) -> Self {
+        self.client_ip_resolver = client_ip_resolver;
+        self
+    }
+
+    /// Bind refresh tokens to the client IP and user agent they were issued to
+    pub fn with_refresh_binding(mut self, refresh_binding: SessionBindingPolicy) -> Self {
+        self.refresh_binding = refresh_binding;
+        self
+    }
+
+    /// Record security events to an audit sink
+    pub fn with_audit_sink(mut self, audit_sink: Arc<dyn AuditSink + Send + Sync>) -> Self {
+        self.audit_sink = Some(audit_sink);
+        self
+    }
+
+    /// Reject new passwords found in known data breaches
+    pub fn with_breach_checker(mut self, breach_checker: Arc<dyn BreachChecker + Send + Sync>) -> Self {
+        self.breach_checker = Some(breach_checker);
+        self
+    }
+
+    /// Enrich new sessions with device and coarse location details
+    pub fn with_session_metadata_enricher(
+        mut self,
+        session_metadata_enricher: Arc<dyn SessionMetadataEnricher + Send + Sync>,
+    ) -> Self {
+        self.session_metadata_enricher = Some(session_metadata_enricher);
+        self
+    }
+
+    /// Reject access tokens revoked through the deny-list
+    pub fn with_token_deny_list(mut self, token_deny_list: Arc<dyn TokenDenyList + Send + Sync>) -> Self {
+        self.token_deny_list = Some(token_deny_list);
+        self
+    }
+
+    /// Reject access tokens issued at or before the revoke-all watermark
+    pub fn with_token_watermark(mut self, token_watermark: Arc<dyn TokenWatermarkStore + Send + Sync>) -> Self {
+        self.token_watermark = Some(token_watermark);
+        self
+    }
+
+    /// Normalize login identifiers before they are stored or looked up
+    pub fn with_identifier_normalizer(
+        mut self,
+        identifier_normalizer: Arc<dyn IdentifierNormalizer + Send + Sync>,
+    ) -> Self {
+        self.identifier_normalizer = Some(identifier_normalizer);
+        self
+    }
+
+    /// Allow browser origins to call the public routes
+    pub fn with_cors(mut self, cors: CorsPolicy) -> Self {
+        self.cors = cors;
+        self
+    }
+
+    /// Replace the default request body size limit
+    pub fn with_max_body_bytes(mut self, max_body_bytes: usize) -> Self {
+        self.max_body_bytes = max_body_bytes;
+        self
+    }
+
+    /// Replace the default public request field length limits
+    pub fn with_field_limits(mut self, field_limits: FieldLimits) -> Self {
+        self.field_limits = field_limits;
+        self
+    }
+
+    /// Require internal callers to present a registered client certificate
+    pub fn with_require_client_cert(mut self, require_client_cert: bool) -> Self {
+        self.require_client_cert = require_client_cert;
+        self
+    }
+
+    /// Trust client certificates forwarded by a TLS-terminating proxy
+    pub fn with_client_cert_proxy(mut self, client_cert_proxy: ClientCertificateProxy) -> Self {
+        self.client_cert_proxy = client_cert_proxy;
+        self
+    }
+
+    /// Hide lockouts behind the generic invalid-credentials response
+    pub fn with_generic_auth_failures(mut self, generic_auth_failures: bool) -> Self {
+        self.generic_auth_failures = generic_auth_failures;
+        self
+    }
+
+    /// Check the session behind each access token, or trust the token alone
+    pub fn with_access_token_session_binding(mut self, access_token_session_binding: bool) -> Self {
+        self.access_token_session_binding = access_token_session_binding;
+        self
+    }
+
+    /// Replace the system clock (e.g. with a frozen clock in tests)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Replace the OS random source (e.g. with a seeded source in tests)
+    pub fn with_random_source(mut self, random: Arc<dyn RandomSource + Send + Sync>) -> Self {
+        self.random = random;
+        self
+    }
+
+    /// Access token validation with this state's session binding, deny-list
+    /// and watermark
+    pub fn access_token_validator(&self) -> ValidateAccessToken<'_> {
+        let validator = ValidateAccessToken::new(&*self.token_service, &*self.session_repo)
+            .with_session_validation(self.access_token_session_binding);
+        let validator = match self.token_deny_list.as_deref() {
+            Some(deny_list) => validator.with_deny_list(deny_list),
+            None => validator,
+        };
+        match self.token_watermark.as_deref() {
+            Some(watermark) => validator.with_watermark(watermark),
+            None => validator,
+        }
+    }
+
+    /// Token introspection with this state's deny-list and watermark
+    pub fn token_introspector(&self) -> IntrospectToken<'_> {
+        let introspector = IntrospectToken::new(&*self.token_service, &*self.session_repo, &*self.clock);
+        let introspector = match self.token_deny_list.as_deref() {
+            Some(deny_list) => introspector.with_deny_list(deny_list),
+            None => introspector,
+        };
+        match self.token_watermark.as_deref() {
+            Some(watermark) => introspector.with_watermark(watermark),
+            None => introspector,
+        }
+    }
+
+    /// The form `identifier` is stored and looked up in
+    pub fn normalize_identifier(&self, identifier: &str) -> String {
+        match self.identifier_normalizer.as_deref() {
+            Some(normalizer) => normalizer.normalize(identifier),
+            None => identifier.to_string(),
         }
     }
 }
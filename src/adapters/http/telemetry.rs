@@ -0,0 +1,104 @@
+// Structured tracing spans around use case execution
+
+/*
+Use cases stay free of span plumbing; handlers wrap each execution in a
+`UsecaseSpan` instead. Spans carry only:
+ - `usecase`: static use case name
+ - `user_id`: truncated SHA-256 of the internal user id, never the login identifier
+ - `outcome`: "success" or "failure"
+ - `failure`: coarse failure category derived from the error variant
+
+Error messages are never recorded, since several of them embed identifiers
+or timestamps tied to a specific account.
+
+Compiled out (spans become `Span::none()`) without the `tracing` feature.
+*/
+
+use sha2::{Digest, Sha256};
+use tracing::Span;
+
+use crate::core::error::{AuthenticationError, CoreError};
+
+/// Number of hex characters kept from the user id digest
+const USER_ID_HASH_LEN: usize = 16;
+
+/// Span wrapping a single use case execution
+pub struct UsecaseSpan {
+    span: Span,
+}
+
+impl UsecaseSpan {
+    /// Open a span for the named use case
+    #[cfg(feature = "tracing")]
+    pub fn new(usecase: &'static str) -> Self {
+        Self {
+            span: tracing::info_span!(
+                "usecase",
+                usecase,
+                user_id = tracing::field::Empty,
+                outcome = tracing::field::Empty,
+                failure = tracing::field::Empty,
+            ),
+        }
+    }
+
+    /// Open a span for the named use case (disabled build)
+    #[cfg(not(feature = "tracing"))]
+    pub fn new(_usecase: &'static str) -> Self {
+        Self { span: Span::none() }
+    }
+
+    /// The underlying span, for `Instrument::instrument`
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+
+    /// Attach the (hashed) user id
+    pub fn record_user(&self, user_id: &str) {
+        self.span.record("user_id", hash_user_id(user_id).as_str());
+    }
+
+    /// Record the outcome of the execution
+    pub fn record_result<T>(&self, result: &Result<T, CoreError>) {
+        match result {
+            Ok(_) => self.record_success(),
+            Err(error) => self.record_failure(failure_category(error)),
+        }
+    }
+
+    /// Mark the execution as successful
+    pub fn record_success(&self) {
+        self.span.record("outcome", "success");
+    }
+
+    /// Mark the execution as failed with a coarse category
+    pub fn record_failure(&self, category: &'static str) {
+        self.span.record("outcome", "failure");
+        self.span.record("failure", category);
+    }
+}
+
+/// Pseudonymize a user id for log correlation
+pub fn hash_user_id(user_id: &str) -> String {
+    let digest = hex::encode(Sha256::digest(user_id.as_bytes()));
+    digest[..USER_ID_HASH_LEN].to_string()
+}
+
+/// Coarse, non-identifying category for a use case error
+pub fn failure_category(error: &CoreError) -> &'static str {
+    match error {
+        CoreError::Authentication(auth) => match auth {
+            AuthenticationError::UserNotFound { .. } | AuthenticationError::InvalidCredentials => {
+                "invalid_credentials"
+            }
+            AuthenticationError::AccountLocked { .. }
+            | AuthenticationError::MaxAttemptsExceeded { .. } => "locked",
+            AuthenticationError::CredentialExpired { .. } => "credential_expired",
+            AuthenticationError::IncompleteFlow { .. } => "incomplete_flow",
+            _ => "authentication",
+        },
+        CoreError::Credential(_) => "credential",
+        CoreError::Token(_) => "token",
+        CoreError::Invariant(_) => "internal",
+    }
+}
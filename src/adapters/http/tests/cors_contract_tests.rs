@@ -0,0 +1,101 @@
+//! Contract test: CORS applies to every `/public` route and to no
+//! `/internal` route.
+
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+};
+use tower::ServiceExt;
+
+use crate::adapters::http::middleware::CorsPolicy;
+use crate::adapters::http::router::{create_router, route_paths};
+use crate::adapters::http::state::AppState;
+
+use super::no_store_contract_tests::test_state;
+
+const ALLOWED_ORIGIN: &str = "https://app.example.com";
+
+fn cors_state() -> AppState {
+    test_state().with_cors(CorsPolicy::new(
+        vec![ALLOWED_ORIGIN.to_string()],
+        vec![Method::GET, Method::POST],
+        true,
+    ))
+}
+
+fn preflight(path: &str) -> Request<Body> {
+    Request::builder()
+        .method(Method::OPTIONS)
+        .uri(path)
+        .header(header::ORIGIN, ALLOWED_ORIGIN)
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .body(Body::empty())
+        .unwrap()
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_public_routes_answer_preflight() {
+    let paths: Vec<String> = route_paths()
+        .into_iter()
+        .filter(|p| p.starts_with("/public/"))
+        .collect();
+    assert!(paths.iter().any(|p| p == "/public/auth/refresh"));
+
+    for path in paths {
+        let response = create_router(cors_state()).oneshot(preflight(&path)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT, "preflight on {}", path);
+        assert_eq!(
+            response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+            ALLOWED_ORIGIN,
+            "missing Access-Control-Allow-Origin on {}",
+            path
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_internal_routes_never_send_cors_headers() {
+    let paths: Vec<String> = route_paths()
+        .into_iter()
+        .filter(|p| p.starts_with("/internal/"))
+        .collect();
+    assert!(!paths.is_empty());
+
+    for path in paths {
+        let response = create_router(cors_state()).oneshot(preflight(&path)).await.unwrap();
+
+        assert_ne!(response.status(), StatusCode::NO_CONTENT, "preflight answered on {}", path);
+        assert!(
+            response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none(),
+            "CORS header leaked on {}",
+            path
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_public_response_carries_allow_origin() {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/public/auth/authenticate")
+        .header(header::ORIGIN, ALLOWED_ORIGIN)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(r#"{"identifier":"alice","password":"password123"}"#))
+        .unwrap();
+
+    let response = create_router(cors_state()).oneshot(request).await.unwrap();
+
+    assert_eq!(
+        response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+        ALLOWED_ORIGIN
+    );
+    assert_eq!(
+        response.headers().get(header::ACCESS_CONTROL_ALLOW_CREDENTIALS).unwrap(),
+        "true"
+    );
+}
@@ -0,0 +1,643 @@
+//! End-to-end session lifecycle over in-memory adapters
+//!
+//! Wires in-memory repositories, the Argon2 hasher and a sequencing token
+//! service into `AppState`, then drives authenticate → issue → refresh →
+//! validate → revoke through the use cases exactly as the handlers do. No
+//! Postgres is involved, so this catches contract drift between ports and use
+//! cases on every run.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+};
+use chrono::{DateTime, Duration, Utc};
+use futures::future::BoxFuture;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::adapters::clock::FixedClock;
+use crate::adapters::crypto::password::Argon2PasswordHasher;
+use crate::adapters::http::error::ErrorResponse;
+use crate::adapters::http::router::create_router;
+use crate::adapters::http::state::AppState;
+use crate::core::credentials::StoredCredential;
+use crate::core::error::{CoreError, TokenError};
+use crate::core::identity::{ExternalIdentity, UserIdentity};
+use crate::core::token::Token;
+use crate::core::usecases::ports::session_repository::Session;
+use crate::core::usecases::ports::{
+    CredentialRepository, ExchangeAuthorizationCode, ExternalIdentityRepository, ExternalTokenValidator,
+    IdentityRepository, PasswordHasher, ServiceRegistry, SessionRepository, SessionSummary, TokenService,
+    UserServiceClient,
+};
+use crate::core::usecases::{
+    AuthenticateUser, AuthenticateUserInput, IssueSession, IssueSessionInput, IssueSessionOutput,
+    RefreshSession, RefreshSessionInput, RevokeSession, RevokeSessionInput, ValidateAccessToken,
+    ValidateAccessTokenInput,
+};
+use crate::core::usecases::policies::LockoutPolicy;
+
+const IDENTIFIER: &str = "alice@example.com";
+const PASSWORD: &str = "correct horse battery staple";
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+struct Harness {
+    state: AppState,
+    sessions: Arc<InMemorySessionRepo>,
+    user_id: String,
+}
+
+async fn harness() -> Harness {
+    let sessions = Arc::new(InMemorySessionRepo::default());
+    let identities = Arc::new(InMemoryIdentityRepo::default());
+    let credentials = Arc::new(InMemoryCredentialRepo::default());
+    let hasher = Arc::new(Argon2PasswordHasher::new(1024, 1, 1, 16).expect("valid parameters"));
+
+    let state = AppState::new(
+        identities.clone(),
+        credentials.clone(),
+        sessions.clone(),
+        hasher,
+        Arc::new(SequencedTokenService::default()),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        900,
+        30,
+        true,
+        3600,
+    );
+
+    // Register the user the way the internal credentials endpoint does
+    let user_id = Uuid::new_v4();
+    let hashed = state.password_hasher.hash(PASSWORD);
+    state
+        .identity_repo
+        .create(&user_id, IDENTIFIER, hashed.as_hash_str(), "", "", 0)
+        .await
+        .unwrap();
+    state.credential_repo.update_password(&user_id.to_string(), hashed).await;
+
+    Harness {
+        state,
+        sessions,
+        user_id: user_id.to_string(),
+    }
+}
+
+impl Harness {
+    async fn login(&self) -> IssueSessionOutput {
+        let state = &self.state;
+        let authenticated = AuthenticateUser::new(
+            state.identity_repo.as_ref(),
+            state.credential_repo.as_ref(),
+            state.password_hasher.as_ref(),
+            state.clock.as_ref(),
+            LockoutPolicy::new(5, 60, true),
+        )
+        .execute(AuthenticateUserInput {
+            identifier: IDENTIFIER.to_string(),
+            password: PASSWORD.to_string(),
+            source_ip: None,
+        })
+        .await
+        .expect("authentication succeeds");
+
+        IssueSession::new(
+            state.session_repo.as_ref(),
+            state.token_service.as_ref(),
+            state.clock.as_ref(),
+            state.random.as_ref(),
+            state.config.tokens.access_ttl_secs,
+            state.config.tokens.refresh_ttl_days,
+        )
+        .execute(IssueSessionInput {
+            user: authenticated.user,
+            ip_address: "203.0.113.7".to_string(),
+            user_agent: "lifecycle-test".to_string(),
+            scopes: Vec::new(),
+            workspace_id: None,
+        })
+        .await
+        .expect("session issued")
+    }
+
+    async fn refresh(&self, refresh_token: &Token) -> Result<(Token, Token), CoreError> {
+        let state = &self.state;
+        let output = RefreshSession::new(
+            state.session_repo.as_ref(),
+            state.token_service.as_ref(),
+            state.clock.as_ref(),
+            state.config.tokens.access_ttl_secs,
+            state.config.tokens.rotate_refresh_tokens,
+        )
+        .execute(RefreshSessionInput {
+            refresh_token: refresh_token.clone(),
+            ip_address: "127.0.0.1".to_string(),
+            user_agent: "lifecycle-test".to_string(),
+        })
+        .await?;
+
+        Ok((output.access_token, output.refresh_token.expect("rotation enabled")))
+    }
+
+    async fn validate(&self, access_token: &Token) -> Result<bool, CoreError> {
+        let state = &self.state;
+        let output = ValidateAccessToken::new(state.token_service.as_ref(), state.session_repo.as_ref())
+            .execute(ValidateAccessTokenInput {
+                access_token: access_token.clone(),
+                required_scopes: Vec::new(),
+            })
+            .await?;
+
+        Ok(output.valid)
+    }
+
+    async fn active_sessions(&self) -> Vec<String> {
+        self.state
+            .session_repo
+            .list_active_for_user(&self.user_id)
+            .await
+            .into_iter()
+            .map(|session| session.session_id)
+            .collect()
+    }
+}
+
+/// Records a token and asserts it was never handed out before
+fn assert_fresh(seen: &mut HashSet<String>, token: &Token) {
+    assert!(!token.value().is_empty());
+    assert!(seen.insert(token.value().to_string()), "token issued twice: {}", token.value());
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_authenticate_issue_refresh_validate_revoke() {
+    let h = harness().await;
+    let mut seen = HashSet::new();
+    assert!(h.active_sessions().await.is_empty());
+
+    // Authenticate and issue a session
+    let first = h.login().await;
+    assert_fresh(&mut seen, &first.access_token);
+    assert_fresh(&mut seen, &first.refresh_token);
+    assert_eq!(h.active_sessions().await, vec![first.session_id.clone()]);
+    assert!(h.validate(&first.access_token).await.unwrap());
+
+    // Refresh rotates both tokens within the same session
+    let (access, refresh) = h.refresh(&first.refresh_token).await.unwrap();
+    assert_fresh(&mut seen, &access);
+    assert_fresh(&mut seen, &refresh);
+    assert_eq!(h.active_sessions().await, vec![first.session_id.clone()]);
+    assert!(h.validate(&access).await.unwrap());
+    assert_eq!(h.sessions.live_hash_count(), 1);
+
+    // A second login opens a second, independent session
+    let second = h.login().await;
+    assert_fresh(&mut seen, &second.access_token);
+    assert_fresh(&mut seen, &second.refresh_token);
+    assert_ne!(second.session_id, first.session_id);
+    assert_eq!(h.active_sessions().await.len(), 2);
+
+    // Revoke the first session
+    let revoked = RevokeSession::new(h.state.session_repo.as_ref())
+        .execute(RevokeSessionInput {
+            session_id: Some(first.session_id.clone()),
+            refresh_token_hash: None,
+        })
+        .await
+        .unwrap();
+    assert!(revoked.revoked);
+    assert_eq!(h.active_sessions().await, vec![second.session_id.clone()]);
+
+    // Its latest refresh token no longer works, and its access token reports revocation
+    assert!(h.refresh(&refresh).await.is_err());
+    assert!(matches!(
+        h.validate(&access).await,
+        Err(CoreError::Token(TokenError::Revoked { .. }))
+    ));
+
+    // The other session is untouched
+    assert!(h.validate(&second.access_token).await.unwrap());
+    let (access, refresh) = h.refresh(&second.refresh_token).await.unwrap();
+    assert_fresh(&mut seen, &access);
+    assert_fresh(&mut seen, &refresh);
+    assert_eq!(h.active_sessions().await, vec![second.session_id.clone()]);
+}
+
+#[tokio::test]
+async fn test_replayed_refresh_token_revokes_every_session() {
+    let h = harness().await;
+
+    let first = h.login().await;
+    let second = h.login().await;
+    assert_eq!(h.active_sessions().await.len(), 2);
+
+    h.refresh(&first.refresh_token).await.unwrap();
+
+    // Presenting the rotated-out token again is treated as theft
+    assert!(h.refresh(&first.refresh_token).await.is_err());
+    assert!(h.active_sessions().await.is_empty());
+    assert!(h.refresh(&second.refresh_token).await.is_err());
+}
+
+#[tokio::test]
+async fn test_locked_account_reports_retry_after() {
+    let h = harness().await;
+    let now = Utc::now();
+    h.state
+        .credential_repo
+        .lock_until(&h.user_id, &(now + Duration::seconds(90)).to_rfc3339())
+        .await;
+    let app = create_router(h.state.clone().with_clock(Arc::new(FixedClock::new(now))));
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method(Method::POST)
+                .uri("/public/auth/authenticate")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "identifier": IDENTIFIER, "password": PASSWORD }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::LOCKED);
+    assert_eq!(response.headers().get(header::RETRY_AFTER).unwrap(), "90");
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: ErrorResponse = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body.code, "ACCOUNT_LOCKED");
+    assert_eq!(body.retry_after_seconds, Some(90));
+}
+
+// ============================================================================
+// In-memory Adapters
+// ============================================================================
+
+/// Token service handing out opaque, never-repeating tokens
+#[derive(Default)]
+struct SequencedTokenService {
+    next: AtomicU64,
+    issued: Mutex<HashMap<String, (String, String)>>, // token -> (type, claims)
+}
+
+impl SequencedTokenService {
+    fn issue(&self, kind: &str, claims: &str) -> Token {
+        let token = format!("{}-{}", kind, self.next.fetch_add(1, Ordering::SeqCst));
+        self.issued
+            .lock()
+            .unwrap()
+            .insert(token.clone(), (kind.to_string(), claims.to_string()));
+        Token::new(token)
+    }
+
+    fn validate(&self, kind: &str, token: &Token) -> Result<String, ()> {
+        match self.issued.lock().unwrap().get(token.value()) {
+            Some((issued_kind, claims)) if issued_kind == kind => Ok(claims.clone()),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TokenService for SequencedTokenService {
+    fn issue_access_token(&self, _subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        // Like the real services, stamp the token type into the claims
+        let mut claims: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(claims).unwrap_or_default();
+        claims.insert("type".to_string(), serde_json::Value::from("access"));
+        let token = self.issue("access", &serde_json::Value::Object(claims).to_string());
+        Box::pin(async move { token })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        let token = self.issue("refresh", claims);
+        Box::pin(async move { token })
+    }
+
+    fn issue_service_token(&self, _subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        let token = self.issue("service", claims);
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = self.validate("access", token);
+        Box::pin(async move { claims })
+    }
+
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = self.validate("refresh", token);
+        Box::pin(async move { claims })
+    }
+
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = self.validate("service", token);
+        Box::pin(async move { claims })
+    }
+}
+
+struct SessionRecord {
+    user_id: String,
+    refresh_token_hash: String,
+    created_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Default)]
+struct InMemorySessionRepo {
+    sessions: Mutex<HashMap<String, SessionRecord>>,
+    rotated_hashes: Mutex<HashSet<String>>,
+}
+
+impl InMemorySessionRepo {
+    /// Refresh token hashes that can still be redeemed
+    fn live_hash_count(&self) -> usize {
+        self.sessions
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|record| record.revoked_at.is_none())
+            .count()
+    }
+
+    fn revoke_where(&self, predicate: impl Fn(&str, &SessionRecord) -> bool) -> u64 {
+        let now = Utc::now();
+        let mut revoked = 0;
+        for (id, record) in self.sessions.lock().unwrap().iter_mut() {
+            if record.revoked_at.is_none() && predicate(id, record) {
+                record.revoked_at = Some(now);
+                revoked += 1;
+            }
+        }
+        revoked
+    }
+}
+
+impl SessionRepository for InMemorySessionRepo {
+    fn create_session(
+        &self,
+        session_id: &str,
+        user: &UserIdentity,
+        refresh_token_hash: &str,
+        _metadata: &str,
+    ) -> BoxFuture<'_, Result<(), CoreError>> {
+        self.sessions.lock().unwrap().insert(
+            session_id.to_string(),
+            SessionRecord {
+                user_id: user.id().to_string(),
+                refresh_token_hash: refresh_token_hash.to_string(),
+                created_at: Utc::now(),
+                revoked_at: None,
+            },
+        );
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, hash: &str) -> BoxFuture<'_, Option<Session>> {
+        let found = self
+            .sessions
+            .lock()
+            .unwrap()
+            .values()
+            .any(|record| record.revoked_at.is_none() && record.refresh_token_hash == hash);
+        Box::pin(async move { found.then_some(Session {}) })
+    }
+
+    fn find_by_id(&self, session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        let found = self
+            .sessions
+            .lock()
+            .unwrap()
+            .get(session_id)
+            .is_some_and(|record| record.revoked_at.is_none());
+        Box::pin(async move { found.then_some(Session {}) })
+    }
+
+    fn rotate_refresh_token(&self, session_id: &str, current_hash: &str, new_hash: &str) -> BoxFuture<'_, bool> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let rotated = match sessions.get_mut(session_id) {
+            Some(record) if record.revoked_at.is_none() && record.refresh_token_hash == current_hash => {
+                record.refresh_token_hash = new_hash.to_string();
+                self.rotated_hashes.lock().unwrap().insert(current_hash.to_string());
+                true
+            }
+            _ => false,
+        };
+        Box::pin(async move { rotated })
+    }
+
+    fn is_rotated_refresh_token(&self, hash: &str) -> BoxFuture<'_, bool> {
+        let rotated = self.rotated_hashes.lock().unwrap().contains(hash);
+        Box::pin(async move { rotated })
+    }
+
+    fn revoked_at(&self, session_id: &str) -> BoxFuture<'_, Option<DateTime<Utc>>> {
+        let revoked_at = self
+            .sessions
+            .lock()
+            .unwrap()
+            .get(session_id)
+            .and_then(|record| record.revoked_at);
+        Box::pin(async move { revoked_at })
+    }
+
+    fn revoke_session(&self, session_id: &str) -> BoxFuture<'_, ()> {
+        self.revoke_where(|id, _| id == session_id);
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, user_id: &str) -> BoxFuture<'_, u64> {
+        let revoked = self.revoke_where(|_, record| record.user_id == user_id);
+        Box::pin(async move { revoked })
+    }
+
+    fn revoke_all_for_user_except(&self, user_id: &str, keep_session_id: &str) -> BoxFuture<'_, u64> {
+        let revoked = self.revoke_where(|id, record| record.user_id == user_id && id != keep_session_id);
+        Box::pin(async move { revoked })
+    }
+
+    fn list_active_for_user(&self, user_id: &str) -> BoxFuture<'_, Vec<SessionSummary>> {
+        let mut active: Vec<SessionSummary> = self
+            .sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, record)| record.user_id == user_id && record.revoked_at.is_none())
+            .map(|(id, record)| SessionSummary {
+                session_id: id.clone(),
+                created_at: record.created_at,
+                expires_at: record.created_at + Duration::days(30),
+                ip_address: "203.0.113.7".to_string(),
+                user_agent: "lifecycle-test".to_string(),
+            })
+            .collect();
+        active.sort_by(|a, b| b.created_at.cmp(&a.created_at).then_with(|| b.session_id.cmp(&a.session_id)));
+        Box::pin(async move { active })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+#[derive(Default)]
+struct InMemoryIdentityRepo {
+    identities: Mutex<HashMap<String, String>>, // identifier -> user_id
+}
+
+impl IdentityRepository for InMemoryIdentityRepo {
+    fn find_by_identifier(&self, identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = self.identities.lock().unwrap().get(identifier).map(UserIdentity::new);
+        Box::pin(async move { result })
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = self
+            .identities
+            .lock()
+            .unwrap()
+            .values()
+            .find(|user_id| user_id.as_str() == id)
+            .map(UserIdentity::new);
+        Box::pin(async move { result })
+    }
+
+    fn create(
+        &self,
+        user_id: &Uuid,
+        identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        self.identities
+            .lock()
+            .unwrap()
+            .insert(identifier.to_string(), user_id.to_string());
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+#[derive(Default)]
+struct InMemoryCredentialRepo {
+    credentials: Mutex<HashMap<String, StoredCredential>>,
+}
+
+impl CredentialRepository for InMemoryCredentialRepo {
+    fn get_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        let result = self.credentials.lock().unwrap().get(user_id).cloned();
+        Box::pin(async move { result })
+    }
+
+    fn update_failed_attempts(&self, user_id: &str, attempts: u32) -> BoxFuture<'_, ()> {
+        if let Some(credential) = self.credentials.lock().unwrap().get_mut(user_id) {
+            credential.failed_attempts = attempts;
+            if attempts == 0 {
+                credential.locked_until = None;
+            }
+        }
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, user_id: &str, until: &str) -> BoxFuture<'_, ()> {
+        if let Some(credential) = self.credentials.lock().unwrap().get_mut(user_id) {
+            credential.locked_until = Some(until.to_string());
+        }
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, user_id: &str, new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        self.credentials.lock().unwrap().insert(user_id.to_string(), new_credential);
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+/// Inert implementation of the ports this lifecycle never touches
+struct Stub;
+
+impl ExternalIdentityRepository for Stub {
+    fn find_by_provider_user(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+    ) -> BoxFuture<'_, Result<Option<Uuid>, anyhow::Error>> {
+        Box::pin(async move { Ok(None) })
+    }
+
+    fn upsert(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+        _user_id: Uuid,
+        _email: Option<&str>,
+    ) -> BoxFuture<'_, Result<Uuid, anyhow::Error>> {
+        Box::pin(async move { Ok(Uuid::nil()) })
+    }
+
+    fn delete(&self, _provider: &str, _provider_user_id: &str) -> BoxFuture<'_, Result<(), anyhow::Error>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl UserServiceClient for Stub {
+    fn register_google_user(
+        &self,
+        _request: crate::core::usecases::ports::user_service_client::RegisterGoogleUserRequest,
+    ) -> BoxFuture<'static, Result<Uuid, CoreError>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+}
+
+impl ServiceRegistry for Stub {
+    fn validate_api_key(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn is_service_active(&self, _service_id: &str) -> bool {
+        false
+    }
+
+    fn validate_credentials(
+        &self,
+        _service_id: &str,
+        _service_secret: &str,
+        _password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        None
+    }
+}
+
+impl ExternalTokenValidator for Stub {
+    fn validate(&self, _token: &str) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl ExchangeAuthorizationCode for Stub {
+    fn exchange(&self, _code: &str, _state: Option<&str>) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
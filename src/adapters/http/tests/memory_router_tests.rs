@@ -0,0 +1,384 @@
+//! Full router over the in-memory repositories
+//!
+//! Builds `AppState` from `adapters::memory`, the Argon2 hasher and a real
+//! HMAC token service, then drives authenticate → refresh through
+//! `create_router` over HTTP, as a client would against a deployment backed
+//! by Postgres.
+
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    http::{header, Method, Request, Response, StatusCode},
+    Router,
+};
+use futures::future::BoxFuture;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::adapters::crypto::password::Argon2PasswordHasher;
+use crate::adapters::crypto::token::{HmacKey, HmacTokenService};
+use crate::adapters::http::dto::public::{AuthenticateResponse, RefreshTokenResponse};
+use crate::adapters::http::error::ErrorResponse;
+use crate::adapters::http::router::create_router;
+use crate::adapters::http::state::AppState;
+use crate::adapters::memory::{
+    CredentialRepositoryMemory, IdentityRepositoryMemory, MemoryStore, SessionRepositoryMemory,
+};
+use crate::core::error::CoreError;
+use crate::core::identity::ExternalIdentity;
+use crate::core::token::Token;
+use crate::core::usecases::ports::{
+    ExchangeAuthorizationCode, ExternalIdentityRepository, ExternalTokenValidator, PasswordHasher, ServiceRegistry,
+    UserServiceClient,
+};
+
+const IDENTIFIER: &str = "alice@example.com";
+const PASSWORD: &str = "correct horse battery staple";
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+async fn memory_state() -> AppState {
+    memory_state_over(MemoryStore::new()).await
+}
+
+async fn memory_state_over(store: MemoryStore) -> AppState {
+    let key = HmacKey::generate().expect("Should generate key");
+    let tokens = HmacTokenService::from_secret_key(&key.as_bytes()).expect("Should create service with valid key");
+
+    let state = AppState::new(
+        Arc::new(IdentityRepositoryMemory::new(store.clone())),
+        Arc::new(CredentialRepositoryMemory::new(store.clone())),
+        Arc::new(SessionRepositoryMemory::new(store)),
+        Arc::new(Argon2PasswordHasher::new(1024, 1, 1, 16).expect("valid parameters")),
+        Arc::new(tokens),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        Arc::new(Stub),
+        900,
+        30,
+        true,
+        3600,
+    );
+
+    // Register the user the way the internal credentials endpoint does
+    let hashed = state.password_hasher.hash(PASSWORD);
+    state
+        .identity_repo
+        .create(&Uuid::new_v4(), IDENTIFIER, hashed.as_hash_str(), "", "", 0)
+        .await
+        .unwrap();
+
+    state
+}
+
+async fn post_json(app: &Router, uri: &str, body: serde_json::Value) -> Response<Body> {
+    send_json(app, uri, None, body).await
+}
+
+async fn send_json(app: &Router, uri: &str, bearer: Option<&str>, body: serde_json::Value) -> Response<Body> {
+    let mut request = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = bearer {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+
+    app.clone()
+        .oneshot(request.body(Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn read_json<T: serde::de::DeserializeOwned>(response: Response<Body>) -> T {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_authenticate_then_refresh_over_http() {
+    let state = memory_state().await;
+    let app = create_router(state.clone());
+
+    let response = post_json(
+        &app,
+        "/public/auth/authenticate",
+        serde_json::json!({ "identifier": IDENTIFIER, "password": PASSWORD }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let authenticated: AuthenticateResponse = read_json(response).await;
+    assert!(state
+        .session_repo
+        .find_by_id(&authenticated.session_id)
+        .await
+        .is_some());
+
+    let response = send_json(
+        &app,
+        "/public/auth/refresh",
+        Some(&authenticated.access_token),
+        serde_json::json!({ "refresh_token": authenticated.refresh_token }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let refreshed: RefreshTokenResponse = read_json(response).await;
+    assert_eq!(refreshed.token_type, "Bearer");
+    assert!(state
+        .token_service
+        .validate_access_token(&Token::new(refreshed.access_token))
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_authenticate_into_workspace_over_http() {
+    let store = MemoryStore::new();
+    let state = memory_state_over(store.clone()).await;
+    let user = state.identity_repo.find_by_identifier(IDENTIFIER).await.unwrap();
+    IdentityRepositoryMemory::new(store).add_workspace_member("workspace-1", &Uuid::parse_str(user.id()).unwrap());
+    let app = create_router(state.clone());
+
+    let response = post_json(
+        &app,
+        "/public/auth/authenticate",
+        serde_json::json!({ "identifier": IDENTIFIER, "password": PASSWORD, "workspace_id": "workspace-1" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let authenticated: AuthenticateResponse = read_json(response).await;
+    assert_eq!(authenticated.workspace_id.as_deref(), Some("workspace-1"));
+    let claims = state
+        .token_service
+        .validate_access_token(&Token::new(authenticated.access_token))
+        .await
+        .unwrap();
+    assert!(claims.contains(r#""workspace_id":"workspace-1""#), "claims: {}", claims);
+
+    // Unknown workspaces are refused like ones the user does not belong to
+    let response = post_json(
+        &app,
+        "/public/auth/authenticate",
+        serde_json::json!({ "identifier": IDENTIFIER, "password": PASSWORD, "workspace_id": "workspace-2" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_wrong_password_is_rejected_over_http() {
+    let state = memory_state().await;
+    let app = create_router(state);
+
+    let response = post_json(
+        &app,
+        "/public/auth/authenticate",
+        serde_json::json!({ "identifier": IDENTIFIER, "password": "not the password" }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_invalid_request_reports_every_field_over_http() {
+    let state = memory_state().await;
+    let app = create_router(state);
+
+    let response = post_json(
+        &app,
+        "/public/auth/authenticate",
+        serde_json::json!({ "identifier": "", "password": "" }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let error: ErrorResponse = read_json(response).await;
+    let fields: Vec<_> = error.errors.iter().map(|e| e.field.as_str()).collect();
+    assert_eq!(fields, vec!["identifier", "password"]);
+}
+
+/// Lock the registered account for an hour
+async fn lock_account(state: &AppState) {
+    let user = state.identity_repo.find_by_identifier(IDENTIFIER).await.unwrap();
+    let until = chrono::Utc::now() + chrono::Duration::hours(1);
+    state.credential_repo.lock_until(user.id(), &until.to_rfc3339()).await;
+}
+
+/// Status, `Retry-After` and raw body of a login attempt
+async fn login_failure(app: &Router, identifier: &str, password: &str) -> (StatusCode, bool, Vec<u8>) {
+    let response = post_json(
+        app,
+        "/public/auth/authenticate",
+        serde_json::json!({ "identifier": identifier, "password": password }),
+    )
+    .await;
+    let status = response.status();
+    let retry_after = response.headers().contains_key(header::RETRY_AFTER);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, retry_after, bytes.to_vec())
+}
+
+#[tokio::test]
+async fn test_generic_auth_failures_are_indistinguishable_over_http() {
+    let state = memory_state().await.with_generic_auth_failures(true);
+    let app = create_router(state.clone());
+
+    let unknown = login_failure(&app, "mallory@example.com", PASSWORD).await;
+    let wrong_password = login_failure(&app, IDENTIFIER, "not the password").await;
+    lock_account(&state).await;
+    let locked = login_failure(&app, IDENTIFIER, PASSWORD).await;
+
+    assert_eq!(unknown.0, StatusCode::UNAUTHORIZED);
+    assert!(!unknown.1);
+    assert_eq!(unknown, wrong_password);
+    assert_eq!(unknown, locked);
+}
+
+#[tokio::test]
+async fn test_verbose_auth_failures_report_lockout_over_http() {
+    let state = memory_state().await;
+    let app = create_router(state.clone());
+
+    let wrong_password = login_failure(&app, IDENTIFIER, "not the password").await;
+    lock_account(&state).await;
+    let locked = login_failure(&app, IDENTIFIER, PASSWORD).await;
+
+    assert_eq!(wrong_password.0, StatusCode::UNAUTHORIZED);
+    assert_eq!(locked.0, StatusCode::LOCKED);
+    assert!(locked.1);
+    assert_ne!(wrong_password.2, locked.2);
+}
+
+/// Log in and revoke the new session, returning its access token
+async fn access_token_of_revoked_session(app: &Router, state: &AppState) -> String {
+    let response = post_json(
+        app,
+        "/public/auth/authenticate",
+        serde_json::json!({ "identifier": IDENTIFIER, "password": PASSWORD }),
+    )
+    .await;
+    let authenticated: AuthenticateResponse = read_json(response).await;
+    state.session_repo.revoke_session(&authenticated.session_id).await;
+    authenticated.access_token
+}
+
+async fn validate_over_http(app: &Router, access_token: &str) -> StatusCode {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .method(Method::POST)
+                .uri("/public/auth/validate")
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(header::AUTHORIZATION, format!("Bearer {}", access_token))
+                .body(Body::from(serde_json::json!({ "token": access_token }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .status()
+}
+
+#[tokio::test]
+async fn test_access_token_of_revoked_session_fails_with_session_binding() {
+    let state = memory_state().await;
+    let app = create_router(state.clone());
+
+    let access_token = access_token_of_revoked_session(&app, &state).await;
+
+    assert_eq!(validate_over_http(&app, &access_token).await, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_access_token_of_revoked_session_passes_without_session_binding() {
+    let state = memory_state().await.with_access_token_session_binding(false);
+    let app = create_router(state.clone());
+
+    let access_token = access_token_of_revoked_session(&app, &state).await;
+
+    assert_eq!(validate_over_http(&app, &access_token).await, StatusCode::OK);
+}
+
+// ============================================================================
+// Stubs
+// ============================================================================
+
+/// Inert implementation of the ports this flow never touches
+struct Stub;
+
+impl ExternalIdentityRepository for Stub {
+    fn find_by_provider_user(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+    ) -> BoxFuture<'_, Result<Option<Uuid>, anyhow::Error>> {
+        Box::pin(async move { Ok(None) })
+    }
+
+    fn upsert(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+        _user_id: Uuid,
+        _email: Option<&str>,
+    ) -> BoxFuture<'_, Result<Uuid, anyhow::Error>> {
+        Box::pin(async move { Ok(Uuid::nil()) })
+    }
+
+    fn delete(&self, _provider: &str, _provider_user_id: &str) -> BoxFuture<'_, Result<(), anyhow::Error>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl UserServiceClient for Stub {
+    fn register_google_user(
+        &self,
+        _request: crate::core::usecases::ports::user_service_client::RegisterGoogleUserRequest,
+    ) -> BoxFuture<'static, Result<Uuid, CoreError>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+}
+
+impl ServiceRegistry for Stub {
+    fn validate_api_key(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn is_service_active(&self, _service_id: &str) -> bool {
+        false
+    }
+
+    fn validate_credentials(
+        &self,
+        _service_id: &str,
+        _service_secret: &str,
+        _password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        None
+    }
+}
+
+impl ExternalTokenValidator for Stub {
+    fn validate(&self, _token: &str) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
+
+impl ExchangeAuthorizationCode for Stub {
+    fn exchange(&self, _code: &str, _state: Option<&str>) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async move {
+            ExternalIdentity::new("mock_provider".to_string(), "mock_external_id".to_string(), None, None, None, None)
+        })
+    }
+}
@@ -1,2 +1,9 @@
 // HTTP adapter tests
+mod lifecycle_tests;
+mod memory_router_tests;
 mod state_tests;
+mod no_store_contract_tests;
+mod cors_contract_tests;
+mod request_context_tests;
+mod telemetry_tests;
+mod health_tests;
\ No newline at end of file
@@ -0,0 +1,329 @@
+//! Contract test: every response, and in particular every token-issuing
+//! response, carries `Cache-Control: no-store`.
+
+use std::sync::Arc;
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+};
+use futures::future::BoxFuture;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::adapters::http::router::{create_router, route_paths};
+use crate::adapters::http::state::AppState;
+use crate::core::credentials::StoredCredential;
+use crate::core::error::CoreError;
+use crate::core::identity::{ExternalIdentity, UserIdentity};
+use crate::core::token::Token;
+use crate::core::usecases::ports::session_repository::Session;
+use crate::core::usecases::ports::{
+    CredentialRepository, ExchangeAuthorizationCode, ExternalIdentityRepository,
+    ExternalTokenValidator, IdentityRepository, PasswordHasher, ServiceRegistry,
+    SessionRepository, TokenService, UserServiceClient,
+};
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+struct MockIdentityRepo;
+struct MockCredentialRepo;
+struct MockSessionRepo;
+struct MockTokenService;
+struct MockPasswordHasher;
+struct MockServiceRegistry;
+struct MockExternalTokenValidator;
+struct MockExchangeAuthorizationCode;
+struct MockExternalIdentityRepository;
+struct MockUserServiceClient;
+
+impl IdentityRepository for MockIdentityRepo {
+    fn find_by_identifier(&self, _identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { Some(UserIdentity::new("user123")) })
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let user = UserIdentity::new(id);
+        Box::pin(async move { Some(user) })
+    }
+
+    fn create(
+        &self,
+        _user_id: &Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl CredentialRepository for MockCredentialRepo {
+    fn get_by_user_id(&self, _user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        Box::pin(async move { Some(StoredCredential::from_hash("hashed_password123")) })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, _user_id: &str, _new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+impl SessionRepository for MockSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { Some(Session {}) })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { Some(Session {}) })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+impl TokenService for MockTokenService {
+    fn issue_access_token(&self, user_id: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("access_{}", user_id));
+        Box::pin(async move { token })
+    }
+
+    fn issue_refresh_token(&self, user_id: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("refresh_{}", user_id));
+        Box::pin(async move { token })
+    }
+
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let exp = chrono::Utc::now().timestamp() + 3600;
+        let claims = Ok(format!(r#"{{"sub":"user123","type":"access","exp":{},"sid":"session-1"}}"#, exp));
+        Box::pin(async move { claims })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let exp = chrono::Utc::now().timestamp() + 3600;
+        let claims = Ok(format!(r#"{{"sub":"user123","type":"refresh","exp":{},"sid":"session-1"}}"#, exp));
+        Box::pin(async move { claims })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Ok(r#"{"sub":"service123","type":"service"}"#.to_string()) })
+    }
+}
+
+impl PasswordHasher for MockPasswordHasher {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, raw: &str, stored: &StoredCredential) -> bool {
+        stored.as_hash_str() == format!("hashed_{}", raw)
+    }
+}
+
+impl ServiceRegistry for MockServiceRegistry {
+    fn validate_api_key(&self, _api_key: &str) -> Option<String> {
+        Some("test-service".to_string())
+    }
+
+    fn is_service_active(&self, _service_name: &str) -> bool {
+        true
+    }
+
+    fn validate_credentials(
+        &self,
+        service_id: &str,
+        _service_secret: &str,
+        _password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        Some(service_id.to_string())
+    }
+}
+
+fn external_identity() -> ExternalIdentity {
+    ExternalIdentity {
+        provider: "google".to_string(),
+        provider_user_id: "test123".to_string(),
+        email: Some("test@example.com".to_string()),
+        name: None,
+        family_name: None,
+        picture: None,
+    }
+}
+
+impl ExternalTokenValidator for MockExternalTokenValidator {
+    fn validate(&self, _token: &str) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async { Ok(external_identity()) })
+    }
+}
+
+impl ExchangeAuthorizationCode for MockExchangeAuthorizationCode {
+    fn exchange(&self, _code: &str, _state: Option<&str>) -> BoxFuture<'static, Result<ExternalIdentity, CoreError>> {
+        Box::pin(async { Ok(external_identity()) })
+    }
+}
+
+impl ExternalIdentityRepository for MockExternalIdentityRepository {
+    fn find_by_provider_user(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+    ) -> BoxFuture<'_, Result<Option<Uuid>, anyhow::Error>> {
+        Box::pin(async { Ok(Some(Uuid::nil())) })
+    }
+
+    fn upsert(
+        &self,
+        _provider: &str,
+        _provider_user_id: &str,
+        _user_id: Uuid,
+        _email: Option<&str>,
+    ) -> BoxFuture<'_, Result<Uuid, anyhow::Error>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+
+    fn delete(&self, _provider: &str, _provider_user_id: &str) -> BoxFuture<'_, Result<(), anyhow::Error>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+impl UserServiceClient for MockUserServiceClient {
+    fn register_google_user(
+        &self,
+        _request: crate::core::usecases::ports::user_service_client::RegisterGoogleUserRequest,
+    ) -> BoxFuture<'static, Result<Uuid, CoreError>> {
+        Box::pin(async { Ok(Uuid::nil()) })
+    }
+}
+
+pub(super) fn test_state() -> AppState {
+    AppState::new(
+        Arc::new(MockIdentityRepo),
+        Arc::new(MockCredentialRepo),
+        Arc::new(MockSessionRepo),
+        Arc::new(MockPasswordHasher),
+        Arc::new(MockTokenService),
+        Arc::new(MockServiceRegistry),
+        Arc::new(MockExternalTokenValidator),
+        Arc::new(MockExchangeAuthorizationCode),
+        Arc::new(MockExternalIdentityRepository),
+        Arc::new(MockUserServiceClient),
+        900,
+        7,
+        true,
+        3600,
+    )
+}
+
+fn request(method: Method, path: &str, body: &str) -> Request<Body> {
+    Request::builder()
+        .method(method)
+        .uri(path)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::AUTHORIZATION, "Bearer test-token")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn assert_no_store(response: &axum::response::Response, path: &str) {
+    let cache_control = response
+        .headers()
+        .get(header::CACHE_CONTROL)
+        .and_then(|v| v.to_str().ok());
+    assert_eq!(cache_control, Some("no-store"), "missing Cache-Control: no-store on {}", path);
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_every_route_sets_no_store() {
+    let paths = route_paths();
+    assert!(paths.iter().any(|p| p == "/public/auth/authenticate"));
+    assert!(paths.iter().any(|p| p == "/public/auth/refresh"));
+
+    for path in paths {
+        let app = create_router(test_state());
+        let mut response = app
+            .oneshot(request(Method::POST, &path, "{}"))
+            .await
+            .unwrap();
+
+        if response.status() == StatusCode::METHOD_NOT_ALLOWED {
+            let app = create_router(test_state());
+            response = app
+                .oneshot(request(Method::GET, &path, ""))
+                .await
+                .unwrap();
+        }
+
+        assert_ne!(response.status(), StatusCode::NOT_FOUND, "route {} not registered", path);
+        assert_no_store(&response, &path);
+    }
+}
+
+#[tokio::test]
+async fn test_successful_authenticate_sets_no_store() {
+    let app = create_router(test_state());
+
+    let response = app
+        .oneshot(request(
+            Method::POST,
+            "/public/auth/authenticate",
+            r#"{"identifier":"alice","password":"password123"}"#,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_no_store(&response, "/public/auth/authenticate");
+}
+
+#[tokio::test]
+async fn test_successful_refresh_sets_no_store() {
+    let app = create_router(test_state());
+
+    let response = app
+        .oneshot(request(
+            Method::POST,
+            "/public/auth/refresh",
+            r#"{"refresh_token":"refresh_user123"}"#,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_no_store(&response, "/public/auth/refresh");
+}
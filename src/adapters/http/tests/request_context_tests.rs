@@ -0,0 +1,139 @@
+//! Tests for client IP resolution and RequestContext
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::http::{header::USER_AGENT, HeaderMap, HeaderValue};
+
+use crate::adapters::http::request_context::{
+    parse_ip, ClientIpResolver, RequestContext, FORWARDED_FOR_HEADER, UNKNOWN_IP,
+};
+
+fn forwarded(chain: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(FORWARDED_FOR_HEADER, HeaderValue::from_str(chain).unwrap());
+    headers
+}
+
+fn peer() -> Option<SocketAddr> {
+    Some("10.0.0.2:51234".parse().unwrap())
+}
+
+fn ip(raw: &str) -> IpAddr {
+    raw.parse().unwrap()
+}
+
+// ============================================================================
+// Proxy Chains
+// ============================================================================
+
+#[test]
+fn test_multi_hop_chain_honors_trusted_hop_count() {
+    let headers = forwarded("203.0.113.7, 10.0.0.5, 10.0.0.9");
+
+    assert_eq!(ClientIpResolver::new(1).resolve(&headers, peer()), Some(ip("10.0.0.9")));
+    assert_eq!(ClientIpResolver::new(2).resolve(&headers, peer()), Some(ip("10.0.0.5")));
+    assert_eq!(ClientIpResolver::new(3).resolve(&headers, peer()), Some(ip("203.0.113.7")));
+}
+
+#[test]
+fn test_spoofed_extra_hop_is_ignored() {
+    // The client sent "X-Forwarded-For: 1.2.3.4"; our single proxy appended
+    // the address it actually saw
+    let headers = forwarded("1.2.3.4, 203.0.113.7");
+
+    let resolved = ClientIpResolver::new(1).resolve(&headers, peer());
+    assert_eq!(resolved, Some(ip("203.0.113.7")));
+}
+
+#[test]
+fn test_chain_shorter_than_trusted_hops_uses_leftmost_entry() {
+    let headers = forwarded("203.0.113.7");
+
+    assert_eq!(ClientIpResolver::new(3).resolve(&headers, peer()), Some(ip("203.0.113.7")));
+}
+
+#[test]
+fn test_repeated_headers_form_one_chain() {
+    let mut headers = forwarded("1.2.3.4");
+    headers.append(FORWARDED_FOR_HEADER, HeaderValue::from_static("203.0.113.7"));
+
+    assert_eq!(ClientIpResolver::new(1).resolve(&headers, peer()), Some(ip("203.0.113.7")));
+}
+
+#[test]
+fn test_zero_trusted_hops_ignores_header() {
+    let headers = forwarded("203.0.113.7");
+
+    assert_eq!(ClientIpResolver::new(0).resolve(&headers, peer()), Some(ip("10.0.0.2")));
+}
+
+// ============================================================================
+// Fallbacks
+// ============================================================================
+
+#[test]
+fn test_missing_header_falls_back_to_socket_address() {
+    let resolved = ClientIpResolver::default().resolve(&HeaderMap::new(), peer());
+    assert_eq!(resolved, Some(ip("10.0.0.2")));
+}
+
+#[test]
+fn test_garbage_entry_falls_back_to_socket_address() {
+    let headers = forwarded("not-an-ip");
+
+    assert_eq!(ClientIpResolver::new(1).resolve(&headers, peer()), Some(ip("10.0.0.2")));
+    assert_eq!(ClientIpResolver::new(1).resolve(&headers, None), None);
+}
+
+// ============================================================================
+// IPv6 Normalization
+// ============================================================================
+
+#[test]
+fn test_ipv6_address_is_normalized() {
+    let headers = forwarded("2001:DB8:0:0:0:0:0:1");
+
+    let resolved = ClientIpResolver::new(1).resolve(&headers, peer()).unwrap();
+    assert_eq!(resolved.to_string(), "2001:db8::1");
+}
+
+#[test]
+fn test_parse_ip_accepts_ports_and_brackets() {
+    assert_eq!(parse_ip("[2001:db8::1]:8443"), Some(ip("2001:db8::1")));
+    assert_eq!(parse_ip("[2001:db8::1]"), Some(ip("2001:db8::1")));
+    assert_eq!(parse_ip("203.0.113.7:8080"), Some(ip("203.0.113.7")));
+    assert_eq!(parse_ip(" 203.0.113.7 "), Some(ip("203.0.113.7")));
+    assert_eq!(parse_ip("unknown"), None);
+}
+
+#[test]
+fn test_ipv4_mapped_ipv6_collapses_to_ipv4() {
+    assert_eq!(parse_ip("::ffff:203.0.113.7"), Some(ip("203.0.113.7")));
+
+    let mapped_peer: SocketAddr = "[::ffff:10.0.0.2]:443".parse().unwrap();
+    let resolved = ClientIpResolver::new(0).resolve(&HeaderMap::new(), Some(mapped_peer));
+    assert_eq!(resolved, Some(ip("10.0.0.2")));
+}
+
+// ============================================================================
+// RequestContext
+// ============================================================================
+
+#[test]
+fn test_request_context_from_parts() {
+    let mut headers = forwarded("1.2.3.4, 2001:db8::7");
+    headers.insert(USER_AGENT, HeaderValue::from_static("agora-test/1.0"));
+
+    let context = RequestContext::from_parts(&headers, peer(), &ClientIpResolver::default());
+
+    assert_eq!(context.ip_address, "2001:db8::7");
+    assert_eq!(context.user_agent, "agora-test/1.0");
+}
+
+#[test]
+fn test_request_context_defaults_when_nothing_known() {
+    let context = RequestContext::from_parts(&HeaderMap::new(), None, &ClientIpResolver::default());
+
+    assert_eq!(context.ip_address, UNKNOWN_IP);
+    assert_eq!(context.user_agent, "unknown");
+}
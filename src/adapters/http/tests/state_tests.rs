@@ -3,6 +3,7 @@
 use std::sync::Arc;
 use futures::future::BoxFuture;
 use crate::adapters::http::state::AppState;
+use crate::core::usecases::policies::{AuthPolicyConfig, HasherParams, LockoutPolicy, TokenLifetimes};
 use crate::core::usecases::ports::{
     IdentityRepository, CredentialRepository, SessionRepository, TokenService, PasswordHasher, ServiceRegistry,
     ExternalTokenValidator, ExchangeAuthorizationCode, ExternalIdentityRepository, UserServiceClient,
@@ -86,8 +87,8 @@ impl SessionRepository for MockSessionRepo {
         Box::pin(async move {})
     }
     
-    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, ()> {
-        Box::pin(async move {})
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
     }
     
     fn delete_expired(&self) -> BoxFuture<'_, ()> {
@@ -96,28 +97,31 @@ impl SessionRepository for MockSessionRepo {
 }
 
 impl TokenService for MockTokenService {
-    fn issue_access_token(&self, user_id: &str, _claims: &str) -> Token {
-        Token::new(format!("access_{}", user_id))
+    fn issue_access_token(&self, user_id: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("access_{}", user_id));
+        Box::pin(async move { token })
     }
     
-    fn issue_refresh_token(&self, user_id: &str, _claims: &str) -> Token {
-        Token::new(format!("refresh_{}", user_id))
+    fn issue_refresh_token(&self, user_id: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("refresh_{}", user_id));
+        Box::pin(async move { token })
     }
 
-    fn issue_service_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(format!("service_{}", subject))
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_{}", subject));
+        Box::pin(async move { token })
     }
     
-    fn validate_access_token(&self, _token: &Token) -> Result<String, ()> {
-        Ok(r#"{"sub":"user123","type":"access"}"#.to_string())
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Ok(r#"{"sub":"user123","type":"access"}"#.to_string()) })
     }
     
-    fn validate_refresh_token(&self, _token: &Token) -> Result<String, ()> {
-        Ok(r#"{"sub":"user123","type":"refresh"}"#.to_string())
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Ok(r#"{"sub":"user123","type":"refresh"}"#.to_string()) })
     }
 
-    fn validate_service_token(&self, _token: &Token) -> Result<String, ()> {
-        Ok(r#"{"sub":"service123","type":"service"}"#.to_string())
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Ok(r#"{"sub":"service123","type":"service"}"#.to_string()) })
     }
 }
 
@@ -247,10 +251,10 @@ fn test_app_state_creation() {
     );
     
     // Verify the state was created successfully
-    assert_eq!(state.access_token_ttl_seconds, 3600);
-    assert_eq!(state.refresh_token_ttl_days, 7);
-    assert!(state.rotate_refresh_tokens);
-    assert_eq!(state.service_token_ttl_seconds, 3600);
+    assert_eq!(state.config.tokens.access_ttl_secs, 3600);
+    assert_eq!(state.config.tokens.refresh_ttl_days, 7);
+    assert!(state.config.tokens.rotate_refresh_tokens);
+    assert_eq!(state.config.tokens.service_ttl_secs, 3600);
 }
 
 #[test]
@@ -275,10 +279,10 @@ fn test_app_state_clone() {
     // Clone should work since all fields are Arc or Copy types
     let cloned = state.clone();
     
-    assert_eq!(cloned.access_token_ttl_seconds, state.access_token_ttl_seconds);
-    assert_eq!(cloned.refresh_token_ttl_days, state.refresh_token_ttl_days);
-    assert_eq!(cloned.rotate_refresh_tokens, state.rotate_refresh_tokens);
-    assert_eq!(cloned.service_token_ttl_seconds, state.service_token_ttl_seconds);
+    assert_eq!(cloned.config.tokens.access_ttl_secs, state.config.tokens.access_ttl_secs);
+    assert_eq!(cloned.config.tokens.refresh_ttl_days, state.config.tokens.refresh_ttl_days);
+    assert_eq!(cloned.config.tokens.rotate_refresh_tokens, state.config.tokens.rotate_refresh_tokens);
+    assert_eq!(cloned.config.tokens.service_ttl_secs, state.config.tokens.service_ttl_secs);
 }
 
 #[test]
@@ -301,10 +305,10 @@ fn test_app_state_default_token_ttls() {
         1800u64,
     );
     
-    assert_eq!(short_lived.access_token_ttl_seconds, 900);
-    assert_eq!(short_lived.refresh_token_ttl_days, 1);
-    assert!(!short_lived.rotate_refresh_tokens);
-    assert_eq!(short_lived.service_token_ttl_seconds, 1800);
+    assert_eq!(short_lived.config.tokens.access_ttl_secs, 900);
+    assert_eq!(short_lived.config.tokens.refresh_ttl_days, 1);
+    assert!(!short_lived.config.tokens.rotate_refresh_tokens);
+    assert_eq!(short_lived.config.tokens.service_ttl_secs, 1800);
 }
 
 #[test]
@@ -327,8 +331,70 @@ fn test_app_state_long_lived_tokens() {
         7200u64,
     );
     
-    assert_eq!(long_lived.access_token_ttl_seconds, 86400);
-    assert_eq!(long_lived.refresh_token_ttl_days, 30);
-    assert!(long_lived.rotate_refresh_tokens);
-    assert_eq!(long_lived.service_token_ttl_seconds, 7200);
+    assert_eq!(long_lived.config.tokens.access_ttl_secs, 86400);
+    assert_eq!(long_lived.config.tokens.refresh_ttl_days, 30);
+    assert!(long_lived.config.tokens.rotate_refresh_tokens);
+    assert_eq!(long_lived.config.tokens.service_ttl_secs, 7200);
+}
+
+#[test]
+fn test_app_state_with_config_replaces_lockout_policy() {
+    let state = AppState::new(
+        Arc::new(MockIdentityRepo),
+        Arc::new(MockCredentialRepo),
+        Arc::new(MockSessionRepo),
+        Arc::new(MockPasswordHasher),
+        Arc::new(MockTokenService),
+        Arc::new(MockServiceRegistry),
+        Arc::new(MockExternalTokenValidator),
+        Arc::new(MockExchangeAuthorizationCode),
+        Arc::new(MockExternalIdentityRepository),
+        Arc::new(MockUserServiceClient),
+        900u64,
+        7u64,
+        true,
+        3600u64,
+    );
+    assert_eq!(state.config.lockout.max_attempts, 5);
+    assert!(state.config.validate().is_ok());
+
+    let config = AuthPolicyConfig::new(
+        LockoutPolicy::new(3, 60, true),
+        TokenLifetimes::new(300, 1, 600, false),
+        HasherParams::new(4096, 1, 1),
+    );
+    let state = state.with_config(config);
+
+    assert_eq!(state.config.lockout.max_attempts, 3);
+    assert_eq!(state.config.lockout.lock_duration_secs, 60);
+    assert_eq!(state.config.tokens.access_ttl_secs, 300);
+    assert_eq!(state.config.hasher.memory_cost_kib, 4096);
+}
+
+#[test]
+fn test_app_state_with_credential_policy() {
+    let state = AppState::new(
+        Arc::new(MockIdentityRepo),
+        Arc::new(MockCredentialRepo),
+        Arc::new(MockSessionRepo),
+        Arc::new(MockPasswordHasher),
+        Arc::new(MockTokenService),
+        Arc::new(MockServiceRegistry),
+        Arc::new(MockExternalTokenValidator),
+        Arc::new(MockExchangeAuthorizationCode),
+        Arc::new(MockExternalIdentityRepository),
+        Arc::new(MockUserServiceClient),
+        900u64,
+        7u64,
+        true,
+        3600u64,
+    );
+    assert_eq!(state.credential_policy.min_length, 8);
+    assert_eq!(state.credential_policy.password_history_depth, 0);
+
+    let state = state.with_credential_policy(
+        crate::core::credentials::CredentialPolicy::default().with_password_history(5),
+    );
+
+    assert_eq!(state.credential_policy.password_history_depth, 5);
 }
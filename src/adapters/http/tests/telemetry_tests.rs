@@ -0,0 +1,226 @@
+//! Tests for use case tracing spans
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use futures::future::BoxFuture;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Instrument, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::Registry;
+
+use crate::adapters::clock::SystemClock;
+use crate::adapters::http::telemetry::{failure_category, hash_user_id, UsecaseSpan};
+use crate::core::credentials::StoredCredential;
+use crate::core::error::{AuthenticationError, CoreError};
+use crate::core::identity::UserIdentity;
+use crate::core::usecases::authenticate_user::{AuthenticateUser, AuthenticateUserInput};
+use crate::core::usecases::policies::LockoutPolicy;
+use crate::core::usecases::ports::{CredentialRepository, IdentityRepository, PasswordHasher};
+
+const IDENTIFIER: &str = "alice@example.com";
+const USER_ID: &str = "user-7f3a";
+const PASSWORD: &str = "correct horse";
+const WRONG_PASSWORD: &str = "hunter2";
+
+// ============================================================================
+// Span Capture
+// ============================================================================
+
+type CapturedSpans = Arc<Mutex<HashMap<u64, HashMap<String, String>>>>;
+
+#[derive(Clone, Default)]
+struct CaptureLayer {
+    spans: CapturedSpans,
+}
+
+impl CaptureLayer {
+    fn usecase_spans(&self) -> Vec<HashMap<String, String>> {
+        self.spans.lock().unwrap().values().cloned().collect()
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+impl<S: Subscriber> Layer<S> for CaptureLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _ctx: Context<'_, S>) {
+        if attrs.metadata().name() != "usecase" {
+            return;
+        }
+        let mut fields = HashMap::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+        self.spans.lock().unwrap().insert(id.into_u64(), fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        if let Some(fields) = self.spans.lock().unwrap().get_mut(&id.into_u64()) {
+            values.record(&mut FieldVisitor(fields));
+        }
+    }
+}
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+struct SingleUserIdentityRepo;
+
+impl IdentityRepository for SingleUserIdentityRepo {
+    fn find_by_identifier(&self, identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = (identifier == IDENTIFIER).then(|| UserIdentity::new(USER_ID));
+        Box::pin(async move { result })
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = (id == USER_ID).then(|| UserIdentity::new(USER_ID));
+        Box::pin(async move { result })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+struct SingleCredentialRepo;
+
+impl CredentialRepository for SingleCredentialRepo {
+    fn get_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        let result = (user_id == USER_ID)
+            .then(|| StoredCredential::from_hash(format!("hashed_{}", PASSWORD)));
+        Box::pin(async move { result })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, _user_id: &str, _new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+struct PrefixPasswordHasher;
+
+impl PasswordHasher for PrefixPasswordHasher {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, raw: &str, stored: &StoredCredential) -> bool {
+        stored.as_hash_str() == format!("hashed_{}", raw)
+    }
+}
+
+/// Run a login the same way the handler does and return the captured spans
+async fn login(password: &str) -> Vec<HashMap<String, String>> {
+    let layer = CaptureLayer::default();
+    let _guard = tracing::subscriber::set_default(Registry::default().with(layer.clone()));
+
+    let use_case = AuthenticateUser::new(
+        &SingleUserIdentityRepo,
+        &SingleCredentialRepo,
+        &PrefixPasswordHasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60, true),
+    );
+
+    let span = UsecaseSpan::new("authenticate_user");
+    let result = use_case
+        .execute(AuthenticateUserInput {
+            identifier: IDENTIFIER.to_string(),
+            password: password.to_string(),
+            source_ip: None,
+        })
+        .instrument(span.span())
+        .await;
+    if let Ok(output) = &result {
+        span.record_user(output.user.id());
+    }
+    span.record_result(&result);
+    drop(span);
+
+    layer.usecase_spans()
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn test_failed_login_emits_failure_span_without_sensitive_fields() {
+    let spans = login(WRONG_PASSWORD).await;
+
+    assert_eq!(spans.len(), 1);
+    let fields = &spans[0];
+    assert_eq!(fields.get("usecase").map(String::as_str), Some("authenticate_user"));
+    assert_eq!(fields.get("outcome").map(String::as_str), Some("failure"));
+    assert_eq!(fields.get("failure").map(String::as_str), Some("invalid_credentials"));
+
+    for value in fields.values() {
+        assert!(!value.contains(WRONG_PASSWORD));
+        assert!(!value.contains(PASSWORD));
+        assert!(!value.contains(IDENTIFIER));
+        assert!(!value.contains(USER_ID));
+    }
+}
+
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn test_successful_login_records_hashed_user_id() {
+    let spans = login(PASSWORD).await;
+
+    assert_eq!(spans.len(), 1);
+    let fields = &spans[0];
+    assert_eq!(fields.get("outcome").map(String::as_str), Some("success"));
+    assert_eq!(fields.get("user_id"), Some(&hash_user_id(USER_ID)));
+    assert!(!fields.contains_key("failure"));
+}
+
+#[test]
+fn test_hash_user_id_is_stable_and_truncated() {
+    let hashed = hash_user_id(USER_ID);
+
+    assert_eq!(hashed, hash_user_id(USER_ID));
+    assert_ne!(hashed, hash_user_id("user-other"));
+    assert_eq!(hashed.len(), 16);
+    assert!(hashed.chars().all(|c| c.is_ascii_hexdigit()));
+}
+
+#[test]
+fn test_failure_category_never_includes_error_details() {
+    let not_found = CoreError::Authentication(AuthenticationError::user_not_found(IDENTIFIER));
+    let locked = CoreError::Authentication(AuthenticationError::account_locked(
+        "locked until 2030-01-01T00:00:00Z",
+    ));
+
+    assert_eq!(failure_category(&not_found), "invalid_credentials");
+    assert_eq!(failure_category(&locked), "locked");
+}
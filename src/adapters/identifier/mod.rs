@@ -0,0 +1,16 @@
+//! Identifier normalization adapters.
+//!
+//! This module provides concrete implementations of the `IdentifierNormalizer`
+//! port from the core domain.
+//!
+//! # Components
+//!
+//! - [`RuleBasedIdentifierNormalizer`]: Trimming, case folding, Unicode NFKC
+//!   and email canonicalization, each switchable
+
+pub mod rule_based_identifier_normalizer;
+
+pub use rule_based_identifier_normalizer::RuleBasedIdentifierNormalizer;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,113 @@
+//! Rule-based identifier normalizer.
+//!
+//! Applies a fixed sequence of rules, each of which can be switched off:
+//!
+//! 1. Unicode NFKC, so compatibility forms (full-width letters, ligatures)
+//!    compare equal to their plain counterparts
+//! 2. Lowercasing
+//! 3. Trimming surrounding whitespace (always on)
+//! 4. Email canonicalization: drop a `+tag` subaddress and lowercase the
+//!    domain, leaving the rest of the local part alone
+//!
+//! Email identifiers usually want all of them but the last; opaque
+//! usernames may be case-sensitive. See [`RuleBasedIdentifierNormalizer::email`]
+//! and [`RuleBasedIdentifierNormalizer::username`].
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::core::usecases::ports::IdentifierNormalizer;
+
+/// Identifier normalizer built from switchable rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleBasedIdentifierNormalizer {
+    lowercase: bool,
+    nfkc: bool,
+    canonicalize_email: bool,
+}
+
+impl RuleBasedIdentifierNormalizer {
+    /// Trim and lowercase.
+    pub fn new() -> Self {
+        Self {
+            lowercase: true,
+            nfkc: false,
+            canonicalize_email: false,
+        }
+    }
+
+    /// Rules for email identifiers: trim, NFKC and lowercase.
+    ///
+    /// Subaddresses are kept; enable them with
+    /// [`with_email_canonicalization`](Self::with_email_canonicalization).
+    pub fn email() -> Self {
+        Self::new().with_nfkc(true)
+    }
+
+    /// Rules for opaque usernames: trim and NFKC, case preserved.
+    pub fn username() -> Self {
+        Self::new().with_lowercase(false).with_nfkc(true)
+    }
+
+    /// Lowercase the identifier.
+    pub fn with_lowercase(mut self, enabled: bool) -> Self {
+        self.lowercase = enabled;
+        self
+    }
+
+    /// Apply Unicode NFKC normalization.
+    pub fn with_nfkc(mut self, enabled: bool) -> Self {
+        self.nfkc = enabled;
+        self
+    }
+
+    /// Drop `+tag` subaddresses and lowercase the domain of email identifiers.
+    ///
+    /// Identifiers without an `@` are left alone.
+    pub fn with_email_canonicalization(mut self, enabled: bool) -> Self {
+        self.canonicalize_email = enabled;
+        self
+    }
+}
+
+impl Default for RuleBasedIdentifierNormalizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Drop the `+tag` subaddress and lowercase the domain.
+fn canonicalize_email(identifier: &str) -> String {
+    let Some((local, domain)) = identifier.rsplit_once('@') else {
+        return identifier.to_string();
+    };
+
+    let local = match local.split_once('+') {
+        Some((base, _tag)) if !base.is_empty() => base,
+        _ => local,
+    };
+    let domain = domain.trim_end_matches('.').to_lowercase();
+
+    format!("{}@{}", local, domain)
+}
+
+impl IdentifierNormalizer for RuleBasedIdentifierNormalizer {
+    fn normalize(&self, identifier: &str) -> String {
+        let mut normalized = if self.nfkc {
+            identifier.nfkc().collect::<String>()
+        } else {
+            identifier.to_string()
+        };
+
+        if self.lowercase {
+            normalized = normalized.to_lowercase();
+        }
+
+        let normalized = normalized.trim();
+
+        if self.canonicalize_email {
+            canonicalize_email(normalized)
+        } else {
+            normalized.to_string()
+        }
+    }
+}
@@ -0,0 +1,3 @@
+//! Identifier normalizer tests.
+
+mod rule_based_identifier_normalizer_tests;
@@ -0,0 +1,56 @@
+//! Tests for RuleBasedIdentifierNormalizer.
+
+use crate::adapters::identifier::RuleBasedIdentifierNormalizer;
+use crate::core::usecases::ports::IdentifierNormalizer;
+
+#[test]
+fn default_trims_and_lowercases() {
+    let normalizer = RuleBasedIdentifierNormalizer::default();
+
+    assert_eq!(normalizer.normalize("  Foo@Bar.COM"), "foo@bar.com");
+    assert_eq!(normalizer.normalize("foo@bar.com\t\n"), "foo@bar.com");
+}
+
+#[test]
+fn normalization_is_idempotent() {
+    let normalizer = RuleBasedIdentifierNormalizer::email().with_email_canonicalization(true);
+
+    let once = normalizer.normalize("  Ｆｏｏ+news@Bar.COM. ");
+
+    assert_eq!(once, "foo@bar.com");
+    assert_eq!(normalizer.normalize(&once), once);
+}
+
+#[test]
+fn nfkc_folds_compatibility_forms() {
+    let normalizer = RuleBasedIdentifierNormalizer::email();
+
+    // Full-width letters and the "fi" ligature
+    assert_eq!(normalizer.normalize("Ｆｏｏ@bar.com"), "foo@bar.com");
+    assert_eq!(normalizer.normalize("\u{FB01}le@bar.com"), "file@bar.com");
+}
+
+#[test]
+fn nfkc_is_off_by_default() {
+    let normalizer = RuleBasedIdentifierNormalizer::new();
+
+    assert_eq!(normalizer.normalize("\u{FB01}le@bar.com"), "\u{FB01}le@bar.com");
+}
+
+#[test]
+fn username_rules_preserve_case() {
+    let normalizer = RuleBasedIdentifierNormalizer::username();
+
+    assert_eq!(normalizer.normalize("  JohnDoe "), "JohnDoe");
+}
+
+#[test]
+fn email_canonicalization_drops_subaddress_and_lowercases_domain() {
+    let normalizer = RuleBasedIdentifierNormalizer::username().with_email_canonicalization(true);
+
+    assert_eq!(normalizer.normalize("John.Doe+work@Example.COM"), "John.Doe@example.com");
+    // Nothing to drop
+    assert_eq!(normalizer.normalize("+tag@example.com"), "+tag@example.com");
+    // Not an email
+    assert_eq!(normalizer.normalize("john+doe"), "john+doe");
+}
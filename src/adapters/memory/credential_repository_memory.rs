@@ -0,0 +1,246 @@
+//! In-memory implementation of credential repository.
+
+use chrono::{DateTime, Utc};
+use futures::future::FutureExt;
+use uuid::Uuid;
+
+use crate::adapters::memory::store::MemoryStore;
+use crate::adapters::persistence::error::PersistenceError;
+use crate::core::credentials::{CredentialStatus, StoredCredential};
+use crate::core::usecases::ports::{CredentialRepository, LockoutState};
+
+/// In-memory counterpart of `CredentialRepositorySql`.
+///
+/// Reads and writes the same accounts as [`IdentityRepositoryMemory`] when
+/// built from the same [`MemoryStore`], as both SQL repositories share the
+/// `identity_credential` table.
+///
+/// Responsibilities:
+/// - Track failed attempts and lockout next to the password hash
+/// - Clear the lock whenever the counter is reset or the password changes
+/// - Track failed attempts and lockout per source address as well
+/// - Track credential status, last login, last failure and password history
+///
+/// [`IdentityRepositoryMemory`]: super::IdentityRepositoryMemory
+pub struct CredentialRepositoryMemory {
+    store: MemoryStore,
+}
+
+impl CredentialRepositoryMemory {
+    /// Create a repository over `store`.
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+
+    /// Set the credential lifecycle status.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Execution(ExecutionError::NotFound)` if the
+    /// user has no credential.
+    pub fn update_status(&self, user_id: &str, status: CredentialStatus) -> Result<(), PersistenceError> {
+        if self.store.update_account(user_id, |account| account.status = status) {
+            Ok(())
+        } else {
+            Err(PersistenceError::not_found("Credential"))
+        }
+    }
+}
+
+fn parse_rfc3339(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+impl CredentialRepository for CredentialRepositoryMemory {
+    fn get_by_user_id(&self, user_id: &str) -> futures::future::BoxFuture<'_, Option<StoredCredential>> {
+        let credential = Uuid::parse_str(user_id).ok().and_then(|user_id| {
+            self.store.accounts().get(&user_id).map(|account| {
+                StoredCredential::from_parts(
+                    account.password_hash.clone(),
+                    account.failed_attempts,
+                    account.locked_until.map(|dt| dt.to_rfc3339()),
+                )
+                .with_status(account.status.clone())
+                .with_last_login_at(account.last_login_at.map(|dt| dt.to_rfc3339()))
+                .with_last_failed_at(account.last_failed_at.map(|dt| dt.to_rfc3339()))
+            })
+        });
+        async move { credential }.boxed()
+    }
+
+    fn set_status(&self, user_id: &str, status: CredentialStatus) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        let result = self.update_status(user_id, status).map_err(|e| e.to_string());
+        async move { result }.boxed()
+    }
+
+    fn update_failed_attempts(&self, user_id: &str, attempts: u32) -> futures::future::BoxFuture<'_, ()> {
+        self.store.update_account(user_id, |account| {
+            account.failed_attempts = attempts;
+            if attempts == 0 {
+                // Reset to 0 on successful authentication also unlocks
+                account.locked_until = None;
+            }
+        });
+        async move {}.boxed()
+    }
+
+    fn lock_until(&self, user_id: &str, until: &str) -> futures::future::BoxFuture<'_, ()> {
+        if let Some(until) = parse_rfc3339(until) {
+            self.store.update_account(user_id, |account| account.locked_until = Some(until));
+        }
+        async move {}.boxed()
+    }
+
+    fn clear_lock(&self, user_id: &str) -> futures::future::BoxFuture<'_, ()> {
+        self.update_failed_attempts(user_id, 0)
+    }
+
+    fn unlock(&self, user_id: &str) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        let unlocked = self.store.update_account(user_id, |account| {
+            account.failed_attempts = 0;
+            account.locked_until = None;
+            account.source_lockouts.clear();
+        });
+        let result = if unlocked {
+            Ok(())
+        } else {
+            Err(PersistenceError::not_found("Credential").to_string())
+        };
+        async move { result }.boxed()
+    }
+
+    fn get_source_lockout(&self, user_id: &str, source_ip: &str) -> futures::future::BoxFuture<'_, LockoutState> {
+        let lockout = Uuid::parse_str(user_id)
+            .ok()
+            .and_then(|user_id| {
+                self.store.accounts().get(&user_id).and_then(|account| {
+                    account.source_lockouts.get(source_ip).map(|source| LockoutState {
+                        failed_attempts: source.failed_attempts,
+                        locked_until: source.locked_until.map(|dt| dt.to_rfc3339()),
+                        last_failed_at: source.last_failed_at.map(|dt| dt.to_rfc3339()),
+                    })
+                })
+            })
+            .unwrap_or_default();
+        async move { lockout }.boxed()
+    }
+
+    fn has_source_lockouts(&self, user_id: &str) -> futures::future::BoxFuture<'_, bool> {
+        let tracked = Uuid::parse_str(user_id)
+            .ok()
+            .and_then(|user_id| {
+                self.store.accounts().get(&user_id).map(|account| {
+                    account
+                        .source_lockouts
+                        .values()
+                        .any(|source| source.failed_attempts > 0 || source.locked_until.is_some())
+                })
+            })
+            .unwrap_or(false);
+        async move { tracked }.boxed()
+    }
+
+    fn update_source_failed_attempts(
+        &self,
+        user_id: &str,
+        source_ip: &str,
+        attempts: u32,
+    ) -> futures::future::BoxFuture<'_, ()> {
+        self.store.update_account(user_id, |account| {
+            if attempts == 0 {
+                // Nothing left to track for this source once it is reset
+                account.source_lockouts.remove(source_ip);
+            } else {
+                account.source_lockouts.entry(source_ip.to_string()).or_default().failed_attempts = attempts;
+            }
+        });
+        async move {}.boxed()
+    }
+
+    fn lock_source_until(&self, user_id: &str, source_ip: &str, until: &str) -> futures::future::BoxFuture<'_, ()> {
+        if let Some(until) = parse_rfc3339(until) {
+            self.store.update_account(user_id, |account| {
+                account.source_lockouts.entry(source_ip.to_string()).or_default().locked_until = Some(until);
+            });
+        }
+        async move {}.boxed()
+    }
+
+    fn record_successful_login(&self, user_id: &str, at: &str) -> futures::future::BoxFuture<'_, ()> {
+        if let Some(at) = parse_rfc3339(at) {
+            self.store.update_account(user_id, |account| account.last_login_at = Some(at));
+        }
+        async move {}.boxed()
+    }
+
+    fn record_failed_login(&self, user_id: &str, at: &str) -> futures::future::BoxFuture<'_, ()> {
+        if let Some(at) = parse_rfc3339(at) {
+            self.store.update_account(user_id, |account| account.last_failed_at = Some(at));
+        }
+        async move {}.boxed()
+    }
+
+    fn record_source_failed_login(&self, user_id: &str, source_ip: &str, at: &str) -> futures::future::BoxFuture<'_, ()> {
+        if let Some(at) = parse_rfc3339(at) {
+            self.store.update_account(user_id, |account| {
+                account.source_lockouts.entry(source_ip.to_string()).or_default().last_failed_at = Some(at);
+            });
+        }
+        async move {}.boxed()
+    }
+
+    fn update_password(&self, user_id: &str, new_credential: StoredCredential) -> futures::future::BoxFuture<'_, ()> {
+        self.store.update_account(user_id, |account| {
+            account.password_hash = new_credential.as_hash_str().to_string();
+            account.failed_attempts = 0;
+            account.locked_until = None;
+        });
+        async move {}.boxed()
+    }
+
+    fn password_history(&self, user_id: &str, limit: usize) -> futures::future::BoxFuture<'_, Vec<StoredCredential>> {
+        let history: Vec<StoredCredential> = Uuid::parse_str(user_id)
+            .ok()
+            .and_then(|user_id| {
+                self.store.accounts().get(&user_id).map(|account| {
+                    account
+                        .password_history
+                        .iter()
+                        .take(limit)
+                        .map(StoredCredential::from_hash)
+                        .collect()
+                })
+            })
+            .unwrap_or_default();
+        async move { history }.boxed()
+    }
+
+    fn push_password_history(
+        &self,
+        user_id: &str,
+        previous: StoredCredential,
+        depth: usize,
+    ) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        let pushed = self.store.update_account(user_id, |account| {
+            account.password_history.insert(0, previous.as_hash_str().to_string());
+            account.password_history.truncate(depth);
+        });
+        // The SQL history insert fails its foreign key for an unknown user
+        let result = if pushed {
+            Ok(())
+        } else {
+            Err(PersistenceError::not_found("Credential").to_string())
+        };
+        async move { result }.boxed()
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        async move {
+            // Credential state is initialized when identity is created
+            Ok(())
+        }
+        .boxed()
+    }
+}
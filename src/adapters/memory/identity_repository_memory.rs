@@ -0,0 +1,222 @@
+//! In-memory implementation of identity repository.
+
+use chrono::Utc;
+use futures::future::FutureExt;
+use uuid::Uuid;
+
+use crate::adapters::memory::store::{AccountRecord, MemoryStore};
+use crate::adapters::persistence::error::PersistenceError;
+use crate::core::identity::{UserIdentity, WorkspaceIdentity};
+use crate::core::usecases::ports::{BatchCreateOutcome, IdentityRepository, NewIdentity};
+
+/// In-memory counterpart of `IdentityRepositorySql`.
+///
+/// Responsibilities:
+/// - Retrieve live identities by identifier or user_id
+/// - Reject duplicate identifiers and user ids, with the SQL error messages
+/// - Create identities in batches, atomically or item by item
+/// - Tombstone identities, revoking their sessions in the same step
+/// - Resolve workspaces and check workspace membership
+///
+/// Does NOT:
+/// - Survive the process
+/// - Hash or verify passwords
+pub struct IdentityRepositoryMemory {
+    store: MemoryStore,
+}
+
+impl IdentityRepositoryMemory {
+    /// Create a repository over `store`.
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+
+    /// Insert one identity, enforcing the same uniqueness as the SQL schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Constraint` if the identifier belongs to a
+    /// live identity or the user id is already taken.
+    pub fn create_identity(&self, user_id: &Uuid, identifier: &str, password_hash: &str) -> Result<(), PersistenceError> {
+        let mut accounts = self.store.accounts_mut();
+        if let Some(conflict) = conflict(&accounts, user_id, identifier) {
+            return Err(conflict);
+        }
+        accounts.insert(*user_id, AccountRecord::new(identifier, password_hash));
+        Ok(())
+    }
+
+    /// Add `user_id` to the members of `workspace_id`, creating the
+    /// workspace if it does not exist yet.
+    pub fn add_workspace_member(&self, workspace_id: &str, user_id: &Uuid) {
+        self.store
+            .workspaces_mut()
+            .entry(workspace_id.to_string())
+            .or_default()
+            .insert(*user_id);
+    }
+
+    /// Create several identities, returning one outcome per input.
+    ///
+    /// In atomic mode the whole batch is checked under one lock before
+    /// anything is written, so either every identity is created or none is.
+    pub fn create_identities_batch(&self, identities: &[NewIdentity], atomic: bool) -> Vec<BatchCreateOutcome> {
+        if !atomic {
+            return identities
+                .iter()
+                .map(|identity| {
+                    batch_outcome(self.create_identity(&identity.user_id, &identity.identifier, &identity.password_hash))
+                })
+                .collect();
+        }
+
+        let mut accounts = self.store.accounts_mut();
+        let mut staged = accounts.clone();
+        for (index, identity) in identities.iter().enumerate() {
+            if let Some(conflict) = conflict(&staged, &identity.user_id, &identity.identifier) {
+                let mut outcomes = vec![BatchCreateOutcome::RolledBack; identities.len()];
+                outcomes[index] = batch_outcome(Err(conflict));
+                return outcomes;
+            }
+            staged.insert(identity.user_id, AccountRecord::new(&identity.identifier, &identity.password_hash));
+        }
+        *accounts = staged;
+
+        vec![BatchCreateOutcome::Created; identities.len()]
+    }
+
+    /// Tombstone an identity after revoking all of its sessions.
+    ///
+    /// Returns the number of sessions revoked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Execution(ExecutionError::NotFound)` if no live identity exists.
+    pub fn delete_identity_and_revoke_sessions(&self, user_id: &str) -> Result<u64, PersistenceError> {
+        let not_found = || PersistenceError::not_found("Identity");
+        let user_id = Uuid::parse_str(user_id).map_err(|_| not_found())?;
+
+        // Hold the account lock throughout, so no session can be created for
+        // the identity between revocation and tombstoning
+        let mut accounts = self.store.accounts_mut();
+        let account = accounts
+            .get_mut(&user_id)
+            .filter(|account| account.is_live())
+            .ok_or_else(not_found)?;
+
+        let now = Utc::now();
+        let mut revoked = 0;
+        for session in self.store.sessions_mut().values_mut() {
+            if session.row.user_id == user_id && session.row.revoked_at.is_none() {
+                session.row.revoked_at = Some(now);
+                session.row.updated_at = now;
+                revoked += 1;
+            }
+        }
+        account.deleted_at = Some(now);
+
+        Ok(revoked)
+    }
+
+    fn find_live(&self, matches: impl Fn(&Uuid, &AccountRecord) -> bool) -> Option<UserIdentity> {
+        self.store
+            .accounts()
+            .iter()
+            .find(|(user_id, account)| account.is_live() && matches(user_id, account))
+            .map(|(user_id, _)| UserIdentity::new(user_id.to_string()))
+    }
+}
+
+/// The constraint an insert of `(user_id, identifier)` would violate, if any.
+fn conflict(
+    accounts: &std::collections::HashMap<Uuid, AccountRecord>,
+    user_id: &Uuid,
+    identifier: &str,
+) -> Option<PersistenceError> {
+    let taken = accounts.contains_key(user_id)
+        || accounts
+            .values()
+            .any(|account| account.is_live() && account.identifier == identifier);
+    taken.then(|| PersistenceError::unique_violation("identifier already exists"))
+}
+
+fn batch_outcome(result: Result<(), PersistenceError>) -> BatchCreateOutcome {
+    match result {
+        Ok(()) => BatchCreateOutcome::Created,
+        Err(PersistenceError::Constraint(e)) => BatchCreateOutcome::Conflict(e.to_string()),
+        Err(e) => BatchCreateOutcome::Failed(e.to_string()),
+    }
+}
+
+impl IdentityRepository for IdentityRepositoryMemory {
+    fn find_by_identifier(&self, identifier: &str) -> futures::future::BoxFuture<'_, Option<UserIdentity>> {
+        let found = self.find_live(|_, account| account.identifier == identifier);
+        async move { found }.boxed()
+    }
+
+    fn find_by_id(&self, id: &str) -> futures::future::BoxFuture<'_, Option<UserIdentity>> {
+        let found = Uuid::parse_str(id)
+            .ok()
+            .and_then(|id| self.find_live(|user_id, _| *user_id == id));
+        async move { found }.boxed()
+    }
+
+    fn find_identifier_by_id(&self, id: &str) -> futures::future::BoxFuture<'_, Option<String>> {
+        let found = Uuid::parse_str(id).ok().and_then(|id| {
+            self.store
+                .accounts()
+                .get(&id)
+                .filter(|account| account.is_live())
+                .map(|account| account.identifier.clone())
+        });
+        async move { found }.boxed()
+    }
+
+    fn exists(&self, identifier: &str) -> futures::future::BoxFuture<'_, bool> {
+        let exists = self.find_live(|_, account| account.identifier == identifier).is_some();
+        async move { exists }.boxed()
+    }
+
+    fn create(&self, user_id: &uuid::Uuid, identifier: &str, password_hash: &str, _salt: &str, _algorithm: &str, _iterations: u32) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        let result = self
+            .create_identity(user_id, identifier, password_hash)
+            .map_err(|e| e.to_string());
+        async move { result }.boxed()
+    }
+
+    fn create_batch(
+        &self,
+        identities: Vec<NewIdentity>,
+        atomic: bool,
+    ) -> futures::future::BoxFuture<'_, Result<Vec<BatchCreateOutcome>, String>> {
+        let outcomes = self.create_identities_batch(&identities, atomic);
+        async move { Ok(outcomes) }.boxed()
+    }
+
+    fn find_workspace_by_id(&self, workspace_id: &str) -> futures::future::BoxFuture<'_, Option<WorkspaceIdentity>> {
+        let found = self
+            .store
+            .workspaces()
+            .contains_key(workspace_id)
+            .then(|| WorkspaceIdentity::new(workspace_id));
+        async move { found }.boxed()
+    }
+
+    fn is_workspace_member(&self, user_id: &str, workspace_id: &str) -> futures::future::BoxFuture<'_, bool> {
+        let member = Uuid::parse_str(user_id).is_ok_and(|user_id| {
+            self.store
+                .workspaces()
+                .get(workspace_id)
+                .is_some_and(|members| members.contains(&user_id))
+        });
+        async move { member }.boxed()
+    }
+
+    fn soft_delete(&self, user_id: &str) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        let result = self
+            .delete_identity_and_revoke_sessions(user_id)
+            .map(|_| ())
+            .map_err(|e| e.to_string());
+        async move { result }.boxed()
+    }
+}
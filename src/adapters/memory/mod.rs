@@ -0,0 +1,32 @@
+//! In-memory repository implementations.
+//!
+//! Thread-safe stand-ins for the SQL repositories, for exercising the full
+//! stack (use cases, handlers, router) without Postgres. They follow the SQL
+//! versions for every operation they implement: identifiers are unique among
+//! live identities, lockout state lives next to the password hash, and
+//! sessions expire, rotate and revoke the same way, denied access tokens
+//! and opaque tokens lapse at their expiry, and the token watermark only
+//! moves forward.
+//!
+//! Only compiled for tests or with the `test-support` feature. Nothing here
+//! is durable.
+
+mod store;
+
+pub mod credential_repository_memory;
+pub mod identity_repository_memory;
+pub mod opaque_token_store_memory;
+pub mod session_repository_memory;
+pub mod token_deny_list_memory;
+pub mod token_watermark_store_memory;
+
+pub use credential_repository_memory::CredentialRepositoryMemory;
+pub use identity_repository_memory::IdentityRepositoryMemory;
+pub use opaque_token_store_memory::OpaqueTokenStoreMemory;
+pub use session_repository_memory::SessionRepositoryMemory;
+pub use token_deny_list_memory::TokenDenyListMemory;
+pub use token_watermark_store_memory::TokenWatermarkStoreMemory;
+pub use store::MemoryStore;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,67 @@
+//! In-memory implementation of the opaque token store.
+
+use chrono::Utc;
+use futures::future::FutureExt;
+
+use crate::adapters::memory::store::MemoryStore;
+use crate::core::error::CoreError;
+use crate::core::usecases::ports::{OpaqueTokenRecord, OpaqueTokenStore};
+
+/// In-memory counterpart of `OpaqueTokenStoreSql`.
+///
+/// Responsibilities:
+/// - Hold one record per token hash
+/// - Ignore records past their expiry, like the SQL lookup does
+/// - Drop lapsed records when purged
+pub struct OpaqueTokenStoreMemory {
+    store: MemoryStore,
+}
+
+impl OpaqueTokenStoreMemory {
+    /// Create a token store over `store`.
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+
+    /// Number of records held, lapsed or not.
+    pub fn len(&self) -> usize {
+        self.store.opaque_tokens().len()
+    }
+
+    /// Whether no record is held.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl OpaqueTokenStore for OpaqueTokenStoreMemory {
+    fn put(&self, record: OpaqueTokenRecord) -> futures::future::BoxFuture<'_, Result<(), CoreError>> {
+        self.store.opaque_tokens_mut().insert(record.token_hash.clone(), record);
+        async move { Ok(()) }.boxed()
+    }
+
+    fn get(&self, token_hash: &str) -> futures::future::BoxFuture<'_, Option<OpaqueTokenRecord>> {
+        let now = Utc::now().timestamp();
+        let record = self
+            .store
+            .opaque_tokens()
+            .get(token_hash)
+            .filter(|record| record.expires_at > now)
+            .cloned();
+        async move { record }.boxed()
+    }
+
+    fn remove(&self, token_hash: &str) -> futures::future::BoxFuture<'_, bool> {
+        let removed = self.store.opaque_tokens_mut().remove(token_hash).is_some();
+        async move { removed }.boxed()
+    }
+
+    fn purge_expired(&self) -> futures::future::BoxFuture<'_, Result<u64, CoreError>> {
+        let now = Utc::now().timestamp();
+        let mut tokens = self.store.opaque_tokens_mut();
+        let before = tokens.len();
+        tokens.retain(|_, record| record.expires_at > now);
+        let purged = (before - tokens.len()) as u64;
+        async move { Ok(purged) }.boxed()
+    }
+}
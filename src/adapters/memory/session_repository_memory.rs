@@ -0,0 +1,389 @@
+//! In-memory implementation of session repository.
+
+use chrono::{DateTime, Utc};
+use futures::future::FutureExt;
+use uuid::Uuid;
+
+use crate::adapters::memory::store::{MemoryStore, StoredSession};
+use crate::adapters::persistence::error::PersistenceError;
+use crate::adapters::persistence::models::SessionRow;
+use crate::adapters::persistence::repositories::session_repository_sql::{
+    active_session_summaries, client_details_from_metadata, client_from_metadata, expiry_from_metadata, session_page,
+};
+use crate::core::error::{CoreError, InvariantError};
+use crate::core::identity::UserIdentity;
+use crate::core::usecases::ports::{
+    SessionClientDetails, SessionCursor, SessionPage, SessionRepository, SessionSummary,
+};
+use crate::core::usecases::session_repository::Session;
+
+/// In-memory counterpart of `SessionRepositorySql`.
+///
+/// Sessions may only be created for accounts in the same [`MemoryStore`],
+/// mirroring the foreign key from `auth_session` to `identity_credential`.
+///
+/// Responsibilities:
+/// - Create sessions with the lifetime and client given in their metadata
+/// - Find sessions that are neither revoked nor expired
+/// - Rotate refresh token hashes with compare-and-swap semantics, keeping
+///   every rotated-out hash
+/// - Hold the successor of a rotation for its grace window
+/// - Revoke, list, page and purge sessions like the SQL queries do
+pub struct SessionRepositoryMemory {
+    store: MemoryStore,
+}
+
+impl SessionRepositoryMemory {
+    /// Create a repository over `store`.
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+
+    /// Insert a session row.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Constraint` if the session_id is not unique,
+    /// or `PersistenceError::Execution` if the id is not a UUID or the user
+    /// has no account.
+    pub fn insert_session(
+        &self,
+        session_id: &str,
+        user_id: &str,
+        refresh_token_hash: &str,
+        expires_at: DateTime<Utc>,
+        ip_address: &str,
+        user_agent: &str,
+    ) -> Result<(), PersistenceError> {
+        self.insert_enriched_session(
+            session_id,
+            user_id,
+            refresh_token_hash,
+            expires_at,
+            ip_address,
+            user_agent,
+            &SessionClientDetails::default(),
+        )
+    }
+
+    /// Insert a session row carrying enriched client details.
+    ///
+    /// # Errors
+    ///
+    /// As for [`insert_session`](Self::insert_session).
+    #[allow(clippy::too_many_arguments)]
+    pub fn insert_enriched_session(
+        &self,
+        session_id: &str,
+        user_id: &str,
+        refresh_token_hash: &str,
+        expires_at: DateTime<Utc>,
+        ip_address: &str,
+        user_agent: &str,
+        details: &SessionClientDetails,
+    ) -> Result<(), PersistenceError> {
+        let invalid = |what: &str| PersistenceError::query_failed(format!("failed to create session: invalid {}", what));
+        let id = Uuid::parse_str(session_id).map_err(|_| invalid("session id"))?;
+        let user_id = Uuid::parse_str(user_id).map_err(|_| invalid("user id"))?;
+
+        // Lock order is accounts, then sessions, as in soft delete
+        let accounts = self.store.accounts();
+        if !accounts.contains_key(&user_id) {
+            return Err(PersistenceError::not_found("Identity"));
+        }
+
+        let mut sessions = self.store.sessions_mut();
+        if sessions.contains_key(&id) {
+            return Err(PersistenceError::unique_violation("session_id already exists"));
+        }
+
+        let now = Utc::now();
+        sessions.insert(
+            id,
+            StoredSession {
+                row: SessionRow {
+                    id,
+                    user_id,
+                    refresh_token_hash: refresh_token_hash.to_string(),
+                    created_at: now,
+                    expires_at,
+                    revoked_at: None,
+                    ip_address: ip_address.to_string(),
+                    user_agent: user_agent.to_string(),
+                    device_type: details.device_type.clone(),
+                    os: details.os.clone(),
+                    browser: details.browser.clone(),
+                    geo_country: details.country.clone(),
+                    geo_region: details.region.clone(),
+                    geo_city: details.city.clone(),
+                    updated_at: now,
+                },
+                previous_refresh_token_hash: None,
+                rotation_successor: None,
+                rotated_refresh_token_hashes: Vec::new(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Find a session that is neither revoked nor expired.
+    fn find_active(&self, matches: impl Fn(&StoredSession) -> bool) -> Option<SessionRow> {
+        let now = Utc::now();
+        self.store
+            .sessions()
+            .values()
+            .find(|session| session.row.is_active(now) && matches(session))
+            .map(|session| session.row.clone())
+    }
+
+    /// Revoke every matching session that is not already revoked.
+    ///
+    /// Returns the number of sessions revoked by this call.
+    fn revoke_where(&self, matches: impl Fn(&SessionRow) -> bool) -> u64 {
+        let now = Utc::now();
+        let mut revoked = 0;
+        for session in self.store.sessions_mut().values_mut() {
+            if session.row.revoked_at.is_none() && matches(&session.row) {
+                session.row.revoked_at = Some(now);
+                session.row.updated_at = now;
+                revoked += 1;
+            }
+        }
+        revoked
+    }
+
+    /// Rows of one user, newest first, in `(created_at, id)` descending order.
+    fn rows_for_user(&self, user_id: &str) -> Vec<SessionRow> {
+        let Ok(user_id) = Uuid::parse_str(user_id) else {
+            return Vec::new();
+        };
+        let mut rows: Vec<SessionRow> = self
+            .store
+            .sessions()
+            .values()
+            .filter(|session| session.row.user_id == user_id)
+            .map(|session| session.row.clone())
+            .collect();
+        rows.sort_by_key(|row| std::cmp::Reverse((row.created_at, row.id)));
+        rows
+    }
+
+    /// Delete expired sessions and return how many were removed.
+    pub fn remove_expired(&self) -> u64 {
+        let now = Utc::now();
+        let mut sessions = self.store.sessions_mut();
+        let before = sessions.len();
+        sessions.retain(|_, session| session.row.expires_at >= now);
+        (before - sessions.len()) as u64
+    }
+}
+
+fn parse_id(session_id: &str) -> Option<Uuid> {
+    Uuid::parse_str(session_id).ok()
+}
+
+impl SessionRepository for SessionRepositoryMemory {
+    fn create_session(
+        &self,
+        session_id: &str,
+        user: &UserIdentity,
+        refresh_token_hash: &str,
+        metadata: &str,
+    ) -> futures::future::BoxFuture<'_, Result<(), CoreError>> {
+        let (ip_address, user_agent) = client_from_metadata(metadata);
+        let details = client_details_from_metadata(metadata);
+        let expires_at = expiry_from_metadata(metadata, Utc::now());
+
+        let result = self
+            .insert_enriched_session(
+                session_id,
+                &user.id,
+                refresh_token_hash,
+                expires_at,
+                &ip_address,
+                &user_agent,
+                &details,
+            )
+            .map_err(|e| InvariantError::dependency_unavailable("session store", e.to_string()).into());
+        async move { result }.boxed()
+    }
+
+    fn find_by_refresh_token_hash(&self, hash: &str) -> futures::future::BoxFuture<'_, Option<Session>> {
+        let found = self
+            .find_active(|session| session.row.refresh_token_hash == hash)
+            .map(|_| Session {});
+        async move { found }.boxed()
+    }
+
+    fn find_by_id(&self, session_id: &str) -> futures::future::BoxFuture<'_, Option<Session>> {
+        let found = self.find_summary_by_id(session_id);
+        async move { found.await.map(|_| Session {}) }.boxed()
+    }
+
+    fn find_summary_by_id(&self, session_id: &str) -> futures::future::BoxFuture<'_, Option<SessionSummary>> {
+        let summary = parse_id(session_id)
+            .and_then(|id| self.find_active(|session| session.row.id == id))
+            .and_then(|row| active_session_summaries(std::slice::from_ref(&row), Utc::now()).pop());
+        async move { summary }.boxed()
+    }
+
+    fn rotate_refresh_token(
+        &self,
+        session_id: &str,
+        current_hash: &str,
+        new_hash: &str,
+    ) -> futures::future::BoxFuture<'_, bool> {
+        let rotated = parse_id(session_id).is_some_and(|id| {
+            let mut sessions = self.store.sessions_mut();
+            match sessions.get_mut(&id) {
+                Some(session)
+                    if session.row.refresh_token_hash == current_hash && session.row.revoked_at.is_none() =>
+                {
+                    let previous = std::mem::replace(&mut session.row.refresh_token_hash, new_hash.to_string());
+                    session.rotated_refresh_token_hashes.push(previous.clone());
+                    session.previous_refresh_token_hash = Some(previous);
+                    session.rotation_successor = None;
+                    session.row.updated_at = Utc::now();
+                    true
+                }
+                _ => false,
+            }
+        });
+        async move { rotated }.boxed()
+    }
+
+    fn is_rotated_refresh_token(&self, hash: &str) -> futures::future::BoxFuture<'_, bool> {
+        let rotated = self
+            .store
+            .sessions()
+            .values()
+            .any(|session| session.rotated_refresh_token_hashes.iter().any(|rotated| rotated == hash));
+        async move { rotated }.boxed()
+    }
+
+    fn record_rotation_successor(
+        &self,
+        previous_hash: &str,
+        successor_hash: &str,
+        until: DateTime<Utc>,
+    ) -> futures::future::BoxFuture<'_, ()> {
+        let mut sessions = self.store.sessions_mut();
+        if let Some(session) = sessions
+            .values_mut()
+            .find(|session| session.previous_refresh_token_hash.as_deref() == Some(previous_hash))
+        {
+            session.rotation_successor = Some((successor_hash.to_string(), until));
+        }
+        async move {}.boxed()
+    }
+
+    fn rotation_successor(&self, previous_hash: &str, now: DateTime<Utc>) -> futures::future::BoxFuture<'_, Option<String>> {
+        let successor = self
+            .store
+            .sessions()
+            .values()
+            .find(|session| session.previous_refresh_token_hash.as_deref() == Some(previous_hash))
+            .and_then(|session| session.rotation_successor.clone())
+            .filter(|(_, until)| now < *until)
+            .map(|(successor_hash, _)| successor_hash);
+        async move { successor }.boxed()
+    }
+
+    fn reissue_rotation_successor(
+        &self,
+        previous_hash: &str,
+        new_hash: &str,
+        now: DateTime<Utc>,
+    ) -> futures::future::BoxFuture<'_, bool> {
+        let mut sessions = self.store.sessions_mut();
+        let reissued = match sessions
+            .values_mut()
+            .find(|session| session.previous_refresh_token_hash.as_deref() == Some(previous_hash))
+        {
+            Some(session) if session.row.revoked_at.is_none() => match session.rotation_successor.take() {
+                Some((successor_hash, until))
+                    if now < until && successor_hash == session.row.refresh_token_hash =>
+                {
+                    let displaced = std::mem::replace(&mut session.row.refresh_token_hash, new_hash.to_string());
+                    session.rotated_refresh_token_hashes.push(displaced);
+                    session.row.updated_at = Utc::now();
+                    true
+                }
+                successor => {
+                    session.rotation_successor = successor;
+                    false
+                }
+            },
+            _ => false,
+        };
+        async move { reissued }.boxed()
+    }
+
+    fn revoked_at(&self, session_id: &str) -> futures::future::BoxFuture<'_, Option<DateTime<Utc>>> {
+        let revoked_at = parse_id(session_id)
+            .and_then(|id| self.store.sessions().get(&id).and_then(|session| session.row.revoked_at));
+        async move { revoked_at }.boxed()
+    }
+
+    fn expires_at(&self, session_id: &str) -> futures::future::BoxFuture<'_, Option<DateTime<Utc>>> {
+        let expires_at = parse_id(session_id)
+            .and_then(|id| self.store.sessions().get(&id).map(|session| session.row.expires_at));
+        async move { expires_at }.boxed()
+    }
+
+    fn revoke_session(&self, session_id: &str) -> futures::future::BoxFuture<'_, ()> {
+        if let Some(id) = parse_id(session_id) {
+            self.revoke_where(|row| row.id == id);
+        }
+        async move {}.boxed()
+    }
+
+    fn revoke_all_for_user(&self, user_id: &str) -> futures::future::BoxFuture<'_, u64> {
+        let revoked = Uuid::parse_str(user_id)
+            .map(|user_id| self.revoke_where(|row| row.user_id == user_id))
+            .unwrap_or(0);
+        async move { revoked }.boxed()
+    }
+
+    fn revoke_all_for_user_except(&self, user_id: &str, keep_session_id: &str) -> futures::future::BoxFuture<'_, u64> {
+        let keep = parse_id(keep_session_id);
+        let revoked = Uuid::parse_str(user_id)
+            .map(|user_id| self.revoke_where(|row| row.user_id == user_id && Some(row.id) != keep))
+            .unwrap_or(0);
+        async move { revoked }.boxed()
+    }
+
+    fn list_active_for_user(&self, user_id: &str) -> futures::future::BoxFuture<'_, Vec<SessionSummary>> {
+        let summaries = active_session_summaries(&self.rows_for_user(user_id), Utc::now());
+        async move { summaries }.boxed()
+    }
+
+    fn list_for_user_paginated(
+        &self,
+        user_id: &str,
+        cursor: Option<&SessionCursor>,
+        limit: usize,
+    ) -> futures::future::BoxFuture<'_, Result<SessionPage, CoreError>> {
+        let after = cursor.map(|cursor| (cursor.created_at, parse_id(&cursor.session_id).unwrap_or_default()));
+        // One extra row tells whether another page follows
+        let rows: Vec<SessionRow> = self
+            .rows_for_user(user_id)
+            .into_iter()
+            .filter(|row| after.is_none_or(|after| (row.created_at, row.id) < after))
+            .take(limit.saturating_add(1))
+            .collect();
+        let page = session_page(&rows, limit, Utc::now());
+        async move { Ok(page) }.boxed()
+    }
+
+    fn delete_expired(&self) -> futures::future::BoxFuture<'_, ()> {
+        self.remove_expired();
+        async move {}.boxed()
+    }
+
+    fn purge_expired(&self) -> futures::future::BoxFuture<'_, Result<u64, String>> {
+        let purged = self.remove_expired();
+        async move { Ok(purged) }.boxed()
+    }
+}
@@ -0,0 +1,165 @@
+//! Shared state behind the in-memory repositories.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::adapters::persistence::models::SessionRow;
+use crate::core::credentials::CredentialStatus;
+use crate::core::usecases::ports::OpaqueTokenRecord;
+
+/// One account: identity and credential state together, like a row of
+/// `identity_credential`.
+#[derive(Debug, Clone)]
+pub(crate) struct AccountRecord {
+    pub identifier: String,
+    pub password_hash: String,
+    pub failed_attempts: u32,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub status: CredentialStatus,
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub last_failed_at: Option<DateTime<Utc>>,
+    /// Previous password hashes, newest first
+    pub password_history: Vec<String>,
+    /// Lockout state per source address, like `credential_source_lockout`
+    pub source_lockouts: HashMap<String, SourceLockoutRecord>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl AccountRecord {
+    pub(crate) fn new(identifier: &str, password_hash: &str) -> Self {
+        Self {
+            identifier: identifier.to_string(),
+            password_hash: password_hash.to_string(),
+            failed_attempts: 0,
+            locked_until: None,
+            status: CredentialStatus::Active,
+            last_login_at: None,
+            last_failed_at: None,
+            password_history: Vec::new(),
+            source_lockouts: HashMap::new(),
+            deleted_at: None,
+        }
+    }
+
+    pub(crate) fn is_live(&self) -> bool {
+        self.deleted_at.is_none()
+    }
+}
+
+/// Failed attempts and lock counted for one source address of an account.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SourceLockoutRecord {
+    pub failed_attempts: u32,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub last_failed_at: Option<DateTime<Utc>>,
+}
+
+/// One session: an `auth_session` row plus the hash it was rotated from.
+#[derive(Debug, Clone)]
+pub(crate) struct StoredSession {
+    pub row: SessionRow,
+    pub previous_refresh_token_hash: Option<String>,
+    /// Hash of the refresh token the previous hash was rotated to, and
+    /// until when a retry with the previous token may replace it
+    pub rotation_successor: Option<(String, DateTime<Utc>)>,
+    /// Every hash rotated out of this session or displaced by a retry
+    /// within the grace window, oldest first
+    pub rotated_refresh_token_hashes: Vec<String>,
+}
+
+/// Accounts, sessions and token state shared by the in-memory repositories.
+///
+/// Cloning is cheap; all clones see the same data. Build every repository
+/// of one test from the same store so that, as with one database, a
+/// soft-deleted identity takes its sessions with it.
+///
+/// A poisoned lock is recovered rather than propagated, so one panicking
+/// test cannot take down every other test sharing the store.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    accounts: Arc<RwLock<HashMap<Uuid, AccountRecord>>>,
+    sessions: Arc<RwLock<HashMap<Uuid, StoredSession>>>,
+    /// Denied access token jti to the expiry of its token
+    denied_tokens: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
+    /// Opaque token hash to its record
+    opaque_tokens: Arc<RwLock<HashMap<String, OpaqueTokenRecord>>>,
+    /// Earliest issue time still accepted for access tokens
+    token_watermark: Arc<RwLock<Option<DateTime<Utc>>>>,
+    /// Workspace id to the user ids of its members
+    workspaces: Arc<RwLock<HashMap<String, HashSet<Uuid>>>>,
+}
+
+impl MemoryStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn accounts(&self) -> RwLockReadGuard<'_, HashMap<Uuid, AccountRecord>> {
+        self.accounts.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn accounts_mut(&self) -> RwLockWriteGuard<'_, HashMap<Uuid, AccountRecord>> {
+        self.accounts.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn sessions(&self) -> RwLockReadGuard<'_, HashMap<Uuid, StoredSession>> {
+        self.sessions.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn sessions_mut(&self) -> RwLockWriteGuard<'_, HashMap<Uuid, StoredSession>> {
+        self.sessions.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn denied_tokens(&self) -> RwLockReadGuard<'_, HashMap<String, DateTime<Utc>>> {
+        self.denied_tokens.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn denied_tokens_mut(&self) -> RwLockWriteGuard<'_, HashMap<String, DateTime<Utc>>> {
+        self.denied_tokens.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn opaque_tokens(&self) -> RwLockReadGuard<'_, HashMap<String, OpaqueTokenRecord>> {
+        self.opaque_tokens.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn opaque_tokens_mut(&self) -> RwLockWriteGuard<'_, HashMap<String, OpaqueTokenRecord>> {
+        self.opaque_tokens.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn token_watermark(&self) -> RwLockReadGuard<'_, Option<DateTime<Utc>>> {
+        self.token_watermark.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn token_watermark_mut(&self) -> RwLockWriteGuard<'_, Option<DateTime<Utc>>> {
+        self.token_watermark.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn workspaces(&self) -> RwLockReadGuard<'_, HashMap<String, HashSet<Uuid>>> {
+        self.workspaces.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn workspaces_mut(&self) -> RwLockWriteGuard<'_, HashMap<String, HashSet<Uuid>>> {
+        self.workspaces.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Apply `change` to the account of `user_id`, deleted or not.
+    ///
+    /// Returns `false` if there is no such account, like an `UPDATE` that
+    /// matched no row.
+    pub(crate) fn update_account(&self, user_id: &str, change: impl FnOnce(&mut AccountRecord)) -> bool {
+        let Ok(user_id) = Uuid::parse_str(user_id) else {
+            return false;
+        };
+        match self.accounts_mut().get_mut(&user_id) {
+            Some(account) => {
+                change(account);
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Tests for CredentialRepositoryMemory.
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::adapters::memory::{CredentialRepositoryMemory, IdentityRepositoryMemory, MemoryStore};
+use crate::core::credentials::{CredentialStatus, StoredCredential};
+use crate::core::usecases::ports::{CredentialRepository, IdentityRepository};
+
+async fn setup() -> (CredentialRepositoryMemory, String) {
+    let store = MemoryStore::new();
+    let user_id = Uuid::new_v4();
+    IdentityRepositoryMemory::new(store.clone())
+        .create(&user_id, "alice@example.com", "original-hash", "", "", 0)
+        .await
+        .unwrap();
+    (CredentialRepositoryMemory::new(store), user_id.to_string())
+}
+
+#[tokio::test]
+async fn credential_is_created_with_the_identity() {
+    let (repo, user_id) = setup().await;
+
+    let credential = repo.get_by_user_id(&user_id).await.unwrap();
+
+    assert_eq!(credential.as_hash_str(), "original-hash");
+    assert_eq!(credential.failed_attempts, 0);
+    assert!(credential.locked_until.is_none());
+    assert!(credential.status.is_active());
+}
+
+#[tokio::test]
+async fn resetting_failed_attempts_clears_the_lock() {
+    let (repo, user_id) = setup().await;
+    let until = (Utc::now() + Duration::minutes(30)).to_rfc3339();
+
+    repo.update_failed_attempts(&user_id, 5).await;
+    repo.lock_until(&user_id, &until).await;
+    let locked = repo.get_by_user_id(&user_id).await.unwrap();
+    assert_eq!(locked.failed_attempts, 5);
+    assert!(locked.locked_until.is_some());
+
+    repo.update_failed_attempts(&user_id, 0).await;
+    let unlocked = repo.get_by_user_id(&user_id).await.unwrap();
+    assert_eq!(unlocked.failed_attempts, 0);
+    assert!(unlocked.locked_until.is_none());
+}
+
+#[tokio::test]
+async fn invalid_lock_timestamp_is_ignored() {
+    let (repo, user_id) = setup().await;
+
+    repo.lock_until(&user_id, "not a timestamp").await;
+
+    assert!(repo.get_by_user_id(&user_id).await.unwrap().locked_until.is_none());
+}
+
+#[tokio::test]
+async fn update_password_replaces_hash_and_unlocks() {
+    let (repo, user_id) = setup().await;
+    repo.update_failed_attempts(&user_id, 3).await;
+    repo.lock_until(&user_id, &(Utc::now() + Duration::minutes(5)).to_rfc3339()).await;
+
+    repo.update_password(&user_id, StoredCredential::from_hash("new-hash")).await;
+
+    let credential = repo.get_by_user_id(&user_id).await.unwrap();
+    assert_eq!(credential.as_hash_str(), "new-hash");
+    assert_eq!(credential.failed_attempts, 0);
+    assert!(credential.locked_until.is_none());
+}
+
+#[tokio::test]
+async fn password_history_keeps_newest_entries() {
+    let (repo, user_id) = setup().await;
+
+    for hash in ["first", "second", "third"] {
+        repo.push_password_history(&user_id, StoredCredential::from_hash(hash), 2)
+            .await
+            .unwrap();
+    }
+
+    let history: Vec<String> = repo
+        .password_history(&user_id, 5)
+        .await
+        .iter()
+        .map(|credential| credential.as_hash_str().to_string())
+        .collect();
+    assert_eq!(history, vec!["third", "second"]);
+}
+
+#[tokio::test]
+async fn set_status_on_unknown_user_is_not_found() {
+    let (repo, user_id) = setup().await;
+    let status = CredentialStatus::Revoked { revoked_at: None };
+
+    repo.set_status(&user_id, status.clone()).await.unwrap();
+    assert_eq!(repo.get_status(&user_id).await, status);
+
+    assert!(repo
+        .set_status(&Uuid::new_v4().to_string(), CredentialStatus::Active)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn unlock_clears_failed_attempts_and_lock() {
+    let (repo, user_id) = setup().await;
+    repo.update_failed_attempts(&user_id, 5).await;
+    repo.lock_until(&user_id, &(Utc::now() + Duration::minutes(30)).to_rfc3339()).await;
+
+    repo.unlock(&user_id).await.unwrap();
+
+    let credential = repo.get_by_user_id(&user_id).await.unwrap();
+    assert_eq!(credential.failed_attempts, 0);
+    assert!(credential.locked_until.is_none());
+}
+
+#[tokio::test]
+async fn unlock_is_idempotent() {
+    let (repo, user_id) = setup().await;
+
+    repo.unlock(&user_id).await.unwrap();
+    repo.unlock(&user_id).await.unwrap();
+
+    let credential = repo.get_by_user_id(&user_id).await.unwrap();
+    assert_eq!(credential.failed_attempts, 0);
+    assert!(credential.locked_until.is_none());
+}
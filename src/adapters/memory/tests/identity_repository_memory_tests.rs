@@ -0,0 +1,107 @@
+//! Tests for IdentityRepositoryMemory.
+
+use uuid::Uuid;
+
+use crate::adapters::memory::{IdentityRepositoryMemory, MemoryStore, SessionRepositoryMemory};
+use crate::core::identity::UserIdentity;
+use crate::core::usecases::ports::{BatchCreateOutcome, IdentityRepository, NewIdentity, SessionRepository};
+
+fn new_identity(identifier: &str) -> NewIdentity {
+    NewIdentity {
+        user_id: Uuid::new_v4(),
+        identifier: identifier.to_string(),
+        password_hash: "hash".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn create_then_find_by_identifier_and_id() {
+    let repo = IdentityRepositoryMemory::new(MemoryStore::new());
+    let user_id = Uuid::new_v4();
+
+    repo.create(&user_id, "alice@example.com", "hash", "", "", 0).await.unwrap();
+
+    let by_identifier = repo.find_by_identifier("alice@example.com").await.unwrap();
+    assert_eq!(by_identifier.id, user_id.to_string());
+    assert!(repo.find_by_id(&user_id.to_string()).await.is_some());
+    assert!(repo.exists("alice@example.com").await);
+    assert!(!repo.exists("bob@example.com").await);
+    assert_eq!(
+        repo.find_identifier_by_id(&user_id.to_string()).await.as_deref(),
+        Some("alice@example.com")
+    );
+}
+
+#[tokio::test]
+async fn duplicate_identifier_is_a_unique_violation() {
+    let repo = IdentityRepositoryMemory::new(MemoryStore::new());
+    repo.create(&Uuid::new_v4(), "alice@example.com", "hash", "", "", 0).await.unwrap();
+
+    let err = repo
+        .create(&Uuid::new_v4(), "alice@example.com", "hash", "", "", 0)
+        .await
+        .unwrap_err();
+
+    assert!(err.contains("identifier already exists"));
+}
+
+#[tokio::test]
+async fn atomic_batch_rolls_back_on_conflict() {
+    let repo = IdentityRepositoryMemory::new(MemoryStore::new());
+    repo.create(&Uuid::new_v4(), "taken@example.com", "hash", "", "", 0).await.unwrap();
+
+    let outcomes = repo
+        .create_batch(
+            vec![new_identity("first@example.com"), new_identity("taken@example.com")],
+            true,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(outcomes[0], BatchCreateOutcome::RolledBack);
+    assert!(matches!(outcomes[1], BatchCreateOutcome::Conflict(_)));
+    assert!(!repo.exists("first@example.com").await);
+}
+
+#[tokio::test]
+async fn non_atomic_batch_reports_each_item() {
+    let repo = IdentityRepositoryMemory::new(MemoryStore::new());
+
+    let outcomes = repo
+        .create_batch(
+            vec![new_identity("same@example.com"), new_identity("same@example.com")],
+            false,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(outcomes[0], BatchCreateOutcome::Created);
+    assert!(matches!(outcomes[1], BatchCreateOutcome::Conflict(_)));
+}
+
+#[tokio::test]
+async fn soft_delete_hides_identity_and_revokes_its_sessions() {
+    let store = MemoryStore::new();
+    let identities = IdentityRepositoryMemory::new(store.clone());
+    let sessions = SessionRepositoryMemory::new(store);
+    let user_id = Uuid::new_v4();
+    let session_id = Uuid::new_v4().to_string();
+    identities.create(&user_id, "alice@example.com", "hash", "", "", 0).await.unwrap();
+    sessions
+        .create_session(&session_id, &UserIdentity::new(user_id.to_string()), "refresh-hash", "{}")
+        .await
+        .unwrap();
+
+    identities.soft_delete(&user_id.to_string()).await.unwrap();
+
+    assert!(identities.find_by_identifier("alice@example.com").await.is_none());
+    assert!(sessions.find_by_id(&session_id).await.is_none());
+    assert!(sessions.revoked_at(&session_id).await.is_some());
+    assert!(identities.soft_delete(&user_id.to_string()).await.is_err());
+
+    // The identifier is free again once its identity is gone
+    identities
+        .create(&Uuid::new_v4(), "alice@example.com", "hash", "", "", 0)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,8 @@
+//! In-memory repository tests.
+
+mod credential_repository_memory_tests;
+mod identity_repository_memory_tests;
+mod opaque_token_store_memory_tests;
+mod session_repository_memory_tests;
+mod token_deny_list_memory_tests;
+mod token_watermark_store_memory_tests;
@@ -0,0 +1,45 @@
+//! Tests for OpaqueTokenStoreMemory.
+
+use chrono::{Duration, Utc};
+
+use crate::adapters::memory::{MemoryStore, OpaqueTokenStoreMemory};
+use crate::core::usecases::ports::{OpaqueTokenRecord, OpaqueTokenStore};
+
+fn record(token_hash: &str, expires_at: i64) -> OpaqueTokenRecord {
+    OpaqueTokenRecord {
+        token_hash: token_hash.to_string(),
+        token_type: "access".to_string(),
+        claims: r#"{"sub":"user-1"}"#.to_string(),
+        expires_at,
+    }
+}
+
+#[tokio::test]
+async fn stored_record_is_found_until_removed() {
+    let store = OpaqueTokenStoreMemory::new(MemoryStore::new());
+    let in_an_hour = (Utc::now() + Duration::hours(1)).timestamp();
+
+    store.put(record("hash-1", in_an_hour)).await.unwrap();
+
+    assert_eq!(store.get("hash-1").await, Some(record("hash-1", in_an_hour)));
+    assert!(store.get("hash-2").await.is_none());
+    assert!(store.remove("hash-1").await);
+    assert!(store.get("hash-1").await.is_none());
+    assert!(!store.remove("hash-1").await);
+}
+
+#[tokio::test]
+async fn lapsed_records_are_ignored_until_purged() {
+    let store = OpaqueTokenStoreMemory::new(MemoryStore::new());
+
+    store.put(record("hash-1", (Utc::now() - Duration::seconds(1)).timestamp())).await.unwrap();
+    assert!(store.get("hash-1").await.is_none());
+    assert_eq!(store.len(), 1);
+
+    store.put(record("hash-2", (Utc::now() + Duration::hours(1)).timestamp())).await.unwrap();
+    assert_eq!(store.len(), 2);
+
+    assert_eq!(store.purge_expired().await.unwrap(), 1);
+    assert_eq!(store.len(), 1);
+    assert!(store.get("hash-2").await.is_some());
+}
@@ -0,0 +1,179 @@
+//! Tests for SessionRepositoryMemory.
+
+use uuid::Uuid;
+
+use crate::adapters::memory::{IdentityRepositoryMemory, MemoryStore, SessionRepositoryMemory};
+use crate::core::identity::UserIdentity;
+use crate::core::usecases::ports::{IdentityRepository, SessionRepository};
+
+async fn setup() -> (SessionRepositoryMemory, UserIdentity) {
+    let store = MemoryStore::new();
+    let user_id = Uuid::new_v4();
+    IdentityRepositoryMemory::new(store.clone())
+        .create(&user_id, "alice@example.com", "hash", "", "", 0)
+        .await
+        .unwrap();
+    (SessionRepositoryMemory::new(store), UserIdentity::new(user_id.to_string()))
+}
+
+async fn open_session(repo: &SessionRepositoryMemory, user: &UserIdentity, hash: &str) -> String {
+    let session_id = Uuid::new_v4().to_string();
+    repo.create_session(&session_id, user, hash, r#"{"ip":"203.0.113.7","ua":"Mozilla/5.0"}"#)
+        .await
+        .unwrap();
+    session_id
+}
+
+#[tokio::test]
+async fn created_session_is_found_with_client_metadata() {
+    let (repo, user) = setup().await;
+    let session_id = open_session(&repo, &user, "hash-1").await;
+
+    assert!(repo.find_by_refresh_token_hash("hash-1").await.is_some());
+    let summary = repo.find_summary_by_id(&session_id).await.unwrap();
+    assert_eq!(summary.ip_address, "203.0.113.7");
+    assert_eq!(summary.user_agent, "Mozilla/5.0");
+}
+
+#[tokio::test]
+async fn session_requires_an_existing_user() {
+    let (repo, _) = setup().await;
+    let stranger = UserIdentity::new(Uuid::new_v4().to_string());
+
+    let result = repo
+        .create_session(&Uuid::new_v4().to_string(), &stranger, "hash", "{}")
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn duplicate_session_id_is_rejected() {
+    let (repo, user) = setup().await;
+    let session_id = open_session(&repo, &user, "hash-1").await;
+
+    assert!(repo.create_session(&session_id, &user, "hash-2", "{}").await.is_err());
+}
+
+#[tokio::test]
+async fn rotation_is_compare_and_swap() {
+    let (repo, user) = setup().await;
+    let session_id = open_session(&repo, &user, "hash-1").await;
+
+    assert!(repo.rotate_refresh_token(&session_id, "hash-1", "hash-2").await);
+    assert!(!repo.rotate_refresh_token(&session_id, "hash-1", "hash-3").await);
+
+    assert!(repo.is_rotated_refresh_token("hash-1").await);
+    assert!(repo.find_by_refresh_token_hash("hash-1").await.is_none());
+    assert!(repo.find_by_refresh_token_hash("hash-2").await.is_some());
+}
+
+#[tokio::test]
+async fn every_rotated_hash_stays_recognised() {
+    let (repo, user) = setup().await;
+    let session_id = open_session(&repo, &user, "hash-1").await;
+
+    assert!(repo.rotate_refresh_token(&session_id, "hash-1", "hash-2").await);
+    assert!(repo.rotate_refresh_token(&session_id, "hash-2", "hash-3").await);
+
+    assert!(repo.is_rotated_refresh_token("hash-1").await);
+    assert!(repo.is_rotated_refresh_token("hash-2").await);
+    assert!(!repo.is_rotated_refresh_token("hash-3").await);
+}
+
+#[tokio::test]
+async fn rotation_successor_is_kept_for_its_grace_window_only() {
+    let (repo, user) = setup().await;
+    let session_id = open_session(&repo, &user, "hash-1").await;
+    let now = chrono::Utc::now();
+
+    repo.rotate_refresh_token(&session_id, "hash-1", "hash-2").await;
+    repo.record_rotation_successor("hash-1", "hash-2", now + chrono::Duration::seconds(30))
+        .await;
+
+    assert_eq!(repo.rotation_successor("hash-1", now).await.as_deref(), Some("hash-2"));
+    assert!(repo.rotation_successor("hash-1", now + chrono::Duration::seconds(31)).await.is_none());
+
+    // The next rotation forgets it
+    repo.rotate_refresh_token(&session_id, "hash-2", "hash-3").await;
+    assert!(repo.rotation_successor("hash-1", now).await.is_none());
+}
+
+#[tokio::test]
+async fn reissue_replaces_live_successor_within_grace_window() {
+    let (repo, user) = setup().await;
+    let session_id = open_session(&repo, &user, "hash-1").await;
+    let now = chrono::Utc::now();
+    let until = now + chrono::Duration::seconds(30);
+
+    repo.rotate_refresh_token(&session_id, "hash-1", "hash-2").await;
+    repo.record_rotation_successor("hash-1", "hash-2", until).await;
+
+    assert!(!repo.reissue_rotation_successor("hash-1", "hash-3", until).await);
+    assert!(repo.reissue_rotation_successor("hash-1", "hash-3", now).await);
+
+    assert!(repo.find_by_refresh_token_hash("hash-2").await.is_none());
+    assert!(repo.find_by_refresh_token_hash("hash-3").await.is_some());
+    assert!(repo.is_rotated_refresh_token("hash-1").await);
+
+    // The displaced successor counts as rotated and the window is closed
+    assert!(repo.is_rotated_refresh_token("hash-2").await);
+    assert!(repo.rotation_successor("hash-1", now).await.is_none());
+    assert!(!repo.reissue_rotation_successor("hash-1", "hash-4", now).await);
+    assert!(repo.find_by_refresh_token_hash("hash-3").await.is_some());
+}
+
+#[tokio::test]
+async fn revoked_session_cannot_rotate_or_be_found() {
+    let (repo, user) = setup().await;
+    let session_id = open_session(&repo, &user, "hash-1").await;
+
+    repo.revoke_session(&session_id).await;
+
+    assert!(repo.find_by_id(&session_id).await.is_none());
+    assert!(repo.revoked_at(&session_id).await.is_some());
+    assert!(!repo.rotate_refresh_token(&session_id, "hash-1", "hash-2").await);
+}
+
+#[tokio::test]
+async fn revoke_all_except_keeps_one_session() {
+    let (repo, user) = setup().await;
+    let keep = open_session(&repo, &user, "hash-1").await;
+    open_session(&repo, &user, "hash-2").await;
+    open_session(&repo, &user, "hash-3").await;
+
+    assert_eq!(repo.revoke_all_for_user_except(&user.id, &keep).await, 2);
+
+    let active = repo.list_active_for_user(&user.id).await;
+    assert_eq!(active.len(), 1);
+    assert_eq!(active[0].session_id, keep);
+    assert_eq!(repo.revoke_all_for_user(&user.id).await, 1);
+}
+
+#[tokio::test]
+async fn history_pages_through_every_session() {
+    let (repo, user) = setup().await;
+    for i in 0..3 {
+        open_session(&repo, &user, &format!("hash-{}", i)).await;
+    }
+
+    let first = repo.list_for_user_paginated(&user.id, None, 2).await.unwrap();
+    assert_eq!(first.sessions.len(), 2);
+    let cursor = first.next_cursor.expect("a second page");
+
+    let second = repo.list_for_user_paginated(&user.id, Some(&cursor), 2).await.unwrap();
+    assert_eq!(second.sessions.len(), 1);
+    assert!(second.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn session_expiry_comes_from_metadata() {
+    let (repo, user) = setup().await;
+    let session_id = Uuid::new_v4().to_string();
+    let metadata = serde_json::json!({ "expires": "2020-01-01T00:00:00Z" }).to_string();
+
+    repo.create_session(&session_id, &user, "hash-1", &metadata).await.unwrap();
+
+    assert_eq!(repo.expires_at(&session_id).await.unwrap().to_rfc3339(), "2020-01-01T00:00:00+00:00");
+    assert!(repo.find_by_refresh_token_hash("hash-1").await.is_none());
+}
@@ -0,0 +1,53 @@
+//! Tests for TokenDenyListMemory.
+
+use chrono::{Duration, Utc};
+
+use crate::adapters::memory::{MemoryStore, TokenDenyListMemory};
+use crate::core::usecases::ports::TokenDenyList;
+
+fn in_an_hour() -> i64 {
+    (Utc::now() + Duration::hours(1)).timestamp()
+}
+
+#[tokio::test]
+async fn denied_jti_is_reported_until_expiry() {
+    let deny_list = TokenDenyListMemory::new(MemoryStore::new());
+
+    deny_list.deny("jti-1", in_an_hour()).await.unwrap();
+
+    assert!(deny_list.is_denied("jti-1").await);
+    assert!(!deny_list.is_denied("jti-2").await);
+}
+
+#[tokio::test]
+async fn entry_past_expiry_is_ignored() {
+    let deny_list = TokenDenyListMemory::new(MemoryStore::new());
+
+    deny_list.deny("jti-1", (Utc::now() - Duration::seconds(1)).timestamp()).await.unwrap();
+
+    assert!(!deny_list.is_denied("jti-1").await);
+}
+
+#[tokio::test]
+async fn denying_again_succeeds() {
+    let deny_list = TokenDenyListMemory::new(MemoryStore::new());
+
+    deny_list.deny("jti-1", in_an_hour()).await.unwrap();
+    deny_list.deny("jti-1", in_an_hour()).await.unwrap();
+
+    assert!(deny_list.is_denied("jti-1").await);
+    assert_eq!(deny_list.len(), 1);
+}
+
+#[tokio::test]
+async fn lapsed_entries_are_dropped_on_deny() {
+    let deny_list = TokenDenyListMemory::new(MemoryStore::new());
+    let lapsed = (Utc::now() - Duration::seconds(1)).timestamp();
+    deny_list.deny("old-1", lapsed).await.unwrap();
+    deny_list.deny("old-2", lapsed).await.unwrap();
+
+    deny_list.deny("jti-1", in_an_hour()).await.unwrap();
+
+    assert_eq!(deny_list.len(), 1);
+    assert!(deny_list.is_denied("jti-1").await);
+}
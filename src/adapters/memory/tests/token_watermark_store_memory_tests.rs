@@ -0,0 +1,31 @@
+//! Tests for TokenWatermarkStoreMemory.
+
+use crate::adapters::memory::{MemoryStore, TokenWatermarkStoreMemory};
+use crate::core::usecases::ports::TokenWatermarkStore;
+
+#[tokio::test]
+async fn no_watermark_until_advanced() {
+    let watermark = TokenWatermarkStoreMemory::new(MemoryStore::new());
+
+    assert_eq!(watermark.revoked_through().await, None);
+}
+
+#[tokio::test]
+async fn watermark_never_moves_backwards() {
+    let watermark = TokenWatermarkStoreMemory::new(MemoryStore::new());
+
+    assert_eq!(watermark.advance(2_000).await.unwrap(), 2_000);
+    assert_eq!(watermark.advance(1_000).await.unwrap(), 2_000);
+
+    assert_eq!(watermark.revoked_through().await, Some(2_000));
+}
+
+#[tokio::test]
+async fn watermark_survives_reload() {
+    let store = MemoryStore::new();
+    TokenWatermarkStoreMemory::new(store.clone()).advance(2_000).await.unwrap();
+
+    let reloaded = TokenWatermarkStoreMemory::new(store);
+
+    assert_eq!(reloaded.revoked_through().await, Some(2_000));
+}
@@ -0,0 +1,66 @@
+//! In-memory implementation of the access token deny-list.
+
+use chrono::{DateTime, TimeZone, Utc};
+use futures::future::FutureExt;
+
+use crate::adapters::memory::store::MemoryStore;
+use crate::core::error::CoreError;
+use crate::core::usecases::ports::TokenDenyList;
+
+/// In-memory counterpart of `TokenDenyListSql`.
+///
+/// Responsibilities:
+/// - Deny a jti until its token's expiry, keeping the first entry
+/// - Ignore entries past their expiry, like the SQL lookup does
+/// - Drop lapsed entries whenever a token is denied, so the list stays
+///   bounded by the number of live denied tokens
+pub struct TokenDenyListMemory {
+    store: MemoryStore,
+}
+
+impl TokenDenyListMemory {
+    /// Create a deny-list over `store`.
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+
+    /// Deny `jti` until `expires_at` and drop every lapsed entry.
+    pub fn insert(&self, jti: &str, expires_at: DateTime<Utc>) {
+        let now = Utc::now();
+        let mut denied = self.store.denied_tokens_mut();
+        denied.retain(|_, expires_at| *expires_at > now);
+        denied.entry(jti.to_string()).or_insert(expires_at);
+    }
+
+    /// Whether `jti` has an entry that has not lapsed yet.
+    pub fn contains(&self, jti: &str) -> bool {
+        let now = Utc::now();
+        self.store
+            .denied_tokens()
+            .get(jti)
+            .is_some_and(|expires_at| *expires_at > now)
+    }
+
+    /// Number of entries held, lapsed or not.
+    pub fn len(&self) -> usize {
+        self.store.denied_tokens().len()
+    }
+
+    /// Whether no entry is held.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl TokenDenyList for TokenDenyListMemory {
+    fn deny(&self, jti: &str, expires_at: i64) -> futures::future::BoxFuture<'_, Result<(), CoreError>> {
+        let expires_at = Utc.timestamp_opt(expires_at, 0).single().unwrap_or_else(Utc::now);
+        self.insert(jti, expires_at);
+        async move { Ok(()) }.boxed()
+    }
+
+    fn is_denied(&self, jti: &str) -> futures::future::BoxFuture<'_, bool> {
+        let denied = self.contains(jti);
+        async move { denied }.boxed()
+    }
+}
@@ -0,0 +1,53 @@
+//! In-memory implementation of the access token issued-at watermark.
+
+use chrono::{DateTime, TimeZone, Utc};
+use futures::future::FutureExt;
+
+use crate::adapters::memory::store::MemoryStore;
+use crate::core::error::CoreError;
+use crate::core::usecases::ports::TokenWatermarkStore;
+
+/// In-memory counterpart of `TokenWatermarkStoreSql`.
+///
+/// The watermark lives in the shared store, so a store built over the same
+/// `MemoryStore` sees it, like a restarted service reading the same database.
+///
+/// Responsibilities:
+/// - Raise the watermark, never lowering it
+/// - Answer the watermark in effect
+pub struct TokenWatermarkStoreMemory {
+    store: MemoryStore,
+}
+
+impl TokenWatermarkStoreMemory {
+    /// Create a watermark store over `store`.
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+
+    /// Raise the watermark to `revoked_through` and return the one in effect.
+    pub fn raise(&self, revoked_through: DateTime<Utc>) -> DateTime<Utc> {
+        let mut watermark = self.store.token_watermark_mut();
+        let raised = watermark.map_or(revoked_through, |current| current.max(revoked_through));
+        *watermark = Some(raised);
+        raised
+    }
+
+    /// The watermark in effect, if one was ever set.
+    pub fn current(&self) -> Option<DateTime<Utc>> {
+        *self.store.token_watermark()
+    }
+}
+
+impl TokenWatermarkStore for TokenWatermarkStoreMemory {
+    fn revoked_through(&self) -> futures::future::BoxFuture<'_, Option<i64>> {
+        let revoked_through = self.current().map(|watermark| watermark.timestamp());
+        async move { revoked_through }.boxed()
+    }
+
+    fn advance(&self, revoked_through: i64) -> futures::future::BoxFuture<'_, Result<i64, CoreError>> {
+        let revoked_through = Utc.timestamp_opt(revoked_through, 0).single().unwrap_or_else(Utc::now);
+        let raised = self.raise(revoked_through).timestamp();
+        async move { Ok(raised) }.boxed()
+    }
+}
@@ -1,4 +1,10 @@
+pub mod audit;
 pub mod clients;
+pub mod clock;
 pub mod persistence;
+pub mod random;
 pub mod crypto;
 pub mod http;
+pub mod identifier;
+#[cfg(any(test, feature = "test-support"))]
+pub mod memory;
@@ -1,10 +1,22 @@
 // Database connection pool and transaction management.
 
 use sqlx::postgres::{PgPool, PgPoolOptions, PgConnectOptions, PgConnection};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::str::FromStr;
 
-use crate::adapters::persistence::error::{ConnectionError, PersistenceError};
+use crate::adapters::persistence::error::{ConnectionError, ExecutionError, PersistenceError};
+
+/// SQLSTATE raised when Postgres cancels a statement (`query_canceled`),
+/// including cancellations caused by `statement_timeout`.
+const QUERY_CANCELED: &str = "57014";
+
+/// SQLSTATE raised when a transaction cannot be serialized against a
+/// concurrent one (`serialization_failure`).
+const SERIALIZATION_FAILURE: &str = "40001";
+
+/// SQLSTATE raised when Postgres breaks a deadlock by aborting one of the
+/// transactions involved (`deadlock_detected`).
+const DEADLOCK_DETECTED: &str = "40P01";
 
 /// Connection pool configuration.
 #[derive(Debug, Clone)]
@@ -27,48 +39,189 @@ impl Default for PoolConfig {
     }
 }
 
-/// Database connection pool manager.
+/// Session settings applied to every pooled connection when it is opened.
 ///
-/// Handles creation, management, and lifecycle of database connections.
-/// All repository operations must use this pool.
-#[derive(Clone)]
-pub struct Database {
-    pool: PgPool,
+/// Both timeouts are off by default (Postgres' own default). Setting them
+/// keeps a runaway query or a transaction abandoned mid-way from pinning a
+/// connection and its locks indefinitely.
+#[derive(Debug, Clone, Default)]
+pub struct DatabaseOptions {
+    /// Abort any statement running longer than this (`statement_timeout`)
+    pub statement_timeout: Option<Duration>,
+    /// Terminate a session idling inside an open transaction for longer than
+    /// this (`idle_in_transaction_session_timeout`)
+    pub idle_in_transaction_session_timeout: Option<Duration>,
 }
 
-impl Database {
-    /// Create a new database connection pool.
-    ///
-    /// # Arguments
-    ///
-    /// * `database_url` - PostgreSQL connection string
-    /// * `config` - Pool configuration
-    ///
-    /// # Errors
+impl DatabaseOptions {
+    /// Set the `statement_timeout` for every connection.
+    pub fn with_statement_timeout(mut self, timeout: Duration) -> Self {
+        self.statement_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the `idle_in_transaction_session_timeout` for every connection.
+    pub fn with_idle_in_transaction_session_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_in_transaction_session_timeout = Some(timeout);
+        self
+    }
+
+    /// `SET` statements to run on each new connection.
     ///
-    /// Returns `PersistenceError::Connection` if the pool cannot be created
-    /// or if the initial connection test fails.
-    pub async fn new(
-        database_url: &str,
-        config: PoolConfig,
-    ) -> Result<Self, PersistenceError> {
-        if database_url.is_empty() {
+    /// Values are whole milliseconds; `SET` does not accept bind parameters.
+    pub fn session_statements(&self) -> Vec<String> {
+        let settings = [
+            ("statement_timeout", self.statement_timeout),
+            ("idle_in_transaction_session_timeout", self.idle_in_transaction_session_timeout),
+        ];
+
+        settings
+            .into_iter()
+            .filter_map(|(name, timeout)| timeout.map(|t| format!("SET {} = {}", name, t.as_millis())))
+            .collect()
+    }
+}
+
+/// Map a failed query, surfacing a statement timeout or a connection
+/// acquire timeout as a retryable `ConnectionError::Timeout`, and a dropped
+/// connection as `ConnectionError::Unavailable`, rather than a generic query
+/// failure. A serialization failure or deadlock becomes a retryable
+/// `ExecutionError::SerializationFailure`.
+pub fn map_query_error(error: sqlx::Error, context: &str) -> PersistenceError {
+    map_transient_error(&error, context)
+        .unwrap_or_else(|| PersistenceError::Execution(ExecutionError::query_failed(format!("{}: {}", context, error))))
+}
+
+/// Map a failed `BEGIN` or `COMMIT` like [`map_query_error`], falling back
+/// to `ExecutionError::TransactionFailed` rather than a query failure.
+pub fn map_transaction_error(error: sqlx::Error, context: &str) -> PersistenceError {
+    map_transient_error(&error, context).unwrap_or_else(|| {
+        PersistenceError::Execution(ExecutionError::transaction_failed(format!("{}: {}", context, error)))
+    })
+}
+
+/// The retryable error `error` stands for, if any.
+fn map_transient_error(error: &sqlx::Error, context: &str) -> Option<PersistenceError> {
+    let code = error.as_database_error().and_then(|db| db.code());
+    let canceled = code.as_deref() == Some(QUERY_CANCELED);
+    let conflicted = matches!(code.as_deref(), Some(SERIALIZATION_FAILURE | DEADLOCK_DETECTED));
+
+    if conflicted {
+        Some(PersistenceError::Execution(ExecutionError::serialization_failure(format!("{}: {}", context, error))))
+    } else if canceled || matches!(error, sqlx::Error::PoolTimedOut) {
+        Some(PersistenceError::Connection(ConnectionError::timeout(format!("{}: {}", context, error))))
+    } else if matches!(error, sqlx::Error::Io(_) | sqlx::Error::PoolClosed) {
+        Some(PersistenceError::Connection(ConnectionError::unavailable(format!("{}: {}", context, error))))
+    } else {
+        None
+    }
+}
+
+/// Builder for a [`Database`] with a tuned connection pool.
+///
+/// Created by [`Database::builder`]. Settings left alone keep the defaults
+/// of [`PoolConfig`] and sqlx.
+#[derive(Debug, Clone)]
+pub struct DatabaseBuilder {
+    database_url: String,
+    config: PoolConfig,
+    min_connections: u32,
+    acquire_timeout: Duration,
+    test_before_acquire: bool,
+    options: DatabaseOptions,
+}
+
+impl DatabaseBuilder {
+    /// Maximum number of connections in the pool.
+    pub fn max_connections(mut self, max_connections: u32) -> Self {
+        self.config.max_connections = max_connections;
+        self
+    }
+
+    /// Number of connections the pool keeps open even when idle.
+    pub fn min_connections(mut self, min_connections: u32) -> Self {
+        self.min_connections = min_connections;
+        self
+    }
+
+    /// How long to wait for a free connection before failing with
+    /// `ConnectionError::Timeout`.
+    pub fn acquire_timeout(mut self, timeout: Duration) -> Self {
+        self.acquire_timeout = timeout;
+        self
+    }
+
+    /// How long a connection may sit idle before it is closed.
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.config.idle_timeout = timeout;
+        self
+    }
+
+    /// Maximum lifetime of a connection.
+    pub fn max_lifetime(mut self, lifetime: Duration) -> Self {
+        self.config.max_lifetime = lifetime;
+        self
+    }
+
+    /// Whether to ping a pooled connection before handing it out.
+    pub fn test_before_acquire(mut self, test: bool) -> Self {
+        self.test_before_acquire = test;
+        self
+    }
+
+    /// Session settings applied to every connection when it is opened.
+    pub fn with_options(mut self, options: DatabaseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    fn pool_options(&self) -> PgPoolOptions {
+        let session_statements = self.options.session_statements();
+
+        PgPoolOptions::new()
+            .max_connections(self.config.max_connections)
+            .min_connections(self.min_connections)
+            .acquire_timeout(self.acquire_timeout)
+            .idle_timeout(Some(self.config.idle_timeout))
+            .max_lifetime(Some(self.config.max_lifetime))
+            .test_before_acquire(self.test_before_acquire)
+            .after_connect(move |conn, _meta| {
+                let statements = session_statements.clone();
+                Box::pin(async move {
+                    for statement in &statements {
+                        sqlx::query(statement).execute(&mut *conn).await?;
+                    }
+                    Ok(())
+                })
+            })
+    }
+
+    fn connect_options(&self) -> Result<PgConnectOptions, PersistenceError> {
+        if self.database_url.is_empty() {
             return Err(PersistenceError::Connection(ConnectionError::unavailable(
                 "database URL cannot be empty",
             )));
         }
 
-        let connect_options = PgConnectOptions::from_str(database_url).map_err(|e| {
+        PgConnectOptions::from_str(&self.database_url).map_err(|e| {
             PersistenceError::Connection(ConnectionError::unavailable(format!(
                 "invalid database url: {}",
                 e
             )))
-        })?;
+        })
+    }
 
-        let pool = PgPoolOptions::new()
-            .max_connections(config.max_connections)
-            .idle_timeout(Some(config.idle_timeout))
-            .max_lifetime(Some(config.max_lifetime))
+    /// Create the pool and test one connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Connection` if the pool cannot be created,
+    /// a session setting is rejected, or the initial connection test fails.
+    pub async fn connect(self) -> Result<Database, PersistenceError> {
+        let connect_options = self.connect_options()?;
+
+        let pool = self
+            .pool_options()
             .connect_with(connect_options)
             .await
             .map_err(|e| {
@@ -89,12 +242,103 @@ impl Database {
                 )))
             })?;
 
-        Ok(Self { pool })
+        Ok(Database { pool })
+    }
+
+    /// Create the pool without opening any connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Connection` if the URL is invalid.
+    pub fn connect_lazy(self) -> Result<Database, PersistenceError> {
+        let connect_options = self.connect_options()?;
+
+        Ok(Database {
+            pool: self.pool_options().connect_lazy_with(connect_options),
+        })
+    }
+}
+
+/// Database connection pool manager.
+///
+/// Handles creation, management, and lifecycle of database connections.
+/// All repository operations must use this pool.
+#[derive(Clone)]
+pub struct Database {
+    pool: PgPool,
+}
+
+impl Database {
+    /// Start configuring a pool for `database_url`.
+    pub fn builder(database_url: impl Into<String>) -> DatabaseBuilder {
+        DatabaseBuilder {
+            database_url: database_url.into(),
+            config: PoolConfig::default(),
+            min_connections: 0,
+            acquire_timeout: Duration::from_secs(30),
+            test_before_acquire: true,
+            options: DatabaseOptions::default(),
+        }
+    }
+
+    /// Create a new database connection pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_url` - PostgreSQL connection string
+    /// * `config` - Pool configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Connection` if the pool cannot be created
+    /// or if the initial connection test fails.
+    pub async fn new(
+        database_url: &str,
+        config: PoolConfig,
+    ) -> Result<Self, PersistenceError> {
+        Self::new_with_options(database_url, config, DatabaseOptions::default()).await
+    }
+
+    /// Create a new database connection pool whose connections apply the
+    /// given session settings on connect.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Connection` if the pool cannot be created,
+    /// a session setting is rejected, or the initial connection test fails.
+    pub async fn new_with_options(
+        database_url: &str,
+        config: PoolConfig,
+        options: DatabaseOptions,
+    ) -> Result<Self, PersistenceError> {
+        Self::builder(database_url)
+            .max_connections(config.max_connections)
+            .idle_timeout(config.idle_timeout)
+            .max_lifetime(config.max_lifetime)
+            .with_options(options)
+            .connect()
+            .await
+    }
+
+    /// Create a pool that opens connections on first use.
+    ///
+    /// Succeeds without reaching the database, so the service can start (and
+    /// report itself not ready) while the database is still unavailable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Connection` if the URL is invalid.
+    pub fn connect_lazy(database_url: &str, config: PoolConfig) -> Result<Self, PersistenceError> {
+        Self::builder(database_url)
+            .max_connections(config.max_connections)
+            .idle_timeout(config.idle_timeout)
+            .max_lifetime(config.max_lifetime)
+            .connect_lazy()
     }
 
     /// Create a new database connection pool with default configuration.
     pub async fn new_default(database_url: &str) -> Result<Self, PersistenceError> {
-        Self::new(database_url, PoolConfig::default()).await
+        Self::builder(database_url).connect().await
     }
 
     /// Get a reference to the connection pool.
@@ -102,17 +346,27 @@ impl Database {
         &self.pool
     }
 
+    /// Begin a transaction on a pooled connection.
+    ///
+    /// Dropping the returned handle without committing rolls it back.
+    pub async fn begin(&self) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, PersistenceError> {
+        self.pool.begin().await.map_err(|e| map_transaction_error(e, "failed to begin transaction"))
+    }
+
     /// Acquire a single connection from the pool.
     pub async fn acquire(&self) -> Result<PgConnection, PersistenceError> {
         self.pool
             .acquire()
             .await
             .map(|conn| conn.detach())
-            .map_err(|e| {
-                PersistenceError::Connection(ConnectionError::pool_exhausted(format!(
+            .map_err(|e| match e {
+                sqlx::Error::PoolTimedOut => {
+                    PersistenceError::Connection(ConnectionError::timeout("timed out acquiring a connection"))
+                }
+                e => PersistenceError::Connection(ConnectionError::pool_exhausted(format!(
                     "failed to acquire connection: {}",
                     e
-                )))
+                ))),
             })
     }
 
@@ -121,6 +375,40 @@ impl Database {
         self.pool.close().await;
     }
 
+    /// Probe connectivity with `SELECT 1` and measure its round-trip latency.
+    ///
+    /// Lets readiness checks flag a database that is reachable but slow.
+    pub async fn health_with_latency(&self) -> Result<DatabaseHealth, PersistenceError> {
+        let started = Instant::now();
+
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                PersistenceError::Connection(ConnectionError::unavailable(format!(
+                    "health probe failed: {}",
+                    e
+                )))
+            })?;
+
+        Ok(DatabaseHealth {
+            latency: started.elapsed(),
+        })
+    }
+
+    /// Current pool occupancy, for readiness reporting and metrics.
+    pub fn pool_status(&self) -> PoolStatus {
+        let size = self.pool.size();
+        let idle = u32::try_from(self.pool.num_idle()).unwrap_or(u32::MAX).min(size);
+
+        PoolStatus {
+            size,
+            idle,
+            in_use: size - idle,
+            max_connections: self.pool.options().get_max_connections(),
+        }
+    }
+
     /// Get pool statistics for monitoring.
     pub fn pool_stats(&self) -> PoolStats {
         let size = self.pool.size();
@@ -140,3 +428,35 @@ pub struct PoolStats {
     /// Total pool size
     pub pool_size: u32,
 }
+
+/// Occupancy of the connection pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolStatus {
+    /// Open connections, idle or in use
+    pub size: u32,
+    /// Open connections waiting in the pool
+    pub idle: u32,
+    /// Connections currently checked out
+    pub in_use: u32,
+    /// Upper bound on open connections
+    pub max_connections: u32,
+}
+
+/// Result of a database health probe.
+#[derive(Debug, Clone, Copy)]
+pub struct DatabaseHealth {
+    /// Round-trip time of the probe query
+    pub latency: Duration,
+}
+
+impl DatabaseHealth {
+    /// Probe latency in milliseconds (fractional).
+    pub fn latency_ms(&self) -> f64 {
+        self.latency.as_secs_f64() * 1000.0
+    }
+
+    /// Whether the probe was slower than the given threshold.
+    pub fn is_degraded(&self, threshold: Duration) -> bool {
+        self.latency > threshold
+    }
+}
@@ -11,6 +11,9 @@ pub enum ExecutionError {
     InvalidTransactionState { reason: String },
     /// Database is in a corrupted or invalid state
     CorruptedState { reason: String },
+    /// Transaction was aborted by a serialization failure or deadlock and
+    /// can be run again as-is
+    SerializationFailure { reason: String },
 }
 
 impl ExecutionError {
@@ -44,6 +47,12 @@ impl ExecutionError {
         }
     }
 
+    pub fn serialization_failure(reason: impl Into<String>) -> Self {
+        Self::SerializationFailure {
+            reason: reason.into(),
+        }
+    }
+
     /// Returns true if this error indicates the transaction is compromised
     pub fn is_transaction_compromised(&self) -> bool {
         matches!(
@@ -51,6 +60,7 @@ impl ExecutionError {
             ExecutionError::TransactionFailed { .. }
                 | ExecutionError::InvalidTransactionState { .. }
                 | ExecutionError::CorruptedState { .. }
+                | ExecutionError::SerializationFailure { .. }
         )
     }
 
@@ -58,7 +68,9 @@ impl ExecutionError {
     pub fn is_retryable(&self) -> bool {
         matches!(
             self,
-            ExecutionError::QueryFailed { .. } | ExecutionError::CorruptedState { .. }
+            ExecutionError::QueryFailed { .. }
+                | ExecutionError::CorruptedState { .. }
+                | ExecutionError::SerializationFailure { .. }
         )
     }
 }
@@ -81,6 +93,9 @@ impl std::fmt::Display for ExecutionError {
             ExecutionError::CorruptedState { reason } => {
                 write!(f, "database in corrupted state: {}", reason)
             }
+            ExecutionError::SerializationFailure { reason } => {
+                write!(f, "transaction aborted by a concurrent update: {}", reason)
+            }
         }
     }
 }
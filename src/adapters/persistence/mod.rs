@@ -8,6 +8,7 @@ It is responsible for:
  - Mapping database rows to domain entities
  - Executing queries and mutations
  - Supporting transactions for coordinated operations
+ - Retrying reads that hit transient connection failures
 
 It is NOT responsible for:
  - Business logic or policy enforcement
@@ -24,11 +25,13 @@ pub mod error;
 pub mod id_conversion;
 pub mod models;
 pub mod repositories;
+pub mod retry;
 
-pub use database::Database;
+pub use database::{Database, DatabaseBuilder, DatabaseOptions, PoolStatus};
 pub use error::PersistenceError;
 pub use id_conversion::to_uuid;
 pub use repositories::{CredentialRepositorySql, IdentityRepositorySql, SessionRepositorySql};
+pub use retry::{with_retries, RetryPolicy};
 
 #[cfg(test)]
 pub mod tests;
\ No newline at end of file
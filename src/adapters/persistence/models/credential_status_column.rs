@@ -0,0 +1,55 @@
+//! Column mapping for the credential lifecycle status.
+//!
+//! The `identity_credential` table stores the status as two columns:
+//! `credential_status` (`active`, `revoked`, `expired`, `not_yet_valid`) and
+//! `credential_status_at`, the optional timestamp payload of the variant.
+//! The payload is kept as text so whatever the core recorded reads back
+//! unchanged.
+
+use crate::adapters::persistence::error::{MappingError, PersistenceError};
+use crate::core::credentials::CredentialStatus;
+
+const ENTITY: &str = "CredentialStatus";
+
+pub const STATUS_ACTIVE: &str = "active";
+pub const STATUS_REVOKED: &str = "revoked";
+pub const STATUS_EXPIRED: &str = "expired";
+pub const STATUS_NOT_YET_VALID: &str = "not_yet_valid";
+
+/// Split a status into its `credential_status` and `credential_status_at` column values.
+pub fn status_to_columns(status: &CredentialStatus) -> (&'static str, Option<String>) {
+    match status {
+        CredentialStatus::Active => (STATUS_ACTIVE, None),
+        CredentialStatus::Revoked { revoked_at } => (STATUS_REVOKED, revoked_at.clone()),
+        CredentialStatus::Expired { expired_at } => (STATUS_EXPIRED, expired_at.clone()),
+        CredentialStatus::NotYetValid { valid_from } => (STATUS_NOT_YET_VALID, valid_from.clone()),
+    }
+}
+
+/// Rebuild a status from its column values.
+///
+/// # Errors
+///
+/// Returns a mapping error for an unknown status value, or when an active
+/// status carries a timestamp payload.
+pub fn status_from_columns(
+    status: &str,
+    status_at: Option<String>,
+) -> Result<CredentialStatus, PersistenceError> {
+    match status {
+        STATUS_ACTIVE => match status_at {
+            None => Ok(CredentialStatus::Active),
+            Some(_) => Err(PersistenceError::Mapping(MappingError::schema_mismatch(
+                ENTITY,
+                "active status must not carry credential_status_at",
+            ))),
+        },
+        STATUS_REVOKED => Ok(CredentialStatus::Revoked { revoked_at: status_at }),
+        STATUS_EXPIRED => Ok(CredentialStatus::Expired { expired_at: status_at }),
+        STATUS_NOT_YET_VALID => Ok(CredentialStatus::NotYetValid { valid_from: status_at }),
+        other => Err(PersistenceError::Mapping(MappingError::deserialization_failed(
+            ENTITY,
+            format!("unknown credential_status '{}'", other),
+        ))),
+    }
+}
@@ -12,6 +12,7 @@ Important distinctions:
 All row types must implement `sqlx::FromRow` for direct deserialization.
 */
 
+pub mod credential_status_column;
 pub mod external_identity_row;
 pub mod identity_row;
 pub mod session_row;
@@ -33,6 +33,30 @@ pub struct SessionRow {
     /// User agent from which the session was created
     pub user_agent: String,
 
+    /// Device class derived from the user agent (NULL if not enriched)
+    #[sqlx(default)]
+    pub device_type: Option<String>,
+
+    /// Operating system derived from the user agent (NULL if not enriched)
+    #[sqlx(default)]
+    pub os: Option<String>,
+
+    /// Browser derived from the user agent (NULL if not enriched)
+    #[sqlx(default)]
+    pub browser: Option<String>,
+
+    /// Country resolved from the IP address (NULL if not enriched)
+    #[sqlx(default)]
+    pub geo_country: Option<String>,
+
+    /// Region resolved from the IP address (NULL if not enriched)
+    #[sqlx(default)]
+    pub geo_region: Option<String>,
+
+    /// City resolved from the IP address; never anything finer (NULL if not enriched)
+    #[sqlx(default)]
+    pub geo_city: Option<String>,
+
     /// Timestamp when the record was last updated
     pub updated_at: DateTime<Utc>,
 }
@@ -0,0 +1,53 @@
+//! Tests for the credential status column mapping.
+
+use crate::adapters::persistence::error::PersistenceError;
+use crate::adapters::persistence::models::credential_status_column::{status_from_columns, status_to_columns};
+use crate::core::credentials::CredentialStatus;
+
+fn round_trip(status: CredentialStatus) -> CredentialStatus {
+    let (value, at) = status_to_columns(&status);
+    status_from_columns(value, at).expect("status should map back")
+}
+
+#[test]
+fn every_status_round_trips_losslessly() {
+    let at = Some("2024-03-01T12:00:00+00:00".to_string());
+    let statuses = vec![
+        CredentialStatus::Active,
+        CredentialStatus::Revoked { revoked_at: at.clone() },
+        CredentialStatus::Revoked { revoked_at: None },
+        CredentialStatus::Expired { expired_at: at.clone() },
+        CredentialStatus::Expired { expired_at: None },
+        CredentialStatus::NotYetValid { valid_from: at.clone() },
+        CredentialStatus::NotYetValid { valid_from: None },
+    ];
+
+    for status in statuses {
+        assert_eq!(round_trip(status.clone()), status);
+    }
+}
+
+#[test]
+fn status_columns_use_stable_text_values() {
+    assert_eq!(status_to_columns(&CredentialStatus::Active), ("active", None));
+    assert_eq!(
+        status_to_columns(&CredentialStatus::Revoked { revoked_at: Some("t".to_string()) }),
+        ("revoked", Some("t".to_string()))
+    );
+    assert_eq!(status_to_columns(&CredentialStatus::Expired { expired_at: None }).0, "expired");
+    assert_eq!(status_to_columns(&CredentialStatus::NotYetValid { valid_from: None }).0, "not_yet_valid");
+}
+
+#[test]
+fn unknown_status_value_is_a_mapping_error() {
+    let result = status_from_columns("suspended", None);
+
+    assert!(matches!(result, Err(PersistenceError::Mapping(_))));
+}
+
+#[test]
+fn active_status_with_payload_is_a_mapping_error() {
+    let result = status_from_columns("active", Some("2024-03-01T12:00:00+00:00".to_string()));
+
+    assert!(matches!(result, Err(PersistenceError::Mapping(_))));
+}
@@ -1,4 +1,5 @@
 /// Model tests module organizing all model-related tests.
 
+mod credential_status_column_tests;
 mod identity_row_tests;
 mod session_row_tests;
@@ -19,6 +19,12 @@ fn session_row_is_active_when_not_revoked_and_not_expired() {
         revoked_at: None,
         ip_address: "127.0.0.1".to_string(),
         user_agent: "Mozilla".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: now,
     };
 
@@ -39,6 +45,12 @@ fn session_row_not_active_when_revoked() {
         revoked_at: Some(now),
         ip_address: "127.0.0.1".to_string(),
         user_agent: "Mozilla".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: now,
     };
 
@@ -59,6 +71,12 @@ fn session_row_not_active_when_expired() {
         revoked_at: None,
         ip_address: "127.0.0.1".to_string(),
         user_agent: "Mozilla".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: past,
     };
 
@@ -79,6 +97,12 @@ fn session_row_is_expired_when_past_expiration() {
         revoked_at: None,
         ip_address: "127.0.0.1".to_string(),
         user_agent: "Mozilla".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: past,
     };
 
@@ -99,6 +123,12 @@ fn session_row_not_expired_when_before_expiration() {
         revoked_at: None,
         ip_address: "127.0.0.1".to_string(),
         user_agent: "Mozilla".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: now,
     };
 
@@ -119,6 +149,12 @@ fn session_row_is_revoked_when_revoked_at_is_set() {
         revoked_at: Some(now),
         ip_address: "127.0.0.1".to_string(),
         user_agent: "Mozilla".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: now,
     };
 
@@ -139,6 +175,12 @@ fn session_row_not_revoked_when_revoked_at_is_none() {
         revoked_at: None,
         ip_address: "127.0.0.1".to_string(),
         user_agent: "Mozilla".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: now,
     };
 
@@ -159,6 +201,12 @@ fn session_row_time_to_expiration_returns_duration_when_active() {
         revoked_at: None,
         ip_address: "127.0.0.1".to_string(),
         user_agent: "Mozilla".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: now,
     };
 
@@ -184,6 +232,12 @@ fn session_row_time_to_expiration_returns_none_when_expired() {
         revoked_at: None,
         ip_address: "127.0.0.1".to_string(),
         user_agent: "Mozilla".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: past,
     };
 
@@ -0,0 +1,78 @@
+//! SQL-backed implementation of the audit sink.
+
+use futures::future::FutureExt;
+
+use crate::adapters::persistence::{
+    database::{map_query_error, Database},
+    error::PersistenceError,
+};
+use crate::core::usecases::ports::{AuditEvent, AuditSink};
+
+/// SQL-backed, append-only audit trail.
+///
+/// Implements operations against the `auth_audit_event` table:
+///
+/// ```sql
+/// CREATE TABLE auth_audit_event (
+///     id          BIGSERIAL PRIMARY KEY,
+///     actor       TEXT NOT NULL,
+///     event_type  TEXT NOT NULL,
+///     occurred_at TIMESTAMPTZ NOT NULL,
+///     outcome     TEXT NOT NULL,
+///     reason      TEXT NULL,
+///     source_ip   TEXT NULL
+/// );
+/// ```
+///
+/// Responsibilities:
+/// - Insert one row per audit event
+///
+/// Does NOT:
+/// - Update or delete events; retention is handled outside the service
+pub struct AuditSinkSql {
+    db: Database,
+}
+
+impl AuditSinkSql {
+    /// Create a new audit sink with the given database pool.
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    /// Append an event to the audit table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn insert(&self, event: &AuditEvent) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            INSERT INTO auth_audit_event
+                (actor, event_type, occurred_at, outcome, reason, source_ip)
+            VALUES ($1, $2, $3, $4, $5, $6)
+        "#;
+
+        sqlx::query(QUERY)
+            .bind(&event.actor)
+            .bind(event.event_type.as_str())
+            .bind(event.timestamp)
+            .bind(event.outcome.as_str())
+            .bind(event.reason.as_deref())
+            .bind(event.source_ip.as_deref())
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to insert audit event"))?;
+
+        Ok(())
+    }
+
+    /// Get the database pool reference.
+    pub fn db(&self) -> &Database {
+        &self.db
+    }
+}
+
+impl AuditSink for AuditSinkSql {
+    fn record(&self, event: AuditEvent) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        async move { self.insert(&event).await.map_err(|e| e.to_string()) }.boxed()
+    }
+}
@@ -0,0 +1,283 @@
+//! Short-TTL caching decorator for identity lookups.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use dashmap::DashMap;
+use futures::future::{BoxFuture, FutureExt};
+use serde_json::{Map, Value};
+
+use crate::core::identity::{UserIdentity, WorkspaceIdentity};
+use crate::adapters::clock::SystemClock;
+use crate::core::usecases::ports::{BatchCreateOutcome, Clock, IdentityRepository, NewIdentity};
+
+/// Default number of entries kept in each cache index
+pub const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+struct CacheEntry {
+    identity: UserIdentity,
+    cached_at: DateTime<Utc>,
+}
+
+/// Identity repository that caches `find_by_identifier` and `find_by_id`
+/// hits in memory.
+///
+/// Meant to absorb bursts of logins for the same account. The two lookups
+/// are cached in separate indexes, each bounded by `max_entries`. Only
+/// successful lookups are cached, so a freshly created identity is never
+/// hidden behind a cached miss.
+///
+/// Every mutation going through this repository (`create`,
+/// `update_identifier`, `soft_delete`, `set_enabled`) drops the affected
+/// entries from both indexes. Mutations made directly against the
+/// underlying store bypass invalidation and stay visible for at most one
+/// TTL, which is why the cache is disabled unless explicitly configured.
+///
+/// Password hashes, lockout counters and credential status are read through
+/// the `CredentialRepository` and are never cached here, so password and
+/// lock changes take effect immediately.
+pub struct CachedIdentityRepository {
+    inner: Arc<dyn IdentityRepository + Send + Sync>,
+    ttl: Duration,
+    max_entries: usize,
+    entries: DashMap<String, CacheEntry>,
+    by_id: DashMap<String, CacheEntry>,
+    /// Bumped on every invalidation so lookups racing a mutation don't
+    /// re-insert the value they read before it.
+    generation: AtomicU64,
+    clock: Arc<dyn Clock + Send + Sync>,
+}
+
+impl CachedIdentityRepository {
+    /// Wrap `inner`, caching up to `max_entries` identifiers and as many
+    /// user ids for `ttl_secs`
+    pub fn new(
+        inner: Arc<dyn IdentityRepository + Send + Sync>,
+        ttl_secs: u64,
+        max_entries: usize,
+    ) -> Self {
+        Self::with_clock(inner, ttl_secs, max_entries, Arc::new(SystemClock))
+    }
+
+    /// Wrap `inner`, reading time from the given clock
+    pub fn with_clock(
+        inner: Arc<dyn IdentityRepository + Send + Sync>,
+        ttl_secs: u64,
+        max_entries: usize,
+        clock: Arc<dyn Clock + Send + Sync>,
+    ) -> Self {
+        Self {
+            inner,
+            ttl: Duration::seconds(ttl_secs.min(u32::MAX as u64) as i64),
+            max_entries: max_entries.max(1),
+            entries: DashMap::new(),
+            by_id: DashMap::new(),
+            generation: AtomicU64::new(0),
+            clock,
+        }
+    }
+
+    /// Drop the cached entry for an identifier
+    pub fn invalidate_identifier(&self, identifier: &str) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.entries.remove(identifier);
+    }
+
+    /// Drop every cached entry resolving to the given user
+    pub fn invalidate_user(&self, user_id: &str) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.entries.retain(|_, entry| entry.identity.id() != user_id);
+        self.by_id.remove(user_id);
+    }
+
+    /// Drop all cached entries
+    pub fn clear(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.entries.clear();
+        self.by_id.clear();
+    }
+
+    /// Number of identifiers currently cached
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true when no identifier is cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of user ids currently cached
+    pub fn id_len(&self) -> usize {
+        self.by_id.len()
+    }
+
+    fn cached(&self, identifier: &str, now: DateTime<Utc>) -> Option<UserIdentity> {
+        self.cached_in(&self.entries, identifier, now)
+    }
+
+    fn cached_in(&self, index: &DashMap<String, CacheEntry>, key: &str, now: DateTime<Utc>) -> Option<UserIdentity> {
+        let entry = index.get(key)?;
+        if now - entry.cached_at < self.ttl {
+            return Some(entry.identity.clone());
+        }
+        drop(entry);
+        index.remove(key);
+        None
+    }
+
+    fn insert_into(&self, index: &DashMap<String, CacheEntry>, key: String, identity: UserIdentity, now: DateTime<Utc>) {
+        if index.len() >= self.max_entries && !index.contains_key(&key) {
+            index.retain(|_, entry| now - entry.cached_at < self.ttl);
+            if index.len() >= self.max_entries {
+                let oldest = index
+                    .iter()
+                    .min_by_key(|entry| entry.cached_at)
+                    .map(|entry| entry.key().clone());
+                if let Some(oldest) = oldest {
+                    index.remove(&oldest);
+                }
+            }
+        }
+        index.insert(key, CacheEntry { identity, cached_at: now });
+    }
+}
+
+impl IdentityRepository for CachedIdentityRepository {
+    fn find_by_identifier(&self, identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let identifier = identifier.to_string();
+        async move {
+            let now = self.clock.now();
+            if let Some(identity) = self.cached(&identifier, now) {
+                tracing::debug!("[IDENTITY_CACHE] Hit for identifier");
+                return Some(identity);
+            }
+
+            let generation = self.generation.load(Ordering::SeqCst);
+            let identity = self.inner.find_by_identifier(&identifier).await?;
+            if self.generation.load(Ordering::SeqCst) == generation {
+                self.insert_into(&self.entries, identifier, identity.clone(), now);
+            }
+            Some(identity)
+        }
+        .boxed()
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let id = id.to_string();
+        async move {
+            let now = self.clock.now();
+            if let Some(identity) = self.cached_in(&self.by_id, &id, now) {
+                tracing::debug!("[IDENTITY_CACHE] Hit for user id");
+                return Some(identity);
+            }
+
+            let generation = self.generation.load(Ordering::SeqCst);
+            let identity = self.inner.find_by_id(&id).await?;
+            if self.generation.load(Ordering::SeqCst) == generation {
+                self.insert_into(&self.by_id, id, identity.clone(), now);
+            }
+            Some(identity)
+        }
+        .boxed()
+    }
+
+    fn find_identifier_by_id(&self, id: &str) -> BoxFuture<'_, Option<String>> {
+        self.inner.find_identifier_by_id(id)
+    }
+
+    fn find_claims_by_id(&self, id: &str) -> BoxFuture<'_, Option<Map<String, Value>>> {
+        // Claims are not cached; they are only fetched on refresh
+        self.inner.find_claims_by_id(id)
+    }
+
+    fn find_workspace_by_id(&self, workspace_id: &str) -> BoxFuture<'_, Option<WorkspaceIdentity>> {
+        self.inner.find_workspace_by_id(workspace_id)
+    }
+
+    fn is_workspace_member(&self, user_id: &str, workspace_id: &str) -> BoxFuture<'_, bool> {
+        self.inner.is_workspace_member(user_id, workspace_id)
+    }
+
+    fn exists(&self, identifier: &str) -> BoxFuture<'_, bool> {
+        let identifier = identifier.to_string();
+        async move {
+            if self.cached(&identifier, self.clock.now()).is_some() {
+                return true;
+            }
+            self.inner.exists(&identifier).await
+        }
+        .boxed()
+    }
+
+    fn create(
+        &self,
+        user_id: &uuid::Uuid,
+        identifier: &str,
+        password_hash: &str,
+        salt: &str,
+        algorithm: &str,
+        iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        let identifier = identifier.to_string();
+        let result = self
+            .inner
+            .create(user_id, &identifier, password_hash, salt, algorithm, iterations);
+        async move {
+            let result = result.await;
+            self.invalidate_identifier(&identifier);
+            result
+        }
+        .boxed()
+    }
+
+    fn create_batch(
+        &self,
+        identities: Vec<NewIdentity>,
+        atomic: bool,
+    ) -> BoxFuture<'_, Result<Vec<BatchCreateOutcome>, String>> {
+        let identifiers: Vec<String> = identities.iter().map(|i| i.identifier.clone()).collect();
+        let result = self.inner.create_batch(identities, atomic);
+        async move {
+            let result = result.await;
+            for identifier in &identifiers {
+                self.invalidate_identifier(identifier);
+            }
+            result
+        }
+        .boxed()
+    }
+
+    fn update_identifier(&self, user_id: &str, new_identifier: &str) -> BoxFuture<'_, Result<(), String>> {
+        let user_id = user_id.to_string();
+        let new_identifier = new_identifier.to_string();
+        async move {
+            let result = self.inner.update_identifier(&user_id, &new_identifier).await;
+            self.invalidate_user(&user_id);
+            self.invalidate_identifier(&new_identifier);
+            result
+        }
+        .boxed()
+    }
+
+    fn soft_delete(&self, user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        let user_id = user_id.to_string();
+        async move {
+            let result = self.inner.soft_delete(&user_id).await;
+            self.invalidate_user(&user_id);
+            result
+        }
+        .boxed()
+    }
+
+    fn set_enabled(&self, user_id: &str, enabled: bool) -> BoxFuture<'_, Result<(), String>> {
+        let user_id = user_id.to_string();
+        async move {
+            let result = self.inner.set_enabled(&user_id, enabled).await;
+            self.invalidate_user(&user_id);
+            result
+        }
+        .boxed()
+    }
+}
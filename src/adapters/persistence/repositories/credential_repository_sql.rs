@@ -5,11 +5,13 @@ use futures::future::FutureExt;
 use sqlx::Row;
 
 use crate::adapters::persistence::{
-    database::Database,
+    database::{map_query_error, map_transaction_error, Database},
     error::{ExecutionError, PersistenceError},
+    models::credential_status_column::{status_from_columns, status_to_columns},
+    retry::{with_retries, RetryPolicy},
 };
-use crate::core::credentials::StoredCredential;
-use crate::core::usecases::ports::CredentialRepository;
+use crate::core::credentials::{CredentialStatus, StoredCredential};
+use crate::core::usecases::ports::{CredentialRepository, LockoutState};
 
 /// SQL-backed repository for credential state management.
 ///
@@ -19,25 +21,83 @@ use crate::core::usecases::ports::CredentialRepository;
 /// - Update failed_attempts counter
 /// - Update locked_until timestamp
 /// - Update password hash and password_changed_at
+/// - Persist the credential lifecycle status
+/// - Record the time of the last successful login and of the last failure
+/// - Keep a bounded history of previous password hashes
+/// - Track failed attempts and lockout per source address
+/// - Retry reads and idempotent updates that hit a transient failure
 /// - Support transactional operations
 ///
+/// The status lives in two columns on the same table:
+///
+/// ```sql
+/// credential_status    TEXT NOT NULL DEFAULT 'active'
+///     CHECK (credential_status IN ('active', 'revoked', 'expired', 'not_yet_valid')),
+/// credential_status_at TEXT NULL
+/// ```
+///
+/// `credential_status_at` holds the variant's timestamp payload verbatim.
+///
+/// Successful logins are tracked in `last_login_at TIMESTAMPTZ NULL`, the
+/// last counted failure in `last_failed_at TIMESTAMPTZ NULL`.
+///
+/// Previous password hashes live in their own table:
+///
+/// ```sql
+/// CREATE TABLE password_history (
+///     id            BIGSERIAL PRIMARY KEY,
+///     user_id       UUID NOT NULL,
+///     password_hash TEXT NOT NULL,
+///     created_at    TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+/// );
+/// CREATE INDEX idx_password_history_user ON password_history (user_id, created_at DESC);
+/// ```
+///
+/// Per-source lockout state, used when lockout is scoped per source IP:
+///
+/// ```sql
+/// CREATE TABLE credential_source_lockout (
+///     user_id         UUID NOT NULL,
+///     source_ip       TEXT NOT NULL,
+///     failed_attempts INTEGER NOT NULL DEFAULT 0,
+///     locked_until    TIMESTAMPTZ NULL,
+///     last_failed_at  TIMESTAMPTZ NULL,
+///     updated_at      TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+///     PRIMARY KEY (user_id, source_ip)
+/// );
+/// ```
+///
 /// Does NOT:
 /// - Hash passwords (that's the crypto adapter)
 /// - Validate policies
 /// - Interpret credentials
 pub struct CredentialRepositorySql {
     db: Database,
+    retry_policy: RetryPolicy,
 }
 
 impl CredentialRepositorySql {
     /// Create a new credential repository with the given database pool.
     pub fn new(db: Database) -> Self {
-        Self { db }
+        Self {
+            db,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Set how queries retry transient failures.
+    ///
+    /// Incrementing the failed-attempt counter is never retried, so a
+    /// repeat cannot count one failure twice.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
     }
 
     /// Get credential state for a user.
     ///
-    /// Returns failed_attempts, locked_until status, and password hash.
+    /// Returns failed_attempts, locked_until status, password hash, the
+    /// credential lifecycle status and the last login and failure times.
     ///
     /// # Errors
     ///
@@ -47,31 +107,78 @@ impl CredentialRepositorySql {
         user_id: &str,
     ) -> Result<CredentialState, PersistenceError> {
         const QUERY: &str = r#"
-            SELECT failed_attempts, locked_until, password_changed_at, password_hash
+            SELECT failed_attempts, locked_until, password_changed_at, password_hash,
+                   credential_status, credential_status_at, last_login_at, last_failed_at
             FROM identity_credential
             WHERE user_id = $1::uuid
         "#;
 
-        let row = sqlx::query(QUERY)
-            .bind(user_id)
-            .fetch_optional(self.db.pool())
-            .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to query credential state: {}",
-                    e
-                )))
-            })?
-            .ok_or_else(|| PersistenceError::Execution(ExecutionError::not_found("Credential")))?;
+        let row = with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(user_id)
+                .fetch_optional(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query credential state"))
+        })
+        .await?
+        .ok_or_else(|| PersistenceError::Execution(ExecutionError::not_found("Credential")))?;
+
+        let status = status_from_columns(
+            row.get::<String, _>("credential_status").as_str(),
+            row.get("credential_status_at"),
+        )?;
 
         Ok(CredentialState {
             failed_attempts: row.get("failed_attempts"),
             locked_until: row.get("locked_until"),
             password_changed_at: row.get("password_changed_at"),
             password_hash: row.get("password_hash"),
+            status,
+            last_login_at: row.get("last_login_at"),
+            last_failed_at: row.get("last_failed_at"),
         })
     }
 
+    /// Set the credential lifecycle status.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Execution(ExecutionError::NotFound)` if the
+    /// user has no credential, or `PersistenceError` on query failure.
+    pub async fn set_status(
+        &self,
+        user_id: &str,
+        status: &CredentialStatus,
+    ) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            UPDATE identity_credential
+            SET credential_status = $1,
+                credential_status_at = $2,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE user_id = $3::uuid
+        "#;
+
+        let (value, status_at) = status_to_columns(status);
+        let status_at = status_at.as_deref();
+
+        let result = with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(value)
+                .bind(status_at)
+                .bind(user_id)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to set credential status"))
+        })
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(PersistenceError::Execution(ExecutionError::not_found("Credential")));
+        }
+
+        Ok(())
+    }
+
     /// Increment failed authentication attempts.
     ///
     /// # Errors
@@ -93,12 +200,7 @@ impl CredentialRepositorySql {
             .bind(user_id)
             .fetch_optional(self.db.pool())
             .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to increment failed attempts: {}",
-                    e
-                )))
-            })?
+            .map_err(|e| map_query_error(e, "failed to increment failed attempts"))?
             .ok_or_else(|| PersistenceError::Execution(ExecutionError::not_found("User")))?;
 
         Ok(row.get("failed_attempts"))
@@ -120,16 +222,14 @@ impl CredentialRepositorySql {
             WHERE user_id = $1::uuid
         "#;
 
-        sqlx::query(QUERY)
-            .bind(user_id)
-            .execute(self.db.pool())
-            .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to reset failed attempts: {}",
-                    e
-                )))
-            })?;
+        with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(user_id)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to reset failed attempts"))
+        })
+        .await?;
 
         Ok(())
     }
@@ -151,17 +251,15 @@ impl CredentialRepositorySql {
             WHERE user_id = $2::uuid
         "#;
 
-        sqlx::query(QUERY)
-            .bind(until)
-            .bind(user_id)
-            .execute(self.db.pool())
-            .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to lock account: {}",
-                    e
-                )))
-            })?;
+        with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(until)
+                .bind(user_id)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to lock account"))
+        })
+        .await?;
 
         Ok(())
     }
@@ -189,22 +287,151 @@ impl CredentialRepositorySql {
             WHERE user_id = $3::uuid
         "#;
 
-        sqlx::query(QUERY)
-            .bind(password_hash)
-            .bind(changed_at)
-            .bind(user_id)
-            .execute(self.db.pool())
-            .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to update password: {}",
-                    e
-                )))
-            })?;
+        with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(password_hash)
+                .bind(changed_at)
+                .bind(user_id)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to update password"))
+        })
+        .await?;
 
         Ok(())
     }
 
+    /// Record a successful login at the given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn record_successful_login(
+        &self,
+        user_id: &str,
+        at: DateTime<Utc>,
+    ) -> Result<(), PersistenceError> {
+        with_retries(self.retry_policy, || async move {
+            record_successful_login(self.db.pool(), user_id, at).await
+        })
+        .await
+    }
+
+    /// Record a failed login counted against the account at the given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn record_failed_login(
+        &self,
+        user_id: &str,
+        at: DateTime<Utc>,
+    ) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            UPDATE identity_credential
+            SET last_failed_at = $1,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE user_id = $2::uuid
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(at)
+                .bind(user_id)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to record failed login"))
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get the user's previous password hashes, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn password_history(&self, user_id: &str, limit: usize) -> Result<Vec<String>, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT password_hash
+            FROM password_history
+            WHERE user_id = $1::uuid
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_scalar::<_, String>(QUERY)
+                .bind(user_id)
+                .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+                .fetch_all(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query password history"))
+        })
+        .await
+    }
+
+    /// Add a previous password hash to the user's history and keep only the
+    /// newest `depth` entries.
+    ///
+    /// Insert and trim run in one transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn push_password_history(
+        &self,
+        user_id: &str,
+        password_hash: &str,
+        depth: usize,
+    ) -> Result<(), PersistenceError> {
+        const INSERT: &str = r#"
+            INSERT INTO password_history (user_id, password_hash)
+            VALUES ($1::uuid, $2)
+        "#;
+        const TRIM: &str = r#"
+            DELETE FROM password_history
+            WHERE user_id = $1::uuid
+              AND id NOT IN (
+                  SELECT id
+                  FROM password_history
+                  WHERE user_id = $1::uuid
+                  ORDER BY created_at DESC, id DESC
+                  LIMIT $2
+              )
+        "#;
+
+        let failed = |e: sqlx::Error| {
+            map_query_error(e, "failed to record password history")
+        };
+
+        with_retries(self.retry_policy, || async move {
+            let mut tx = self
+                .db
+                .pool()
+                .begin()
+                .await
+                .map_err(|e| map_transaction_error(e, "failed to begin password history update"))?;
+            sqlx::query(INSERT)
+                .bind(user_id)
+                .bind(password_hash)
+                .execute(&mut *tx)
+                .await
+                .map_err(failed)?;
+            sqlx::query(TRIM)
+                .bind(user_id)
+                .bind(i64::try_from(depth).unwrap_or(i64::MAX))
+                .execute(&mut *tx)
+                .await
+                .map_err(failed)?;
+            tx.commit()
+                .await
+                .map_err(|e| map_transaction_error(e, "failed to commit password history update"))
+        })
+        .await
+    }
+
     /// Set failed attempts to a specific value (not increment).
     async fn set_failed_attempts(&self, user_id: &str, attempts: u32) -> Result<(), PersistenceError> {
         const QUERY: &str = r#"
@@ -214,17 +441,196 @@ impl CredentialRepositorySql {
             WHERE user_id = $2::uuid
         "#;
 
-        sqlx::query(QUERY)
-            .bind(attempts as i32)
-            .bind(user_id)
-            .execute(self.db.pool())
-            .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to set failed attempts: {}",
-                    e
-                )))
-            })?;
+        with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(attempts as i32)
+                .bind(user_id)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to set failed attempts"))
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get the lockout state counted for one source address.
+    ///
+    /// Returns `None` if nothing is tracked for that source.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn find_source_lockout(
+        &self,
+        user_id: &str,
+        source_ip: &str,
+    ) -> Result<Option<(i32, Option<DateTime<Utc>>, Option<DateTime<Utc>>)>, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT failed_attempts, locked_until, last_failed_at
+            FROM credential_source_lockout
+            WHERE user_id = $1::uuid
+              AND source_ip = $2
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_as(QUERY)
+                .bind(user_id)
+                .bind(source_ip)
+                .fetch_optional(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query source lockout"))
+        })
+        .await
+    }
+
+    /// Whether any source address has failed attempts or a lock counted
+    /// against the account.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn source_lockouts_exist(&self, user_id: &str) -> Result<bool, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM credential_source_lockout
+                WHERE user_id = $1::uuid
+                  AND (failed_attempts > 0 OR locked_until IS NOT NULL)
+            )
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_scalar(QUERY)
+                .bind(user_id)
+                .fetch_one(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query source lockouts"))
+        })
+        .await
+    }
+
+    /// Set the failed attempts counted for one source address.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn set_source_failed_attempts(
+        &self,
+        user_id: &str,
+        source_ip: &str,
+        attempts: u32,
+    ) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            INSERT INTO credential_source_lockout (user_id, source_ip, failed_attempts)
+            VALUES ($1::uuid, $2, $3)
+            ON CONFLICT (user_id, source_ip) DO UPDATE
+            SET failed_attempts = EXCLUDED.failed_attempts,
+                updated_at = CURRENT_TIMESTAMP
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(user_id)
+                .bind(source_ip)
+                .bind(attempts as i32)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to set source failed attempts"))
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Record a failed login counted against one source address.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn record_source_failed_login(
+        &self,
+        user_id: &str,
+        source_ip: &str,
+        at: DateTime<Utc>,
+    ) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            INSERT INTO credential_source_lockout (user_id, source_ip, last_failed_at)
+            VALUES ($1::uuid, $2, $3)
+            ON CONFLICT (user_id, source_ip) DO UPDATE
+            SET last_failed_at = EXCLUDED.last_failed_at,
+                updated_at = CURRENT_TIMESTAMP
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(user_id)
+                .bind(source_ip)
+                .bind(at)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to record source failed login"))
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Lock logins from one source address until a specific timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn lock_source(
+        &self,
+        user_id: &str,
+        source_ip: &str,
+        until: DateTime<Utc>,
+    ) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            INSERT INTO credential_source_lockout (user_id, source_ip, locked_until)
+            VALUES ($1::uuid, $2, $3)
+            ON CONFLICT (user_id, source_ip) DO UPDATE
+            SET locked_until = EXCLUDED.locked_until,
+                updated_at = CURRENT_TIMESTAMP
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(user_id)
+                .bind(source_ip)
+                .bind(until)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to lock source"))
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Delete the lockout state of one source address, or of every source
+    /// address when `source_ip` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn delete_source_lockouts(&self, user_id: &str, source_ip: Option<&str>) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            DELETE FROM credential_source_lockout
+            WHERE user_id = $1::uuid
+              AND ($2::text IS NULL OR source_ip = $2)
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(user_id)
+                .bind(source_ip)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to delete source lockout"))
+        })
+        .await?;
 
         Ok(())
     }
@@ -244,16 +650,33 @@ impl CredentialRepository for CredentialRepositorySql {
                 .ok()
                 .and_then(|state| {
                     let locked_until = state.locked_until.map(|dt| dt.to_rfc3339());
-                    Some(StoredCredential::from_parts(
-                        state.password_hash,
-                        state.failed_attempts as u32,
-                        locked_until,
-                    ))
+                    let last_login_at = state.last_login_at.map(|dt| dt.to_rfc3339());
+                    let last_failed_at = state.last_failed_at.map(|dt| dt.to_rfc3339());
+                    Some(
+                        StoredCredential::from_parts(
+                            state.password_hash,
+                            state.failed_attempts as u32,
+                            locked_until,
+                        )
+                        .with_status(state.status)
+                        .with_last_login_at(last_login_at)
+                        .with_last_failed_at(last_failed_at),
+                    )
                 })
         }
         .boxed()
     }
 
+    fn set_status(&self, user_id: &str, status: CredentialStatus) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        let user_id = user_id.to_string();
+        async move {
+            self.set_status(&user_id, &status)
+                .await
+                .map_err(|e| e.to_string())
+        }
+        .boxed()
+    }
+
     fn update_failed_attempts(&self, user_id: &str, attempts: u32) -> futures::future::BoxFuture<'_, ()> {
         let user_id = user_id.to_string();
         async move {
@@ -280,18 +703,172 @@ impl CredentialRepository for CredentialRepositorySql {
         .boxed()
     }
 
-    fn update_password(&self, user_id: &str, _new_credential: StoredCredential) -> futures::future::BoxFuture<'_, ()> {
+    fn clear_lock(&self, user_id: &str) -> futures::future::BoxFuture<'_, ()> {
         let user_id = user_id.to_string();
         async move {
-            let _ = self.update_password(&user_id, "", Utc::now()).await;
+            // Resetting the counter also clears `locked_until`
+            let _ = self.reset_failed_attempts(&user_id).await;
         }
         .boxed()
     }
 
-    fn initialize_credential_state(&self, _user_id: &str) -> futures::future::BoxFuture<'_, Result<(), String>> {
+    fn unlock(&self, user_id: &str) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        let user_id = user_id.to_string();
         async move {
-            // Credential state is initialized when identity is created
-            Ok(())
+            self.reset_failed_attempts(&user_id)
+                .await
+                .map_err(|e| e.to_string())?;
+            self.delete_source_lockouts(&user_id, None)
+                .await
+                .map_err(|e| e.to_string())
+        }
+        .boxed()
+    }
+
+    fn get_source_lockout(&self, user_id: &str, source_ip: &str) -> futures::future::BoxFuture<'_, LockoutState> {
+        let user_id = user_id.to_string();
+        let source_ip = source_ip.to_string();
+        async move {
+            match self.find_source_lockout(&user_id, &source_ip).await {
+                Ok(Some((failed_attempts, locked_until, last_failed_at))) => LockoutState {
+                    failed_attempts: failed_attempts.max(0) as u32,
+                    locked_until: locked_until.map(|dt| dt.to_rfc3339()),
+                    last_failed_at: last_failed_at.map(|dt| dt.to_rfc3339()),
+                },
+                Ok(None) => LockoutState::default(),
+                Err(e) => {
+                    tracing::error!("[CREDENTIAL_REPO] Error querying source lockout: {:?}", e);
+                    LockoutState::default()
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn has_source_lockouts(&self, user_id: &str) -> futures::future::BoxFuture<'_, bool> {
+        let user_id = user_id.to_string();
+        async move {
+            self.source_lockouts_exist(&user_id).await.unwrap_or_else(|e| {
+                tracing::error!("[CREDENTIAL_REPO] Error querying source lockouts: {:?}", e);
+                false
+            })
+        }
+        .boxed()
+    }
+
+    fn update_source_failed_attempts(
+        &self,
+        user_id: &str,
+        source_ip: &str,
+        attempts: u32,
+    ) -> futures::future::BoxFuture<'_, ()> {
+        let user_id = user_id.to_string();
+        let source_ip = source_ip.to_string();
+        async move {
+            if attempts == 0 {
+                // Nothing left to track for this source once it is reset
+                let _ = self.delete_source_lockouts(&user_id, Some(&source_ip)).await;
+            } else {
+                let _ = self.set_source_failed_attempts(&user_id, &source_ip, attempts).await;
+            }
+        }
+        .boxed()
+    }
+
+    fn lock_source_until(&self, user_id: &str, source_ip: &str, until: &str) -> futures::future::BoxFuture<'_, ()> {
+        let user_id = user_id.to_string();
+        let source_ip = source_ip.to_string();
+        let until = until.to_string();
+        async move {
+            if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(&until) {
+                let _ = self.lock_source(&user_id, &source_ip, dt.with_timezone(&Utc)).await;
+            }
+        }
+        .boxed()
+    }
+
+    fn record_successful_login(&self, user_id: &str, at: &str) -> futures::future::BoxFuture<'_, ()> {
+        let user_id = user_id.to_string();
+        let at = at.to_string();
+        async move {
+            if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(&at) {
+                let _ = self.record_successful_login(&user_id, dt.with_timezone(&Utc)).await;
+            }
+        }
+        .boxed()
+    }
+
+    fn record_failed_login(&self, user_id: &str, at: &str) -> futures::future::BoxFuture<'_, ()> {
+        let user_id = user_id.to_string();
+        let at = at.to_string();
+        async move {
+            if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(&at) {
+                let _ = self.record_failed_login(&user_id, dt.with_timezone(&Utc)).await;
+            }
+        }
+        .boxed()
+    }
+
+    fn record_source_failed_login(&self, user_id: &str, source_ip: &str, at: &str) -> futures::future::BoxFuture<'_, ()> {
+        let user_id = user_id.to_string();
+        let source_ip = source_ip.to_string();
+        let at = at.to_string();
+        async move {
+            if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(&at) {
+                let _ = self
+                    .record_source_failed_login(&user_id, &source_ip, dt.with_timezone(&Utc))
+                    .await;
+            }
+        }
+        .boxed()
+    }
+
+    fn update_password(&self, user_id: &str, new_credential: StoredCredential) -> futures::future::BoxFuture<'_, ()> {
+        let user_id = user_id.to_string();
+        async move {
+            let _ = self.update_password(&user_id, new_credential.as_hash_str(), Utc::now()).await;
+        }
+        .boxed()
+    }
+
+    fn password_history(&self, user_id: &str, limit: usize) -> futures::future::BoxFuture<'_, Vec<StoredCredential>> {
+        let user_id = user_id.to_string();
+        async move {
+            match self.password_history(&user_id, limit).await {
+                Ok(hashes) => hashes.into_iter().map(StoredCredential::from_hash).collect(),
+                Err(e) => {
+                    tracing::error!("[CREDENTIAL_REPO] Error loading password history: {:?}", e);
+                    Vec::new()
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn push_password_history(
+        &self,
+        user_id: &str,
+        previous: StoredCredential,
+        depth: usize,
+    ) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        let user_id = user_id.to_string();
+        async move {
+            self.push_password_history(&user_id, previous.as_hash_str(), depth)
+                .await
+                .map_err(|e| e.to_string())
+        }
+        .boxed()
+    }
+
+    fn initialize_credential_state(&self, user_id: &str) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        let user_id = user_id.to_string();
+        async move {
+            let user_id = user_id.as_str();
+            with_retries(self.retry_policy, || async move {
+                initialize_credential_state(self.db.pool(), user_id).await
+            })
+            .await
+            .map_err(|e| e.to_string())
         }
         .boxed()
     }
@@ -308,6 +885,67 @@ pub struct CredentialState {
     pub password_changed_at: DateTime<Utc>,
     /// The stored password hash
     pub password_hash: String,
+    /// Credential lifecycle status
+    pub status: CredentialStatus,
+    /// Timestamp of the last successful login, if any
+    pub last_login_at: Option<DateTime<Utc>>,
+    /// Timestamp of the last counted failed login, if any
+    pub last_failed_at: Option<DateTime<Utc>>,
+}
+
+/// Record a successful login of `user_id` at `at`.
+///
+/// Takes any executor, so the login can be recorded in the transaction that
+/// persists its session.
+pub(crate) async fn record_successful_login<'e, E>(
+    executor: E,
+    user_id: &str,
+    at: DateTime<Utc>,
+) -> Result<(), PersistenceError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    const QUERY: &str = r#"
+        UPDATE identity_credential
+        SET last_login_at = $1,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE user_id = $2::uuid
+    "#;
+
+    sqlx::query(QUERY)
+        .bind(at)
+        .bind(user_id)
+        .execute(executor)
+        .await
+        .map_err(|e| map_query_error(e, "failed to record login"))?;
+
+    Ok(())
+}
+
+/// Reset a new identity's credential state: no failed attempts and no lock.
+///
+/// Takes any executor, so a caller creating identities in a transaction can
+/// initialize their state in that same transaction.
+pub(crate) async fn initialize_credential_state<'e, E>(executor: E, user_id: &str) -> Result<(), PersistenceError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    const QUERY: &str = r#"
+        UPDATE identity_credential
+        SET failed_attempts = 0,
+            locked_until = NULL,
+            last_failed_at = NULL,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE user_id = $1::uuid
+    "#;
+
+    sqlx::query(QUERY)
+        .bind(user_id)
+        .execute(executor)
+        .await
+        .map_err(|e| map_query_error(e, "failed to initialize credential state"))?;
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -324,6 +962,9 @@ mod tests {
             locked_until: Some(now),
             password_changed_at: now,
             password_hash: "$argon2id$v=19$m=65536,t=3,p=4$...".to_string(),
+            status: CredentialStatus::Active,
+            last_login_at: None,
+            last_failed_at: None,
         };
 
         assert_eq!(state.failed_attempts, 3);
@@ -16,7 +16,7 @@ use uuid::Uuid;
 use anyhow::Result;
 
 use crate::adapters::persistence::{
-    database::Database,
+    database::{map_query_error, Database},
     error::{ConstraintError, ExecutionError, PersistenceError},
     models::ExternalIdentityRow,
 };
@@ -52,12 +52,7 @@ impl ExternalIdentityRepositorySql {
             .bind(provider_user_id)
             .fetch_optional(self.db.pool())
             .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to query external identity by provider+user_id: {}",
-                    e
-                )))
-            })?;
+            .map_err(|e| map_query_error(e, "failed to query external identity by provider+user_id"))?;
 
         Ok(row_opt.map(|row| row.user_id))
     }
@@ -100,10 +95,7 @@ impl ExternalIdentityRepositorySql {
                         "provider+provider_user_id already linked to different user",
                     ))
                 } else {
-                    PersistenceError::Execution(ExecutionError::query_failed(format!(
-                        "failed to upsert external identity: {}",
-                        e
-                    )))
+                    map_query_error(e, "failed to upsert external identity")
                 }
             })?;
 
@@ -128,12 +120,7 @@ impl ExternalIdentityRepositorySql {
             .bind(provider_user_id)
             .execute(self.db.pool())
             .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to delete external identity: {}",
-                    e
-                )))
-            })?;
+            .map_err(|e| map_query_error(e, "failed to delete external identity"))?;
 
         if result.rows_affected() == 0 {
             return Err(PersistenceError::Execution(ExecutionError::not_found(
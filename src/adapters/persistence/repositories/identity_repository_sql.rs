@@ -2,12 +2,20 @@
 
 use futures::future::FutureExt;
 use crate::adapters::persistence::{
-    database::Database,
+    database::{map_query_error, map_transaction_error, Database},
     error::{ConstraintError, ExecutionError, PersistenceError},
     models::IdentityRow,
+    repositories::credential_repository_sql::initialize_credential_state,
+    retry::{is_transient, with_retries, RetryPolicy},
 };
-use crate::core::identity::UserIdentity;
-use crate::core::usecases::ports::IdentityRepository;
+use crate::core::identity::{UserIdentity, WorkspaceIdentity};
+use crate::core::usecases::ports::{BatchCreateOutcome, IdentityRepository, NewIdentity};
+
+const INSERT_IDENTITY: &str = r#"
+    INSERT INTO identity_credential
+    (user_id, identifier, password_hash, failed_attempts, password_changed_at, created_at, updated_at)
+    VALUES ($1::uuid, $2, $3, 0, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+"#;
 
 /// SQL-backed repository for user identity and credential data.
 ///
@@ -15,7 +23,29 @@ use crate::core::usecases::ports::IdentityRepository;
 /// Responsibilities:
 /// - Retrieve identity by identifier (username/email)
 /// - Retrieve identity by user_id
+/// - Check identifier presence without fetching the row
 /// - Map database rows to domain entities
+/// - Tombstone identities, revoking their sessions in the same transaction
+/// - Create identities in batches, optionally in a single transaction
+/// - Resolve workspaces and check workspace membership
+/// - Retry queries and whole transactions that hit a transient failure
+///
+/// Workspaces and their members live in their own tables; ids from the
+/// Workspace Service are stored as text:
+///
+/// ```sql
+/// CREATE TABLE workspace (
+///     id          TEXT PRIMARY KEY,
+///     created_at  TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+///     deleted_at  TIMESTAMPTZ NULL
+/// );
+/// CREATE TABLE workspace_member (
+///     workspace_id  TEXT NOT NULL REFERENCES workspace (id),
+///     user_id       UUID NOT NULL,
+///     created_at    TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+///     PRIMARY KEY (workspace_id, user_id)
+/// );
+/// ```
 ///
 /// Does NOT:
 /// - Hash or verify passwords
@@ -23,12 +53,22 @@ use crate::core::usecases::ports::IdentityRepository;
 /// - Validate policies
 pub struct IdentityRepositorySql {
     db: Database,
+    retry_policy: RetryPolicy,
 }
 
 impl IdentityRepositorySql {
     /// Create a new identity repository with the given database pool.
     pub fn new(db: Database) -> Self {
-        Self { db }
+        Self {
+            db,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Set how queries retry transient failures.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
     }
 
     /// Find identity by identifier (username/email).
@@ -45,20 +85,18 @@ impl IdentityRepositorySql {
             SELECT user_id::TEXT, identifier, password_hash, failed_attempts, 
                    locked_until, password_changed_at, created_at, updated_at
             FROM identity_credential
-            WHERE identifier = $1
+            WHERE identifier = $1 AND deleted_at IS NULL
         "#;
 
-        sqlx::query_as::<_, IdentityRow>(QUERY)
-            .bind(identifier)
-            .fetch_optional(self.db.pool())
-            .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to query identity by identifier: {}",
-                    e
-                )))
-            })?
-            .ok_or_else(|| PersistenceError::Execution(ExecutionError::not_found("Identity")))
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_as::<_, IdentityRow>(QUERY)
+                .bind(identifier)
+                .fetch_optional(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query identity by identifier"))
+        })
+        .await?
+        .ok_or_else(|| PersistenceError::Execution(ExecutionError::not_found("Identity")))
     }
 
     /// Find identity by user ID.
@@ -72,20 +110,91 @@ impl IdentityRepositorySql {
             SELECT user_id::TEXT, identifier, password_hash, failed_attempts,
                    locked_until, password_changed_at, created_at, updated_at
             FROM identity_credential
-            WHERE user_id = $1::uuid
+            WHERE user_id = $1::uuid AND deleted_at IS NULL
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_as::<_, IdentityRow>(QUERY)
+                .bind(user_id)
+                .fetch_optional(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query identity by user_id"))
+        })
+        .await?
+        .ok_or_else(|| PersistenceError::Execution(ExecutionError::not_found("Identity")))
+    }
+
+    /// Check whether a live identity exists for an identifier.
+    ///
+    /// Uses `SELECT EXISTS(...)` so no row is fetched or mapped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Execution(ExecutionError::QueryFailed)` if the query fails.
+    pub async fn identifier_exists(&self, identifier: &str) -> Result<bool, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT EXISTS(
+                SELECT 1 FROM identity_credential
+                WHERE identifier = $1 AND deleted_at IS NULL
+            )
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_scalar::<_, bool>(QUERY)
+                .bind(identifier)
+                .fetch_one(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to check identity existence"))
+        })
+        .await
+    }
+
+    /// Check whether a live workspace exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Execution(ExecutionError::QueryFailed)` if the query fails.
+    pub async fn workspace_exists(&self, workspace_id: &str) -> Result<bool, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT EXISTS(
+                SELECT 1 FROM workspace
+                WHERE id = $1 AND deleted_at IS NULL
+            )
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_scalar::<_, bool>(QUERY)
+                .bind(workspace_id)
+                .fetch_one(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to check workspace existence"))
+        })
+        .await
+    }
+
+    /// Check whether a user is a member of a live workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Execution(ExecutionError::QueryFailed)` if the query fails.
+    pub async fn workspace_member_exists(&self, user_id: &str, workspace_id: &str) -> Result<bool, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT EXISTS(
+                SELECT 1 FROM workspace_member m
+                JOIN workspace w ON w.id = m.workspace_id
+                WHERE m.workspace_id = $1 AND m.user_id = $2::uuid AND w.deleted_at IS NULL
+            )
         "#;
 
-        sqlx::query_as::<_, IdentityRow>(QUERY)
-            .bind(user_id)
-            .fetch_optional(self.db.pool())
-            .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to query identity by user_id: {}",
-                    e
-                )))
-            })?
-            .ok_or_else(|| PersistenceError::Execution(ExecutionError::not_found("Identity")))
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_scalar::<_, bool>(QUERY)
+                .bind(workspace_id)
+                .bind(user_id)
+                .fetch_one(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to check workspace membership"))
+        })
+        .await
     }
 
     /// Get the database pool reference.
@@ -112,33 +221,172 @@ impl IdentityRepositorySql {
         identifier: &str,
         password_hash: &str,
     ) -> Result<(), PersistenceError> {
-        const QUERY: &str = r#"
-            INSERT INTO identity_credential
-            (user_id, identifier, password_hash, failed_attempts, password_changed_at, created_at, updated_at)
-            VALUES ($1::uuid, $2, $3, 0, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
-        "#;
+        with_retries(self.retry_policy, || async move {
+            sqlx::query(INSERT_IDENTITY)
+                .bind(user_id)
+                .bind(identifier)
+                .bind(password_hash)
+                .execute(self.db.pool())
+                .await
+                .map_err(map_insert_error)
+        })
+        .await?;
 
-        sqlx::query(QUERY)
-            .bind(user_id)
-            .bind(identifier)
-            .bind(password_hash)
-            .execute(self.db.pool())
-            .await
-            .map_err(|e| {
-                // Check for unique constraint violation
-                if e.to_string().contains("unique constraint") {
-                    PersistenceError::Constraint(ConstraintError::unique_violation(
-                        "identifier already exists",
-                    ))
-                } else {
-                    PersistenceError::Execution(ExecutionError::query_failed(format!(
-                        "failed to create identity: {}",
-                        e
-                    )))
+        Ok(())
+    }
+
+    /// Create several identities, returning one outcome per input.
+    ///
+    /// In atomic mode all inserts share one transaction, which also
+    /// initializes each new identity's credential state: the first failure
+    /// rolls it back and every other item reports `RolledBack`. Otherwise
+    /// each insert runs on its own and the outcomes are independent. An
+    /// atomic batch aborted by a transient failure is run again as a whole.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Execution(ExecutionError::TransactionFailed)` if the
+    /// atomic transaction cannot be started or committed, or the transient error once
+    /// the retries are used up; nothing is created in either case.
+    pub async fn create_identities_batch(
+        &self,
+        identities: &[NewIdentity],
+        atomic: bool,
+    ) -> Result<Vec<BatchCreateOutcome>, PersistenceError> {
+        if !atomic {
+            let mut outcomes = Vec::with_capacity(identities.len());
+            for identity in identities {
+                let result = self
+                    .create_identity(&identity.user_id.to_string(), &identity.identifier, &identity.password_hash)
+                    .await;
+                outcomes.push(batch_outcome(result));
+            }
+            return Ok(outcomes);
+        }
+
+        with_retries(self.retry_policy, || async move {
+            let mut tx = self
+                .db
+                .pool()
+                .begin()
+                .await
+                .map_err(|e| map_transaction_error(e, "failed to begin identity batch"))?;
+
+            for (index, identity) in identities.iter().enumerate() {
+                let result = sqlx::query(INSERT_IDENTITY)
+                    .bind(identity.user_id.to_string())
+                    .bind(&identity.identifier)
+                    .bind(&identity.password_hash)
+                    .execute(&mut *tx)
+                    .await;
+
+                let result = match result {
+                    Ok(_) => initialize_credential_state(&mut *tx, &identity.user_id.to_string()).await,
+                    Err(e) => Err(map_insert_error(e)),
+                };
+
+                if let Err(error) = result {
+                    // Dropping the transaction rolls back every earlier write
+                    if is_transient(&error) {
+                        return Err(error);
+                    }
+                    let mut outcomes = vec![BatchCreateOutcome::RolledBack; identities.len()];
+                    outcomes[index] = batch_outcome(Err(error));
+                    return Ok(outcomes);
                 }
-            })?;
+            }
 
-        Ok(())
+            tx.commit()
+                .await
+                .map_err(|e| map_transaction_error(e, "failed to commit identity batch"))?;
+
+            Ok(vec![BatchCreateOutcome::Created; identities.len()])
+        })
+        .await
+    }
+
+    /// Tombstone an identity after revoking all of its sessions.
+    ///
+    /// Sessions reference `identity_credential`, so a hard delete would either
+    /// be blocked by the foreign key or orphan them. Instead, both steps run in
+    /// one transaction: every active session is revoked (kept for audit, never
+    /// deleted) and the identity row is stamped with `deleted_at`, after which
+    /// lookups no longer find it.
+    ///
+    /// Returns the number of sessions revoked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Execution(ExecutionError::NotFound)` if no live identity exists.
+    /// Returns `PersistenceError::Execution(ExecutionError::TransactionFailed)` if the transaction
+    /// cannot be started or committed; nothing is changed in that case.
+    pub async fn delete_identity_and_revoke_sessions(&self, user_id: &str) -> Result<u64, PersistenceError> {
+        const REVOKE_SESSIONS: &str = r#"
+            UPDATE auth_session
+            SET revoked_at = CURRENT_TIMESTAMP,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE user_id = $1::uuid AND revoked_at IS NULL
+        "#;
+        const TOMBSTONE_IDENTITY: &str = r#"
+            UPDATE identity_credential
+            SET deleted_at = CURRENT_TIMESTAMP,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE user_id = $1::uuid AND deleted_at IS NULL
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            let mut tx = self
+                .db
+                .pool()
+                .begin()
+                .await
+                .map_err(|e| map_transaction_error(e, "failed to begin identity deletion"))?;
+
+            let revoked = sqlx::query(REVOKE_SESSIONS)
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| map_query_error(e, "failed to revoke sessions for deleted identity"))?
+                .rows_affected();
+
+            let tombstoned = sqlx::query(TOMBSTONE_IDENTITY)
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| map_query_error(e, "failed to delete identity"))?
+                .rows_affected();
+
+            if tombstoned == 0 {
+                // Dropping the transaction rolls back the session revocation
+                return Err(PersistenceError::Execution(ExecutionError::not_found("Identity")));
+            }
+
+            tx.commit()
+                .await
+                .map_err(|e| map_transaction_error(e, "failed to commit identity deletion"))?;
+
+            Ok(revoked)
+        })
+        .await
+    }
+}
+
+/// Map an insert failure, telling unique violations apart from other errors.
+fn map_insert_error(e: sqlx::Error) -> PersistenceError {
+    if e.to_string().contains("unique constraint") {
+        PersistenceError::Constraint(ConstraintError::unique_violation(
+            "identifier already exists",
+        ))
+    } else {
+        map_query_error(e, "failed to create identity")
+    }
+}
+
+fn batch_outcome(result: Result<(), PersistenceError>) -> BatchCreateOutcome {
+    match result {
+        Ok(()) => BatchCreateOutcome::Created,
+        Err(PersistenceError::Constraint(e)) => BatchCreateOutcome::Conflict(e.to_string()),
+        Err(e) => BatchCreateOutcome::Failed(e.to_string()),
     }
 }
 
@@ -165,6 +413,27 @@ impl IdentityRepository for IdentityRepositorySql {
         .boxed()
     }
 
+    fn find_identifier_by_id(&self, id: &str) -> futures::future::BoxFuture<'_, Option<String>> {
+        let id = id.to_string();
+        async move {
+            self.find_by_id(&id)
+                .await
+                .ok()
+                .map(|row| row.identifier)
+        }
+        .boxed()
+    }
+
+    fn exists(&self, identifier: &str) -> futures::future::BoxFuture<'_, bool> {
+        let identifier = identifier.to_string();
+        async move {
+            self.identifier_exists(&identifier)
+                .await
+                .unwrap_or(false)
+        }
+        .boxed()
+    }
+
     fn create(&self, user_id: &uuid::Uuid, identifier: &str, password_hash: &str, _salt: &str, _algorithm: &str, _iterations: u32) -> futures::future::BoxFuture<'_, Result<(), String>> {
         let user_id_str = user_id.to_string();
         let identifier = identifier.to_string();
@@ -177,6 +446,59 @@ impl IdentityRepository for IdentityRepositorySql {
         }
         .boxed()
     }
+
+    fn create_batch(
+        &self,
+        identities: Vec<NewIdentity>,
+        atomic: bool,
+    ) -> futures::future::BoxFuture<'_, Result<Vec<BatchCreateOutcome>, String>> {
+        async move {
+            self.create_identities_batch(&identities, atomic)
+                .await
+                .map_err(|e| e.to_string())
+        }
+        .boxed()
+    }
+
+    fn find_workspace_by_id(&self, workspace_id: &str) -> futures::future::BoxFuture<'_, Option<WorkspaceIdentity>> {
+        let workspace_id = workspace_id.to_string();
+        async move {
+            match self.workspace_exists(&workspace_id).await {
+                Ok(true) => Some(WorkspaceIdentity::new(workspace_id)),
+                Ok(false) => None,
+                Err(e) => {
+                    tracing::error!("Failed to look up workspace {}: {}", workspace_id, e);
+                    None
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn is_workspace_member(&self, user_id: &str, workspace_id: &str) -> futures::future::BoxFuture<'_, bool> {
+        let user_id = user_id.to_string();
+        let workspace_id = workspace_id.to_string();
+        async move {
+            self.workspace_member_exists(&user_id, &workspace_id)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::error!("Failed to check membership of workspace {}: {}", workspace_id, e);
+                    false
+                })
+        }
+        .boxed()
+    }
+
+    fn soft_delete(&self, user_id: &str) -> futures::future::BoxFuture<'_, Result<(), String>> {
+        let user_id = user_id.to_string();
+        async move {
+            self.delete_identity_and_revoke_sessions(&user_id)
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        }
+        .boxed()
+    }
 }
 
 #[cfg(test)]
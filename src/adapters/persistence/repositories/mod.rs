@@ -10,15 +10,35 @@ Each repository:
  - Does NOT contain business logic
 */
 
+pub mod audit_sink_sql;
+pub mod cached_identity_repository;
 pub mod credential_repository_sql;
 pub mod external_identity_repository_sql;
 pub mod identity_repository_sql;
+pub mod opaque_token_store_sql;
+pub mod passkey_repository_sql;
 pub mod session_repository_sql;
+pub mod reset_token_store_sql;
+pub mod service_registry_sql;
+pub mod token_deny_list_sql;
+pub mod token_watermark_store_sql;
+pub mod totp_repository_sql;
+pub mod unit_of_work_sql;
 
+pub use audit_sink_sql::AuditSinkSql;
+pub use cached_identity_repository::CachedIdentityRepository;
 pub use credential_repository_sql::CredentialRepositorySql;
 pub use external_identity_repository_sql::ExternalIdentityRepositorySql;
 pub use identity_repository_sql::IdentityRepositorySql;
+pub use opaque_token_store_sql::OpaqueTokenStoreSql;
+pub use passkey_repository_sql::PasskeyRepositorySql;
 pub use session_repository_sql::SessionRepositorySql;
+pub use reset_token_store_sql::ResetTokenStoreSql;
+pub use service_registry_sql::ServiceRegistrySql;
+pub use token_deny_list_sql::TokenDenyListSql;
+pub use token_watermark_store_sql::TokenWatermarkStoreSql;
+pub use totp_repository_sql::TotpRepositorySql;
+pub use unit_of_work_sql::UnitOfWorkSql;
 
 #[cfg(test)]
 mod tests;
\ No newline at end of file
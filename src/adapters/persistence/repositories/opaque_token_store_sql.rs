@@ -0,0 +1,187 @@
+//! SQL-backed implementation of the opaque token store.
+
+use chrono::{DateTime, TimeZone, Utc};
+use futures::future::FutureExt;
+
+use crate::adapters::persistence::{
+    database::{map_query_error, Database},
+    error::PersistenceError,
+};
+use crate::core::error::{AuthenticationError, CoreError, InvariantError};
+use crate::core::usecases::ports::{OpaqueTokenRecord, OpaqueTokenStore};
+
+/// SQL-backed store of opaque reference tokens.
+///
+/// Implements operations against the `opaque_token` table:
+///
+/// ```sql
+/// CREATE TABLE opaque_token (
+///     token_hash  TEXT PRIMARY KEY,
+///     token_type  TEXT NOT NULL,
+///     claims      TEXT NOT NULL,
+///     expires_at  TIMESTAMPTZ NOT NULL,
+///     created_at  TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+/// );
+/// ```
+///
+/// Responsibilities:
+/// - Record an issued token's hash, type, claims and expiry
+/// - Look a token up by hash, ignoring lapsed records
+/// - Delete a token's record to revoke it
+/// - Delete lapsed records when purged, which the session cleaner does
+///   periodically
+///
+/// Does NOT:
+/// - Generate, hash or compare token values
+pub struct OpaqueTokenStoreSql {
+    db: Database,
+}
+
+impl OpaqueTokenStoreSql {
+    /// Create a new token store with the given database pool.
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    /// Insert the record of a newly issued token.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure, including a hash
+    /// collision with an existing record.
+    pub async fn insert(&self, record: &OpaqueTokenRecord) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            INSERT INTO opaque_token (token_hash, token_type, claims, expires_at)
+            VALUES ($1, $2, $3, $4)
+        "#;
+
+        let expires_at = Utc.timestamp_opt(record.expires_at, 0).single().unwrap_or_else(Utc::now);
+
+        sqlx::query(QUERY)
+            .bind(&record.token_hash)
+            .bind(&record.token_type)
+            .bind(&record.claims)
+            .bind(expires_at)
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to store opaque token"))?;
+
+        Ok(())
+    }
+
+    /// Find the record for `token_hash` that has not lapsed yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn find(&self, token_hash: &str) -> Result<Option<OpaqueTokenRecord>, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT token_hash, token_type, claims, expires_at
+            FROM opaque_token
+            WHERE token_hash = $1
+            AND expires_at > CURRENT_TIMESTAMP
+        "#;
+
+        let row: Option<(String, String, String, DateTime<Utc>)> = sqlx::query_as(QUERY)
+            .bind(token_hash)
+            .fetch_optional(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to query opaque token"))?;
+
+        Ok(row.map(|(token_hash, token_type, claims, expires_at)| OpaqueTokenRecord {
+            token_hash,
+            token_type,
+            claims,
+            expires_at: expires_at.timestamp(),
+        }))
+    }
+
+    /// Delete the record for `token_hash`.
+    ///
+    /// Returns whether a record was deleted.
+    pub async fn delete(&self, token_hash: &str) -> Result<bool, PersistenceError> {
+        const QUERY: &str = r#"
+            DELETE FROM opaque_token
+            WHERE token_hash = $1
+        "#;
+
+        let result = sqlx::query(QUERY)
+            .bind(token_hash)
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to delete opaque token"))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete records for tokens that have expired.
+    ///
+    /// Returns the number of records deleted.
+    pub async fn delete_expired(&self) -> Result<u64, PersistenceError> {
+        const QUERY: &str = r#"
+            DELETE FROM opaque_token
+            WHERE expires_at <= CURRENT_TIMESTAMP
+        "#;
+
+        let result = sqlx::query(QUERY)
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to delete expired opaque tokens"))?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Get the database pool reference.
+    pub fn db(&self) -> &Database {
+        &self.db
+    }
+}
+
+impl OpaqueTokenStore for OpaqueTokenStoreSql {
+    fn put(&self, record: OpaqueTokenRecord) -> futures::future::BoxFuture<'_, Result<(), CoreError>> {
+        async move {
+            self.insert(&record).await.map_err(|e| {
+                CoreError::from(AuthenticationError::incomplete_flow(format!("opaque token storage failed: {}", e)))
+            })
+        }
+        .boxed()
+    }
+
+    fn get(&self, token_hash: &str) -> futures::future::BoxFuture<'_, Option<OpaqueTokenRecord>> {
+        let token_hash = token_hash.to_string();
+        async move {
+            match self.find(&token_hash).await {
+                Ok(record) => record,
+                Err(e) => {
+                    // Fail closed: a token we cannot look up does not validate
+                    tracing::error!("[OPAQUE_TOKEN_STORE] Error looking up opaque token: {:?}", e);
+                    None
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn remove(&self, token_hash: &str) -> futures::future::BoxFuture<'_, bool> {
+        let token_hash = token_hash.to_string();
+        async move {
+            match self.delete(&token_hash).await {
+                Ok(deleted) => deleted,
+                Err(e) => {
+                    tracing::error!("[OPAQUE_TOKEN_STORE] Error deleting opaque token: {:?}", e);
+                    false
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn purge_expired(&self) -> futures::future::BoxFuture<'_, Result<u64, CoreError>> {
+        async move {
+            self.delete_expired()
+                .await
+                .map_err(|e| InvariantError::dependency_unavailable("opaque token store", e.to_string()).into())
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,161 @@
+//! SQL-backed implementation of the passkey repository.
+
+use futures::future::{BoxFuture, FutureExt};
+
+use crate::adapters::persistence::{
+    database::{map_query_error, Database},
+    error::PersistenceError,
+};
+use crate::core::usecases::ports::{PasskeyCredential, PasskeyRepository};
+
+/// SQL-backed repository of registered passkeys.
+///
+/// Implements operations against the `passkey_credential` table:
+///
+/// ```sql
+/// CREATE TABLE passkey_credential (
+///     credential_id TEXT PRIMARY KEY,
+///     user_id       UUID NOT NULL,
+///     public_key    BYTEA NOT NULL,
+///     sign_count    BIGINT NOT NULL DEFAULT 0,
+///     created_at    TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+///     last_used_at  TIMESTAMPTZ
+/// );
+///
+/// CREATE INDEX idx_passkey_credential_user ON passkey_credential (user_id);
+/// ```
+///
+/// Responsibilities:
+/// - Store a passkey's public key and initial signature counter
+/// - Look a passkey up by credential ID
+/// - Advance the signature counter, only from the value last read
+///
+/// Does NOT:
+/// - Verify WebAuthn responses or signatures
+/// - Decide whether a counter is acceptable
+pub struct PasskeyRepositorySql {
+    db: Database,
+}
+
+impl PasskeyRepositorySql {
+    /// Create a new passkey repository with the given database pool.
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    /// Find the passkey with `credential_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn find(&self, credential_id: &str) -> Result<Option<PasskeyCredential>, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT credential_id, user_id::text, public_key, sign_count
+            FROM passkey_credential
+            WHERE credential_id = $1
+        "#;
+
+        let row: Option<(String, String, Vec<u8>, i64)> = sqlx::query_as(QUERY)
+            .bind(credential_id)
+            .fetch_optional(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to query passkey"))?;
+
+        Ok(row.map(|(credential_id, user_id, public_key, sign_count)| PasskeyCredential {
+            credential_id,
+            user_id,
+            public_key,
+            sign_count: u32::try_from(sign_count).unwrap_or(u32::MAX),
+        }))
+    }
+
+    /// Insert a newly registered passkey.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure, including a credential
+    /// ID that is already registered.
+    pub async fn insert(&self, credential: &PasskeyCredential) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            INSERT INTO passkey_credential (credential_id, user_id, public_key, sign_count)
+            VALUES ($1, $2::uuid, $3, $4)
+        "#;
+
+        sqlx::query(QUERY)
+            .bind(&credential.credential_id)
+            .bind(&credential.user_id)
+            .bind(&credential.public_key)
+            .bind(i64::from(credential.sign_count))
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to store passkey"))?;
+
+        Ok(())
+    }
+
+    /// Set the signature counter to `sign_count` if it is still `expected`.
+    ///
+    /// Returns whether the counter was updated.
+    pub async fn advance_sign_count(
+        &self,
+        credential_id: &str,
+        expected: u32,
+        sign_count: u32,
+    ) -> Result<bool, PersistenceError> {
+        const QUERY: &str = r#"
+            UPDATE passkey_credential
+            SET sign_count = $3, last_used_at = CURRENT_TIMESTAMP
+            WHERE credential_id = $1
+            AND sign_count = $2
+        "#;
+
+        let result = sqlx::query(QUERY)
+            .bind(credential_id)
+            .bind(i64::from(expected))
+            .bind(i64::from(sign_count))
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to update passkey counter"))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Get the database pool reference.
+    pub fn db(&self) -> &Database {
+        &self.db
+    }
+}
+
+impl PasskeyRepository for PasskeyRepositorySql {
+    fn find_by_credential_id(&self, credential_id: &str) -> BoxFuture<'_, Option<PasskeyCredential>> {
+        let credential_id = credential_id.to_string();
+
+        async move {
+            match self.find(&credential_id).await {
+                Ok(credential) => credential,
+                Err(e) => {
+                    tracing::error!("[PASSKEY_REPO] Error finding passkey: {:?}", e);
+                    None
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn create(&self, credential: &PasskeyCredential) -> BoxFuture<'_, Result<(), String>> {
+        let credential = credential.clone();
+
+        async move { self.insert(&credential).await.map_err(|e| e.to_string()) }.boxed()
+    }
+
+    fn update_sign_count(&self, credential_id: &str, expected: u32, sign_count: u32) -> BoxFuture<'_, Result<bool, String>> {
+        let credential_id = credential_id.to_string();
+
+        async move {
+            self.advance_sign_count(&credential_id, expected, sign_count)
+                .await
+                .map_err(|e| e.to_string())
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,129 @@
+//! SQL-backed implementation of the reset token store.
+
+use chrono::{DateTime, TimeZone, Utc};
+use futures::future::FutureExt;
+
+use crate::adapters::persistence::{
+    database::{map_query_error, Database},
+    error::PersistenceError,
+};
+use crate::core::error::{AuthenticationError, CoreError, TokenError};
+use crate::core::usecases::ports::ResetTokenStore;
+
+/// SQL-backed store of consumed password-reset tokens.
+///
+/// Implements operations against the `consumed_reset_token` table:
+///
+/// ```sql
+/// CREATE TABLE consumed_reset_token (
+///     jti         TEXT PRIMARY KEY,
+///     expires_at  TIMESTAMPTZ NOT NULL,
+///     consumed_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+/// );
+/// ```
+///
+/// Responsibilities:
+/// - Record a reset token as consumed, exactly once per jti
+/// - Delete entries whose token has expired
+///
+/// Does NOT:
+/// - Validate or decode reset tokens
+/// - Change passwords
+pub struct ResetTokenStoreSql {
+    db: Database,
+}
+
+impl ResetTokenStoreSql {
+    /// Create a new reset token store with the given database pool.
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    /// Mark a reset token as consumed.
+    ///
+    /// Relies on the primary key so that concurrent inserts for the same
+    /// jti cannot both succeed. Returns `None` if this call consumed the
+    /// token, or the original `consumed_at` if it had already been consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn consume(
+        &self,
+        jti: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Option<DateTime<Utc>>, PersistenceError> {
+        const QUERY: &str = r#"
+            INSERT INTO consumed_reset_token (jti, expires_at)
+            VALUES ($1, $2)
+            ON CONFLICT (jti) DO NOTHING
+        "#;
+
+        let result = sqlx::query(QUERY)
+            .bind(jti)
+            .bind(expires_at)
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to consume reset token"))?;
+
+        if result.rows_affected() == 1 {
+            return Ok(None);
+        }
+
+        const LOOKUP: &str = r#"
+            SELECT consumed_at
+            FROM consumed_reset_token
+            WHERE jti = $1
+        "#;
+
+        let consumed_at: DateTime<Utc> = sqlx::query_scalar(LOOKUP)
+            .bind(jti)
+            .fetch_one(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to query consumed reset token"))?;
+
+        Ok(Some(consumed_at))
+    }
+
+    /// Delete consumed entries for tokens that have expired.
+    ///
+    /// Returns the number of entries deleted.
+    pub async fn delete_expired(&self) -> Result<u64, PersistenceError> {
+        const QUERY: &str = r#"
+            DELETE FROM consumed_reset_token
+            WHERE expires_at < CURRENT_TIMESTAMP
+        "#;
+
+        let result = sqlx::query(QUERY)
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to delete expired reset tokens"))?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Get the database pool reference.
+    pub fn db(&self) -> &Database {
+        &self.db
+    }
+}
+
+impl ResetTokenStore for ResetTokenStoreSql {
+    fn consume_reset_token(&self, jti: &str, expires_at: i64) -> futures::future::BoxFuture<'_, Result<(), CoreError>> {
+        let jti = jti.to_string();
+        let expires_at = Utc.timestamp_opt(expires_at, 0).single().unwrap_or_else(Utc::now);
+
+        async move {
+            match self.consume(&jti, expires_at).await {
+                Ok(None) => Ok(()),
+                Ok(Some(consumed_at)) => Err(TokenError::revoked(consumed_at.to_rfc3339()).into()),
+                Err(e) => Err(AuthenticationError::incomplete_flow(format!(
+                    "reset token consumption failed: {}",
+                    e
+                ))
+                .into()),
+            }
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,406 @@
+//! SQL-backed implementation of the service registry.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use sha2::{Digest, Sha256};
+use sqlx::Row;
+
+use crate::adapters::persistence::{
+    database::{map_query_error, Database},
+    error::{ConstraintError, ExecutionError, PersistenceError},
+};
+use crate::core::credentials::StoredCredential;
+use crate::core::usecases::ports::{PasswordHasher, ServiceRegistry};
+
+/// Status stored for a service account that may authenticate.
+const STATUS_ACTIVE: &str = "active";
+/// Status stored for a service account that may not authenticate.
+const STATUS_DISABLED: &str = "disabled";
+
+/// Cached view of one `service_account` row.
+#[derive(Debug, Clone)]
+struct ServiceAccount {
+    secret_hash: String,
+    active: bool,
+}
+
+/// SQL-backed registry of internal service accounts.
+///
+/// Implements operations against the `service_account` table:
+///
+/// ```sql
+/// CREATE TABLE service_account (
+///     service_id  TEXT PRIMARY KEY,
+///     secret_hash TEXT NOT NULL,
+///     status      TEXT NOT NULL DEFAULT 'active'
+///                 CHECK (status IN ('active', 'disabled')),
+///     created_at  TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+///     rotated_at  TIMESTAMPTZ NULL
+/// );
+/// ```
+///
+/// The `ServiceRegistry` port is synchronous, so its methods answer from a
+/// snapshot of the table. The snapshot is loaded by [`refresh`] and updated
+/// by every write made through this registry; call `refresh` periodically to
+/// pick up changes made by other instances.
+///
+/// Secrets that verified once are remembered against the hash they matched,
+/// so repeated requests from a service do not run the slow password hash
+/// on the async runtime each time.
+///
+/// Presented keys for `X-Service-Key` take the form `service_id:secret`.
+///
+/// Responsibilities:
+/// - Register service accounts with a hashed shared secret
+/// - Verify presented secrets against the stored hash
+/// - Rotate secrets and enable or disable accounts
+///
+/// Does NOT:
+/// - Store or return raw secrets
+/// - Issue service tokens
+///
+/// [`refresh`]: ServiceRegistrySql::refresh
+pub struct ServiceRegistrySql {
+    db: Database,
+    password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    accounts: RwLock<HashMap<String, ServiceAccount>>,
+    verified: VerifiedSecrets,
+}
+
+impl ServiceRegistrySql {
+    /// Create a new service registry with the given database pool.
+    ///
+    /// The snapshot starts empty; call [`refresh`](Self::refresh) to load it.
+    pub fn new(db: Database, password_hasher: Arc<dyn PasswordHasher + Send + Sync>) -> Self {
+        Self {
+            db,
+            password_hasher,
+            accounts: RwLock::new(HashMap::new()),
+            verified: VerifiedSecrets::default(),
+        }
+    }
+
+    /// Reload the snapshot from the `service_account` table.
+    ///
+    /// Returns the number of service accounts loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn refresh(&self) -> Result<usize, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT service_id, secret_hash, status
+            FROM service_account
+        "#;
+
+        let rows = sqlx::query(QUERY)
+            .fetch_all(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to load service accounts"))?;
+
+        let accounts: HashMap<String, ServiceAccount> = rows
+            .iter()
+            .map(|row| {
+                let status: String = row.get("status");
+                (
+                    row.get("service_id"),
+                    ServiceAccount {
+                        secret_hash: row.get("secret_hash"),
+                        active: status == STATUS_ACTIVE,
+                    },
+                )
+            })
+            .collect();
+
+        let count = accounts.len();
+        *self.accounts.write().unwrap_or_else(|e| e.into_inner()) = accounts;
+        Ok(count)
+    }
+
+    /// Register a new, active service account.
+    ///
+    /// The secret is hashed with the registry's password hasher; only the
+    /// hash is stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Constraint` if the service id is already
+    /// registered, or `PersistenceError` on query failure.
+    pub async fn register(&self, service_id: &str, secret: &str) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            INSERT INTO service_account (service_id, secret_hash, status)
+            VALUES ($1, $2, $3)
+        "#;
+
+        let secret_hash = self.password_hasher.hash(secret).as_hash_str().to_string();
+
+        sqlx::query(QUERY)
+            .bind(service_id)
+            .bind(&secret_hash)
+            .bind(STATUS_ACTIVE)
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| {
+                if e.to_string().contains("unique constraint") {
+                    PersistenceError::Constraint(ConstraintError::unique_violation(
+                        "service account already registered",
+                    ))
+                } else {
+                    map_query_error(e, "failed to register service account")
+                }
+            })?;
+
+        self.cache(service_id, ServiceAccount { secret_hash, active: true });
+        Ok(())
+    }
+
+    /// Check a presented secret against the stored hash.
+    ///
+    /// Reads the current hash from the table rather than the snapshot, so a
+    /// rotation made by another instance takes effect immediately. Unknown
+    /// and disabled services spend the same hashing work as a wrong secret;
+    /// that work runs on the blocking thread pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn verify(&self, service_id: &str, secret: &str) -> Result<bool, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT secret_hash
+            FROM service_account
+            WHERE service_id = $1 AND status = $2
+        "#;
+
+        let secret_hash: Option<String> = sqlx::query_scalar(QUERY)
+            .bind(service_id)
+            .bind(STATUS_ACTIVE)
+            .fetch_optional(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to query service account"))?;
+
+        if let Some(hash) = secret_hash.as_deref()
+            && self.verified.contains(service_id, hash, secret)
+        {
+            return Ok(true);
+        }
+
+        let password_hasher = self.password_hasher.clone();
+        let stored_hash = secret_hash.clone();
+        let presented = secret.to_string();
+        let verified = tokio::task::spawn_blocking(move || {
+            verify_secret(password_hasher.as_ref(), stored_hash.as_deref(), &presented)
+        })
+        .await
+        .map_err(|e| {
+            PersistenceError::Execution(ExecutionError::query_failed(format!(
+                "service secret verification failed: {}",
+                e
+            )))
+        })?;
+
+        if let Some(hash) = secret_hash.as_deref().filter(|_| verified) {
+            self.verified.remember(service_id, hash, secret);
+        }
+        Ok(verified)
+    }
+
+    /// Replace a service's shared secret.
+    ///
+    /// The previous secret stops verifying as soon as this returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Execution` (not found) if the service id is
+    /// not registered, or `PersistenceError` on query failure.
+    pub async fn rotate(&self, service_id: &str, new_secret: &str) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            UPDATE service_account
+            SET secret_hash = $2, rotated_at = CURRENT_TIMESTAMP
+            WHERE service_id = $1
+            RETURNING status
+        "#;
+
+        let secret_hash = self.password_hasher.hash(new_secret).as_hash_str().to_string();
+
+        let status: Option<String> = sqlx::query_scalar(QUERY)
+            .bind(service_id)
+            .bind(&secret_hash)
+            .fetch_optional(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to rotate service secret"))?;
+
+        let status = status.ok_or_else(|| PersistenceError::not_found("service_account"))?;
+        self.cache(
+            service_id,
+            ServiceAccount { secret_hash, active: status == STATUS_ACTIVE },
+        );
+        Ok(())
+    }
+
+    /// Enable or disable a service account.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError::Execution` (not found) if the service id is
+    /// not registered, or `PersistenceError` on query failure.
+    pub async fn set_active(&self, service_id: &str, active: bool) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            UPDATE service_account
+            SET status = $2
+            WHERE service_id = $1
+            RETURNING secret_hash
+        "#;
+
+        let status = if active { STATUS_ACTIVE } else { STATUS_DISABLED };
+
+        let secret_hash: Option<String> = sqlx::query_scalar(QUERY)
+            .bind(service_id)
+            .bind(status)
+            .fetch_optional(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to update service account status"))?;
+
+        let secret_hash = secret_hash.ok_or_else(|| PersistenceError::not_found("service_account"))?;
+        self.cache(service_id, ServiceAccount { secret_hash, active });
+        Ok(())
+    }
+
+    /// Get the database pool reference.
+    pub fn db(&self) -> &Database {
+        &self.db
+    }
+
+    fn cache(&self, service_id: &str, account: ServiceAccount) {
+        self.accounts
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(service_id.to_string(), account);
+    }
+
+    /// Hash of an active account in the snapshot, if any.
+    fn active_hash(&self, service_id: &str) -> Option<String> {
+        self.accounts
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(service_id)
+            .filter(|account| account.active)
+            .map(|account| account.secret_hash.clone())
+    }
+
+    /// Check `secret` against the snapshot, skipping the password hash for
+    /// a secret that already verified against the current hash.
+    fn verify_cached(
+        &self,
+        password_hasher: &(dyn PasswordHasher + Send + Sync),
+        service_id: &str,
+        secret: &str,
+    ) -> bool {
+        let secret_hash = self.active_hash(service_id);
+        if let Some(hash) = secret_hash.as_deref()
+            && self.verified.contains(service_id, hash, secret)
+        {
+            return true;
+        }
+
+        let verified = verify_secret(password_hasher, secret_hash.as_deref(), secret);
+        if let Some(hash) = secret_hash.as_deref().filter(|_| verified) {
+            self.verified.remember(service_id, hash, secret);
+        }
+        verified
+    }
+}
+
+/// Secrets that already verified, each keyed by service and paired with the
+/// stored hash it matched.
+///
+/// Only a SHA-256 digest of the secret is kept. Rotating a secret changes
+/// the stored hash, so an entry never outlives the hash it was checked
+/// against.
+#[derive(Default)]
+pub(crate) struct VerifiedSecrets {
+    entries: RwLock<HashMap<String, (String, Vec<u8>)>>,
+}
+
+impl VerifiedSecrets {
+    /// Whether `secret` already verified against `secret_hash`.
+    pub(crate) fn contains(&self, service_id: &str, secret_hash: &str, secret: &str) -> bool {
+        let digest = secret_digest(secret);
+        self.entries
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(service_id)
+            .is_some_and(|(hash, known)| hash == secret_hash && constant_time_eq(known, &digest))
+    }
+
+    /// Remember that `secret` verified against `secret_hash`.
+    pub(crate) fn remember(&self, service_id: &str, secret_hash: &str, secret: &str) {
+        self.entries
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(service_id.to_string(), (secret_hash.to_string(), secret_digest(secret)));
+    }
+}
+
+fn secret_digest(secret: &str) -> Vec<u8> {
+    Sha256::digest(secret.as_bytes()).to_vec()
+}
+
+/// Compare two byte strings without an early exit on the first difference.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
+
+/// Verify `secret` against a stored hash, spending one verification's worth
+/// of work even when there is no hash.
+///
+/// The hasher's comparison is constant-time; falling back to
+/// `dummy_verify` keeps unknown service ids from answering faster than a
+/// wrong secret.
+pub(crate) fn verify_secret(
+    password_hasher: &(dyn PasswordHasher + Send + Sync),
+    secret_hash: Option<&str>,
+    secret: &str,
+) -> bool {
+    match secret_hash {
+        Some(hash) => password_hasher.verify(secret, &StoredCredential::from_hash(hash)),
+        None => {
+            password_hasher.dummy_verify(secret);
+            false
+        }
+    }
+}
+
+impl ServiceRegistry for ServiceRegistrySql {
+    fn validate_api_key(&self, api_key: &str) -> Option<String> {
+        let (service_id, secret) = api_key.split_once(':')?;
+
+        self.verify_cached(self.password_hasher.as_ref(), service_id, secret)
+            .then(|| service_id.to_string())
+    }
+
+    fn is_service_active(&self, service_name: &str) -> bool {
+        self.accounts
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(service_name)
+            .is_some_and(|account| account.active)
+    }
+
+    fn validate_credentials(
+        &self,
+        service_id: &str,
+        service_secret: &str,
+        password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+    ) -> Option<String> {
+        self.verify_cached(password_hasher.as_ref(), service_id, service_secret)
+            .then(|| service_id.to_string())
+    }
+}
@@ -4,25 +4,44 @@ use chrono::{DateTime, Utc};
 use futures::future::FutureExt;
 
 use crate::adapters::persistence::{
-    database::Database,
+    database::{map_query_error, Database},
     error::{ConstraintError, ExecutionError, PersistenceError},
     models::SessionRow,
+    retry::{with_retries, RetryPolicy},
 };
-use crate::core::error::CoreError;
+use crate::core::error::{CoreError, InvariantError};
 use crate::core::identity::UserIdentity;
-use crate::core::usecases::ports::SessionRepository;
+use crate::core::usecases::ports::{
+    SessionClientDetails, SessionCursor, SessionPage, SessionRecord, SessionRepository, SessionStatus, SessionSummary,
+};
 use crate::core::usecases::session_repository::Session;
 
+/// Lifetime of a newly persisted session whose metadata carries no expiry.
+pub(crate) const SESSION_TTL_DAYS: i64 = 7;
+
 /// SQL-backed repository for session management.
 ///
-/// Implements operations against the `auth_session` table.
+/// Implements operations against the `auth_session` table, and keeps the
+/// hashes each session rotated out of in `auth_session_rotated_token`:
+///
+/// ```sql
+/// CREATE TABLE auth_session_rotated_token (
+///     refresh_token_hash TEXT PRIMARY KEY,
+///     session_id UUID NOT NULL REFERENCES auth_session(id) ON DELETE CASCADE,
+///     rotated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+/// );
+/// ```
+///
 /// Responsibilities:
 /// - Create new sessions
 /// - Find sessions by refresh_token_hash
 /// - Revoke individual sessions
 /// - Revoke all sessions for a user
+/// - List a user's active sessions
+/// - Page through a user's full session history
 /// - Delete expired sessions
 /// - Map database rows to domain entities
+/// - Retry creates, reads and idempotent updates that hit a transient failure
 ///
 /// Does NOT:
 /// - Generate or hash refresh tokens (that's the crypto/token adapter)
@@ -30,12 +49,26 @@ use crate::core::usecases::session_repository::Session;
 /// - Rotate tokens
 pub struct SessionRepositorySql {
     db: Database,
+    retry_policy: RetryPolicy,
 }
 
 impl SessionRepositorySql {
     /// Create a new session repository with the given database pool.
     pub fn new(db: Database) -> Self {
-        Self { db }
+        Self {
+            db,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Set how queries retry transient failures.
+    ///
+    /// Conditional updates, such as refresh token rotation, are never
+    /// retried: a repeat could not tell its own earlier success apart from
+    /// a lost race.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
     }
 
     /// Create a new session.
@@ -61,36 +94,20 @@ impl SessionRepositorySql {
         ip_address: &str,
         user_agent: &str,
     ) -> Result<(), PersistenceError> {
-        const QUERY: &str = r#"
-            INSERT INTO auth_session
-            (id, user_id, refresh_token_hash, created_at, expires_at, ip_address, user_agent, updated_at)
-            VALUES ($1::uuid, $2::uuid, $3, CURRENT_TIMESTAMP, $4, $5, $6, CURRENT_TIMESTAMP)
-        "#;
-
-        sqlx::query(QUERY)
-            .bind(session_id)
-            .bind(user_id)
-            .bind(refresh_token_hash)
-            .bind(expires_at)
-            .bind(ip_address)
-            .bind(user_agent)
-            .execute(self.db.pool())
-            .await
-            .map_err(|e| {
-                // Check for unique constraint violation
-                if e.to_string().contains("unique constraint") {
-                    PersistenceError::Constraint(ConstraintError::unique_violation(
-                        "session_id already exists",
-                    ))
-                } else {
-                    PersistenceError::Execution(ExecutionError::query_failed(format!(
-                        "failed to create session: {}",
-                        e
-                    )))
-                }
-            })?;
-
-        Ok(())
+        let details = SessionClientDetails::default();
+        with_retries(self.retry_policy, || {
+            insert_session(
+                self.db.pool(),
+                session_id,
+                user_id,
+                refresh_token_hash,
+                expires_at,
+                ip_address,
+                user_agent,
+                &details,
+            )
+        })
+        .await
     }
 
     /// Find an active session by refresh token hash.
@@ -106,24 +123,23 @@ impl SessionRepositorySql {
     ) -> Result<SessionRow, PersistenceError> {
         const QUERY: &str = r#"
             SELECT id, user_id, refresh_token_hash, created_at, expires_at,
-                   revoked_at, ip_address, user_agent, updated_at
+                   revoked_at, ip_address, user_agent, device_type, os, browser,
+                   geo_country, geo_region, geo_city, updated_at
             FROM auth_session
             WHERE refresh_token_hash = $1
               AND revoked_at IS NULL
               AND expires_at > CURRENT_TIMESTAMP
         "#;
 
-        let row = sqlx::query_as::<_, SessionRow>(QUERY)
-            .bind(refresh_token_hash)
-            .fetch_optional(self.db.pool())
-            .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to query session by refresh token: {}",
-                    e
-                )))
-            })?
-            .ok_or_else(|| PersistenceError::Execution(ExecutionError::not_found("Session")))?;
+        let row = with_retries(self.retry_policy, || async move {
+            sqlx::query_as::<_, SessionRow>(QUERY)
+                .bind(refresh_token_hash)
+                .fetch_optional(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query session by refresh token"))
+        })
+        .await?
+        .ok_or_else(|| PersistenceError::Execution(ExecutionError::not_found("Session")))?;
 
         Ok(row)
     }
@@ -145,12 +161,7 @@ impl SessionRepositorySql {
             .bind(session_id)
             .execute(self.db.pool())
             .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to revoke session: {}",
-                    e
-                )))
-            })?;
+            .map_err(|e| map_query_error(e, "failed to revoke session"))?;
 
         if result.rows_affected() == 0 {
             return Err(PersistenceError::Execution(ExecutionError::not_found(
@@ -172,16 +183,42 @@ impl SessionRepositorySql {
             WHERE user_id = $1::uuid AND revoked_at IS NULL
         "#;
 
-        let result = sqlx::query(QUERY)
-            .bind(user_id)
-            .execute(self.db.pool())
-            .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to revoke sessions for user: {}",
-                    e
-                )))
-            })?;
+        let result = with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(user_id)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to revoke sessions for user"))
+        })
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Revoke all sessions for a user except the given one.
+    ///
+    /// Used for "log out other devices". Returns the number of sessions revoked.
+    pub async fn revoke_all_for_user_except(
+        &self,
+        user_id: &str,
+        keep_session_id: &str,
+    ) -> Result<u64, PersistenceError> {
+        const QUERY: &str = r#"
+            UPDATE auth_session
+            SET revoked_at = CURRENT_TIMESTAMP,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE user_id = $1::uuid AND id <> $2::uuid AND revoked_at IS NULL
+        "#;
+
+        let result = with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(user_id)
+                .bind(keep_session_id)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to revoke other sessions for user"))
+        })
+        .await?;
 
         Ok(result.rows_affected())
     }
@@ -195,15 +232,13 @@ impl SessionRepositorySql {
             WHERE expires_at < CURRENT_TIMESTAMP
         "#;
 
-        let result = sqlx::query(QUERY)
-            .execute(self.db.pool())
-            .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to delete expired sessions: {}",
-                    e
-                )))
-            })?;
+        let result = with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to delete expired sessions"))
+        })
+        .await?;
 
         Ok(result.rows_affected())
     }
@@ -221,26 +256,304 @@ impl SessionRepositorySql {
     ) -> Result<SessionRow, PersistenceError> {
         const QUERY: &str = r#"
             SELECT id, user_id, refresh_token_hash, created_at, expires_at,
-                   revoked_at, ip_address, user_agent, updated_at
+                   revoked_at, ip_address, user_agent, device_type, os, browser,
+                   geo_country, geo_region, geo_city, updated_at
             FROM auth_session
             WHERE id = $1::uuid
               AND revoked_at IS NULL
               AND expires_at > CURRENT_TIMESTAMP
         "#;
 
-        let row = sqlx::query_as::<_, SessionRow>(QUERY)
+        let row = with_retries(self.retry_policy, || async move {
+            sqlx::query_as::<_, SessionRow>(QUERY)
+                .bind(session_id)
+                .fetch_optional(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query session by id"))
+        })
+        .await?
+        .ok_or_else(|| PersistenceError::Execution(ExecutionError::not_found("Session")))?;
+
+        Ok(row)
+    }
+
+    /// Rotate a session's refresh token hash.
+    ///
+    /// The old hash moves to `previous_refresh_token_hash`, for the grace
+    /// window, and is added to `auth_session_rotated_token` so a later replay
+    /// of the consumed token can be recognised however many rotations have
+    /// happened since. The update only applies while
+    /// `current_hash` is still live, so concurrent rotations of the same token
+    /// cannot both succeed. Returns whether this call performed the rotation.
+    pub async fn rotate_refresh_token(
+        &self,
+        session_id: &str,
+        current_hash: &str,
+        new_hash: &str,
+    ) -> Result<bool, PersistenceError> {
+        const QUERY: &str = r#"
+            WITH rotated AS (
+                UPDATE auth_session
+                SET previous_refresh_token_hash = refresh_token_hash,
+                    refresh_token_hash = $3,
+                    rotation_successor_hash = NULL,
+                    rotation_grace_until = NULL,
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE id = $1::uuid
+                  AND refresh_token_hash = $2
+                  AND revoked_at IS NULL
+                RETURNING id
+            )
+            INSERT INTO auth_session_rotated_token (refresh_token_hash, session_id)
+            SELECT $2, id FROM rotated
+        "#;
+
+        let result = sqlx::query(QUERY)
             .bind(session_id)
-            .fetch_optional(self.db.pool())
+            .bind(current_hash)
+            .bind(new_hash)
+            .execute(self.db.pool())
             .await
-            .map_err(|e| {
-                PersistenceError::Execution(ExecutionError::query_failed(format!(
-                    "failed to query session by id: {}",
-                    e
-                )))
-            })?
-            .ok_or_else(|| PersistenceError::Execution(ExecutionError::not_found("Session")))?;
+            .map_err(|e| map_query_error(e, "failed to rotate refresh token"))?;
 
-        Ok(row)
+        Ok(result.rows_affected() == 1)
+    }
+
+    /// Check whether a refresh token hash was ever rotated out of a session,
+    /// or displaced by a reissue within the grace window.
+    pub async fn is_rotated_refresh_token(&self, hash: &str) -> Result<bool, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT EXISTS (
+                SELECT 1 FROM auth_session_rotated_token
+                WHERE refresh_token_hash = $1
+            )
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_scalar::<_, bool>(QUERY)
+                .bind(hash)
+                .fetch_one(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query rotated refresh token"))
+        })
+        .await
+    }
+
+    /// Remember the hash of the refresh token `previous_hash` was rotated
+    /// to, until `until`.
+    ///
+    /// The successor is cleared by the next rotation and is never returned
+    /// after `until`.
+    pub async fn record_rotation_successor(
+        &self,
+        previous_hash: &str,
+        successor_hash: &str,
+        until: DateTime<Utc>,
+    ) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            UPDATE auth_session
+            SET rotation_successor_hash = $2,
+                rotation_grace_until = $3
+            WHERE previous_refresh_token_hash = $1
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query(QUERY)
+                .bind(previous_hash)
+                .bind(successor_hash)
+                .bind(until)
+                .execute(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to record rotation successor"))
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Find the hash of the refresh token `previous_hash` was rotated to, if
+    /// its grace window is still open at `now`.
+    pub async fn rotation_successor(
+        &self,
+        previous_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<String>, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT rotation_successor_hash
+            FROM auth_session
+            WHERE previous_refresh_token_hash = $1
+              AND rotation_grace_until > $2
+              AND rotation_successor_hash IS NOT NULL
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_scalar::<_, String>(QUERY)
+                .bind(previous_hash)
+                .bind(now)
+                .fetch_optional(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query rotation successor"))
+        })
+        .await
+    }
+
+    /// Replace the still-live successor of `previous_hash` with `new_hash`
+    /// while its grace window is open at `now`.
+    ///
+    /// The displaced successor is added to `auth_session_rotated_token` so a
+    /// later use of it is recognised as reuse, and the window closes so the
+    /// rotation is reissued at most once. Returns whether this call performed
+    /// the swap.
+    pub async fn reissue_rotation_successor(
+        &self,
+        previous_hash: &str,
+        new_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<bool, PersistenceError> {
+        // The displaced successor stays in `rotation_successor_hash` until
+        // the next rotation, so it can be returned here; clearing the grace
+        // deadline alone closes the window
+        const QUERY: &str = r#"
+            WITH reissued AS (
+                UPDATE auth_session
+                SET refresh_token_hash = $2,
+                    rotation_grace_until = NULL,
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE previous_refresh_token_hash = $1
+                  AND rotation_successor_hash = refresh_token_hash
+                  AND rotation_grace_until > $3
+                  AND revoked_at IS NULL
+                RETURNING id, rotation_successor_hash
+            )
+            INSERT INTO auth_session_rotated_token (refresh_token_hash, session_id)
+            SELECT rotation_successor_hash, id FROM reissued
+        "#;
+
+        let result = sqlx::query(QUERY)
+            .bind(previous_hash)
+            .bind(new_hash)
+            .bind(now)
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to reissue rotation successor"))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    /// Look up when a session was revoked.
+    ///
+    /// Returns `None` for sessions that are still active, expired without
+    /// being revoked, or do not exist.
+    pub async fn revoked_at(&self, session_id: &str) -> Result<Option<DateTime<Utc>>, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT revoked_at
+            FROM auth_session
+            WHERE id = $1::uuid
+              AND revoked_at IS NOT NULL
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_scalar::<_, DateTime<Utc>>(QUERY)
+                .bind(session_id)
+                .fetch_optional(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query session revocation"))
+        })
+        .await
+    }
+
+    /// Look up when a session expires, whatever its state.
+    ///
+    /// Returns `None` if the session does not exist.
+    pub async fn expires_at(&self, session_id: &str) -> Result<Option<DateTime<Utc>>, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT expires_at
+            FROM auth_session
+            WHERE id = $1::uuid
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_scalar::<_, DateTime<Utc>>(QUERY)
+                .bind(session_id)
+                .fetch_optional(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to query session expiry"))
+        })
+        .await
+    }
+
+    /// List a user's active sessions, newest first.
+    ///
+    /// Returns only sessions that are not revoked and not expired.
+    pub async fn list_active_for_user(&self, user_id: &str) -> Result<Vec<SessionRow>, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT id, user_id, refresh_token_hash, created_at, expires_at,
+                   revoked_at, ip_address, user_agent, device_type, os, browser,
+                   geo_country, geo_region, geo_city, updated_at
+            FROM auth_session
+            WHERE user_id = $1::uuid
+              AND revoked_at IS NULL
+              AND expires_at > CURRENT_TIMESTAMP
+            ORDER BY created_at DESC
+        "#;
+
+        with_retries(self.retry_policy, || async move {
+            sqlx::query_as::<_, SessionRow>(QUERY)
+                .bind(user_id)
+                .fetch_all(self.db.pool())
+                .await
+                .map_err(|e| map_query_error(e, "failed to list sessions for user"))
+        })
+        .await
+    }
+
+    /// Page through a user's sessions, newest first, whatever their state.
+    ///
+    /// Keyset pagination on `(created_at, id)`: rows strictly after `cursor`
+    /// in descending order, so inserts between pages neither shift nor
+    /// repeat entries. Fetches up to `limit` rows.
+    pub async fn list_for_user_paginated(
+        &self,
+        user_id: &str,
+        cursor: Option<&SessionCursor>,
+        limit: usize,
+    ) -> Result<Vec<SessionRow>, PersistenceError> {
+        const FIRST_PAGE: &str = r#"
+            SELECT id, user_id, refresh_token_hash, created_at, expires_at,
+                   revoked_at, ip_address, user_agent, device_type, os, browser,
+                   geo_country, geo_region, geo_city, updated_at
+            FROM auth_session
+            WHERE user_id = $1::uuid
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2
+        "#;
+        const NEXT_PAGE: &str = r#"
+            SELECT id, user_id, refresh_token_hash, created_at, expires_at,
+                   revoked_at, ip_address, user_agent, device_type, os, browser,
+                   geo_country, geo_region, geo_city, updated_at
+            FROM auth_session
+            WHERE user_id = $1::uuid
+              AND (created_at, id) < ($3, $4::uuid)
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2
+        "#;
+
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        with_retries(self.retry_policy, || async move {
+            let query = match cursor {
+                None => sqlx::query_as::<_, SessionRow>(FIRST_PAGE)
+                    .bind(user_id)
+                    .bind(limit),
+                Some(cursor) => sqlx::query_as::<_, SessionRow>(NEXT_PAGE)
+                    .bind(user_id)
+                    .bind(limit)
+                    .bind(cursor.created_at)
+                    .bind(&cursor.session_id),
+            };
+
+            query.fetch_all(self.db.pool()).await.map_err(|e| map_query_error(e, "failed to page sessions for user"))
+        })
+        .await
     }
 
     /// Get the database pool reference.
@@ -261,23 +574,13 @@ impl SessionRepository for SessionRepositorySql {
         let user_id = user.id.clone();
         let refresh_token_hash = refresh_token_hash.to_string();
         let metadata = metadata.to_string();
-        let expires_at = Utc::now() + chrono::Duration::days(7);
 
         async move {
-            self.create_session(
-                &session_id,
-                &user_id,
-                &refresh_token_hash,
-                expires_at,
-                "0.0.0.0",
-                &metadata,
-            )
+            with_retries(self.retry_policy, || {
+                insert_session_from_metadata(self.db.pool(), &session_id, &user_id, &refresh_token_hash, &metadata)
+            })
             .await
-            .map_err(|e| CoreError::Authentication(
-                crate::core::error::AuthenticationError::IncompleteFlow {
-                    stage: format!("session persistence failed: {}", e),
-                }
-            ))
+            .map_err(session_persistence_failed)
         }
         .boxed()
     }
@@ -316,6 +619,133 @@ impl SessionRepository for SessionRepositorySql {
         .boxed()
     }
 
+    fn find_summary_by_id(&self, session_id: &str) -> futures::future::BoxFuture<'_, Option<SessionSummary>> {
+        let session_id = session_id.to_string();
+        async move {
+            match self.find_by_id(&session_id).await {
+                Ok(row) => active_session_summaries(std::slice::from_ref(&row), Utc::now()).pop(),
+                Err(e) => {
+                    tracing::error!("[SESSION_REPO] Error finding session summary by id: {:?}", e);
+                    None
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn rotate_refresh_token(
+        &self,
+        session_id: &str,
+        current_hash: &str,
+        new_hash: &str,
+    ) -> futures::future::BoxFuture<'_, bool> {
+        let session_id = session_id.to_string();
+        let current_hash = current_hash.to_string();
+        let new_hash = new_hash.to_string();
+        async move {
+            match self.rotate_refresh_token(&session_id, &current_hash, &new_hash).await {
+                Ok(rotated) => rotated,
+                Err(e) => {
+                    tracing::error!("[SESSION_REPO] Error rotating refresh token: {:?}", e);
+                    false
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn is_rotated_refresh_token(&self, hash: &str) -> futures::future::BoxFuture<'_, bool> {
+        let hash = hash.to_string();
+        async move {
+            match self.is_rotated_refresh_token(&hash).await {
+                Ok(rotated) => rotated,
+                Err(e) => {
+                    tracing::error!("[SESSION_REPO] Error checking rotated refresh token: {:?}", e);
+                    false
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn record_rotation_successor(
+        &self,
+        previous_hash: &str,
+        successor_hash: &str,
+        until: DateTime<Utc>,
+    ) -> futures::future::BoxFuture<'_, ()> {
+        let previous_hash = previous_hash.to_string();
+        let successor_hash = successor_hash.to_string();
+        async move {
+            if let Err(e) = self.record_rotation_successor(&previous_hash, &successor_hash, until).await {
+                tracing::error!("[SESSION_REPO] Error recording rotation successor: {:?}", e);
+            }
+        }
+        .boxed()
+    }
+
+    fn rotation_successor(&self, previous_hash: &str, now: DateTime<Utc>) -> futures::future::BoxFuture<'_, Option<String>> {
+        let previous_hash = previous_hash.to_string();
+        async move {
+            match self.rotation_successor(&previous_hash, now).await {
+                Ok(successor_hash) => successor_hash,
+                Err(e) => {
+                    tracing::error!("[SESSION_REPO] Error finding rotation successor: {:?}", e);
+                    None
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn reissue_rotation_successor(
+        &self,
+        previous_hash: &str,
+        new_hash: &str,
+        now: DateTime<Utc>,
+    ) -> futures::future::BoxFuture<'_, bool> {
+        let previous_hash = previous_hash.to_string();
+        let new_hash = new_hash.to_string();
+        async move {
+            match self.reissue_rotation_successor(&previous_hash, &new_hash, now).await {
+                Ok(reissued) => reissued,
+                Err(e) => {
+                    tracing::error!("[SESSION_REPO] Error reissuing rotation successor: {:?}", e);
+                    false
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn revoked_at(&self, session_id: &str) -> futures::future::BoxFuture<'_, Option<DateTime<Utc>>> {
+        let session_id = session_id.to_string();
+        async move {
+            match self.revoked_at(&session_id).await {
+                Ok(revoked_at) => revoked_at,
+                Err(e) => {
+                    tracing::error!("[SESSION_REPO] Error checking session revocation: {:?}", e);
+                    None
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn expires_at(&self, session_id: &str) -> futures::future::BoxFuture<'_, Option<DateTime<Utc>>> {
+        let session_id = session_id.to_string();
+        async move {
+            match self.expires_at(&session_id).await {
+                Ok(expires_at) => expires_at,
+                Err(e) => {
+                    tracing::error!("[SESSION_REPO] Error checking session expiry: {:?}", e);
+                    None
+                }
+            }
+        }
+        .boxed()
+    }
+
     fn revoke_session(&self, session_id: &str) -> futures::future::BoxFuture<'_, ()> {
         let session_id = session_id.to_string();
         async move {
@@ -324,10 +754,54 @@ impl SessionRepository for SessionRepositorySql {
         .boxed()
     }
 
-    fn revoke_all_for_user(&self, user_id: &str) -> futures::future::BoxFuture<'_, ()> {
+    fn revoke_all_for_user(&self, user_id: &str) -> futures::future::BoxFuture<'_, u64> {
+        let user_id = user_id.to_string();
+        async move {
+            self.revoke_all_for_user(&user_id).await.unwrap_or(0)
+        }
+        .boxed()
+    }
+
+    fn revoke_all_for_user_except(&self, user_id: &str, keep_session_id: &str) -> futures::future::BoxFuture<'_, u64> {
+        let user_id = user_id.to_string();
+        let keep_session_id = keep_session_id.to_string();
+        async move {
+            self.revoke_all_for_user_except(&user_id, &keep_session_id)
+                .await
+                .unwrap_or(0)
+        }
+        .boxed()
+    }
+
+    fn list_active_for_user(&self, user_id: &str) -> futures::future::BoxFuture<'_, Vec<SessionSummary>> {
         let user_id = user_id.to_string();
         async move {
-            let _ = self.revoke_all_for_user(&user_id).await;
+            match self.list_active_for_user(&user_id).await {
+                Ok(rows) => active_session_summaries(&rows, Utc::now()),
+                Err(e) => {
+                    tracing::error!("[SESSION_REPO] Error listing sessions: {:?}", e);
+                    Vec::new()
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn list_for_user_paginated(
+        &self,
+        user_id: &str,
+        cursor: Option<&SessionCursor>,
+        limit: usize,
+    ) -> futures::future::BoxFuture<'_, Result<SessionPage, CoreError>> {
+        let user_id = user_id.to_string();
+        let cursor = cursor.cloned();
+        async move {
+            // One extra row tells whether another page follows
+            let rows = self
+                .list_for_user_paginated(&user_id, cursor.as_ref(), limit.saturating_add(1))
+                .await
+                .map_err(|e| InvariantError::dependency_unavailable("session store", e.to_string()))?;
+            Ok(session_page(&rows, limit, Utc::now()))
         }
         .boxed()
     }
@@ -338,6 +812,225 @@ impl SessionRepository for SessionRepositorySql {
         }
         .boxed()
     }
+
+    fn purge_expired(&self) -> futures::future::BoxFuture<'_, Result<u64, String>> {
+        async move { self.delete_expired().await.map_err(|e| e.to_string()) }.boxed()
+    }
+}
+
+/// Project session rows onto owner-facing summaries.
+///
+/// Rows that are revoked or expired at `now` are dropped even if the query
+/// returned them (e.g. a session expiring between query and response), and
+/// the refresh token hash never leaves this layer. Newest first.
+pub fn active_session_summaries(rows: &[SessionRow], now: DateTime<Utc>) -> Vec<SessionSummary> {
+    let mut summaries: Vec<SessionSummary> = rows
+        .iter()
+        .filter(|row| row.is_active(now))
+        .map(|row| SessionSummary {
+            session_id: row.id.to_string(),
+            created_at: row.created_at,
+            expires_at: row.expires_at,
+            ip_address: row.ip_address.clone(),
+            user_agent: row.user_agent.clone(),
+        })
+        .collect();
+    summaries.sort_by_key(|summary| std::cmp::Reverse(summary.created_at));
+    summaries
+}
+
+/// Build a history page from rows fetched with one row of lookahead.
+///
+/// `rows` must be newest first and may hold up to `limit + 1` entries; the
+/// extra row only signals that a next page exists. Each session's status is
+/// judged at `now`, and the refresh token hash never leaves this layer.
+pub fn session_page(rows: &[SessionRow], limit: usize, now: DateTime<Utc>) -> SessionPage {
+    let sessions: Vec<SessionRecord> = rows
+        .iter()
+        .take(limit)
+        .map(|row| SessionRecord {
+            session_id: row.id.to_string(),
+            created_at: row.created_at,
+            expires_at: row.expires_at,
+            revoked_at: row.revoked_at,
+            status: if row.is_revoked() {
+                SessionStatus::Revoked
+            } else if row.is_expired(now) {
+                SessionStatus::Expired
+            } else {
+                SessionStatus::Active
+            },
+            ip_address: row.ip_address.clone(),
+            user_agent: row.user_agent.clone(),
+        })
+        .collect();
+
+    let next_cursor = if rows.len() > limit {
+        sessions.last().map(SessionCursor::after)
+    } else {
+        None
+    };
+
+    SessionPage { sessions, next_cursor }
+}
+
+/// Insert a session row through any executor (the pool or an open transaction).
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn insert_session<'e, E>(
+    executor: E,
+    session_id: &str,
+    user_id: &str,
+    refresh_token_hash: &str,
+    expires_at: DateTime<Utc>,
+    ip_address: &str,
+    user_agent: &str,
+    details: &SessionClientDetails,
+) -> Result<(), PersistenceError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    const QUERY: &str = r#"
+        INSERT INTO auth_session
+        (id, user_id, refresh_token_hash, created_at, expires_at, ip_address, user_agent,
+         device_type, os, browser, geo_country, geo_region, geo_city, updated_at)
+        VALUES ($1::uuid, $2::uuid, $3, CURRENT_TIMESTAMP, $4, $5, $6,
+                $7, $8, $9, $10, $11, $12, CURRENT_TIMESTAMP)
+    "#;
+
+    sqlx::query(QUERY)
+        .bind(session_id)
+        .bind(user_id)
+        .bind(refresh_token_hash)
+        .bind(expires_at)
+        .bind(ip_address)
+        .bind(user_agent)
+        .bind(&details.device_type)
+        .bind(&details.os)
+        .bind(&details.browser)
+        .bind(&details.country)
+        .bind(&details.region)
+        .bind(&details.city)
+        .execute(executor)
+        .await
+        .map_err(|e| {
+            // Check for unique constraint violation
+            if e.to_string().contains("unique constraint") {
+                PersistenceError::Constraint(ConstraintError::unique_violation(
+                    "session_id already exists",
+                ))
+            } else {
+                map_query_error(e, "failed to create session")
+            }
+        })?;
+
+    Ok(())
+}
+
+/// Persist a session as described by the `SessionRepository` port contract.
+pub(crate) async fn persist_session<'e, E>(
+    executor: E,
+    session_id: &str,
+    user_id: &str,
+    refresh_token_hash: &str,
+    metadata: &str,
+) -> Result<(), CoreError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    insert_session_from_metadata(executor, session_id, user_id, refresh_token_hash, metadata)
+        .await
+        .map_err(session_persistence_failed)
+}
+
+/// Insert a session row with the client details and expiry read from the
+/// session metadata JSON.
+async fn insert_session_from_metadata<'e, E>(
+    executor: E,
+    session_id: &str,
+    user_id: &str,
+    refresh_token_hash: &str,
+    metadata: &str,
+) -> Result<(), PersistenceError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let (ip_address, user_agent) = client_from_metadata(metadata);
+    let details = client_details_from_metadata(metadata);
+    let expires_at = expiry_from_metadata(metadata, Utc::now());
+
+    insert_session(
+        executor,
+        session_id,
+        user_id,
+        refresh_token_hash,
+        expires_at,
+        &ip_address,
+        &user_agent,
+        &details,
+    )
+    .await
+}
+
+fn session_persistence_failed(e: PersistenceError) -> CoreError {
+    InvariantError::dependency_unavailable("session store", e.to_string()).into()
+}
+
+/// Extract client IP and user agent from the session metadata JSON.
+///
+/// Falls back to unknown values when the metadata is not the JSON object
+/// built by `IssueSession`.
+pub(crate) fn client_from_metadata(metadata: &str) -> (String, String) {
+    let parsed: serde_json::Value = serde_json::from_str(metadata).unwrap_or_default();
+    let field = |key: &str, fallback: &str| {
+        parsed
+            .get(key)
+            .and_then(|v| v.as_str())
+            .filter(|v| !v.is_empty())
+            .unwrap_or(fallback)
+            .to_string()
+    };
+    (field("ip", "0.0.0.0"), field("ua", "unknown"))
+}
+
+/// Extract the enriched client details from the session metadata JSON.
+///
+/// `IssueSession` records only the details its enricher could derive, so
+/// every missing or empty key stays unknown.
+pub(crate) fn client_details_from_metadata(metadata: &str) -> SessionClientDetails {
+    let parsed: serde_json::Value = serde_json::from_str(metadata).unwrap_or_default();
+    let field = |key: &str| {
+        parsed
+            .get(key)
+            .and_then(|v| v.as_str())
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+    SessionClientDetails {
+        device_type: field("device_type"),
+        os: field("os"),
+        browser: field("browser"),
+        country: field("geo_country"),
+        region: field("geo_region"),
+        city: field("geo_city"),
+    }
+}
+
+/// Read the session expiry from the session metadata JSON.
+///
+/// `IssueSession` records the refresh token's expiry as "expires", so the
+/// row and the token cannot disagree. Metadata without a valid timestamp
+/// gets the default lifetime from `now`.
+pub(crate) fn expiry_from_metadata(metadata: &str, now: DateTime<Utc>) -> DateTime<Utc> {
+    serde_json::from_str::<serde_json::Value>(metadata)
+        .ok()
+        .and_then(|parsed| {
+            parsed
+                .get("expires")
+                .and_then(|v| v.as_str())
+                .and_then(|v| DateTime::parse_from_rfc3339(v).ok())
+        })
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| now + chrono::Duration::days(SESSION_TTL_DAYS))
 }
 
 #[cfg(test)]
@@ -362,6 +1055,12 @@ mod tests {
             revoked_at: None,
             ip_address: "127.0.0.1".to_string(),
             user_agent: "test".to_string(),
+            device_type: None,
+            os: None,
+            browser: None,
+            geo_country: None,
+            geo_region: None,
+            geo_city: None,
             updated_at: now,
         };
 
@@ -394,6 +1093,12 @@ mod tests {
             revoked_at: None,
             ip_address: "127.0.0.1".to_string(),
             user_agent: "test".to_string(),
+            device_type: None,
+            os: None,
+            browser: None,
+            geo_country: None,
+            geo_region: None,
+            geo_city: None,
             updated_at: now,
         };
 
@@ -419,6 +1124,12 @@ mod tests {
             revoked_at: None,
             ip_address: "127.0.0.1".to_string(),
             user_agent: "test".to_string(),
+            device_type: None,
+            os: None,
+            browser: None,
+            geo_country: None,
+            geo_region: None,
+            geo_city: None,
             updated_at: now,
         };
 
@@ -427,4 +1138,56 @@ mod tests {
         let duration = time_left.unwrap();
         assert!(duration.as_secs() > 3500 && duration.as_secs() < 3610);
     }
+
+    #[test]
+    fn test_client_from_metadata() {
+        let metadata = serde_json::json!({
+            "ip": "203.0.113.7",
+            "ua": "Mozilla/5.0 \"quoted\"",
+            "created": "2024-01-01T00:00:00Z",
+        })
+        .to_string();
+
+        let (ip, ua) = client_from_metadata(&metadata);
+        assert_eq!(ip, "203.0.113.7");
+        assert_eq!(ua, "Mozilla/5.0 \"quoted\"");
+
+        let (ip, ua) = client_from_metadata("not json");
+        assert_eq!(ip, "0.0.0.0");
+        assert_eq!(ua, "unknown");
+    }
+
+    #[test]
+    fn test_client_details_from_metadata() {
+        let metadata = serde_json::json!({
+            "ip": "203.0.113.7",
+            "ua": "Mozilla/5.0",
+            "device_type": "mobile",
+            "os": "iOS",
+            "geo_country": "DE",
+            "geo_city": "",
+        })
+        .to_string();
+
+        let details = client_details_from_metadata(&metadata);
+        assert_eq!(details.device_type.as_deref(), Some("mobile"));
+        assert_eq!(details.os.as_deref(), Some("iOS"));
+        assert_eq!(details.browser, None);
+        assert_eq!(details.country.as_deref(), Some("DE"));
+        assert_eq!(details.city, None);
+
+        assert!(client_details_from_metadata("not json").is_empty());
+    }
+
+    #[test]
+    fn test_expiry_from_metadata() {
+        let now = Utc::now();
+        let metadata = serde_json::json!({ "expires": "2024-01-08T00:00:00Z" }).to_string();
+
+        assert_eq!(expiry_from_metadata(&metadata, now).to_rfc3339(), "2024-01-08T00:00:00+00:00");
+        assert_eq!(
+            expiry_from_metadata("{}", now),
+            now + chrono::Duration::days(SESSION_TTL_DAYS)
+        );
+    }
 }
@@ -0,0 +1,345 @@
+//! Tests for CachedIdentityRepository.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use futures::future::BoxFuture;
+
+use crate::adapters::persistence::repositories::CachedIdentityRepository;
+use crate::core::identity::UserIdentity;
+use crate::core::usecases::ports::{Clock, IdentityRepository};
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+struct ManualClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl ManualClock {
+    fn new() -> Self {
+        Self {
+            now: Mutex::new(Utc.timestamp_opt(1_700_000_000, 0).unwrap()),
+        }
+    }
+
+    fn advance(&self, secs: i64) {
+        *self.now.lock().unwrap() += Duration::seconds(secs);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
+/// Identity store counting lookups; disabled users are not found.
+struct CountingIdentityRepo {
+    users: RwLock<HashMap<String, (String, bool)>>,
+    lookups: AtomicUsize,
+    id_lookups: AtomicUsize,
+}
+
+impl CountingIdentityRepo {
+    fn new() -> Self {
+        let mut users = HashMap::new();
+        users.insert("alice@example.com".to_string(), ("user-1".to_string(), true));
+        users.insert("bob@example.com".to_string(), ("user-2".to_string(), true));
+        Self {
+            users: RwLock::new(users),
+            lookups: AtomicUsize::new(0),
+            id_lookups: AtomicUsize::new(0),
+        }
+    }
+
+    fn lookups(&self) -> usize {
+        self.lookups.load(Ordering::SeqCst)
+    }
+
+    fn id_lookups(&self) -> usize {
+        self.id_lookups.load(Ordering::SeqCst)
+    }
+}
+
+impl IdentityRepository for CountingIdentityRepo {
+    fn find_by_identifier(&self, identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+        let result = self
+            .users
+            .read()
+            .unwrap()
+            .get(identifier)
+            .filter(|(_, enabled)| *enabled)
+            .map(|(id, _)| UserIdentity::new(id.clone()));
+        Box::pin(async move { result })
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        self.id_lookups.fetch_add(1, Ordering::SeqCst);
+        let result = self
+            .users
+            .read()
+            .unwrap()
+            .values()
+            .find(|(user_id, enabled)| user_id == id && *enabled)
+            .map(|(user_id, _)| UserIdentity::new(user_id.clone()));
+        Box::pin(async move { result })
+    }
+
+    fn create(
+        &self,
+        user_id: &uuid::Uuid,
+        identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        self.users
+            .write()
+            .unwrap()
+            .insert(identifier.to_string(), (user_id.to_string(), true));
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn update_identifier(&self, user_id: &str, new_identifier: &str) -> BoxFuture<'_, Result<(), String>> {
+        let mut users = self.users.write().unwrap();
+        let old = users
+            .iter()
+            .find(|(_, (id, _))| id == user_id)
+            .map(|(identifier, _)| identifier.clone());
+        if let Some(old) = old {
+            let entry = users.remove(&old).unwrap();
+            users.insert(new_identifier.to_string(), entry);
+        }
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn soft_delete(&self, user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        self.users.write().unwrap().retain(|_, (id, _)| id != user_id);
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn set_enabled(&self, user_id: &str, enabled: bool) -> BoxFuture<'_, Result<(), String>> {
+        for (id, flag) in self.users.write().unwrap().values_mut() {
+            if id == user_id {
+                *flag = enabled;
+            }
+        }
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+fn setup(ttl_secs: u64, max_entries: usize) -> (Arc<CountingIdentityRepo>, Arc<ManualClock>, CachedIdentityRepository) {
+    let inner = Arc::new(CountingIdentityRepo::new());
+    let clock = Arc::new(ManualClock::new());
+    let cache = CachedIdentityRepository::with_clock(inner.clone(), ttl_secs, max_entries, clock.clone());
+    (inner, clock, cache)
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_repeated_lookup_served_from_cache() {
+    let (inner, _clock, cache) = setup(30, 100);
+
+    let first = cache.find_by_identifier("alice@example.com").await.unwrap();
+    let second = cache.find_by_identifier("alice@example.com").await.unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(inner.lookups(), 1);
+    assert_eq!(cache.len(), 1);
+}
+
+#[tokio::test]
+async fn test_misses_are_not_cached() {
+    let (inner, _clock, cache) = setup(30, 100);
+
+    assert!(cache.find_by_identifier("carol@example.com").await.is_none());
+    assert!(cache.is_empty());
+
+    cache
+        .create(&uuid::Uuid::new_v4(), "carol@example.com", "hash", "", "argon2id", 3)
+        .await
+        .unwrap();
+
+    assert!(cache.find_by_identifier("carol@example.com").await.is_some());
+    assert_eq!(inner.lookups(), 2);
+}
+
+#[tokio::test]
+async fn test_entry_expires_after_ttl() {
+    let (inner, clock, cache) = setup(30, 100);
+
+    cache.find_by_identifier("alice@example.com").await.unwrap();
+    clock.advance(29);
+    cache.find_by_identifier("alice@example.com").await.unwrap();
+    assert_eq!(inner.lookups(), 1);
+
+    clock.advance(1);
+    cache.find_by_identifier("alice@example.com").await.unwrap();
+    assert_eq!(inner.lookups(), 2);
+}
+
+#[tokio::test]
+async fn test_set_enabled_invalidates_cached_identity() {
+    let (inner, _clock, cache) = setup(30, 100);
+
+    assert!(cache.find_by_identifier("alice@example.com").await.is_some());
+
+    cache.set_enabled("user-1", false).await.unwrap();
+
+    assert!(cache.find_by_identifier("alice@example.com").await.is_none());
+    assert_eq!(inner.lookups(), 2);
+}
+
+#[tokio::test]
+async fn test_soft_delete_invalidates_cached_identity() {
+    let (_inner, _clock, cache) = setup(30, 100);
+
+    assert!(cache.find_by_identifier("alice@example.com").await.is_some());
+
+    cache.soft_delete("user-1").await.unwrap();
+
+    assert!(cache.find_by_identifier("alice@example.com").await.is_none());
+}
+
+#[tokio::test]
+async fn test_update_identifier_invalidates_old_identifier() {
+    let (_inner, _clock, cache) = setup(30, 100);
+
+    assert!(cache.find_by_identifier("alice@example.com").await.is_some());
+    assert!(cache.find_by_identifier("bob@example.com").await.is_some());
+
+    cache.update_identifier("user-1", "alice@new.example.com").await.unwrap();
+
+    assert!(cache.find_by_identifier("alice@example.com").await.is_none());
+    assert_eq!(
+        cache.find_by_identifier("alice@new.example.com").await.unwrap().id(),
+        "user-1"
+    );
+    // Unrelated users stay cached
+    assert_eq!(cache.len(), 2);
+}
+
+#[tokio::test]
+async fn test_cache_is_bounded() {
+    let (_inner, clock, cache) = setup(30, 1);
+
+    cache.find_by_identifier("alice@example.com").await.unwrap();
+    clock.advance(1);
+    cache.find_by_identifier("bob@example.com").await.unwrap();
+
+    assert_eq!(cache.len(), 1);
+}
+
+#[tokio::test]
+async fn test_repeated_find_by_id_served_from_cache() {
+    let (inner, _clock, cache) = setup(30, 100);
+
+    assert_eq!(cache.find_by_id("user-2").await.unwrap().id(), "user-2");
+    assert_eq!(cache.find_by_id("user-2").await.unwrap().id(), "user-2");
+
+    assert_eq!(inner.id_lookups(), 1);
+    assert_eq!(cache.id_len(), 1);
+    // Id lookups do not populate the identifier index
+    assert!(cache.is_empty());
+}
+
+#[tokio::test]
+async fn test_find_by_id_misses_are_not_cached() {
+    let (inner, _clock, cache) = setup(30, 100);
+
+    assert!(cache.find_by_id("user-9").await.is_none());
+    assert!(cache.find_by_id("user-9").await.is_none());
+
+    assert_eq!(inner.id_lookups(), 2);
+    assert_eq!(cache.id_len(), 0);
+}
+
+#[tokio::test]
+async fn test_id_entry_expires_after_ttl() {
+    let (inner, clock, cache) = setup(30, 100);
+
+    cache.find_by_id("user-1").await.unwrap();
+    clock.advance(29);
+    cache.find_by_id("user-1").await.unwrap();
+    assert_eq!(inner.id_lookups(), 1);
+
+    clock.advance(1);
+    cache.find_by_id("user-1").await.unwrap();
+    assert_eq!(inner.id_lookups(), 2);
+}
+
+#[tokio::test]
+async fn test_status_change_forces_id_refetch() {
+    let (inner, _clock, cache) = setup(30, 100);
+
+    assert!(cache.find_by_id("user-1").await.is_some());
+
+    cache.set_enabled("user-1", false).await.unwrap();
+
+    assert!(cache.find_by_id("user-1").await.is_none());
+    assert_eq!(inner.id_lookups(), 2);
+
+    cache.set_enabled("user-1", true).await.unwrap();
+
+    assert!(cache.find_by_id("user-1").await.is_some());
+    assert_eq!(inner.id_lookups(), 3);
+}
+
+#[tokio::test]
+async fn test_soft_delete_invalidates_id_entry() {
+    let (_inner, _clock, cache) = setup(30, 100);
+
+    assert!(cache.find_by_id("user-1").await.is_some());
+
+    cache.soft_delete("user-1").await.unwrap();
+
+    assert!(cache.find_by_id("user-1").await.is_none());
+    assert_eq!(cache.id_len(), 0);
+}
+
+#[tokio::test]
+async fn test_id_index_is_bounded() {
+    let (_inner, clock, cache) = setup(30, 1);
+
+    cache.find_by_id("user-1").await.unwrap();
+    clock.advance(1);
+    cache.find_by_id("user-2").await.unwrap();
+
+    assert_eq!(cache.id_len(), 1);
+}
+
+#[tokio::test]
+async fn test_clear_drops_both_indexes() {
+    let (_inner, _clock, cache) = setup(30, 100);
+
+    cache.find_by_identifier("alice@example.com").await.unwrap();
+    cache.find_by_id("user-1").await.unwrap();
+
+    cache.clear();
+
+    assert!(cache.is_empty());
+    assert_eq!(cache.id_len(), 0);
+}
+
+#[tokio::test]
+async fn test_exists_answered_from_cache() {
+    let (inner, _clock, cache) = setup(30, 100);
+
+    cache.find_by_identifier("alice@example.com").await.unwrap();
+
+    assert!(cache.exists("alice@example.com").await);
+    assert_eq!(inner.lookups(), 1);
+
+    assert!(!cache.exists("carol@example.com").await);
+    assert!(cache.exists("bob@example.com").await);
+    assert_eq!(cache.len(), 1, "existence checks never populate the cache");
+}
@@ -3,4 +3,10 @@
 mod identity_repository_tests;
 mod credential_repository_tests;
 mod session_repository_tests;
-mod external_identity_repository_tests;
\ No newline at end of file
+mod external_identity_repository_tests;
+mod reset_token_store_tests;
+mod passkey_repository_tests;
+mod cached_identity_repository_tests;
+mod audit_sink_tests;
+mod service_registry_tests;
+mod totp_repository_tests;
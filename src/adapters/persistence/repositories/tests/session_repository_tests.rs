@@ -4,7 +4,33 @@
 /// Integration tests requiring database connectivity should be marked with #[ignore]
 /// and run with `cargo test -- --ignored` when a test database is available.
 
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use uuid::Uuid;
+
+use crate::adapters::persistence::models::SessionRow;
+use crate::adapters::persistence::repositories::session_repository_sql::{active_session_summaries, session_page};
 use crate::adapters::persistence::repositories::SessionRepositorySql;
+use crate::core::usecases::ports::{SessionCursor, SessionStatus};
+
+fn row(created_at: DateTime<Utc>, expires_at: DateTime<Utc>, revoked_at: Option<DateTime<Utc>>) -> SessionRow {
+    SessionRow {
+        id: Uuid::new_v4(),
+        user_id: Uuid::nil(),
+        refresh_token_hash: "secret-refresh-hash".to_string(),
+        created_at,
+        expires_at,
+        revoked_at,
+        ip_address: "203.0.113.7".to_string(),
+        user_agent: "Mozilla/5.0".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
+        updated_at: created_at,
+    }
+}
 
 #[test]
 fn session_repository_sql_can_be_constructed() {
@@ -14,3 +40,93 @@ fn session_repository_sql_can_be_constructed() {
     assert!(_repo_type.contains("SessionRepositorySql"));
 }
 
+#[test]
+fn active_session_summaries_lists_multiple_sessions_newest_first() {
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let expires = now + Duration::days(7);
+    let oldest = row(now - Duration::days(3), expires, None);
+    let newest = row(now - Duration::hours(1), expires, None);
+    let middle = row(now - Duration::days(1), expires, None);
+
+    let summaries = active_session_summaries(&[oldest.clone(), newest.clone(), middle.clone()], now);
+
+    let ids: Vec<String> = summaries.iter().map(|s| s.session_id.clone()).collect();
+    assert_eq!(ids, vec![newest.id.to_string(), middle.id.to_string(), oldest.id.to_string()]);
+    assert_eq!(summaries[0].ip_address, "203.0.113.7");
+    assert_eq!(summaries[0].user_agent, "Mozilla/5.0");
+    assert_eq!(summaries[0].expires_at, expires);
+}
+
+#[test]
+fn active_session_summaries_drops_revoked_and_expired_rows() {
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let active = row(now - Duration::hours(2), now + Duration::days(1), None);
+    let revoked = row(now - Duration::hours(3), now + Duration::days(1), Some(now - Duration::hours(1)));
+    let expired = row(now - Duration::days(8), now - Duration::seconds(1), None);
+    let expiring_now = row(now - Duration::days(7), now, None);
+
+    let summaries = active_session_summaries(&[active.clone(), revoked, expired, expiring_now], now);
+
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0].session_id, active.id.to_string());
+}
+
+#[test]
+fn active_session_summaries_never_expose_refresh_token_hash() {
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let summaries = active_session_summaries(&[row(now, now + Duration::days(1), None)], now);
+
+    let rendered = format!("{:?}", summaries);
+    assert!(!rendered.contains("secret-refresh-hash"));
+}
+
+#[test]
+fn session_page_with_lookahead_row_points_cursor_at_last_kept_session() {
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let rows: Vec<SessionRow> = (0..3)
+        .map(|i| row(now - Duration::hours(i), now + Duration::days(1), None))
+        .collect();
+
+    let page = session_page(&rows, 2, now);
+
+    assert_eq!(page.sessions.len(), 2);
+    assert_eq!(
+        page.next_cursor,
+        Some(SessionCursor {
+            created_at: rows[1].created_at,
+            session_id: rows[1].id.to_string(),
+        })
+    );
+}
+
+#[test]
+fn session_page_without_lookahead_row_is_last_page() {
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let rows: Vec<SessionRow> = (0..2)
+        .map(|i| row(now - Duration::hours(i), now + Duration::days(1), None))
+        .collect();
+
+    let page = session_page(&rows, 2, now);
+
+    assert_eq!(page.sessions.len(), 2);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn session_page_reports_status_of_each_session() {
+    let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let active = row(now - Duration::hours(1), now + Duration::days(1), None);
+    let revoked = row(now - Duration::hours(2), now + Duration::days(1), Some(now - Duration::minutes(5)));
+    let expired = row(now - Duration::days(8), now - Duration::seconds(1), None);
+    let revoked_then_expired = row(now - Duration::days(9), now - Duration::days(2), Some(now - Duration::days(3)));
+
+    let page = session_page(&[active, revoked.clone(), expired, revoked_then_expired], 10, now);
+
+    let statuses: Vec<SessionStatus> = page.sessions.iter().map(|s| s.status).collect();
+    assert_eq!(
+        statuses,
+        vec![SessionStatus::Active, SessionStatus::Revoked, SessionStatus::Expired, SessionStatus::Revoked]
+    );
+    assert_eq!(page.sessions[1].revoked_at, revoked.revoked_at);
+    assert!(!format!("{:?}", page).contains("secret-refresh-hash"));
+}
@@ -0,0 +1,147 @@
+//! SQL-backed implementation of the access token deny-list.
+
+use chrono::{DateTime, TimeZone, Utc};
+use futures::future::FutureExt;
+
+use crate::adapters::persistence::{
+    database::{map_query_error, Database},
+    error::PersistenceError,
+};
+use crate::core::error::{AuthenticationError, CoreError};
+use crate::core::usecases::ports::TokenDenyList;
+
+/// SQL-backed deny-list of revoked access tokens.
+///
+/// Implements operations against the `denied_access_token` table:
+///
+/// ```sql
+/// CREATE TABLE denied_access_token (
+///     jti         TEXT PRIMARY KEY,
+///     expires_at  TIMESTAMPTZ NOT NULL,
+///     denied_at   TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+/// );
+/// ```
+///
+/// Responsibilities:
+/// - Record a token's jti as denied until the token's expiry
+/// - Answer whether a jti is denied, ignoring lapsed entries
+/// - Delete entries whose token has expired, each time a token is denied,
+///   so the table stays bounded by the number of live denied tokens
+///
+/// Does NOT:
+/// - Validate or decode access tokens
+/// - Revoke sessions
+pub struct TokenDenyListSql {
+    db: Database,
+}
+
+impl TokenDenyListSql {
+    /// Create a new deny-list with the given database pool.
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    /// Deny a token until `expires_at`.
+    ///
+    /// Denying a jti twice keeps the first entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn insert(&self, jti: &str, expires_at: DateTime<Utc>) -> Result<(), PersistenceError> {
+        const QUERY: &str = r#"
+            INSERT INTO denied_access_token (jti, expires_at)
+            VALUES ($1, $2)
+            ON CONFLICT (jti) DO NOTHING
+        "#;
+
+        sqlx::query(QUERY)
+            .bind(jti)
+            .bind(expires_at)
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to deny access token"))?;
+
+        Ok(())
+    }
+
+    /// Whether a jti has an entry that has not lapsed yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn contains(&self, jti: &str) -> Result<bool, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM denied_access_token
+                WHERE jti = $1
+                AND expires_at > CURRENT_TIMESTAMP
+            )
+        "#;
+
+        sqlx::query_scalar(QUERY)
+            .bind(jti)
+            .fetch_one(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to query denied access token"))
+    }
+
+    /// Delete entries for tokens that have expired.
+    ///
+    /// Returns the number of entries deleted.
+    pub async fn delete_expired(&self) -> Result<u64, PersistenceError> {
+        const QUERY: &str = r#"
+            DELETE FROM denied_access_token
+            WHERE expires_at <= CURRENT_TIMESTAMP
+        "#;
+
+        let result = sqlx::query(QUERY)
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to delete expired denied tokens"))?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Get the database pool reference.
+    pub fn db(&self) -> &Database {
+        &self.db
+    }
+}
+
+impl TokenDenyList for TokenDenyListSql {
+    fn deny(&self, jti: &str, expires_at: i64) -> futures::future::BoxFuture<'_, Result<(), CoreError>> {
+        let jti = jti.to_string();
+        let expires_at = Utc.timestamp_opt(expires_at, 0).single().unwrap_or_else(Utc::now);
+
+        async move {
+            self.insert(&jti, expires_at).await.map_err(|e| {
+                CoreError::from(AuthenticationError::incomplete_flow(format!("access token denial failed: {}", e)))
+            })?;
+
+            // Lapsed entries are already ignored by lookups; dropping them is housekeeping
+            if let Err(e) = self.delete_expired().await {
+                tracing::warn!("[TOKEN_DENY_LIST] Error deleting expired denied tokens: {:?}", e);
+            }
+            Ok(())
+        }
+        .boxed()
+    }
+
+    fn is_denied(&self, jti: &str) -> futures::future::BoxFuture<'_, bool> {
+        let jti = jti.to_string();
+
+        async move {
+            match self.contains(&jti).await {
+                Ok(denied) => denied,
+                Err(e) => {
+                    // Fail closed: a token we cannot check is not trusted
+                    tracing::error!("[TOKEN_DENY_LIST] Error checking denied token: {:?}", e);
+                    true
+                }
+            }
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,148 @@
+//! SQL-backed implementation of the access token issued-at watermark.
+
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use chrono::{DateTime, TimeZone, Utc};
+use futures::future::FutureExt;
+
+use crate::adapters::persistence::{
+    database::{map_query_error, Database},
+    error::PersistenceError,
+};
+use crate::core::error::{CoreError, InvariantError};
+use crate::core::usecases::ports::TokenWatermarkStore;
+
+/// Marks "no watermark loaded" in the cached value.
+const NO_WATERMARK: i64 = i64::MIN;
+
+/// SQL-backed issued-at watermark for access tokens.
+///
+/// Implements operations against the single-row `token_watermark` table:
+///
+/// ```sql
+/// CREATE TABLE token_watermark (
+///     id                 BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+///     minimum_issued_at  TIMESTAMPTZ NOT NULL,
+///     updated_at         TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+/// );
+/// ```
+///
+/// Responsibilities:
+/// - Raise the watermark, never lowering it, even under concurrent writers
+/// - Answer lookups from the watermark loaded at startup and kept current
+///   on every write, so validating a token costs no query
+/// - Pick up an advance made through another instance on
+///   [`fetch`](Self::fetch), which the caller schedules periodically
+///
+/// Does NOT:
+/// - Validate or decode access tokens
+/// - Revoke sessions
+pub struct TokenWatermarkStoreSql {
+    db: Database,
+    /// Last watermark read or written, Unix epoch seconds
+    last_known: AtomicI64,
+}
+
+impl TokenWatermarkStoreSql {
+    /// Create a watermark store with the given database pool.
+    ///
+    /// Nothing is known until [`load`](Self::load) or [`fetch`](Self::fetch).
+    pub fn new(db: Database) -> Self {
+        Self {
+            db,
+            last_known: AtomicI64::new(NO_WATERMARK),
+        }
+    }
+
+    /// Create a watermark store and load the persisted watermark.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn load(db: Database) -> Result<Self, PersistenceError> {
+        let store = Self::new(db);
+        store.fetch().await?;
+        Ok(store)
+    }
+
+    /// Read the persisted watermark, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn fetch(&self) -> Result<Option<DateTime<Utc>>, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT minimum_issued_at
+            FROM token_watermark
+            WHERE id
+        "#;
+
+        let watermark: Option<DateTime<Utc>> = sqlx::query_scalar(QUERY)
+            .fetch_optional(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to query token watermark"))?;
+
+        if let Some(watermark) = watermark {
+            self.remember(watermark);
+        }
+        Ok(watermark)
+    }
+
+    /// Raise the watermark to `revoked_through` and return the one in effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn raise(&self, revoked_through: DateTime<Utc>) -> Result<DateTime<Utc>, PersistenceError> {
+        const QUERY: &str = r#"
+            INSERT INTO token_watermark (id, minimum_issued_at)
+            VALUES (TRUE, $1)
+            ON CONFLICT (id) DO UPDATE
+            SET minimum_issued_at = GREATEST(token_watermark.minimum_issued_at, EXCLUDED.minimum_issued_at),
+                updated_at = CURRENT_TIMESTAMP
+            RETURNING minimum_issued_at
+        "#;
+
+        let watermark: DateTime<Utc> = sqlx::query_scalar(QUERY)
+            .bind(revoked_through)
+            .fetch_one(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to advance token watermark"))?;
+
+        self.remember(watermark);
+        Ok(watermark)
+    }
+
+    /// The last watermark read or written, if any.
+    pub fn last_known(&self) -> Option<i64> {
+        Some(self.last_known.load(Ordering::Relaxed)).filter(|watermark| *watermark != NO_WATERMARK)
+    }
+
+    fn remember(&self, watermark: DateTime<Utc>) {
+        self.last_known.fetch_max(watermark.timestamp(), Ordering::Relaxed);
+    }
+
+    /// Get the database pool reference.
+    pub fn db(&self) -> &Database {
+        &self.db
+    }
+}
+
+impl TokenWatermarkStore for TokenWatermarkStoreSql {
+    fn revoked_through(&self) -> futures::future::BoxFuture<'_, Option<i64>> {
+        let watermark = self.last_known();
+        async move { watermark }.boxed()
+    }
+
+    fn advance(&self, revoked_through: i64) -> futures::future::BoxFuture<'_, Result<i64, CoreError>> {
+        let revoked_through = Utc.timestamp_opt(revoked_through, 0).single().unwrap_or_else(Utc::now);
+
+        async move {
+            self.raise(revoked_through)
+                .await
+                .map(|watermark| watermark.timestamp())
+                .map_err(|e| InvariantError::dependency_unavailable("token watermark store", e.to_string()).into())
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,120 @@
+//! SQL-backed implementation of the TOTP enrollment repository.
+
+use futures::future::{BoxFuture, FutureExt};
+
+use crate::adapters::persistence::{
+    database::{map_query_error, Database},
+    error::PersistenceError,
+};
+use crate::core::usecases::ports::{TotpEnrollment, TotpRepository};
+
+/// SQL-backed repository of TOTP enrollments.
+///
+/// Implements operations against the `totp_enrollment` table:
+///
+/// ```sql
+/// CREATE TABLE totp_enrollment (
+///     user_id         UUID PRIMARY KEY,
+///     secret          BYTEA NOT NULL,
+///     last_used_step  BIGINT,
+///     created_at      TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+///     updated_at      TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+/// );
+/// ```
+///
+/// Responsibilities:
+/// - Look up a user's shared secret and last accepted time step
+/// - Advance the last accepted step, only forwards, in a single statement
+///
+/// Does NOT:
+/// - Compute or check TOTP codes
+/// - Enroll users
+pub struct TotpRepositorySql {
+    db: Database,
+}
+
+impl TotpRepositorySql {
+    /// Create a new TOTP repository with the given database pool.
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+
+    /// Find the TOTP enrollment of `user_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn find(&self, user_id: &str) -> Result<Option<TotpEnrollment>, PersistenceError> {
+        const QUERY: &str = r#"
+            SELECT secret, last_used_step
+            FROM totp_enrollment
+            WHERE user_id = $1::uuid
+        "#;
+
+        let row: Option<(Vec<u8>, Option<i64>)> = sqlx::query_as(QUERY)
+            .bind(user_id)
+            .fetch_optional(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to query totp enrollment"))?;
+
+        Ok(row.map(|(secret, last_used_step)| TotpEnrollment {
+            secret,
+            last_used_step: last_used_step.and_then(|step| u64::try_from(step).ok()),
+        }))
+    }
+
+    /// Set the last accepted step to `step` if it is later than the one
+    /// recorded.
+    ///
+    /// Returns whether the step was recorded; `false` means an equal or
+    /// later step already was.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PersistenceError` on query failure.
+    pub async fn advance_used_step(&self, user_id: &str, step: u64) -> Result<bool, PersistenceError> {
+        const QUERY: &str = r#"
+            UPDATE totp_enrollment
+            SET last_used_step = $2, updated_at = CURRENT_TIMESTAMP
+            WHERE user_id = $1::uuid
+              AND (last_used_step IS NULL OR last_used_step < $2)
+        "#;
+
+        let result = sqlx::query(QUERY)
+            .bind(user_id)
+            .bind(i64::try_from(step).unwrap_or(i64::MAX))
+            .execute(self.db.pool())
+            .await
+            .map_err(|e| map_query_error(e, "failed to record totp step"))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Get the database pool reference.
+    pub fn db(&self) -> &Database {
+        &self.db
+    }
+}
+
+impl TotpRepository for TotpRepositorySql {
+    fn find_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<TotpEnrollment>> {
+        let user_id = user_id.to_string();
+
+        async move {
+            match self.find(&user_id).await {
+                Ok(enrollment) => enrollment,
+                Err(e) => {
+                    tracing::error!("[TOTP_REPO] Error finding totp enrollment: {:?}", e);
+                    None
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn record_used_step(&self, user_id: &str, step: u64) -> BoxFuture<'_, Result<bool, String>> {
+        let user_id = user_id.to_string();
+
+        async move { self.advance_used_step(&user_id, step).await.map_err(|e| e.to_string()) }.boxed()
+    }
+}
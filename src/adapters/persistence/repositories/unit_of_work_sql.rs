@@ -0,0 +1,98 @@
+//! SQL-backed implementation of the unit of work port.
+
+use chrono::Utc;
+use futures::future::{BoxFuture, FutureExt};
+use sqlx::{Postgres, Transaction};
+
+use crate::adapters::persistence::database::{map_transaction_error, Database};
+use crate::adapters::persistence::repositories::credential_repository_sql::record_successful_login;
+use crate::adapters::persistence::repositories::session_repository_sql::persist_session;
+use crate::core::error::{CoreError, InvariantError};
+use crate::core::identity::UserIdentity;
+use crate::core::usecases::ports::{UnitOfWork, UnitOfWorkScope};
+
+/// Starts database transactions for writes that must land together.
+///
+/// Each scope owns one Postgres transaction; its writes use the same SQL as
+/// the matching repositories, so rows look identical either way.
+pub struct UnitOfWorkSql {
+    db: Database,
+}
+
+impl UnitOfWorkSql {
+    /// Create a new unit of work factory over the given database pool.
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+impl UnitOfWork for UnitOfWorkSql {
+    fn begin(&self) -> BoxFuture<'_, Result<Box<dyn UnitOfWorkScope>, CoreError>> {
+        async move {
+            let tx = self
+                .db
+                .begin()
+                .await
+                .map_err(|e| CoreError::from(InvariantError::dependency_unavailable("unit of work", e.to_string())))?;
+            Ok(Box::new(UnitOfWorkScopeSql { tx }) as Box<dyn UnitOfWorkScope>)
+        }
+        .boxed()
+    }
+}
+
+/// One open transaction; dropping it without committing rolls it back.
+pub struct UnitOfWorkScopeSql {
+    tx: Transaction<'static, Postgres>,
+}
+
+impl UnitOfWorkScope for UnitOfWorkScopeSql {
+    fn create_session(
+        &mut self,
+        session_id: &str,
+        user: &UserIdentity,
+        refresh_token_hash: &str,
+        metadata: &str,
+    ) -> BoxFuture<'_, Result<(), CoreError>> {
+        let session_id = session_id.to_string();
+        let user_id = user.id.clone();
+        let refresh_token_hash = refresh_token_hash.to_string();
+        let metadata = metadata.to_string();
+
+        async move {
+            persist_session(&mut *self.tx, &session_id, &user_id, &refresh_token_hash, &metadata).await
+        }
+        .boxed()
+    }
+
+    fn record_successful_login(&mut self, user_id: &str, at: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        let user_id = user_id.to_string();
+        let at = chrono::DateTime::parse_from_rfc3339(at).map(|at| at.with_timezone(&Utc));
+
+        async move {
+            let at = at.map_err(|e| InvariantError::violated(format!("login time is not RFC3339: {}", e)))?;
+            record_successful_login(&mut *self.tx, &user_id, at)
+                .await
+                .map_err(|e| InvariantError::dependency_unavailable("credential store", e.to_string()).into())
+        }
+        .boxed()
+    }
+
+    fn commit(self: Box<Self>) -> BoxFuture<'static, Result<(), CoreError>> {
+        async move {
+            self.tx.commit().await.map_err(|e| {
+                let error = map_transaction_error(e, "failed to commit unit of work");
+                InvariantError::dependency_unavailable("unit of work", error.to_string()).into()
+            })
+        }
+        .boxed()
+    }
+
+    fn rollback(self: Box<Self>) -> BoxFuture<'static, ()> {
+        async move {
+            if let Err(e) = self.tx.rollback().await {
+                tracing::warn!("[UNIT_OF_WORK] Rollback failed: {}", e);
+            }
+        }
+        .boxed()
+    }
+}
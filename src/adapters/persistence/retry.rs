@@ -0,0 +1,107 @@
+// Retry with backoff for transient persistence failures.
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::adapters::persistence::error::{ExecutionError, PersistenceError};
+
+/// How often, and how patiently, to retry a read that hit a transient
+/// connection failure.
+///
+/// Both knobs are bounded: at most [`RetryPolicy::MAX_ATTEMPTS`] attempts,
+/// and no single wait longer than [`RetryPolicy::MAX_BACKOFF`], so a
+/// database outage cannot hold an auth request open indefinitely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    attempts: u32,
+    backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Upper bound on attempts, the first one included
+    pub const MAX_ATTEMPTS: u32 = 5;
+    /// Upper bound on the wait before any one retry
+    pub const MAX_BACKOFF: Duration = Duration::from_secs(2);
+
+    /// Make up to `attempts` attempts, waiting `backoff` before the first
+    /// retry and doubling the wait before each further one.
+    ///
+    /// Values are clamped to `1..=MAX_ATTEMPTS` and `..=MAX_BACKOFF`.
+    pub fn new(attempts: u32, backoff: Duration) -> Self {
+        Self {
+            attempts: attempts.clamp(1, Self::MAX_ATTEMPTS),
+            backoff: backoff.min(Self::MAX_BACKOFF),
+        }
+    }
+
+    /// A single attempt, never retried.
+    pub fn none() -> Self {
+        Self::new(1, Duration::ZERO)
+    }
+
+    /// Maximum number of attempts, the first one included.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Wait before the first retry.
+    pub fn backoff(&self) -> Duration {
+        self.backoff
+    }
+
+    /// Wait before retry number `retry` (1-based).
+    fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.backoff.saturating_mul(factor).min(Self::MAX_BACKOFF)
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts, 50ms then 100ms apart.
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(50))
+    }
+}
+
+/// Whether `error` is worth another attempt.
+///
+/// Connection failures and timeouts qualify, as do serialization failures
+/// and deadlocks, where the database aborted the work only because of a
+/// concurrent transaction. Anything else the database answered, a
+/// constraint violation above all, would fail the same way again.
+pub(crate) fn is_transient(error: &PersistenceError) -> bool {
+    match error {
+        PersistenceError::Connection(e) => e.is_retryable(),
+        PersistenceError::Execution(ExecutionError::SerializationFailure { .. }) => true,
+        _ => false,
+    }
+}
+
+/// Run `op`, retrying it on transient failures per `policy`.
+///
+/// Only for operations that are safe to repeat: reads, or a whole
+/// transaction that `op` begins and commits itself. Returns the
+/// first success, the first non-transient error, or the last error once
+/// the attempts are used up.
+pub async fn with_retries<T, F, Fut>(policy: RetryPolicy, mut op: F) -> Result<T, PersistenceError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, PersistenceError>>,
+{
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Err(e) if is_transient(&e) && attempt < policy.attempts() => {
+                tracing::warn!(
+                    "[PERSISTENCE] Transient error on attempt {}/{}, retrying: {}",
+                    attempt,
+                    policy.attempts(),
+                    e
+                );
+                tokio::time::sleep(policy.delay(attempt)).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
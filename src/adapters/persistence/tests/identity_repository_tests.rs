@@ -303,3 +303,222 @@ async fn test_identity_duplicate_identifier_constraint() {
     let _ = cleanup_identity(&db, identifier).await;
     db.shutdown().await;
 }
+
+#[tokio::test]
+#[ignore] // Requires running PostgreSQL instance
+async fn test_delete_identity_revokes_sessions_and_tombstones_identity() {
+    let (db, repo) = setup_test_db()
+        .await
+        .expect("Failed to setup test database");
+
+    let identifier = "deletion.test@example.com";
+    let user_id_str = "550e8400-e29b-41d4-a716-446655440110";
+    let session_ids = [
+        "550e8400-e29b-41d4-a716-446655440111",
+        "550e8400-e29b-41d4-a716-446655440112",
+    ];
+    let user_id_uuid = to_uuid(user_id_str);
+
+    // Cleanup first (sessions before the identity they reference)
+    let _ = sqlx::query("DELETE FROM auth_session WHERE user_id = $1::uuid")
+        .bind(&user_id_uuid)
+        .execute(db.pool())
+        .await;
+    let _ = cleanup_identity_by_user_id(&db, user_id_str).await;
+
+    // Insert identity with two active sessions
+    let now = Utc::now();
+    repo.create_identity(user_id_str, identifier, "$2b$12$hash")
+        .await
+        .expect("Failed to create identity");
+
+    for session_id in session_ids {
+        sqlx::query(
+            r#"
+            INSERT INTO auth_session
+            (id, user_id, refresh_token_hash, created_at, expires_at, ip_address, user_agent, updated_at)
+            VALUES ($1::uuid, $2::uuid, $3, $4, $5, $6, $7, $8)
+            "#
+        )
+        .bind(to_uuid(session_id))
+        .bind(&user_id_uuid)
+        .bind(format!("hash_{}", session_id))
+        .bind(now)
+        .bind(now + chrono::Duration::days(7))
+        .bind("192.168.1.1")
+        .bind("Mozilla/5.0")
+        .bind(now)
+        .execute(db.pool())
+        .await
+        .expect("Failed to create session");
+    }
+
+    // Delete the identity
+    let revoked = repo
+        .delete_identity_and_revoke_sessions(user_id_str)
+        .await
+        .expect("Deletion should succeed");
+    assert_eq!(revoked, 2);
+
+    // Sessions are revoked, not deleted
+    let (total, still_active) = sqlx::query_as::<_, (i64, i64)>(
+        "SELECT COUNT(*), COUNT(*) FILTER (WHERE revoked_at IS NULL) FROM auth_session WHERE user_id = $1::uuid"
+    )
+    .bind(&user_id_uuid)
+    .fetch_one(db.pool())
+    .await
+    .expect("Failed to count sessions");
+    assert_eq!(total, 2, "Sessions must be kept for audit");
+    assert_eq!(still_active, 0, "Every session must be revoked");
+
+    // Identity is tombstoned and no longer found
+    assert!(repo.find_by_id(user_id_str).await.is_err());
+    assert!(repo.find_by_identifier(identifier).await.is_err());
+    let deleted_at = sqlx::query_scalar::<_, Option<chrono::DateTime<Utc>>>(
+        "SELECT deleted_at FROM identity_credential WHERE user_id = $1::uuid"
+    )
+    .bind(&user_id_uuid)
+    .fetch_one(db.pool())
+    .await
+    .expect("Tombstone row should remain");
+    assert!(deleted_at.is_some());
+
+    // Deleting again reports the identity as gone
+    assert!(repo.delete_identity_and_revoke_sessions(user_id_str).await.is_err());
+
+    // Cleanup
+    let _ = sqlx::query("DELETE FROM auth_session WHERE user_id = $1::uuid")
+        .bind(&user_id_uuid)
+        .execute(db.pool())
+        .await;
+    let _ = cleanup_identity_by_user_id(&db, user_id_str).await;
+    db.shutdown().await;
+}
+
+#[tokio::test]
+#[ignore] // Requires running PostgreSQL instance
+async fn test_identifier_exists() {
+    let (db, repo) = setup_test_db()
+        .await
+        .expect("Failed to setup test database");
+
+    let identifier = "exists.test@example.com";
+    let missing_identifier = "missing.test@example.com";
+    let user_id_str = "550e8400-e29b-41d4-a716-446655440140";
+
+    let _ = cleanup_identity(&db, identifier).await;
+    let _ = cleanup_identity(&db, missing_identifier).await;
+    let _ = cleanup_identity_by_user_id(&db, user_id_str).await;
+
+    repo.create_identity(user_id_str, identifier, "$argon2id$v=19$m=19456,t=2,p=1$test$hash")
+        .await
+        .expect("Failed to create identity");
+
+    // Existing identifier
+    assert!(repo.identifier_exists(identifier).await.expect("Query should succeed"));
+
+    // Non-existing identifier
+    assert!(!repo.identifier_exists(missing_identifier).await.expect("Query should succeed"));
+
+    // Tombstoned identities no longer count
+    repo.delete_identity_and_revoke_sessions(user_id_str)
+        .await
+        .expect("Deletion should succeed");
+    assert!(!repo.identifier_exists(identifier).await.expect("Query should succeed"));
+
+    let _ = cleanup_identity_by_user_id(&db, user_id_str).await;
+    db.shutdown().await;
+}
+
+#[tokio::test]
+#[ignore] // Requires running PostgreSQL instance
+async fn test_atomic_batch_with_conflict_creates_nothing() {
+    use crate::core::usecases::ports::{BatchCreateOutcome, NewIdentity};
+
+    let (db, repo) = setup_test_db()
+        .await
+        .expect("Failed to setup test database");
+
+    let taken = "batch-taken@example.com";
+    let fresh = "batch-fresh@example.com";
+    let _ = cleanup_identity(&db, taken).await;
+    let _ = cleanup_identity(&db, fresh).await;
+
+    repo.create_identity(&uuid::Uuid::new_v4().to_string(), taken, "$argon2id$hash")
+        .await
+        .expect("First insert should succeed");
+
+    let batch = vec![
+        NewIdentity {
+            user_id: uuid::Uuid::new_v4(),
+            identifier: fresh.to_string(),
+            password_hash: "$argon2id$hash".to_string(),
+        },
+        NewIdentity {
+            user_id: uuid::Uuid::new_v4(),
+            identifier: taken.to_string(),
+            password_hash: "$argon2id$hash".to_string(),
+        },
+    ];
+
+    let outcomes = repo
+        .create_identities_batch(&batch, true)
+        .await
+        .expect("Batch should report per-item outcomes");
+
+    assert_eq!(outcomes[0], BatchCreateOutcome::RolledBack);
+    assert!(matches!(outcomes[1], BatchCreateOutcome::Conflict(_)));
+    assert!(
+        !repo.identifier_exists(fresh).await.expect("Lookup should succeed"),
+        "Rolled back identity must not be persisted"
+    );
+
+    let _ = cleanup_identity(&db, taken).await;
+    let _ = cleanup_identity(&db, fresh).await;
+    db.shutdown().await;
+}
+
+#[tokio::test]
+#[ignore] // Requires running PostgreSQL instance
+async fn test_workspace_membership_lookup() {
+    let (db, repo) = setup_test_db()
+        .await
+        .expect("Failed to setup test database");
+
+    let workspace_id = "workspace-membership-test";
+    let member = "550e8400-e29b-41d4-a716-446655440120";
+    let outsider = "550e8400-e29b-41d4-a716-446655440121";
+    for statement in [
+        "CREATE TABLE IF NOT EXISTS workspace (id TEXT PRIMARY KEY, created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP, deleted_at TIMESTAMPTZ NULL)",
+        "CREATE TABLE IF NOT EXISTS workspace_member (workspace_id TEXT NOT NULL REFERENCES workspace (id), user_id UUID NOT NULL, created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP, PRIMARY KEY (workspace_id, user_id))",
+    ] {
+        sqlx::query(statement).execute(db.pool()).await.expect("Failed to create workspace tables");
+    }
+    sqlx::query("INSERT INTO workspace (id) VALUES ($1) ON CONFLICT (id) DO UPDATE SET deleted_at = NULL")
+        .bind(workspace_id)
+        .execute(db.pool())
+        .await
+        .expect("Failed to insert workspace");
+    sqlx::query("INSERT INTO workspace_member (workspace_id, user_id) VALUES ($1, $2::uuid) ON CONFLICT DO NOTHING")
+        .bind(workspace_id)
+        .bind(member)
+        .execute(db.pool())
+        .await
+        .expect("Failed to insert workspace member");
+
+    assert!(repo.workspace_exists(workspace_id).await.expect("Lookup should succeed"));
+    assert!(!repo.workspace_exists("workspace-unknown").await.expect("Lookup should succeed"));
+    assert!(repo.workspace_member_exists(member, workspace_id).await.expect("Lookup should succeed"));
+    assert!(!repo.workspace_member_exists(outsider, workspace_id).await.expect("Lookup should succeed"));
+
+    // A deleted workspace admits nobody
+    sqlx::query("UPDATE workspace SET deleted_at = CURRENT_TIMESTAMP WHERE id = $1")
+        .bind(workspace_id)
+        .execute(db.pool())
+        .await
+        .expect("Failed to delete workspace");
+    assert!(!repo.workspace_exists(workspace_id).await.expect("Lookup should succeed"));
+    assert!(!repo.workspace_member_exists(member, workspace_id).await.expect("Lookup should succeed"));
+
+    db.shutdown().await;
+}
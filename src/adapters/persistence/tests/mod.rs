@@ -1,6 +1,8 @@
 // Integration tests for the persistence adapter layer.
 
+pub mod credential_repository_tests;
 pub mod database_tests;
+pub mod retry_tests;
 pub mod identity_repository_tests;
 pub mod session_repository_tests;
 pub mod id_conversion_tests;
\ No newline at end of file
@@ -0,0 +1,114 @@
+//! Retry-with-backoff tests.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    use crate::adapters::persistence::{
+        error::{ConnectionError, ExecutionError, PersistenceError},
+        retry::{with_retries, RetryPolicy},
+    };
+
+    /// Fails with `error` for the first `failures` calls, then succeeds.
+    struct FlakyOp {
+        calls: AtomicU32,
+        failures: u32,
+        error: PersistenceError,
+    }
+
+    impl FlakyOp {
+        fn new(failures: u32, error: PersistenceError) -> Self {
+            Self {
+                calls: AtomicU32::new(0),
+                failures,
+                error,
+            }
+        }
+
+        async fn call(&self) -> Result<&'static str, PersistenceError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if call <= self.failures {
+                Err(self.error.clone())
+            } else {
+                Ok("row")
+            }
+        }
+
+        fn calls(&self) -> u32 {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    fn fast_policy(attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(attempts, Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn test_transient_failures_are_retried_to_success() {
+        let op = FlakyOp::new(2, PersistenceError::Connection(ConnectionError::timeout("acquire timed out")));
+
+        let result = with_retries(fast_policy(3), || op.call()).await;
+
+        assert_eq!(result.unwrap(), "row");
+        assert_eq!(op.calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_serialization_failure_is_retried_to_success() {
+        let op = FlakyOp::new(
+            1,
+            PersistenceError::Execution(ExecutionError::serialization_failure("deadlock detected")),
+        );
+
+        let result = with_retries(fast_policy(3), || op.call()).await;
+
+        assert_eq!(result.unwrap(), "row");
+        assert_eq!(op.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_constraint_error_is_not_retried() {
+        let op = FlakyOp::new(2, PersistenceError::unique_violation("identifier taken"));
+
+        let result = with_retries(fast_policy(3), || op.call()).await;
+
+        assert!(result.unwrap_err().is_conflict());
+        assert_eq!(op.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_query_failure_is_not_retried() {
+        let op = FlakyOp::new(1, PersistenceError::query_failed("syntax error"));
+
+        assert!(with_retries(fast_policy(3), || op.call()).await.is_err());
+        assert_eq!(op.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_configured_attempts() {
+        let op = FlakyOp::new(10, PersistenceError::unavailable("connection reset"));
+
+        let result = with_retries(fast_policy(2), || op.call()).await;
+
+        assert!(result.unwrap_err().is_unavailable());
+        assert_eq!(op.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_policy_makes_one_attempt() {
+        let op = FlakyOp::new(1, PersistenceError::unavailable("connection reset"));
+
+        assert!(with_retries(RetryPolicy::none(), || op.call()).await.is_err());
+        assert_eq!(op.calls(), 1);
+    }
+
+    #[test]
+    fn test_retry_policy_is_bounded() {
+        let policy = RetryPolicy::new(100, Duration::from_secs(60));
+        assert_eq!(policy.attempts(), RetryPolicy::MAX_ATTEMPTS);
+        assert_eq!(policy.backoff(), RetryPolicy::MAX_BACKOFF);
+
+        assert_eq!(RetryPolicy::new(0, Duration::ZERO).attempts(), 1);
+    }
+}
@@ -162,6 +162,12 @@ async fn test_session_is_active() {
         revoked_at: None,
         ip_address: "192.168.1.1".to_string(),
         user_agent: "Mozilla/5.0".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: now,
     };
 
@@ -190,6 +196,12 @@ async fn test_session_is_expired() {
         revoked_at: None,
         ip_address: "192.168.1.1".to_string(),
         user_agent: "Mozilla/5.0".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: now,
     };
 
@@ -219,6 +231,12 @@ async fn test_session_is_revoked() {
         revoked_at: Some(now - chrono::Duration::hours(1)),
         ip_address: "192.168.1.1".to_string(),
         user_agent: "Mozilla/5.0".to_string(),
+        device_type: None,
+        os: None,
+        browser: None,
+        geo_country: None,
+        geo_region: None,
+        geo_city: None,
         updated_at: now,
     };
 
@@ -367,3 +385,182 @@ async fn test_revoke_session() {
     let _ = cleanup_session(&db, session_id).await;
     db.shutdown().await;
 }
+
+#[tokio::test]
+#[ignore] // Requires running PostgreSQL instance
+async fn test_list_active_for_user_filters_revoked_and_expired() {
+    let (db, repo) = setup_test_db()
+        .await
+        .expect("Failed to setup test database");
+
+    let user_id = "550e8400-e29b-41d4-a716-446655440070";
+
+    // Ensure test identity exists
+    ensure_test_identity(&db, user_id)
+        .await
+        .expect("Failed to create test identity");
+
+    let now = Utc::now();
+    let user_id_uuid = to_uuid(user_id);
+
+    // (id, created_at, expires_at, revoked_at)
+    let sessions = vec![
+        ("550e8400-e29b-41d4-a716-446655440071", now - chrono::Duration::hours(2), now + chrono::Duration::days(7), None),
+        ("550e8400-e29b-41d4-a716-446655440072", now - chrono::Duration::hours(1), now + chrono::Duration::days(7), None),
+        ("550e8400-e29b-41d4-a716-446655440073", now - chrono::Duration::hours(3), now + chrono::Duration::days(7), Some(now)),
+        ("550e8400-e29b-41d4-a716-446655440074", now - chrono::Duration::days(8), now - chrono::Duration::hours(1), None),
+    ];
+
+    for (idx, (session_id, created_at, expires_at, revoked_at)) in sessions.iter().enumerate() {
+        let _ = cleanup_session(&db, session_id).await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO auth_session
+            (id, user_id, refresh_token_hash, created_at, expires_at, revoked_at, ip_address, user_agent, updated_at)
+            VALUES ($1::uuid, $2::uuid, $3, $4, $5, $6, $7, $8, $9)
+            "#
+        )
+        .bind(to_uuid(session_id))
+        .bind(&user_id_uuid)
+        .bind(format!("$2b$12$list_hash_{}", idx))
+        .bind(created_at)
+        .bind(expires_at)
+        .bind(revoked_at)
+        .bind("192.168.1.1")
+        .bind("Mozilla/5.0")
+        .bind(now)
+        .execute(db.pool())
+        .await
+        .unwrap_or_else(|_| panic!("Failed to create session {}", idx));
+    }
+
+    let rows = repo
+        .list_active_for_user(user_id)
+        .await
+        .expect("Listing should succeed");
+
+    let ids: Vec<String> = rows.iter().map(|row| row.id.to_string()).collect();
+    assert_eq!(
+        ids,
+        vec![
+            "550e8400-e29b-41d4-a716-446655440072".to_string(),
+            "550e8400-e29b-41d4-a716-446655440071".to_string(),
+        ],
+        "Only active sessions should be listed, newest first"
+    );
+
+    // Cleanup
+    for (session_id, _, _, _) in sessions {
+        let _ = cleanup_session(&db, session_id).await;
+    }
+    db.shutdown().await;
+}
+
+#[tokio::test]
+#[ignore] // Requires running PostgreSQL instance
+async fn test_rotate_refresh_token_is_single_use() {
+    let (db, repo) = setup_test_db()
+        .await
+        .expect("Failed to setup test database");
+
+    let session_id = "550e8400-e29b-41d4-a716-446655440080";
+    let user_id = "550e8400-e29b-41d4-a716-446655440081";
+
+    ensure_test_identity(&db, user_id)
+        .await
+        .expect("Failed to create test identity");
+    let _ = cleanup_session(&db, session_id).await;
+
+    repo.create_session(
+        session_id,
+        user_id,
+        "rotate_hash_0",
+        Utc::now() + chrono::Duration::days(7),
+        "192.168.1.1",
+        "Mozilla/5.0",
+    )
+    .await
+    .expect("Failed to create session");
+
+    // First rotation consumes the live hash
+    let rotated = repo
+        .rotate_refresh_token(session_id, "rotate_hash_0", "rotate_hash_1")
+        .await
+        .expect("Rotation should succeed");
+    assert!(rotated);
+
+    // A second rotation of the same hash loses
+    let rotated_again = repo
+        .rotate_refresh_token(session_id, "rotate_hash_0", "rotate_hash_2")
+        .await
+        .expect("Rotation query should succeed");
+    assert!(!rotated_again, "A consumed hash must not rotate twice");
+
+    assert!(repo.is_rotated_refresh_token("rotate_hash_0").await.unwrap());
+    assert!(!repo.is_rotated_refresh_token("rotate_hash_1").await.unwrap());
+
+    let row = repo
+        .find_by_refresh_token_hash("rotate_hash_1")
+        .await
+        .expect("Rotated hash should be live");
+    assert_eq!(row.id.to_string(), session_id);
+
+    // Cleanup
+    let _ = cleanup_session(&db, session_id).await;
+    db.shutdown().await;
+}
+
+#[tokio::test]
+#[ignore] // Requires running PostgreSQL instance
+async fn test_unit_of_work_rollback_discards_session() {
+    use crate::adapters::persistence::repositories::UnitOfWorkSql;
+    use crate::core::identity::UserIdentity;
+    use crate::core::usecases::ports::UnitOfWork;
+
+    let (db, repo) = setup_test_db()
+        .await
+        .expect("Failed to setup test database");
+
+    let user_id = "550e8400-e29b-41d4-a716-446655440071";
+    let rolled_back_id = "550e8400-e29b-41d4-a716-446655440072";
+    let committed_id = "550e8400-e29b-41d4-a716-446655440073";
+    ensure_test_identity(&db, user_id)
+        .await
+        .expect("Failed to create test identity");
+    let _ = cleanup_session(&db, rolled_back_id).await;
+    let _ = cleanup_session(&db, committed_id).await;
+
+    let unit_of_work = UnitOfWorkSql::new(db.clone());
+    let user = UserIdentity::new(user_id);
+    let metadata = r#"{"ip":"203.0.113.7","ua":"uow-test"}"#;
+
+    let mut scope = unit_of_work.begin().await.expect("Failed to begin unit of work");
+    scope
+        .create_session(rolled_back_id, &user, "uow_rolled_back_hash", metadata)
+        .await
+        .expect("Staging the session should succeed");
+    scope.rollback().await;
+
+    assert!(
+        repo.find_by_id(rolled_back_id).await.is_err(),
+        "Rolled back session must not be persisted"
+    );
+
+    let mut scope = unit_of_work.begin().await.expect("Failed to begin unit of work");
+    scope
+        .create_session(committed_id, &user, "uow_committed_hash", metadata)
+        .await
+        .expect("Staging the session should succeed");
+    scope
+        .record_successful_login(user_id, &chrono::Utc::now().to_rfc3339())
+        .await
+        .expect("Staging the login should succeed");
+    scope.commit().await.expect("Commit should succeed");
+
+    let row = repo.find_by_id(committed_id).await.expect("Committed session should exist");
+    assert_eq!(row.user_agent, "uow-test");
+
+    let _ = cleanup_session(&db, committed_id).await;
+    db.shutdown().await;
+}
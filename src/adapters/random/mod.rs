@@ -0,0 +1,18 @@
+//! Random source adapters.
+//!
+//! This module provides concrete randomness implementing the `RandomSource`
+//! port from the core domain.
+//!
+//! # Components
+//!
+//! - [`SystemRandomSource`]: Operating system CSPRNG
+//! - [`SeededRandomSource`]: Deterministic stream from a fixed seed (tests)
+
+pub mod seeded_random_source;
+pub mod system_random_source;
+
+pub use seeded_random_source::SeededRandomSource;
+pub use system_random_source::SystemRandomSource;
+
+#[cfg(test)]
+mod tests;
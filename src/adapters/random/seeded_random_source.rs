@@ -0,0 +1,41 @@
+//! Deterministic random source for tests.
+
+use std::sync::Mutex;
+
+use crate::core::usecases::ports::RandomSource;
+
+/// Random source producing a fixed byte stream from a seed.
+///
+/// Uses SplitMix64, so the same seed always yields the same bytes on every
+/// platform and library version. Not suitable for production use.
+#[derive(Debug)]
+pub struct SeededRandomSource {
+    state: Mutex<u64>,
+}
+
+impl SeededRandomSource {
+    /// Create a source whose stream is determined by `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: Mutex::new(seed),
+        }
+    }
+
+    fn next_u64(state: &mut u64) -> u64 {
+        *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl RandomSource for SeededRandomSource {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        for chunk in dest.chunks_mut(8) {
+            let bytes = Self::next_u64(&mut state).to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Operating system random source.
+
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::core::usecases::ports::RandomSource;
+
+/// Random source reading from the operating system CSPRNG.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemRandomSource;
+
+impl RandomSource for SystemRandomSource {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        // The OS generator only fails if the platform cannot supply entropy,
+        // in which case no id or token we could produce would be safe
+        SystemRandom::new()
+            .fill(dest)
+            .expect("operating system random generator is unavailable");
+    }
+}
@@ -0,0 +1,4 @@
+//! Tests for the random module.
+
+mod seeded_random_source_tests;
+mod system_random_source_tests;
@@ -0,0 +1,67 @@
+//! Tests for SeededRandomSource.
+
+use chrono::{TimeZone, Utc};
+
+use crate::adapters::random::SeededRandomSource;
+use crate::core::usecases::ports::RandomSource;
+
+#[test]
+fn test_same_seed_yields_same_stream() {
+    let a = SeededRandomSource::new(7);
+    let b = SeededRandomSource::new(7);
+
+    let mut first = [0u8; 24];
+    let mut second = [0u8; 24];
+    a.fill_bytes(&mut first);
+    b.fill_bytes(&mut second);
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_different_seeds_yield_different_streams() {
+    let mut first = [0u8; 16];
+    let mut second = [0u8; 16];
+    SeededRandomSource::new(1).fill_bytes(&mut first);
+    SeededRandomSource::new(2).fill_bytes(&mut second);
+
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_stream_advances_between_calls() {
+    let random = SeededRandomSource::new(7);
+
+    assert_ne!(random.opaque_token(16), random.opaque_token(16));
+}
+
+#[test]
+fn test_known_opaque_token() {
+    let random = SeededRandomSource::new(42);
+
+    assert_eq!(
+        random.opaque_token(32),
+        "956eeb2f2632d7bd03f166b233e3ef28529f0f135767524794e34a0effe11c58"
+    );
+}
+
+#[test]
+fn test_known_uuid_v4() {
+    let random = SeededRandomSource::new(42);
+
+    let id = random.uuid_v4();
+
+    assert_eq!(id.to_string(), "956eeb2f-2632-47bd-83f1-66b233e3ef28");
+    assert_eq!(id.get_version_num(), 4);
+}
+
+#[test]
+fn test_known_uuid_v7() {
+    let random = SeededRandomSource::new(42);
+    let at = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+
+    let id = random.uuid_v7(at);
+
+    assert_eq!(id.to_string(), "018bcfe5-6800-756e-ab2f-2632d7bd03f1");
+    assert_eq!(id.get_version_num(), 7);
+}
@@ -0,0 +1,18 @@
+//! Tests for SystemRandomSource.
+
+use crate::adapters::random::SystemRandomSource;
+use crate::core::usecases::ports::RandomSource;
+
+#[test]
+fn test_system_random_source_fills_buffer() {
+    let mut bytes = [0u8; 32];
+    SystemRandomSource.fill_bytes(&mut bytes);
+
+    assert_ne!(bytes, [0u8; 32]);
+}
+
+#[test]
+fn test_system_random_source_values_differ() {
+    assert_ne!(SystemRandomSource.uuid_v4(), SystemRandomSource.uuid_v4());
+    assert_ne!(SystemRandomSource.opaque_token(16), SystemRandomSource.opaque_token(16));
+}
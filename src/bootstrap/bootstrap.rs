@@ -6,13 +6,14 @@
 use super::config::AuthConfig;
 use super::server;
 use super::wiring::{initialize_components, AppComponents};
+use crate::adapters::http::middleware::install_panic_hook;
 
 /// Main entry point for bootstrapping the application.
 ///
 /// This function orchestrates the entire startup sequence:
 /// 1. Load .env file if present
 /// 2. Load configuration from environment
-/// 3. Initialize tracing/logging
+/// 3. Initialize tracing/logging and the redacting panic hook
 /// 4. Build all application components
 /// 5. Start the HTTP server
 ///
@@ -39,8 +40,9 @@ pub async fn run() -> anyhow::Result<()> {
             e
         })?;
     
-    // Step 3: Initialize logging
+    // Step 3: Initialize logging, then route panics through it without payloads
     init_logging(&config);
+    install_panic_hook();
     
     tracing::info!(
         mode = %config.mode,
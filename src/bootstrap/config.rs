@@ -4,6 +4,11 @@
 //! structured configuration for all service components.
 
 use std::env;
+use std::net::IpAddr;
+
+use crate::core::usecases::policies::{
+    AuthPolicyConfig, HasherParams, IpBinding, LockoutPolicy, LockoutScope, TokenLifetimes,
+};
 
 /// Centralized configuration for the authentication service.
 ///
@@ -59,6 +64,8 @@ pub struct ServerConfig {
     pub host: String,
     /// Port to listen on
     pub port: u16,
+    /// Seconds in-flight requests may take to finish after a shutdown signal
+    pub shutdown_grace_secs: u64,
 }
 
 /// Database connection configuration
@@ -81,6 +88,8 @@ pub struct CryptoConfig {
     pub password_hash_iterations: u32,
     /// Argon2 parallelism factor
     pub password_hash_parallelism: u32,
+    /// Optional server-held pepper mixed into every password hash
+    pub password_pepper: Option<String>,
     /// JWT signing algorithm: "eddsa" or "hmac"
     pub token_algorithm: TokenAlgorithm,
     /// JWT signing key for HMAC (HS256 symmetric key)
@@ -93,6 +102,10 @@ pub struct CryptoConfig {
     pub access_token_ttl_mins: u64,
     /// Refresh token TTL in days
     pub refresh_token_ttl_days: u64,
+    /// Clock skew tolerated when checking token `exp`/`nbf`, in seconds
+    pub token_leeway_secs: u64,
+    /// Seconds a rotated-out refresh token may be retried (0 treats any retry as reuse)
+    pub refresh_rotation_grace_secs: u64,
 }
 
 /// JWT signing algorithm
@@ -102,6 +115,8 @@ pub enum TokenAlgorithm {
     EdDSA,
     /// HMAC-SHA256 - symmetric
     Hmac,
+    /// Opaque reference tokens whose claims are kept in the database
+    Opaque,
 }
 
 /// Security policy configuration
@@ -111,8 +126,94 @@ pub struct SecurityConfig {
     pub max_failed_attempts: u32,
     /// Account lockout duration in minutes
     pub lock_duration_mins: u64,
+    /// Whether failed attempts lock the account or only the source IP
+    pub lockout_scope: LockoutScope,
+    /// Seconds after the last failure at which failed attempts start over (0 = never)
+    pub failed_attempt_reset_secs: u64,
+    /// Failed attempts across all source IPs that lock the account under the
+    /// per-IP scope (0 = four times `max_failed_attempts`)
+    pub lockout_account_ceiling: u32,
+    /// Whether failed logins hide lockouts behind the generic invalid-credentials response
+    pub generic_auth_failures: bool,
     /// Enable debug logging (security-sensitive)
     pub enable_debug_logs: bool,
+    /// Maximum requests per client within the rate limit window
+    pub rate_limit_max_requests: u32,
+    /// Rate limit window length in seconds
+    pub rate_limit_window_secs: u64,
+    /// TTL of cached identity lookups in seconds (0 disables the cache)
+    pub identity_cache_ttl_secs: u64,
+    /// Maximum number of identifiers held in the identity cache
+    pub identity_cache_max_entries: usize,
+    /// Reverse proxies in front of the service whose X-Forwarded-For
+    /// entries are trusted (0 ignores the header)
+    pub trusted_proxy_hops: usize,
+    /// Interval between expired-session cleanup runs in seconds (0 disables the cleaner)
+    pub session_cleanup_interval_secs: u64,
+    /// Seconds between reloads of the revoke-all token watermark, so one
+    /// raised by another instance takes effect here (0 disables)
+    pub token_watermark_refresh_secs: u64,
+    /// How strictly a refresh must come from the session's original IP
+    pub refresh_ip_binding: IpBinding,
+    /// How login identifiers are normalized before storage and lookup
+    pub identifier_normalization: IdentifierNormalization,
+    /// Whether a refresh must come from the session's original user agent
+    pub refresh_bind_user_agent: bool,
+    /// Whether access tokens are rejected once their session is revoked
+    /// (costs a session lookup per validation)
+    pub access_token_session_binding: bool,
+    /// Where security audit events are recorded
+    pub audit_sink: AuditSinkKind,
+    /// Browser origins allowed to call the public routes (empty disables CORS)
+    pub cors_allowed_origins: Vec<String>,
+    /// Methods announced to CORS preflight requests
+    pub cors_allowed_methods: Vec<String>,
+    /// Whether cross-origin requests may carry credentials
+    pub cors_allow_credentials: bool,
+    /// Largest request body accepted by the public routes, in bytes
+    pub max_body_bytes: usize,
+    /// Longest login identifier accepted, in bytes
+    pub max_identifier_bytes: usize,
+    /// Longest password accepted, in bytes
+    pub max_password_bytes: usize,
+    /// Longest refresh token accepted, in bytes
+    pub max_refresh_token_bytes: usize,
+    /// Whether new passwords are checked against the Pwned Passwords breach corpus
+    pub breach_check_enabled: bool,
+    /// Base URL of the Pwned Passwords range API (only the hash prefix is sent)
+    pub breach_check_base_url: String,
+    /// Shortest password accepted, in bytes
+    pub password_min_length: usize,
+    /// Previous passwords a new one must differ from, besides the current one (0 disables)
+    pub password_history_depth: usize,
+    /// Whether passwords containing the account identifier are rejected
+    pub password_forbid_identifier: bool,
+    /// Minimum zxcvbn strength score (0-4) a password must reach (0 disables scoring)
+    pub password_min_strength_score: u8,
+}
+
+/// Destination of the security audit trail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditSinkKind {
+    /// No audit trail
+    Off,
+    /// JSON lines on standard output
+    Stdout,
+    /// The `auth_audit_event` table
+    Database,
+}
+
+/// Rules login identifiers are normalized with before storage and lookup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentifierNormalization {
+    /// Identifiers are stored and looked up as given
+    Off,
+    /// Trim, Unicode NFKC and lowercase
+    Email,
+    /// As `Email`, also dropping `+tag` subaddresses
+    CanonicalEmail,
+    /// Trim and Unicode NFKC, case preserved
+    Username,
 }
 
 /// Service-to-service authentication configuration
@@ -133,6 +234,31 @@ pub struct ServiceAuthConfig {
     pub eddsa_service_public_key: Option<String>,
     /// Service token TTL in minutes
     pub service_token_ttl_mins: u64,
+    /// Require a registered mTLS client certificate on internal routes.
+    ///
+    /// The server speaks plain TCP, so mutual TLS is terminated by a proxy
+    /// that forwards the verified certificate in `client_cert_header`.
+    pub require_client_cert: bool,
+    /// Client certificate identities: service_id -> SPIFFE ID or subject
+    /// Format: service_id=identity (semicolon-separated, as subjects contain commas)
+    pub certificate_identities: Vec<(String, String)>,
+    /// Header carrying the certificate verified by the TLS-terminating proxy
+    pub client_cert_header: String,
+    /// Addresses of the TLS-terminating proxies trusted to set `client_cert_header`
+    pub client_cert_trusted_proxies: Vec<IpAddr>,
+    /// Where service accounts are looked up
+    pub registry: ServiceRegistryKind,
+    /// Seconds between reloads of the database service registry (0 disables)
+    pub registry_refresh_secs: u64,
+}
+
+/// Source of the service accounts allowed to call internal routes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceRegistryKind {
+    /// Keys and credentials from the environment
+    Static,
+    /// The `service_account` table, with hashed, rotatable secrets
+    Database,
 }
 
 /// Deployment mode determines operational characteristics
@@ -158,6 +284,7 @@ impl AuthConfig {
             server: ServerConfig {
                 host: Self::get_env("AUTH_SERVER_HOST", "0.0.0.0"),
                 port: Self::parse_port()?,
+                shutdown_grace_secs: Self::parse_u64("AUTH_SHUTDOWN_GRACE_SECS", 30)?,
             },
             database: DatabaseConfig {
                 url: Self::require_env("AUTH_DATABASE_URL")?,
@@ -170,18 +297,55 @@ impl AuthConfig {
                 password_hash_iterations: Self::parse_u32("AUTH_HASH_ITERATIONS", 
                     if mode == DeploymentMode::Development { 2 } else { 3 })?,
                 password_hash_parallelism: Self::parse_u32("AUTH_HASH_PARALLELISM", 1)?,
+                password_pepper: std::env::var("AUTH_PASSWORD_PEPPER").ok().filter(|v| !v.is_empty()),
                 token_algorithm: Self::parse_token_algorithm()?,
                 token_signing_key: Self::require_env("AUTH_TOKEN_SIGNING_KEY")?,
                 eddsa_private_key: Self::get_env("AUTH_EDDSA_PRIVATE_KEY", "").into(),
                 eddsa_public_key: Self::get_env("AUTH_EDDSA_PUBLIC_KEY", "").into(),
                 access_token_ttl_mins: Self::parse_u64("AUTH_ACCESS_TOKEN_TTL_MINS", 15)?,
                 refresh_token_ttl_days: Self::parse_u64("AUTH_REFRESH_TOKEN_TTL_DAYS", 7)?,
+                token_leeway_secs: Self::parse_u64("AUTH_TOKEN_LEEWAY_SECS", 0)?,
+                refresh_rotation_grace_secs: Self::parse_u64("AUTH_REFRESH_ROTATION_GRACE_SECS", 0)?,
             },
             security: SecurityConfig {
                 max_failed_attempts: Self::parse_u32("AUTH_MAX_FAILED_ATTEMPTS", 5)?,
                 lock_duration_mins: Self::parse_u64("AUTH_LOCK_DURATION_MINS", 30)?,
+                lockout_scope: Self::parse_lockout_scope()?,
+                failed_attempt_reset_secs: Self::parse_u64("AUTH_FAILED_ATTEMPT_RESET_SECS", 0)?,
+                lockout_account_ceiling: Self::parse_u32("AUTH_LOCKOUT_ACCOUNT_CEILING", 0)?,
+                generic_auth_failures: Self::parse_bool("AUTH_GENERIC_AUTH_FAILURES", false),
                 enable_debug_logs: Self::parse_bool("AUTH_ENABLE_DEBUG_LOGS", 
                     mode == DeploymentMode::Development),
+                rate_limit_max_requests: Self::parse_u32("AUTH_RATE_LIMIT_MAX_REQUESTS", 60)?,
+                rate_limit_window_secs: Self::parse_u64("AUTH_RATE_LIMIT_WINDOW_SECS", 60)?,
+                identity_cache_ttl_secs: Self::parse_u64("AUTH_IDENTITY_CACHE_TTL_SECS", 0)?,
+                identity_cache_max_entries: Self::parse_u64("AUTH_IDENTITY_CACHE_MAX_ENTRIES", 10_000)? as usize,
+                trusted_proxy_hops: Self::parse_u64("AUTH_TRUSTED_PROXY_HOPS", 1)? as usize,
+                session_cleanup_interval_secs: Self::parse_u64("AUTH_SESSION_CLEANUP_INTERVAL_SECS", 3600)?,
+                token_watermark_refresh_secs: Self::parse_u64("AUTH_TOKEN_WATERMARK_REFRESH_SECS", 30)?,
+                refresh_ip_binding: Self::parse_refresh_ip_binding()?,
+                identifier_normalization: Self::parse_identifier_normalization()?,
+                refresh_bind_user_agent: Self::parse_bool("AUTH_REFRESH_BIND_USER_AGENT", false),
+                access_token_session_binding: Self::parse_bool("AUTH_ACCESS_TOKEN_SESSION_BINDING", true),
+                audit_sink: Self::parse_audit_sink()?,
+                cors_allowed_origins: Self::parse_list("AUTH_CORS_ALLOWED_ORIGINS", ""),
+                cors_allowed_methods: Self::parse_list("AUTH_CORS_ALLOWED_METHODS", "GET,POST")
+                    .into_iter()
+                    .map(|method| method.to_uppercase())
+                    .collect(),
+                cors_allow_credentials: Self::parse_bool("AUTH_CORS_ALLOW_CREDENTIALS", false),
+                max_body_bytes: Self::parse_u64("AUTH_MAX_BODY_BYTES", 16 * 1024)? as usize,
+                max_identifier_bytes: Self::parse_u64("AUTH_MAX_IDENTIFIER_BYTES", 320)? as usize,
+                max_password_bytes: Self::parse_u64("AUTH_MAX_PASSWORD_BYTES", 1024)? as usize,
+                max_refresh_token_bytes: Self::parse_u64("AUTH_MAX_REFRESH_TOKEN_BYTES", 4096)? as usize,
+                breach_check_enabled: Self::parse_bool("AUTH_BREACH_CHECK_ENABLED", false),
+                breach_check_base_url: Self::get_env("AUTH_BREACH_CHECK_BASE_URL", "https://api.pwnedpasswords.com"),
+                password_min_length: Self::parse_u64("AUTH_PASSWORD_MIN_LENGTH", 8)? as usize,
+                password_history_depth: Self::parse_u64("AUTH_PASSWORD_HISTORY_DEPTH", 0)? as usize,
+                password_forbid_identifier: Self::parse_bool("AUTH_PASSWORD_FORBID_IDENTIFIER", false),
+                password_min_strength_score: Self::parse_u32("AUTH_PASSWORD_MIN_STRENGTH_SCORE", 0)?
+                    .try_into()
+                    .map_err(|_| anyhow::anyhow!("AUTH_PASSWORD_MIN_STRENGTH_SCORE must be between 0 and 4"))?,
             },
             service_auth: ServiceAuthConfig {
                 valid_service_keys: Self::parse_service_keys()?,
@@ -191,6 +355,12 @@ impl AuthConfig {
                 eddsa_service_private_key: Self::get_env("AUTH_EDDSA_SERVICE_PRIVATE_KEY", "").into(),
                 eddsa_service_public_key: Self::get_env("AUTH_EDDSA_SERVICE_PUBLIC_KEY", "").into(),
                 service_token_ttl_mins: Self::parse_u64("AUTH_SERVICE_TOKEN_TTL_MINS", 60)?,
+                require_client_cert: Self::parse_bool("AUTH_INTERNAL_REQUIRE_CLIENT_CERT", false),
+                certificate_identities: Self::parse_certificate_identities()?,
+                client_cert_header: Self::get_env("AUTH_CLIENT_CERT_HEADER", "x-forwarded-client-cert"),
+                client_cert_trusted_proxies: Self::parse_client_cert_trusted_proxies()?,
+                registry: Self::parse_service_registry()?,
+                registry_refresh_secs: Self::parse_u64("AUTH_SERVICE_REGISTRY_REFRESH_SECS", 60)?,
             },
             google_oauth: GoogleOAuthConfig {
                 client_id: Self::require_env("GOOGLE_CLIENT_ID")?,
@@ -244,6 +414,63 @@ impl AuthConfig {
             "Lock duration must be greater than 0 minutes"
         );
 
+        // Validate rate limit parameters
+        anyhow::ensure!(
+            self.security.rate_limit_max_requests > 0,
+            "Rate limit max requests must be greater than 0"
+        );
+
+        anyhow::ensure!(
+            self.security.rate_limit_window_secs > 0,
+            "Rate limit window must be greater than 0 seconds"
+        );
+
+        // Validate request size limit
+        anyhow::ensure!(
+            self.security.max_body_bytes > 0,
+            "Max body size must be greater than 0 bytes"
+        );
+        anyhow::ensure!(
+            self.security.max_identifier_bytes > 0
+                && self.security.max_password_bytes > 0
+                && self.security.max_refresh_token_bytes > 0,
+            "Max identifier, password and refresh token lengths must be greater than 0 bytes"
+        );
+
+        // Validate the password policy against the accepted password length
+        anyhow::ensure!(
+            self.security.password_min_length > 0
+                && self.security.password_min_length <= self.security.max_password_bytes,
+            "Password minimum length must be between 1 and the max password length ({} bytes), got {}",
+            self.security.max_password_bytes,
+            self.security.password_min_length
+        );
+
+        anyhow::ensure!(
+            self.security.password_min_strength_score <= 4,
+            "Password minimum strength score must be between 0 and 4, got {}",
+            self.security.password_min_strength_score
+        );
+
+        // Validate the breach check endpoint when the check is on
+        if self.security.breach_check_enabled {
+            anyhow::ensure!(
+                self.security.breach_check_base_url.starts_with("https://")
+                    || self.security.breach_check_base_url.starts_with("http://"),
+                "Breach check base URL must be an http(s) URL, got '{}'",
+                self.security.breach_check_base_url
+            );
+        }
+
+        // Validate CORS origins: an explicit allowlist, never a wildcard
+        for origin in &self.security.cors_allowed_origins {
+            anyhow::ensure!(
+                origin.starts_with("https://") || origin.starts_with("http://"),
+                "CORS origin must be an explicit http(s) origin, got '{}'",
+                origin
+            );
+        }
+
         // Validate signing key entropy (minimum 32 bytes for HS256)
         use base64::Engine;
         let key_bytes = base64::engine::general_purpose::STANDARD
@@ -257,12 +484,28 @@ impl AuthConfig {
             key_bytes.len()
         );
 
-        // Validate service keys are present
+        // Validate service keys are present (the database registry holds its own)
         anyhow::ensure!(
-            !self.service_auth.valid_service_keys.is_empty(),
+            self.service_auth.registry == ServiceRegistryKind::Database
+                || !self.service_auth.valid_service_keys.is_empty(),
             "At least one service API key must be configured"
         );
 
+        // Client certificates reach the server only through the proxy that
+        // terminates mTLS in front of it; without one trusted to forward
+        // them, every internal request would be rejected
+        if self.service_auth.require_client_cert {
+            anyhow::ensure!(
+                !self.service_auth.client_cert_trusted_proxies.is_empty(),
+                "AUTH_CLIENT_CERT_TRUSTED_PROXIES must list the TLS-terminating proxy \
+                 when AUTH_INTERNAL_REQUIRE_CLIENT_CERT is enabled"
+            );
+            anyhow::ensure!(
+                axum::http::HeaderName::from_bytes(self.service_auth.client_cert_header.as_bytes()).is_ok(),
+                "AUTH_CLIENT_CERT_HEADER must be a valid header name"
+            );
+        }
+
         // Validate service token signing key
         let service_key_bytes = base64::engine::general_purpose::STANDARD
             .decode(&self.service_auth.service_token_signing_key)
@@ -286,9 +529,44 @@ impl AuthConfig {
             );
         }
 
+        // Validate the configuration the use cases will run with
+        self.policy_config()
+            .validate()
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+
         Ok(())
     }
 
+    /// Typed configuration handed to the use cases.
+    ///
+    /// Lockouts escalate exponentially up to one day (or the base lock
+    /// duration, if longer); refresh tokens rotate.
+    pub fn policy_config(&self) -> AuthPolicyConfig {
+        let lock_duration_secs = self.security.lock_duration_mins * 60;
+        let mut lockout = LockoutPolicy::new(self.security.max_failed_attempts, lock_duration_secs, true)
+            .with_exponential_backoff(lock_duration_secs.max(24 * 60 * 60))
+            .with_scope(self.security.lockout_scope)
+            .with_failure_reset_window(self.security.failed_attempt_reset_secs);
+        if self.security.lockout_account_ceiling > 0 {
+            lockout = lockout.with_account_ceiling(self.security.lockout_account_ceiling);
+        }
+        AuthPolicyConfig::new(
+            lockout,
+            TokenLifetimes::new(
+                self.crypto.access_token_ttl_mins * 60,
+                self.crypto.refresh_token_ttl_days,
+                self.service_auth.service_token_ttl_mins * 60,
+                true,
+            )
+            .with_rotation_grace(self.crypto.refresh_rotation_grace_secs),
+            HasherParams::new(
+                self.crypto.password_hash_memory_cost,
+                self.crypto.password_hash_iterations,
+                self.crypto.password_hash_parallelism,
+            ),
+        )
+    }
+
     // Helper methods for environment parsing
 
     fn get_env(key: &str, default: &str) -> String {
@@ -340,18 +618,120 @@ impl AuthConfig {
         matches!(val.as_str(), "true" | "1" | "yes" | "on")
     }
 
+    fn parse_list(key: &str, default: &str) -> Vec<String> {
+        Self::get_env(key, default)
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+
+    fn parse_certificate_identities() -> anyhow::Result<Vec<(String, String)>> {
+        // Split on the first '=' only: certificate subjects contain '=' themselves
+        Self::get_env("AUTH_SERVICE_CERT_IDENTITIES", "")
+            .split(';')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| match entry.split_once('=') {
+                Some((service_id, identity)) if !service_id.trim().is_empty() && !identity.trim().is_empty() => {
+                    Ok((service_id.trim().to_string(), identity.trim().to_string()))
+                }
+                _ => Err(anyhow::anyhow!(
+                    "Invalid AUTH_SERVICE_CERT_IDENTITIES entry: '{}'. Expected service_id=identity",
+                    entry
+                )),
+            })
+            .collect()
+    }
+
+    fn parse_client_cert_trusted_proxies() -> anyhow::Result<Vec<IpAddr>> {
+        Self::parse_list("AUTH_CLIENT_CERT_TRUSTED_PROXIES", "")
+            .iter()
+            .map(|entry| {
+                entry.parse::<IpAddr>().map_err(|_| {
+                    anyhow::anyhow!("Invalid AUTH_CLIENT_CERT_TRUSTED_PROXIES entry: '{}'. Expected an IP address", entry)
+                })
+            })
+            .collect()
+    }
+
     fn parse_token_algorithm() -> anyhow::Result<TokenAlgorithm> {
         let alg_str = Self::get_env("AUTH_TOKEN_ALGORITHM", "hmac").to_lowercase();
         match alg_str.as_str() {
             "eddsa" | "ed25519" => Ok(TokenAlgorithm::EdDSA),
             "hmac" | "hs256" | "hs384" | "hs512" => Ok(TokenAlgorithm::Hmac),
+            "opaque" => Ok(TokenAlgorithm::Opaque),
             _ => Err(anyhow::anyhow!(
-                "Invalid AUTH_TOKEN_ALGORITHM: {}. Must be 'eddsa' or 'hmac'",
+                "Invalid AUTH_TOKEN_ALGORITHM: {}. Must be 'eddsa', 'hmac' or 'opaque'",
                 alg_str
             )),
         }
     }
 
+    fn parse_lockout_scope() -> anyhow::Result<LockoutScope> {
+        let scope_str = Self::get_env("AUTH_LOCKOUT_SCOPE", "account").to_lowercase();
+        match scope_str.as_str() {
+            "account" => Ok(LockoutScope::Account),
+            "account_ip" | "ip" => Ok(LockoutScope::AccountAndSourceIp),
+            _ => Err(anyhow::anyhow!(
+                "Invalid AUTH_LOCKOUT_SCOPE: {}. Must be 'account' or 'account_ip'",
+                scope_str
+            )),
+        }
+    }
+
+    fn parse_refresh_ip_binding() -> anyhow::Result<IpBinding> {
+        let binding_str = Self::get_env("AUTH_REFRESH_IP_BINDING", "off").to_lowercase();
+        match binding_str.as_str() {
+            "off" | "none" => Ok(IpBinding::Off),
+            "subnet" => Ok(IpBinding::subnet()),
+            "exact" => Ok(IpBinding::Exact),
+            _ => Err(anyhow::anyhow!(
+                "Invalid AUTH_REFRESH_IP_BINDING: {}. Must be 'off', 'subnet', or 'exact'",
+                binding_str
+            )),
+        }
+    }
+
+    fn parse_identifier_normalization() -> anyhow::Result<IdentifierNormalization> {
+        let rules_str = Self::get_env("AUTH_IDENTIFIER_NORMALIZATION", "off").to_lowercase();
+        match rules_str.as_str() {
+            "off" | "none" => Ok(IdentifierNormalization::Off),
+            "email" => Ok(IdentifierNormalization::Email),
+            "canonical_email" => Ok(IdentifierNormalization::CanonicalEmail),
+            "username" => Ok(IdentifierNormalization::Username),
+            _ => Err(anyhow::anyhow!(
+                "Invalid AUTH_IDENTIFIER_NORMALIZATION: {}. Must be 'off', 'email', 'canonical_email', or 'username'",
+                rules_str
+            )),
+        }
+    }
+
+    fn parse_audit_sink() -> anyhow::Result<AuditSinkKind> {
+        let sink_str = Self::get_env("AUTH_AUDIT_SINK", "stdout").to_lowercase();
+        match sink_str.as_str() {
+            "off" | "none" => Ok(AuditSinkKind::Off),
+            "stdout" => Ok(AuditSinkKind::Stdout),
+            "database" | "db" => Ok(AuditSinkKind::Database),
+            _ => Err(anyhow::anyhow!(
+                "Invalid AUTH_AUDIT_SINK: {}. Must be 'off', 'stdout', or 'database'",
+                sink_str
+            )),
+        }
+    }
+
+    fn parse_service_registry() -> anyhow::Result<ServiceRegistryKind> {
+        let registry_str = Self::get_env("AUTH_SERVICE_REGISTRY", "static").to_lowercase();
+        match registry_str.as_str() {
+            "static" | "env" => Ok(ServiceRegistryKind::Static),
+            "database" | "db" => Ok(ServiceRegistryKind::Database),
+            _ => Err(anyhow::anyhow!(
+                "Invalid AUTH_SERVICE_REGISTRY: {}. Must be 'static' or 'database'",
+                registry_str
+            )),
+        }
+    }
+
     fn parse_service_token_algorithm() -> anyhow::Result<TokenAlgorithm> {
         let alg_str = Self::get_env("AUTH_SERVICE_TOKEN_ALGORITHM", "hmac").to_lowercase();
         match alg_str.as_str() {
@@ -470,6 +850,7 @@ impl std::fmt::Display for TokenAlgorithm {
         match self {
             TokenAlgorithm::EdDSA => write!(f, "EdDSA"),
             TokenAlgorithm::Hmac => write!(f, "Hmac"),
+            TokenAlgorithm::Opaque => write!(f, "Opaque"),
         }
     }
 }
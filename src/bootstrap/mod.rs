@@ -5,10 +5,12 @@
 //! - Initializing infrastructure (database, crypto)
 //! - Building repositories and services
 //! - Composing the HTTP application
-//! - Starting the server with graceful shutdown
+//! - Starting the server and background tasks with graceful shutdown
 
 pub mod config;
 pub mod server;
+pub mod session_cleaner;
+pub mod shutdown;
 pub mod wiring;
 
 // The bootstrap module contains the main orchestration logic
@@ -16,6 +18,8 @@ pub mod bootstrap;
 
 pub use config::{AuthConfig, DeploymentMode};
 pub use server::run_server;
+pub use session_cleaner::{SessionCleaner, SessionCleanerHandle};
+pub use shutdown::Shutdown;
 pub use wiring::{initialize_components, AppComponents};
 
 // Re-export the main run function for convenience
@@ -3,27 +3,47 @@
 //! This module handles Axum server setup, signal handling, and graceful shutdown.
 
 use std::net::SocketAddr;
+use std::sync::Arc;
 use std::time::Duration;
 
 use tokio::signal;
 
 use crate::adapters::http::create_router;
+use crate::adapters::http::health_check::{Heartbeat, HeartbeatProbe};
 
 use super::config::AuthConfig;
+use super::session_cleaner::{SessionCleaner, SessionCleanerHandle};
+use super::shutdown::{serve_with_grace, Shutdown};
 use super::wiring::AppComponents;
 
 /// Run the HTTP server with graceful shutdown.
 ///
 /// This function:
-/// 1. Creates the Axum router with application state
-/// 2. Binds to the configured address
-/// 3. Starts the server with graceful shutdown handling
-/// 4. Waits for SIGTERM or SIGINT signals
-/// 5. Drains connections and closes resources on shutdown
+/// 1. Starts the expired-session cleaner (unless disabled)
+/// 2. Creates the Axum router with application state
+/// 3. Binds to the configured address
+/// 4. Starts the server with graceful shutdown handling
+/// 5. Waits for SIGTERM or SIGINT signals, which stop new connections and
+///    background tasks together
+/// 6. Drains in-flight requests for up to the configured grace period, then
+///    closes resources
 ///
 /// # Errors
 /// Returns an error if the server fails to start or encounters a fatal error.
 pub async fn run_server(config: &AuthConfig, components: AppComponents) -> anyhow::Result<()> {
+    // One signal stops the listener and every background task
+    let shutdown = Shutdown::new();
+    let signal_listener = {
+        let shutdown = shutdown.clone();
+        tokio::spawn(async move {
+            shutdown_signal().await;
+            shutdown.trigger();
+        })
+    };
+
+    // Start background cleanup before the router takes ownership of the state
+    let session_cleaner = spawn_session_cleaner(config, &components, &shutdown);
+
     // Build the router with application state
     let app = create_router(components.app_state);
     
@@ -40,15 +60,22 @@ pub async fn run_server(config: &AuthConfig, components: AppComponents) -> anyho
         addr
     );
     
-    // Start server with graceful shutdown
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
+    // Start server; on shutdown, drain in-flight requests within the grace period
+    let grace = Duration::from_secs(config.server.shutdown_grace_secs);
+    serve_with_grace(listener, app, &shutdown, grace)
         .await
         .map_err(|e| anyhow::anyhow!("Server error: {}", e))?;
+    signal_listener.abort();
     
     // Shutdown sequence
     tracing::info!("Initiating graceful shutdown...");
     
+    // Stop background tasks before the pool goes away
+    if let Some(cleaner) = session_cleaner {
+        cleaner.shutdown().await;
+        tracing::info!("Session cleaner stopped");
+    }
+    
     // Close database pool
     components.database.shutdown().await;
     tracing::info!("Database pool closed");
@@ -61,6 +88,44 @@ pub async fn run_server(config: &AuthConfig, components: AppComponents) -> anyho
     Ok(())
 }
 
+/// Cleanup passes the session cleaner may miss before it is reported unhealthy
+const SESSION_CLEANER_MISSED_RUNS: u32 = 3;
+
+/// Spawn the expired-session cleaner, or return `None` when it is disabled.
+///
+/// The cleaner is registered as a non-critical health component: a stalled
+/// cleaner degrades the service without taking it out of rotation.
+fn spawn_session_cleaner(
+    config: &AuthConfig,
+    components: &AppComponents,
+    shutdown: &Shutdown,
+) -> Option<SessionCleanerHandle> {
+    let interval_secs = config.security.session_cleanup_interval_secs;
+    if interval_secs == 0 {
+        tracing::info!("Expired-session cleanup is disabled");
+        return None;
+    }
+
+    let interval = Duration::from_secs(interval_secs);
+    let heartbeat = Heartbeat::new();
+    if let Some(checker) = &components.app_state.health_checker {
+        checker.register(
+            "session_cleaner",
+            false,
+            Arc::new(HeartbeatProbe::new(heartbeat.clone(), interval * SESSION_CLEANER_MISSED_RUNS)),
+        );
+    }
+
+    let cleaner = SessionCleaner::new(components.app_state.session_repo.clone(), interval)
+        .with_heartbeat(heartbeat);
+    let cleaner = match &components.opaque_token_store {
+        Some(store) => cleaner.with_opaque_token_store(store.clone()),
+        None => cleaner,
+    };
+    tracing::info!(interval_secs, "Expired-session cleanup scheduled");
+    Some(cleaner.spawn_until(shutdown))
+}
+
 /// Parse server bind address from configuration.
 fn parse_bind_address(config: &AuthConfig) -> anyhow::Result<SocketAddr> {
     let addr_str = format!("{}:{}", config.server.host, config.server.port);
@@ -0,0 +1,155 @@
+//! Background cleanup of expired sessions.
+//!
+//! Expired rows are never read again but stay in `auth_session` until
+//! something deletes them. The cleaner purges them on a fixed interval for
+//! as long as the server runs, along with lapsed opaque token records when
+//! an opaque token store is attached. A failed run is logged and retried on
+//! the next tick; it never takes the process down.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+use crate::adapters::http::health_check::Heartbeat;
+use crate::core::usecases::ports::{OpaqueTokenStore, SessionRepository};
+
+use super::shutdown::Shutdown;
+
+/// Periodically deletes expired sessions through the session repository.
+pub struct SessionCleaner {
+    session_repo: Arc<dyn SessionRepository + Send + Sync>,
+    opaque_token_store: Option<Arc<dyn OpaqueTokenStore + Send + Sync>>,
+    interval: Duration,
+    heartbeat: Option<Heartbeat>,
+}
+
+impl SessionCleaner {
+    /// Create a cleaner running every `interval`.
+    pub fn new(session_repo: Arc<dyn SessionRepository + Send + Sync>, interval: Duration) -> Self {
+        Self {
+            session_repo,
+            opaque_token_store: None,
+            interval,
+            heartbeat: None,
+        }
+    }
+
+    /// Also delete lapsed records from `opaque_token_store` on every pass.
+    pub fn with_opaque_token_store(mut self, opaque_token_store: Arc<dyn OpaqueTokenStore + Send + Sync>) -> Self {
+        self.opaque_token_store = Some(opaque_token_store);
+        self
+    }
+
+    /// Beat `heartbeat` after every successful cleanup pass.
+    pub fn with_heartbeat(mut self, heartbeat: Heartbeat) -> Self {
+        self.heartbeat = Some(heartbeat);
+        self
+    }
+
+    /// Interval between cleanup runs.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Run a single cleanup pass.
+    ///
+    /// Returns the number of sessions removed, or `None` if the run failed.
+    /// Failures are logged rather than propagated; failing to purge opaque
+    /// tokens does not fail the run.
+    pub async fn tick(&self) -> Option<u64> {
+        if let Some(opaque_token_store) = &self.opaque_token_store {
+            match opaque_token_store.purge_expired().await {
+                Ok(removed) => tracing::info!(removed, "[SessionCleaner] Lapsed opaque tokens deleted"),
+                Err(e) => tracing::warn!("[SessionCleaner] Opaque token cleanup failed: {}", e),
+            }
+        }
+
+        match self.session_repo.purge_expired().await {
+            Ok(removed) => {
+                tracing::info!(removed, "[SessionCleaner] Expired sessions deleted");
+                if let Some(heartbeat) = &self.heartbeat {
+                    heartbeat.beat();
+                }
+                Some(removed)
+            }
+            Err(e) => {
+                tracing::warn!("[SessionCleaner] Cleanup run failed: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Run cleanup passes until `shutdown` resolves.
+    ///
+    /// The first pass runs immediately. A pass already in progress when
+    /// shutdown is requested is allowed to finish.
+    pub async fn run<F>(self, shutdown: F)
+    where
+        F: Future<Output = ()>,
+    {
+        let mut ticker = tokio::time::interval(self.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        tokio::pin!(shutdown);
+
+        loop {
+            tokio::select! {
+                _ = &mut shutdown => break,
+                _ = ticker.tick() => {
+                    self.tick().await;
+                }
+            }
+        }
+
+        tracing::info!("[SessionCleaner] Stopped");
+    }
+
+    /// Spawn the cleaner on the current runtime.
+    ///
+    /// It runs until the returned handle is shut down or dropped.
+    pub fn spawn(self) -> SessionCleanerHandle {
+        self.spawn_with(std::future::pending())
+    }
+
+    /// Spawn the cleaner, stopping it when `shutdown` is triggered as well
+    /// as when the returned handle is shut down.
+    pub fn spawn_until(self, shutdown: &Shutdown) -> SessionCleanerHandle {
+        self.spawn_with(shutdown.wait())
+    }
+
+    fn spawn_with<F>(self, triggered: F) -> SessionCleanerHandle
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+        let task = tokio::spawn(self.run(async move {
+            tokio::select! {
+                _ = shutdown_rx => {}
+                _ = triggered => {}
+            }
+        }));
+
+        SessionCleanerHandle { shutdown_tx, task }
+    }
+}
+
+/// Handle to a spawned [`SessionCleaner`].
+///
+/// Dropping the handle also stops the cleaner.
+pub struct SessionCleanerHandle {
+    shutdown_tx: oneshot::Sender<()>,
+    task: JoinHandle<()>,
+}
+
+impl SessionCleanerHandle {
+    /// Signal the cleaner to stop and wait for it to finish.
+    pub async fn shutdown(self) {
+        let _ = self.shutdown_tx.send(());
+        if let Err(e) = self.task.await {
+            tracing::warn!("[SessionCleaner] Task ended abnormally: {}", e);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Coordinated graceful shutdown.
+//!
+//! One [`Shutdown`] is shared by the HTTP server and every background task.
+//! Triggering it stops the server from accepting new connections and tells
+//! background tasks to stop, while requests already in flight are given a
+//! bounded grace period to finish.
+
+use std::future::IntoFuture;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use axum::Router;
+use tokio::net::TcpListener;
+use tokio::sync::watch;
+
+/// Shutdown signal shared between the server and background tasks.
+///
+/// Cloning is cheap; all clones observe the same trigger.
+#[derive(Clone)]
+pub struct Shutdown {
+    tx: watch::Sender<bool>,
+}
+
+impl Shutdown {
+    /// Create a signal that has not been triggered yet.
+    pub fn new() -> Self {
+        let (tx, _) = watch::channel(false);
+        Self { tx }
+    }
+
+    /// Request shutdown. Idempotent.
+    pub fn trigger(&self) {
+        self.tx.send_replace(true);
+    }
+
+    /// Whether shutdown has been requested.
+    pub fn is_triggered(&self) -> bool {
+        *self.tx.borrow()
+    }
+
+    /// Resolve once shutdown has been requested.
+    ///
+    /// Resolves immediately if it already was.
+    pub fn wait(&self) -> impl std::future::Future<Output = ()> + Send + 'static {
+        let mut rx = self.tx.subscribe();
+        async move {
+            let _ = rx.wait_for(|triggered| *triggered).await;
+        }
+    }
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Serve `app` on `listener` until `shutdown` is triggered, then drain.
+///
+/// Once triggered the listener is closed, so new connections are refused,
+/// and in-flight requests are given up to `grace` to complete. Requests
+/// still running when the grace period ends are abandoned so that shutdown
+/// can proceed; they stop when the process exits.
+///
+/// # Errors
+/// Returns an error if the server fails while accepting connections.
+pub async fn serve_with_grace(
+    listener: TcpListener,
+    app: Router,
+    shutdown: &Shutdown,
+    grace: Duration,
+) -> std::io::Result<()> {
+    // Connection info lets the rate limiter fall back to the peer address
+    let server = axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .with_graceful_shutdown(shutdown.wait())
+        .into_future();
+
+    let grace_expired = {
+        let triggered = shutdown.wait();
+        async move {
+            triggered.await;
+            tokio::time::sleep(grace).await;
+        }
+    };
+
+    tokio::select! {
+        result = server => result,
+        _ = grace_expired => {
+            tracing::warn!(
+                grace_secs = grace.as_secs(),
+                "Grace period elapsed with requests still in flight; abandoning them"
+            );
+            Ok(())
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Tests for configuration management.
 
-use crate::bootstrap::config::{AuthConfig, CryptoConfig, DatabaseConfig, DeploymentMode, GoogleOAuthConfig, SecurityConfig, ServerConfig, ServiceAuthConfig, TokenAlgorithm};
+use crate::bootstrap::config::{AuditSinkKind, AuthConfig, CryptoConfig, DatabaseConfig, DeploymentMode, GoogleOAuthConfig, IdentifierNormalization, SecurityConfig, ServerConfig, ServiceAuthConfig, ServiceRegistryKind, TokenAlgorithm};
+use crate::core::usecases::policies::{IpBinding, LockoutScope};
 
 #[test]
 fn test_deployment_mode_display() {
@@ -32,6 +33,7 @@ fn test_server_config_default() {
     let config = ServerConfig {
         host: "0.0.0.0".to_string(),
         port: 8080,
+        shutdown_grace_secs: 30,
     };
     assert_eq!(config.host, "0.0.0.0");
     assert_eq!(config.port, 8080);
@@ -55,12 +57,15 @@ fn test_crypto_config_default() {
         password_hash_memory_cost: 65536,
         password_hash_iterations: 3,
         password_hash_parallelism: 4,
+        password_pepper: None,
         token_algorithm: TokenAlgorithm::Hmac,
         token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVub3VnaC1mb3ItaHMyNTY=".to_string(),
         eddsa_private_key: None,
         eddsa_public_key: None,
         access_token_ttl_mins: 15,
         refresh_token_ttl_days: 7,
+        token_leeway_secs: 0,
+        refresh_rotation_grace_secs: 0,
     };
     assert_eq!(config.password_hash_memory_cost, 65536);
     assert_eq!(config.password_hash_iterations, 3);
@@ -75,7 +80,36 @@ fn test_security_config_default() {
     let config = SecurityConfig {
         max_failed_attempts: 5,
         lock_duration_mins: 30,
+        lockout_scope: LockoutScope::Account,
+        failed_attempt_reset_secs: 0,
+        lockout_account_ceiling: 0,
+        generic_auth_failures: false,
         enable_debug_logs: false,
+        rate_limit_max_requests: 60,
+        rate_limit_window_secs: 60,
+        identity_cache_ttl_secs: 0,
+        identity_cache_max_entries: 10_000,
+        trusted_proxy_hops: 1,
+        session_cleanup_interval_secs: 3600,
+        token_watermark_refresh_secs: 30,
+        refresh_ip_binding: IpBinding::Off,
+        identifier_normalization: IdentifierNormalization::Off,
+        refresh_bind_user_agent: false,
+        access_token_session_binding: true,
+        audit_sink: AuditSinkKind::Off,
+        cors_allowed_origins: vec![],
+        cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+        cors_allow_credentials: false,
+        max_body_bytes: 16 * 1024,
+        max_identifier_bytes: 320,
+        max_password_bytes: 1024,
+        max_refresh_token_bytes: 4096,
+        breach_check_enabled: false,
+        breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+        password_min_length: 8,
+        password_history_depth: 0,
+        password_forbid_identifier: false,
+        password_min_strength_score: 0,
     };
     assert_eq!(config.max_failed_attempts, 5);
     assert_eq!(config.lock_duration_mins, 30);
@@ -92,6 +126,12 @@ fn test_service_auth_config() {
         eddsa_service_private_key: None,
         eddsa_service_public_key: None,
         service_token_ttl_mins: 60,
+        require_client_cert: false,
+        certificate_identities: vec![],
+        client_cert_header: "x-forwarded-client-cert".to_string(),
+        client_cert_trusted_proxies: vec![],
+        registry: ServiceRegistryKind::Static,
+        registry_refresh_secs: 60,
     };
     assert_eq!(config.valid_service_keys.len(), 2);
     assert_eq!(config.valid_service_keys[0], "key1");
@@ -105,6 +145,7 @@ fn test_auth_config_validation_valid() {
         server: ServerConfig {
             host: "0.0.0.0".to_string(),
             port: 8080,
+            shutdown_grace_secs: 30,
         },
         database: DatabaseConfig {
             url: "postgres://localhost/auth".to_string(),
@@ -115,17 +156,49 @@ fn test_auth_config_validation_valid() {
             password_hash_memory_cost: 65536,
             password_hash_iterations: 3,
             password_hash_parallelism: 4,
+            password_pepper: None,
             token_algorithm: TokenAlgorithm::Hmac,
             token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
             eddsa_private_key: None,
             eddsa_public_key: None,
             access_token_ttl_mins: 15,
             refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
         },
         security: SecurityConfig {
             max_failed_attempts: 5,
             lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
             enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
         },
         service_auth: ServiceAuthConfig {
             valid_service_keys: vec!["service-key".to_string()],
@@ -135,6 +208,303 @@ fn test_auth_config_validation_valid() {
             eddsa_service_private_key: None,
             eddsa_service_public_key: None,
             service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
+        },
+        google_oauth: GoogleOAuthConfig {
+            client_id: "test-client-id.googleusercontent.com".to_string(),
+            client_secret: "test-client-secret".to_string(),
+            redirect_uri: "http://localhost:8080/auth/google/callback".to_string(),
+            issuer: "https://accounts.google.com".to_string(),
+            jwks_url: "https://www.googleapis.com/oauth2/v3/certs".to_string(),
+            token_url: "https://oauth2.googleapis.com/token".to_string(),
+        },
+        user_service: crate::bootstrap::config::UserServiceConfig {
+            base_url: "http://localhost:8083".to_string(),
+        },
+        mode: DeploymentMode::Production,
+    };
+    
+    // Should pass validation
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_auth_config_validation_hash_memory_below_lane_minimum() {
+    let config = AuthConfig {
+        server: ServerConfig {
+            host: "0.0.0.0".to_string(),
+            port: 8080,
+            shutdown_grace_secs: 30,
+        },
+        database: DatabaseConfig {
+            url: "postgres://localhost/auth".to_string(),
+            max_connections: 10,
+            connect_timeout_secs: 30,
+        },
+        crypto: CryptoConfig {
+            password_hash_memory_cost: 65536,
+            password_hash_iterations: 3,
+            password_hash_parallelism: 16384,
+            password_pepper: None,
+            token_algorithm: TokenAlgorithm::Hmac,
+            token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_private_key: None,
+            eddsa_public_key: None,
+            access_token_ttl_mins: 15,
+            refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
+        },
+        security: SecurityConfig {
+            max_failed_attempts: 5,
+            lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
+            enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
+        },
+        service_auth: ServiceAuthConfig {
+            valid_service_keys: vec!["service-key".to_string()],
+            service_credentials: vec![],
+            service_token_algorithm: TokenAlgorithm::Hmac,
+            service_token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVub3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_service_private_key: None,
+            eddsa_service_public_key: None,
+            service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
+        },
+        google_oauth: GoogleOAuthConfig {
+            client_id: "test-client-id.googleusercontent.com".to_string(),
+            client_secret: "test-client-secret".to_string(),
+            redirect_uri: "http://localhost:8080/auth/google/callback".to_string(),
+            issuer: "https://accounts.google.com".to_string(),
+            jwks_url: "https://www.googleapis.com/oauth2/v3/certs".to_string(),
+            token_url: "https://oauth2.googleapis.com/token".to_string(),
+        },
+        user_service: crate::bootstrap::config::UserServiceConfig {
+            base_url: "http://localhost:8083".to_string(),
+        },
+        mode: DeploymentMode::Production,
+    };
+    
+    // Should pass validation
+    let err_msg = format!("{}", config.validate().unwrap_err());
+    assert!(err_msg.contains("8 KiB per lane"));
+}
+
+#[test]
+fn test_auth_config_policy_config_uses_security_settings() {
+    let config = AuthConfig {
+        server: ServerConfig {
+            host: "0.0.0.0".to_string(),
+            port: 8080,
+            shutdown_grace_secs: 30,
+        },
+        database: DatabaseConfig {
+            url: "postgres://localhost/auth".to_string(),
+            max_connections: 10,
+            connect_timeout_secs: 30,
+        },
+        crypto: CryptoConfig {
+            password_hash_memory_cost: 65536,
+            password_hash_iterations: 3,
+            password_hash_parallelism: 4,
+            password_pepper: None,
+            token_algorithm: TokenAlgorithm::Hmac,
+            token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_private_key: None,
+            eddsa_public_key: None,
+            access_token_ttl_mins: 15,
+            refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
+        },
+        security: SecurityConfig {
+            max_failed_attempts: 5,
+            lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
+            enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
+        },
+        service_auth: ServiceAuthConfig {
+            valid_service_keys: vec!["service-key".to_string()],
+            service_credentials: vec![],
+            service_token_algorithm: TokenAlgorithm::Hmac,
+            service_token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVub3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_service_private_key: None,
+            eddsa_service_public_key: None,
+            service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
+        },
+        google_oauth: GoogleOAuthConfig {
+            client_id: "test-client-id.googleusercontent.com".to_string(),
+            client_secret: "test-client-secret".to_string(),
+            redirect_uri: "http://localhost:8080/auth/google/callback".to_string(),
+            issuer: "https://accounts.google.com".to_string(),
+            jwks_url: "https://www.googleapis.com/oauth2/v3/certs".to_string(),
+            token_url: "https://oauth2.googleapis.com/token".to_string(),
+        },
+        user_service: crate::bootstrap::config::UserServiceConfig {
+            base_url: "http://localhost:8083".to_string(),
+        },
+        mode: DeploymentMode::Production,
+    };
+    
+    // Should pass validation
+    let policy = config.policy_config();
+    assert_eq!(policy.lockout.max_attempts, config.security.max_failed_attempts);
+    assert_eq!(policy.lockout.lock_duration_secs, config.security.lock_duration_mins * 60);
+    assert_eq!(policy.lockout.scope(), config.security.lockout_scope);
+    assert_eq!(policy.tokens.access_ttl_secs, config.crypto.access_token_ttl_mins * 60);
+    assert_eq!(policy.tokens.refresh_ttl_days, config.crypto.refresh_token_ttl_days);
+    assert_eq!(policy.tokens.service_ttl_secs, config.service_auth.service_token_ttl_mins * 60);
+    assert_eq!(policy.hasher.memory_cost_kib, config.crypto.password_hash_memory_cost);
+    assert!(policy.validate().is_ok());
+}
+
+#[test]
+fn test_auth_config_validation_database_registry_without_static_keys() {
+    let config = AuthConfig {
+        server: ServerConfig {
+            host: "0.0.0.0".to_string(),
+            port: 8080,
+            shutdown_grace_secs: 30,
+        },
+        database: DatabaseConfig {
+            url: "postgres://localhost/auth".to_string(),
+            max_connections: 10,
+            connect_timeout_secs: 30,
+        },
+        crypto: CryptoConfig {
+            password_hash_memory_cost: 65536,
+            password_hash_iterations: 3,
+            password_hash_parallelism: 4,
+            password_pepper: None,
+            token_algorithm: TokenAlgorithm::Hmac,
+            token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_private_key: None,
+            eddsa_public_key: None,
+            access_token_ttl_mins: 15,
+            refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
+        },
+        security: SecurityConfig {
+            max_failed_attempts: 5,
+            lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
+            enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
+        },
+        service_auth: ServiceAuthConfig {
+            valid_service_keys: vec![],
+            service_credentials: vec![],
+            service_token_algorithm: TokenAlgorithm::Hmac,
+            service_token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVub3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_service_private_key: None,
+            eddsa_service_public_key: None,
+            service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Database,
+            registry_refresh_secs: 60,
         },
         google_oauth: GoogleOAuthConfig {
             client_id: "test-client-id.googleusercontent.com".to_string(),
@@ -160,6 +530,7 @@ fn test_auth_config_validation_invalid_ttl() {
         server: ServerConfig {
             host: "0.0.0.0".to_string(),
             port: 8080,
+            shutdown_grace_secs: 30,
         },
         database: DatabaseConfig {
             url: "postgres://localhost/auth".to_string(),
@@ -170,17 +541,49 @@ fn test_auth_config_validation_invalid_ttl() {
             password_hash_memory_cost: 65536,
             password_hash_iterations: 3,
             password_hash_parallelism: 4,
+            password_pepper: None,
             token_algorithm: TokenAlgorithm::Hmac,
             token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
             eddsa_private_key: None,
             eddsa_public_key: None,
             access_token_ttl_mins: 10080, // 7 days - longer than refresh token
             refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
         },
         security: SecurityConfig {
             max_failed_attempts: 5,
             lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
             enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
         },
         service_auth: ServiceAuthConfig {
             valid_service_keys: vec!["service-key".to_string()],
@@ -190,6 +593,12 @@ fn test_auth_config_validation_invalid_ttl() {
             eddsa_service_private_key: None,
             eddsa_service_public_key: None,
             service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
         },
         google_oauth: GoogleOAuthConfig {
             client_id: "test-client-id.googleusercontent.com".to_string(),
@@ -218,6 +627,7 @@ fn test_auth_config_validation_short_signing_key() {
         server: ServerConfig {
             host: "0.0.0.0".to_string(),
             port: 8080,
+            shutdown_grace_secs: 30,
         },
         database: DatabaseConfig {
             url: "postgres://localhost/auth".to_string(),
@@ -228,17 +638,49 @@ fn test_auth_config_validation_short_signing_key() {
             password_hash_memory_cost: 65536,
             password_hash_iterations: 3,
             password_hash_parallelism: 4,
+            password_pepper: None,
             token_algorithm: TokenAlgorithm::Hmac,
             token_signing_key: "c2hvcnQta2V5".to_string(), // "short-key" - too short
             eddsa_private_key: None,
             eddsa_public_key: None,
             access_token_ttl_mins: 15,
             refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
         },
         security: SecurityConfig {
             max_failed_attempts: 5,
             lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
             enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
         },
         service_auth: ServiceAuthConfig {
             valid_service_keys: vec!["service-key".to_string()],
@@ -248,6 +690,12 @@ fn test_auth_config_validation_short_signing_key() {
             eddsa_service_private_key: None,
             eddsa_service_public_key: None,
             service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
         },
         google_oauth: GoogleOAuthConfig {
             client_id: "test-client-id.googleusercontent.com".to_string(),
@@ -276,6 +724,7 @@ fn test_auth_config_validation_zero_max_attempts() {
         server: ServerConfig {
             host: "0.0.0.0".to_string(),
             port: 8080,
+            shutdown_grace_secs: 30,
         },
         database: DatabaseConfig {
             url: "postgres://localhost/auth".to_string(),
@@ -286,17 +735,49 @@ fn test_auth_config_validation_zero_max_attempts() {
             password_hash_memory_cost: 65536,
             password_hash_iterations: 3,
             password_hash_parallelism: 4,
+            password_pepper: None,
             token_algorithm: TokenAlgorithm::Hmac,
             token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
             eddsa_private_key: None,
             eddsa_public_key: None,
             access_token_ttl_mins: 15,
             refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
         },
         security: SecurityConfig {
             max_failed_attempts: 0, // Invalid - must be > 0
             lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
             enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
         },
         service_auth: ServiceAuthConfig {
             valid_service_keys: vec!["service-key".to_string()],
@@ -306,6 +787,12 @@ fn test_auth_config_validation_zero_max_attempts() {
             eddsa_service_private_key: None,
             eddsa_service_public_key: None,
             service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
         },
         google_oauth: GoogleOAuthConfig {
             client_id: "test-client-id.googleusercontent.com".to_string(),
@@ -334,6 +821,7 @@ fn test_auth_config_validation_production_requirements() {
         server: ServerConfig {
             host: "0.0.0.0".to_string(),
             port: 8080,
+            shutdown_grace_secs: 30,
         },
         database: DatabaseConfig {
             url: "postgres://localhost/auth".to_string(),
@@ -344,17 +832,49 @@ fn test_auth_config_validation_production_requirements() {
             password_hash_memory_cost: 4096, // Too low for production
             password_hash_iterations: 2,      // Too low for production
             password_hash_parallelism: 4,
+            password_pepper: None,
             token_algorithm: TokenAlgorithm::Hmac,
             token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
             eddsa_private_key: None,
             eddsa_public_key: None,
             access_token_ttl_mins: 15,
             refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
         },
         security: SecurityConfig {
             max_failed_attempts: 5,
             lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
             enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
         },
         service_auth: ServiceAuthConfig {
             valid_service_keys: vec!["service-key".to_string()],
@@ -364,6 +884,12 @@ fn test_auth_config_validation_production_requirements() {
             eddsa_service_private_key: None,
             eddsa_service_public_key: None,
             service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
         },
         google_oauth: GoogleOAuthConfig {
             client_id: "test-client-id.googleusercontent.com".to_string(),
@@ -392,6 +918,7 @@ fn test_auth_config_validation_development_allows_lower_security() {
         server: ServerConfig {
             host: "0.0.0.0".to_string(),
             port: 8080,
+            shutdown_grace_secs: 30,
         },
         database: DatabaseConfig {
             url: "postgres://localhost/auth".to_string(),
@@ -402,17 +929,49 @@ fn test_auth_config_validation_development_allows_lower_security() {
             password_hash_memory_cost: 4096, // Lower is OK for development
             password_hash_iterations: 2,      // Lower is OK for development
             password_hash_parallelism: 4,
+            password_pepper: None,
             token_algorithm: TokenAlgorithm::Hmac,
             token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
             eddsa_private_key: None,
             eddsa_public_key: None,
             access_token_ttl_mins: 15,
             refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
         },
         security: SecurityConfig {
             max_failed_attempts: 5,
             lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
             enable_debug_logs: true,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
         },
         service_auth: ServiceAuthConfig {
             valid_service_keys: vec!["service-key".to_string()],
@@ -422,6 +981,12 @@ fn test_auth_config_validation_development_allows_lower_security() {
             eddsa_service_private_key: None,
             eddsa_service_public_key: None,
             service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
         },
         google_oauth: GoogleOAuthConfig {
             client_id: "test-client-id.googleusercontent.com".to_string(),
@@ -447,6 +1012,7 @@ fn test_auth_config_validation_empty_service_keys() {
         server: ServerConfig {
             host: "0.0.0.0".to_string(),
             port: 8080,
+            shutdown_grace_secs: 30,
         },
         database: DatabaseConfig {
             url: "postgres://localhost/auth".to_string(),
@@ -457,17 +1023,49 @@ fn test_auth_config_validation_empty_service_keys() {
             password_hash_memory_cost: 65536,
             password_hash_iterations: 3,
             password_hash_parallelism: 4,
+            password_pepper: None,
             token_algorithm: TokenAlgorithm::Hmac,
             token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
             eddsa_private_key: None,
             eddsa_public_key: None,
             access_token_ttl_mins: 15,
             refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
         },
         security: SecurityConfig {
             max_failed_attempts: 5,
             lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
             enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
         },
         service_auth: ServiceAuthConfig {
             valid_service_keys: vec![], // Empty - should fail
@@ -477,6 +1075,12 @@ fn test_auth_config_validation_empty_service_keys() {
             eddsa_service_private_key: None,
             eddsa_service_public_key: None,
             service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
         },
         google_oauth: GoogleOAuthConfig {
             client_id: "test-client-id.googleusercontent.com".to_string(),
@@ -499,9 +1103,504 @@ fn test_auth_config_validation_empty_service_keys() {
     assert!(err_msg.contains("service API key"));
 }
 
+#[test]
+fn test_auth_config_validation_wildcard_cors_origin() {
+    let config = AuthConfig {
+        server: ServerConfig {
+            host: "0.0.0.0".to_string(),
+            port: 8080,
+            shutdown_grace_secs: 30,
+        },
+        database: DatabaseConfig {
+            url: "postgres://localhost/auth".to_string(),
+            max_connections: 10,
+            connect_timeout_secs: 30,
+        },
+        crypto: CryptoConfig {
+            password_hash_memory_cost: 65536,
+            password_hash_iterations: 3,
+            password_hash_parallelism: 4,
+            password_pepper: None,
+            token_algorithm: TokenAlgorithm::Hmac,
+            token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_private_key: None,
+            eddsa_public_key: None,
+            access_token_ttl_mins: 15,
+            refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
+        },
+        security: SecurityConfig {
+            max_failed_attempts: 5,
+            lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
+            enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec!["https://app.example.com".to_string(), "*".to_string()],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
+        },
+        service_auth: ServiceAuthConfig {
+            valid_service_keys: vec!["service-key".to_string()],
+            service_credentials: vec![],
+            service_token_algorithm: TokenAlgorithm::Hmac,
+            service_token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVub3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_service_private_key: None,
+            eddsa_service_public_key: None,
+            service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
+        },
+        google_oauth: GoogleOAuthConfig {
+            client_id: "test-client-id.googleusercontent.com".to_string(),
+            client_secret: "test-client-secret".to_string(),
+            redirect_uri: "http://localhost:8080/auth/google/callback".to_string(),
+            issuer: "https://accounts.google.com".to_string(),
+            jwks_url: "https://www.googleapis.com/oauth2/v3/certs".to_string(),
+            token_url: "https://oauth2.googleapis.com/token".to_string(),
+        },
+        user_service: crate::bootstrap::config::UserServiceConfig {
+            base_url: "http://localhost:8083".to_string(),
+        },
+        mode: DeploymentMode::Production,
+    };
+    
+    // Should fail validation
+    let result = config.validate();
+    assert!(result.is_err());
+    let err_msg = format!("{}", result.unwrap_err());
+    assert!(err_msg.contains("CORS origin"));
+}
+
+#[test]
+fn test_auth_config_validation_breach_check_base_url() {
+    let config = AuthConfig {
+        server: ServerConfig {
+            host: "0.0.0.0".to_string(),
+            port: 8080,
+            shutdown_grace_secs: 30,
+        },
+        database: DatabaseConfig {
+            url: "postgres://localhost/auth".to_string(),
+            max_connections: 10,
+            connect_timeout_secs: 30,
+        },
+        crypto: CryptoConfig {
+            password_hash_memory_cost: 65536,
+            password_hash_iterations: 3,
+            password_hash_parallelism: 4,
+            password_pepper: None,
+            token_algorithm: TokenAlgorithm::Hmac,
+            token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_private_key: None,
+            eddsa_public_key: None,
+            access_token_ttl_mins: 15,
+            refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
+        },
+        security: SecurityConfig {
+            max_failed_attempts: 5,
+            lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
+            enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: true,
+            breach_check_base_url: "api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
+        },
+        service_auth: ServiceAuthConfig {
+            valid_service_keys: vec!["service-key".to_string()],
+            service_credentials: vec![],
+            service_token_algorithm: TokenAlgorithm::Hmac,
+            service_token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVub3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_service_private_key: None,
+            eddsa_service_public_key: None,
+            service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
+        },
+        google_oauth: GoogleOAuthConfig {
+            client_id: "test-client-id.googleusercontent.com".to_string(),
+            client_secret: "test-client-secret".to_string(),
+            redirect_uri: "http://localhost:8080/auth/google/callback".to_string(),
+            issuer: "https://accounts.google.com".to_string(),
+            jwks_url: "https://www.googleapis.com/oauth2/v3/certs".to_string(),
+            token_url: "https://oauth2.googleapis.com/token".to_string(),
+        },
+        user_service: crate::bootstrap::config::UserServiceConfig {
+            base_url: "http://localhost:8083".to_string(),
+        },
+        mode: DeploymentMode::Production,
+    };
+    
+    // Should fail validation
+    let result = config.validate();
+    assert!(result.is_err());
+    let err_msg = format!("{}", result.unwrap_err());
+    assert!(err_msg.contains("Breach check base URL"));
+}
+
+#[test]
+fn test_auth_config_validation_password_min_length_above_max() {
+    let config = AuthConfig {
+        server: ServerConfig {
+            host: "0.0.0.0".to_string(),
+            port: 8080,
+            shutdown_grace_secs: 30,
+        },
+        database: DatabaseConfig {
+            url: "postgres://localhost/auth".to_string(),
+            max_connections: 10,
+            connect_timeout_secs: 30,
+        },
+        crypto: CryptoConfig {
+            password_hash_memory_cost: 65536,
+            password_hash_iterations: 3,
+            password_hash_parallelism: 4,
+            password_pepper: None,
+            token_algorithm: TokenAlgorithm::Hmac,
+            token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_private_key: None,
+            eddsa_public_key: None,
+            access_token_ttl_mins: 15,
+            refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
+        },
+        security: SecurityConfig {
+            max_failed_attempts: 5,
+            lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
+            enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 2048,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
+        },
+        service_auth: ServiceAuthConfig {
+            valid_service_keys: vec!["service-key".to_string()],
+            service_credentials: vec![],
+            service_token_algorithm: TokenAlgorithm::Hmac,
+            service_token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVub3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_service_private_key: None,
+            eddsa_service_public_key: None,
+            service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
+        },
+        google_oauth: GoogleOAuthConfig {
+            client_id: "test-client-id.googleusercontent.com".to_string(),
+            client_secret: "test-client-secret".to_string(),
+            redirect_uri: "http://localhost:8080/auth/google/callback".to_string(),
+            issuer: "https://accounts.google.com".to_string(),
+            jwks_url: "https://www.googleapis.com/oauth2/v3/certs".to_string(),
+            token_url: "https://oauth2.googleapis.com/token".to_string(),
+        },
+        user_service: crate::bootstrap::config::UserServiceConfig {
+            base_url: "http://localhost:8083".to_string(),
+        },
+        mode: DeploymentMode::Production,
+    };
+    
+    // Should fail validation
+    let result = config.validate();
+    assert!(result.is_err());
+    let err_msg = format!("{}", result.unwrap_err());
+    assert!(err_msg.contains("Password minimum length"));
+}
+
+#[test]
+fn test_auth_config_validation_password_strength_score_out_of_range() {
+    let config = AuthConfig {
+        server: ServerConfig {
+            host: "0.0.0.0".to_string(),
+            port: 8080,
+            shutdown_grace_secs: 30,
+        },
+        database: DatabaseConfig {
+            url: "postgres://localhost/auth".to_string(),
+            max_connections: 10,
+            connect_timeout_secs: 30,
+        },
+        crypto: CryptoConfig {
+            password_hash_memory_cost: 65536,
+            password_hash_iterations: 3,
+            password_hash_parallelism: 4,
+            password_pepper: None,
+            token_algorithm: TokenAlgorithm::Hmac,
+            token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_private_key: None,
+            eddsa_public_key: None,
+            access_token_ttl_mins: 15,
+            refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
+        },
+        security: SecurityConfig {
+            max_failed_attempts: 5,
+            lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
+            enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 5,
+        },
+        service_auth: ServiceAuthConfig {
+            valid_service_keys: vec!["service-key".to_string()],
+            service_credentials: vec![],
+            service_token_algorithm: TokenAlgorithm::Hmac,
+            service_token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVub3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_service_private_key: None,
+            eddsa_service_public_key: None,
+            service_token_ttl_mins: 60,
+            require_client_cert: false,
+            certificate_identities: vec![],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
+        },
+        google_oauth: GoogleOAuthConfig {
+            client_id: "test-client-id.googleusercontent.com".to_string(),
+            client_secret: "test-client-secret".to_string(),
+            redirect_uri: "http://localhost:8080/auth/google/callback".to_string(),
+            issuer: "https://accounts.google.com".to_string(),
+            jwks_url: "https://www.googleapis.com/oauth2/v3/certs".to_string(),
+            token_url: "https://oauth2.googleapis.com/token".to_string(),
+        },
+        user_service: crate::bootstrap::config::UserServiceConfig {
+            base_url: "http://localhost:8083".to_string(),
+        },
+        mode: DeploymentMode::Production,
+    };
+    
+    // Should fail validation
+    let result = config.validate();
+    assert!(result.is_err());
+    let err_msg = format!("{}", result.unwrap_err());
+    assert!(err_msg.contains("Password minimum strength score"));
+}
+
+#[test]
+fn test_auth_config_validation_client_cert_requires_trusted_proxy() {
+    let mut config = AuthConfig {
+        server: ServerConfig {
+            host: "0.0.0.0".to_string(),
+            port: 8080,
+            shutdown_grace_secs: 30,
+        },
+        database: DatabaseConfig {
+            url: "postgres://localhost/auth".to_string(),
+            max_connections: 10,
+            connect_timeout_secs: 30,
+        },
+        crypto: CryptoConfig {
+            password_hash_memory_cost: 65536,
+            password_hash_iterations: 3,
+            password_hash_parallelism: 4,
+            password_pepper: None,
+            token_algorithm: TokenAlgorithm::Hmac,
+            token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVuZ3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_private_key: None,
+            eddsa_public_key: None,
+            access_token_ttl_mins: 15,
+            refresh_token_ttl_days: 7,
+            token_leeway_secs: 0,
+            refresh_rotation_grace_secs: 0,
+        },
+        security: SecurityConfig {
+            max_failed_attempts: 5,
+            lock_duration_mins: 30,
+            lockout_scope: LockoutScope::Account,
+            failed_attempt_reset_secs: 0,
+            lockout_account_ceiling: 0,
+            generic_auth_failures: false,
+            enable_debug_logs: false,
+            rate_limit_max_requests: 60,
+            rate_limit_window_secs: 60,
+            identity_cache_ttl_secs: 0,
+            identity_cache_max_entries: 10_000,
+            trusted_proxy_hops: 1,
+            session_cleanup_interval_secs: 3600,
+            token_watermark_refresh_secs: 30,
+            refresh_ip_binding: IpBinding::Off,
+            identifier_normalization: IdentifierNormalization::Off,
+            refresh_bind_user_agent: false,
+            access_token_session_binding: true,
+            audit_sink: AuditSinkKind::Off,
+            cors_allowed_origins: vec![],
+            cors_allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+            cors_allow_credentials: false,
+            max_body_bytes: 16 * 1024,
+            max_identifier_bytes: 320,
+            max_password_bytes: 1024,
+            max_refresh_token_bytes: 4096,
+            breach_check_enabled: false,
+            breach_check_base_url: "https://api.pwnedpasswords.com".to_string(),
+            password_min_length: 8,
+            password_history_depth: 0,
+            password_forbid_identifier: false,
+            password_min_strength_score: 0,
+        },
+        service_auth: ServiceAuthConfig {
+            valid_service_keys: vec!["service-key".to_string()],
+            service_credentials: vec![],
+            service_token_algorithm: TokenAlgorithm::Hmac,
+            service_token_signing_key: "dGVzdC1rZXktdGhhdC1pcy1sb25nLWVub3VnaC1mb3ItaHMyNTY=".to_string(),
+            eddsa_service_private_key: None,
+            eddsa_service_public_key: None,
+            service_token_ttl_mins: 60,
+            require_client_cert: true,
+            certificate_identities: vec![("billing".to_string(), "CN=billing".to_string())],
+            client_cert_header: "x-forwarded-client-cert".to_string(),
+            client_cert_trusted_proxies: vec![],
+            registry: ServiceRegistryKind::Static,
+            registry_refresh_secs: 60,
+        },
+        google_oauth: GoogleOAuthConfig {
+            client_id: "test-client-id.googleusercontent.com".to_string(),
+            client_secret: "test-client-secret".to_string(),
+            redirect_uri: "http://localhost:8080/auth/google/callback".to_string(),
+            issuer: "https://accounts.google.com".to_string(),
+            jwks_url: "https://www.googleapis.com/oauth2/v3/certs".to_string(),
+            token_url: "https://oauth2.googleapis.com/token".to_string(),
+        },
+        user_service: crate::bootstrap::config::UserServiceConfig {
+            base_url: "http://localhost:8083".to_string(),
+        },
+        mode: DeploymentMode::Production,
+    };
+    
+    // No proxy is trusted to forward certificates, so none could be checked
+    let result = config.validate();
+    assert!(result.is_err());
+    let err_msg = format!("{}", result.unwrap_err());
+    assert!(err_msg.contains("AUTH_CLIENT_CERT_TRUSTED_PROXIES"));
+
+    config.service_auth.client_cert_trusted_proxies = vec!["10.0.0.2".parse().unwrap()];
+    assert!(config.validate().is_ok());
+
+    config.service_auth.client_cert_header = "x forwarded cert".to_string();
+    let result = config.validate();
+    assert!(result.is_err());
+    let err_msg = format!("{}", result.unwrap_err());
+    assert!(err_msg.contains("AUTH_CLIENT_CERT_HEADER"));
+}
+
 #[test]
 fn test_token_algorithm_display() {
     assert_eq!(format!("{}", TokenAlgorithm::EdDSA), "EdDSA");
     assert_eq!(format!("{}", TokenAlgorithm::Hmac), "Hmac");
+    assert_eq!(format!("{}", TokenAlgorithm::Opaque), "Opaque");
 }
 
@@ -2,4 +2,6 @@
 
 pub mod config_test;
 pub mod server_test;
+pub mod session_cleaner_test;
+pub mod shutdown_test;
 pub mod wiring_test;
@@ -0,0 +1,191 @@
+//! Tests for the expired-session cleaner.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+
+use crate::adapters::memory::{MemoryStore, OpaqueTokenStoreMemory};
+use crate::bootstrap::session_cleaner::SessionCleaner;
+use crate::bootstrap::shutdown::Shutdown;
+use crate::core::error::CoreError;
+use crate::core::identity::UserIdentity;
+use crate::core::usecases::ports::session_repository::Session;
+use crate::core::usecases::ports::{OpaqueTokenRecord, OpaqueTokenStore, SessionRepository};
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+/// Session repository counting `delete_expired` calls.
+///
+/// The first `failures` purge runs report an error without deleting anything.
+#[derive(Default)]
+struct CountingSessionRepo {
+    delete_calls: AtomicUsize,
+    failures: AtomicUsize,
+}
+
+impl CountingSessionRepo {
+    fn failing_first(failures: usize) -> Self {
+        Self { failures: AtomicUsize::new(failures), ..Self::default() }
+    }
+
+    fn delete_calls(&self) -> usize {
+        self.delete_calls.load(Ordering::SeqCst)
+    }
+}
+
+impl SessionRepository for CountingSessionRepo {
+    fn create_session(
+        &self,
+        _session_id: &str,
+        _user: &UserIdentity,
+        _refresh_token_hash: &str,
+        _metadata: &str,
+    ) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        self.delete_calls.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async move {})
+    }
+
+    fn purge_expired(&self) -> BoxFuture<'_, Result<u64, String>> {
+        Box::pin(async move {
+            let failing = self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| left.checked_sub(1))
+                .is_ok();
+            if failing {
+                return Err("connection refused".to_string());
+            }
+
+            self.delete_expired().await;
+            Ok(3)
+        })
+    }
+}
+
+fn cleaner(repo: Arc<CountingSessionRepo>, interval: Duration) -> SessionCleaner {
+    SessionCleaner::new(repo, interval)
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_tick_invokes_delete_expired() {
+    let repo = Arc::new(CountingSessionRepo::default());
+    let cleaner = cleaner(repo.clone(), Duration::from_secs(3600));
+
+    let removed = cleaner.tick().await;
+
+    assert_eq!(removed, Some(3));
+    assert_eq!(repo.delete_calls(), 1);
+}
+
+#[tokio::test]
+async fn test_tick_purges_lapsed_opaque_tokens() {
+    let repo = Arc::new(CountingSessionRepo::default());
+    let store = Arc::new(OpaqueTokenStoreMemory::new(MemoryStore::new()));
+    let now = chrono::Utc::now().timestamp();
+    for (token_hash, expires_at) in [("lapsed", now - 1), ("live", now + 3600)] {
+        store
+            .put(OpaqueTokenRecord {
+                token_hash: token_hash.to_string(),
+                token_type: "access".to_string(),
+                claims: "{}".to_string(),
+                expires_at,
+            })
+            .await
+            .unwrap();
+    }
+    let cleaner = cleaner(repo.clone(), Duration::from_secs(3600)).with_opaque_token_store(store.clone());
+
+    assert_eq!(cleaner.tick().await, Some(3));
+    assert_eq!(store.len(), 1);
+    assert!(store.get("live").await.is_some());
+}
+
+#[tokio::test]
+async fn test_failed_tick_is_reported_without_panicking() {
+    let repo = Arc::new(CountingSessionRepo::failing_first(1));
+    let cleaner = cleaner(repo.clone(), Duration::from_secs(3600));
+
+    assert_eq!(cleaner.tick().await, None);
+    assert_eq!(repo.delete_calls(), 0);
+    assert_eq!(cleaner.tick().await, Some(3));
+    assert_eq!(repo.delete_calls(), 1);
+}
+
+#[tokio::test]
+async fn test_spawned_cleaner_keeps_running_after_a_failed_run() {
+    let repo = Arc::new(CountingSessionRepo::failing_first(1));
+    let handle = cleaner(repo.clone(), Duration::from_millis(5)).spawn();
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    handle.shutdown().await;
+
+    assert!(repo.delete_calls() >= 1, "cleaner should run again after a failure");
+}
+
+#[tokio::test]
+async fn test_shutdown_stops_the_cleaner() {
+    let repo = Arc::new(CountingSessionRepo::default());
+    let handle = cleaner(repo.clone(), Duration::from_secs(3600)).spawn();
+
+    tokio::time::timeout(Duration::from_secs(1), handle.shutdown())
+        .await
+        .expect("cleaner should stop promptly on shutdown");
+
+    // Only the immediate first run can have happened
+    assert!(repo.delete_calls() <= 1);
+}
+
+#[tokio::test]
+async fn test_run_exits_when_shutdown_resolves() {
+    let repo = Arc::new(CountingSessionRepo::default());
+
+    tokio::time::timeout(
+        Duration::from_secs(1),
+        cleaner(repo, Duration::from_secs(3600)).run(std::future::ready(())),
+    )
+    .await
+    .expect("run should return once shutdown resolves");
+}
+
+#[tokio::test]
+async fn test_spawned_cleaner_stops_on_shutdown_signal() {
+    let repo = Arc::new(CountingSessionRepo::default());
+    let shutdown = Shutdown::new();
+    let _handle = cleaner(repo.clone(), Duration::from_millis(5)).spawn_until(&shutdown);
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    shutdown.trigger();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let calls_at_shutdown = repo.delete_calls();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert!(calls_at_shutdown >= 1);
+    assert_eq!(repo.delete_calls(), calls_at_shutdown, "cleaner kept running after the signal");
+}
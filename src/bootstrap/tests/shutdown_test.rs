@@ -0,0 +1,129 @@
+//! Tests for coordinated graceful shutdown.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{routing::get, Router};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+use crate::bootstrap::shutdown::{serve_with_grace, Shutdown};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+/// Router whose `/slow` handler signals `started` and then takes `delay`.
+fn slow_app(started: Arc<Notify>, delay: Duration) -> Router {
+    Router::new().route(
+        "/slow",
+        get(move || {
+            let started = started.clone();
+            async move {
+                started.notify_one();
+                tokio::time::sleep(delay).await;
+                "done"
+            }
+        }),
+    )
+}
+
+async fn start_server(
+    app: Router,
+    shutdown: &Shutdown,
+    grace: Duration,
+) -> (SocketAddr, JoinHandle<std::io::Result<()>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let shutdown = shutdown.clone();
+    let server = tokio::spawn(async move { serve_with_grace(listener, app, &shutdown, grace).await });
+    (addr, server)
+}
+
+/// Whether `addr` starts refusing connections within a second.
+async fn refuses_connections(addr: SocketAddr) -> bool {
+    for _ in 0..50 {
+        if TcpStream::connect(addr).await.is_err() {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    false
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_wait_resolves_after_trigger() {
+    let shutdown = Shutdown::new();
+    let waiter = tokio::spawn(shutdown.wait());
+
+    assert!(!shutdown.is_triggered());
+    shutdown.trigger();
+
+    tokio::time::timeout(Duration::from_secs(1), waiter)
+        .await
+        .expect("wait should resolve once triggered")
+        .unwrap();
+    assert!(shutdown.is_triggered());
+}
+
+#[tokio::test]
+async fn test_wait_resolves_immediately_when_already_triggered() {
+    let shutdown = Shutdown::new();
+    shutdown.trigger();
+
+    tokio::time::timeout(Duration::from_millis(100), shutdown.wait())
+        .await
+        .expect("wait should not block after trigger");
+}
+
+#[tokio::test]
+async fn test_in_flight_request_completes_within_grace_while_new_connections_are_refused() {
+    let shutdown = Shutdown::new();
+    let started = Arc::new(Notify::new());
+    let app = slow_app(started.clone(), Duration::from_millis(300));
+    let (addr, server) = start_server(app, &shutdown, Duration::from_secs(5)).await;
+
+    let in_flight = tokio::spawn(reqwest::get(format!("http://{}/slow", addr)));
+    started.notified().await;
+    shutdown.trigger();
+
+    assert!(refuses_connections(addr).await, "listener still accepting after shutdown");
+
+    let response = in_flight.await.unwrap().expect("in-flight request should complete");
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(response.text().await.unwrap(), "done");
+
+    tokio::time::timeout(Duration::from_secs(1), server)
+        .await
+        .expect("server should stop once drained")
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_shutdown_proceeds_when_grace_period_ends() {
+    let shutdown = Shutdown::new();
+    let started = Arc::new(Notify::new());
+    let app = slow_app(started.clone(), Duration::from_secs(60));
+    let (addr, server) = start_server(app, &shutdown, Duration::from_millis(100)).await;
+
+    let in_flight = tokio::spawn(reqwest::get(format!("http://{}/slow", addr)));
+    started.notified().await;
+    shutdown.trigger();
+
+    tokio::time::timeout(Duration::from_secs(2), server)
+        .await
+        .expect("server should stop when the grace period ends")
+        .unwrap()
+        .unwrap();
+
+    // The slow request is abandoned; it ends with the process
+    assert!(!in_flight.is_finished());
+    in_flight.abort();
+}
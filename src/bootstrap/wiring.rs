@@ -4,30 +4,54 @@
 //! following the dependency graph defined in the bootstrap README.
 
 use std::sync::Arc;
+use axum::http::HeaderName;
 use reqwest::Client;
 
-use crate::adapters::crypto::password::Argon2PasswordHasher;
-use crate::adapters::crypto::token::{EddsaTokenService, HmacTokenService};
+use crate::adapters::audit::JsonLinesAuditSink;
+use crate::adapters::clients::hibp::{HibpBreachChecker, HibpBreachCheckerConfig};
+use crate::adapters::crypto::password::{Argon2PasswordHasher, ZxcvbnStrengthEstimator};
+use crate::adapters::crypto::token::{EddsaTokenService, HmacTokenService, OpaqueTokenService};
+use crate::adapters::http::dto::public::FieldLimits;
+use crate::adapters::http::handlers::health::DATABASE_DEGRADED_THRESHOLD;
+use crate::adapters::http::health_check::{DatabaseProbe, HealthChecker, TokenServiceProbe};
+use crate::adapters::http::middleware::{ClientCertificateProxy, CorsPolicy, RateLimiter};
+use crate::adapters::http::request_context::ClientIpResolver;
 use crate::adapters::http::state::AppState;
+use crate::adapters::identifier::RuleBasedIdentifierNormalizer;
 use crate::adapters::persistence::database::{Database, PoolConfig};
 use crate::adapters::persistence::repositories::{
+    AuditSinkSql,
+    CachedIdentityRepository,
     CredentialRepositorySql, 
     ExternalIdentityRepositorySql,
     IdentityRepositorySql, 
+    OpaqueTokenStoreSql,
+    ServiceRegistrySql,
     SessionRepositorySql,
+    TokenDenyListSql,
+    TokenWatermarkStoreSql,
+    UnitOfWorkSql,
 };
+use crate::core::credentials::CredentialPolicy;
+use crate::core::usecases::policies::{SessionBindingPolicy, TokenPolicy};
 use crate::core::usecases::ports::{
+    AuditSink,
+    BreachChecker,
     ExchangeAuthorizationCode,
+    IdentifierNormalizer,
     ExternalIdentityRepository,
     ExternalTokenValidator, 
+    IdentityRepository,
+    OpaqueTokenStore,
     PasswordHasher, 
     ServiceRegistry, 
     TokenService,
+    TokenWatermarkStore,
     UserServiceClient,
 };
 
 use crate::adapters::crypto::token::EddsaKey;
-use super::config::{AuthConfig, TokenAlgorithm};
+use super::config::{AuditSinkKind, AuthConfig, IdentifierNormalization, ServiceRegistryKind, TokenAlgorithm};
 use crate::adapters::clients::user_service::{UserServiceHttpClient, UserServiceHttpClientConfig};
 
 /// Container for all initialized application components.
@@ -39,6 +63,9 @@ pub struct AppComponents {
     pub database: Database,
     /// HTTP application state (for Axum)
     pub app_state: AppState,
+    /// Store behind opaque tokens, whose lapsed records need purging
+    /// (None unless `AUTH_TOKEN_ALGORITHM=opaque`)
+    pub opaque_token_store: Option<Arc<dyn OpaqueTokenStore + Send + Sync>>,
 }
 
 /// Initialize all application components.
@@ -60,19 +87,26 @@ pub async fn initialize_components(config: &AuthConfig) -> anyhow::Result<AppCom
     
     // Step 2: Build repositories (depend on database)
     tracing::info!("Building repositories...");
-    let identity_repo = IdentityRepositorySql::new(database.clone());
+    let identity_repo = build_identity_repository(config, IdentityRepositorySql::new(database.clone()));
     let credential_repo = CredentialRepositorySql::new(database.clone());
     let session_repo = SessionRepositorySql::new(database.clone());
     let external_identity_repo = ExternalIdentityRepositorySql::new(database.clone());
+    let token_watermark = load_token_watermark(config, &database).await?;
     
     // Step 3: Initialize crypto adapters
     tracing::info!("Initializing crypto adapters...");
-    let password_hasher = initialize_password_hasher(config)?;
-    let token_service = initialize_token_service(config)?;
+    let password_hasher: Arc<dyn PasswordHasher + Send + Sync> = Arc::new(initialize_password_hasher(config)?);
+    let opaque_token_store = build_opaque_token_store(config, &database);
+    let token_service = initialize_token_service(config, opaque_token_store.clone())?;
     
     // Step 4: Build service registry for internal auth
     tracing::info!("Building service registry...");
-    let service_registry = build_service_registry(config);
+    let service_registry = match config.service_auth.registry {
+        ServiceRegistryKind::Static => build_service_registry(config),
+        ServiceRegistryKind::Database => {
+            build_sql_service_registry(config, &database, password_hasher.clone()).await?
+        }
+    };
     
     // Step 4.5: Create Google OAuth token validator
     tracing::info!("Initializing Google OAuth token validator...");
@@ -112,7 +146,7 @@ pub async fn initialize_components(config: &AuthConfig) -> anyhow::Result<AppCom
         base_url: config.user_service.base_url.clone(),
     };
     let user_service_client = Arc::new(
-        UserServiceHttpClient::new(user_service_config, http_client)
+        UserServiceHttpClient::new(user_service_config, http_client.clone())
     ) as Arc<dyn UserServiceClient + Send + Sync>;
     tracing::info!(
         base_url = %config.user_service.base_url,
@@ -121,25 +155,69 @@ pub async fn initialize_components(config: &AuthConfig) -> anyhow::Result<AppCom
     
     // Step 5: Build HTTP application state
     tracing::info!("Building HTTP state...");
+    let health_checker = build_health_checker(&database, token_service.clone());
+    let client_ip_resolver = ClientIpResolver::new(config.security.trusted_proxy_hops);
     let app_state = build_app_state(
         config,
-        Arc::new(identity_repo),
+        identity_repo,
         Arc::new(credential_repo),
         Arc::new(session_repo),
-        Arc::new(password_hasher),
+        password_hasher,
         token_service,
         service_registry,
         google_token_validator.clone(),
         google_code_exchanger,
         Arc::new(external_identity_repo),
         user_service_client,
-    );
+    )
+    .with_database(database.clone())
+    .with_health_checker(health_checker)
+    .with_unit_of_work(Arc::new(UnitOfWorkSql::new(database.clone())))
+    .with_token_deny_list(Arc::new(TokenDenyListSql::new(database.clone())))
+    .with_token_watermark(token_watermark)
+    .with_rate_limiter(Arc::new(
+        RateLimiter::new(
+            config.security.rate_limit_max_requests,
+            config.security.rate_limit_window_secs,
+        )
+        .with_ip_resolver(client_ip_resolver),
+    ))
+    .with_client_ip_resolver(client_ip_resolver)
+    .with_refresh_binding(SessionBindingPolicy::new(
+        config.security.refresh_ip_binding,
+        config.security.refresh_bind_user_agent,
+    ))
+    .with_cors(build_cors_policy(config))
+    .with_max_body_bytes(config.security.max_body_bytes)
+    .with_field_limits(FieldLimits {
+        identifier: config.security.max_identifier_bytes,
+        password: config.security.max_password_bytes,
+        refresh_token: config.security.max_refresh_token_bytes,
+    })
+    .with_require_client_cert(config.service_auth.require_client_cert)
+    .with_client_cert_proxy(build_client_cert_proxy(config))
+    .with_generic_auth_failures(config.security.generic_auth_failures)
+    .with_access_token_session_binding(config.security.access_token_session_binding)
+    .with_credential_policy(build_credential_policy(config));
+    let app_state = match build_audit_sink(config, &database) {
+        Some(audit_sink) => app_state.with_audit_sink(audit_sink),
+        None => app_state,
+    };
+    let app_state = match build_identifier_normalizer(config) {
+        Some(normalizer) => app_state.with_identifier_normalizer(normalizer),
+        None => app_state,
+    };
+    let app_state = match build_breach_checker(config, http_client) {
+        Some(breach_checker) => app_state.with_breach_checker(breach_checker),
+        None => app_state,
+    };
     
     tracing::info!("Component initialization complete");
     
     Ok(AppComponents {
         database,
         app_state,
+        opaque_token_store,
     })
 }
 
@@ -162,26 +240,73 @@ async fn initialize_database(config: &AuthConfig) -> anyhow::Result<Database> {
     Ok(database)
 }
 
+/// Build the identity repository, wrapping it in a lookup cache when enabled.
+fn build_identity_repository(
+    config: &AuthConfig,
+    identity_repo: IdentityRepositorySql,
+) -> Arc<dyn IdentityRepository + Send + Sync> {
+    if config.security.identity_cache_ttl_secs == 0 {
+        return Arc::new(identity_repo);
+    }
+
+    tracing::info!(
+        ttl_secs = config.security.identity_cache_ttl_secs,
+        max_entries = config.security.identity_cache_max_entries,
+        "Identity lookup cache enabled"
+    );
+    Arc::new(CachedIdentityRepository::new(
+        Arc::new(identity_repo),
+        config.security.identity_cache_ttl_secs,
+        config.security.identity_cache_max_entries,
+    ))
+}
+
 /// Initialize password hasher with configured parameters.
 fn initialize_password_hasher(config: &AuthConfig) -> anyhow::Result<Argon2PasswordHasher> {
-    let hasher = Argon2PasswordHasher::new(
-        config.crypto.password_hash_memory_cost,
-        config.crypto.password_hash_iterations,
-        config.crypto.password_hash_parallelism,
-        16, // salt length in bytes
-    ).map_err(|e| anyhow::anyhow!("Failed to initialize password hasher: {:?}", e))?;
+    let hasher = match &config.crypto.password_pepper {
+        Some(pepper) => Argon2PasswordHasher::new_with_pepper(
+            config.crypto.password_hash_memory_cost,
+            config.crypto.password_hash_iterations,
+            config.crypto.password_hash_parallelism,
+            16, // salt length in bytes
+            pepper.as_bytes(),
+        ),
+        None => Argon2PasswordHasher::new(
+            config.crypto.password_hash_memory_cost,
+            config.crypto.password_hash_iterations,
+            config.crypto.password_hash_parallelism,
+            16, // salt length in bytes
+        ),
+    }
+    .map_err(|e| anyhow::anyhow!("Failed to initialize password hasher: {:?}", e))?;
     
     tracing::info!(
-        "Password hasher initialized (memory_cost={}KB, iterations={})",
+        "Password hasher initialized (memory_cost={}KB, iterations={}, peppered={})",
         config.crypto.password_hash_memory_cost,
-        config.crypto.password_hash_iterations
+        config.crypto.password_hash_iterations,
+        hasher.has_pepper()
     );
     
     Ok(hasher)
 }
 
-/// Initialize token service with signing key (supports both EdDSA and HMAC).
-fn initialize_token_service(config: &AuthConfig) -> anyhow::Result<Arc<dyn TokenService>> {
+/// Build the store behind opaque tokens, when they are configured.
+fn build_opaque_token_store(
+    config: &AuthConfig,
+    database: &Database,
+) -> Option<Arc<dyn OpaqueTokenStore + Send + Sync>> {
+    match config.crypto.token_algorithm {
+        TokenAlgorithm::Opaque => Some(Arc::new(OpaqueTokenStoreSql::new(database.clone()))),
+        TokenAlgorithm::EdDSA | TokenAlgorithm::Hmac => None,
+    }
+}
+
+/// Initialize token service with signing key (supports EdDSA and HMAC), or
+/// backed by `opaque_token_store` for opaque tokens.
+fn initialize_token_service(
+    config: &AuthConfig,
+    opaque_token_store: Option<Arc<dyn OpaqueTokenStore + Send + Sync>>,
+) -> anyhow::Result<Arc<dyn TokenService>> {
     use base64::Engine;
     
     match config.crypto.token_algorithm {
@@ -233,7 +358,13 @@ fn initialize_token_service(config: &AuthConfig) -> anyhow::Result<Arc<dyn Token
             
             token_service = token_service
                 .with_service_token_key(&service_signing_key)
-                .map_err(|e| anyhow::anyhow!("Failed to set service token key: {:?}", e))?;
+                .map_err(|e| anyhow::anyhow!("Failed to set service token key: {:?}", e))?
+                .with_token_policy(TokenPolicy::new(
+                    config.crypto.access_token_ttl_mins * 60,
+                    config.crypto.refresh_token_ttl_days * 24 * 3600,
+                    true,
+                ))
+                .with_leeway(config.crypto.token_leeway_secs);
             
             tracing::info!(
                 "Token service initialized (HMAC, access_ttl={}m, refresh_ttl={}d)",
@@ -241,6 +372,24 @@ fn initialize_token_service(config: &AuthConfig) -> anyhow::Result<Arc<dyn Token
                 config.crypto.refresh_token_ttl_days
             );
             
+            Ok(Arc::new(token_service))
+        }
+        TokenAlgorithm::Opaque => {
+            // Opaque mode: random reference tokens, claims kept in the store
+            let store = opaque_token_store
+                .ok_or_else(|| anyhow::anyhow!("Opaque token store not configured"))?;
+            let token_service = OpaqueTokenService::new(store).with_token_policy(TokenPolicy::new(
+                config.crypto.access_token_ttl_mins * 60,
+                config.crypto.refresh_token_ttl_days * 24 * 3600,
+                true,
+            ));
+
+            tracing::info!(
+                "Token service initialized (opaque, access_ttl={}m, refresh_ttl={}d)",
+                config.crypto.access_token_ttl_mins,
+                config.crypto.refresh_token_ttl_days
+            );
+
             Ok(Arc::new(token_service))
         }
     }
@@ -260,6 +409,11 @@ fn build_service_registry(config: &AuthConfig) -> Arc<dyn ServiceRegistry + Send
         );
         registry.add_credentials(service_id, hashed_secret);
     }
+
+    // Add client certificate identities from config
+    for (service_id, identity) in &config.service_auth.certificate_identities {
+        registry.add_certificate_identity(identity, service_id);
+    }
     
     tracing::info!(
         "[BOOTSTRAP] Service registry initialized with {} credentials",
@@ -269,6 +423,204 @@ fn build_service_registry(config: &AuthConfig) -> Arc<dyn ServiceRegistry + Send
     Arc::new(registry)
 }
 
+/// Build the database-backed service registry, load its accounts and keep
+/// reloading them so changes made by other instances show up.
+async fn build_sql_service_registry(
+    config: &AuthConfig,
+    database: &Database,
+    password_hasher: Arc<dyn PasswordHasher + Send + Sync>,
+) -> anyhow::Result<Arc<dyn ServiceRegistry + Send + Sync>> {
+    let registry = Arc::new(ServiceRegistrySql::new(database.clone(), password_hasher));
+    let count = registry
+        .refresh()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to load service accounts: {}", e))?;
+
+    tracing::info!("[BOOTSTRAP] Service registry loaded {} service accounts from database", count);
+
+    match config.service_auth.registry_refresh_secs {
+        0 => tracing::info!("[BOOTSTRAP] Service registry reload is disabled"),
+        secs => spawn_service_registry_refresh(&registry, std::time::Duration::from_secs(secs)),
+    }
+
+    Ok(registry)
+}
+
+/// Reload `registry` every `interval` for as long as it is in use.
+///
+/// A failed reload is logged and the previous snapshot kept.
+fn spawn_service_registry_refresh(registry: &Arc<ServiceRegistrySql>, interval: std::time::Duration) {
+    let registry = Arc::downgrade(registry);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // The first tick fires immediately; the snapshot was just loaded
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+            let Some(registry) = registry.upgrade() else {
+                break;
+            };
+            if let Err(e) = registry.refresh().await {
+                tracing::warn!("[SERVICE_REGISTRY] Reload failed, keeping previous snapshot: {}", e);
+            }
+        }
+    });
+}
+
+/// Load the access token watermark persisted by an earlier revoke-all and
+/// keep reloading it so an advance made by another instance shows up.
+async fn load_token_watermark(
+    config: &AuthConfig,
+    database: &Database,
+) -> anyhow::Result<Arc<dyn TokenWatermarkStore + Send + Sync>> {
+    let watermark = Arc::new(
+        TokenWatermarkStoreSql::load(database.clone())
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to load token watermark: {}", e))?,
+    );
+
+    match watermark.last_known() {
+        Some(revoked_through) => tracing::warn!(
+            "[BOOTSTRAP] Rejecting access tokens issued at or before {} (token watermark)",
+            revoked_through
+        ),
+        None => tracing::info!("[BOOTSTRAP] No token watermark set"),
+    }
+
+    match config.security.token_watermark_refresh_secs {
+        0 => tracing::info!("[BOOTSTRAP] Token watermark reload is disabled"),
+        secs => spawn_token_watermark_refresh(&watermark, std::time::Duration::from_secs(secs)),
+    }
+
+    Ok(watermark)
+}
+
+/// Reload `watermark` every `interval` for as long as it is in use.
+///
+/// A failed reload is logged and the watermark already known kept.
+fn spawn_token_watermark_refresh(watermark: &Arc<TokenWatermarkStoreSql>, interval: std::time::Duration) {
+    let watermark = Arc::downgrade(watermark);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // The first tick fires immediately; the watermark was just loaded
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+            let Some(watermark) = watermark.upgrade() else {
+                break;
+            };
+            if let Err(e) = watermark.fetch().await {
+                tracing::warn!("[TOKEN_WATERMARK] Reload failed, keeping known watermark: {}", e);
+            }
+        }
+    });
+}
+
+/// Build the CORS policy for the public routes.
+fn build_cors_policy(config: &AuthConfig) -> CorsPolicy {
+    let allowed_methods = config
+        .security
+        .cors_allowed_methods
+        .iter()
+        .filter_map(|method| method.parse().ok())
+        .collect();
+    CorsPolicy::new(
+        config.security.cors_allowed_origins.clone(),
+        allowed_methods,
+        config.security.cors_allow_credentials,
+    )
+}
+
+/// Build the proxies trusted to forward verified client certificates.
+fn build_client_cert_proxy(config: &AuthConfig) -> ClientCertificateProxy {
+    // The header name was checked by configuration validation
+    match HeaderName::from_bytes(config.service_auth.client_cert_header.as_bytes()) {
+        Ok(header) => ClientCertificateProxy::new(header, config.service_auth.client_cert_trusted_proxies.clone()),
+        Err(_) => ClientCertificateProxy::default(),
+    }
+}
+
+/// Build the security audit sink selected by configuration.
+fn build_audit_sink(config: &AuthConfig, database: &Database) -> Option<Arc<dyn AuditSink + Send + Sync>> {
+    let audit_sink: Arc<dyn AuditSink + Send + Sync> = match config.security.audit_sink {
+        AuditSinkKind::Off => return None,
+        AuditSinkKind::Stdout => Arc::new(JsonLinesAuditSink::stdout()),
+        AuditSinkKind::Database => Arc::new(AuditSinkSql::new(database.clone())),
+    };
+    tracing::info!(sink = ?config.security.audit_sink, "[BOOTSTRAP] Audit sink configured");
+    Some(audit_sink)
+}
+
+/// Build the health checker and register the components every deployment has.
+///
+/// Background tasks started later register themselves on the same checker.
+fn build_health_checker(
+    database: &Database,
+    token_service: Arc<dyn TokenService + Send + Sync>,
+) -> Arc<HealthChecker> {
+    let checker = Arc::new(HealthChecker::default());
+    checker.register(
+        "database",
+        true,
+        Arc::new(DatabaseProbe::new(database.clone(), DATABASE_DEGRADED_THRESHOLD)),
+    );
+    checker.register("token_service", true, Arc::new(TokenServiceProbe::new(token_service)));
+    checker
+}
+
+/// Build the login identifier normalizer selected by configuration.
+fn build_identifier_normalizer(config: &AuthConfig) -> Option<Arc<dyn IdentifierNormalizer + Send + Sync>> {
+    let normalizer = match config.security.identifier_normalization {
+        IdentifierNormalization::Off => return None,
+        IdentifierNormalization::Email => RuleBasedIdentifierNormalizer::email(),
+        IdentifierNormalization::CanonicalEmail => {
+            RuleBasedIdentifierNormalizer::email().with_email_canonicalization(true)
+        }
+        IdentifierNormalization::Username => RuleBasedIdentifierNormalizer::username(),
+    };
+    tracing::info!(
+        rules = ?config.security.identifier_normalization,
+        "[BOOTSTRAP] Identifier normalization configured"
+    );
+    Some(Arc::new(normalizer))
+}
+
+/// Build the password policy from the configured security settings.
+///
+/// A non-zero minimum strength score scores passwords with zxcvbn.
+fn build_credential_policy(config: &AuthConfig) -> CredentialPolicy {
+    let policy = CredentialPolicy {
+        min_length: config.security.password_min_length,
+        ..CredentialPolicy::default()
+    }
+    .with_password_history(config.security.password_history_depth)
+    .with_forbid_identifier_in_password(config.security.password_forbid_identifier);
+    match config.security.password_min_strength_score {
+        0 => policy,
+        min_score => policy.with_strength_estimator(Arc::new(ZxcvbnStrengthEstimator::new()), min_score),
+    }
+}
+
+/// Build the breached-password checker, if enabled by configuration.
+fn build_breach_checker(config: &AuthConfig, http_client: Client) -> Option<Arc<dyn BreachChecker + Send + Sync>> {
+    if !config.security.breach_check_enabled {
+        return None;
+    }
+    let checker_config = HibpBreachCheckerConfig {
+        base_url: config.security.breach_check_base_url.clone(),
+        ..HibpBreachCheckerConfig::default()
+    };
+    tracing::info!(
+        base_url = %checker_config.base_url,
+        "[BOOTSTRAP] Breached-password check enabled"
+    );
+    Some(Arc::new(HibpBreachChecker::new(checker_config, http_client)))
+}
+
 /// Build HTTP application state for Axum.
 fn build_app_state(
     config: &AuthConfig,
@@ -299,6 +651,7 @@ fn build_app_state(
             true, // rotate_refresh_tokens
             config.service_auth.service_token_ttl_mins * 60, // Convert to seconds
         )
+        .with_config(config.policy_config())
 }
 
 /// Simple in-memory service registry implementation.
@@ -306,6 +659,7 @@ fn build_app_state(
 struct SimpleServiceRegistry {
     valid_keys: std::collections::HashMap<String, String>,
     credentials: std::collections::HashMap<String, String>,
+    certificate_identities: std::collections::HashMap<String, String>,
 }
 
 impl SimpleServiceRegistry {
@@ -318,6 +672,7 @@ impl SimpleServiceRegistry {
         Self { 
             valid_keys: key_map,
             credentials: std::collections::HashMap::new(),
+            certificate_identities: std::collections::HashMap::new(),
         }
     }
     
@@ -325,6 +680,11 @@ impl SimpleServiceRegistry {
     fn add_credentials(&mut self, service_id: &str, hashed_secret: &str) {
         self.credentials.insert(service_id.to_string(), hashed_secret.to_string());
     }
+
+    /// Add a client certificate identity (SPIFFE ID or subject) for a service
+    fn add_certificate_identity(&mut self, identity: &str, service_id: &str) {
+        self.certificate_identities.insert(identity.to_string(), service_id.to_string());
+    }
 }
 
 impl ServiceRegistry for SimpleServiceRegistry {
@@ -353,4 +713,8 @@ impl ServiceRegistry for SimpleServiceRegistry {
         }
         None
     }
+
+    fn service_for_certificate(&self, identity: &str) -> Option<String> {
+        self.certificate_identities.get(identity).cloned()
+    }
 }
@@ -1,4 +1,9 @@
+use std::sync::Arc;
+
+use zeroize::Zeroizing;
+
 use crate::core::error::CredentialError;
+use crate::core::usecases::ports::{BreachChecker, StrengthEstimator};
 
 /* 
  Policy describing credential validation rules.
@@ -7,6 +12,7 @@ use crate::core::error::CredentialError;
  and pure. Complex checks (real entropy estimation, external blacklists, or
  algorithmic checks) belong to adapters.
 */
+#[derive(Clone)]
 pub struct CredentialPolicy {
 	/// Minimum secret length in bytes.
 	pub min_length: usize,
@@ -19,6 +25,28 @@ pub struct CredentialPolicy {
 	/// the core.
 	pub format_check: Option<fn(&str) -> bool>,
 
+	/// Optional in-process breach check returning `true` for a known
+	/// compromised secret (e.g. a bundled list of common passwords). Checks
+	/// that need IO go through a [`BreachChecker`] instead.
+	pub breach_check: Option<fn(&str) -> bool>,
+
+	/// Minimum strength score (0-4, see [`StrengthEstimator`]) a secret must
+	/// reach. Only enforced when `strength_estimator` is set.
+	pub min_strength_score: u8,
+
+	/// Optional estimator scoring secrets for `min_strength_score`. Injected
+	/// so the core carries no estimation dictionaries itself.
+	pub strength_estimator: Option<Arc<dyn StrengthEstimator + Send + Sync>>,
+
+	/// Reject secrets containing the account identifier or, for an email,
+	/// its local-part. Only enforced by the identifier-aware validation.
+	pub forbid_identifier_in_password: bool,
+
+	/// Number of previous secrets a new one must differ from, besides the
+	/// current one. `0` disables the reuse check. Enforced by the password
+	/// change flows through `CredentialRepository::check_password_history`.
+	pub password_history_depth: usize,
+
 	/// Placeholder note describing entropy expectations. Not used for logic
 	/// inside core, only documentation/reporting.
 	pub entropy_note: Option<String>,
@@ -30,15 +58,176 @@ impl Default for CredentialPolicy {
 			min_length: 8,
 			require_complexity: true,
 			format_check: None,
+			breach_check: None,
+			min_strength_score: 0,
+			strength_estimator: None,
+			forbid_identifier_in_password: false,
+			password_history_depth: 0,
 			entropy_note: None,
 		}
 	}
 }
 
 impl CredentialPolicy {
+	/// Require secrets to reach `min_score` as scored by `estimator`.
+	pub fn with_strength_estimator(
+		mut self,
+		estimator: Arc<dyn StrengthEstimator + Send + Sync>,
+		min_score: u8,
+	) -> Self {
+		self.strength_estimator = Some(estimator);
+		self.min_strength_score = min_score;
+		self
+	}
+
+	/// Reject secrets that contain the account identifier.
+	pub fn with_forbid_identifier_in_password(mut self, forbid: bool) -> Self {
+		self.forbid_identifier_in_password = forbid;
+		self
+	}
+
+	/// Reject secrets matching the current one or any of the `depth`
+	/// before it.
+	pub fn with_password_history(mut self, depth: usize) -> Self {
+		self.password_history_depth = depth;
+		self
+	}
+
 	/// Validate a raw credential according to this policy. Returns a
 	/// `CredentialError` on failure.
 	pub fn validate_raw(&self, raw: &crate::core::credentials::RawCredential) -> Result<(), CredentialError> {
 		raw.validate(self)
 	}
+
+	/// Validate a raw credential, penalising secrets built from
+	/// `user_inputs` (e.g. the account's email or username) in the strength
+	/// check.
+	pub fn validate_raw_with_inputs(
+		&self,
+		raw: &crate::core::credentials::RawCredential,
+		user_inputs: &[&str],
+	) -> Result<(), CredentialError> {
+		raw.validate_with_inputs(self, user_inputs)
+	}
+
+	/// Validate a raw credential for the account it belongs to.
+	///
+	/// Applies every rule of [`validate_raw`](Self::validate_raw), passes the
+	/// identifier to the strength estimator, and enforces
+	/// `forbid_identifier_in_password`.
+	pub fn validate_raw_for_identifier(
+		&self,
+		raw: &crate::core::credentials::RawCredential,
+		identifier: &str,
+	) -> Result<(), CredentialError> {
+		self.check_identifier(raw.as_str(), identifier)?;
+		raw.validate_with_inputs(self, &[identifier])
+	}
+
+	/// Identifier check used by [`validate_raw_for_identifier`](Self::validate_raw_for_identifier).
+	///
+	/// Matches the whole identifier and, for an email, its local-part,
+	/// ignoring case. Fragments shorter than three characters are skipped so
+	/// short usernames do not forbid common substrings.
+	pub(crate) fn check_identifier(&self, secret: &str, identifier: &str) -> Result<(), CredentialError> {
+		if !self.forbid_identifier_in_password {
+			return Ok(());
+		}
+
+		// The lowercased copy is plaintext too; wipe it on return
+		let secret = Zeroizing::new(secret.to_lowercase());
+		let identifier = identifier.trim().to_lowercase();
+		let local_part = identifier.split('@').next().unwrap_or_default();
+
+		let contained = [identifier.as_str(), local_part]
+			.iter()
+			.filter(|part| part.chars().count() >= 3)
+			.any(|part| secret.contains(*part));
+
+		if contained {
+			return Err(CredentialError::contains_identifier());
+		}
+		Ok(())
+	}
+
+	/// Strength check shared by validation and `evaluate`.
+	pub(crate) fn check_strength(&self, secret: &str, user_inputs: &[&str]) -> Result<(), CredentialError> {
+		let Some(estimator) = &self.strength_estimator else {
+			return Ok(());
+		};
+
+		let score = estimator.score(secret, user_inputs);
+		if score < self.min_strength_score {
+			return Err(CredentialError::too_weak(score, self.min_strength_score));
+		}
+		Ok(())
+	}
+
+	/// Validate a raw credential for the account `identifier`, as
+	/// [`validate_raw_for_identifier`](Self::validate_raw_for_identifier)
+	/// does, and then ask `checker` whether it is known to be breached.
+	///
+	/// A checker failure (e.g. the breach service is unreachable) is logged
+	/// and the credential accepted: breach checking hardens the policy but
+	/// must not take password changes down with it.
+	pub async fn validate_raw_with_breach_checker(
+		&self,
+		raw: &crate::core::credentials::RawCredential,
+		identifier: &str,
+		checker: &(dyn BreachChecker + Send + Sync),
+	) -> Result<(), CredentialError> {
+		self.validate_raw_for_identifier(raw, identifier)?;
+
+		match checker.is_compromised(raw.as_str()).await {
+			Ok(true) => Err(CredentialError::compromised()),
+			Ok(false) => Ok(()),
+			Err(e) => {
+				tracing::warn!("[CredentialPolicy] Breach check unavailable, accepting credential: {}", e);
+				Ok(())
+			}
+		}
+	}
+
+	/// Dry-run the policy for the account `identifier` and report every
+	/// failed rule instead of stopping at the first one.
+	///
+	/// Applies the same rules as
+	/// [`validate_raw_for_identifier`](Self::validate_raw_for_identifier).
+	/// Used to give password-strength feedback (e.g. on a password change)
+	/// without storing anything. An empty result means the credential passes.
+	pub fn evaluate(&self, raw: &crate::core::credentials::RawCredential, identifier: &str) -> Vec<CredentialError> {
+		let mut violations = Vec::new();
+
+		if raw.as_str().is_empty() {
+			violations.push(CredentialError::missing_required("secret"));
+			return violations;
+		}
+
+		if let Err(contains_identifier) = self.check_identifier(raw.as_str(), identifier) {
+			violations.push(contains_identifier);
+		}
+
+		if raw.len() < self.min_length {
+			violations.push(CredentialError::insufficient_strength(format!("minimum length is {}", self.min_length)));
+		}
+
+		if let Some(check) = self.format_check
+			&& !check(raw.as_str())
+		{
+			violations.push(CredentialError::invalid_format("credential", "format check failed"));
+		}
+
+		if let Some(is_breached) = self.breach_check
+			&& is_breached(raw.as_str())
+		{
+			violations.push(CredentialError::compromised());
+		}
+
+		let user_inputs = if identifier.is_empty() { &[][..] } else { std::slice::from_ref(&identifier) };
+		if let Err(too_weak) = self.check_strength(raw.as_str(), user_inputs) {
+			violations.push(too_weak);
+		}
+
+		violations
+	}
 }
@@ -1,32 +1,38 @@
+use zeroize::Zeroizing;
+
 use crate::core::error::CredentialError;
 
 /*  
  Transient credential presented during an authentication attempt.
 
  This type intentionally does not implement `Clone` to avoid accidental
- copying of sensitive secret material. Callers may consume it (move) and
- provide the inner secret to a hashing/verification port.
+ copying of sensitive secret material. The secret lives in a zeroizing
+ buffer that is wiped when the credential is dropped, and `Debug` never
+ prints it. Validation and hashing borrow it through `as_str`, so no
+ plaintext copy outlives the credential.
 */
 pub struct RawCredential {
-	secret: String,
+	secret: Zeroizing<String>,
 }
 
 impl RawCredential {
 	/// Create a new `RawCredential` from a secret string.
+	///
+	/// A `String` argument is moved, not copied, into the zeroizing buffer.
 	pub fn new(secret: impl Into<String>) -> Self {
 		Self {
-			secret: secret.into(),
+			secret: Zeroizing::new(secret.into()),
 		}
 	}
 
-	/// Borrow the secret as `&str` for validation purposes.
+	/// Borrow the secret as `&str` for validation and hashing.
 	pub fn as_str(&self) -> &str {
 		&self.secret
 	}
 
-	/// Consume the credential and return the inner secret. Ownership is
-	/// transferred to the caller so core cannot accidentally persist it.
-	pub fn into_inner(self) -> String {
+	/// Consume the credential and return the inner secret, still in a
+	/// zeroizing buffer so it is wiped wherever the caller drops it.
+	pub fn into_inner(self) -> Zeroizing<String> {
 		self.secret
 	}
 
@@ -40,6 +46,16 @@ impl RawCredential {
 	/// Validation is pure and deterministic; it does not perform hashing or
 	/// any side effects. Failures map to `CredentialError`.
 	pub fn validate(&self, policy: &crate::core::credentials::CredentialPolicy) -> Result<(), CredentialError> {
+		self.validate_with_inputs(policy, &[])
+	}
+
+	/// Validate this credential against a policy, passing `user_inputs`
+	/// (identifier, email, ...) to the policy's strength estimator.
+	pub fn validate_with_inputs(
+		&self,
+		policy: &crate::core::credentials::CredentialPolicy,
+		user_inputs: &[&str],
+	) -> Result<(), CredentialError> {
 		// Required
 		if self.secret.is_empty() {
 			return Err(CredentialError::missing_required("secret"));
@@ -57,6 +73,16 @@ impl RawCredential {
 			}
 		}
 
+		// Optional in-process breach check
+		if let Some(is_breached) = policy.breach_check
+			&& is_breached(self.as_str())
+		{
+			return Err(CredentialError::compromised());
+		}
+
+		// Optional strength estimate (estimator supplied by policy)
+		policy.check_strength(self.as_str(), user_inputs)?;
+
 		// Entropy check is intentionally a placeholder: policy may contain a
 		// description/marker; actual entropy measurement belongs to adapters.
 		if let Some(_note) = &policy.entropy_note {
@@ -67,3 +93,11 @@ impl RawCredential {
 	}
 }
 
+impl std::fmt::Debug for RawCredential {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("RawCredential")
+			.field("secret", &"[REDACTED]")
+			.finish()
+	}
+}
+
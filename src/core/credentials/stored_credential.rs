@@ -1,3 +1,5 @@
+use super::CredentialStatus;
+
 /// Opaque representation of a persisted credential (hashed/encoded).
 
 /* 
@@ -10,6 +12,13 @@
 	repr: String,
 	pub failed_attempts: u32,
 	pub locked_until: Option<String>,
+	/// Lifecycle status loaded alongside the hash; `Active` unless the store
+	/// tracks validity windows.
+	pub status: CredentialStatus,
+	/// When the user last logged in successfully (RFC3339), if ever recorded.
+	pub last_login_at: Option<String>,
+	/// When the last failed login was counted (RFC3339), if recorded.
+	pub last_failed_at: Option<String>,
 }
 
 impl StoredCredential {
@@ -22,6 +31,9 @@ impl StoredCredential {
 			repr: hash.into(),
 			failed_attempts: 0,
 			locked_until: None,
+			status: CredentialStatus::Active,
+			last_login_at: None,
+			last_failed_at: None,
 		}
 	}
 
@@ -37,8 +49,29 @@ impl StoredCredential {
 			repr: hash.into(),
 			failed_attempts,
 			locked_until,
+			status: CredentialStatus::Active,
+			last_login_at: None,
+			last_failed_at: None,
 		}
 	}
+
+	/// Attach the credential's lifecycle status.
+	pub fn with_status(mut self, status: CredentialStatus) -> Self {
+		self.status = status;
+		self
+	}
+
+	/// Attach the time of the last successful login.
+	pub fn with_last_login_at(mut self, last_login_at: Option<String>) -> Self {
+		self.last_login_at = last_login_at;
+		self
+	}
+
+	/// Attach the time of the last counted failed login.
+	pub fn with_last_failed_at(mut self, last_failed_at: Option<String>) -> Self {
+		self.last_failed_at = last_failed_at;
+		self
+	}
 }
 
 impl std::fmt::Debug for StoredCredential {
@@ -6,3 +6,267 @@ fn credential_policy_defaults() {
     assert_eq!(p.min_length, 8);
     assert!(p.require_complexity);
 }
+
+#[test]
+fn credential_policy_evaluate_reports_all_failed_rules() {
+    use crate::core::credentials::RawCredential;
+
+    fn has_digit(s: &str) -> bool {
+        s.chars().any(|c| c.is_ascii_digit())
+    }
+
+    let p = CredentialPolicy {
+        format_check: Some(has_digit),
+        ..CredentialPolicy::default()
+    };
+
+    let violations = p.evaluate(&RawCredential::new("short"), "");
+    assert_eq!(violations.len(), 2);
+
+    assert!(p.evaluate(&RawCredential::new("long-enough-1"), "").is_empty());
+    assert_eq!(p.evaluate(&RawCredential::new(""), "").len(), 1);
+}
+
+fn is_breached(s: &str) -> bool {
+    s == "password123"
+}
+
+#[test]
+fn credential_policy_breach_check_rejects_flagged_secret() {
+    use crate::core::credentials::RawCredential;
+    use crate::core::error::CredentialError;
+
+    let p = CredentialPolicy {
+        breach_check: Some(is_breached),
+        ..CredentialPolicy::default()
+    };
+
+    assert_eq!(
+        p.validate_raw(&RawCredential::new("password123")),
+        Err(CredentialError::compromised())
+    );
+    assert!(p.validate_raw(&RawCredential::new("password124")).is_ok());
+    assert_eq!(
+        p.evaluate(&RawCredential::new("password123"), ""),
+        vec![CredentialError::compromised()]
+    );
+}
+
+mod breach_checker {
+    use super::*;
+    use crate::core::credentials::RawCredential;
+    use crate::core::error::CredentialError;
+    use crate::core::usecases::ports::BreachChecker;
+    use futures::future::BoxFuture;
+
+    /// Checker that flags one secret, or fails every lookup
+    struct MockBreachChecker {
+        unavailable: bool,
+    }
+
+    impl BreachChecker for MockBreachChecker {
+        fn is_compromised<'a>(&'a self, secret: &'a str) -> BoxFuture<'a, Result<bool, String>> {
+            let result = if self.unavailable {
+                Err("service unavailable".to_string())
+            } else {
+                Ok(is_breached(secret))
+            };
+            Box::pin(async move { result })
+        }
+    }
+
+    #[tokio::test]
+    async fn flags_breached_secret() {
+        let p = CredentialPolicy::default();
+        let checker = MockBreachChecker { unavailable: false };
+
+        let result = p
+            .validate_raw_with_breach_checker(&RawCredential::new("password123"), "", &checker)
+            .await;
+
+        assert_eq!(result, Err(CredentialError::compromised()));
+    }
+
+    #[tokio::test]
+    async fn accepts_unbreached_secret() {
+        let p = CredentialPolicy::default();
+        let checker = MockBreachChecker { unavailable: false };
+
+        let result = p
+            .validate_raw_with_breach_checker(&RawCredential::new("correct horse"), "", &checker)
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn policy_violations_take_precedence() {
+        let p = CredentialPolicy::default();
+        let checker = MockBreachChecker { unavailable: false };
+
+        let result = p
+            .validate_raw_with_breach_checker(&RawCredential::new("short"), "", &checker)
+            .await;
+
+        assert!(matches!(result, Err(CredentialError::InsufficientStrength { .. })));
+    }
+
+    #[tokio::test]
+    async fn unavailable_checker_fails_open() {
+        let p = CredentialPolicy::default();
+        let checker = MockBreachChecker { unavailable: true };
+
+        let result = p
+            .validate_raw_with_breach_checker(&RawCredential::new("password123"), "", &checker)
+            .await;
+
+        assert!(result.is_ok());
+    }
+}
+
+mod strength_estimator {
+    use super::*;
+    use crate::core::credentials::RawCredential;
+    use crate::core::error::CredentialError;
+    use crate::core::usecases::ports::StrengthEstimator;
+    use std::sync::Arc;
+
+    /// Scores keyboard walks and anything containing a user input as 0,
+    /// everything else as 4
+    struct MockStrengthEstimator;
+
+    impl StrengthEstimator for MockStrengthEstimator {
+        fn score(&self, secret: &str, user_inputs: &[&str]) -> u8 {
+            let lowered = secret.to_lowercase();
+            if lowered.starts_with("qwerty") || user_inputs.iter().any(|input| lowered.contains(input)) {
+                0
+            } else {
+                4
+            }
+        }
+    }
+
+    fn policy() -> CredentialPolicy {
+        CredentialPolicy::default().with_strength_estimator(Arc::new(MockStrengthEstimator), 3)
+    }
+
+    #[test]
+    fn strong_passphrase_passes() {
+        assert!(policy().validate_raw(&RawCredential::new("orbit lantern quasar")).is_ok());
+    }
+
+    #[test]
+    fn keyboard_walk_fails_with_score() {
+        assert_eq!(
+            policy().validate_raw(&RawCredential::new("qwertyuiop")),
+            Err(CredentialError::too_weak(0, 3))
+        );
+        assert_eq!(
+            policy().evaluate(&RawCredential::new("qwertyuiop"), ""),
+            vec![CredentialError::too_weak(0, 3)]
+        );
+    }
+
+    #[test]
+    fn user_inputs_reach_estimator() {
+        let raw = RawCredential::new("janedoe-lantern");
+
+        assert!(policy().validate_raw(&raw).is_ok());
+        assert_eq!(
+            policy().validate_raw_with_inputs(&raw, &["janedoe"]),
+            Err(CredentialError::too_weak(0, 3))
+        );
+        assert_eq!(policy().evaluate(&raw, "janedoe"), vec![CredentialError::too_weak(0, 3)]);
+    }
+
+    #[test]
+    fn no_estimator_skips_scoring() {
+        let p = CredentialPolicy {
+            min_strength_score: 4,
+            ..CredentialPolicy::default()
+        };
+
+        assert!(p.validate_raw(&RawCredential::new("aaaaaaaaaaaa")).is_ok());
+    }
+}
+
+mod identifier_in_password {
+    use super::*;
+    use crate::core::credentials::RawCredential;
+    use crate::core::error::CredentialError;
+
+    fn policy() -> CredentialPolicy {
+        CredentialPolicy::default().with_forbid_identifier_in_password(true)
+    }
+
+    #[test]
+    fn rejects_password_containing_email_local_part() {
+        let result = policy().validate_raw_for_identifier(
+            &RawCredential::new("JaneDoe-2024!"),
+            "janedoe@example.com",
+        );
+
+        assert_eq!(result, Err(CredentialError::contains_identifier()));
+    }
+
+    #[test]
+    fn rejects_password_containing_whole_identifier() {
+        let result = policy().validate_raw_for_identifier(
+            &RawCredential::new("xx-operator7-xx"),
+            "operator7",
+        );
+
+        assert_eq!(result, Err(CredentialError::contains_identifier()));
+    }
+
+    #[test]
+    fn accepts_unrelated_password() {
+        let result = policy().validate_raw_for_identifier(
+            &RawCredential::new("correct horse battery"),
+            "janedoe@example.com",
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn ignores_very_short_identifiers() {
+        let result = policy().validate_raw_for_identifier(&RawCredential::new("joking-around-9"), "jo");
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let result = CredentialPolicy::default().validate_raw_for_identifier(
+            &RawCredential::new("JaneDoe-2024!"),
+            "janedoe@example.com",
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn still_applies_the_other_rules() {
+        let result = policy().validate_raw_for_identifier(&RawCredential::new("short"), "janedoe@example.com");
+
+        assert!(matches!(result, Err(CredentialError::InsufficientStrength { .. })));
+    }
+
+    #[test]
+    fn evaluate_reports_identifier_with_other_violations() {
+        let p = CredentialPolicy {
+            breach_check: Some(|s| s == "jane"),
+            ..policy()
+        };
+
+        assert_eq!(
+            p.evaluate(&RawCredential::new("jane"), "jane@example.com"),
+            vec![
+                CredentialError::contains_identifier(),
+                CredentialError::insufficient_strength("minimum length is 8"),
+                CredentialError::compromised(),
+            ]
+        );
+    }
+}
@@ -39,7 +39,7 @@ fn raw_credential_format_check() {
 fn raw_into_inner_consumes() {
 	let raw = RawCredential::new("secret123");
 	let inner = raw.into_inner();
-	assert_eq!(inner, "secret123");
+	assert_eq!(inner.as_str(), "secret123");
 }
 
 #[test]
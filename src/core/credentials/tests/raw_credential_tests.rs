@@ -33,5 +33,26 @@ fn raw_credential_format_check() {
 fn raw_into_inner_consumes() {
     let raw = RawCredential::new("secret123");
     let inner = raw.into_inner();
-    assert_eq!(inner, "secret123");
+    assert_eq!(inner.as_str(), "secret123");
+}
+
+#[test]
+fn raw_debug_is_redacted() {
+    let raw = RawCredential::new("hunter2-secret");
+    let printed = format!("{:?}", raw);
+    assert!(!printed.contains("hunter2-secret"));
+    assert!(printed.contains("[REDACTED]"));
+}
+
+#[test]
+fn raw_is_not_clone() {
+    // Resolves only while exactly one impl applies, i.e. while
+    // `RawCredential` does not implement `Clone`
+    trait AmbiguousIfClone<A> {
+        fn check() {}
+    }
+    impl<T: ?Sized> AmbiguousIfClone<()> for T {}
+    impl<T: Clone> AmbiguousIfClone<u8> for T {}
+
+    <RawCredential as AmbiguousIfClone<_>>::check();
 }
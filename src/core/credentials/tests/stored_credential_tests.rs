@@ -1,4 +1,4 @@
-use crate::core::credentials::StoredCredential;
+use crate::core::credentials::{CredentialStatus, StoredCredential};
 
 #[test]
 fn stored_credential_inspectors() {
@@ -7,3 +7,13 @@ fn stored_credential_inspectors() {
     assert_eq!(s.repr_len(), "hashed-value-abc".len());
     assert_eq!(format!("{:?}", s), "StoredCredential([REDACTED])");
 }
+
+#[test]
+fn stored_credential_status_defaults_to_active() {
+    let s = StoredCredential::from_parts("hashed-value-abc", 0, None);
+    assert_eq!(s.status, CredentialStatus::Active);
+
+    let s = s.with_status(CredentialStatus::Expired { expired_at: None });
+    assert_eq!(s.status, CredentialStatus::Expired { expired_at: None });
+    assert_eq!(format!("{:?}", s), "StoredCredential([REDACTED])");
+}
@@ -26,6 +26,8 @@ pub enum AuthenticationError {
     /// User account is locked or disabled
     AccountLocked {
         reason: String,
+        /// RFC3339 time the lock lifts, when it is temporary
+        locked_until: Option<String>,
     },
     /// External identity provider rejected the authentication
     ExternalProviderRejected {
@@ -42,8 +44,16 @@ pub enum AuthenticationError {
     },
     /// Invalid credentials provided
     InvalidCredentials,
+    /// Credential is outside its validity window (expired, not yet valid or revoked)
+    CredentialExpired {
+        reason: String,
+    },
     /// Service is not active or not authorized
     ServiceNotActive,
+    /// User is not a member of the requested workspace
+    NotWorkspaceMember {
+        workspace_id: String,
+    },
 }
 
 impl AuthenticationError {
@@ -77,6 +87,15 @@ impl AuthenticationError {
     pub fn account_locked(reason: impl Into<String>) -> Self {
         Self::AccountLocked {
             reason: reason.into(),
+            locked_until: None,
+        }
+    }
+
+    /// Create an AccountLocked error for a lock that lifts at `locked_until` (RFC3339)
+    pub fn account_locked_until(reason: impl Into<String>, locked_until: impl Into<String>) -> Self {
+        Self::AccountLocked {
+            reason: reason.into(),
+            locked_until: Some(locked_until.into()),
         }
     }
 
@@ -94,16 +113,48 @@ impl AuthenticationError {
         }
     }
 
+    /// Create a CredentialExpired error with the given reason
+    pub fn credential_expired(reason: impl Into<String>) -> Self {
+        Self::CredentialExpired {
+            reason: reason.into(),
+        }
+    }
+
+    /// Create a NotWorkspaceMember error for the given workspace
+    pub fn not_workspace_member(workspace_id: impl Into<String>) -> Self {
+        Self::NotWorkspaceMember {
+            workspace_id: workspace_id.into(),
+        }
+    }
+
     /// Returns true if this error is an AccountLocked variant
     pub fn is_account_locked(&self) -> bool {
         matches!(self, Self::AccountLocked { .. })
     }
 
+    /// When a temporary lock lifts (RFC3339), if this is one
+    pub fn locked_until(&self) -> Option<&str> {
+        match self {
+            Self::AccountLocked { locked_until, .. } => locked_until.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Returns true if this error is an InvalidCredentials variant
     pub fn is_invalid_credentials(&self) -> bool {
         matches!(self, Self::InvalidCredentials)
     }
 
+    /// Returns true if this error is a CredentialExpired variant
+    pub fn is_credential_expired(&self) -> bool {
+        matches!(self, Self::CredentialExpired { .. })
+    }
+
+    /// Returns true if this error is a NotWorkspaceMember variant
+    pub fn is_not_workspace_member(&self) -> bool {
+        matches!(self, Self::NotWorkspaceMember { .. })
+    }
+
     /// Returns true if this error is a ServiceNotActive variant
     pub fn is_service_not_active(&self) -> bool {
         matches!(self, Self::ServiceNotActive)
@@ -135,7 +186,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Self::IncompleteFlow { stage } => {
                 write!(f, "Authentication flow incomplete at stage: {}", stage)
             }
-            Self::AccountLocked { reason } => {
+            Self::AccountLocked { reason, .. } => {
                 write!(f, "Account is locked: {}", reason)
             }
             Self::ExternalProviderRejected { provider, reason } => {
@@ -154,9 +205,15 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Self::InvalidCredentials => {
                 write!(f, "Invalid credentials provided")
             }
+            Self::CredentialExpired { reason } => {
+                write!(f, "Credential cannot be used: {}", reason)
+            }
             Self::ServiceNotActive => {
                 write!(f, "Service is not active or not authorized")
             }
+            Self::NotWorkspaceMember { workspace_id } => {
+                write!(f, "User is not a member of workspace: {}", workspace_id)
+            }
         }
     }
 }
@@ -41,6 +41,21 @@ pub enum CredentialError {
     InsufficientStrength {
         reason: String,
     },
+    /// Credential is known to appear in a public data breach
+    Compromised,
+    /// Estimated credential strength is below the required score
+    TooWeak {
+        score: u8,
+        required: u8,
+    },
+    /// Credential contains the account identifier (e.g. the email local-part)
+    ContainsIdentifier,
+    /// Credential matches one the account used recently
+    Reused,
+    /// Credential fails several policy rules at once, in check order
+    PolicyViolations {
+        violations: Vec<CredentialError>,
+    },
 }
 
 impl CredentialError {
@@ -101,6 +116,47 @@ impl CredentialError {
             reason: reason.into(),
         }
     }
+
+    /// Create a Compromised error
+    pub fn compromised() -> Self {
+        Self::Compromised
+    }
+
+    /// Create a TooWeak error
+    pub fn too_weak(score: u8, required: u8) -> Self {
+        Self::TooWeak { score, required }
+    }
+
+    /// Create a ContainsIdentifier error
+    pub fn contains_identifier() -> Self {
+        Self::ContainsIdentifier
+    }
+
+    /// Create a Reused error
+    pub fn reused() -> Self {
+        Self::Reused
+    }
+
+    /// Combine the rules a credential failed into one error.
+    ///
+    /// A single violation is returned as is; several are wrapped in
+    /// `PolicyViolations`. Returns `None` when nothing failed.
+    pub fn from_violations(mut violations: Vec<CredentialError>) -> Option<Self> {
+        match violations.len() {
+            0 => None,
+            1 => violations.pop(),
+            _ => Some(Self::PolicyViolations { violations }),
+        }
+    }
+
+    /// Every rule this error reports: the wrapped list for
+    /// `PolicyViolations`, otherwise just this error.
+    pub fn violations(&self) -> &[CredentialError] {
+        match self {
+            Self::PolicyViolations { violations } => violations,
+            other => std::slice::from_ref(other),
+        }
+    }
 }
 
 impl std::fmt::Display for CredentialError {
@@ -127,6 +183,16 @@ impl std::fmt::Display for CredentialError {
             Self::InsufficientStrength { reason } => {
                 write!(f, "Credential strength insufficient: {}", reason)
             }
+            Self::Compromised => write!(f, "Credential appears in a known data breach"),
+            Self::TooWeak { score, required } => {
+                write!(f, "Credential too weak: strength score {} below required {}", score, required)
+            }
+            Self::ContainsIdentifier => write!(f, "Credential must not contain the account identifier"),
+            Self::Reused => write!(f, "Credential was used recently and cannot be reused"),
+            Self::PolicyViolations { violations } => {
+                let reasons: Vec<String> = violations.iter().map(ToString::to_string).collect();
+                write!(f, "Credential violates policy: {}", reasons.join("; "))
+            }
         }
     }
 }
@@ -78,11 +78,22 @@ fn test_account_locked() {
     assert_eq!(
         err,
         AuthenticationError::AccountLocked {
-            reason: "too many failed attempts".to_string()
+            reason: "too many failed attempts".to_string(),
+            locked_until: None,
         }
     );
 }
 
+#[test]
+fn test_account_locked_until() {
+    let err = AuthenticationError::account_locked_until("too many failed attempts", "2025-03-01T12:00:00+00:00");
+
+    assert!(err.is_account_locked());
+    assert_eq!(err.locked_until(), Some("2025-03-01T12:00:00+00:00"));
+    assert_eq!(AuthenticationError::account_locked("disabled").locked_until(), None);
+    assert_eq!(AuthenticationError::InvalidCredentials.locked_until(), None);
+}
+
 #[test]
 fn test_account_locked_display() {
     let err = AuthenticationError::account_locked("suspicious activity detected");
@@ -185,3 +196,26 @@ fn test_is_service_not_active_false() {
     let err = AuthenticationError::user_not_found("test");
     assert!(!err.is_service_not_active());
 }
+
+#[test]
+fn test_credential_expired_display() {
+    let err = AuthenticationError::credential_expired("Credential expired at: 2024-01-01T00:00:00Z");
+    assert_eq!(
+        err.to_string(),
+        "Credential cannot be used: Credential expired at: 2024-01-01T00:00:00Z"
+    );
+}
+
+#[test]
+fn test_is_credential_expired() {
+    assert!(AuthenticationError::credential_expired("expired").is_credential_expired());
+    assert!(!AuthenticationError::InvalidCredentials.is_credential_expired());
+}
+
+#[test]
+fn test_not_workspace_member_display() {
+    let err = AuthenticationError::not_workspace_member("ws-1");
+    assert_eq!(err.to_string(), "User is not a member of workspace: ws-1");
+    assert!(err.is_not_workspace_member());
+    assert!(!AuthenticationError::InvalidCredentials.is_not_workspace_member());
+}
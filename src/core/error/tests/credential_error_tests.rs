@@ -176,3 +176,44 @@ fn test_credential_error_clone() {
     let cloned = err.clone();
     assert_eq!(err, cloned);
 }
+
+#[test]
+fn test_compromised_display() {
+    let err = CredentialError::compromised();
+    assert_eq!(err.to_string(), "Credential appears in a known data breach");
+}
+
+#[test]
+fn test_too_weak_display() {
+    let err = CredentialError::too_weak(1, 3);
+    assert_eq!(err.to_string(), "Credential too weak: strength score 1 below required 3");
+}
+
+#[test]
+fn test_contains_identifier_display() {
+    let err = CredentialError::contains_identifier();
+    assert_eq!(err.to_string(), "Credential must not contain the account identifier");
+}
+
+#[test]
+fn test_reused_display() {
+    let err = CredentialError::reused();
+    assert_eq!(err.to_string(), "Credential was used recently and cannot be reused");
+}
+
+#[test]
+fn test_from_violations_wraps_only_several() {
+    assert_eq!(CredentialError::from_violations(Vec::new()), None);
+    assert_eq!(
+        CredentialError::from_violations(vec![CredentialError::reused()]),
+        Some(CredentialError::reused())
+    );
+
+    let err = CredentialError::from_violations(vec![CredentialError::contains_identifier(), CredentialError::compromised()])
+        .unwrap();
+    assert_eq!(err.violations(), [CredentialError::contains_identifier(), CredentialError::compromised()]);
+    assert_eq!(
+        err.to_string(),
+        "Credential violates policy: Credential must not contain the account identifier; Credential appears in a known data breach"
+    );
+}
@@ -1,12 +1,13 @@
 use crate::core::error::InvariantError;
 use std::fmt;
 
-use super::{UserIdentity, IdentityClaims};
+use super::{UserIdentity, IdentityClaims, WorkspaceIdentity};
 
-/// Composition of a user identity (workspace removed).
+/// Composition of a user identity and, optionally, the workspace it acts in.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ContextualIdentity {
     pub user: Option<UserIdentity>,
+    pub workspace: Option<WorkspaceIdentity>,
 }
 
 impl ContextualIdentity {
@@ -21,13 +22,20 @@ impl ContextualIdentity {
                 "ContextualIdentity requires a user",
             ));
         }
-        Ok(Self { user })
+        Ok(Self { user, workspace: None })
+    }
+
+    /// Scope the identity to a workspace.
+    pub fn with_workspace(mut self, workspace: WorkspaceIdentity) -> Self {
+        self.workspace = Some(workspace);
+        self
     }
 
     /// Project into token-safe claims.
     pub fn to_claims(&self) -> IdentityClaims {
         IdentityClaims {
             user_id: self.user.as_ref().map(|u| u.to_claims_id()),
+            workspace_id: self.workspace.as_ref().map(|w| w.to_claims_id()),
         }
     }
 
@@ -40,13 +48,22 @@ impl ContextualIdentity {
     pub fn user_id(&self) -> Option<&str> {
         self.user.as_ref().map(|u| u.id())
     }
+
+    /// Returns the workspace identifier if the identity is workspace-scoped.
+    pub fn workspace_id(&self) -> Option<&str> {
+        self.workspace.as_ref().map(|w| w.id())
+    }
 }
 
 impl fmt::Display for ContextualIdentity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.user {
-            Some(u) => write!(f, "{}", u),
-            None => write!(f, "<anonymous>"),
+            Some(u) => write!(f, "{}", u)?,
+            None => write!(f, "<anonymous>")?,
+        }
+        match &self.workspace {
+            Some(w) => write!(f, " in {}", w),
+            None => Ok(()),
         }
     }
 }
@@ -3,11 +3,14 @@
 pub struct IdentityClaims {
     /// Optional user identifier suitable for embedding in claims
     pub user_id: Option<String>,
+    /// Optional workspace the identity is scoped to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<String>,
 }
 
 impl IdentityClaims {
     /// True if there is no identity information present
     pub fn is_empty(&self) -> bool {
-        self.user_id.is_none()
+        self.user_id.is_none() && self.workspace_id.is_none()
     }
 }
@@ -1,4 +1,4 @@
-use crate::core::identity::{ContextualIdentity, UserIdentity};
+use crate::core::identity::{ContextualIdentity, UserIdentity, WorkspaceIdentity};
 use crate::core::error::InvariantError;
 
 #[test]
@@ -39,3 +39,24 @@ fn contextual_from_user() {
     assert_eq!(ctx.user_id(), Some("bob"));
 }
 
+
+#[test]
+fn contextual_with_workspace_claims() {
+    let ctx = ContextualIdentity::from(UserIdentity::new("alice"))
+        .with_workspace(WorkspaceIdentity::new("ws-1"));
+
+    assert_eq!(ctx.workspace_id(), Some("ws-1"));
+    let claims = ctx.to_claims();
+    assert_eq!(claims.user_id, Some("alice".to_string()));
+    assert_eq!(claims.workspace_id, Some("ws-1".to_string()));
+    assert_eq!(ctx.to_string(), "UserIdentity(alice) in WorkspaceIdentity(ws-1)");
+}
+
+#[test]
+fn contextual_without_workspace_omits_it_from_claims() {
+    let ctx = ContextualIdentity::from(UserIdentity::new("alice"));
+
+    assert_eq!(ctx.workspace_id(), None);
+    let json = serde_json::to_string(&ctx.to_claims()).unwrap();
+    assert!(!json.contains("workspace_id"));
+}
@@ -1,6 +1,7 @@
 //! Tests for the core identity module
 
 mod user_identity_tests;
+mod workspace_identity_tests;
 mod contextual_identity_tests;
 mod identity_claims_tests;
 mod external_identity_tests;
\ No newline at end of file
@@ -0,0 +1,33 @@
+use std::fmt;
+
+/// Opaque workspace identity value.
+///
+/// Scopes an authenticated user to one workspace. Membership is owned by
+/// the identity store; this value only names the workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceIdentity {
+    pub id: String,
+}
+
+impl WorkspaceIdentity {
+    /// Construct a new `WorkspaceIdentity` from any string-like id.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+
+    /// Returns the internal identifier.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Produce a claims-safe `String` representation.
+    pub fn to_claims_id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl fmt::Display for WorkspaceIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WorkspaceIdentity({})", self.id)
+    }
+}
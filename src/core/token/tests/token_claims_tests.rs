@@ -174,3 +174,25 @@ fn token_claims_with_only_user() {
 
     assert!(claims.has_identity());
 }
+
+#[test]
+fn token_claims_from_lifetime_copies_temporal_bounds() {
+    use crate::core::token::TokenLifetime;
+
+    let lifetime = TokenLifetime::from_timestamps(1772712911, 1772716511, Some(1772712911));
+    let claims = TokenClaims::from_lifetime("user".to_string(), &lifetime, "access".to_string()).unwrap();
+
+    assert_eq!(claims.iat, 1772712911);
+    assert_eq!(claims.exp, 1772716511);
+    assert_eq!(claims.nbf, Some(1772712911));
+    assert_eq!(claims.lifetime(), lifetime);
+}
+
+#[test]
+fn token_claims_from_lifetime_rejects_empty_window() {
+    use crate::core::token::TokenLifetime;
+
+    let lifetime = TokenLifetime::from_timestamps(1772712911, 1772712911, None);
+
+    assert!(TokenClaims::from_lifetime("user".to_string(), &lifetime, "access".to_string()).is_err());
+}
@@ -165,3 +165,73 @@ fn token_lifetime_rfc3339_string_comparison() {
     assert!(lifetime.is_expired("2026-02-12T11:00:00Z"));
     assert!(lifetime.is_expired("2026-02-12T11:00:01Z"));
 }
+
+#[test]
+fn token_lifetime_from_ttl_spans_ttl() {
+    let issued_at = chrono::DateTime::parse_from_rfc3339("2026-02-12T10:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+
+    let lifetime = TokenLifetime::from_ttl(issued_at, chrono::Duration::minutes(15)).unwrap();
+
+    assert_eq!(lifetime.issued_at, "2026-02-12T10:00:00Z");
+    assert_eq!(lifetime.expires_at, "2026-02-12T10:15:00Z");
+    assert_eq!(lifetime.expires_at_timestamp(), Some(issued_at.timestamp() + 900));
+}
+
+#[test]
+fn token_lifetime_from_ttl_rejects_zero_and_negative_ttl() {
+    let issued_at = chrono::Utc::now();
+
+    assert!(TokenLifetime::from_ttl(issued_at, chrono::Duration::zero()).is_err());
+    assert!(TokenLifetime::from_ttl(issued_at, chrono::Duration::seconds(-1)).is_err());
+}
+
+#[test]
+fn token_lifetime_validate_reports_failure_kind() {
+    use crate::core::token::TokenValidationFailure;
+
+    let lifetime = TokenLifetime::new("2026-02-12T10:00:00Z", "2026-02-12T11:00:00Z")
+        .with_not_before("2026-02-12T10:30:00Z");
+
+    assert_eq!(
+        lifetime.validate("2026-02-12T10:15:00Z"),
+        Err(TokenValidationFailure::not_yet_valid("2026-02-12T10:30:00Z"))
+    );
+    assert_eq!(lifetime.validate("2026-02-12T10:45:00Z"), Ok(()));
+    assert_eq!(
+        lifetime.validate("2026-02-12T11:00:00Z"),
+        Err(TokenValidationFailure::expired("2026-02-12T11:00:00Z"))
+    );
+}
+
+#[test]
+fn token_lifetime_from_timestamps_round_trips() {
+    let lifetime = TokenLifetime::from_timestamps(1772712911, 1772716511, Some(1772712911));
+
+    assert_eq!(lifetime.issued_at_timestamp(), Some(1772712911));
+    assert_eq!(lifetime.expires_at_timestamp(), Some(1772716511));
+    assert_eq!(lifetime.not_before_timestamp(), Some(1772712911));
+}
+
+#[test]
+fn token_lifetime_leeway_is_symmetric() {
+    use chrono::{Duration, TimeZone, Utc};
+
+    let lifetime = TokenLifetime::new("2026-02-12T10:00:00Z", "2026-02-12T11:00:00Z");
+    let leeway = Duration::seconds(30);
+
+    // 10s after expiry
+    let late = Utc.with_ymd_and_hms(2026, 2, 12, 11, 0, 10).unwrap();
+    assert!(lifetime.validate_with_leeway(late, leeway).is_ok());
+    assert!(lifetime.validate_with_leeway(late, Duration::zero()).is_err());
+
+    // 10s before issuance
+    let early = Utc.with_ymd_and_hms(2026, 2, 12, 9, 59, 50).unwrap();
+    assert!(lifetime.validate_with_leeway(early, leeway).is_ok());
+    assert!(lifetime.validate_with_leeway(early, Duration::zero()).is_err());
+
+    // Beyond the leeway on either side
+    assert!(lifetime.validate_with_leeway(late + Duration::seconds(30), leeway).is_err());
+    assert!(lifetime.validate_with_leeway(early - Duration::seconds(30), leeway).is_err());
+}
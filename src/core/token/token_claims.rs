@@ -1,3 +1,6 @@
+use crate::core::error::TokenError;
+use crate::core::token::TokenLifetime;
+
 /// Token claims representing identity context and temporal bounds.
 ///
 /// `TokenClaims` is a data-only type that projects identity information
@@ -24,7 +27,6 @@
 ///   "scope": ["read", "write"]
 /// }
 /// ```
-
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct TokenClaims {
     /// Subject (user identifier) - maps to JWT "sub" claim
@@ -52,6 +54,14 @@ pub struct TokenClaims {
 
     /// Token type: "access", "refresh", or "service" - maps to JWT "token_type" claim
     pub token_type: String,
+
+    /// Unique token identifier - maps to JWT "jti" claim
+    #[serde(default)]
+    pub jti: Option<String>,
+
+    /// Workspace the subject acts in - maps to the "workspace_id" claim
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<String>,
 }
 
 impl TokenClaims {
@@ -71,9 +81,39 @@ impl TokenClaims {
             nbf: None,
             scope: vec![],
             token_type,
+            jti: None,
+            workspace_id: None,
         }
     }
 
+    /// Create a `TokenClaims` whose `iat`, `exp` and `nbf` come from a lifetime.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenError::InvalidClaims` if the lifetime's timestamps are not
+    /// valid RFC3339 or do not satisfy `exp > iat`.
+    pub fn from_lifetime(
+        sub: String,
+        lifetime: &TokenLifetime,
+        token_type: String,
+    ) -> Result<Self, TokenError> {
+        let (iat, exp) = match (lifetime.issued_at_timestamp(), lifetime.expires_at_timestamp()) {
+            (Some(iat), Some(exp)) if exp > iat => (iat, exp),
+            _ => return Err(TokenError::invalid_claims("token lifetime must end after it starts")),
+        };
+
+        let claims = Self::new(sub, iat, exp, token_type);
+        Ok(match lifetime.not_before_timestamp() {
+            Some(nbf) => claims.with_not_before(nbf),
+            None => claims,
+        })
+    }
+
+    /// The temporal bounds of these claims, for validation in the core.
+    pub fn lifetime(&self) -> TokenLifetime {
+        TokenLifetime::from_timestamps(self.iat, self.exp, self.nbf)
+    }
+
     /// Set session ID for revocation tracking.
     pub fn with_sid(mut self, sid: impl Into<String>) -> Self {
         self.sid = Some(sid.into());
@@ -92,6 +132,18 @@ impl TokenClaims {
         self
     }
 
+    /// Set unique token identifier.
+    pub fn with_jti(mut self, jti: impl Into<String>) -> Self {
+        self.jti = Some(jti.into());
+        self
+    }
+
+    /// Scope the subject to a workspace.
+    pub fn with_workspace_id(mut self, workspace_id: impl Into<String>) -> Self {
+        self.workspace_id = Some(workspace_id.into());
+        self
+    }
+
     /// Set scopes/permissions.
     pub fn with_scopes(mut self, scopes: Vec<String>) -> Self {
         self.scope = scopes;
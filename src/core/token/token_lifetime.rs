@@ -22,6 +22,11 @@
 /// - **Temporal-only**: Contains only time-based validation rules
 /// - **Immutable**: Cannot be modified after construction
 
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+
+use crate::core::error::TokenError;
+use crate::core::token::{TokenValidationFailure, TokenValidationResult};
+
 /// Represents the temporal bounds and validity window of a token.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TokenLifetime {
@@ -49,6 +54,41 @@ impl TokenLifetime {
         }
     }
 
+    /// Create a `TokenLifetime` spanning `ttl` from `issued_at`.
+    ///
+    /// This is how issuance derives expiry from policy: the TTL must be
+    /// positive, so `expires_at` is always strictly after `issued_at`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenError::InvalidClaims` if `ttl` is zero or negative.
+    pub fn from_ttl(issued_at: DateTime<Utc>, ttl: Duration) -> Result<Self, TokenError> {
+        if ttl <= Duration::zero() {
+            return Err(TokenError::invalid_claims(format!(
+                "token TTL must be positive, got {}s",
+                ttl.num_seconds()
+            )));
+        }
+
+        let expires_at = issued_at
+            .checked_add_signed(ttl)
+            .ok_or_else(|| TokenError::invalid_claims("token TTL is out of range"))?;
+
+        Ok(Self::new(rfc3339(issued_at), rfc3339(expires_at)))
+    }
+
+    /// Rebuild a `TokenLifetime` from Unix timestamps carried in a token.
+    ///
+    /// Timestamps outside chrono's range collapse to the epoch, which makes
+    /// the token read as expired rather than valid.
+    pub fn from_timestamps(issued_at: i64, expires_at: i64, not_before: Option<i64>) -> Self {
+        let lifetime = Self::new(rfc3339_from_timestamp(issued_at), rfc3339_from_timestamp(expires_at));
+        match not_before {
+            Some(nbf) => lifetime.with_not_before(rfc3339_from_timestamp(nbf)),
+            None => lifetime,
+        }
+    }
+
     /// Set an optional "not before" time.
     pub fn with_not_before(mut self, not_before: impl Into<String>) -> Self {
         self.not_before = Some(not_before.into());
@@ -126,6 +166,67 @@ impl TokenLifetime {
         !self.is_expired(reference_time) && !self.is_not_yet_valid(reference_time)
     }
 
+    /// Validate the lifetime at a reference time.
+    ///
+    /// This is the single place that decides expiry and not-yet-valid;
+    /// adapters decode timestamps and defer the decision here.
+    ///
+    /// # Errors
+    ///
+    /// - `TokenValidationFailure::NotYetValid` before `valid_from()`
+    /// - `TokenValidationFailure::Expired` at or after `expires_at`
+    pub fn validate(&self, reference_time: &str) -> TokenValidationResult {
+        if self.is_not_yet_valid(reference_time) {
+            return Err(TokenValidationFailure::not_yet_valid(self.valid_from()));
+        }
+
+        if self.is_expired(reference_time) {
+            return Err(TokenValidationFailure::expired(self.expires_at.clone()));
+        }
+
+        Ok(())
+    }
+
+    /// Validate the lifetime at an instant, typically `Clock::now()`.
+    pub fn validate_at(&self, instant: DateTime<Utc>) -> TokenValidationResult {
+        self.validate(&rfc3339(instant))
+    }
+
+    /// Validate the lifetime at an instant, tolerating `leeway` of clock skew.
+    ///
+    /// The leeway applies symmetrically: a token stays valid up to `leeway`
+    /// past `expires_at` and is accepted up to `leeway` before `valid_from()`.
+    pub fn validate_with_leeway(&self, instant: DateTime<Utc>, leeway: Duration) -> TokenValidationResult {
+        let leeway = leeway.max(Duration::zero());
+        let early = instant.checked_add_signed(leeway).unwrap_or(instant);
+        let late = instant.checked_sub_signed(leeway).unwrap_or(instant);
+
+        if self.is_not_yet_valid(&rfc3339(early)) {
+            return Err(TokenValidationFailure::not_yet_valid(self.valid_from()));
+        }
+
+        if self.is_expired(&rfc3339(late)) {
+            return Err(TokenValidationFailure::expired(self.expires_at.clone()));
+        }
+
+        Ok(())
+    }
+
+    /// `issued_at` as Unix epoch seconds, if it is a valid RFC3339 timestamp.
+    pub fn issued_at_timestamp(&self) -> Option<i64> {
+        parse_timestamp(&self.issued_at)
+    }
+
+    /// `expires_at` as Unix epoch seconds, if it is a valid RFC3339 timestamp.
+    pub fn expires_at_timestamp(&self) -> Option<i64> {
+        parse_timestamp(&self.expires_at)
+    }
+
+    /// `not_before` as Unix epoch seconds, if set and a valid RFC3339 timestamp.
+    pub fn not_before_timestamp(&self) -> Option<i64> {
+        self.not_before.as_deref().and_then(parse_timestamp)
+    }
+
     /// Get the "not before" time if set, otherwise the issued_at time.
     ///
     /// This represents the earliest time the token becomes valid.
@@ -138,3 +239,16 @@ impl TokenLifetime {
         &self.expires_at
     }
 }
+
+/// Format an instant so that lexicographic order matches time order.
+fn rfc3339(instant: DateTime<Utc>) -> String {
+    instant.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn rfc3339_from_timestamp(timestamp: i64) -> String {
+    rfc3339(DateTime::from_timestamp(timestamp, 0).unwrap_or_default())
+}
+
+fn parse_timestamp(value: &str) -> Option<i64> {
+    DateTime::parse_from_rfc3339(value).ok().map(|dt| dt.timestamp())
+}
@@ -0,0 +1,143 @@
+//! Use case: AuthenticateInWorkspace
+//!
+//! Password authentication scoped to a workspace.
+//!
+//! Responsibilities:
+//! - Authenticate the user exactly as [`AuthenticateUser`] does
+//! - Resolve the requested workspace and check the user's membership
+//! - Return a `ContextualIdentity` and the claims carrying both `user_id`
+//!   and `workspace_id`
+//!
+//! Membership is checked only after the password is proven, so callers
+//! without valid credentials learn nothing about workspaces. Without a
+//! `workspace_id` the result is a global, user-only identity.
+
+use crate::core::error::{AuthenticationError, CoreError};
+use crate::core::identity::{ContextualIdentity, IdentityClaims};
+use crate::core::usecases::authenticate_user::{AuthenticateUser, AuthenticateUserInput};
+use crate::core::usecases::policies::LockoutPolicy;
+use crate::core::usecases::ports::{
+    AuditSink, Clock, CredentialRepository, IdentifierNormalizer, IdentityRepository, PasswordHasher,
+};
+
+/// Input contract for AuthenticateInWorkspace use case.
+pub struct AuthenticateInWorkspaceInput {
+    pub identifier: String,
+    pub password: String,
+    /// Workspace to authenticate into; `None` for a global login
+    pub workspace_id: Option<String>,
+    /// Client address the attempt came from, for the audit trail
+    pub source_ip: Option<String>,
+}
+
+/// Output contract for AuthenticateInWorkspace use case.
+#[derive(Debug)]
+pub struct AuthenticateInWorkspaceOutput {
+    pub identity: ContextualIdentity,
+    /// Token-safe projection of `identity`
+    pub claims: IdentityClaims,
+    /// Failed attempts recorded since the last successful login
+    pub recent_failed_attempts: u32,
+    /// When the user last logged in before this login (RFC3339), if ever
+    pub previous_login_at: Option<String>,
+}
+
+/// Use case for authenticating a user into a workspace.
+pub struct AuthenticateInWorkspace<'a> {
+    identity_repo: &'a (dyn IdentityRepository + Send + Sync),
+    authenticate_user: AuthenticateUser<'a>,
+}
+
+impl<'a> AuthenticateInWorkspace<'a> {
+    /// Create a new AuthenticateInWorkspace use case with dependencies.
+    pub fn new(
+        identity_repo: &'a (dyn IdentityRepository + Send + Sync),
+        credential_repo: &'a (dyn CredentialRepository + Send + Sync),
+        password_hasher: &'a (dyn PasswordHasher + Send + Sync),
+        clock: &'a (dyn Clock + Send + Sync),
+        lockout_policy: LockoutPolicy,
+    ) -> Self {
+        Self {
+            identity_repo,
+            authenticate_user: AuthenticateUser::new(
+                identity_repo,
+                credential_repo,
+                password_hasher,
+                clock,
+                lockout_policy,
+            ),
+        }
+    }
+
+    /// Report the password check's outcome to `audit_sink`.
+    pub fn with_audit_sink(mut self, audit_sink: &'a (dyn AuditSink + Send + Sync)) -> Self {
+        self.authenticate_user = self.authenticate_user.with_audit_sink(audit_sink);
+        self
+    }
+
+    /// Normalize identifiers with `identifier_normalizer` before the lookup.
+    pub fn with_identifier_normalizer(
+        mut self,
+        identifier_normalizer: &'a (dyn IdentifierNormalizer + Send + Sync),
+    ) -> Self {
+        self.authenticate_user = self.authenticate_user.with_identifier_normalizer(identifier_normalizer);
+        self
+    }
+
+    /// Leave recording the login time to the caller; see
+    /// [`AuthenticateUser::without_login_record`].
+    pub fn without_login_record(mut self) -> Self {
+        self.authenticate_user = self.authenticate_user.without_login_record();
+        self
+    }
+
+    /// Execute the workspace-scoped authentication use case.
+    pub async fn execute(
+        &self,
+        input: AuthenticateInWorkspaceInput,
+    ) -> Result<AuthenticateInWorkspaceOutput, CoreError> {
+        // Step 1: Authenticate the user
+        let authenticated = self
+            .authenticate_user
+            .execute(AuthenticateUserInput {
+                identifier: input.identifier,
+                password: input.password,
+                source_ip: input.source_ip,
+            })
+            .await?;
+
+        let mut identity = ContextualIdentity::from(authenticated.user);
+
+        // Step 2: Resolve the workspace and check membership. An unknown
+        // workspace fails the same way as one the user does not belong to.
+        if let Some(workspace_id) = input.workspace_id {
+            let workspace = self
+                .identity_repo
+                .find_workspace_by_id(&workspace_id)
+                .await
+                .ok_or_else(|| AuthenticationError::not_workspace_member(workspace_id.as_str()))?;
+
+            let user_id = identity.user_id().unwrap_or_default();
+            if !self.identity_repo.is_workspace_member(user_id, workspace.id()).await {
+                tracing::debug!(
+                    "[AuthenticateInWorkspace] User {} is not a member of workspace {}",
+                    user_id,
+                    workspace.id()
+                );
+                return Err(AuthenticationError::not_workspace_member(workspace_id).into());
+            }
+
+            identity = identity.with_workspace(workspace);
+        }
+
+        // Step 3: Project the identity into claims
+        let claims = identity.to_claims();
+
+        Ok(AuthenticateInWorkspaceOutput {
+            identity,
+            claims,
+            recent_failed_attempts: authenticated.recent_failed_attempts,
+            previous_login_at: authenticated.previous_login_at,
+        })
+    }
+}
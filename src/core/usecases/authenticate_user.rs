@@ -3,26 +3,49 @@
 //! Orchestrates user authentication with lockout policy enforcement.
 //!
 //! Responsibilities:
-//! - Lookup user by identifier
-//! - Check account lockout status
-//! - Verify password against stored credential
-//! - Track failed attempts and apply lockout policy
-//! - Return authenticated user identity on success
+//! - Optionally normalize the identifier before anything else
+//! - Lookup user by identifier, spending dummy hashing work on a miss so
+//!   unknown identifiers are not distinguishable by timing
+//! - Verify password against stored credential, then check account
+//!   lockout status, so a locked account costs the same hashing work
+//! - Track failed attempts and apply lockout policy, per account or per
+//!   account and source IP; per source, the account lock and the account
+//!   ceiling still apply
+//! - Optionally clear an expired lock on the next successful login
+//! - Reject credentials outside their validity window
+//! - Transparently upgrade outdated password hashes on success
+//! - Record the login time on success, unless the caller records it with
+//!   the session it issues
+//! - Optionally report successes, failures and lockouts to an audit sink
+//! - Return authenticated user identity on success, with the failed attempts
+//!   recorded before the counter was reset and the previous login time
 
+use crate::core::credentials::StoredCredential;
 use crate::core::error::{AuthenticationError, CoreError};
 use crate::core::identity::UserIdentity;
-use crate::core::usecases::ports::{CredentialRepository, IdentityRepository, PasswordHasher};
+use crate::core::usecases::policies::{LockoutPolicy, LockoutScope};
+use crate::core::usecases::ports::{
+    AuditEvent, AuditEventType, AuditOutcome, AuditSink, Clock, CredentialRepository, IdentifierNormalizer,
+    IdentityRepository, LockoutState, PasswordHasher,
+};
 
 /// Input contract for AuthenticateUser use case.
 pub struct AuthenticateUserInput {
     pub identifier: String,
     pub password: String,
+    /// Client address the attempt came from, for the audit trail and
+    /// per-source lockout
+    pub source_ip: Option<String>,
 }
 
 /// Output contract for AuthenticateUser use case.
 #[derive(Debug)]
 pub struct AuthenticateUserOutput {
     pub user: UserIdentity,
+    /// Failed attempts recorded since the last successful login
+    pub recent_failed_attempts: u32,
+    /// When the user last logged in before this login (RFC3339), if ever
+    pub previous_login_at: Option<String>,
 }
 
 /// Use case for authenticating a user with password.
@@ -30,8 +53,11 @@ pub struct AuthenticateUser<'a> {
     identity_repo: &'a (dyn IdentityRepository + Send + Sync),
     credential_repo: &'a (dyn CredentialRepository + Send + Sync),
     password_hasher: &'a (dyn PasswordHasher + Send + Sync),
-    max_attempts: u32,
-    lockout_duration_minutes: u32,
+    clock: &'a (dyn Clock + Send + Sync),
+    lockout_policy: LockoutPolicy,
+    audit_sink: Option<&'a (dyn AuditSink + Send + Sync)>,
+    identifier_normalizer: Option<&'a (dyn IdentifierNormalizer + Send + Sync)>,
+    record_login: bool,
 }
 
 impl<'a> AuthenticateUser<'a> {
@@ -40,26 +66,72 @@ impl<'a> AuthenticateUser<'a> {
         identity_repo: &'a (dyn IdentityRepository + Send + Sync),
         credential_repo: &'a (dyn CredentialRepository + Send + Sync),
         password_hasher: &'a (dyn PasswordHasher + Send + Sync),
-        max_attempts: u32,
-        lockout_duration_minutes: u32,
+        clock: &'a (dyn Clock + Send + Sync),
+        lockout_policy: LockoutPolicy,
     ) -> Self {
         Self {
             identity_repo,
             credential_repo,
             password_hasher,
-            max_attempts,
-            lockout_duration_minutes,
+            clock,
+            lockout_policy,
+            audit_sink: None,
+            identifier_normalizer: None,
+            record_login: true,
         }
     }
 
+    /// Report every attempt's outcome to `audit_sink`.
+    ///
+    /// A failing sink is logged and never affects the authentication result.
+    pub fn with_audit_sink(mut self, audit_sink: &'a (dyn AuditSink + Send + Sync)) -> Self {
+        self.audit_sink = Some(audit_sink);
+        self
+    }
+
+    /// Normalize identifiers with `identifier_normalizer` before the lookup.
+    ///
+    /// Must be the normalizer identifiers were stored with.
+    pub fn with_identifier_normalizer(
+        mut self,
+        identifier_normalizer: &'a (dyn IdentifierNormalizer + Send + Sync),
+    ) -> Self {
+        self.identifier_normalizer = Some(identifier_normalizer);
+        self
+    }
+
+    /// Leave recording the login time to the caller.
+    ///
+    /// For callers that record it in the same unit of work as the session
+    /// they issue, such as [`IssueSession`](crate::core::usecases::IssueSession)
+    /// with a unit of work. `previous_login_at` is still reported.
+    pub fn without_login_record(mut self) -> Self {
+        self.record_login = false;
+        self
+    }
+
     /// Execute the authentication use case.
     pub async fn execute(&self, input: AuthenticateUserInput) -> Result<AuthenticateUserOutput, CoreError> {
-        // Step 1: Find user by identifier
-        let user = self
-            .identity_repo
-            .find_by_identifier(&input.identifier)
-            .await
-            .ok_or_else(|| AuthenticationError::user_not_found("identifier not found"))?;
+        // Step 1: Find user by identifier, in the form it was stored in. An
+        // unknown identifier still pays for one verification and fails
+        // exactly like a wrong password.
+        let identifier = match self.identifier_normalizer {
+            Some(normalizer) => normalizer.normalize(&input.identifier),
+            None => input.identifier,
+        };
+        let user = match self.identity_repo.find_by_identifier(&identifier).await {
+            Some(user) => user,
+            None => {
+                self.password_hasher.dummy_verify(&input.password);
+                self.audit(
+                    AuditEvent::new(&identifier, AuditEventType::LoginFailed, self.clock.now(), AuditOutcome::Failure)
+                        .with_reason("unknown identifier")
+                        .with_source_ip(input.source_ip.clone()),
+                )
+                .await;
+                return Err(AuthenticationError::user_not_found("invalid credentials").into());
+            }
+        };
 
         // Step 2: Get credential state for lockout check
         let credential = self
@@ -67,52 +139,343 @@ impl<'a> AuthenticateUser<'a> {
             .get_by_user_id(&user.id)
             .await;
 
-        // Step 3: Check if account is locked
-        if let Some(ref cred) = credential {
-            if let Some(ref locked_until) = cred.locked_until {
-                let now = chrono::Utc::now().to_rfc3339();
-                if locked_until > &now {
-                    return Err(AuthenticationError::account_locked(format!(
-                        "account locked until {}",
-                        locked_until
-                    ))
-                    .into());
-                }
+        // Step 3: Read the lockout state of the account, and of this source
+        // when lockout is scoped per source
+        let tracker = LockoutTracker::new(self.credential_repo, &self.lockout_policy);
+        let source = tracker.source(input.source_ip.as_deref());
+        let lockout = tracker.state(&user.id, source, credential.as_ref()).await;
+
+        // Step 4: Resolve the credential lifecycle status up front, so the
+        // gate below is decided before verification runs
+        let status = self.credential_repo.get_status(&user.id).await;
+
+        // Step 5: Verify password. This runs whatever the status or lock is,
+        // so a locked account, or a revoked, expired or not-yet-valid
+        // credential, costs the same hashing work as any other attempt.
+        let password_valid = match credential.as_ref() {
+            Some(cred) => self.password_hasher.verify(&input.password, cred),
+            None => {
+                self.password_hasher.dummy_verify(&input.password);
+                false
             }
-        }
+        };
 
-        // Step 4: Verify password
-        let password_valid = credential
-            .as_ref()
-            .map(|cred| self.password_hasher.verify(&input.password, cred))
-            .unwrap_or(false);
+        // Step 6: Refuse while locked, whether or not the password matched
+        let now = self.clock.now();
+        if let Some(locked_until) = LockoutTracker::active_lock(&lockout, now) {
+            self.audit(
+                AuditEvent::new(&user.id, AuditEventType::LoginFailed, now, AuditOutcome::Failure)
+                    .with_reason("account locked")
+                    .with_source_ip(input.source_ip.clone()),
+            )
+            .await;
+            return Err(AuthenticationError::account_locked_until(
+                format!("account locked until {}", locked_until),
+                locked_until,
+            )
+            .into());
+        }
 
         if !password_valid {
-            // Increment failed attempts
-            let new_attempts = credential
-                .as_ref()
-                .map(|c| c.failed_attempts + 1)
-                .unwrap_or(1);
+            let failure = tracker.record_failure(&user.id, source, &lockout, now).await;
 
+            self.audit(
+                AuditEvent::new(&user.id, AuditEventType::LoginFailed, now, AuditOutcome::Failure)
+                    .with_reason("invalid password")
+                    .with_source_ip(input.source_ip.clone()),
+            )
+            .await;
+            if let Some(event) = failure.lock_event(&user.id, now) {
+                self.audit(event.with_source_ip(input.source_ip.clone())).await;
+            }
+
+            return Err(AuthenticationError::user_not_found("invalid credentials").into());
+        }
+
+        // Step 7: Enforce credential lifecycle. The specific status is only
+        // revealed once the password is proven, never to password guessers.
+        if let Err(e) = status.ensure_verifiable() {
+            tracing::debug!("[AuthenticateUser] Credential unusable for user {}: {}", user.id, e);
+            self.audit(
+                AuditEvent::new(&user.id, AuditEventType::LoginFailed, now, AuditOutcome::Failure)
+                    .with_reason("credential not usable")
+                    .with_source_ip(input.source_ip.clone()),
+            )
+            .await;
+            return Err(AuthenticationError::credential_expired(e.to_string()).into());
+        }
+
+        // Step 8: Upgrade the stored hash if it uses outdated parameters.
+        // The repository write is best-effort and never fails the login.
+        if let Some(ref cred) = credential
+            && self.password_hasher.needs_rehash(cred)
+        {
+            tracing::debug!("[AuthenticateUser] Upgrading password hash for user {}", user.id);
+            let upgraded = self.password_hasher.hash(&input.password);
+            self.credential_repo.update_password(&user.id, upgraded).await;
+        }
+
+        // Step 9: Reset failed attempts (and with them any backoff escalation),
+        // reporting the count seen before the reset. A lock that expired
+        // before this login is cleared too when the policy asks for it.
+        let recent_failed_attempts = lockout.counter.failed_attempts;
+        tracker.record_success(&user.id, source, &lockout).await;
+
+        // Step 10: Record this login. The previous value was read with the
+        // credential above, so it reflects the login before this one.
+        let previous_login_at = credential.and_then(|cred| cred.last_login_at);
+        if self.record_login {
             self.credential_repo
-                .update_failed_attempts(&user.id, new_attempts)
+                .record_successful_login(&user.id, &now.to_rfc3339())
                 .await;
+        }
+
+        self.audit(
+            AuditEvent::new(&user.id, AuditEventType::LoginSucceeded, now, AuditOutcome::Success)
+                .with_source_ip(input.source_ip),
+        )
+        .await;
+
+        Ok(AuthenticateUserOutput {
+            user,
+            recent_failed_attempts,
+            previous_login_at,
+        })
+    }
+
+    /// Hand an event to the audit sink, if one is configured.
+    async fn audit(&self, event: AuditEvent) {
+        if let Some(audit_sink) = self.audit_sink {
+            audit_sink.record_or_log(event).await;
+        }
+    }
+}
+
+/// Failed-attempt counting and locking, shared by every use case that
+/// checks a user's password so all of them feed the same lockout.
+pub(crate) struct LockoutTracker<'a> {
+    credential_repo: &'a (dyn CredentialRepository + Send + Sync),
+    policy: &'a LockoutPolicy,
+}
+
+/// Lockout state read before checking a password.
+#[derive(Debug, Default)]
+pub(crate) struct TrackedLockout {
+    /// Counter and lock of the source, or of the account when failures
+    /// count against the account
+    pub counter: LockoutState,
+    /// Counter and lock of the whole account when `counter` is a source's
+    pub account: Option<LockoutState>,
+}
+
+/// What recording one failed attempt did.
+pub(crate) struct RecordedFailure {
+    /// Failed attempts counted, including this one
+    pub attempts: u32,
+    /// Expiry of the lock this failure triggered, if it triggered one
+    pub locked_until: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl RecordedFailure {
+    /// Audit event for the lock this failure triggered, if any.
+    pub(crate) fn lock_event(&self, user_id: &str, now: chrono::DateTime<chrono::Utc>) -> Option<AuditEvent> {
+        self.locked_until.map(|until| {
+            AuditEvent::new(user_id, AuditEventType::AccountLocked, now, AuditOutcome::Failure)
+                .with_reason(format!("{} failed attempts, locked until {}", self.attempts, until.to_rfc3339()))
+        })
+    }
+}
+
+impl<'a> LockoutTracker<'a> {
+    pub(crate) fn new(
+        credential_repo: &'a (dyn CredentialRepository + Send + Sync),
+        policy: &'a LockoutPolicy,
+    ) -> Self {
+        Self { credential_repo, policy }
+    }
+
+    /// The source address failed attempts are counted against, or `None`
+    /// when they count against the whole account.
+    pub(crate) fn source<'s>(&self, source_ip: Option<&'s str>) -> Option<&'s str> {
+        match self.policy.scope() {
+            LockoutScope::Account => None,
+            LockoutScope::AccountAndSourceIp => source_ip.filter(|ip| !ip.is_empty()),
+        }
+    }
+
+    /// Counter and lock of `source`, alongside the account's read from
+    /// `credential`, or of the account alone without a source.
+    pub(crate) async fn state(
+        &self,
+        user_id: &str,
+        source: Option<&str>,
+        credential: Option<&StoredCredential>,
+    ) -> TrackedLockout {
+        let account = credential
+            .map(|cred| LockoutState {
+                failed_attempts: cred.failed_attempts,
+                locked_until: cred.locked_until.clone(),
+                last_failed_at: cred.last_failed_at.clone(),
+            })
+            .unwrap_or_default();
+        match source {
+            Some(source_ip) => TrackedLockout {
+                counter: self.credential_repo.get_source_lockout(user_id, source_ip).await,
+                account: Some(account),
+            },
+            None => TrackedLockout { counter: account, account: None },
+        }
+    }
+
+    /// The lock in `lockout` still in force at `now`, if any; the account's
+    /// lock wins over the source's.
+    ///
+    /// A lock lifts exactly at `locked_until`; an unparsable timestamp
+    /// keeps it in force.
+    pub(crate) fn active_lock(lockout: &TrackedLockout, now: chrono::DateTime<chrono::Utc>) -> Option<String> {
+        let in_force = |state: &LockoutState| {
+            state.locked_until.clone().filter(|locked_until| {
+                chrono::DateTime::parse_from_rfc3339(locked_until)
+                    .map(|until| now < until)
+                    .unwrap_or(true)
+            })
+        };
+        lockout
+            .account
+            .as_ref()
+            .and_then(in_force)
+            .or_else(|| in_force(&lockout.counter))
+    }
+
+    /// Count one failed attempt against `source` and lock it once the policy
+    /// threshold is reached; repeat offenses escalate. A failure counted
+    /// against a source also counts against the account, which locks once
+    /// the policy's account ceiling is reached.
+    ///
+    /// A count starts over when the previous failure fell out of the
+    /// policy's reset window.
+    pub(crate) async fn record_failure(
+        &self,
+        user_id: &str,
+        source: Option<&str>,
+        lockout: &TrackedLockout,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> RecordedFailure {
+        let source_failure = self.count_failure(user_id, source, &lockout.counter, now).await;
+        let (Some(source_ip), Some(account)) = (source, lockout.account.as_ref()) else {
+            return source_failure;
+        };
 
-            // Apply lockout if threshold reached
-            if new_attempts >= self.max_attempts {
-                let lockout_until = chrono::Utc::now()
-                    + chrono::Duration::minutes(self.lockout_duration_minutes as i64);
+        let attempts = self.next_attempts(account, now);
+        self.credential_repo.update_failed_attempts(user_id, attempts).await;
+        self.credential_repo.record_failed_login(user_id, &now.to_rfc3339()).await;
+        if !self.policy.is_account_ceiling_reached(attempts) {
+            return source_failure;
+        }
+
+        tracing::debug!(
+            "[LockoutTracker] Account ceiling reached for user {} from source {}",
+            user_id,
+            source_ip
+        );
+        let locked_until = now + Self::duration(self.policy.account_lock_duration_for(attempts));
+        self.credential_repo.lock_until(user_id, &locked_until.to_rfc3339()).await;
+        RecordedFailure { attempts, locked_until: Some(locked_until) }
+    }
+
+    /// Reset the counters in `lockout` after a successful login, or clear
+    /// the lock behind them that has already expired when the policy asks
+    /// for it.
+    pub(crate) async fn record_success(&self, user_id: &str, source: Option<&str>, lockout: &TrackedLockout) {
+        self.reset(user_id, source, &lockout.counter).await;
+        if source.is_some()
+            && let Some(account) = lockout.account.as_ref()
+        {
+            self.reset(user_id, None, account).await;
+        }
+    }
+
+    async fn reset(&self, user_id: &str, source: Option<&str>, lockout: &LockoutState) {
+        if lockout.locked_until.is_some() && self.policy.should_unlock_on_success() {
+            tracing::debug!("[LockoutTracker] Clearing expired lock for user {}", user_id);
+            match source {
+                Some(source_ip) => self.credential_repo.update_source_failed_attempts(user_id, source_ip, 0).await,
+                None => self.credential_repo.clear_lock(user_id).await,
+            }
+        } else if self.policy.should_reset_on_success() {
+            self.set_failed_attempts(user_id, source, 0).await;
+        }
+    }
+
+    /// Count one failed attempt against the counter of `source`, or of the
+    /// account without a source, and lock it at the policy threshold.
+    async fn count_failure(
+        &self,
+        user_id: &str,
+        source: Option<&str>,
+        lockout: &LockoutState,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> RecordedFailure {
+        let attempts = self.next_attempts(lockout, now);
+        self.set_failed_attempts(user_id, source, attempts).await;
+        match source {
+            Some(source_ip) => {
                 self.credential_repo
-                    .lock_until(&user.id, &lockout_until.to_rfc3339())
-                    .await;
+                    .record_source_failed_login(user_id, source_ip, &now.to_rfc3339())
+                    .await
             }
+            None => self.credential_repo.record_failed_login(user_id, &now.to_rfc3339()).await,
+        }
 
-            return Err(AuthenticationError::user_not_found("invalid credentials").into());
+        if !self.policy.is_locked(attempts) {
+            return RecordedFailure { attempts, locked_until: None };
         }
 
-        // Step 5: Reset failed attempts on successful authentication
-        self.credential_repo.update_failed_attempts(&user.id, 0).await;
+        let locked_until = now + Self::duration(self.policy.lock_duration_for(attempts));
+        match source {
+            Some(source_ip) => {
+                self.credential_repo
+                    .lock_source_until(user_id, source_ip, &locked_until.to_rfc3339())
+                    .await
+            }
+            None => self.credential_repo.lock_until(user_id, &locked_until.to_rfc3339()).await,
+        }
+        RecordedFailure { attempts, locked_until: Some(locked_until) }
+    }
 
-        Ok(AuthenticateUserOutput { user })
+    /// The counter in `lockout` after one more failure at `now`.
+    fn next_attempts(&self, lockout: &LockoutState, now: chrono::DateTime<chrono::Utc>) -> u32 {
+        if self.is_last_failure_stale(lockout, now) {
+            1
+        } else {
+            lockout.failed_attempts.saturating_add(1)
+        }
+    }
+
+    fn duration(secs: u64) -> chrono::Duration {
+        chrono::Duration::seconds(secs.min(i64::MAX as u64) as i64)
+    }
+
+    /// Whether the last failure in `lockout` is too old to count any more.
+    ///
+    /// An unknown or unparsable failure time keeps the counter.
+    fn is_last_failure_stale(&self, lockout: &LockoutState, now: chrono::DateTime<chrono::Utc>) -> bool {
+        lockout
+            .last_failed_at
+            .as_deref()
+            .and_then(|at| chrono::DateTime::parse_from_rfc3339(at).ok())
+            .is_some_and(|at| self.policy.is_failure_stale((now - at.with_timezone(&chrono::Utc)).num_seconds()))
+    }
+
+    /// Store the failed-attempt counter of the account or of one source.
+    pub(crate) async fn set_failed_attempts(&self, user_id: &str, source: Option<&str>, attempts: u32) {
+        match source {
+            Some(source_ip) => {
+                self.credential_repo
+                    .update_source_failed_attempts(user_id, source_ip, attempts)
+                    .await
+            }
+            None => self.credential_repo.update_failed_attempts(user_id, attempts).await,
+        }
     }
 }
@@ -0,0 +1,128 @@
+//! Use case: AuthenticateWithPasskey
+//!
+//! Orchestrates passwordless authentication with a WebAuthn assertion.
+//!
+//! Responsibilities:
+//! - Look up the credential the assertion names
+//! - Verify the assertion against the stored public key
+//! - Bind the assertion to the challenge issued for this ceremony
+//! - Enforce signature counter monotonicity to detect cloned authenticators
+//! - Record the accepted counter
+//!
+//! Does NOT:
+//! - Issue or remember challenges; the caller passes the one it issued
+//! - Issue sessions or tokens
+
+use crate::core::error::{AuthenticationError, CoreError, CredentialError};
+use crate::core::usecases::ports::{PasskeyRepository, WebAuthnVerifier};
+
+/// Input contract for AuthenticateWithPasskey use case.
+pub struct AuthenticateWithPasskeyInput {
+    /// `PublicKeyCredential` JSON returned by `navigator.credentials.get()`
+    pub response: String,
+    /// Challenge issued for this ceremony, base64url-encoded
+    pub challenge: String,
+}
+
+/// Output contract for AuthenticateWithPasskey use case.
+#[derive(Debug)]
+pub struct AuthenticateWithPasskeyOutput {
+    pub user_id: String,
+    pub credential_id: String,
+    pub user_verified: bool,
+}
+
+/// Use case for authenticating a user with a passkey.
+pub struct AuthenticateWithPasskey<'a> {
+    passkey_repo: &'a (dyn PasskeyRepository + Send + Sync),
+    webauthn_verifier: &'a (dyn WebAuthnVerifier + Send + Sync),
+}
+
+impl<'a> AuthenticateWithPasskey<'a> {
+    /// Create a new AuthenticateWithPasskey use case with dependencies.
+    pub fn new(
+        passkey_repo: &'a (dyn PasskeyRepository + Send + Sync),
+        webauthn_verifier: &'a (dyn WebAuthnVerifier + Send + Sync),
+    ) -> Self {
+        Self {
+            passkey_repo,
+            webauthn_verifier,
+        }
+    }
+
+    /// Execute the passkey authentication use case.
+    pub async fn execute(
+        &self,
+        input: AuthenticateWithPasskeyInput,
+    ) -> Result<AuthenticateWithPasskeyOutput, CoreError> {
+        // Step 1: Find the credential the assertion names
+        let credential_id = self
+            .webauthn_verifier
+            .assertion_credential_id(&input.response)
+            .map_err(|_| AuthenticationError::InvalidCredentials)?;
+
+        let credential = self
+            .passkey_repo
+            .find_by_credential_id(&credential_id)
+            .await
+            .ok_or(AuthenticationError::InvalidCredentials)?;
+
+        // Step 2: Verify the assertion against the stored public key
+        let assertion = self
+            .webauthn_verifier
+            .verify_assertion(&input.response, &credential.public_key)
+            .map_err(|e| {
+                tracing::debug!("[AuthenticateWithPasskey] Assertion rejected for {}: {}", credential_id, e);
+                AuthenticationError::InvalidCredentials
+            })?;
+
+        if assertion.credential_id != credential.credential_id {
+            return Err(AuthenticationError::InvalidCredentials.into());
+        }
+
+        // Step 3: Bind it to the challenge issued for this ceremony
+        if input.challenge.is_empty() || assertion.challenge != input.challenge {
+            tracing::debug!("[AuthenticateWithPasskey] Challenge mismatch for {}", credential_id);
+            return Err(AuthenticationError::InvalidCredentials.into());
+        }
+
+        // Step 4: Reject counters that did not advance
+        if !counter_advanced(credential.sign_count, assertion.sign_count) {
+            tracing::warn!(
+                "[AuthenticateWithPasskey] Sign counter for {} did not advance ({} -> {}); possible cloned authenticator",
+                credential_id,
+                credential.sign_count,
+                assertion.sign_count
+            );
+            return Err(CredentialError::verification_failed("passkey signature counter did not advance").into());
+        }
+
+        // Step 5: Record the accepted counter
+        if assertion.sign_count != credential.sign_count {
+            let advanced = self
+                .passkey_repo
+                .update_sign_count(&credential.credential_id, credential.sign_count, assertion.sign_count)
+                .await
+                .map_err(|e| AuthenticationError::incomplete_flow(format!("failed to record passkey counter: {}", e)))?;
+
+            // Another assertion with this counter won the race
+            if !advanced {
+                return Err(CredentialError::verification_failed("passkey signature counter did not advance").into());
+            }
+        }
+
+        Ok(AuthenticateWithPasskeyOutput {
+            user_id: credential.user_id,
+            credential_id: credential.credential_id,
+            user_verified: assertion.user_verified,
+        })
+    }
+}
+
+/// Whether `presented` is an acceptable successor of the `stored` counter.
+///
+/// Authenticators that do not implement a counter report zero every time;
+/// that is accepted as long as the stored counter is zero too.
+fn counter_advanced(stored: u32, presented: u32) -> bool {
+    presented > stored || (stored == 0 && presented == 0)
+}
@@ -0,0 +1,249 @@
+//! Use case: ChangePassword
+//!
+//! Orchestrates a signed-in user replacing their own password.
+//!
+//! Responsibilities:
+//! - Verify the current password before looking at the new one, so a wrong
+//!   current password never reveals whether the new one would be accepted
+//! - Optionally count a wrong current password towards the same lockout as
+//!   failed logins, and refuse while the account is locked
+//! - Enforce CredentialPolicy on the new password, reporting every failed
+//!   rule at once
+//! - Optionally reject a new password found in a known data breach
+//! - Reject reuse of the current or a recent password
+//! - Re-hash and store the new credential, remembering the old one
+//! - Optionally revoke every other session while keeping the current one
+//! - Optionally report the outcome to an audit sink
+//!
+//! The user id must come from an authenticated source (validated token
+//! claims); this use case does not check ownership itself.
+
+use crate::core::credentials::{CredentialPolicy, RawCredential};
+use crate::core::error::{AuthenticationError, CoreError, CredentialError, InvariantError};
+use crate::core::usecases::authenticate_user::LockoutTracker;
+use crate::core::usecases::policies::LockoutPolicy;
+use crate::core::usecases::ports::{
+    AuditEvent, AuditEventType, AuditOutcome, AuditSink, BreachChecker, CredentialRepository,
+    IdentityRepository, PasswordHasher, SessionRepository,
+};
+
+/// Input contract for ChangePassword use case.
+pub struct ChangePasswordInput {
+    pub user_id: String,
+    pub current_password: String,
+    pub new_password: String,
+    /// Session making the request; kept when other sessions are revoked
+    pub current_session_id: Option<String>,
+    pub revoke_other_sessions: bool,
+    /// Client address the request came from, for the audit trail and
+    /// per-source lockout
+    pub source_ip: Option<String>,
+}
+
+/// Output contract for ChangePassword use case.
+#[derive(Debug)]
+pub struct ChangePasswordOutput {
+    pub user_id: String,
+    pub sessions_revoked: u64,
+}
+
+/// Use case for changing a signed-in user's password.
+pub struct ChangePassword<'a> {
+    credential_repo: &'a (dyn CredentialRepository + Send + Sync),
+    password_hasher: &'a (dyn PasswordHasher + Send + Sync),
+    session_repo: &'a (dyn SessionRepository + Send + Sync),
+    credential_policy: CredentialPolicy,
+    audit_sink: Option<&'a (dyn AuditSink + Send + Sync)>,
+    identity_repo: Option<&'a (dyn IdentityRepository + Send + Sync)>,
+    breach_checker: Option<&'a (dyn BreachChecker + Send + Sync)>,
+    lockout_policy: Option<LockoutPolicy>,
+}
+
+impl<'a> ChangePassword<'a> {
+    /// Create a new ChangePassword use case with dependencies.
+    pub fn new(
+        credential_repo: &'a (dyn CredentialRepository + Send + Sync),
+        password_hasher: &'a (dyn PasswordHasher + Send + Sync),
+        session_repo: &'a (dyn SessionRepository + Send + Sync),
+        credential_policy: CredentialPolicy,
+    ) -> Self {
+        Self {
+            credential_repo,
+            password_hasher,
+            session_repo,
+            credential_policy,
+            audit_sink: None,
+            identity_repo: None,
+            breach_checker: None,
+            lockout_policy: None,
+        }
+    }
+
+    /// Report password changes and failed attempts to `audit_sink`.
+    pub fn with_audit_sink(mut self, audit_sink: &'a (dyn AuditSink + Send + Sync)) -> Self {
+        self.audit_sink = Some(audit_sink);
+        self
+    }
+
+    /// Look up the account identifier in `identity_repo` so the policy can
+    /// reject new passwords built from it.
+    pub fn with_identity_repository(mut self, identity_repo: &'a (dyn IdentityRepository + Send + Sync)) -> Self {
+        self.identity_repo = Some(identity_repo);
+        self
+    }
+
+    /// Reject new passwords that `breach_checker` reports as breached.
+    pub fn with_breach_checker(mut self, breach_checker: &'a (dyn BreachChecker + Send + Sync)) -> Self {
+        self.breach_checker = Some(breach_checker);
+        self
+    }
+
+    /// Count wrong current passwords towards the login lockout of
+    /// `lockout_policy`, and refuse changes while it holds the account.
+    pub fn with_lockout_policy(mut self, lockout_policy: LockoutPolicy) -> Self {
+        self.lockout_policy = Some(lockout_policy);
+        self
+    }
+
+    /// Execute the change-password use case.
+    pub async fn execute(&self, input: ChangePasswordInput) -> Result<ChangePasswordOutput, CoreError> {
+        // Step 1: Validate input
+        if input.user_id.is_empty() {
+            return Err(InvariantError::violated("user_id must be provided").into());
+        }
+        if input.revoke_other_sessions && input.current_session_id.as_deref().is_none_or(str::is_empty) {
+            return Err(InvariantError::violated("current_session_id is required to revoke other sessions").into());
+        }
+
+        // Step 2: Read the lockout state of the account (and of this source)
+        let previous = self.credential_repo.get_by_user_id(&input.user_id).await;
+        let now = chrono::Utc::now();
+        let tracker = self
+            .lockout_policy
+            .as_ref()
+            .map(|policy| LockoutTracker::new(self.credential_repo, policy));
+        let source = tracker.as_ref().and_then(|tracker| tracker.source(input.source_ip.as_deref()));
+        let lockout = match tracker.as_ref() {
+            Some(tracker) => tracker.state(&input.user_id, source, previous.as_ref()).await,
+            None => Default::default(),
+        };
+
+        // Step 3: Verify the current password, even while locked so the lock
+        // does not show in response times. A missing credential costs the
+        // same hashing work and fails the same way as a wrong password.
+        let current_valid = match previous.as_ref() {
+            Some(credential) => self.password_hasher.verify(&input.current_password, credential),
+            None => {
+                self.password_hasher.dummy_verify(&input.current_password);
+                false
+            }
+        };
+        if let Some(locked_until) = LockoutTracker::active_lock(&lockout, now) {
+            self.audit(
+                AuditEvent::new(&input.user_id, AuditEventType::PasswordChanged, now, AuditOutcome::Failure)
+                    .with_reason("account locked")
+                    .with_source_ip(input.source_ip.clone()),
+            )
+            .await;
+            return Err(AuthenticationError::account_locked_until(
+                format!("account locked until {}", locked_until),
+                locked_until,
+            )
+            .into());
+        }
+        if !current_valid {
+            self.audit(
+                AuditEvent::new(&input.user_id, AuditEventType::PasswordChanged, now, AuditOutcome::Failure)
+                    .with_reason("invalid current password")
+                    .with_source_ip(input.source_ip.clone()),
+            )
+            .await;
+            if let Some(tracker) = tracker {
+                let failure = tracker.record_failure(&input.user_id, source, &lockout, now).await;
+                if let Some(event) = failure.lock_event(&input.user_id, now) {
+                    self.audit(event.with_source_ip(input.source_ip.clone())).await;
+                }
+            }
+            return Err(AuthenticationError::user_not_found("invalid credentials").into());
+        }
+
+        // Step 4: Enforce credential policy on the new password for this
+        // account, reporting every failed rule so the user can fix them in
+        // one go
+        let raw = RawCredential::new(input.new_password);
+        let identifier = match self.identity_repo {
+            Some(identity_repo) => identity_repo.find_identifier_by_id(&input.user_id).await,
+            None => None,
+        }
+        .unwrap_or_default();
+        let validated = match self.breach_checker {
+            Some(breach_checker) => {
+                self.credential_policy
+                    .validate_raw_with_breach_checker(&raw, &identifier, breach_checker)
+                    .await
+            }
+            None => self.credential_policy.validate_raw_for_identifier(&raw, &identifier),
+        };
+        if let Err(rejected) = validated {
+            let violations = self.credential_policy.evaluate(&raw, &identifier);
+            return Err(CredentialError::from_violations(violations).unwrap_or(rejected).into());
+        }
+
+        // Step 5: Reject the current and recently used passwords
+        let history_depth = self.credential_policy.password_history_depth;
+        self.credential_repo
+            .check_password_history(&input.user_id, raw.as_str(), self.password_hasher, history_depth)
+            .await?;
+
+        // Step 6: Hash and store the new credential
+        let new_credential = self.password_hasher.hash(raw.as_str());
+        self.credential_repo
+            .update_password(&input.user_id, new_credential)
+            .await;
+
+        // Remember the replaced password for later reuse checks
+        if let Some(previous) = previous.filter(|_| history_depth > 0) {
+            let recorded = self
+                .credential_repo
+                .push_password_history(&input.user_id, previous, history_depth)
+                .await;
+            if let Err(e) = recorded {
+                tracing::warn!("[ChangePassword] Failed to record password history for user {}: {}", input.user_id, e);
+            }
+        }
+
+        // Step 7: Optionally sign out every other device
+        let sessions_revoked = match input.current_session_id.as_deref() {
+            Some(keep_session_id) if input.revoke_other_sessions => {
+                self.session_repo
+                    .revoke_all_for_user_except(&input.user_id, keep_session_id)
+                    .await
+            }
+            _ => 0,
+        };
+
+        tracing::debug!(
+            "[ChangePassword] Password changed for user {} ({} other session(s) revoked)",
+            input.user_id,
+            sessions_revoked
+        );
+
+        self.audit(
+            AuditEvent::new(&input.user_id, AuditEventType::PasswordChanged, chrono::Utc::now(), AuditOutcome::Success)
+                .with_reason("password change")
+                .with_source_ip(input.source_ip.clone()),
+        )
+        .await;
+
+        Ok(ChangePasswordOutput {
+            user_id: input.user_id,
+            sessions_revoked,
+        })
+    }
+
+    async fn audit(&self, event: AuditEvent) {
+        if let Some(audit_sink) = self.audit_sink {
+            audit_sink.record_or_log(event).await;
+        }
+    }
+}
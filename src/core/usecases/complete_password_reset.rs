@@ -0,0 +1,222 @@
+//! Use case: CompletePasswordReset
+//!
+//! Orchestrates the second half of the forgotten-password flow.
+//!
+//! Responsibilities:
+//! - Validate the reset token (signature, `type: "reset"`, expiry)
+//! - Enforce CredentialPolicy on the new password, including the
+//!   identifier rule when an identity repository is supplied
+//! - Optionally reject a new password found in a known data breach
+//! - Reject reuse of the current or a recent password
+//! - Consume the token's `jti` so it can complete only one reset
+//! - Re-hash and store the new credential, remembering the old one
+//! - Revoke all existing sessions for the user
+//! - Optionally report the password change to an audit sink
+
+use crate::core::credentials::{CredentialPolicy, RawCredential};
+use crate::core::error::{CoreError, CredentialError, TokenError};
+use crate::core::token::Token;
+use crate::core::usecases::ports::{
+    AuditEvent, AuditEventType, AuditOutcome, AuditSink, BreachChecker, CredentialRepository,
+    IdentityRepository, PasswordHasher, ResetTokenStore, SessionRepository, TokenService,
+};
+
+/// Input contract for CompletePasswordReset use case.
+pub struct CompletePasswordResetInput {
+    pub reset_token: Token,
+    pub new_password: String,
+}
+
+/// Output contract for CompletePasswordReset use case.
+#[derive(Debug)]
+pub struct CompletePasswordResetOutput {
+    pub user_id: String,
+    pub sessions_revoked: bool,
+}
+
+/// Use case for completing a password reset.
+pub struct CompletePasswordReset<'a> {
+    token_service: &'a (dyn TokenService + Send + Sync),
+    credential_repo: &'a (dyn CredentialRepository + Send + Sync),
+    password_hasher: &'a (dyn PasswordHasher + Send + Sync),
+    session_repo: &'a (dyn SessionRepository + Send + Sync),
+    reset_token_store: &'a (dyn ResetTokenStore + Send + Sync),
+    credential_policy: CredentialPolicy,
+    audit_sink: Option<&'a (dyn AuditSink + Send + Sync)>,
+    identity_repo: Option<&'a (dyn IdentityRepository + Send + Sync)>,
+    breach_checker: Option<&'a (dyn BreachChecker + Send + Sync)>,
+}
+
+impl<'a> CompletePasswordReset<'a> {
+    /// Create a new CompletePasswordReset use case with dependencies.
+    pub fn new(
+        token_service: &'a (dyn TokenService + Send + Sync),
+        credential_repo: &'a (dyn CredentialRepository + Send + Sync),
+        password_hasher: &'a (dyn PasswordHasher + Send + Sync),
+        session_repo: &'a (dyn SessionRepository + Send + Sync),
+        reset_token_store: &'a (dyn ResetTokenStore + Send + Sync),
+        credential_policy: CredentialPolicy,
+    ) -> Self {
+        Self {
+            token_service,
+            credential_repo,
+            password_hasher,
+            session_repo,
+            reset_token_store,
+            credential_policy,
+            audit_sink: None,
+            identity_repo: None,
+            breach_checker: None,
+        }
+    }
+
+    /// Report completed password changes to `audit_sink`.
+    pub fn with_audit_sink(mut self, audit_sink: &'a (dyn AuditSink + Send + Sync)) -> Self {
+        self.audit_sink = Some(audit_sink);
+        self
+    }
+
+    /// Look up the account identifier in `identity_repo` so the policy can
+    /// reject new passwords built from it.
+    pub fn with_identity_repository(mut self, identity_repo: &'a (dyn IdentityRepository + Send + Sync)) -> Self {
+        self.identity_repo = Some(identity_repo);
+        self
+    }
+
+    /// Reject new passwords that `breach_checker` reports as breached.
+    pub fn with_breach_checker(mut self, breach_checker: &'a (dyn BreachChecker + Send + Sync)) -> Self {
+        self.breach_checker = Some(breach_checker);
+        self
+    }
+
+    /// Execute the password reset completion use case.
+    pub async fn execute(&self, input: CompletePasswordResetInput) -> Result<CompletePasswordResetOutput, CoreError> {
+        // Step 1: Validate reset token signature
+        let claims = self
+            .token_service
+            .validate_reset_token(&input.reset_token)
+            .await
+            .map_err(|_| TokenError::signature_invalid("invalid reset token"))?;
+
+        // Step 2: Check token type is "reset"
+        if self.extract_token_type(&claims).as_deref() != Some("reset") {
+            return Err(TokenError::invalid_claims("not a reset token").into());
+        }
+
+        // Step 3: Check expiration
+        let exp = self
+            .extract_exp(&claims)
+            .ok_or_else(|| TokenError::invalid_claims("missing exp claim"))?;
+        if chrono::Utc::now().timestamp() > exp {
+            return Err(TokenError::expired(exp.to_string()).into());
+        }
+
+        let user_id = self
+            .extract_user_id(&claims)
+            .ok_or_else(|| TokenError::invalid_claims("missing sub claim"))?;
+        let jti = self
+            .extract_jti(&claims)
+            .ok_or_else(|| TokenError::invalid_claims("missing jti claim"))?;
+
+        // Step 4: Enforce credential policy on the new password
+        let raw = RawCredential::new(input.new_password);
+        let identifier = match self.identity_repo {
+            Some(identity_repo) => identity_repo.find_identifier_by_id(&user_id).await,
+            None => None,
+        }
+        .unwrap_or_default();
+        let validated = match self.breach_checker {
+            Some(breach_checker) => {
+                self.credential_policy
+                    .validate_raw_with_breach_checker(&raw, &identifier, breach_checker)
+                    .await
+            }
+            None => self.credential_policy.validate_raw_for_identifier(&raw, &identifier),
+        };
+        if let Err(rejected) = validated {
+            let violations = self.credential_policy.evaluate(&raw, &identifier);
+            return Err(CredentialError::from_violations(violations).unwrap_or(rejected).into());
+        }
+
+        // Reject the current and recently used passwords
+        let history_depth = self.credential_policy.password_history_depth;
+        self.credential_repo
+            .check_password_history(&user_id, raw.as_str(), self.password_hasher, history_depth)
+            .await?;
+
+        // Step 5: Consume the token; only one concurrent request can win.
+        // Done after the policy check so a rejected password does not burn it.
+        self.reset_token_store.consume_reset_token(&jti, exp).await?;
+
+        // Step 6: Hash and store the new credential
+        let previous = self.credential_repo.get_by_user_id(&user_id).await;
+        let new_credential = self.password_hasher.hash(raw.as_str());
+        self.credential_repo
+            .update_password(&user_id, new_credential)
+            .await;
+
+        // Remember the replaced password for later reuse checks
+        if let Some(previous) = previous.filter(|_| history_depth > 0) {
+            let recorded = self
+                .credential_repo
+                .push_password_history(&user_id, previous, history_depth)
+                .await;
+            if let Err(e) = recorded {
+                tracing::warn!("[CompletePasswordReset] Failed to record password history for user {}: {}", user_id, e);
+            }
+        }
+
+        // Step 7: Revoke all sessions so stolen sessions cannot outlive the reset
+        self.session_repo.revoke_all_for_user(&user_id).await;
+
+        tracing::debug!("[CompletePasswordReset] Password reset for user {}", user_id);
+
+        if let Some(audit_sink) = self.audit_sink {
+            audit_sink
+                .record_or_log(
+                    AuditEvent::new(&user_id, AuditEventType::PasswordChanged, chrono::Utc::now(), AuditOutcome::Success)
+                        .with_reason("password reset"),
+                )
+                .await;
+        }
+
+        Ok(CompletePasswordResetOutput {
+            user_id,
+            sessions_revoked: true,
+        })
+    }
+
+    fn extract_user_id(&self, claims: &str) -> Option<String> {
+        claims
+            .split("\"sub\":\"")
+            .nth(1)
+            .and_then(|s| s.split('"').next())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+    }
+
+    fn extract_exp(&self, claims: &str) -> Option<i64> {
+        claims
+            .split("\"exp\":")
+            .nth(1)
+            .and_then(|s| s.split([',', '}']).next())
+            .and_then(|s| s.trim().parse::<i64>().ok())
+    }
+
+    fn extract_jti(&self, claims: &str) -> Option<String> {
+        claims
+            .split("\"jti\":\"")
+            .nth(1)
+            .and_then(|s| s.split('"').next())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+    }
+
+    fn extract_token_type(&self, claims: &str) -> Option<String> {
+        claims
+            .split("\"type\":\"")
+            .nth(1)
+            .and_then(|s| s.split('"').next())
+            .map(|s| s.to_string())
+    }
+}
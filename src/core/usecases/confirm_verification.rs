@@ -0,0 +1,147 @@
+//! Use case: ConfirmVerification
+//!
+//! Orchestrates the second half of the email verification flow.
+//!
+//! Responsibilities:
+//! - Validate the verification token (signature, `type: "verify"`, expiry)
+//! - Treat confirming an already active credential as success (idempotent)
+//! - Refuse to reactivate revoked or expired credentials
+//! - Consume the token's `jti` so it can confirm at most once
+//! - Transition the credential from pending (`NotYetValid`) to `Active`
+
+use crate::core::credentials::CredentialStatus;
+use crate::core::error::{CoreError, InvariantError, TokenError};
+use crate::core::token::Token;
+use crate::core::usecases::ports::{CredentialRepository, ResetTokenStore, TokenService};
+
+/// Input contract for ConfirmVerification use case.
+pub struct ConfirmVerificationInput {
+    pub verification_token: Token,
+}
+
+/// Output contract for ConfirmVerification use case.
+#[derive(Debug)]
+pub struct ConfirmVerificationOutput {
+    pub user_id: String,
+    /// The credential was already active before this confirmation
+    pub already_verified: bool,
+}
+
+/// Use case for confirming an email verification.
+///
+/// Verification tokens are consumed through the same single-use store as
+/// reset tokens; their `jti` values are random UUIDs, so they never collide.
+pub struct ConfirmVerification<'a> {
+    token_service: &'a (dyn TokenService + Send + Sync),
+    credential_repo: &'a (dyn CredentialRepository + Send + Sync),
+    token_store: &'a (dyn ResetTokenStore + Send + Sync),
+}
+
+impl<'a> ConfirmVerification<'a> {
+    /// Create a new ConfirmVerification use case with dependencies.
+    pub fn new(
+        token_service: &'a (dyn TokenService + Send + Sync),
+        credential_repo: &'a (dyn CredentialRepository + Send + Sync),
+        token_store: &'a (dyn ResetTokenStore + Send + Sync),
+    ) -> Self {
+        Self {
+            token_service,
+            credential_repo,
+            token_store,
+        }
+    }
+
+    /// Execute the verification confirmation use case.
+    pub async fn execute(&self, input: ConfirmVerificationInput) -> Result<ConfirmVerificationOutput, CoreError> {
+        // Step 1: Validate verification token signature
+        let claims = self
+            .token_service
+            .validate_verification_token(&input.verification_token)
+            .await
+            .map_err(|_| TokenError::signature_invalid("invalid verification token"))?;
+
+        // Step 2: Check token type is "verify"
+        if self.extract_token_type(&claims).as_deref() != Some("verify") {
+            return Err(TokenError::invalid_claims("not a verification token").into());
+        }
+
+        // Step 3: Check expiration
+        let exp = self
+            .extract_exp(&claims)
+            .ok_or_else(|| TokenError::invalid_claims("missing exp claim"))?;
+        if chrono::Utc::now().timestamp() > exp {
+            return Err(TokenError::expired(exp.to_string()).into());
+        }
+
+        let user_id = self
+            .extract_user_id(&claims)
+            .ok_or_else(|| TokenError::invalid_claims("missing sub claim"))?;
+        let jti = self
+            .extract_jti(&claims)
+            .ok_or_else(|| TokenError::invalid_claims("missing jti claim"))?;
+
+        // Step 4: Confirming an active credential again changes nothing
+        match self.credential_repo.get_status(&user_id).await {
+            CredentialStatus::Active => {
+                tracing::debug!("[ConfirmVerification] User {} already verified", user_id);
+                return Ok(ConfirmVerificationOutput {
+                    user_id,
+                    already_verified: true,
+                });
+            }
+            CredentialStatus::NotYetValid { .. } => {}
+            // Verification must not bring back a revoked or expired credential
+            status => status.ensure_verifiable()?,
+        }
+
+        // Step 5: Consume the token; only one concurrent request can win
+        self.token_store.consume_reset_token(&jti, exp).await?;
+
+        // Step 6: Activate the credential
+        self.credential_repo
+            .set_status(&user_id, CredentialStatus::Active)
+            .await
+            .map_err(|e| InvariantError::dependency_unavailable("credential store", e))?;
+
+        tracing::debug!("[ConfirmVerification] Credential activated for user {}", user_id);
+
+        Ok(ConfirmVerificationOutput {
+            user_id,
+            already_verified: false,
+        })
+    }
+
+    fn extract_user_id(&self, claims: &str) -> Option<String> {
+        claims
+            .split("\"sub\":\"")
+            .nth(1)
+            .and_then(|s| s.split('"').next())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+    }
+
+    fn extract_exp(&self, claims: &str) -> Option<i64> {
+        claims
+            .split("\"exp\":")
+            .nth(1)
+            .and_then(|s| s.split([',', '}']).next())
+            .and_then(|s| s.trim().parse::<i64>().ok())
+    }
+
+    fn extract_jti(&self, claims: &str) -> Option<String> {
+        claims
+            .split("\"jti\":\"")
+            .nth(1)
+            .and_then(|s| s.split('"').next())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+    }
+
+    fn extract_token_type(&self, claims: &str) -> Option<String> {
+        claims
+            .split("\"type\":\"")
+            .nth(1)
+            .and_then(|s| s.split('"').next())
+            .map(|s| s.to_string())
+    }
+}
@@ -0,0 +1,104 @@
+//! Use case: InitiatePasswordReset
+//!
+//! Orchestrates the first half of the forgotten-password flow.
+//!
+//! Responsibilities:
+//! - Lookup user by identifier, optionally normalized first
+//! - Issue a short-lived reset token (`type: "reset"`, unique `jti`) via TokenService
+//! - Return the token for out-of-band delivery (e.g. email)
+//! - Return the same output shape for unknown identifiers (no user enumeration)
+
+use crate::core::error::CoreError;
+use crate::core::identity::UserIdentity;
+use crate::core::token::Token;
+use crate::core::usecases::issue_session::ensure_issued;
+use crate::core::usecases::ports::{IdentifierNormalizer, IdentityRepository, RandomSource, TokenService};
+
+/// Input contract for InitiatePasswordReset use case.
+pub struct InitiatePasswordResetInput {
+    pub identifier: String,
+}
+
+/// Output contract for InitiatePasswordReset use case.
+///
+/// `reset_token` is `None` when the identifier is unknown. Callers must not
+/// reveal that difference to the requester; the token is only meant for
+/// out-of-band delivery.
+#[derive(Debug)]
+pub struct InitiatePasswordResetOutput {
+    pub reset_token: Option<Token>,
+    pub expires_in: u64,
+}
+
+/// Use case for initiating a password reset.
+pub struct InitiatePasswordReset<'a> {
+    identity_repo: &'a (dyn IdentityRepository + Send + Sync),
+    token_service: &'a (dyn TokenService + Send + Sync),
+    random: &'a (dyn RandomSource + Send + Sync),
+    reset_token_ttl_secs: u64,
+    identifier_normalizer: Option<&'a (dyn IdentifierNormalizer + Send + Sync)>,
+}
+
+impl<'a> InitiatePasswordReset<'a> {
+    /// Create a new InitiatePasswordReset use case with dependencies.
+    pub fn new(
+        identity_repo: &'a (dyn IdentityRepository + Send + Sync),
+        token_service: &'a (dyn TokenService + Send + Sync),
+        random: &'a (dyn RandomSource + Send + Sync),
+        reset_token_ttl_secs: u64,
+    ) -> Self {
+        Self {
+            identity_repo,
+            token_service,
+            random,
+            reset_token_ttl_secs,
+            identifier_normalizer: None,
+        }
+    }
+
+    /// Normalize identifiers with `identifier_normalizer` before the lookup.
+    pub fn with_identifier_normalizer(
+        mut self,
+        identifier_normalizer: &'a (dyn IdentifierNormalizer + Send + Sync),
+    ) -> Self {
+        self.identifier_normalizer = Some(identifier_normalizer);
+        self
+    }
+
+    /// Execute the password reset initiation use case.
+    pub async fn execute(&self, input: InitiatePasswordResetInput) -> Result<InitiatePasswordResetOutput, CoreError> {
+        // Step 1: Find user by identifier
+        let identifier = match self.identifier_normalizer {
+            Some(normalizer) => normalizer.normalize(&input.identifier),
+            None => input.identifier,
+        };
+        let Some(user) = self.identity_repo.find_by_identifier(&identifier).await else {
+            // Unknown identifiers get the same success-shaped output
+            tracing::debug!("[InitiatePasswordReset] Unknown identifier, returning empty output");
+            return Ok(InitiatePasswordResetOutput {
+                reset_token: None,
+                expires_in: self.reset_token_ttl_secs,
+            });
+        };
+
+        // Step 2: Issue reset token
+        let claims = self.build_reset_claims(&user);
+        let reset_token = ensure_issued(self.token_service.issue_reset_token(&user.id, &claims).await, "reset")?;
+
+        tracing::debug!("[InitiatePasswordReset] Reset token issued for user {}", user.id);
+
+        Ok(InitiatePasswordResetOutput {
+            reset_token: Some(reset_token),
+            expires_in: self.reset_token_ttl_secs,
+        })
+    }
+
+    fn build_reset_claims(&self, user: &UserIdentity) -> String {
+        format!(
+            r#"{{"sub":"{}","type":"reset","exp":{},"jti":"{}"}}"#,
+            user.id,
+            chrono::Utc::now().timestamp() + self.reset_token_ttl_secs as i64,
+            self.random.uuid_v4()
+        )
+    }
+}
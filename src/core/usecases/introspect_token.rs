@@ -0,0 +1,163 @@
+//! Use case: IntrospectToken
+//!
+//! Answers "is this access token still good, and what does it say" for
+//! internal services that do not hold the signing keys (RFC 7662 style).
+//!
+//! Responsibilities:
+//! - Delegate to TokenService for signature validation
+//! - Check token type and expiry against the injected clock
+//! - Check the session named by the `sid` claim is still active
+//! - Check the token's `jti` is not on the deny-list, when one is configured
+//! - Check the token was issued after the issued-at watermark, when one is configured
+//! - Expose claims only for active tokens
+//!
+//! Any failure yields an inactive result with no claims, so a caller can
+//! never learn anything about a token it should no longer trust.
+
+use crate::core::error::CoreError;
+use crate::core::token::Token;
+use crate::core::usecases::ports::{Clock, SessionRepository, TokenDenyList, TokenService, TokenWatermarkStore};
+use crate::core::usecases::validate_access_token::extract_scopes;
+
+/// Input contract for IntrospectToken use case.
+pub struct IntrospectTokenInput {
+    pub token: Token,
+}
+
+/// Claims disclosed for an active token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntrospectedClaims {
+    pub sub: String,
+    pub exp: i64,
+    pub iat: Option<i64>,
+    pub scope: Vec<String>,
+    pub token_type: String,
+}
+
+/// Output contract for IntrospectToken use case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntrospectTokenOutput {
+    /// Present only when the token is active.
+    pub claims: Option<IntrospectedClaims>,
+}
+
+impl IntrospectTokenOutput {
+    /// Result for any token that must not be trusted.
+    pub fn inactive() -> Self {
+        Self { claims: None }
+    }
+
+    /// Whether the token is active.
+    pub fn is_active(&self) -> bool {
+        self.claims.is_some()
+    }
+}
+
+/// Use case for introspecting an access token.
+pub struct IntrospectToken<'a> {
+    token_service: &'a (dyn TokenService + Send + Sync),
+    session_repo: &'a (dyn SessionRepository + Send + Sync),
+    clock: &'a (dyn Clock + Send + Sync),
+    deny_list: Option<&'a (dyn TokenDenyList + Send + Sync)>,
+    watermark: Option<&'a (dyn TokenWatermarkStore + Send + Sync)>,
+}
+
+impl<'a> IntrospectToken<'a> {
+    /// Create a new IntrospectToken use case with dependencies.
+    pub fn new(
+        token_service: &'a (dyn TokenService + Send + Sync),
+        session_repo: &'a (dyn SessionRepository + Send + Sync),
+        clock: &'a (dyn Clock + Send + Sync),
+    ) -> Self {
+        Self {
+            token_service,
+            session_repo,
+            clock,
+            deny_list: None,
+            watermark: None,
+        }
+    }
+
+    /// Report tokens whose `jti` has been denied as inactive.
+    pub fn with_deny_list(mut self, deny_list: &'a (dyn TokenDenyList + Send + Sync)) -> Self {
+        self.deny_list = Some(deny_list);
+        self
+    }
+
+    /// Report tokens issued at or before the issued-at watermark as inactive.
+    pub fn with_watermark(mut self, watermark: &'a (dyn TokenWatermarkStore + Send + Sync)) -> Self {
+        self.watermark = Some(watermark);
+        self
+    }
+
+    /// Execute the token introspection use case.
+    pub async fn execute(&self, input: IntrospectTokenInput) -> Result<IntrospectTokenOutput, CoreError> {
+        // Step 1: Validate token signature via TokenService
+        let claims = match self.token_service.validate_access_token(&input.token).await {
+            Ok(claims) => claims,
+            Err(_) => {
+                tracing::debug!("[Introspect] Token failed signature validation");
+                return Ok(IntrospectTokenOutput::inactive());
+            }
+        };
+
+        let parsed: serde_json::Value = match serde_json::from_str(&claims) {
+            Ok(parsed) => parsed,
+            Err(_) => return Ok(IntrospectTokenOutput::inactive()),
+        };
+
+        // Step 2: Require an access token with a subject and an expiry
+        let token_type = parsed.get("type").and_then(|v| v.as_str()).unwrap_or_default();
+        let sub = parsed.get("sub").and_then(|v| v.as_str()).unwrap_or_default();
+        let exp = match parsed.get("exp").and_then(|v| v.as_i64()) {
+            Some(exp) => exp,
+            None => return Ok(IntrospectTokenOutput::inactive()),
+        };
+        if token_type != "access" || sub.is_empty() {
+            return Ok(IntrospectTokenOutput::inactive());
+        }
+
+        // Step 3: Check expiry
+        if self.clock.now().timestamp() >= exp {
+            tracing::debug!("[Introspect] Token expired");
+            return Ok(IntrospectTokenOutput::inactive());
+        }
+
+        // Step 4: Check the session is still active
+        if let Some(sid) = parsed.get("sid").and_then(|v| v.as_str()).filter(|sid| !sid.is_empty())
+            && self.session_repo.find_by_id(sid).await.is_none()
+        {
+            tracing::debug!("[Introspect] Session {} revoked or expired", sid);
+            return Ok(IntrospectTokenOutput::inactive());
+        }
+
+        // Step 5: Check the token was not individually revoked
+        if let (Some(deny_list), Some(jti)) = (self.deny_list, parsed.get("jti").and_then(|v| v.as_str()))
+            && deny_list.is_denied(jti).await
+        {
+            tracing::debug!("[Introspect] Token {} is denied", jti);
+            return Ok(IntrospectTokenOutput::inactive());
+        }
+
+        // Step 6: Check the token was not revoked along with every other one
+        let iat = parsed.get("iat").and_then(|v| v.as_i64());
+        if let Some(watermark) = self.watermark
+            && let Some(revoked_through) = watermark.revoked_through().await
+            && iat.is_none_or(|iat| iat <= revoked_through)
+        {
+            tracing::debug!("[Introspect] Token is covered by watermark {}", revoked_through);
+            return Ok(IntrospectTokenOutput::inactive());
+        }
+
+        // Step 7: Disclose claims for the active token
+        Ok(IntrospectTokenOutput {
+            claims: Some(IntrospectedClaims {
+                sub: sub.to_string(),
+                exp,
+                iat,
+                scope: extract_scopes(&claims),
+                token_type: token_type.to_string(),
+            }),
+        })
+    }
+}
@@ -106,7 +106,7 @@ impl<'a> IssueServiceToken<'a> {
             "[ISSUE_SERVICE_TOKEN] Issuing service token for: {}",
             valid_service_id
         );
-        let access_token = self.token_service.issue_service_token(&valid_service_id, &claims);
+        let access_token = self.token_service.issue_service_token(&valid_service_id, &claims).await;
 
         tracing::info!(
             "[ISSUE_SERVICE_TOKEN] Service token issued successfully for: {}",
@@ -7,14 +7,32 @@
 //! - Issue access token via TokenService
 //! - Issue refresh token via TokenService
 //! - Hash refresh token for storage
+//! - Enrich the client IP and user agent into structured details, when an
+//!   enricher is configured; a failed or timed-out enrichment keeps the raw
+//!   values only
 //! - Persist session to SessionRepository
 //! - Return tokens and session metadata
+//! - Refuse to hand out a token the TokenService failed to produce
+//!
+//! With a [`UnitOfWork`] configured, the session row and the user's login
+//! time are written in one unit of work and the tokens are only returned
+//! once it has committed. A failed write or commit rolls both back, so no
+//! token outlives a session that was never persisted and no login is
+//! recorded without its session.
+
+use std::time::Duration;
 
-use crate::core::error::CoreError;
+use crate::core::error::{CoreError, InvariantError};
 use crate::core::identity::UserIdentity;
 use crate::core::token::{Token, TokenClaims};
 use serde_json::to_string;
-use crate::core::usecases::ports::{SessionRepository, TokenService};
+use crate::core::usecases::ports::{
+    Clock, RandomSource, SessionClientDetails, SessionMetadataEnricher, SessionRepository, TokenService, UnitOfWork,
+};
+
+/// Longest wait for session metadata enrichment before the session is
+/// issued without structured client details.
+pub const DEFAULT_ENRICHMENT_TIMEOUT: Duration = Duration::from_millis(500);
 
 /// Input contract for IssueSession use case.
 pub struct IssueSessionInput {
@@ -22,6 +40,8 @@ pub struct IssueSessionInput {
     pub ip_address: String,
     pub user_agent: String,
     pub scopes: Vec<String>,
+    /// Workspace the session is scoped to, embedded in the access token
+    pub workspace_id: Option<String>,
 }
 
 /// Output contract for IssueSession use case.
@@ -36,8 +56,13 @@ pub struct IssueSessionOutput {
 pub struct IssueSession<'a> {
     session_repo: &'a (dyn SessionRepository + Send + Sync),
     token_service: &'a (dyn TokenService + Send + Sync),
+    clock: &'a (dyn Clock + Send + Sync),
+    random: &'a (dyn RandomSource + Send + Sync),
     access_token_ttl_seconds: u64,
     refresh_token_ttl_days: u64,
+    unit_of_work: Option<&'a (dyn UnitOfWork + Send + Sync)>,
+    metadata_enricher: Option<&'a (dyn SessionMetadataEnricher + Send + Sync)>,
+    enrichment_timeout: Duration,
 }
 
 impl<'a> IssueSession<'a> {
@@ -45,27 +70,59 @@ impl<'a> IssueSession<'a> {
     pub fn new(
         session_repo: &'a (dyn SessionRepository + Send + Sync),
         token_service: &'a (dyn TokenService + Send + Sync),
+        clock: &'a (dyn Clock + Send + Sync),
+        random: &'a (dyn RandomSource + Send + Sync),
         access_token_ttl_seconds: u64,
         refresh_token_ttl_days: u64,
     ) -> Self {
         Self {
             session_repo,
             token_service,
+            clock,
+            random,
             access_token_ttl_seconds,
             refresh_token_ttl_days,
+            unit_of_work: None,
+            metadata_enricher: None,
+            enrichment_timeout: DEFAULT_ENRICHMENT_TIMEOUT,
         }
     }
 
+    /// Persist the session and record the login inside a unit of work
+    /// instead of writing the session directly through the session
+    /// repository.
+    ///
+    /// The login is then recorded here, so the authentication step should
+    /// leave it out (see [`AuthenticateUser::without_login_record`](crate::core::usecases::AuthenticateUser::without_login_record)).
+    pub fn with_unit_of_work(mut self, unit_of_work: &'a (dyn UnitOfWork + Send + Sync)) -> Self {
+        self.unit_of_work = Some(unit_of_work);
+        self
+    }
+
+    /// Store structured client details derived by `metadata_enricher`
+    /// alongside the raw IP and user agent.
+    pub fn with_metadata_enricher(mut self, metadata_enricher: &'a (dyn SessionMetadataEnricher + Send + Sync)) -> Self {
+        self.metadata_enricher = Some(metadata_enricher);
+        self
+    }
+
+    /// Replace the default bound on metadata enrichment.
+    pub fn with_enrichment_timeout(mut self, enrichment_timeout: Duration) -> Self {
+        self.enrichment_timeout = enrichment_timeout;
+        self
+    }
+
     /// Execute the session issuance use case.
     pub async fn execute(&self, input: IssueSessionInput) -> Result<IssueSessionOutput, CoreError> {
         // Step 1: Generate v7) FIRST - needed for token session ID (UUID claims
         tracing::debug!("[ISSUE] Step 1: Generating session ID");
-        let session_id = uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext)).to_string();
+        let now = self.clock.now();
+        let session_id = self.random.uuid_v7(now).to_string();
         tracing::debug!("[ISSUE] Generated session_id={}", session_id);
 
         // Step 2: Issue access token with session_id in claims
         tracing::debug!("[ISSUE] Step 2: Issuing access token");
-        let iat = chrono::Utc::now().timestamp();
+        let iat = now.timestamp();
         let access_claims = TokenClaims::new(
             input.user.id.clone(),
             iat,
@@ -73,20 +130,31 @@ impl<'a> IssueSession<'a> {
             "access".to_string(),
         ).with_sid(session_id.clone())
          .with_scopes(input.scopes.clone());
+        let access_claims = match &input.workspace_id {
+            Some(workspace_id) => access_claims.with_workspace_id(workspace_id.clone()),
+            None => access_claims,
+        };
         let access_claims_json = to_string(&access_claims).expect("TokenClaims serialization failed");
-        let access_token = self.token_service.issue_access_token(&input.user.id, &access_claims_json);
+        let access_token = ensure_issued(
+            self.token_service.issue_access_token(&input.user.id, &access_claims_json).await,
+            "access",
+        )?;
 
-        // Step 3: Issue refresh token with session_id in claims
+        // Step 3: Issue refresh token with session_id in claims; it expires
+        // together with the session row, never after it
         tracing::debug!("[ISSUE] Step 3: Issuing refresh token");
-        let iat = chrono::Utc::now().timestamp();
+        let expires_at = now + chrono::Duration::days(self.refresh_token_ttl_days as i64);
         let refresh_claims = TokenClaims::new(
             input.user.id.clone(),
             iat,
-            iat + (self.refresh_token_ttl_days * 86400) as i64,
+            expires_at.timestamp(),
             "refresh".to_string(),
         ).with_sid(session_id.clone());
         let refresh_claims_json = to_string(&refresh_claims).expect("TokenClaims serialization failed");
-        let refresh_token = self.token_service.issue_refresh_token(&input.user.id, &refresh_claims_json);
+        let refresh_token = ensure_issued(
+            self.token_service.issue_refresh_token(&input.user.id, &refresh_claims_json).await,
+            "refresh",
+        )?;
         
         tracing::debug!("[ISSUE] Refresh token value: {}", refresh_token.value());
 
@@ -95,18 +163,21 @@ impl<'a> IssueSession<'a> {
         let refresh_token_hash = self.hash_token(&refresh_token);
         tracing::debug!("[ISSUE] Computed hash: {}", refresh_token_hash);
 
-        // Step 5: Calculate expiration
-        let _expires_at = chrono::Utc::now()
-            + chrono::Duration::days(self.refresh_token_ttl_days as i64);
-
-        // Step 6: Persist session
-        tracing::debug!("[ISSUE] Step 6: Persisting session to database");
-        self.session_repo.create_session(
-            &session_id,
-            &input.user,
-            &refresh_token_hash,
-            &self.build_session_metadata(&input),
-        ).await?;
+        // Step 5: Persist session; tokens are dropped if this fails
+        tracing::debug!("[ISSUE] Step 5: Persisting session to database");
+        let details = self.enrich_client(&input).await;
+        let metadata = self.build_session_metadata(&input, &details, now, expires_at);
+        match self.unit_of_work {
+            Some(unit_of_work) => {
+                self.persist_atomically(unit_of_work, &session_id, &input.user, &refresh_token_hash, &metadata, now)
+                    .await?
+            }
+            None => {
+                self.session_repo
+                    .create_session(&session_id, &input.user, &refresh_token_hash, &metadata)
+                    .await?
+            }
+        }
         
         tracing::debug!("[ISSUE] Session created successfully");
 
@@ -118,15 +189,83 @@ impl<'a> IssueSession<'a> {
         })
     }
 
+    async fn persist_atomically(
+        &self,
+        unit_of_work: &(dyn UnitOfWork + Send + Sync),
+        session_id: &str,
+        user: &UserIdentity,
+        refresh_token_hash: &str,
+        metadata: &str,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), CoreError> {
+        let mut scope = unit_of_work.begin().await?;
 
-    fn build_session_metadata(&self, input: &IssueSessionInput) -> String {
-        // Build session metadata JSON
-        format!(
-            r#"{{"ip":"{}","ua":"{}","created":"{}"}}"#,
-            input.ip_address,
-            input.user_agent,
-            chrono::Utc::now().to_rfc3339()
-        )
+        let staged = match scope.create_session(session_id, user, refresh_token_hash, metadata).await {
+            Ok(()) => scope.record_successful_login(&user.id, &now.to_rfc3339()).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = staged {
+            tracing::error!("[ISSUE] Session write failed, rolling back: {}", e);
+            scope.rollback().await;
+            return Err(e);
+        }
+
+        scope.commit().await
+    }
+
+    /// Derive structured client details; never fails or stalls the session.
+    async fn enrich_client(&self, input: &IssueSessionInput) -> SessionClientDetails {
+        let Some(enricher) = self.metadata_enricher else {
+            return SessionClientDetails::default();
+        };
+        let enrichment = enricher.enrich(&input.ip_address, &input.user_agent);
+        match tokio::time::timeout(self.enrichment_timeout, enrichment).await {
+            Ok(Ok(details)) => details,
+            Ok(Err(e)) => {
+                tracing::warn!("[ISSUE] Session metadata enrichment failed, keeping raw values: {}", e);
+                SessionClientDetails::default()
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "[ISSUE] Session metadata enrichment timed out after {:?}, keeping raw values",
+                    self.enrichment_timeout
+                );
+                SessionClientDetails::default()
+            }
+        }
+    }
+
+    fn build_session_metadata(
+        &self,
+        input: &IssueSessionInput,
+        details: &SessionClientDetails,
+        now: chrono::DateTime<chrono::Utc>,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> String {
+        // Build session metadata JSON; values are escaped since the user
+        // agent is client-controlled. "expires" becomes the row's
+        // expires_at, so it matches the refresh token's exp to the second.
+        let mut metadata = serde_json::json!({
+            "ip": input.ip_address,
+            "ua": input.user_agent,
+            "created": now.to_rfc3339(),
+            "expires": expires_at.to_rfc3339(),
+        });
+        // Enriched details are only recorded when known
+        let enriched = [
+            ("device_type", &details.device_type),
+            ("os", &details.os),
+            ("browser", &details.browser),
+            ("geo_country", &details.country),
+            ("geo_region", &details.region),
+            ("geo_city", &details.city),
+        ];
+        for (key, value) in enriched {
+            if let Some(value) = value {
+                metadata[key] = serde_json::Value::String(value.clone());
+            }
+        }
+        metadata.to_string()
     }
 
     fn hash_token(&self, token: &Token) -> String {
@@ -141,3 +280,20 @@ impl<'a> IssueSession<'a> {
         hex::encode(result)
     }
 }
+
+/// Reject a token the TokenService failed to produce.
+///
+/// `TokenService` issuance is infallible by signature, and adapters signal
+/// an encoding failure with an empty token. That must never reach a client
+/// or be persisted as a session.
+pub(crate) fn ensure_issued(token: Token, kind: &str) -> Result<Token, CoreError> {
+    if token.is_empty() {
+        tracing::error!("[ISSUE] TokenService returned an empty {} token", kind);
+        return Err(InvariantError::inconsistent_state(format!(
+            "token service returned an empty {} token",
+            kind
+        ))
+        .into());
+    }
+    Ok(token)
+}
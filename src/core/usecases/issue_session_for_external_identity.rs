@@ -17,7 +17,8 @@
 use uuid::Uuid;
 use crate::core::error::CoreError;
 use crate::core::identity::UserIdentity;
-use crate::core::usecases::ports::{SessionRepository, TokenService};
+use crate::core::usecases::issue_session::ensure_issued;
+use crate::core::usecases::ports::{RandomSource, SessionRepository, TokenService};
 
 pub struct IssueSessionForExternalIdentityInput {
     /// Verified user ID — caller is responsible for ensuring this user exists
@@ -37,6 +38,7 @@ pub struct IssueSessionForExternalIdentityOutput {
 pub struct IssueSessionForExternalIdentity<'a> {
     session_repository: &'a (dyn SessionRepository + Send + Sync),
     token_service: &'a (dyn TokenService + Send + Sync),
+    random: &'a (dyn RandomSource + Send + Sync),
     access_token_ttl_seconds: u64,
     refresh_token_ttl_days: u64,
 }
@@ -45,12 +47,14 @@ impl<'a> IssueSessionForExternalIdentity<'a> {
     pub fn new(
         session_repository: &'a (dyn SessionRepository + Send + Sync),
         token_service: &'a (dyn TokenService + Send + Sync),
+        random: &'a (dyn RandomSource + Send + Sync),
         access_token_ttl_seconds: u64,
         refresh_token_ttl_days: u64,
     ) -> Self {
         Self {
             session_repository,
             token_service,
+            random,
             access_token_ttl_seconds,
             refresh_token_ttl_days,
         }
@@ -72,18 +76,24 @@ impl<'a> IssueSessionForExternalIdentity<'a> {
         let identity = UserIdentity::new(user_id_str.clone());
 
         // Generate session ID
-        let session_id = Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext)).to_string();
+        let session_id = self.random.uuid_v7(chrono::Utc::now()).to_string();
 
         // Issue tokens
-        let access_token = self.token_service.issue_access_token(
-            &user_id_str,
-            &self.build_access_claims(&identity, &session_id),
-        );
-
-        let refresh_token = self.token_service.issue_refresh_token(
-            &user_id_str,
-            &self.build_refresh_claims(&identity, &session_id),
-        );
+        let access_token = ensure_issued(
+            self.token_service.issue_access_token(
+                &user_id_str,
+                &self.build_access_claims(&identity, &session_id),
+            ).await,
+            "access",
+        )?;
+
+        let refresh_token = ensure_issued(
+            self.token_service.issue_refresh_token(
+                &user_id_str,
+                &self.build_refresh_claims(&identity, &session_id),
+            ).await,
+            "refresh",
+        )?;
 
         // Hash refresh token for storage
         let refresh_token_hash = self.hash_token(&refresh_token);
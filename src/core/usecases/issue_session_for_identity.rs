@@ -10,7 +10,8 @@
 
 use crate::core::error::CoreError;
 use crate::core::identity::UserIdentity;
-use crate::core::usecases::ports::{IdentityRepository, SessionRepository, TokenService};
+use crate::core::usecases::issue_session::ensure_issued;
+use crate::core::usecases::ports::{IdentityRepository, RandomSource, SessionRepository, TokenService};
 
 /// Input contract for IssueSessionForIdentity use case.
 pub struct IssueSessionForIdentityInput {
@@ -38,6 +39,7 @@ pub struct IssueSessionForIdentity<'a> {
     identity_repository: &'a (dyn IdentityRepository + Send + Sync),
     session_repository: &'a (dyn SessionRepository + Send + Sync),
     token_service: &'a (dyn TokenService + Send + Sync),
+    random: &'a (dyn RandomSource + Send + Sync),
     access_token_ttl_seconds: u64,
     refresh_token_ttl_days: u64,
 }
@@ -48,6 +50,7 @@ impl<'a> IssueSessionForIdentity<'a> {
         identity_repository: &'a (dyn IdentityRepository + Send + Sync),
         session_repository: &'a (dyn SessionRepository + Send + Sync),
         token_service: &'a (dyn TokenService + Send + Sync),
+        random: &'a (dyn RandomSource + Send + Sync),
         access_token_ttl_seconds: u64,
         refresh_token_ttl_days: u64,
     ) -> Self {
@@ -55,6 +58,7 @@ impl<'a> IssueSessionForIdentity<'a> {
             identity_repository,
             session_repository,
             token_service,
+            random,
             access_token_ttl_seconds,
             refresh_token_ttl_days,
         }
@@ -97,24 +101,29 @@ impl<'a> IssueSessionForIdentity<'a> {
         );
 
         // Step 2: Generate session ID
-        let session_id = uuid::Uuid::new_v7(uuid::Timestamp::now(uuid::NoContext)).to_string();
+        let session_id = self.random.uuid_v7(chrono::Utc::now()).to_string();
         tracing::debug!(
             "[ISSUE_SESSION_FOR_IDENTITY] Generated session_id={}",
             session_id
         );
 
         // Step 3: Issue access token
-        let access_token = self
-            .token_service
-            .issue_access_token(&identity.id, &self.build_access_claims(&identity, &session_id));
+        let access_token = ensure_issued(
+            self.token_service
+                .issue_access_token(&identity.id, &self.build_access_claims(&identity, &session_id)).await,
+            "access",
+        )?;
 
         tracing::debug!("[ISSUE_SESSION_FOR_IDENTITY] Access token issued");
 
         // Step 4: Issue refresh token
-        let refresh_token = self.token_service.issue_refresh_token(
-            &identity.id,
-            &self.build_refresh_claims(&identity, &session_id),
-        );
+        let refresh_token = ensure_issued(
+            self.token_service.issue_refresh_token(
+                &identity.id,
+                &self.build_refresh_claims(&identity, &session_id),
+            ).await,
+            "refresh",
+        )?;
 
         tracing::debug!("[ISSUE_SESSION_FOR_IDENTITY] Refresh token issued");
 
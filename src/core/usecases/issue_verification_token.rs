@@ -0,0 +1,103 @@
+//! Use case: IssueVerificationToken
+//!
+//! Orchestrates the first half of the email verification flow.
+//!
+//! Responsibilities:
+//! - Lookup user by id
+//! - Skip issuance when the credential is already active
+//! - Issue a short-lived verification token (`type: "verify"`, unique `jti`) via TokenService
+//! - Return the token for out-of-band delivery (e.g. email)
+
+use crate::core::error::{AuthenticationError, CoreError};
+use crate::core::identity::UserIdentity;
+use crate::core::token::Token;
+use crate::core::usecases::issue_session::ensure_issued;
+use crate::core::usecases::ports::{CredentialRepository, IdentityRepository, RandomSource, TokenService};
+
+/// Input contract for IssueVerificationToken use case.
+pub struct IssueVerificationTokenInput {
+    pub user_id: String,
+}
+
+/// Output contract for IssueVerificationToken use case.
+///
+/// `verification_token` is `None` when the credential is already active, so
+/// there is nothing left to verify.
+#[derive(Debug)]
+pub struct IssueVerificationTokenOutput {
+    pub verification_token: Option<Token>,
+    pub expires_in: u64,
+    pub already_verified: bool,
+}
+
+/// Use case for issuing an email verification token.
+pub struct IssueVerificationToken<'a> {
+    identity_repo: &'a (dyn IdentityRepository + Send + Sync),
+    credential_repo: &'a (dyn CredentialRepository + Send + Sync),
+    token_service: &'a (dyn TokenService + Send + Sync),
+    random: &'a (dyn RandomSource + Send + Sync),
+    verification_token_ttl_secs: u64,
+}
+
+impl<'a> IssueVerificationToken<'a> {
+    /// Create a new IssueVerificationToken use case with dependencies.
+    pub fn new(
+        identity_repo: &'a (dyn IdentityRepository + Send + Sync),
+        credential_repo: &'a (dyn CredentialRepository + Send + Sync),
+        token_service: &'a (dyn TokenService + Send + Sync),
+        random: &'a (dyn RandomSource + Send + Sync),
+        verification_token_ttl_secs: u64,
+    ) -> Self {
+        Self {
+            identity_repo,
+            credential_repo,
+            token_service,
+            random,
+            verification_token_ttl_secs,
+        }
+    }
+
+    /// Execute the verification token issuance use case.
+    pub async fn execute(&self, input: IssueVerificationTokenInput) -> Result<IssueVerificationTokenOutput, CoreError> {
+        // Step 1: Find user by id
+        let user = self
+            .identity_repo
+            .find_by_id(&input.user_id)
+            .await
+            .ok_or_else(|| AuthenticationError::user_not_found("unknown user"))?;
+
+        // Step 2: Nothing to verify once the credential is active
+        if self.credential_repo.get_status(&user.id).await.is_active() {
+            tracing::debug!("[IssueVerificationToken] User {} already verified", user.id);
+            return Ok(IssueVerificationTokenOutput {
+                verification_token: None,
+                expires_in: self.verification_token_ttl_secs,
+                already_verified: true,
+            });
+        }
+
+        // Step 3: Issue verification token
+        let claims = self.build_verification_claims(&user);
+        let verification_token = ensure_issued(
+            self.token_service.issue_verification_token(&user.id, &claims).await,
+            "verify",
+        )?;
+
+        tracing::debug!("[IssueVerificationToken] Verification token issued for user {}", user.id);
+
+        Ok(IssueVerificationTokenOutput {
+            verification_token: Some(verification_token),
+            expires_in: self.verification_token_ttl_secs,
+            already_verified: false,
+        })
+    }
+
+    fn build_verification_claims(&self, user: &UserIdentity) -> String {
+        format!(
+            r#"{{"sub":"{}","type":"verify","exp":{},"jti":"{}"}}"#,
+            user.id,
+            chrono::Utc::now().timestamp() + self.verification_token_ttl_secs as i64,
+            self.random.uuid_v4()
+        )
+    }
+}
@@ -0,0 +1,78 @@
+//! Use case: ListSessionHistory
+//!
+//! Orchestrates the session history view used by support and security
+//! review.
+//!
+//! Responsibilities:
+//! - Page through all of a user's sessions, newest first
+//! - Include revoked and expired sessions with their status
+//! - Bound the page size
+//!
+//! Callers are trusted internal services; this use case does not check
+//! who is asking.
+
+use crate::core::error::{CoreError, InvariantError};
+use crate::core::usecases::ports::{SessionCursor, SessionPage, SessionRepository};
+
+/// Page size used when the caller does not ask for one.
+pub const DEFAULT_SESSION_HISTORY_LIMIT: usize = 20;
+
+/// Largest page a caller may ask for.
+pub const MAX_SESSION_HISTORY_LIMIT: usize = 100;
+
+/// Input contract for ListSessionHistory use case.
+pub struct ListSessionHistoryInput {
+    pub user_id: String,
+    pub cursor: Option<SessionCursor>,
+    pub limit: Option<usize>,
+}
+
+/// Output contract for ListSessionHistory use case.
+#[derive(Debug)]
+pub struct ListSessionHistoryOutput {
+    pub page: SessionPage,
+    /// Page size actually applied
+    pub limit: usize,
+}
+
+/// Use case for paging through a user's session history.
+pub struct ListSessionHistory<'a> {
+    session_repo: &'a (dyn SessionRepository + Send + Sync),
+}
+
+impl<'a> ListSessionHistory<'a> {
+    /// Create a new ListSessionHistory use case with dependencies.
+    pub fn new(session_repo: &'a (dyn SessionRepository + Send + Sync)) -> Self {
+        Self { session_repo }
+    }
+
+    /// Execute the list-session-history use case.
+    pub async fn execute(&self, input: ListSessionHistoryInput) -> Result<ListSessionHistoryOutput, CoreError> {
+        // Step 1: Validate input
+        if input.user_id.is_empty() {
+            return Err(InvariantError::violated("user_id must be provided").into());
+        }
+        if input.limit == Some(0) {
+            return Err(InvariantError::violated("limit must be at least 1").into());
+        }
+        let limit = input
+            .limit
+            .unwrap_or(DEFAULT_SESSION_HISTORY_LIMIT)
+            .min(MAX_SESSION_HISTORY_LIMIT);
+
+        // Step 2: Load one page
+        let page = self
+            .session_repo
+            .list_for_user_paginated(&input.user_id, input.cursor.as_ref(), limit)
+            .await?;
+
+        tracing::debug!(
+            "[ListSessionHistory] Returned {} session(s) for user {} (more: {})",
+            page.sessions.len(),
+            input.user_id,
+            page.next_cursor.is_some()
+        );
+
+        Ok(ListSessionHistoryOutput { page, limit })
+    }
+}
@@ -0,0 +1,67 @@
+//! Use case: ListSessions
+//!
+//! Orchestrates the "where you're logged in" view.
+//!
+//! Responsibilities:
+//! - List the user's active (non-revoked, non-expired) sessions
+//! - Flag which of them made the request
+//!
+//! The user id must come from an authenticated source (validated token
+//! claims); this use case does not check ownership itself.
+
+use crate::core::error::{CoreError, InvariantError};
+use crate::core::usecases::ports::{SessionRepository, SessionSummary};
+
+/// Input contract for ListSessions use case.
+pub struct ListSessionsInput {
+    pub user_id: String,
+    pub current_session_id: Option<String>,
+}
+
+/// Output contract for ListSessions use case.
+#[derive(Debug)]
+pub struct ListSessionsOutput {
+    pub sessions: Vec<SessionSummary>,
+    pub current_session_id: Option<String>,
+}
+
+impl ListSessionsOutput {
+    /// Returns true if the given session made the request
+    pub fn is_current(&self, session: &SessionSummary) -> bool {
+        self.current_session_id.as_deref() == Some(session.session_id.as_str())
+    }
+}
+
+/// Use case for listing a user's active sessions.
+pub struct ListSessions<'a> {
+    session_repo: &'a (dyn SessionRepository + Send + Sync),
+}
+
+impl<'a> ListSessions<'a> {
+    /// Create a new ListSessions use case with dependencies.
+    pub fn new(session_repo: &'a (dyn SessionRepository + Send + Sync)) -> Self {
+        Self { session_repo }
+    }
+
+    /// Execute the list-sessions use case.
+    pub async fn execute(&self, input: ListSessionsInput) -> Result<ListSessionsOutput, CoreError> {
+        // Step 1: Validate input
+        if input.user_id.is_empty() {
+            return Err(InvariantError::violated("user_id must be provided").into());
+        }
+
+        // Step 2: Load active sessions
+        let sessions = self.session_repo.list_active_for_user(&input.user_id).await;
+
+        tracing::debug!(
+            "[ListSessions] Found {} active session(s) for user {}",
+            sessions.len(),
+            input.user_id
+        );
+
+        Ok(ListSessionsOutput {
+            sessions,
+            current_session_id: input.current_session_id,
+        })
+    }
+}
@@ -22,12 +22,28 @@
 //! # Main Use Cases
 //!
 //! - [`AuthenticateUser`]
+//! - [`AuthenticateInWorkspace`]
 //! - [`IssueSession`]
 //! - [`RefreshSession`]
 //! - [`RevokeSession`]
+//! - [`RevokeOtherSessions`]
+//! - [`RevokeAllSessions`]
+//! - [`ListSessions`]
+//! - [`ListSessionHistory`]
 //! - [`ValidateAccessToken`]
+//! - [`IntrospectToken`]
+//! - [`RevokeAccessToken`]
+//! - [`RevokeAllTokens`]
 //! - [`IssueServiceToken`]
 //! - [`IssueSessionForIdentity`]
+//! - [`VerifyTotp`]
+//! - [`RegisterPasskey`]
+//! - [`AuthenticateWithPasskey`]
+//! - [`InitiatePasswordReset`]
+//! - [`CompletePasswordReset`]
+//! - [`ChangePassword`]
+//! - [`IssueVerificationToken`]
+//! - [`ConfirmVerification`]
 //!
 //! # Policies
 //!
@@ -39,23 +55,52 @@
 //! - [`IdentityRepository`]
 //! - [`CredentialRepository`]
 //! - [`SessionRepository`]
+//! - [`SessionMetadataEnricher`]
 //! - [`PasswordHasher`]
 //! - [`TokenService`]
 //! - [`Clock`]
+//! - [`RandomSource`]
+//! - [`BreachChecker`]
+//! - [`StrengthEstimator`]
+//! - [`TotpRepository`]
+//! - [`TotpVerifier`]
+//! - [`PasskeyRepository`]
+//! - [`WebAuthnVerifier`]
+//! - [`ResetTokenStore`]
+//! - [`TokenDenyList`]
+//! - [`TokenWatermarkStore`]
+//! - [`UnitOfWork`]
 
 pub mod authenticate_user;
+pub mod authenticate_in_workspace;
 pub mod issue_session;
 pub mod issue_service_token;
 pub mod issue_session_for_identity;
 pub mod issue_session_for_external_identity;
 pub mod refresh_session;
 pub mod revoke_session;
+pub mod revoke_other_sessions;
+pub mod revoke_all_sessions;
+pub mod list_sessions;
+pub mod list_session_history;
 pub mod validate_access_token;
+pub mod introspect_token;
+pub mod revoke_access_token;
+pub mod revoke_all_tokens;
+pub mod verify_totp;
+pub mod register_passkey;
+pub mod authenticate_with_passkey;
+pub mod initiate_password_reset;
+pub mod complete_password_reset;
+pub mod change_password;
+pub mod issue_verification_token;
+pub mod confirm_verification;
 
 pub mod policies;
 pub mod ports;
 
 pub use authenticate_user::*;
+pub use authenticate_in_workspace::*;
 pub mod exchange_google_code;
 pub use issue_session::*;
 pub use issue_service_token::*;
@@ -63,7 +108,22 @@ pub use issue_session_for_identity::*;
 pub use issue_session_for_external_identity::*;
 pub use refresh_session::*;
 pub use revoke_session::*;
+pub use revoke_other_sessions::*;
+pub use revoke_all_sessions::*;
+pub use list_sessions::*;
+pub use list_session_history::*;
 pub use validate_access_token::*;
+pub use introspect_token::*;
+pub use revoke_access_token::*;
+pub use revoke_all_tokens::*;
+pub use verify_totp::*;
+pub use register_passkey::*;
+pub use authenticate_with_passkey::*;
+pub use initiate_password_reset::*;
+pub use complete_password_reset::*;
+pub use change_password::*;
+pub use issue_verification_token::*;
+pub use confirm_verification::*;
 
 pub use policies::*;
 pub use ports::*;
@@ -0,0 +1,158 @@
+//! Typed configuration consumed by the authentication use cases.
+//!
+//! Groups the lockout policy, token lifetimes and password hashing
+//! parameters that use cases otherwise receive as loose integers, and
+//! checks that they make sense together before the service starts.
+//!
+//! Policy is injected as a configuration object, not hardcoded.
+
+use crate::core::error::InvariantError;
+
+use super::{LockoutBackoff, LockoutPolicy};
+
+/// Seconds in one day, for comparing TTLs given in different units.
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Token lifetimes and refresh rotation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenLifetimes {
+	pub access_ttl_secs: u64,
+	pub refresh_ttl_days: u64,
+	pub service_ttl_secs: u64,
+	pub rotate_refresh_tokens: bool,
+	/// Seconds a rotated-out refresh token may still be exchanged for a new successor.
+	pub refresh_rotation_grace_secs: u64,
+}
+
+impl TokenLifetimes {
+	/// Create token lifetimes.
+	pub fn new(access_ttl_secs: u64, refresh_ttl_days: u64, service_ttl_secs: u64, rotate_refresh_tokens: bool) -> Self {
+		Self {
+			access_ttl_secs,
+			refresh_ttl_days,
+			service_ttl_secs,
+			rotate_refresh_tokens,
+			refresh_rotation_grace_secs: 0,
+		}
+	}
+
+	/// Let a rotated-out refresh token be retried for `grace_secs`.
+	///
+	/// Zero (the default) makes every second use of a token count as reuse.
+	pub fn with_rotation_grace(mut self, grace_secs: u64) -> Self {
+		self.refresh_rotation_grace_secs = grace_secs;
+		self
+	}
+
+	/// Returns the refresh token TTL in seconds.
+	pub fn refresh_ttl_secs(&self) -> u64 {
+		self.refresh_ttl_days.saturating_mul(SECS_PER_DAY)
+	}
+}
+
+/// Argon2 password hashing parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HasherParams {
+	pub memory_cost_kib: u32,
+	pub iterations: u32,
+	pub parallelism: u32,
+}
+
+impl HasherParams {
+	/// Create hashing parameters.
+	pub fn new(memory_cost_kib: u32, iterations: u32, parallelism: u32) -> Self {
+		Self {
+			memory_cost_kib,
+			iterations,
+			parallelism,
+		}
+	}
+}
+
+impl Default for HasherParams {
+	/// 64 MiB, 3 passes, 4 lanes.
+	fn default() -> Self {
+		Self::new(65536, 3, 4)
+	}
+}
+
+/// Configuration handed to the authentication use cases.
+#[derive(Debug, Clone)]
+pub struct AuthPolicyConfig {
+	pub lockout: LockoutPolicy,
+	pub tokens: TokenLifetimes,
+	pub hasher: HasherParams,
+}
+
+impl AuthPolicyConfig {
+	/// Create a configuration from its parts.
+	pub fn new(lockout: LockoutPolicy, tokens: TokenLifetimes, hasher: HasherParams) -> Self {
+		Self { lockout, tokens, hasher }
+	}
+
+	/// Reject combinations that cannot work at runtime.
+	///
+	/// # Errors
+	/// Returns `InvariantError::InvalidConfiguration` describing the first
+	/// problem found.
+	pub fn validate(&self) -> Result<(), InvariantError> {
+		let invalid = |reason: String| Err(InvariantError::invalid_configuration(reason));
+
+		if self.lockout.max_attempts == 0 {
+			return invalid("lockout max attempts must be at least 1".to_string());
+		}
+		if self.lockout.lock_duration_secs == 0 {
+			return invalid("lockout duration must be greater than 0 seconds".to_string());
+		}
+		let backoff_cap = match self.lockout.backoff {
+			LockoutBackoff::Fixed => None,
+			LockoutBackoff::Exponential { max_lock_duration_secs } => Some(max_lock_duration_secs),
+		};
+		if let Some(cap) = backoff_cap.filter(|cap| *cap < self.lockout.lock_duration_secs) {
+			return invalid(format!(
+				"lockout backoff cap ({}s) must not be shorter than the lock duration ({}s)",
+				cap, self.lockout.lock_duration_secs
+			));
+		}
+
+		if self.tokens.access_ttl_secs == 0 {
+			return invalid("access token TTL must be greater than 0 seconds".to_string());
+		}
+		if self.tokens.refresh_ttl_days == 0 {
+			return invalid("refresh token TTL must be at least 1 day".to_string());
+		}
+		if self.tokens.access_ttl_secs >= self.tokens.refresh_ttl_secs() {
+			return invalid(format!(
+				"access token TTL ({}s) must be shorter than refresh token TTL ({}s)",
+				self.tokens.access_ttl_secs,
+				self.tokens.refresh_ttl_secs()
+			));
+		}
+		if self.tokens.refresh_rotation_grace_secs >= self.tokens.access_ttl_secs {
+			return invalid(format!(
+				"refresh rotation grace ({}s) must be shorter than access token TTL ({}s)",
+				self.tokens.refresh_rotation_grace_secs,
+				self.tokens.access_ttl_secs
+			));
+		}
+		if self.tokens.service_ttl_secs == 0 {
+			return invalid("service token TTL must be greater than 0 seconds".to_string());
+		}
+
+		if self.hasher.iterations == 0 {
+			return invalid("password hash iterations must be at least 1".to_string());
+		}
+		if self.hasher.parallelism == 0 {
+			return invalid("password hash parallelism must be at least 1".to_string());
+		}
+		// Argon2 needs at least 8 KiB of memory per lane
+		if u64::from(self.hasher.memory_cost_kib) < 8 * u64::from(self.hasher.parallelism) {
+			return invalid(format!(
+				"password hash memory cost ({} KiB) must be at least 8 KiB per lane ({} lanes)",
+				self.hasher.memory_cost_kib, self.hasher.parallelism
+			));
+		}
+
+		Ok(())
+	}
+}
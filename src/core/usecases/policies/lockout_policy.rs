@@ -4,12 +4,47 @@
 //!
 //! Policy is injected as a configuration object, not hardcoded.
 
+/// How the lock duration grows with repeated lockouts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockoutBackoff {
+	/// Every lockout lasts `lock_duration_secs`.
+	Fixed,
+	/// Each failure past the threshold doubles the lock, up to a cap.
+	Exponential { max_lock_duration_secs: u64 },
+}
+
+/// What failed attempts are counted against.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LockoutScope {
+	/// One counter per account, whatever the source. Failures from anywhere
+	/// lock the account for everyone.
+	#[default]
+	Account,
+	/// One counter per account and source IP. Failures from one address
+	/// only lock out that address; attempts without a known source address
+	/// count against the account. Failures from every address still add up
+	/// to the account ceiling, which locks the account for everyone.
+	AccountAndSourceIp,
+}
+
 /// Lockout policy configuration.
 #[derive(Debug, Clone)]
 pub struct LockoutPolicy {
 	pub max_attempts: u32,
 	pub lock_duration_secs: u64,
 	pub reset_on_success: bool,
+	pub backoff: LockoutBackoff,
+	/// Whether a correct password after the lock window has passed clears the
+	/// stale lock and resets attempts, even when `reset_on_success` is off.
+	/// When off, the lock only ever lifts with time.
+	pub unlock_on_successful_auth: bool,
+	pub scope: LockoutScope,
+	/// Seconds after the last failure at which the failed-attempt counter
+	/// starts over; 0 keeps failures until a success or a lockout.
+	pub failure_reset_window_secs: u64,
+	/// Failed attempts across all sources that lock the whole account when
+	/// counting per source; defaults to four times `max_attempts`.
+	pub account_ceiling: u32,
 }
 
 impl LockoutPolicy {
@@ -19,9 +54,46 @@ impl LockoutPolicy {
 			max_attempts,
 			lock_duration_secs,
 			reset_on_success,
+			backoff: LockoutBackoff::Fixed,
+			unlock_on_successful_auth: false,
+			scope: LockoutScope::Account,
+			failure_reset_window_secs: 0,
+			account_ceiling: max_attempts.saturating_mul(4),
 		}
 	}
 
+	/// Escalate lock durations exponentially, capped at `max_lock_duration_secs`.
+	pub fn with_exponential_backoff(mut self, max_lock_duration_secs: u64) -> Self {
+		self.backoff = LockoutBackoff::Exponential { max_lock_duration_secs };
+		self
+	}
+
+	/// Clear the lock and reset attempts on the first successful login after
+	/// the lock window has passed.
+	pub fn with_unlock_on_successful_auth(mut self, enabled: bool) -> Self {
+		self.unlock_on_successful_auth = enabled;
+		self
+	}
+
+	/// Count failed attempts per `scope`.
+	pub fn with_scope(mut self, scope: LockoutScope) -> Self {
+		self.scope = scope;
+		self
+	}
+
+	/// Forget failed attempts once the last one is older than `window_secs`.
+	pub fn with_failure_reset_window(mut self, window_secs: u64) -> Self {
+		self.failure_reset_window_secs = window_secs;
+		self
+	}
+
+	/// Lock the whole account after `max_attempts` failures across all
+	/// sources when counting per source.
+	pub fn with_account_ceiling(mut self, max_attempts: u32) -> Self {
+		self.account_ceiling = max_attempts;
+		self
+	}
+
 	/// Returns true if the failed attempts exceed the max allowed.
 	pub fn is_locked(&self, failed_attempts: u32) -> bool {
 		failed_attempts >= self.max_attempts
@@ -32,8 +104,65 @@ impl LockoutPolicy {
 		self.lock_duration_secs
 	}
 
+	/// Returns the lock duration in seconds for the given failed-attempt count.
+	///
+	/// With exponential backoff this is `base * 2^(failed_attempts - max_attempts)`,
+	/// capped at the configured maximum. Returns 0 below the threshold.
+	pub fn lock_duration_for(&self, failed_attempts: u32) -> u64 {
+		self.lock_duration_past(self.max_attempts, failed_attempts)
+	}
+
+	/// Returns true if failures across all sources reached the account ceiling.
+	pub fn is_account_ceiling_reached(&self, failed_attempts: u32) -> bool {
+		failed_attempts >= self.account_ceiling
+	}
+
+	/// Returns the account lock duration in seconds for the given count of
+	/// failures across all sources, escalating past the ceiling like
+	/// [`lock_duration_for`](Self::lock_duration_for) does past `max_attempts`.
+	pub fn account_lock_duration_for(&self, failed_attempts: u32) -> u64 {
+		self.lock_duration_past(self.account_ceiling, failed_attempts)
+	}
+
+	fn lock_duration_past(&self, threshold: u32, failed_attempts: u32) -> u64 {
+		if failed_attempts < threshold {
+			return 0;
+		}
+
+		match self.backoff {
+			LockoutBackoff::Fixed => self.lock_duration_secs,
+			LockoutBackoff::Exponential { max_lock_duration_secs } => {
+				let overage = failed_attempts - threshold;
+				2u64.checked_pow(overage)
+					.and_then(|factor| self.lock_duration_secs.checked_mul(factor))
+					.unwrap_or(u64::MAX)
+					.min(max_lock_duration_secs)
+			}
+		}
+	}
+
 	/// Returns true if failed attempts should be reset on successful login.
 	pub fn should_reset_on_success(&self) -> bool {
 		self.reset_on_success
 	}
+
+	/// Returns true if a successful login should clear an expired lock.
+	pub fn should_unlock_on_success(&self) -> bool {
+		self.unlock_on_successful_auth
+	}
+
+	/// Returns true if a failure `seconds_since_failure` ago no longer counts
+	/// towards a lockout.
+	///
+	/// Always false without a reset window. A failure recorded in the future
+	/// (clock skew) still counts.
+	pub fn is_failure_stale(&self, seconds_since_failure: i64) -> bool {
+		self.failure_reset_window_secs > 0
+			&& u64::try_from(seconds_since_failure).is_ok_and(|elapsed| elapsed > self.failure_reset_window_secs)
+	}
+
+	/// Returns what failed attempts are counted against.
+	pub fn scope(&self) -> LockoutScope {
+		self.scope
+	}
 }
@@ -1,11 +1,16 @@
 //! Policy configuration and business rules for authentication use cases.
 //!
-//! This module defines injectable policy objects for lockout, token lifetime, and session rotation.
+//! This module defines injectable policy objects for lockout, token lifetime, session rotation and session binding,
+//! and the typed configuration that groups them for the use cases.
 //!
 //! Policies are configuration objects, not hardcoded values.
 
+pub mod auth_policy_config;
 pub mod lockout_policy;
+pub mod session_binding_policy;
 pub mod token_policy;
 
-pub use lockout_policy::LockoutPolicy;
+pub use auth_policy_config::{AuthPolicyConfig, HasherParams, TokenLifetimes};
+pub use lockout_policy::{LockoutBackoff, LockoutPolicy, LockoutScope};
+pub use session_binding_policy::{BindingMismatch, IpBinding, SessionBindingPolicy};
 pub use token_policy::TokenPolicy;
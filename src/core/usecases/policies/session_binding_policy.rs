@@ -0,0 +1,118 @@
+//! Binding of refresh tokens to the client that obtained them.
+//!
+//! A session records the client IP and user agent it was issued to. When
+//! binding is enabled, a refresh presented from a different client is
+//! rejected, so a stolen refresh token cannot be replayed from elsewhere.
+//!
+//! Policy is injected as a configuration object, not hardcoded.
+
+use std::net::IpAddr;
+
+/// IPv4 prefix length compared by [`IpBinding::subnet`].
+pub const DEFAULT_IPV4_PREFIX: u8 = 24;
+
+/// IPv6 prefix length compared by [`IpBinding::subnet`].
+pub const DEFAULT_IPV6_PREFIX: u8 = 64;
+
+/// How strictly the client IP must match the one the session was issued to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IpBinding {
+	/// The IP is not checked.
+	#[default]
+	Off,
+	/// The IP must fall in the same network as the stored one. Tolerates
+	/// mobile clients hopping between addresses of the same carrier.
+	Subnet { ipv4_prefix: u8, ipv6_prefix: u8 },
+	/// The IP must be identical.
+	Exact,
+}
+
+impl IpBinding {
+	/// Subnet binding with the default prefixes (/24 for IPv4, /64 for IPv6).
+	pub fn subnet() -> Self {
+		Self::Subnet {
+			ipv4_prefix: DEFAULT_IPV4_PREFIX,
+			ipv6_prefix: DEFAULT_IPV6_PREFIX,
+		}
+	}
+
+	/// Returns true if `presented` is acceptable for a session issued to `stored`.
+	///
+	/// Addresses of different families never match. Values that are not IP
+	/// addresses (e.g. the unknown-client placeholder) only match themselves.
+	pub fn matches(&self, stored: &str, presented: &str) -> bool {
+		let (ipv4_prefix, ipv6_prefix) = match *self {
+			IpBinding::Off => return true,
+			IpBinding::Exact => (32, 128),
+			IpBinding::Subnet { ipv4_prefix, ipv6_prefix } => (ipv4_prefix, ipv6_prefix),
+		};
+
+		match (stored.parse::<IpAddr>(), presented.parse::<IpAddr>()) {
+			(Ok(stored), Ok(presented)) => match (stored.to_canonical(), presented.to_canonical()) {
+				(IpAddr::V4(a), IpAddr::V4(b)) => {
+					same_network(u32::from(a) as u128, u32::from(b) as u128, 32, ipv4_prefix)
+				}
+				(IpAddr::V6(a), IpAddr::V6(b)) => same_network(u128::from(a), u128::from(b), 128, ipv6_prefix),
+				_ => false,
+			},
+			_ => stored == presented,
+		}
+	}
+}
+
+/// Compare the leading `prefix` bits of two `width`-bit addresses.
+fn same_network(a: u128, b: u128, width: u32, prefix: u8) -> bool {
+	let ignored = width - u32::from(prefix).min(width);
+	a.checked_shr(ignored).unwrap_or(0) == b.checked_shr(ignored).unwrap_or(0)
+}
+
+/// Why a refresh was refused by the binding policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingMismatch {
+	IpAddress,
+	UserAgent,
+}
+
+/// Session binding policy configuration.
+///
+/// Disabled by default: refresh tokens are accepted from any client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SessionBindingPolicy {
+	pub ip: IpBinding,
+	/// Whether the user agent must match the stored one exactly.
+	pub user_agent: bool,
+}
+
+impl SessionBindingPolicy {
+	/// Create a new session binding policy.
+	pub fn new(ip: IpBinding, user_agent: bool) -> Self {
+		Self { ip, user_agent }
+	}
+
+	/// A policy that accepts refreshes from any client.
+	pub fn disabled() -> Self {
+		Self::default()
+	}
+
+	/// Returns true if any check is active.
+	pub fn is_enabled(&self) -> bool {
+		self.ip != IpBinding::Off || self.user_agent
+	}
+
+	/// Check a presented client against the one stored with the session.
+	pub fn check(
+		&self,
+		stored_ip: &str,
+		stored_user_agent: &str,
+		ip_address: &str,
+		user_agent: &str,
+	) -> Result<(), BindingMismatch> {
+		if self.user_agent && stored_user_agent != user_agent {
+			return Err(BindingMismatch::UserAgent);
+		}
+		if !self.ip.matches(stored_ip, ip_address) {
+			return Err(BindingMismatch::IpAddress);
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,121 @@
+//! Port for the security audit trail.
+//!
+//! Abstracts where security-relevant outcomes (logins, lockouts, password
+//! changes, revocations) are recorded for compliance.
+//!
+//! Adapters must implement this trait to provide an append-only sink.
+//! Events never carry secrets: no passwords, hashes or token material.
+
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+
+/// Kind of security event being recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditEventType {
+	/// Password authentication succeeded
+	LoginSucceeded,
+	/// Password authentication failed
+	LoginFailed,
+	/// Too many failures locked the account
+	AccountLocked,
+	/// An operator cleared the lock on an account
+	AccountUnlocked,
+	/// A user's password was replaced
+	PasswordChanged,
+	/// Sessions were revoked outside of normal expiry
+	SessionsRevoked,
+}
+
+impl AuditEventType {
+	/// Stable snake_case name, as written to the audit trail.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			AuditEventType::LoginSucceeded => "login_succeeded",
+			AuditEventType::LoginFailed => "login_failed",
+			AuditEventType::AccountLocked => "account_locked",
+			AuditEventType::AccountUnlocked => "account_unlocked",
+			AuditEventType::PasswordChanged => "password_changed",
+			AuditEventType::SessionsRevoked => "sessions_revoked",
+		}
+	}
+}
+
+/// Whether the audited operation succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditOutcome {
+	Success,
+	Failure,
+}
+
+impl AuditOutcome {
+	/// Stable snake_case name, as written to the audit trail.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			AuditOutcome::Success => "success",
+			AuditOutcome::Failure => "failure",
+		}
+	}
+}
+
+/// A single entry of the audit trail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEvent {
+	/// User id when known, otherwise the identifier that was presented
+	pub actor: String,
+	pub event_type: AuditEventType,
+	pub timestamp: DateTime<Utc>,
+	pub outcome: AuditOutcome,
+	/// Short non-secret explanation, e.g. why a login failed
+	pub reason: Option<String>,
+	pub source_ip: Option<String>,
+}
+
+impl AuditEvent {
+	/// Create an event without reason or source address.
+	pub fn new(
+		actor: impl Into<String>,
+		event_type: AuditEventType,
+		timestamp: DateTime<Utc>,
+		outcome: AuditOutcome,
+	) -> Self {
+		Self {
+			actor: actor.into(),
+			event_type,
+			timestamp,
+			outcome,
+			reason: None,
+			source_ip: None,
+		}
+	}
+
+	/// Attach a non-secret explanation.
+	pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+		self.reason = Some(reason.into());
+		self
+	}
+
+	/// Attach the client address the operation came from.
+	pub fn with_source_ip(mut self, source_ip: Option<String>) -> Self {
+		self.source_ip = source_ip;
+		self
+	}
+}
+
+/// Contract for recording audit events.
+pub trait AuditSink: Send + Sync {
+	/// Append an event to the audit trail.
+	fn record(&self, event: AuditEvent) -> BoxFuture<'_, Result<(), String>>;
+
+	/// Append an event, logging instead of propagating a failure.
+	///
+	/// Use cases call this so an unavailable sink never blocks the
+	/// operation being audited.
+	fn record_or_log(&self, event: AuditEvent) -> BoxFuture<'_, ()> {
+		Box::pin(async move {
+			let event_type = event.event_type;
+			if let Err(e) = self.record(event).await {
+				tracing::error!("[AUDIT] Failed to record {} event: {}", event_type.as_str(), e);
+			}
+		})
+	}
+}
@@ -0,0 +1,19 @@
+//! Port for breached-password lookup.
+//!
+//! Abstracts checks of a secret against known data breaches (e.g. Have I
+//! Been Pwned) so credential policy can reject compromised passwords without
+//! the core doing any IO.
+//!
+//! Adapters must never send the plaintext secret off the machine; see
+//! `HibpBreachChecker` for the k-anonymity scheme used in production.
+
+use futures::future::BoxFuture;
+
+/// Contract for breached-password lookup.
+pub trait BreachChecker {
+	/// Whether `secret` is known to appear in a data breach.
+	///
+	/// # Errors
+	/// Returns an error if the breach source cannot be consulted.
+	fn is_compromised<'a>(&'a self, secret: &'a str) -> BoxFuture<'a, Result<bool, String>>;
+}
@@ -5,22 +5,205 @@
 //! Adapters must implement this trait to provide persistence or external credential management.
 
 use futures::future::BoxFuture;
-use crate::core::credentials::StoredCredential;
+use crate::core::credentials::{CredentialStatus, StoredCredential};
+use crate::core::error::CredentialError;
+use crate::core::usecases::ports::PasswordHasher;
+
+/// Failed attempts and lock of one lockout counter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LockoutState {
+	pub failed_attempts: u32,
+	/// Lock expiry as RFC3339, if locked
+	pub locked_until: Option<String>,
+	/// Time of the last counted failure as RFC3339, if recorded
+	pub last_failed_at: Option<String>,
+}
 
 /// Contract for credential repository access.
 pub trait CredentialRepository: Send + Sync {
 	/// Get the stored credential for a user by user id.
 	fn get_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<StoredCredential>>;
 
+	/// Get the lifecycle status of the user's credential.
+	///
+	/// The default reads the status carried by the stored credential, so
+	/// stores that do not track validity windows treat every credential as
+	/// active. A missing credential also reads as active; it fails password
+	/// verification anyway.
+	fn get_status(&self, user_id: &str) -> BoxFuture<'_, CredentialStatus> {
+		let credential = self.get_by_user_id(user_id);
+		Box::pin(async move {
+			credential
+				.await
+				.map(|credential| credential.status)
+				.unwrap_or(CredentialStatus::Active)
+		})
+	}
+
+	/// Persist a new lifecycle status for the user's credential.
+	///
+	/// The default reports the operation as unsupported, for stores that do
+	/// not track credential status.
+	fn set_status(&self, _user_id: &str, _status: CredentialStatus) -> BoxFuture<'_, Result<(), String>> {
+		Box::pin(async move { Err("Credential status is not supported by this repository".to_string()) })
+	}
+
 	/// Update the failed login attempts counter for a user.
 	fn update_failed_attempts(&self, user_id: &str, attempts: u32) -> BoxFuture<'_, ()>;
 
 	/// Lock the user account until a given timestamp (as RFC3339 string or epoch seconds).
 	fn lock_until(&self, user_id: &str, until: &str) -> BoxFuture<'_, ()>;
 
+	/// Clear any lock on the account and reset its failed attempts.
+	///
+	/// The default resets the counter only, for stores that drop the lock
+	/// together with it.
+	fn clear_lock(&self, user_id: &str) -> BoxFuture<'_, ()> {
+		self.update_failed_attempts(user_id, 0)
+	}
+
+	/// Unlock the account on an operator's request.
+	///
+	/// Resets failed attempts to 0 and clears `locked_until`, for the account
+	/// and every source address. Unlocking an account that is not locked
+	/// succeeds and changes nothing. The default goes through `clear_lock`,
+	/// which cannot report a failure.
+	///
+	/// # Errors
+	/// Returns an error if the store cannot be updated.
+	fn unlock(&self, user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+		let cleared = self.clear_lock(user_id);
+		Box::pin(async move {
+			cleared.await;
+			Ok(())
+		})
+	}
+
+	/// Get the failed attempts and lock counted for logins from `source_ip`.
+	///
+	/// Backs per-source lockout. The default reads the account-wide state,
+	/// so stores that do not track sources fall back to per-account lockout
+	/// rather than to none.
+	fn get_source_lockout(&self, user_id: &str, _source_ip: &str) -> BoxFuture<'_, LockoutState> {
+		let credential = self.get_by_user_id(user_id);
+		Box::pin(async move {
+			credential
+				.await
+				.map(|credential| LockoutState {
+					failed_attempts: credential.failed_attempts,
+					locked_until: credential.locked_until,
+					last_failed_at: credential.last_failed_at,
+				})
+				.unwrap_or_default()
+		})
+	}
+
+	/// Whether any source address has failed attempts or a lock counted
+	/// against the account.
+	///
+	/// The default reports none, for stores that do not track sources.
+	fn has_source_lockouts(&self, _user_id: &str) -> BoxFuture<'_, bool> {
+		Box::pin(async move { false })
+	}
+
+	/// Update the failed attempts counted for logins from `source_ip`.
+	///
+	/// Setting the counter to 0 also clears the lock of that source. The
+	/// default updates the account-wide counter.
+	fn update_source_failed_attempts(&self, user_id: &str, _source_ip: &str, attempts: u32) -> BoxFuture<'_, ()> {
+		self.update_failed_attempts(user_id, attempts)
+	}
+
+	/// Lock logins from `source_ip` until a given timestamp (RFC3339).
+	///
+	/// The default locks the whole account.
+	fn lock_source_until(&self, user_id: &str, _source_ip: &str, until: &str) -> BoxFuture<'_, ()> {
+		self.lock_until(user_id, until)
+	}
+
+	/// Record a successful login at `at` (RFC3339).
+	///
+	/// The value is read back as `StoredCredential::last_login_at`. The
+	/// default records nothing, for stores that do not track logins.
+	fn record_successful_login(&self, _user_id: &str, _at: &str) -> BoxFuture<'_, ()> {
+		Box::pin(async move {})
+	}
+
+	/// Record a failed login counted against the account at `at` (RFC3339).
+	///
+	/// The value is read back as `StoredCredential::last_failed_at` and lets
+	/// old failures expire. The default records nothing, so failures never
+	/// expire in stores that do not track them.
+	fn record_failed_login(&self, _user_id: &str, _at: &str) -> BoxFuture<'_, ()> {
+		Box::pin(async move {})
+	}
+
+	/// Record a failed login counted against `source_ip` at `at` (RFC3339).
+	///
+	/// Read back as `LockoutState::last_failed_at` of that source. The
+	/// default records it against the account.
+	fn record_source_failed_login(&self, user_id: &str, _source_ip: &str, at: &str) -> BoxFuture<'_, ()> {
+		self.record_failed_login(user_id, at)
+	}
+
 	/// Update the user's password to a new stored credential.
 	fn update_password(&self, user_id: &str, new_credential: StoredCredential) -> BoxFuture<'_, ()>;
 
+	/// The user's previous credentials, newest first, at most `limit`.
+	///
+	/// The current credential is not part of the history. Default: no
+	/// history is kept.
+	fn password_history(&self, _user_id: &str, _limit: usize) -> BoxFuture<'_, Vec<StoredCredential>> {
+		Box::pin(async move { Vec::new() })
+	}
+
+	/// Remember `previous` as the user's most recent former credential.
+	///
+	/// Keeps at most `depth` entries, dropping the oldest. Default:
+	/// remembers nothing.
+	fn push_password_history(
+		&self,
+		_user_id: &str,
+		_previous: StoredCredential,
+		_depth: usize,
+	) -> BoxFuture<'_, Result<(), String>> {
+		Box::pin(async move { Ok(()) })
+	}
+
+	/// Reject `candidate` if it matches the user's current credential or any
+	/// of the last `depth` ones.
+	///
+	/// Compares through `PasswordHasher::verify`, since every stored hash
+	/// carries its own salt. A `depth` of zero checks nothing.
+	///
+	/// # Errors
+	/// Returns `CredentialError::Reused` on a match.
+	fn check_password_history<'a>(
+		&'a self,
+		user_id: &'a str,
+		candidate: &'a str,
+		password_hasher: &'a (dyn PasswordHasher + Send + Sync),
+		depth: usize,
+	) -> BoxFuture<'a, Result<(), CredentialError>> {
+		Box::pin(async move {
+			if depth == 0 {
+				return Ok(());
+			}
+
+			let current = self.get_by_user_id(user_id).await;
+			let history = self.password_history(user_id, depth).await;
+			if current
+				.iter()
+				.chain(history.iter())
+				.any(|stored| password_hasher.verify(candidate, stored))
+			{
+				return Err(CredentialError::reused());
+			}
+
+			Ok(())
+		})
+	}
+
 	/// Initialize credential state for a new user.
 	///
 	/// Sets failed_attempts to 0 and no lock.
@@ -0,0 +1,17 @@
+//! Port for login identifier normalization.
+//!
+//! Abstracts how a login identifier is brought into canonical form before
+//! it is stored or looked up, so that `" User@Example.com"` and
+//! `"user@example.com"` name the same account.
+//!
+//! Adapters must implement this trait to provide concrete normalization rules.
+//! The same implementation must serve both writes and lookups; otherwise
+//! stored identifiers stop matching the ones presented at login.
+
+/// Contract for login identifier normalization.
+pub trait IdentifierNormalizer {
+	/// Returns the canonical form of `identifier`.
+	///
+	/// Must be idempotent: normalizing a normalized identifier changes nothing.
+	fn normalize(&self, identifier: &str) -> String;
+}
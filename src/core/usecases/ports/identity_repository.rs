@@ -5,7 +5,29 @@
 //! Adapters must implement this trait to provide persistence or external identity resolution.
 
 use futures::future::BoxFuture;
-use crate::core::identity::UserIdentity;
+use serde_json::{Map, Value};
+use crate::core::identity::{UserIdentity, WorkspaceIdentity};
+
+/// One identity to create as part of a batch.
+#[derive(Debug, Clone)]
+pub struct NewIdentity {
+	pub user_id: uuid::Uuid,
+	pub identifier: String,
+	pub password_hash: String,
+}
+
+/// Per-item outcome of [`IdentityRepository::create_batch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchCreateOutcome {
+	/// The identity was created (and committed, in atomic mode)
+	Created,
+	/// The identifier or user id is already taken
+	Conflict(String),
+	/// Persistence failed for another reason
+	Failed(String),
+	/// Not written because another item failed an atomic batch
+	RolledBack,
+}
 
 /// Contract for identity repository access.
 pub trait IdentityRepository: Send + Sync {
@@ -15,6 +37,54 @@ pub trait IdentityRepository: Send + Sync {
 	/// Find a user identity by its unique id.
 	fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>>;
 
+	/// The identifier (e.g. email or username) of a user, by id.
+	///
+	/// Lets password rules reject secrets built from the account name. The
+	/// default knows no identifiers and returns `None`.
+	fn find_identifier_by_id(&self, _id: &str) -> BoxFuture<'_, Option<String>> {
+		Box::pin(async { None })
+	}
+
+	/// Current application claims (roles, tenant, ...) for a user.
+	///
+	/// Read when a refresh is configured to re-assemble claims instead of
+	/// copying them from the refresh token. Returns `None` if the user no
+	/// longer exists. The default knows no application claims and returns an
+	/// empty map for any existing user.
+	fn find_claims_by_id(&self, id: &str) -> BoxFuture<'_, Option<Map<String, Value>>> {
+		let lookup = self.find_by_id(id);
+		Box::pin(async move { lookup.await.map(|_| Map::new()) })
+	}
+
+	/// Whether a live identity with this identifier exists.
+	///
+	/// Cheaper than [`find_by_identifier`](Self::find_by_identifier) when the
+	/// row itself is not needed (signup "already taken" hints, internal
+	/// preconditions). An answer to "is this email registered?" is an
+	/// account-enumeration oracle, so anything reachable from outside must sit
+	/// behind service authentication or rate limiting.
+	///
+	/// The default implementation falls back to a full lookup.
+	fn exists(&self, identifier: &str) -> BoxFuture<'_, bool> {
+		let lookup = self.find_by_identifier(identifier);
+		Box::pin(async move { lookup.await.is_some() })
+	}
+
+	/// Find a workspace by its unique id.
+	///
+	/// The default knows no workspaces, so stores without a workspace
+	/// dimension reject every workspace-scoped login.
+	fn find_workspace_by_id(&self, _workspace_id: &str) -> BoxFuture<'_, Option<WorkspaceIdentity>> {
+		Box::pin(async { None })
+	}
+
+	/// Whether the user is a member of the workspace.
+	///
+	/// The default treats nobody as a member.
+	fn is_workspace_member(&self, _user_id: &str, _workspace_id: &str) -> BoxFuture<'_, bool> {
+		Box::pin(async { false })
+	}
+
 	/// Create a new identity with the given credentials.
 	///
 	/// # Arguments
@@ -36,4 +106,76 @@ pub trait IdentityRepository: Send + Sync {
 		algorithm: &str,
 		iterations: u32,
 	) -> BoxFuture<'_, Result<(), String>>;
+
+	/// Create several identities, returning one outcome per input, in order.
+	///
+	/// With `atomic` set, either every identity is created or none is: after
+	/// the first failure the batch is rolled back and every other item
+	/// reports [`BatchCreateOutcome::RolledBack`]. Each created identity's
+	/// credential state is initialized in the same transaction, as by
+	/// [`CredentialRepository::initialize_credential_state`](super::CredentialRepository::initialize_credential_state).
+	/// Otherwise each item stands on its own and the caller initializes it.
+	///
+	/// The default creates items one by one and cannot roll back, so it only
+	/// supports non-atomic batches.
+	///
+	/// # Errors
+	/// Returns an error if atomic mode is unsupported or the batch as a whole
+	/// could not be committed.
+	fn create_batch(
+		&self,
+		identities: Vec<NewIdentity>,
+		atomic: bool,
+	) -> BoxFuture<'_, Result<Vec<BatchCreateOutcome>, String>> {
+		Box::pin(async move {
+			if atomic {
+				return Err("atomic create_batch not supported".to_string());
+			}
+
+			let mut outcomes = Vec::with_capacity(identities.len());
+			for identity in identities {
+				let outcome = if self.exists(&identity.identifier).await {
+					BatchCreateOutcome::Conflict("identifier already exists".to_string())
+				} else {
+					match self
+						.create(&identity.user_id, &identity.identifier, &identity.password_hash, "", "", 0)
+						.await
+					{
+						Ok(()) => BatchCreateOutcome::Created,
+						Err(e) => BatchCreateOutcome::Failed(e),
+					}
+				};
+				outcomes.push(outcome);
+			}
+			Ok(outcomes)
+		})
+	}
+
+	/// Change the unique identifier (username/email) of a user.
+	///
+	/// # Errors
+	/// Returns an error if the new identifier is taken or the store does not
+	/// support identity mutation.
+	fn update_identifier(&self, _user_id: &str, _new_identifier: &str) -> BoxFuture<'_, Result<(), String>> {
+		Box::pin(async { Err("update_identifier not supported".to_string()) })
+	}
+
+	/// Soft-delete a user so it can no longer be looked up.
+	///
+	/// Implementations must revoke (not delete) every session of the user in
+	/// the same unit of work, so no session outlives its identity.
+	///
+	/// # Errors
+	/// Returns an error if the store does not support identity mutation.
+	fn soft_delete(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+		Box::pin(async { Err("soft_delete not supported".to_string()) })
+	}
+
+	/// Enable or disable a user.
+	///
+	/// # Errors
+	/// Returns an error if the store does not support identity mutation.
+	fn set_enabled(&self, _user_id: &str, _enabled: bool) -> BoxFuture<'_, Result<(), String>> {
+		Box::pin(async { Err("set_enabled not supported".to_string()) })
+	}
 }
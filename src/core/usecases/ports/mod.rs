@@ -6,26 +6,58 @@
 //! Adapters must implement these traits to provide concrete behavior.
 
 pub mod identity_repository;
+pub mod identifier_normalizer;
 pub mod external_identity_repository;
 pub mod credential_repository;
 pub mod session_repository;
+pub mod session_metadata_enricher;
 pub mod password_hasher;
 pub mod token_service;
 pub mod clock;
+pub mod breach_checker;
+pub mod strength_estimator;
+pub mod random_source;
 pub mod service_registry;
 pub mod external_token_validator;
 pub mod exchange_authorization_code;
 pub mod user_service_client;
+pub mod totp_repository;
+pub mod totp_verifier;
+pub mod passkey_repository;
+pub mod webauthn_verifier;
+pub mod reset_token_store;
+pub mod token_deny_list;
+pub mod token_watermark_store;
+pub mod opaque_token_store;
+pub mod unit_of_work;
+pub mod audit_sink;
 
-pub use identity_repository::IdentityRepository;
+pub use identity_repository::{IdentityRepository, NewIdentity, BatchCreateOutcome};
+pub use identifier_normalizer::IdentifierNormalizer;
 pub use external_identity_repository::ExternalIdentityRepository;
-pub use credential_repository::CredentialRepository;
-pub use session_repository::SessionRepository;
+pub use credential_repository::{CredentialRepository, LockoutState};
+pub use session_repository::{
+    SessionCursor, SessionPage, SessionRecord, SessionRepository, SessionStatus, SessionSummary,
+};
+pub use session_metadata_enricher::{SessionClientDetails, SessionMetadataEnricher};
 pub use password_hasher::PasswordHasher;
 pub use token_service::TokenService;
 pub use clock::Clock;
+pub use breach_checker::BreachChecker;
+pub use strength_estimator::StrengthEstimator;
+pub use random_source::RandomSource;
 pub use service_registry::ServiceRegistry;
 pub use external_token_validator::{ExternalTokenValidator, ExternalClaims};
 pub use exchange_authorization_code::ExchangeAuthorizationCode;
 pub use user_service_client::{UserServiceClient, RegisterGoogleUserRequest};
+pub use totp_repository::{TotpRepository, TotpEnrollment};
+pub use totp_verifier::TotpVerifier;
+pub use passkey_repository::{PasskeyRepository, PasskeyCredential};
+pub use webauthn_verifier::{WebAuthnVerifier, AttestedPasskey, VerifiedAssertion};
+pub use reset_token_store::ResetTokenStore;
+pub use token_deny_list::TokenDenyList;
+pub use token_watermark_store::TokenWatermarkStore;
+pub use opaque_token_store::{OpaqueTokenRecord, OpaqueTokenStore};
+pub use unit_of_work::{UnitOfWork, UnitOfWorkScope};
+pub use audit_sink::{AuditEvent, AuditEventType, AuditOutcome, AuditSink};
 
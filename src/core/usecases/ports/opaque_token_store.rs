@@ -0,0 +1,40 @@
+//! Port for opaque reference token storage.
+//!
+//! Holds what an opaque token stands for, keyed by a hash of the token, so
+//! the token itself carries nothing and is revoked by deleting its record.
+
+use futures::future::BoxFuture;
+
+use crate::core::error::CoreError;
+
+/// What one opaque token stands for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpaqueTokenRecord {
+	/// SHA-256 of the token value, hex-encoded; the token itself is never stored
+	pub token_hash: String,
+	/// Token type (`"access"`, `"refresh"`, ...)
+	pub token_type: String,
+	/// Claims JSON returned when the token validates
+	pub claims: String,
+	/// Expiry (Unix epoch seconds)
+	pub expires_at: i64,
+}
+
+/// Contract for opaque token storage.
+pub trait OpaqueTokenStore: Send + Sync {
+	/// Store the record of a newly issued token.
+	fn put(&self, record: OpaqueTokenRecord) -> BoxFuture<'_, Result<(), CoreError>>;
+
+	/// Look up the record for `token_hash`, ignoring lapsed records.
+	///
+	/// Returns `None` when there is no record or it cannot be read.
+	fn get(&self, token_hash: &str) -> BoxFuture<'_, Option<OpaqueTokenRecord>>;
+
+	/// Delete the record for `token_hash`, returning whether one existed.
+	fn remove(&self, token_hash: &str) -> BoxFuture<'_, bool>;
+
+	/// Delete lapsed records, returning how many were deleted.
+	///
+	/// Lookups already ignore them; this is housekeeping, run periodically.
+	fn purge_expired(&self) -> BoxFuture<'_, Result<u64, CoreError>>;
+}
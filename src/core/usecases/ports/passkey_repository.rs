@@ -0,0 +1,44 @@
+//! Port for passkey (WebAuthn credential) persistence.
+//!
+//! Abstracts storing the public key of each authenticator a user registered
+//! and the signature counter it last reported, which is what lets the use
+//! cases detect cloned authenticators.
+//!
+//! Adapters must implement this trait to provide persistence of passkeys.
+
+use futures::future::BoxFuture;
+
+/// A registered WebAuthn credential.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasskeyCredential {
+	/// Credential ID chosen by the authenticator, base64url-encoded
+	pub credential_id: String,
+	/// User the credential was registered to
+	pub user_id: String,
+	/// Public key, in the encoding the `WebAuthnVerifier` produced it
+	pub public_key: Vec<u8>,
+	/// Signature counter reported by the last accepted ceremony
+	pub sign_count: u32,
+}
+
+/// Contract for passkey persistence.
+pub trait PasskeyRepository: Send + Sync {
+	/// Load a credential by its credential ID.
+	fn find_by_credential_id(&self, credential_id: &str) -> BoxFuture<'_, Option<PasskeyCredential>>;
+
+	/// Store a newly registered credential.
+	///
+	/// Fails if a credential with the same ID is already stored.
+	fn create(&self, credential: &PasskeyCredential) -> BoxFuture<'_, Result<(), String>>;
+
+	/// Advance the stored signature counter from `expected` to `sign_count`.
+	///
+	/// Returns `Ok(false)` without writing when the stored counter is no
+	/// longer `expected`, i.e. another assertion was accepted in between.
+	fn update_sign_count(
+		&self,
+		credential_id: &str,
+		expected: u32,
+		sign_count: u32,
+	) -> BoxFuture<'_, Result<bool, String>>;
+}
@@ -6,6 +6,9 @@
 
 use crate::core::credentials::StoredCredential;
 
+/// Password hashed to produce the dummy credential used by `dummy_verify`.
+pub const DUMMY_PASSWORD: &str = "agora-auth-dummy-password";
+
 /// Contract for password hashing and verification.
 pub trait PasswordHasher {
 	/// Hash a raw password and return a stored credential.
@@ -13,4 +16,25 @@ pub trait PasswordHasher {
 
 	/// Verify a raw password against a stored credential.
 	fn verify(&self, raw: &str, stored: &StoredCredential) -> bool;
+
+	/// Whether a stored credential was produced with outdated parameters or
+	/// algorithm and should be re-hashed after a successful verification.
+	///
+	/// Default: never request a rehash.
+	fn needs_rehash(&self, _stored: &StoredCredential) -> bool {
+		false
+	}
+
+	/// Spend the cost of one verification when there is no credential.
+	///
+	/// Called for unknown identifiers so that they take about as long to
+	/// reject as a wrong password, instead of revealing that the account
+	/// does not exist. Adapters should verify against a dummy hash computed
+	/// once with their current parameters.
+	///
+	/// Default: verifies against a freshly hashed `DUMMY_PASSWORD`.
+	fn dummy_verify(&self, raw: &str) {
+		let dummy = self.hash(DUMMY_PASSWORD);
+		let _ = self.verify(raw, &dummy);
+	}
 }
@@ -0,0 +1,37 @@
+//! Port for randomness.
+//!
+//! Abstracts the random bytes behind session ids, token identifiers and other
+//! generated values, so tests can substitute a deterministic stream.
+//!
+//! Adapters must implement this trait to provide concrete random sources.
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Contract for a source of random bytes.
+pub trait RandomSource {
+	/// Fill `dest` entirely with random bytes.
+	fn fill_bytes(&self, dest: &mut [u8]);
+
+	/// Generate a random (version 4) UUID.
+	fn uuid_v4(&self) -> Uuid {
+		let mut bytes = [0u8; 16];
+		self.fill_bytes(&mut bytes);
+		uuid::Builder::from_random_bytes(bytes).into_uuid()
+	}
+
+	/// Generate a time-ordered (version 7) UUID for the instant `at`.
+	fn uuid_v7(&self, at: DateTime<Utc>) -> Uuid {
+		let mut bytes = [0u8; 10];
+		self.fill_bytes(&mut bytes);
+		let millis = u64::try_from(at.timestamp_millis()).unwrap_or_default();
+		uuid::Builder::from_unix_timestamp_millis(millis, &bytes).into_uuid()
+	}
+
+	/// Generate an opaque token of `len` random bytes, hex-encoded.
+	fn opaque_token(&self, len: usize) -> String {
+		let mut bytes = vec![0u8; len];
+		self.fill_bytes(&mut bytes);
+		bytes.iter().map(|b| format!("{:02x}", b)).collect()
+	}
+}
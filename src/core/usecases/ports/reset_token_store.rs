@@ -0,0 +1,20 @@
+//! Port for single-use reset token consumption.
+//!
+//! Records which password-reset tokens have been redeemed so each token
+//! completes at most one reset.
+//!
+//! Adapters must implement this trait to provide an atomic consumed-token store.
+
+use futures::future::BoxFuture;
+use crate::core::error::CoreError;
+
+/// Contract for reset token consumption.
+pub trait ResetTokenStore: Send + Sync {
+	/// Atomically mark the reset token identified by `jti` as consumed.
+	///
+	/// Must succeed for exactly one caller per `jti`, even under concurrent
+	/// requests; later calls fail with `TokenError::revoked`. `expires_at`
+	/// (Unix epoch seconds) lets the store discard entries once the token
+	/// could no longer validate anyway.
+	fn consume_reset_token(&self, jti: &str, expires_at: i64) -> BoxFuture<'_, Result<(), CoreError>>;
+}
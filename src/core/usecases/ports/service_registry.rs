@@ -29,6 +29,15 @@ pub trait ServiceRegistry: Send + Sync {
     /// * `false` - If the service is inactive or not found
     fn is_service_active(&self, service_name: &str) -> bool;
 
+    /// Resolve a client certificate identity to a service name
+    ///
+    /// `identity` is a SPIFFE ID or a certificate subject. Backs mutual TLS
+    /// on internal endpoints; a static allowlist or a dynamic source may
+    /// answer it. Default: no certificate is recognised.
+    fn service_for_certificate(&self, _identity: &str) -> Option<String> {
+        None
+    }
+
     /// Validate service credentials using the provided password hasher
     /// 
     /// # Arguments
@@ -58,6 +67,7 @@ pub mod tests {
         valid_keys: RwLock<HashMap<String, String>>,
         active_services: RwLock<Vec<String>>,
         credentials: RwLock<HashMap<String, String>>,
+        certificate_identities: RwLock<HashMap<String, String>>,
     }
     
     impl MockServiceRegistry {
@@ -78,6 +88,7 @@ pub mod tests {
                 valid_keys: RwLock::new(valid_keys),
                 active_services: RwLock::new(active_services),
                 credentials: RwLock::new(credentials),
+                certificate_identities: RwLock::new(HashMap::new()),
             }
         }
         
@@ -92,6 +103,12 @@ pub mod tests {
             services.retain(|s| s != service_name);
         }
         
+        /// Register a client certificate identity for testing
+        pub fn add_certificate_identity(&self, identity: &str, service_name: &str) {
+            self.certificate_identities.write().unwrap()
+                .insert(identity.to_string(), service_name.to_string());
+        }
+        
         /// Add service credentials for testing
         pub fn add_credentials(&self, service_id: &str, hashed_secret: &str) {
             self.credentials.write().unwrap()
@@ -108,6 +125,10 @@ pub mod tests {
             self.active_services.read().unwrap().contains(&service_name.to_string())
         }
         
+        fn service_for_certificate(&self, identity: &str) -> Option<String> {
+            self.certificate_identities.read().unwrap().get(identity).cloned()
+        }
+        
         fn validate_credentials(
             &self, 
             service_id: &str, 
@@ -0,0 +1,50 @@
+//! Port for session metadata enrichment.
+//!
+//! Turns the raw client IP and user agent of a new session into structured
+//! details (device, OS, browser, coarse location) so session listings can
+//! say "Firefox on Linux, Berlin" instead of echoing a user-agent string.
+//!
+//! Enrichment is best effort: `IssueSession` stores the raw values either
+//! way and never fails a login because an enricher did.
+//!
+//! Adapters must keep location coarse. City is the finest level allowed;
+//! coordinates, postal codes and network owner are never stored.
+
+use futures::future::BoxFuture;
+
+/// Structured client details derived from the raw IP and user agent.
+///
+/// Every field is optional; an enricher fills in what it can tell.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionClientDetails {
+	/// Device class, e.g. `desktop`, `mobile` or `tablet`
+	pub device_type: Option<String>,
+	/// Operating system, e.g. `Windows` or `iOS`
+	pub os: Option<String>,
+	/// Browser or client application, e.g. `Firefox`
+	pub browser: Option<String>,
+	/// Country code, e.g. `DE`
+	pub country: Option<String>,
+	/// Region or state
+	pub region: Option<String>,
+	/// City; the finest location level ever stored
+	pub city: Option<String>,
+}
+
+impl SessionClientDetails {
+	/// Whether no detail is known.
+	pub fn is_empty(&self) -> bool {
+		*self == Self::default()
+	}
+}
+
+/// Contract for session metadata enrichment.
+pub trait SessionMetadataEnricher: Send + Sync {
+	/// Derive client details for a session created from `ip_address` with
+	/// `user_agent`.
+	///
+	/// # Errors
+	/// Returns an error if the details cannot be derived; the session is
+	/// then stored with its raw values only.
+	fn enrich<'a>(&'a self, ip_address: &'a str, user_agent: &'a str) -> BoxFuture<'a, Result<SessionClientDetails, String>>;
+}
@@ -4,6 +4,7 @@
 //!
 //! Adapters must implement this trait to provide persistence or external session management.
 
+use chrono::{DateTime, Utc};
 use futures::future::BoxFuture;
 use crate::core::identity::UserIdentity;
 use crate::core::error::CoreError;
@@ -11,6 +12,81 @@ use crate::core::error::CoreError;
 /// Opaque session type for use case contracts (to be defined in usecases).
 pub struct Session {/* fields omitted for now */}
 
+/// Owner-facing view of an active session.
+///
+/// Deliberately carries no token material.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionSummary {
+	pub session_id: String,
+	pub created_at: DateTime<Utc>,
+	pub expires_at: DateTime<Utc>,
+	pub ip_address: String,
+	pub user_agent: String,
+}
+
+/// Lifecycle state of a session at the time it is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionStatus {
+	Active,
+	Revoked,
+	Expired,
+}
+
+impl SessionStatus {
+	/// Stable lowercase name, as exposed to callers.
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Active => "active",
+			Self::Revoked => "revoked",
+			Self::Expired => "expired",
+		}
+	}
+}
+
+/// Historical view of a session, whatever its state.
+///
+/// Deliberately carries no token material.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionRecord {
+	pub session_id: String,
+	pub created_at: DateTime<Utc>,
+	pub expires_at: DateTime<Utc>,
+	pub revoked_at: Option<DateTime<Utc>>,
+	pub status: SessionStatus,
+	pub ip_address: String,
+	pub user_agent: String,
+}
+
+/// Position in a user's session history.
+///
+/// Points at the last session of a page; the next page starts strictly
+/// after it in `(created_at, session_id)` descending order, so sessions
+/// created meanwhile never shift or repeat entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionCursor {
+	pub created_at: DateTime<Utc>,
+	pub session_id: String,
+}
+
+impl SessionCursor {
+	/// Cursor pointing just past `record`.
+	pub fn after(record: &SessionRecord) -> Self {
+		Self {
+			created_at: record.created_at,
+			session_id: record.session_id.clone(),
+		}
+	}
+}
+
+/// One page of a user's session history.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionPage {
+	/// Sessions, newest first.
+	pub sessions: Vec<SessionRecord>,
+	/// Where the next page starts; `None` on the last page.
+	pub next_cursor: Option<SessionCursor>,
+}
+
 /// Contract for session repository access.
 pub trait SessionRepository: Send + Sync {
 	/// Create a new session for a user.
@@ -35,13 +111,143 @@ pub trait SessionRepository: Send + Sync {
 	/// Returns the session only if it is not revoked and not expired.
 	fn find_by_id(&self, session_id: &str) -> BoxFuture<'_, Option<Session>>;
 
+	/// Owner-facing view of a session, including the client it was issued to.
+	///
+	/// Returns the summary only if the session is not revoked and not
+	/// expired. Default: reports nothing.
+	fn find_summary_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<SessionSummary>> {
+		Box::pin(async move { None })
+	}
+
+	/// Replace a session's refresh token hash, remembering the old one.
+	///
+	/// Every hash a session has rotated through stays remembered, not just
+	/// the latest, so a token replayed several rotations later is still
+	/// recognised.
+	///
+	/// Must be a compare-and-swap: succeeds only while `current_hash` is still
+	/// the session's live hash, so two rotations of the same token cannot both
+	/// win. Returns whether the swap happened. Default: persists nothing and
+	/// reports success.
+	fn rotate_refresh_token(&self, _session_id: &str, _current_hash: &str, _new_hash: &str) -> BoxFuture<'_, bool> {
+		Box::pin(async move { true })
+	}
+
+	/// Whether `hash` belongs to a refresh token that was already rotated out,
+	/// including a successor displaced by
+	/// [`reissue_rotation_successor`](Self::reissue_rotation_successor).
+	///
+	/// Default: no rotation history is kept.
+	fn is_rotated_refresh_token(&self, _hash: &str) -> BoxFuture<'_, bool> {
+		Box::pin(async move { false })
+	}
+
+	/// Remember that `previous_hash` was rotated to `successor_hash`, with a
+	/// grace window open until `until`.
+	///
+	/// Backs the rotation grace window: a client that lost the response to a
+	/// rotation can retry with the old token and receive a new successor.
+	/// Only the successor's hash is kept, never the token itself. Default:
+	/// remembers nothing.
+	fn record_rotation_successor(
+		&self,
+		_previous_hash: &str,
+		_successor_hash: &str,
+		_until: DateTime<Utc>,
+	) -> BoxFuture<'_, ()> {
+		Box::pin(async move {})
+	}
+
+	/// Hash of the refresh token that replaced `previous_hash`, if recorded
+	/// and still within its grace window at `now`.
+	///
+	/// Default: no successor is ever known.
+	fn rotation_successor(&self, _previous_hash: &str, _now: DateTime<Utc>) -> BoxFuture<'_, Option<String>> {
+		Box::pin(async move { None })
+	}
+
+	/// Replace the successor of `previous_hash` with `new_hash`, if its grace
+	/// window is still open at `now` and the successor is still the
+	/// session's live token.
+	///
+	/// The replaced successor stops working and is remembered as rotated, so
+	/// presenting it later counts as reuse. The swap closes the window: a
+	/// rotation is reissued at most once. Must be atomic, like
+	/// [`rotate_refresh_token`](Self::rotate_refresh_token). Returns whether
+	/// this call performed the swap. Default: never swaps.
+	fn reissue_rotation_successor(
+		&self,
+		_previous_hash: &str,
+		_new_hash: &str,
+		_now: DateTime<Utc>,
+	) -> BoxFuture<'_, bool> {
+		Box::pin(async move { false })
+	}
+
+	/// When the session was explicitly revoked, if it was.
+	///
+	/// Lets callers tell a revoked session apart from an expired or unknown
+	/// one. Default: reports nothing as revoked.
+	fn revoked_at(&self, _session_id: &str) -> BoxFuture<'_, Option<DateTime<Utc>>> {
+		Box::pin(async move { None })
+	}
+
+	/// When the session expires, whatever its state.
+	///
+	/// Lets refresh enforce the session's own lifetime independently of the
+	/// refresh token's `exp`. Default: reports no expiry, leaving the token
+	/// and the lookup methods to enforce it.
+	fn expires_at(&self, _session_id: &str) -> BoxFuture<'_, Option<DateTime<Utc>>> {
+		Box::pin(async move { None })
+	}
+
 	/// Revoke a session by id or token hash.
 	fn revoke_session(&self, session_id: &str) -> BoxFuture<'_, ()>;
 
 	/// Revoke all sessions for a user.
-	fn revoke_all_for_user(&self, user_id: &str) -> BoxFuture<'_, ()>;
+	///
+	/// Returns the number of sessions revoked.
+	fn revoke_all_for_user(&self, user_id: &str) -> BoxFuture<'_, u64>;
+
+	/// Revoke all active sessions for a user except `keep_session_id`.
+	///
+	/// Returns the number of sessions revoked. Default: revokes nothing.
+	fn revoke_all_for_user_except(&self, _user_id: &str, _keep_session_id: &str) -> BoxFuture<'_, u64> {
+		Box::pin(async move { 0 })
+	}
+
+	/// List the user's sessions that are neither revoked nor expired.
+	///
+	/// Newest first. Default: lists nothing.
+	fn list_active_for_user(&self, _user_id: &str) -> BoxFuture<'_, Vec<SessionSummary>> {
+		Box::pin(async move { Vec::new() })
+	}
+
+	/// List all of the user's sessions, including revoked and expired ones.
+	///
+	/// Newest first, at most `limit` per page, starting after `cursor` when
+	/// given. Default: lists nothing.
+	fn list_for_user_paginated(
+		&self,
+		_user_id: &str,
+		_cursor: Option<&SessionCursor>,
+		_limit: usize,
+	) -> BoxFuture<'_, Result<SessionPage, CoreError>> {
+		Box::pin(async move { Ok(SessionPage::default()) })
+	}
 
 	/// Delete all expired sessions.
 	fn delete_expired(&self) -> BoxFuture<'_, ()>;
+
+	/// Delete all expired sessions and report how many were removed.
+	///
+	/// Default: delegates to `delete_expired` and reports zero, since the
+	/// count is not available through that method.
+	fn purge_expired(&self) -> BoxFuture<'_, Result<u64, String>> {
+		Box::pin(async move {
+			self.delete_expired().await;
+			Ok(0)
+		})
+	}
 }
 
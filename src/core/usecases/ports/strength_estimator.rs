@@ -0,0 +1,14 @@
+//! Port for password strength estimation.
+//!
+//! Abstracts guessability scoring (e.g. zxcvbn) so credential policy can
+//! reject weak secrets that satisfy a length rule, such as "aaaaaaaaaaaa",
+//! without the core shipping frequency dictionaries.
+
+/// Contract for password strength estimation.
+pub trait StrengthEstimator {
+	/// Score `secret` from 0 (trivially guessable) to 4 (very unguessable).
+	///
+	/// `user_inputs` are user-specific words (identifier, email, name) that
+	/// an attacker would try first; secrets built from them must score lower.
+	fn score(&self, secret: &str, user_inputs: &[&str]) -> u8;
+}
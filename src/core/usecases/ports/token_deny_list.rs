@@ -0,0 +1,22 @@
+//! Port for the access token deny-list.
+//!
+//! Access tokens are stateless, so revoking one before it expires means
+//! remembering its `jti` until the token could no longer validate anyway.
+//!
+//! Adapters must implement this trait to provide a deny-list store.
+
+use futures::future::BoxFuture;
+use crate::core::error::CoreError;
+
+/// Contract for the access token deny-list.
+pub trait TokenDenyList: Send + Sync {
+	/// Deny the access token identified by `jti` until `expires_at`.
+	///
+	/// `expires_at` (Unix epoch seconds) is the token's `exp`; the entry may be
+	/// discarded after it, since the token is rejected as expired from then on.
+	/// Denying an already denied `jti` succeeds.
+	fn deny(&self, jti: &str, expires_at: i64) -> BoxFuture<'_, Result<(), CoreError>>;
+
+	/// Whether `jti` is denied. Entries past their `expires_at` never count.
+	fn is_denied(&self, jti: &str) -> BoxFuture<'_, bool>;
+}
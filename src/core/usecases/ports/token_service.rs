@@ -3,26 +3,70 @@
 //! Abstracts access and refresh token issuance and validation for authentication use cases.
 //!
 //! Adapters must implement this trait to provide concrete token logic (e.g., JWT, PASETO).
+//!
+//! Operations are async so adapters backed by a store (opaque tokens) can
+//! query it; self-contained formats answer with a ready future.
+
+use futures::future::BoxFuture;
 
 use crate::core::token::Token;
 
 /// Contract for token service.
 pub trait TokenService: Send + Sync {
 	/// Issue a new access token for a subject (user id, claims, etc.).
-	fn issue_access_token(&self, subject: &str, claims: &str) -> Token;
+	fn issue_access_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token>;
 
 	/// Issue a new refresh token for a subject.
-	fn issue_refresh_token(&self, subject: &str, claims: &str) -> Token;
+	fn issue_refresh_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token>;
 
 	/// Issue a new service token for service-to-service authentication.
-	fn issue_service_token(&self, subject: &str, claims: &str) -> Token;
+	fn issue_service_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token>;
 
 	/// Validate an access token and return claims if valid.
-	fn validate_access_token(&self, token: &Token) -> Result<String, ()>;
+	fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>>;
 
 	/// Validate a refresh token and return claims if valid.
-	fn validate_refresh_token(&self, token: &Token) -> Result<String, ()>;
+	fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>>;
 
 	/// Validate a service token and return claims if valid.
-	fn validate_service_token(&self, token: &Token) -> Result<String, ()>;
+	fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>>;
+
+	/// Signing algorithms this service issues and accepts (e.g. `"HS256"`).
+	///
+	/// Default: no algorithm is advertised.
+	fn supported_algorithms(&self) -> &[&str] {
+		&[]
+	}
+
+	/// Issue a password reset token (`type: "reset"`) for a subject.
+	///
+	/// The `exp` claim, when present in `claims`, sets the token expiry.
+	/// Default: reset tokens are unsupported and an empty token is returned.
+	fn issue_reset_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+		Box::pin(async { Token::new("") })
+	}
+
+	/// Validate a password reset token and return claims if valid.
+	///
+	/// Must reject tokens whose type is not `"reset"`.
+	/// Default: reset tokens are unsupported and validation always fails.
+	fn validate_reset_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+		Box::pin(async { Err(()) })
+	}
+
+	/// Issue an email verification token (`type: "verify"`) for a subject.
+	///
+	/// The `exp` claim, when present in `claims`, sets the token expiry.
+	/// Default: verification tokens are unsupported and an empty token is returned.
+	fn issue_verification_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+		Box::pin(async { Token::new("") })
+	}
+
+	/// Validate an email verification token and return claims if valid.
+	///
+	/// Must reject tokens whose type is not `"verify"`.
+	/// Default: verification tokens are unsupported and validation always fails.
+	fn validate_verification_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+		Box::pin(async { Err(()) })
+	}
 }
@@ -0,0 +1,26 @@
+//! Port for the access token issued-at watermark.
+//!
+//! A global kill switch: every access token issued at or before the
+//! watermark is rejected, without tracking tokens individually. The
+//! watermark only ever moves forward.
+//!
+//! Adapters must implement this trait to provide a durable watermark store;
+//! a watermark forgotten on restart would silently revive every token.
+//! Lookups sit on the path of every token validation, so adapters answer
+//! them from memory and keep that copy current.
+
+use futures::future::BoxFuture;
+use crate::core::error::CoreError;
+
+/// Contract for the access token issued-at watermark.
+pub trait TokenWatermarkStore: Send + Sync {
+	/// Latest `iat` (Unix epoch seconds) revoked: access tokens issued at or
+	/// before it are rejected. `None` if no watermark was ever set.
+	fn revoked_through(&self) -> BoxFuture<'_, Option<i64>>;
+
+	/// Raise the watermark to `revoked_through`.
+	///
+	/// A value below the current watermark leaves it unchanged. Returns the
+	/// watermark in effect afterwards.
+	fn advance(&self, revoked_through: i64) -> BoxFuture<'_, Result<i64, CoreError>>;
+}
@@ -0,0 +1,39 @@
+//! Port for TOTP enrollment access.
+//!
+//! Abstracts loading a user's TOTP shared secret and the replay guard
+//! (last accepted time step) for second-factor verification.
+//!
+//! Adapters must implement this trait to provide persistence of TOTP enrollments.
+
+use futures::future::BoxFuture;
+
+/// TOTP enrollment data for a single user.
+#[derive(Clone)]
+pub struct TotpEnrollment {
+	/// Raw shared secret (already base32-decoded)
+	pub secret: Vec<u8>,
+	/// Last time step for which a code was accepted, if any
+	pub last_used_step: Option<u64>,
+}
+
+impl std::fmt::Debug for TotpEnrollment {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("TotpEnrollment")
+			.field("secret", &"[REDACTED]")
+			.field("last_used_step", &self.last_used_step)
+			.finish()
+	}
+}
+
+/// Contract for TOTP enrollment access.
+pub trait TotpRepository: Send + Sync {
+	/// Load the TOTP enrollment for a user, if the user has enrolled.
+	fn find_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<TotpEnrollment>>;
+
+	/// Record the time step of an accepted code so it cannot be replayed.
+	///
+	/// The write is conditional: it only takes effect when `step` is later
+	/// than the last recorded step, so of two concurrent submissions of the
+	/// same code exactly one records it. Returns whether this call did.
+	fn record_used_step(&self, user_id: &str, step: u64) -> BoxFuture<'_, Result<bool, String>>;
+}
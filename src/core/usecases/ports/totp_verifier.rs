@@ -0,0 +1,14 @@
+//! Port for TOTP code verification.
+//!
+//! Abstracts the one-time-password computation (RFC 6238 / RFC 4226) so the
+//! use cases layer never performs cryptography directly.
+//!
+//! Adapters must implement this trait to provide the HMAC computation.
+
+/// Contract for verifying a TOTP code against a single time step.
+pub trait TotpVerifier: Send + Sync {
+	/// Returns true if `code` is the valid code for `secret` at time step `step`.
+	///
+	/// Implementations must compare codes in constant time.
+	fn verify(&self, secret: &[u8], code: &str, step: u64) -> bool;
+}
@@ -0,0 +1,42 @@
+//! Port for atomic units of work.
+//!
+//! Groups writes that must succeed or fail together, such as persisting a
+//! session alongside the login it records.
+//!
+//! Adapters must implement this trait to provide transaction scoping.
+
+use futures::future::BoxFuture;
+use crate::core::error::CoreError;
+use crate::core::identity::UserIdentity;
+
+/// Contract for starting an atomic unit of work.
+pub trait UnitOfWork: Send + Sync {
+	/// Begin a unit of work. Nothing staged in it is visible to others until
+	/// it is committed.
+	fn begin(&self) -> BoxFuture<'_, Result<Box<dyn UnitOfWorkScope>, CoreError>>;
+}
+
+/// Writes staged inside one unit of work.
+///
+/// Dropping a scope without committing discards everything staged in it.
+pub trait UnitOfWorkScope: Send {
+	/// Stage a new session; same contract as
+	/// [`SessionRepository::create_session`](super::SessionRepository::create_session).
+	fn create_session(
+		&mut self,
+		session_id: &str,
+		user: &UserIdentity,
+		refresh_token_hash: &str,
+		metadata: &str,
+	) -> BoxFuture<'_, Result<(), CoreError>>;
+
+	/// Stage the login time of `user_id` (RFC3339); same contract as
+	/// [`CredentialRepository::record_successful_login`](super::CredentialRepository::record_successful_login).
+	fn record_successful_login(&mut self, user_id: &str, at: &str) -> BoxFuture<'_, Result<(), CoreError>>;
+
+	/// Make every staged write durable.
+	fn commit(self: Box<Self>) -> BoxFuture<'static, Result<(), CoreError>>;
+
+	/// Discard every staged write.
+	fn rollback(self: Box<Self>) -> BoxFuture<'static, ()>;
+}
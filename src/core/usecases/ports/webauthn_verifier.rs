@@ -0,0 +1,53 @@
+//! Port for WebAuthn ceremony verification.
+//!
+//! Abstracts checking the responses a browser returns from
+//! `navigator.credentials.create()` (registration) and
+//! `navigator.credentials.get()` (assertion): parsing the client and
+//! authenticator data, checking the RP ID and origin, and verifying
+//! signatures. The relying-party configuration and all cryptography stay
+//! behind this port.
+//!
+//! Adapters must implement this trait to provide the WebAuthn verification.
+
+/// A credential taken from a verified registration response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttestedPasskey {
+	/// Credential ID chosen by the authenticator, base64url-encoded
+	pub credential_id: String,
+	/// Public key to verify later assertions with
+	pub public_key: Vec<u8>,
+	/// Signature counter reported at registration
+	pub sign_count: u32,
+	/// Challenge the client data was produced for, base64url-encoded
+	pub challenge: String,
+}
+
+/// The content of a verified assertion response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedAssertion {
+	/// Credential ID the assertion was made with, base64url-encoded
+	pub credential_id: String,
+	/// Signature counter reported by the authenticator
+	pub sign_count: u32,
+	/// Challenge the client data was produced for, base64url-encoded
+	pub challenge: String,
+	/// Whether the authenticator verified the user (PIN, biometrics)
+	pub user_verified: bool,
+}
+
+/// Contract for verifying WebAuthn registration and assertion responses.
+///
+/// Responses are the JSON serialization of the browser's `PublicKeyCredential`.
+/// Implementations must reject responses made for another RP ID or origin,
+/// or for the other ceremony type. Matching the challenge and the signature
+/// counter against stored state is left to the use cases.
+pub trait WebAuthnVerifier: Send + Sync {
+	/// Verify a registration response and extract the new credential.
+	fn verify_registration(&self, response: &str) -> Result<AttestedPasskey, String>;
+
+	/// Credential ID an assertion response names, before it is verified.
+	fn assertion_credential_id(&self, response: &str) -> Result<String, String>;
+
+	/// Verify an assertion response against the credential's stored public key.
+	fn verify_assertion(&self, response: &str, public_key: &[u8]) -> Result<VerifiedAssertion, String>;
+}
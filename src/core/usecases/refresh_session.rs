@@ -5,18 +5,46 @@
 //! Responsibilities:
 //! - Validate refresh token signature via TokenService
 //! - Lookup session by refresh token hash
-//! - Check session is not revoked and not expired
-//! - Issue new access token
+//! - Check session is not revoked and not expired, by the session's own
+//!   `expires_at` rather than the refresh token's `exp`
+//! - Optionally check the client matches the one the session was issued to
+//! - Issue new access token, optionally with claims re-fetched from the
+//!   identity store instead of copied from the refresh token
 //! - Optionally rotate refresh token (revoke old, issue new)
+//! - Detect reuse of a rotated refresh token and revoke every session of the user
 //! - Return new access token
+//!
+//! Reuse detection is strict by default: a refresh token is good for exactly
+//! one rotation. If a client fires two refreshes with the same token at
+//! nearly the same time, one wins the rotation and the other is treated as
+//! reuse, which signs the user out everywhere. Telling that race apart from
+//! a replayed stolen token is not possible from the server side, so we err
+//! on the side of revoking.
+//!
+//! A rotation grace window relaxes this for clients that lost the response
+//! to a rotation: for a few seconds the immediately-previous token is
+//! answered with a freshly minted successor that replaces the lost one. Only
+//! the successor's hash is stored, so the lost token itself cannot be handed
+//! out again; it is remembered as rotated, and presenting it later is reuse.
+//! A rotation is reissued at most once, which closes the window. Outside the
+//! window, or once the successor itself was used, the old token is reuse as
+//! before.
 
 use crate::core::error::{CoreError, TokenError, AuthenticationError};
 use crate::core::token::Token;
-use crate::core::usecases::ports::{SessionRepository, TokenService};
+use crate::core::usecases::issue_session::ensure_issued;
+use crate::core::usecases::policies::{BindingMismatch, SessionBindingPolicy};
+use crate::core::usecases::ports::{Clock, IdentityRepository, SessionRepository, TokenService};
+use crate::core::usecases::validate_access_token::RESERVED_CLAIMS;
+use serde_json::{Map, Value};
 
 /// Input contract for RefreshSession use case.
 pub struct RefreshSessionInput {
     pub refresh_token: Token,
+    /// IP address of the client presenting the token
+    pub ip_address: String,
+    /// User agent of the client presenting the token
+    pub user_agent: String,
 }
 
 /// Output contract for RefreshSession use case.
@@ -32,8 +60,12 @@ pub struct RefreshSessionOutput {
 pub struct RefreshSession<'a> {
     session_repo: &'a (dyn SessionRepository + Send + Sync),
     token_service: &'a (dyn TokenService + Send + Sync),
+    clock: &'a (dyn Clock + Send + Sync),
     access_token_ttl_seconds: u64,
     rotate_refresh_tokens: bool,
+    claims_source: Option<&'a (dyn IdentityRepository + Send + Sync)>,
+    binding: SessionBindingPolicy,
+    rotation_grace_seconds: u64,
 }
 
 impl<'a> RefreshSession<'a> {
@@ -41,17 +73,54 @@ impl<'a> RefreshSession<'a> {
     pub fn new(
         session_repo: &'a (dyn SessionRepository + Send + Sync),
         token_service: &'a (dyn TokenService + Send + Sync),
+        clock: &'a (dyn Clock + Send + Sync),
         access_token_ttl_seconds: u64,
         rotate_refresh_tokens: bool,
     ) -> Self {
         Self {
             session_repo,
             token_service,
+            clock,
             access_token_ttl_seconds,
             rotate_refresh_tokens,
+            claims_source: None,
+            binding: SessionBindingPolicy::disabled(),
+            rotation_grace_seconds: 0,
         }
     }
 
+    /// Re-fetch the user's claims from `identity_repo` on every refresh.
+    ///
+    /// Role or tenant changes then reach the next access token instead of
+    /// waiting for a new login, at the cost of one identity lookup per
+    /// refresh. A user who no longer exists cannot refresh. Off by default:
+    /// the application claims are copied from the refresh token.
+    pub fn with_claims_refetch(mut self, identity_repo: &'a (dyn IdentityRepository + Send + Sync)) -> Self {
+        self.claims_source = Some(identity_repo);
+        self
+    }
+
+    /// Only accept refreshes from the client the session was issued to.
+    ///
+    /// The presented IP and user agent are compared against the ones stored
+    /// with the session; a mismatch is rejected without revoking anything.
+    /// Off by default.
+    pub fn with_binding(mut self, binding: SessionBindingPolicy) -> Self {
+        self.binding = binding;
+        self
+    }
+
+    /// Keep a rotated-out refresh token usable for `grace_seconds`.
+    ///
+    /// Within the window, presenting the previous token again mints a new
+    /// successor in place of the one the client lost, so a retried request
+    /// does not brick the session. Only one such retry is answered per
+    /// rotation. Off (zero) by default.
+    pub fn with_rotation_grace(mut self, grace_seconds: u64) -> Self {
+        self.rotation_grace_seconds = grace_seconds;
+        self
+    }
+
     /// Execute the session refresh use case.
     pub async fn execute(&self, input: RefreshSessionInput) -> Result<RefreshSessionOutput, CoreError> {
         // Step 1: Validate refresh token signature
@@ -59,6 +128,7 @@ impl<'a> RefreshSession<'a> {
         let claims = self
             .token_service
             .validate_refresh_token(&input.refresh_token)
+            .await
             .map_err(|e| {
                 tracing::error!("[REFRESH] Step 1 failed: token validation error: {:?}", e);
                 TokenError::signature_invalid("refresh token validation failed")
@@ -98,39 +168,105 @@ impl<'a> RefreshSession<'a> {
             tracing::debug!("[REFRESH] Step 4: Session NOT found in database");
         }
         
-        let _session = session.ok_or_else(|| {
+        // A retry within the rotation grace window replaces the successor
+        // already issued for this token instead of rotating from it
+        let mut within_grace = false;
+        if session.is_none() {
+            // A token that was already rotated out is being replayed
+            if self.session_repo.is_rotated_refresh_token(&refresh_token_hash).await {
+                if !self.grace_window_open(&refresh_token_hash).await {
+                    return Err(self.revoke_family(&user_id).await);
+                }
+                within_grace = true;
+                tracing::info!("[REFRESH] Step 4: Rotated token retried within grace window");
+            } else {
                 tracing::error!("[REFRESH] Step 4 failed: session not found for hash");
-                AuthenticationError::user_not_found("session not found")
-            })?;
+                return Err(AuthenticationError::user_not_found("session not found").into());
+            }
+        }
         
         tracing::debug!("[REFRESH] Step 4 succeeded: session found");
 
-        // Step 5: Issue new access token with session_id
-        tracing::debug!("[REFRESH] Step 5: Issuing new access token");
-        let access_token = self.token_service.issue_access_token(
-            &user_id,
-            &self.build_access_claims(&user_id, &session_id),
-        );
+        // Step 5: Enforce the session's own expiry; a refresh token that
+        // still validates must not outlive the session it belongs to
+        let now = self.clock.now();
+        if let Some(expires_at) = self
+            .session_repo
+            .expires_at(&session_id)
+            .await
+            .filter(|expires_at| now >= *expires_at)
+        {
+            tracing::warn!("[REFRESH] Step 5 failed: session {} expired at {}", session_id, expires_at);
+            return Err(TokenError::expired(expires_at.to_rfc3339()).into());
+        }
+
+        // Step 6: Check the client against the one the session was issued to
+        if self.binding.is_enabled() {
+            tracing::debug!("[REFRESH] Step 6: Checking session binding");
+            self.check_binding(&session_id, &input.ip_address, &input.user_agent).await?;
+        }
+
+        // Step 7: Issue new access token with session_id, re-assembling
+        // application claims from the identity store when configured
+        tracing::debug!("[REFRESH] Step 7: Issuing new access token");
+        let claims_source = match self.claims_source {
+            Some(identity_repo) => identity_repo
+                .find_claims_by_id(&user_id)
+                .await
+                .ok_or_else(|| AuthenticationError::user_not_found("identity no longer exists"))?,
+            None => serde_json::from_str::<Map<String, Value>>(&claims).unwrap_or_default(),
+        };
+        let access_token = ensure_issued(
+            self.token_service.issue_access_token(
+                &user_id,
+                &self.build_access_claims(&user_id, &session_id, claims_source),
+            ).await,
+            "access",
+        )?;
         
-        tracing::debug!("[REFRESH] Step 5 succeeded: access_token issued");
-
-        // Step 6: Optionally rotate refresh token
-        tracing::debug!("[REFRESH] Step 6: rotate_refresh_tokens={}", self.rotate_refresh_tokens);
-        let (refresh_token, _new_hash) = if self.rotate_refresh_tokens {
-            tracing::debug!("[REFRESH] Step 6a: Rotating refresh token");
-            let new_token = self.token_service.issue_refresh_token(&user_id, &claims);
-            let _new_hash = self.hash_token(&new_token);
-            
-            // Revoke old session and create new one
-            // Note: This would need session_id exposed from Session
-            // self.session_repo.revoke_session(&session_id);
-            // self.session_repo.create_session(...);
-            
-            tracing::debug!("[REFRESH] Step 6a: New refresh token issued");
-            (Some(new_token), Some(_new_hash))
+        tracing::debug!("[REFRESH] Step 7 succeeded: access_token issued");
+
+        // Step 8: Optionally rotate refresh token
+        tracing::debug!("[REFRESH] Step 8: rotate_refresh_tokens={}", self.rotate_refresh_tokens);
+        let refresh_token = if within_grace || self.rotate_refresh_tokens {
+            tracing::debug!("[REFRESH] Step 8a: Rotating refresh token");
+            let new_token = ensure_issued(
+                self.token_service.issue_refresh_token(&user_id, &claims).await,
+                "refresh",
+            )?;
+            let new_hash = self.hash_token(&new_token);
+
+            // Consume the presented token; losing this race means another
+            // request already rotated it
+            let rotated = !within_grace
+                && self
+                    .session_repo
+                    .rotate_refresh_token(&session_id, &refresh_token_hash, &new_hash)
+                    .await;
+            if rotated {
+                let grace_until = i64::try_from(self.rotation_grace_seconds)
+                    .ok()
+                    .filter(|secs| *secs > 0)
+                    .and_then(chrono::Duration::try_seconds)
+                    .and_then(|grace| self.clock.now().checked_add_signed(grace));
+                if let Some(until) = grace_until {
+                    self.session_repo
+                        .record_rotation_successor(&refresh_token_hash, &new_hash, until)
+                        .await;
+                }
+            } else if self.reissue_successor(&refresh_token_hash, &new_hash).await {
+                // The request that rotated first may be this client's own
+                // lost attempt; its successor is replaced by this one
+                tracing::info!("[REFRESH] Step 8a: Successor replaced within grace window");
+            } else {
+                return Err(self.revoke_family(&user_id).await);
+            }
+
+            tracing::debug!("[REFRESH] Step 8a: New refresh token issued");
+            Some(new_token)
         } else {
-            tracing::debug!("[REFRESH] Step 6b: Not rotating refresh token");
-            (None, None)
+            tracing::debug!("[REFRESH] Step 8b: Not rotating refresh token");
+            None
         };
 
         tracing::debug!("[REFRESH] All steps completed successfully");
@@ -142,6 +278,77 @@ impl<'a> RefreshSession<'a> {
         })
     }
 
+    /// Reject a refresh whose client does not match the session's.
+    ///
+    /// Fails closed: a session whose client cannot be looked up is refused.
+    async fn check_binding(&self, session_id: &str, ip_address: &str, user_agent: &str) -> Result<(), CoreError> {
+        let stored = self
+            .session_repo
+            .find_summary_by_id(session_id)
+            .await
+            .ok_or_else(|| {
+                tracing::warn!("[REFRESH] Step 6 failed: no client recorded for session {}", session_id);
+                AuthenticationError::InvalidCredentials
+            })?;
+
+        self.binding
+            .check(&stored.ip_address, &stored.user_agent, ip_address, user_agent)
+            .map_err(|mismatch| {
+                let field = match mismatch {
+                    BindingMismatch::IpAddress => "ip address",
+                    BindingMismatch::UserAgent => "user agent",
+                };
+                tracing::warn!("[REFRESH] Step 6 failed: {} mismatch for session {}", field, session_id);
+                AuthenticationError::InvalidCredentials.into()
+            })
+    }
+
+    /// Whether the grace window of `previous_hash` is open and its
+    /// successor is still the session's live token.
+    ///
+    /// Only the immediately-previous token is covered: once the successor
+    /// has itself been rotated or its session revoked, this is false.
+    async fn grace_window_open(&self, previous_hash: &str) -> bool {
+        if self.rotation_grace_seconds == 0 {
+            return false;
+        }
+
+        match self
+            .session_repo
+            .rotation_successor(previous_hash, self.clock.now())
+            .await
+        {
+            Some(successor_hash) => self
+                .session_repo
+                .find_by_refresh_token_hash(&successor_hash)
+                .await
+                .is_some(),
+            None => false,
+        }
+    }
+
+    /// Replace the successor of `previous_hash` with `new_hash` while its
+    /// grace window is open. Returns whether the swap happened; the window
+    /// is closed afterwards.
+    async fn reissue_successor(&self, previous_hash: &str, new_hash: &str) -> bool {
+        self.rotation_grace_seconds > 0
+            && self
+                .session_repo
+                .reissue_rotation_successor(previous_hash, new_hash, self.clock.now())
+                .await
+    }
+
+    /// Revoke every session of a user whose refresh token was replayed.
+    async fn revoke_family(&self, user_id: &str) -> CoreError {
+        let revoked = self.session_repo.revoke_all_for_user(user_id).await;
+        tracing::warn!(
+            "[REFRESH] Refresh token reuse detected for user {}, revoked {} session(s)",
+            user_id,
+            revoked
+        );
+        AuthenticationError::credential_expired("refresh token reuse detected").into()
+    }
+
     fn extract_user_id(&self, claims: &str) -> Option<String> {
         // Simple JSON parsing to extract "sub" field
         claims
@@ -160,13 +367,22 @@ impl<'a> RefreshSession<'a> {
             .map(|s| s.to_string())
     }
 
-    fn build_access_claims(&self, user_id: &str, session_id: &str) -> String {
-        format!(
-            r#"{{"sub":"{}","type":"access","exp":{},"sid":"{}"}}"#,
-            user_id,
-            chrono::Utc::now().timestamp() + self.access_token_ttl_seconds as i64,
-            session_id
-        )
+    /// Access token claims. Only application claims are taken from
+    /// `source`; reserved claims are dropped and `sub`, `type`, `exp` and
+    /// `sid` are always set here.
+    fn build_access_claims(&self, user_id: &str, session_id: &str, source: Map<String, Value>) -> String {
+        let mut claims: Map<String, Value> = source
+            .into_iter()
+            .filter(|(key, _)| !RESERVED_CLAIMS.contains(&key.as_str()))
+            .collect();
+        claims.insert("sub".to_string(), Value::from(user_id));
+        claims.insert("type".to_string(), Value::from("access"));
+        claims.insert(
+            "exp".to_string(),
+            Value::from(self.clock.now().timestamp() + self.access_token_ttl_seconds as i64),
+        );
+        claims.insert("sid".to_string(), Value::from(session_id));
+        Value::Object(claims).to_string()
     }
 
     fn hash_token(&self, token: &Token) -> String {
@@ -0,0 +1,90 @@
+//! Use case: RegisterPasskey
+//!
+//! Orchestrates WebAuthn passkey registration.
+//!
+//! Responsibilities:
+//! - Verify the registration response through the WebAuthn verifier
+//! - Bind the response to the challenge issued for this ceremony
+//! - Refuse credential IDs that are already registered
+//! - Store the credential's public key and initial signature counter
+//!
+//! Does NOT:
+//! - Issue or remember challenges; the caller passes the one it issued
+
+use crate::core::error::{AuthenticationError, CoreError, CredentialError};
+use crate::core::usecases::ports::{PasskeyCredential, PasskeyRepository, WebAuthnVerifier};
+
+/// Input contract for RegisterPasskey use case.
+pub struct RegisterPasskeyInput {
+    pub user_id: String,
+    /// `PublicKeyCredential` JSON returned by `navigator.credentials.create()`
+    pub response: String,
+    /// Challenge issued for this ceremony, base64url-encoded
+    pub challenge: String,
+}
+
+/// Output contract for RegisterPasskey use case.
+#[derive(Debug)]
+pub struct RegisterPasskeyOutput {
+    pub credential_id: String,
+}
+
+/// Use case for registering a passkey to a user.
+pub struct RegisterPasskey<'a> {
+    passkey_repo: &'a (dyn PasskeyRepository + Send + Sync),
+    webauthn_verifier: &'a (dyn WebAuthnVerifier + Send + Sync),
+}
+
+impl<'a> RegisterPasskey<'a> {
+    /// Create a new RegisterPasskey use case with dependencies.
+    pub fn new(
+        passkey_repo: &'a (dyn PasskeyRepository + Send + Sync),
+        webauthn_verifier: &'a (dyn WebAuthnVerifier + Send + Sync),
+    ) -> Self {
+        Self {
+            passkey_repo,
+            webauthn_verifier,
+        }
+    }
+
+    /// Execute the passkey registration use case.
+    pub async fn execute(&self, input: RegisterPasskeyInput) -> Result<RegisterPasskeyOutput, CoreError> {
+        // Step 1: Verify the registration response
+        let attested = self
+            .webauthn_verifier
+            .verify_registration(&input.response)
+            .map_err(|e| CredentialError::verification_failed(format!("passkey registration rejected: {}", e)))?;
+
+        // Step 2: Bind it to the challenge issued for this ceremony
+        if input.challenge.is_empty() || attested.challenge != input.challenge {
+            tracing::debug!("[RegisterPasskey] Challenge mismatch for user {}", input.user_id);
+            return Err(CredentialError::verification_failed("passkey registration challenge mismatch").into());
+        }
+
+        // Step 3: Refuse a credential that is already registered
+        if self
+            .passkey_repo
+            .find_by_credential_id(&attested.credential_id)
+            .await
+            .is_some()
+        {
+            return Err(CredentialError::invalid_format("passkey", "credential is already registered").into());
+        }
+
+        // Step 4: Store the public key and initial counter
+        let credential = PasskeyCredential {
+            credential_id: attested.credential_id,
+            user_id: input.user_id,
+            public_key: attested.public_key,
+            sign_count: attested.sign_count,
+        };
+        self.passkey_repo
+            .create(&credential)
+            .await
+            .map_err(|e| AuthenticationError::incomplete_flow(format!("failed to store passkey: {}", e)))?;
+
+        Ok(RegisterPasskeyOutput {
+            credential_id: credential.credential_id,
+        })
+    }
+}
@@ -0,0 +1,83 @@
+//! Use case: RevokeAccessToken
+//!
+//! Kill switch for a single access token, e.g. one known to be leaked.
+//!
+//! Responsibilities:
+//! - Delegate to TokenService for signature validation
+//! - Require an access token carrying `jti` and `exp` claims
+//! - Deny the token's `jti` until its `exp`, after which the entry may lapse
+//!
+//! Does NOT:
+//! - Revoke the session the token belongs to; use RevokeSession for that
+
+use crate::core::error::{CoreError, TokenError};
+use crate::core::token::Token;
+use crate::core::usecases::ports::{TokenDenyList, TokenService};
+
+/// Input contract for RevokeAccessToken use case.
+pub struct RevokeAccessTokenInput {
+    pub access_token: Token,
+}
+
+/// Output contract for RevokeAccessToken use case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevokeAccessTokenOutput {
+    /// Identifier of the denied token
+    pub jti: String,
+    /// When the deny-list entry lapses (Unix epoch seconds), the token's `exp`
+    pub expires_at: i64,
+}
+
+/// Use case for revoking a single access token.
+pub struct RevokeAccessToken<'a> {
+    token_service: &'a (dyn TokenService + Send + Sync),
+    deny_list: &'a (dyn TokenDenyList + Send + Sync),
+}
+
+impl<'a> RevokeAccessToken<'a> {
+    /// Create a new RevokeAccessToken use case with dependencies.
+    pub fn new(
+        token_service: &'a (dyn TokenService + Send + Sync),
+        deny_list: &'a (dyn TokenDenyList + Send + Sync),
+    ) -> Self {
+        Self {
+            token_service,
+            deny_list,
+        }
+    }
+
+    /// Execute the access token revocation use case.
+    pub async fn execute(&self, input: RevokeAccessTokenInput) -> Result<RevokeAccessTokenOutput, CoreError> {
+        // Step 1: Validate token signature via TokenService
+        let claims = self
+            .token_service
+            .validate_access_token(&input.access_token)
+            .await
+            .map_err(|_| TokenError::signature_invalid("access token failed validation"))?;
+        let claims: serde_json::Value = serde_json::from_str(&claims)
+            .map_err(|_| TokenError::malformed("access token claims are not JSON"))?;
+
+        // Step 2: Require an access token that can be identified and expires
+        if claims.get("type").and_then(|v| v.as_str()) != Some("access") {
+            return Err(TokenError::invalid_claims("not an access token").into());
+        }
+        let jti = claims
+            .get("jti")
+            .and_then(|v| v.as_str())
+            .filter(|jti| !jti.is_empty())
+            .ok_or_else(|| TokenError::invalid_claims("missing jti claim"))?;
+        let exp = claims
+            .get("exp")
+            .and_then(|v| v.as_i64())
+            .ok_or_else(|| TokenError::invalid_claims("missing exp claim"))?;
+
+        // Step 3: Deny the token until it would have expired anyway
+        self.deny_list.deny(jti, exp).await?;
+        tracing::debug!("[RevokeAccessToken] Denied access token {} until {}", jti, exp);
+
+        Ok(RevokeAccessTokenOutput {
+            jti: jti.to_string(),
+            expires_at: exp,
+        })
+    }
+}
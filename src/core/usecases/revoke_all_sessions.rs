@@ -0,0 +1,72 @@
+//! Use case: RevokeAllSessions
+//!
+//! Orchestrates "log out everywhere".
+//!
+//! Responsibilities:
+//! - Revoke every active session belonging to the user, including the current one
+//! - Report how many sessions were revoked
+//! - Optionally record the revocation to an audit sink
+//!
+//! The user id must come from an authenticated source (validated token
+//! claims); this use case does not check ownership itself.
+
+use crate::core::error::{CoreError, InvariantError};
+use crate::core::usecases::ports::{AuditEvent, AuditEventType, AuditOutcome, AuditSink, SessionRepository};
+
+/// Input contract for RevokeAllSessions use case.
+pub struct RevokeAllSessionsInput {
+    pub user_id: String,
+}
+
+/// Output contract for RevokeAllSessions use case.
+#[derive(Debug)]
+pub struct RevokeAllSessionsOutput {
+    pub sessions_revoked: u64,
+}
+
+/// Use case for revoking every session of a user.
+pub struct RevokeAllSessions<'a> {
+    session_repo: &'a (dyn SessionRepository + Send + Sync),
+    audit_sink: Option<&'a (dyn AuditSink + Send + Sync)>,
+}
+
+impl<'a> RevokeAllSessions<'a> {
+    /// Create a new RevokeAllSessions use case with dependencies.
+    pub fn new(session_repo: &'a (dyn SessionRepository + Send + Sync)) -> Self {
+        Self { session_repo, audit_sink: None }
+    }
+
+    /// Record every revocation to `audit_sink`.
+    pub fn with_audit_sink(mut self, audit_sink: &'a (dyn AuditSink + Send + Sync)) -> Self {
+        self.audit_sink = Some(audit_sink);
+        self
+    }
+
+    /// Execute the revoke-all-sessions use case.
+    pub async fn execute(&self, input: RevokeAllSessionsInput) -> Result<RevokeAllSessionsOutput, CoreError> {
+        // Step 1: Validate input
+        if input.user_id.is_empty() {
+            return Err(InvariantError::violated("user_id must be provided").into());
+        }
+
+        // Step 2: Revoke everything
+        let sessions_revoked = self.session_repo.revoke_all_for_user(&input.user_id).await;
+
+        tracing::debug!(
+            "[RevokeAllSessions] Revoked {} session(s) for user {}",
+            sessions_revoked,
+            input.user_id
+        );
+
+        if let Some(audit_sink) = self.audit_sink {
+            audit_sink
+                .record_or_log(
+                    AuditEvent::new(&input.user_id, AuditEventType::SessionsRevoked, chrono::Utc::now(), AuditOutcome::Success)
+                        .with_reason(format!("{} session(s) revoked", sessions_revoked)),
+                )
+                .await;
+        }
+
+        Ok(RevokeAllSessionsOutput { sessions_revoked })
+    }
+}
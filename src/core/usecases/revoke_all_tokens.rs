@@ -0,0 +1,54 @@
+//! Use case: RevokeAllTokens
+//!
+//! Global kill switch for an incident: invalidates every access token issued
+//! so far at once.
+//!
+//! Responsibilities:
+//! - Advance the issued-at watermark to the current second, so every
+//!   token issued up to now carries an `iat` at or below it
+//! - Never move the watermark backwards
+//!
+//! Does NOT:
+//! - Revoke sessions; their refresh tokens still issue new access tokens,
+//!   so revoke sessions as well to force users to sign in again
+//!
+//! `iat` has second granularity, so tokens issued later within the same
+//! second are cut off too.
+
+use crate::core::error::CoreError;
+use crate::core::usecases::ports::{Clock, TokenWatermarkStore};
+
+/// Output contract for RevokeAllTokens use case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevokeAllTokensOutput {
+    /// Latest `iat` (Unix epoch seconds) revoked; tokens issued at or
+    /// before it are rejected from now on
+    pub revoked_through: i64,
+}
+
+/// Use case for revoking every access token issued so far.
+pub struct RevokeAllTokens<'a> {
+    watermark: &'a (dyn TokenWatermarkStore + Send + Sync),
+    clock: &'a (dyn Clock + Send + Sync),
+}
+
+impl<'a> RevokeAllTokens<'a> {
+    /// Create a new RevokeAllTokens use case with dependencies.
+    pub fn new(
+        watermark: &'a (dyn TokenWatermarkStore + Send + Sync),
+        clock: &'a (dyn Clock + Send + Sync),
+    ) -> Self {
+        Self { watermark, clock }
+    }
+
+    /// Execute the revoke-all use case.
+    pub async fn execute(&self) -> Result<RevokeAllTokensOutput, CoreError> {
+        let revoked_through = self.watermark.advance(self.clock.now().timestamp()).await?;
+        tracing::debug!(
+            "[RevokeAllTokens] Access tokens issued at or before {} are now rejected",
+            revoked_through
+        );
+
+        Ok(RevokeAllTokensOutput { revoked_through })
+    }
+}
@@ -0,0 +1,66 @@
+//! Use case: RevokeOtherSessions
+//!
+//! Orchestrates "log out other devices".
+//!
+//! Responsibilities:
+//! - Validate the caller's current session is active
+//! - Revoke every other active session belonging to the user
+//! - Report how many sessions were revoked
+
+use crate::core::error::{AuthenticationError, CoreError, InvariantError};
+use crate::core::usecases::ports::SessionRepository;
+
+/// Input contract for RevokeOtherSessions use case.
+pub struct RevokeOtherSessionsInput {
+    pub user_id: String,
+    pub current_session_id: String,
+}
+
+/// Output contract for RevokeOtherSessions use case.
+#[derive(Debug)]
+pub struct RevokeOtherSessionsOutput {
+    pub sessions_revoked: u64,
+    pub current_session_id: String,
+}
+
+/// Use case for revoking all sessions except the current one.
+pub struct RevokeOtherSessions<'a> {
+    session_repo: &'a (dyn SessionRepository + Send + Sync),
+}
+
+impl<'a> RevokeOtherSessions<'a> {
+    /// Create a new RevokeOtherSessions use case with dependencies.
+    pub fn new(session_repo: &'a (dyn SessionRepository + Send + Sync)) -> Self {
+        Self { session_repo }
+    }
+
+    /// Execute the revoke-other-sessions use case.
+    pub async fn execute(&self, input: RevokeOtherSessionsInput) -> Result<RevokeOtherSessionsOutput, CoreError> {
+        // Step 1: Validate input
+        if input.user_id.is_empty() || input.current_session_id.is_empty() {
+            return Err(InvariantError::violated("user_id and current_session_id must be provided").into());
+        }
+
+        // Step 2: The session being kept must itself be active
+        if self.session_repo.find_by_id(&input.current_session_id).await.is_none() {
+            return Err(AuthenticationError::user_not_found("session revoked or expired").into());
+        }
+
+        // Step 3: Revoke everything else
+        let sessions_revoked = self
+            .session_repo
+            .revoke_all_for_user_except(&input.user_id, &input.current_session_id)
+            .await;
+
+        tracing::debug!(
+            "[RevokeOtherSessions] Revoked {} session(s) for user {}",
+            sessions_revoked,
+            input.user_id
+        );
+
+        Ok(RevokeOtherSessionsOutput {
+            sessions_revoked,
+            current_session_id: input.current_session_id,
+        })
+    }
+}
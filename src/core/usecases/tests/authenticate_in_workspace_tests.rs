@@ -0,0 +1,201 @@
+//! Tests for AuthenticateInWorkspace use case.
+
+use futures::future::BoxFuture;
+use std::collections::{HashMap, HashSet};
+use super::super::authenticate_in_workspace::{AuthenticateInWorkspace, AuthenticateInWorkspaceInput};
+use crate::core::identity::{UserIdentity, WorkspaceIdentity};
+use crate::core::credentials::StoredCredential;
+use crate::core::usecases::ports::{IdentityRepository, CredentialRepository, PasswordHasher};
+use crate::core::error::{AuthenticationError, CoreError};
+use crate::core::usecases::policies::LockoutPolicy;
+use crate::adapters::clock::SystemClock;
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+/// Identity store with two users, two workspaces and one membership
+struct WorkspaceIdentityRepo {
+    users: HashMap<String, UserIdentity>,
+    workspaces: HashSet<String>,
+    memberships: HashSet<(String, String)>, // (user_id, workspace_id)
+}
+
+impl WorkspaceIdentityRepo {
+    fn new() -> Self {
+        let mut users = HashMap::new();
+        users.insert("alice".to_string(), UserIdentity::new("user-alice"));
+        users.insert("bob".to_string(), UserIdentity::new("user-bob"));
+
+        let workspaces = ["ws-acme", "ws-globex"].iter().map(|w| w.to_string()).collect();
+
+        let mut memberships = HashSet::new();
+        memberships.insert(("user-alice".to_string(), "ws-acme".to_string()));
+
+        Self { users, workspaces, memberships }
+    }
+}
+
+impl IdentityRepository for WorkspaceIdentityRepo {
+    fn find_by_identifier(&self, identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = self.users.get(identifier).cloned();
+        Box::pin(async move { result })
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = self.users.values().find(|u| u.id() == id).cloned();
+        Box::pin(async move { result })
+    }
+
+    fn find_workspace_by_id(&self, workspace_id: &str) -> BoxFuture<'_, Option<WorkspaceIdentity>> {
+        let result = self.workspaces.get(workspace_id).map(WorkspaceIdentity::new);
+        Box::pin(async move { result })
+    }
+
+    fn is_workspace_member(&self, user_id: &str, workspace_id: &str) -> BoxFuture<'_, bool> {
+        let result = self.memberships.contains(&(user_id.to_string(), workspace_id.to_string()));
+        Box::pin(async move { result })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+/// Every user's password is "correct_password"
+struct MockCredentialRepo;
+
+impl CredentialRepository for MockCredentialRepo {
+    fn get_by_user_id(&self, _user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        Box::pin(async move { Some(StoredCredential::from_hash("hashed_correct_password")) })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, _user_id: &str, _new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+struct MockPasswordHasher;
+
+impl PasswordHasher for MockPasswordHasher {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, raw: &str, stored: &StoredCredential) -> bool {
+        stored.as_hash_str() == format!("hashed_{}", raw)
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+async fn authenticate(
+    identifier: &str,
+    password: &str,
+    workspace_id: Option<&str>,
+) -> Result<super::super::authenticate_in_workspace::AuthenticateInWorkspaceOutput, CoreError> {
+    let identity_repo = WorkspaceIdentityRepo::new();
+    let use_case = AuthenticateInWorkspace::new(
+        &identity_repo,
+        &MockCredentialRepo,
+        &MockPasswordHasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 300, true),
+    );
+
+    use_case
+        .execute(AuthenticateInWorkspaceInput {
+            identifier: identifier.to_string(),
+            password: password.to_string(),
+            workspace_id: workspace_id.map(str::to_string),
+            source_ip: None,
+        })
+        .await
+}
+
+fn is_not_member(result: &Result<impl std::fmt::Debug, CoreError>) -> bool {
+    matches!(
+        result,
+        Err(CoreError::Authentication(AuthenticationError::NotWorkspaceMember { .. }))
+    )
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_member_authenticates_into_workspace() {
+    let output = authenticate("alice", "correct_password", Some("ws-acme"))
+        .await
+        .expect("member should authenticate");
+
+    assert_eq!(output.identity.user_id(), Some("user-alice"));
+    assert_eq!(output.identity.workspace_id(), Some("ws-acme"));
+    assert_eq!(output.claims.user_id.as_deref(), Some("user-alice"));
+    assert_eq!(output.claims.workspace_id.as_deref(), Some("ws-acme"));
+}
+
+#[tokio::test]
+async fn test_non_member_is_rejected() {
+    let result = authenticate("bob", "correct_password", Some("ws-acme")).await;
+
+    assert!(is_not_member(&result), "expected NotWorkspaceMember, got {:?}", result);
+}
+
+#[tokio::test]
+async fn test_member_of_other_workspace_is_rejected() {
+    let result = authenticate("alice", "correct_password", Some("ws-globex")).await;
+
+    assert!(is_not_member(&result));
+}
+
+#[tokio::test]
+async fn test_unknown_workspace_fails_like_non_membership() {
+    let result = authenticate("alice", "correct_password", Some("ws-missing")).await;
+
+    assert!(is_not_member(&result));
+}
+
+#[tokio::test]
+async fn test_wrong_password_fails_before_membership_is_checked() {
+    let result = authenticate("bob", "wrong_password", Some("ws-acme")).await;
+
+    assert!(matches!(
+        result,
+        Err(CoreError::Authentication(AuthenticationError::UserNotFound { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn test_without_workspace_authenticates_globally() {
+    let output = authenticate("bob", "correct_password", None)
+        .await
+        .expect("global login should succeed");
+
+    assert_eq!(output.identity.user_id(), Some("user-bob"));
+    assert!(output.identity.workspace_id().is_none());
+    assert!(output.claims.workspace_id.is_none());
+}
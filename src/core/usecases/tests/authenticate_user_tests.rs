@@ -3,9 +3,13 @@
 use futures::future::BoxFuture;
 use super::super::authenticate_user::{AuthenticateUser, AuthenticateUserInput};
 use crate::core::identity::UserIdentity;
-use crate::core::credentials::StoredCredential;
-use crate::core::usecases::ports::{IdentityRepository, CredentialRepository, PasswordHasher};
-use crate::core::error::CoreError;
+use crate::core::credentials::{CredentialStatus, StoredCredential};
+use crate::core::usecases::ports::{
+    AuditEvent, AuditEventType, AuditOutcome, AuditSink, IdentityRepository, CredentialRepository, PasswordHasher,
+};
+use crate::core::error::{AuthenticationError, CoreError};
+use crate::core::usecases::policies::{LockoutPolicy, LockoutScope};
+use crate::adapters::clock::{FixedClock, SystemClock};
 
 // ============================================================================
 // Mock Implementations
@@ -53,6 +57,9 @@ struct MockCredentialRepo {
     credentials: std::sync::RwLock<std::collections::HashMap<String, StoredCredential>>,
     failed_attempts: std::sync::RwLock<std::collections::HashMap<String, u32>>,
     locked_until: std::sync::RwLock<std::collections::HashMap<String, String>>,
+    password_updates: std::sync::RwLock<Vec<(String, String)>>,
+    statuses: std::sync::RwLock<std::collections::HashMap<String, CredentialStatus>>,
+    last_logins: std::sync::RwLock<std::collections::HashMap<String, String>>,
 }
 
 impl MockCredentialRepo {
@@ -71,6 +78,9 @@ impl MockCredentialRepo {
             credentials: std::sync::RwLock::new(credentials),
             failed_attempts: std::sync::RwLock::new(std::collections::HashMap::new()),
             locked_until: std::sync::RwLock::new(std::collections::HashMap::new()),
+            password_updates: std::sync::RwLock::new(Vec::new()),
+            statuses: std::sync::RwLock::new(std::collections::HashMap::new()),
+            last_logins: std::sync::RwLock::new(std::collections::HashMap::new()),
         }
     }
     
@@ -81,6 +91,18 @@ impl MockCredentialRepo {
     fn get_failed_attempts(&self, user_id: &str) -> u32 {
         *self.failed_attempts.read().unwrap().get(user_id).unwrap_or(&0)
     }
+    
+    fn get_password_updates(&self) -> Vec<(String, String)> {
+        self.password_updates.read().unwrap().clone()
+    }
+    
+    fn set_status(&self, user_id: &str, status: CredentialStatus) {
+        self.statuses.write().unwrap().insert(user_id.to_string(), status);
+    }
+    
+    fn get_last_login(&self, user_id: &str) -> Option<String> {
+        self.last_logins.read().unwrap().get(user_id).cloned()
+    }
 }
 
 impl CredentialRepository for MockCredentialRepo {
@@ -88,6 +110,7 @@ impl CredentialRepository for MockCredentialRepo {
         let credentials = self.credentials.read().unwrap();
         let failed_attempts = self.failed_attempts.read().unwrap();
         let locked_until = self.locked_until.read().unwrap();
+        let last_logins = self.last_logins.read().unwrap();
         
         let result = credentials.get(user_id).map(|c| {
             let mut cred = StoredCredential::from_hash(c.as_hash_str());
@@ -95,11 +118,23 @@ impl CredentialRepository for MockCredentialRepo {
             cred.failed_attempts = *failed_attempts.get(user_id).unwrap_or(&c.failed_attempts);
             // Get locked_until from tracking map (more up-to-date than stored credential)
             cred.locked_until = locked_until.get(user_id).cloned().or_else(|| c.locked_until.clone());
+            cred.last_login_at = last_logins.get(user_id).cloned();
             cred
         });
         Box::pin(async move { result })
     }
     
+    fn get_status(&self, user_id: &str) -> BoxFuture<'_, CredentialStatus> {
+        let result = self
+            .statuses
+            .read()
+            .unwrap()
+            .get(user_id)
+            .cloned()
+            .unwrap_or(CredentialStatus::Active);
+        Box::pin(async move { result })
+    }
+    
     fn update_failed_attempts(&self, user_id: &str, attempts: u32) -> BoxFuture<'_, ()> {
         self.failed_attempts.write().unwrap().insert(user_id.to_string(), attempts);
         Box::pin(async move {})
@@ -110,7 +145,22 @@ impl CredentialRepository for MockCredentialRepo {
         Box::pin(async move {})
     }
     
-    fn update_password(&self, _user_id: &str, _new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+    fn clear_lock(&self, user_id: &str) -> BoxFuture<'_, ()> {
+        self.locked_until.write().unwrap().remove(user_id);
+        self.failed_attempts.write().unwrap().insert(user_id.to_string(), 0);
+        Box::pin(async move {})
+    }
+    
+    fn record_successful_login(&self, user_id: &str, at: &str) -> BoxFuture<'_, ()> {
+        self.last_logins.write().unwrap().insert(user_id.to_string(), at.to_string());
+        Box::pin(async move {})
+    }
+    
+    fn update_password(&self, user_id: &str, new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        self.password_updates
+            .write()
+            .unwrap()
+            .push((user_id.to_string(), new_credential.as_hash_str().to_string()));
         Box::pin(async move {})
     }
     
@@ -133,6 +183,48 @@ impl PasswordHasher for MockPasswordHasher {
     }
 }
 
+/// Hasher whose current scheme is `hashed_v2_`; plain `hashed_` is legacy.
+struct UpgradingPasswordHasher;
+
+impl PasswordHasher for UpgradingPasswordHasher {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_v2_{}", raw))
+    }
+    
+    fn verify(&self, raw: &str, stored: &StoredCredential) -> bool {
+        stored.as_hash_str() == format!("hashed_v2_{}", raw)
+            || stored.as_hash_str() == format!("hashed_{}", raw)
+    }
+    
+    fn needs_rehash(&self, stored: &StoredCredential) -> bool {
+        !stored.as_hash_str().starts_with("hashed_v2_")
+    }
+}
+
+/// Hasher counting verifications; `dummy_verify` keeps its default, which
+/// runs one verification against a dummy hash.
+#[derive(Default)]
+struct CountingPasswordHasher {
+    verifications: std::sync::atomic::AtomicUsize,
+}
+
+impl CountingPasswordHasher {
+    fn verifications(&self) -> usize {
+        self.verifications.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+impl PasswordHasher for CountingPasswordHasher {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, raw: &str, stored: &StoredCredential) -> bool {
+        self.verifications.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        stored.as_hash_str() == format!("hashed_{}", raw)
+    }
+}
+
 // ============================================================================
 // Test Cases
 // ============================================================================
@@ -147,13 +239,14 @@ async fn test_authenticate_user_success() {
         &identity_repo,
         &credential_repo,
         &password_hasher,
-        5,    // max_attempts
-        60,   // lockout_duration_minutes
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
     );
     
     let input = AuthenticateUserInput {
         identifier: "valid_user".to_string(),
         password: "correct_password".to_string(),
+        source_ip: None,
     };
     
     let result = use_case.execute(input).await;
@@ -176,13 +269,14 @@ async fn test_authenticate_user_not_found() {
         &identity_repo,
         &credential_repo,
         &password_hasher,
-        5,
-        60,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
     );
     
     let input = AuthenticateUserInput {
         identifier: "nonexistent_user".to_string(),
         password: "any_password".to_string(),
+        source_ip: None,
     };
     
     let result = use_case.execute(input).await;
@@ -207,13 +301,14 @@ async fn test_authenticate_user_wrong_password() {
         &identity_repo,
         &credential_repo,
         &password_hasher,
-        5,
-        60,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
     );
     
     let input = AuthenticateUserInput {
         identifier: "valid_user".to_string(),
         password: "wrong_password".to_string(),
+        source_ip: None,
     };
     
     let result = use_case.execute(input).await;
@@ -241,13 +336,14 @@ async fn test_authenticate_user_account_locked_by_time() {
         &identity_repo,
         &credential_repo,
         &password_hasher,
-        5,
-        60,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
     );
     
     let input = AuthenticateUserInput {
         identifier: "locked_user".to_string(),
         password: "locked_password".to_string(),
+        source_ip: None,
     };
     
     let result = use_case.execute(input).await;
@@ -271,8 +367,8 @@ async fn test_authenticate_user_lockout_after_max_attempts() {
         &identity_repo,
         &credential_repo,
         &password_hasher,
-        3,    // max_attempts = 3
-        60,
+        &SystemClock,
+        LockoutPolicy::new(3, 60 * 60, true),
     );
     
     // First 2 failed attempts
@@ -280,6 +376,7 @@ async fn test_authenticate_user_lockout_after_max_attempts() {
         let input = AuthenticateUserInput {
             identifier: "valid_user".to_string(),
             password: "wrong_password".to_string(),
+            source_ip: None,
         };
         let result = use_case.execute(input).await;
         assert!(result.is_err());
@@ -294,6 +391,7 @@ async fn test_authenticate_user_lockout_after_max_attempts() {
     let input = AuthenticateUserInput {
         identifier: "valid_user".to_string(),
         password: "wrong_password".to_string(),
+        source_ip: None,
     };
     let result = use_case.execute(input).await;
     assert!(result.is_err());
@@ -306,6 +404,7 @@ async fn test_authenticate_user_lockout_after_max_attempts() {
     let input = AuthenticateUserInput {
         identifier: "valid_user".to_string(),
         password: "correct_password".to_string(), // Even with correct password
+        source_ip: None,
     };
     let result = use_case.execute(input).await;
     assert!(result.is_err(), "Should be locked out after max attempts");
@@ -321,8 +420,8 @@ async fn test_authenticate_user_reset_failed_attempts_on_success() {
         &identity_repo,
         &credential_repo,
         &password_hasher,
-        5,
-        60,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
     );
     
     // First, add some failed attempts
@@ -333,6 +432,7 @@ async fn test_authenticate_user_reset_failed_attempts_on_success() {
     let input = AuthenticateUserInput {
         identifier: "valid_user".to_string(),
         password: "correct_password".to_string(),
+        source_ip: None,
     };
     
     let _output = use_case.execute(input).await;
@@ -342,6 +442,55 @@ async fn test_authenticate_user_reset_failed_attempts_on_success() {
     assert_eq!(credential_repo.get_failed_attempts("user123"), 0);
 }
 
+#[tokio::test]
+async fn test_authenticate_user_reports_recent_failed_attempts() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    );
+
+    for _ in 0..2 {
+        let result = use_case
+            .execute(AuthenticateUserInput {
+                identifier: "valid_user".to_string(),
+                password: "wrong_password".to_string(),
+                source_ip: None,
+            })
+            .await;
+        assert!(result.is_err());
+    }
+
+    let output = use_case
+        .execute(AuthenticateUserInput {
+            identifier: "valid_user".to_string(),
+            password: "correct_password".to_string(),
+            source_ip: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(output.recent_failed_attempts, 2);
+    assert_eq!(credential_repo.get_failed_attempts("user123"), 0);
+
+    // The next clean login has nothing to report
+    let output = use_case
+        .execute(AuthenticateUserInput {
+            identifier: "valid_user".to_string(),
+            password: "correct_password".to_string(),
+            source_ip: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(output.recent_failed_attempts, 0);
+}
+
 #[tokio::test]
 async fn test_authenticate_user_no_credential_found() {
     let identity_repo = MockIdentityRepo::new();
@@ -352,14 +501,15 @@ async fn test_authenticate_user_no_credential_found() {
         &identity_repo,
         &credential_repo,
         &password_hasher,
-        5,
-        60,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
     );
     
     // User exists but has no credential
     let input = AuthenticateUserInput {
         identifier: "no_credential_user".to_string(),
         password: "any_password".to_string(),
+        source_ip: None,
     };
     
     let result = use_case.execute(input).await;
@@ -380,13 +530,14 @@ async fn test_authenticate_user_lockout_expired() {
         &identity_repo,
         &credential_repo,
         &password_hasher,
-        5,
-        60,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
     );
     
     let input = AuthenticateUserInput {
         identifier: "locked_user".to_string(),
         password: "locked_password".to_string(),
+        source_ip: None,
     };
     
     // Should succeed because lock has expired
@@ -401,3 +552,1199 @@ async fn test_authenticate_user_lockout_expired() {
         _ => {} // Could succeed or fail for other reasons
     }
 }
+
+#[tokio::test]
+async fn test_authenticate_user_rehashes_outdated_credential() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = UpgradingPasswordHasher;
+    
+    let use_case = AuthenticateUser::new(&identity_repo, &credential_repo, &password_hasher, &SystemClock, LockoutPolicy::new(5, 60 * 60, true));
+    
+    let input = AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "correct_password".to_string(),
+        source_ip: None,
+    };
+    assert!(use_case.execute(input).await.is_ok());
+    
+    let updates = credential_repo.get_password_updates();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].0, "user123");
+    assert_eq!(updates[0].1, "hashed_v2_correct_password");
+}
+
+#[tokio::test]
+async fn test_authenticate_user_does_not_rehash_current_credential() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    credential_repo.credentials.write().unwrap().insert(
+        "user123".to_string(),
+        StoredCredential::from_hash("hashed_v2_correct_password"),
+    );
+    let password_hasher = UpgradingPasswordHasher;
+    
+    let use_case = AuthenticateUser::new(&identity_repo, &credential_repo, &password_hasher, &SystemClock, LockoutPolicy::new(5, 60 * 60, true));
+    
+    let input = AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "correct_password".to_string(),
+        source_ip: None,
+    };
+    assert!(use_case.execute(input).await.is_ok());
+    assert!(credential_repo.get_password_updates().is_empty());
+}
+
+#[tokio::test]
+async fn test_authenticate_user_does_not_rehash_on_failure() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = UpgradingPasswordHasher;
+    
+    let use_case = AuthenticateUser::new(&identity_repo, &credential_repo, &password_hasher, &SystemClock, LockoutPolicy::new(5, 60 * 60, true));
+    
+    let input = AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "wrong_password".to_string(),
+        source_ip: None,
+    };
+    assert!(use_case.execute(input).await.is_err());
+    assert!(credential_repo.get_password_updates().is_empty());
+}
+
+fn locked_for_secs(credential_repo: &MockCredentialRepo, user_id: &str) -> i64 {
+    let until = credential_repo.locked_until.read().unwrap().get(user_id).cloned().unwrap();
+    let until = chrono::DateTime::parse_from_rfc3339(&until).unwrap();
+    (until.with_timezone(&chrono::Utc) - chrono::Utc::now()).num_seconds()
+}
+
+#[tokio::test]
+async fn test_authenticate_user_lockout_escalates_exponentially() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let policy = LockoutPolicy::new(3, 60, true).with_exponential_backoff(3600);
+    
+    let use_case = AuthenticateUser::new(&identity_repo, &credential_repo, &password_hasher, &SystemClock, policy);
+    
+    let wrong = || AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "wrong_password".to_string(),
+        source_ip: None,
+    };
+    
+    // First lockout: reaching the threshold locks for the base duration
+    credential_repo.update_failed_attempts("user123", 2).await;
+    assert!(use_case.execute(wrong()).await.is_err());
+    let first = locked_for_secs(&credential_repo, "user123");
+    assert!((55..=60).contains(&first), "first lockout was {}s", first);
+    
+    // Second lockout (after the first expired): twice the base
+    credential_repo.locked_until.write().unwrap().clear();
+    assert!(use_case.execute(wrong()).await.is_err());
+    let second = locked_for_secs(&credential_repo, "user123");
+    assert!((115..=120).contains(&second), "second lockout was {}s", second);
+}
+
+#[tokio::test]
+async fn test_authenticate_user_success_resets_lockout_escalation() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let policy = LockoutPolicy::new(3, 60, true).with_exponential_backoff(3600);
+    
+    let use_case = AuthenticateUser::new(&identity_repo, &credential_repo, &password_hasher, &SystemClock, policy);
+    
+    // Escalated state from earlier lockouts, now expired
+    credential_repo.update_failed_attempts("user123", 5).await;
+    
+    let input = AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "correct_password".to_string(),
+        source_ip: None,
+    };
+    assert!(use_case.execute(input).await.is_ok());
+    assert_eq!(credential_repo.get_failed_attempts("user123"), 0);
+    
+    // The next lockout starts from the base duration again
+    credential_repo.update_failed_attempts("user123", 2).await;
+    let input = AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "wrong_password".to_string(),
+        source_ip: None,
+    };
+    assert!(use_case.execute(input).await.is_err());
+    let lock = locked_for_secs(&credential_repo, "user123");
+    assert!((55..=60).contains(&lock), "lockout after reset was {}s", lock);
+}
+
+#[tokio::test]
+async fn test_authenticate_user_expired_credential_rejected() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    credential_repo.set_status(
+        "user123",
+        CredentialStatus::Expired { expired_at: Some("2024-01-01T00:00:00Z".to_string()) },
+    );
+    credential_repo.update_failed_attempts("user123", 2).await;
+    
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    );
+    
+    let input = AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "correct_password".to_string(),
+        source_ip: None,
+    };
+    
+    match use_case.execute(input).await {
+        Err(CoreError::Authentication(err)) => {
+            assert!(err.is_credential_expired(), "expected CredentialExpired, got {:?}", err);
+            assert!(err.to_string().contains("2024-01-01T00:00:00Z"));
+        }
+        other => panic!("expected credential expired error, got {:?}", other),
+    }
+    
+    // Not a wrong-password attempt: counter untouched, no lock applied
+    assert_eq!(credential_repo.get_failed_attempts("user123"), 2);
+    assert!(credential_repo.locked_until.read().unwrap().get("user123").is_none());
+}
+
+#[tokio::test]
+async fn test_authenticate_user_not_yet_valid_credential_rejected() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    credential_repo.set_status(
+        "user123",
+        CredentialStatus::NotYetValid { valid_from: Some("2099-01-01T00:00:00Z".to_string()) },
+    );
+    
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    );
+    
+    let input = AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "correct_password".to_string(),
+        source_ip: None,
+    };
+    
+    let result = use_case.execute(input).await;
+    assert!(matches!(
+        result,
+        Err(CoreError::Authentication(AuthenticationError::CredentialExpired { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn test_authenticate_user_unusable_credential_with_wrong_password_is_invalid_credentials() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    credential_repo.set_status("user123", CredentialStatus::Expired { expired_at: None });
+    
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    );
+    
+    let input = AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "wrong_password".to_string(),
+        source_ip: None,
+    };
+    
+    // Status is only revealed once the password is proven
+    match use_case.execute(input).await {
+        Err(CoreError::Authentication(err)) => assert!(!err.is_credential_expired()),
+        other => panic!("expected authentication error, got {:?}", other),
+    }
+    assert_eq!(credential_repo.get_failed_attempts("user123"), 1);
+}
+
+#[tokio::test]
+async fn test_authenticate_user_active_credential_proceeds() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    credential_repo.set_status("user123", CredentialStatus::Active);
+    
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    );
+    
+    let input = AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "correct_password".to_string(),
+        source_ip: None,
+    };
+    
+    let output = use_case.execute(input).await.unwrap();
+    assert_eq!(output.user.id(), "user123");
+}
+
+// ============================================================================
+// Injected Clock
+// ============================================================================
+
+fn frozen_instant() -> chrono::DateTime<chrono::Utc> {
+    use chrono::TimeZone;
+    chrono::Utc.timestamp_opt(1_700_000_000, 0).unwrap()
+}
+
+#[tokio::test]
+async fn test_authenticate_user_lockout_evaluated_against_injected_clock() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let clock = FixedClock::new(frozen_instant());
+    
+    // Lock expressed relative to the frozen clock, far in the real past
+    let locked_until = frozen_instant() + chrono::Duration::seconds(60);
+    credential_repo.set_locked_until("user123", &locked_until.to_rfc3339());
+    
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &clock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    );
+    let input = || AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "correct_password".to_string(),
+        source_ip: None,
+    };
+    
+    match use_case.execute(input()).await {
+        Err(CoreError::Authentication(err)) => assert!(err.is_account_locked()),
+        other => panic!("expected account locked, got {:?}", other),
+    }
+    
+    // One second before expiry: still locked
+    clock.advance(chrono::Duration::seconds(59));
+    match use_case.execute(input()).await {
+        Err(CoreError::Authentication(err)) => assert!(err.is_account_locked()),
+        other => panic!("expected account locked, got {:?}", other),
+    }
+    
+    // Exactly at the expiry instant: the lock has lifted
+    clock.advance(chrono::Duration::seconds(1));
+    assert!(use_case.execute(input()).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_authenticate_user_lockout_until_computed_from_injected_clock() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let clock = FixedClock::new(frozen_instant());
+    
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &clock,
+        LockoutPolicy::new(1, 90, true),
+    );
+    let input = AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "wrong_password".to_string(),
+        source_ip: None,
+    };
+    assert!(use_case.execute(input).await.is_err());
+    
+    let until = credential_repo.locked_until.read().unwrap().get("user123").cloned().unwrap();
+    let until = chrono::DateTime::parse_from_rfc3339(&until).unwrap();
+    assert_eq!(until, frozen_instant() + chrono::Duration::seconds(90));
+}
+
+#[tokio::test]
+async fn test_authenticate_user_unlock_on_success_clears_expired_lock() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let clock = FixedClock::new(frozen_instant());
+    
+    // Locked out earlier; the lock window has since passed
+    credential_repo.update_failed_attempts("user123", 5).await;
+    let expired = (frozen_instant() - chrono::Duration::minutes(1)).to_rfc3339();
+    credential_repo.set_locked_until("user123", &expired);
+    
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &clock,
+        LockoutPolicy::new(5, 60, false).with_unlock_on_successful_auth(true),
+    );
+    let output = use_case
+        .execute(AuthenticateUserInput {
+            identifier: "valid_user".to_string(),
+            password: "correct_password".to_string(),
+            source_ip: None,
+        })
+        .await
+        .unwrap();
+    
+    assert_eq!(output.recent_failed_attempts, 5);
+    assert_eq!(credential_repo.get_failed_attempts("user123"), 0);
+    assert!(credential_repo.locked_until.read().unwrap().get("user123").is_none());
+    
+    // A single wrong password afterwards does not re-lock the account
+    let result = use_case
+        .execute(AuthenticateUserInput {
+            identifier: "valid_user".to_string(),
+            password: "wrong_password".to_string(),
+            source_ip: None,
+        })
+        .await;
+    match result {
+        Err(CoreError::Authentication(err)) => assert!(!err.is_account_locked()),
+        other => panic!("expected invalid credentials, got {:?}", other),
+    }
+    assert!(credential_repo.locked_until.read().unwrap().get("user123").is_none());
+}
+
+#[tokio::test]
+async fn test_authenticate_user_without_unlock_on_success_keeps_time_based_unlock() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let clock = FixedClock::new(frozen_instant());
+    
+    credential_repo.update_failed_attempts("user123", 5).await;
+    let expired = (frozen_instant() - chrono::Duration::minutes(1)).to_rfc3339();
+    credential_repo.set_locked_until("user123", &expired);
+    
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &clock,
+        LockoutPolicy::new(5, 60, false),
+    );
+    
+    // The expired lock no longer blocks a correct password...
+    assert!(use_case
+        .execute(AuthenticateUserInput {
+            identifier: "valid_user".to_string(),
+            password: "correct_password".to_string(),
+            source_ip: None,
+        })
+        .await
+        .is_ok());
+    
+    // ...but nothing is cleared, so the lock record and counter persist
+    assert_eq!(credential_repo.get_failed_attempts("user123"), 5);
+    assert_eq!(
+        credential_repo.locked_until.read().unwrap().get("user123").cloned(),
+        Some(expired)
+    );
+}
+
+#[tokio::test]
+async fn test_authenticate_user_revoked_credential_rejected() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = UpgradingPasswordHasher;
+    credential_repo.set_status(
+        "user123",
+        CredentialStatus::Revoked { revoked_at: Some("2024-06-01T00:00:00Z".to_string()) },
+    );
+    credential_repo.update_failed_attempts("user123", 2).await;
+    
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    );
+    
+    let input = AuthenticateUserInput {
+        identifier: "valid_user".to_string(),
+        password: "correct_password".to_string(),
+        source_ip: None,
+    };
+    
+    match use_case.execute(input).await {
+        Err(CoreError::Authentication(err)) => {
+            assert!(err.is_credential_expired(), "expected CredentialExpired, got {:?}", err);
+            assert!(err.to_string().contains("revoked"));
+            assert!(err.to_string().contains("2024-06-01T00:00:00Z"));
+        }
+        other => panic!("expected credential revoked error, got {:?}", other),
+    }
+    
+    // A rejected credential is neither upgraded nor has its counter reset
+    assert!(credential_repo.get_password_updates().is_empty());
+    assert_eq!(credential_repo.get_failed_attempts("user123"), 2);
+}
+
+#[tokio::test]
+async fn test_authenticate_user_status_does_not_change_wrong_password_shape() {
+    let statuses = [
+        CredentialStatus::Active,
+        CredentialStatus::Revoked { revoked_at: None },
+        CredentialStatus::Expired { expired_at: None },
+        CredentialStatus::NotYetValid { valid_from: None },
+    ];
+    
+    let mut errors = Vec::new();
+    for status in statuses {
+        let identity_repo = MockIdentityRepo::new();
+        let credential_repo = MockCredentialRepo::new();
+        let password_hasher = MockPasswordHasher;
+        credential_repo.set_status("user123", status);
+        
+        let use_case = AuthenticateUser::new(
+            &identity_repo,
+            &credential_repo,
+            &password_hasher,
+            &SystemClock,
+            LockoutPolicy::new(5, 60 * 60, true),
+        );
+        let input = AuthenticateUserInput {
+            identifier: "valid_user".to_string(),
+            password: "wrong_password".to_string(),
+            source_ip: None,
+        };
+        
+        errors.push(use_case.execute(input).await.unwrap_err().to_string());
+        assert_eq!(credential_repo.get_failed_attempts("user123"), 1);
+    }
+    
+    // Every status yields the identical wrong-password error
+    assert!(errors.windows(2).all(|pair| pair[0] == pair[1]), "errors differ: {:?}", errors);
+}
+
+/// Run a login with a fresh counting hasher, returning the error and the
+/// number of verifications performed.
+async fn failed_login_cost(identifier: &str, password: &str) -> (String, usize) {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = CountingPasswordHasher::default();
+
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    );
+
+    let error = use_case
+        .execute(AuthenticateUserInput {
+            identifier: identifier.to_string(),
+            password: password.to_string(),
+            source_ip: None,
+        })
+        .await
+        .expect_err("login should fail");
+
+    (error.to_string(), password_hasher.verifications())
+}
+
+#[tokio::test]
+async fn test_authenticate_user_unknown_identifier_costs_same_as_wrong_password() {
+    let (unknown_error, unknown_cost) = failed_login_cost("nonexistent_user", "any_password").await;
+    let (wrong_error, wrong_cost) = failed_login_cost("valid_user", "wrong_password").await;
+
+    assert_eq!(wrong_cost, 1);
+    assert_eq!(unknown_cost, wrong_cost, "unknown identifier must run the hasher like a wrong password");
+    assert_eq!(unknown_error, wrong_error, "both failures must look identical");
+}
+
+#[tokio::test]
+async fn test_authenticate_user_missing_credential_costs_same_as_wrong_password() {
+    let (missing_error, missing_cost) = failed_login_cost("no_credential_user", "any_password").await;
+    let (wrong_error, wrong_cost) = failed_login_cost("valid_user", "wrong_password").await;
+
+    assert_eq!(missing_cost, wrong_cost);
+    assert_eq!(missing_error, wrong_error);
+}
+
+#[tokio::test]
+async fn test_authenticate_user_locked_account_costs_same_as_wrong_password() {
+    let identity_repo = MockIdentityRepo::new();
+    let credential_repo = MockCredentialRepo::new();
+    let future_time = (chrono::Utc::now() + chrono::Duration::hours(1)).to_rfc3339();
+    credential_repo.set_locked_until("user456", &future_time);
+    let password_hasher = CountingPasswordHasher::default();
+
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    );
+    let result = use_case
+        .execute(AuthenticateUserInput {
+            identifier: "locked_user".to_string(),
+            password: "locked_password".to_string(),
+            source_ip: None,
+        })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(CoreError::Authentication(AuthenticationError::AccountLocked { .. }))
+    ));
+    let (_, wrong_cost) = failed_login_cost("valid_user", "wrong_password").await;
+    assert_eq!(password_hasher.verifications(), wrong_cost, "a locked account must run the hasher too");
+}
+
+// ============================================================================
+// Last login tracking
+// ============================================================================
+
+async fn login_at(
+    credential_repo: &MockCredentialRepo,
+    instant: chrono::DateTime<chrono::Utc>,
+    password: &str,
+) -> Result<super::super::authenticate_user::AuthenticateUserOutput, CoreError> {
+    let identity_repo = MockIdentityRepo::new();
+    let clock = FixedClock::new(instant);
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        credential_repo,
+        &MockPasswordHasher,
+        &clock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    );
+
+    use_case
+        .execute(AuthenticateUserInput {
+            identifier: "valid_user".to_string(),
+            password: password.to_string(),
+            source_ip: None,
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_authenticate_user_first_login_has_no_previous_login() {
+    let credential_repo = MockCredentialRepo::new();
+    let first = frozen_instant();
+
+    let output = login_at(&credential_repo, first, "correct_password").await.unwrap();
+
+    assert!(output.previous_login_at.is_none());
+    assert_eq!(credential_repo.get_last_login("user123"), Some(first.to_rfc3339()));
+}
+
+#[tokio::test]
+async fn test_authenticate_user_returns_login_before_this_one() {
+    let credential_repo = MockCredentialRepo::new();
+    let first = frozen_instant();
+    let second = first + chrono::Duration::hours(2);
+
+    login_at(&credential_repo, first, "correct_password").await.unwrap();
+    let output = login_at(&credential_repo, second, "correct_password").await.unwrap();
+
+    assert_eq!(output.previous_login_at, Some(first.to_rfc3339()));
+    assert_eq!(credential_repo.get_last_login("user123"), Some(second.to_rfc3339()));
+}
+
+#[tokio::test]
+async fn test_authenticate_user_without_login_record_leaves_it_to_the_caller() {
+    let credential_repo = MockCredentialRepo::new();
+    let first = frozen_instant();
+    login_at(&credential_repo, first, "correct_password").await.unwrap();
+
+    let identity_repo = MockIdentityRepo::new();
+    let clock = FixedClock::new(first + chrono::Duration::hours(1));
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &MockPasswordHasher,
+        &clock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    )
+    .without_login_record();
+
+    let output = use_case
+        .execute(AuthenticateUserInput {
+            identifier: "valid_user".to_string(),
+            password: "correct_password".to_string(),
+            source_ip: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(output.previous_login_at, Some(first.to_rfc3339()));
+    assert_eq!(credential_repo.get_last_login("user123"), Some(first.to_rfc3339()));
+}
+
+#[tokio::test]
+async fn test_authenticate_user_failed_login_does_not_record_login() {
+    let credential_repo = MockCredentialRepo::new();
+    let first = frozen_instant();
+
+    login_at(&credential_repo, first, "correct_password").await.unwrap();
+    let result = login_at(&credential_repo, first + chrono::Duration::hours(1), "wrong_password").await;
+    assert!(result.is_err());
+
+    assert_eq!(credential_repo.get_last_login("user123"), Some(first.to_rfc3339()));
+}
+
+#[tokio::test]
+async fn test_authenticate_user_reports_prior_failures_with_previous_login() {
+    let credential_repo = MockCredentialRepo::new();
+    let first = frozen_instant();
+
+    login_at(&credential_repo, first, "correct_password").await.unwrap();
+    let _ = login_at(&credential_repo, first + chrono::Duration::minutes(1), "wrong_password").await;
+    let output = login_at(&credential_repo, first + chrono::Duration::minutes(2), "correct_password")
+        .await
+        .unwrap();
+
+    assert_eq!(output.recent_failed_attempts, 1);
+    assert_eq!(output.previous_login_at, Some(first.to_rfc3339()));
+}
+
+// ============================================================================
+// Audit trail
+// ============================================================================
+
+#[derive(Default)]
+struct CapturingAuditSink {
+    events: std::sync::Mutex<Vec<AuditEvent>>,
+}
+
+impl CapturingAuditSink {
+    fn events(&self) -> Vec<AuditEvent> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl AuditSink for CapturingAuditSink {
+    fn record(&self, event: AuditEvent) -> BoxFuture<'_, Result<(), String>> {
+        self.events.lock().unwrap().push(event);
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+struct FailingAuditSink;
+
+impl AuditSink for FailingAuditSink {
+    fn record(&self, _event: AuditEvent) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Err("sink unavailable".to_string()) })
+    }
+}
+
+async fn audited_login(
+    credential_repo: &MockCredentialRepo,
+    audit_sink: &(dyn AuditSink + Send + Sync),
+    lockout_policy: LockoutPolicy,
+    identifier: &str,
+    password: &str,
+) -> Result<super::super::authenticate_user::AuthenticateUserOutput, CoreError> {
+    let identity_repo = MockIdentityRepo::new();
+    let clock = FixedClock::new(frozen_instant());
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        credential_repo,
+        &MockPasswordHasher,
+        &clock,
+        lockout_policy,
+    )
+    .with_audit_sink(audit_sink);
+
+    use_case
+        .execute(AuthenticateUserInput {
+            identifier: identifier.to_string(),
+            password: password.to_string(),
+            source_ip: Some("203.0.113.7".to_string()),
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_authenticate_user_audits_failed_login() {
+    let credential_repo = MockCredentialRepo::new();
+    let sink = CapturingAuditSink::default();
+
+    let result = audited_login(
+        &credential_repo,
+        &sink,
+        LockoutPolicy::new(5, 60 * 60, true),
+        "valid_user",
+        "wrong_password",
+    )
+    .await;
+    assert!(result.is_err());
+
+    let expected = AuditEvent::new("user123", AuditEventType::LoginFailed, frozen_instant(), AuditOutcome::Failure)
+        .with_reason("invalid password")
+        .with_source_ip(Some("203.0.113.7".to_string()));
+    assert_eq!(sink.events(), vec![expected]);
+}
+
+#[tokio::test]
+async fn test_authenticate_user_audits_lockout() {
+    let credential_repo = MockCredentialRepo::new();
+    let sink = CapturingAuditSink::default();
+
+    let result = audited_login(
+        &credential_repo,
+        &sink,
+        LockoutPolicy::new(1, 60 * 60, true),
+        "valid_user",
+        "wrong_password",
+    )
+    .await;
+    assert!(result.is_err());
+
+    let events = sink.events();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].event_type, AuditEventType::LoginFailed);
+
+    let locked = &events[1];
+    assert_eq!(locked.event_type, AuditEventType::AccountLocked);
+    assert_eq!(locked.actor, "user123");
+    assert_eq!(locked.outcome, AuditOutcome::Failure);
+    assert_eq!(locked.timestamp, frozen_instant());
+    assert_eq!(locked.source_ip.as_deref(), Some("203.0.113.7"));
+    let until = (frozen_instant() + chrono::Duration::hours(1)).to_rfc3339();
+    assert_eq!(locked.reason, Some(format!("1 failed attempts, locked until {}", until)));
+}
+
+#[tokio::test]
+async fn test_authenticate_user_audits_unknown_identifier_as_presented() {
+    let credential_repo = MockCredentialRepo::new();
+    let sink = CapturingAuditSink::default();
+
+    let result = audited_login(
+        &credential_repo,
+        &sink,
+        LockoutPolicy::new(5, 60 * 60, true),
+        "nobody",
+        "whatever",
+    )
+    .await;
+    assert!(result.is_err());
+
+    let events = sink.events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].actor, "nobody");
+    assert_eq!(events[0].event_type, AuditEventType::LoginFailed);
+    assert_eq!(events[0].reason.as_deref(), Some("unknown identifier"));
+}
+
+#[tokio::test]
+async fn test_authenticate_user_audits_success() {
+    let credential_repo = MockCredentialRepo::new();
+    let sink = CapturingAuditSink::default();
+
+    audited_login(
+        &credential_repo,
+        &sink,
+        LockoutPolicy::new(5, 60 * 60, true),
+        "valid_user",
+        "correct_password",
+    )
+    .await
+    .unwrap();
+
+    let events = sink.events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_type, AuditEventType::LoginSucceeded);
+    assert_eq!(events[0].outcome, AuditOutcome::Success);
+    assert_eq!(events[0].actor, "user123");
+}
+
+#[tokio::test]
+async fn test_authenticate_user_succeeds_when_audit_sink_fails() {
+    let credential_repo = MockCredentialRepo::new();
+
+    let result = audited_login(
+        &credential_repo,
+        &FailingAuditSink,
+        LockoutPolicy::new(5, 60 * 60, true),
+        "valid_user",
+        "correct_password",
+    )
+    .await;
+
+    assert!(result.is_ok(), "An unavailable audit sink must not block login");
+}
+
+// ============================================================================
+// Suspending Ports
+// ============================================================================
+
+/// Identity repository whose lookups yield to the runtime before answering,
+/// like a real database round-trip.
+struct YieldingIdentityRepo(MockIdentityRepo);
+
+impl IdentityRepository for YieldingIdentityRepo {
+    fn find_by_identifier(&self, identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let lookup = self.0.find_by_identifier(identifier);
+        Box::pin(async move {
+            tokio::task::yield_now().await;
+            lookup.await
+        })
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let lookup = self.0.find_by_id(id);
+        Box::pin(async move {
+            tokio::task::yield_now().await;
+            lookup.await
+        })
+    }
+
+    fn create(
+        &self,
+        user_id: &uuid::Uuid,
+        identifier: &str,
+        password_hash: &str,
+        salt: &str,
+        algorithm: &str,
+        iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        self.0.create(user_id, identifier, password_hash, salt, algorithm, iterations)
+    }
+}
+
+/// Credential repository whose reads and writes yield to the runtime first.
+struct YieldingCredentialRepo(MockCredentialRepo);
+
+impl CredentialRepository for YieldingCredentialRepo {
+    fn get_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        let lookup = self.0.get_by_user_id(user_id);
+        Box::pin(async move {
+            tokio::task::yield_now().await;
+            lookup.await
+        })
+    }
+
+    fn update_failed_attempts(&self, user_id: &str, attempts: u32) -> BoxFuture<'_, ()> {
+        let write = self.0.update_failed_attempts(user_id, attempts);
+        Box::pin(async move {
+            tokio::task::yield_now().await;
+            write.await
+        })
+    }
+
+    fn lock_until(&self, user_id: &str, until: &str) -> BoxFuture<'_, ()> {
+        let write = self.0.lock_until(user_id, until);
+        Box::pin(async move {
+            tokio::task::yield_now().await;
+            write.await
+        })
+    }
+
+    fn get_status(&self, user_id: &str) -> BoxFuture<'_, CredentialStatus> {
+        self.0.get_status(user_id)
+    }
+
+    fn clear_lock(&self, user_id: &str) -> BoxFuture<'_, ()> {
+        self.0.clear_lock(user_id)
+    }
+
+    fn record_successful_login(&self, user_id: &str, at: &str) -> BoxFuture<'_, ()> {
+        self.0.record_successful_login(user_id, at)
+    }
+
+    fn update_password(&self, user_id: &str, new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        self.0.update_password(user_id, new_credential)
+    }
+
+    fn initialize_credential_state(&self, user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        self.0.initialize_credential_state(user_id)
+    }
+}
+
+#[tokio::test]
+async fn test_authenticate_user_against_suspending_ports() {
+    // Ports are async all the way down: no blocking bridge, and two logins
+    // interleave on a single-threaded runtime
+    let identity_repo = YieldingIdentityRepo(MockIdentityRepo::new());
+    let credential_repo = YieldingCredentialRepo(MockCredentialRepo::new());
+    let password_hasher = MockPasswordHasher;
+
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &password_hasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60 * 60, true),
+    );
+
+    let (success, failure) = tokio::join!(
+        use_case.execute(AuthenticateUserInput {
+            identifier: "valid_user".to_string(),
+            password: "correct_password".to_string(),
+            source_ip: None,
+        }),
+        use_case.execute(AuthenticateUserInput {
+            identifier: "locked_user".to_string(),
+            password: "wrong_password".to_string(),
+            source_ip: None,
+        }),
+    );
+
+    assert_eq!(success.unwrap().user.id(), "user123");
+    assert!(matches!(failure, Err(CoreError::Authentication(_))));
+    assert_eq!(credential_repo.0.get_failed_attempts("user123"), 0);
+    assert_eq!(credential_repo.0.get_failed_attempts("user456"), 1);
+}
+
+// ============================================================================
+// Lockout scope
+// ============================================================================
+
+/// Repositories over one in-memory account "alice" with password "correct_password".
+async fn memory_account() -> (
+    crate::adapters::memory::IdentityRepositoryMemory,
+    crate::adapters::memory::CredentialRepositoryMemory,
+) {
+    let store = crate::adapters::memory::MemoryStore::new();
+    let identity_repo = crate::adapters::memory::IdentityRepositoryMemory::new(store.clone());
+    identity_repo
+        .create(&uuid::Uuid::new_v4(), "alice", "hashed_correct_password", "", "", 0)
+        .await
+        .unwrap();
+    (identity_repo, crate::adapters::memory::CredentialRepositoryMemory::new(store))
+}
+
+async fn login_from(use_case: &AuthenticateUser<'_>, password: &str, source_ip: &str) -> Result<(), CoreError> {
+    use_case
+        .execute(AuthenticateUserInput {
+            identifier: "alice".to_string(),
+            password: password.to_string(),
+            source_ip: Some(source_ip.to_string()),
+        })
+        .await
+        .map(|_| ())
+}
+
+#[tokio::test]
+async fn test_authenticate_user_per_ip_lockout_spares_other_sources() {
+    let (identity_repo, credential_repo) = memory_account().await;
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &MockPasswordHasher,
+        &SystemClock,
+        LockoutPolicy::new(3, 60 * 60, true).with_scope(LockoutScope::AccountAndSourceIp),
+    );
+
+    for _ in 0..3 {
+        assert!(login_from(&use_case, "wrong_password", "198.51.100.1").await.is_err());
+    }
+
+    // The attacking address is locked out, even with the right password
+    let locked = login_from(&use_case, "correct_password", "198.51.100.1").await;
+    assert!(matches!(
+        locked,
+        Err(CoreError::Authentication(AuthenticationError::AccountLocked { .. }))
+    ));
+
+    // The victim's own address is not
+    assert!(login_from(&use_case, "correct_password", "203.0.113.7").await.is_ok());
+}
+
+#[tokio::test]
+async fn test_authenticate_user_account_lockout_applies_to_every_source() {
+    let (identity_repo, credential_repo) = memory_account().await;
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &MockPasswordHasher,
+        &SystemClock,
+        LockoutPolicy::new(3, 60 * 60, true),
+    );
+
+    for _ in 0..3 {
+        assert!(login_from(&use_case, "wrong_password", "198.51.100.1").await.is_err());
+    }
+
+    let locked = login_from(&use_case, "correct_password", "203.0.113.7").await;
+    assert!(matches!(
+        locked,
+        Err(CoreError::Authentication(AuthenticationError::AccountLocked { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn test_authenticate_user_per_ip_success_resets_only_its_source() {
+    let (identity_repo, credential_repo) = memory_account().await;
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &MockPasswordHasher,
+        &SystemClock,
+        LockoutPolicy::new(3, 60 * 60, true).with_scope(LockoutScope::AccountAndSourceIp),
+    );
+
+    for _ in 0..2 {
+        assert!(login_from(&use_case, "wrong_password", "198.51.100.1").await.is_err());
+    }
+    assert!(login_from(&use_case, "correct_password", "203.0.113.7").await.is_ok());
+
+    // Two failures are still on record for the first source; one more locks it
+    assert!(login_from(&use_case, "wrong_password", "198.51.100.1").await.is_err());
+    assert!(login_from(&use_case, "correct_password", "198.51.100.1").await.is_err());
+}
+
+#[tokio::test]
+async fn test_authenticate_user_per_ip_lockout_honors_account_lock() {
+    let (identity_repo, credential_repo) = memory_account().await;
+    let user_id = identity_repo.find_by_identifier("alice").await.unwrap().id().to_string();
+    let locked_until = (chrono::Utc::now() + chrono::Duration::hours(1)).to_rfc3339();
+    credential_repo.lock_until(&user_id, &locked_until).await;
+
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &MockPasswordHasher,
+        &SystemClock,
+        LockoutPolicy::new(3, 60 * 60, true).with_scope(LockoutScope::AccountAndSourceIp),
+    );
+
+    let locked = login_from(&use_case, "correct_password", "203.0.113.7").await;
+    assert!(matches!(
+        locked,
+        Err(CoreError::Authentication(AuthenticationError::AccountLocked { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn test_authenticate_user_per_ip_failures_add_up_to_account_ceiling() {
+    let (identity_repo, credential_repo) = memory_account().await;
+    let use_case = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &MockPasswordHasher,
+        &SystemClock,
+        LockoutPolicy::new(3, 60 * 60, true)
+            .with_scope(LockoutScope::AccountAndSourceIp)
+            .with_account_ceiling(4),
+    );
+
+    // No single address reaches its own threshold
+    for source_ip in ["198.51.100.1", "198.51.100.2"] {
+        for _ in 0..2 {
+            assert!(login_from(&use_case, "wrong_password", source_ip).await.is_err());
+        }
+    }
+
+    // Together they locked the account, for every address
+    let locked = login_from(&use_case, "correct_password", "203.0.113.7").await;
+    assert!(matches!(
+        locked,
+        Err(CoreError::Authentication(AuthenticationError::AccountLocked { .. }))
+    ));
+}
+
+// ============================================================================
+// Identifier normalization
+// ============================================================================
+
+async fn login_as(use_case: &AuthenticateUser<'_>, identifier: &str) -> Result<(), CoreError> {
+    use_case
+        .execute(AuthenticateUserInput {
+            identifier: identifier.to_string(),
+            password: "correct_password".to_string(),
+            source_ip: None,
+        })
+        .await
+        .map(|_| ())
+}
+
+#[tokio::test]
+async fn test_authenticate_user_normalizes_identifier_before_lookup() {
+    let store = crate::adapters::memory::MemoryStore::new();
+    let identity_repo = crate::adapters::memory::IdentityRepositoryMemory::new(store.clone());
+    identity_repo
+        .create(&uuid::Uuid::new_v4(), "foo@bar.com", "hashed_correct_password", "", "", 0)
+        .await
+        .unwrap();
+    let credential_repo = crate::adapters::memory::CredentialRepositoryMemory::new(store);
+    let normalizer = crate::adapters::identifier::RuleBasedIdentifierNormalizer::email();
+
+    let plain = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &MockPasswordHasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60, true),
+    );
+    assert!(login_as(&plain, "  Foo@Bar.COM").await.is_err());
+
+    let normalizing = AuthenticateUser::new(
+        &identity_repo,
+        &credential_repo,
+        &MockPasswordHasher,
+        &SystemClock,
+        LockoutPolicy::new(5, 60, true),
+    )
+    .with_identifier_normalizer(&normalizer);
+    assert!(login_as(&normalizing, "  Foo@Bar.COM").await.is_ok());
+}
+
+// ============================================================================
+// Failed attempt reset window
+// ============================================================================
+
+/// Fail one login as "alice" at `instant` and return her failed-attempt count.
+async fn fail_at(
+    identity_repo: &crate::adapters::memory::IdentityRepositoryMemory,
+    credential_repo: &crate::adapters::memory::CredentialRepositoryMemory,
+    instant: chrono::DateTime<chrono::Utc>,
+) -> u32 {
+    let clock = FixedClock::new(instant);
+    let use_case = AuthenticateUser::new(
+        identity_repo,
+        credential_repo,
+        &MockPasswordHasher,
+        &clock,
+        LockoutPolicy::new(5, 60 * 60, true).with_failure_reset_window(24 * 60 * 60),
+    );
+    assert!(login_from(&use_case, "wrong_password", "198.51.100.1").await.is_err());
+
+    let user = identity_repo.find_by_identifier("alice").await.unwrap();
+    credential_repo.get_by_user_id(user.id()).await.unwrap().failed_attempts
+}
+
+#[tokio::test]
+async fn test_authenticate_user_failure_within_reset_window_increments() {
+    let (identity_repo, credential_repo) = memory_account().await;
+    let first = frozen_instant();
+
+    assert_eq!(fail_at(&identity_repo, &credential_repo, first).await, 1);
+    assert_eq!(fail_at(&identity_repo, &credential_repo, first + chrono::Duration::hours(23)).await, 2);
+}
+
+#[tokio::test]
+async fn test_authenticate_user_failure_after_reset_window_starts_over() {
+    let (identity_repo, credential_repo) = memory_account().await;
+    let first = frozen_instant();
+
+    assert_eq!(fail_at(&identity_repo, &credential_repo, first).await, 1);
+    assert_eq!(fail_at(&identity_repo, &credential_repo, first + chrono::Duration::minutes(1)).await, 2);
+    assert_eq!(fail_at(&identity_repo, &credential_repo, first + chrono::Duration::days(30)).await, 1);
+}
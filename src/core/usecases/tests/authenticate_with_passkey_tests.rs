@@ -0,0 +1,225 @@
+//! Tests for AuthenticateWithPasskey use case.
+
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use super::super::authenticate_with_passkey::{AuthenticateWithPasskey, AuthenticateWithPasskeyInput};
+use crate::core::error::{AuthenticationError, CoreError};
+use crate::core::usecases::ports::{
+    AttestedPasskey, PasskeyCredential, PasskeyRepository, VerifiedAssertion, WebAuthnVerifier,
+};
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+const CHALLENGE: &str = "c2VydmVyLWNoYWxsZW5nZQ";
+
+/// Stand-in for the WebAuthn ceremony.
+///
+/// A response is `credential_id|challenge|sign_count|key`; it verifies when
+/// `key` equals the stored public key, as if it had been signed with it.
+struct MockWebAuthnVerifier;
+
+impl MockWebAuthnVerifier {
+    fn response(credential_id: &str, challenge: &str, sign_count: u32, key: &str) -> String {
+        format!("{}|{}|{}|{}", credential_id, challenge, sign_count, key)
+    }
+
+    fn parts(response: &str) -> Result<Vec<&str>, String> {
+        let parts: Vec<&str> = response.split('|').collect();
+        if parts.len() != 4 {
+            return Err("malformed response".to_string());
+        }
+        Ok(parts)
+    }
+}
+
+impl WebAuthnVerifier for MockWebAuthnVerifier {
+    fn verify_registration(&self, response: &str) -> Result<AttestedPasskey, String> {
+        let parts = Self::parts(response)?;
+        Ok(AttestedPasskey {
+            credential_id: parts[0].to_string(),
+            challenge: parts[1].to_string(),
+            sign_count: parts[2].parse().map_err(|_| "bad counter".to_string())?,
+            public_key: parts[3].as_bytes().to_vec(),
+        })
+    }
+
+    fn assertion_credential_id(&self, response: &str) -> Result<String, String> {
+        Ok(Self::parts(response)?[0].to_string())
+    }
+
+    fn verify_assertion(&self, response: &str, public_key: &[u8]) -> Result<VerifiedAssertion, String> {
+        let parts = Self::parts(response)?;
+        if parts[3].as_bytes() != public_key {
+            return Err("signature does not verify".to_string());
+        }
+        Ok(VerifiedAssertion {
+            credential_id: parts[0].to_string(),
+            challenge: parts[1].to_string(),
+            sign_count: parts[2].parse().map_err(|_| "bad counter".to_string())?,
+            user_verified: true,
+        })
+    }
+}
+
+struct MockPasskeyRepo {
+    credentials: RwLock<HashMap<String, PasskeyCredential>>,
+}
+
+impl MockPasskeyRepo {
+    fn with_credential(sign_count: u32) -> Self {
+        let mut credentials = HashMap::new();
+        credentials.insert(
+            "cred-1".to_string(),
+            PasskeyCredential {
+                credential_id: "cred-1".to_string(),
+                user_id: "user123".to_string(),
+                public_key: b"key-1".to_vec(),
+                sign_count,
+            },
+        );
+        Self {
+            credentials: RwLock::new(credentials),
+        }
+    }
+
+    fn sign_count(&self, credential_id: &str) -> u32 {
+        self.credentials.read().unwrap()[credential_id].sign_count
+    }
+}
+
+impl PasskeyRepository for MockPasskeyRepo {
+    fn find_by_credential_id(&self, credential_id: &str) -> BoxFuture<'_, Option<PasskeyCredential>> {
+        let result = self.credentials.read().unwrap().get(credential_id).cloned();
+        Box::pin(async move { result })
+    }
+
+    fn create(&self, credential: &PasskeyCredential) -> BoxFuture<'_, Result<(), String>> {
+        self.credentials
+            .write()
+            .unwrap()
+            .insert(credential.credential_id.clone(), credential.clone());
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn update_sign_count(&self, credential_id: &str, expected: u32, sign_count: u32) -> BoxFuture<'_, Result<bool, String>> {
+        let mut credentials = self.credentials.write().unwrap();
+        let updated = match credentials.get_mut(credential_id) {
+            Some(credential) if credential.sign_count == expected => {
+                credential.sign_count = sign_count;
+                true
+            }
+            _ => false,
+        };
+        Box::pin(async move { Ok(updated) })
+    }
+}
+
+fn input(sign_count: u32) -> AuthenticateWithPasskeyInput {
+    AuthenticateWithPasskeyInput {
+        response: MockWebAuthnVerifier::response("cred-1", CHALLENGE, sign_count, "key-1"),
+        challenge: CHALLENGE.to_string(),
+    }
+}
+
+fn is_invalid_credentials(result: &Result<impl std::fmt::Debug, CoreError>) -> bool {
+    matches!(result, Err(CoreError::Authentication(AuthenticationError::InvalidCredentials)))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_valid_assertion_authenticates_and_records_counter() {
+    let repo = MockPasskeyRepo::with_credential(5);
+    let use_case = AuthenticateWithPasskey::new(&repo, &MockWebAuthnVerifier);
+
+    let output = use_case.execute(input(6)).await.unwrap();
+
+    assert_eq!(output.user_id, "user123");
+    assert_eq!(output.credential_id, "cred-1");
+    assert!(output.user_verified);
+    assert_eq!(repo.sign_count("cred-1"), 6);
+}
+
+#[tokio::test]
+async fn test_replayed_assertion_with_same_counter_is_rejected() {
+    let repo = MockPasskeyRepo::with_credential(5);
+    let use_case = AuthenticateWithPasskey::new(&repo, &MockWebAuthnVerifier);
+
+    use_case.execute(input(6)).await.unwrap();
+    let replay = use_case.execute(input(6)).await;
+
+    assert!(matches!(replay, Err(CoreError::Credential(_))));
+    assert_eq!(repo.sign_count("cred-1"), 6);
+}
+
+#[tokio::test]
+async fn test_counter_going_backwards_is_rejected() {
+    let repo = MockPasskeyRepo::with_credential(5);
+    let use_case = AuthenticateWithPasskey::new(&repo, &MockWebAuthnVerifier);
+
+    let result = use_case.execute(input(3)).await;
+
+    assert!(matches!(result, Err(CoreError::Credential(_))));
+    assert_eq!(repo.sign_count("cred-1"), 5);
+}
+
+#[tokio::test]
+async fn test_authenticator_without_counter_is_accepted() {
+    let repo = MockPasskeyRepo::with_credential(0);
+    let use_case = AuthenticateWithPasskey::new(&repo, &MockWebAuthnVerifier);
+
+    assert!(use_case.execute(input(0)).await.is_ok());
+    assert!(use_case.execute(input(0)).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_assertion_for_other_challenge_is_rejected() {
+    let repo = MockPasskeyRepo::with_credential(5);
+    let use_case = AuthenticateWithPasskey::new(&repo, &MockWebAuthnVerifier);
+
+    let result = use_case
+        .execute(AuthenticateWithPasskeyInput {
+            response: MockWebAuthnVerifier::response("cred-1", "b2xkLWNoYWxsZW5nZQ", 6, "key-1"),
+            challenge: CHALLENGE.to_string(),
+        })
+        .await;
+
+    assert!(is_invalid_credentials(&result));
+    assert_eq!(repo.sign_count("cred-1"), 5);
+}
+
+#[tokio::test]
+async fn test_assertion_signed_with_other_key_is_rejected() {
+    let repo = MockPasskeyRepo::with_credential(5);
+    let use_case = AuthenticateWithPasskey::new(&repo, &MockWebAuthnVerifier);
+
+    let result = use_case
+        .execute(AuthenticateWithPasskeyInput {
+            response: MockWebAuthnVerifier::response("cred-1", CHALLENGE, 6, "key-2"),
+            challenge: CHALLENGE.to_string(),
+        })
+        .await;
+
+    assert!(is_invalid_credentials(&result));
+}
+
+#[tokio::test]
+async fn test_unknown_credential_is_rejected() {
+    let repo = MockPasskeyRepo::with_credential(5);
+    let use_case = AuthenticateWithPasskey::new(&repo, &MockWebAuthnVerifier);
+
+    let result = use_case
+        .execute(AuthenticateWithPasskeyInput {
+            response: MockWebAuthnVerifier::response("cred-9", CHALLENGE, 6, "key-1"),
+            challenge: CHALLENGE.to_string(),
+        })
+        .await;
+
+    assert!(is_invalid_credentials(&result));
+}
@@ -0,0 +1,478 @@
+//! Tests for ChangePassword use case.
+
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use super::super::change_password::{ChangePassword, ChangePasswordInput};
+use crate::core::credentials::{CredentialPolicy, StoredCredential};
+use crate::core::error::{CoreError, CredentialError};
+use crate::core::identity::UserIdentity;
+use crate::core::usecases::policies::LockoutPolicy;
+use crate::core::usecases::ports::{
+    BreachChecker, CredentialRepository, IdentityRepository, PasswordHasher, SessionRepository,
+};
+use crate::core::usecases::ports::session_repository::Session;
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+struct MockCredentialRepo {
+    passwords: RwLock<HashMap<String, String>>, // user_id -> hash
+    history: RwLock<Vec<String>>,               // previous hashes of user123, newest first
+    lockout: RwLock<(u32, Option<String>)>,     // failed attempts and lock expiry of user123
+}
+
+impl MockCredentialRepo {
+    /// user123 currently uses `current`
+    fn with_password(current: &str) -> Self {
+        let mut passwords = HashMap::new();
+        passwords.insert("user123".to_string(), format!("hashed_{}", current));
+        Self {
+            passwords: RwLock::new(passwords),
+            history: RwLock::new(Vec::new()),
+            lockout: RwLock::new((0, None)),
+        }
+    }
+
+    fn password_of(&self, user_id: &str) -> Option<String> {
+        self.passwords.read().unwrap().get(user_id).cloned()
+    }
+}
+
+impl CredentialRepository for MockCredentialRepo {
+    fn get_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        let (failed_attempts, locked_until) = self.lockout.read().unwrap().clone();
+        let stored = self.password_of(user_id);
+        Box::pin(async move { stored.map(|hash| StoredCredential::from_parts(hash, failed_attempts, locked_until)) })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, attempts: u32) -> BoxFuture<'_, ()> {
+        self.lockout.write().unwrap().0 = attempts;
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, until: &str) -> BoxFuture<'_, ()> {
+        self.lockout.write().unwrap().1 = Some(until.to_string());
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, user_id: &str, new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        self.passwords
+            .write()
+            .unwrap()
+            .insert(user_id.to_string(), new_credential.as_hash_str().to_string());
+        Box::pin(async move {})
+    }
+
+    fn password_history(&self, _user_id: &str, limit: usize) -> BoxFuture<'_, Vec<StoredCredential>> {
+        let history: Vec<StoredCredential> = self
+            .history
+            .read()
+            .unwrap()
+            .iter()
+            .take(limit)
+            .map(|hash| StoredCredential::from_hash(hash.clone()))
+            .collect();
+        Box::pin(async move { history })
+    }
+
+    fn push_password_history(&self, _user_id: &str, previous: StoredCredential, depth: usize) -> BoxFuture<'_, Result<(), String>> {
+        let mut history = self.history.write().unwrap();
+        history.insert(0, previous.as_hash_str().to_string());
+        history.truncate(depth);
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+struct MockPasswordHasher;
+
+impl PasswordHasher for MockPasswordHasher {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, raw: &str, stored: &StoredCredential) -> bool {
+        stored.as_hash_str() == format!("hashed_{}", raw)
+    }
+}
+
+/// Hasher counting verifications, dummy ones included.
+#[derive(Default)]
+struct CountingPasswordHasher {
+    verifications: std::sync::atomic::AtomicUsize,
+}
+
+impl PasswordHasher for CountingPasswordHasher {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        MockPasswordHasher.hash(raw)
+    }
+
+    fn verify(&self, raw: &str, stored: &StoredCredential) -> bool {
+        self.verifications.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        MockPasswordHasher.verify(raw, stored)
+    }
+}
+
+struct MockSessionRepo {
+    kept_sessions: RwLock<Vec<String>>,
+}
+
+impl MockSessionRepo {
+    fn new() -> Self {
+        Self {
+            kept_sessions: RwLock::new(Vec::new()),
+        }
+    }
+}
+
+impl SessionRepository for MockSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn revoke_all_for_user_except(&self, _user_id: &str, keep_session_id: &str) -> BoxFuture<'_, u64> {
+        self.kept_sessions.write().unwrap().push(keep_session_id.to_string());
+        Box::pin(async move { 2 })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+/// Knows `user123` as `jane@example.com`
+struct MockIdentityRepo;
+
+impl IdentityRepository for MockIdentityRepo {
+    fn find_by_identifier(&self, _identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = (id == "user123").then(|| UserIdentity::new("user123"));
+        Box::pin(async move { result })
+    }
+
+    fn find_identifier_by_id(&self, id: &str) -> BoxFuture<'_, Option<String>> {
+        let result = (id == "user123").then(|| "jane@example.com".to_string());
+        Box::pin(async move { result })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+/// Knows one breached password
+struct MockBreachChecker;
+
+impl BreachChecker for MockBreachChecker {
+    fn is_compromised<'a>(&'a self, secret: &'a str) -> BoxFuture<'a, Result<bool, String>> {
+        let compromised = secret == "breached-password";
+        Box::pin(async move { Ok(compromised) })
+    }
+}
+
+fn input(current_password: &str, new_password: &str) -> ChangePasswordInput {
+    ChangePasswordInput {
+        user_id: "user123".to_string(),
+        current_password: current_password.to_string(),
+        new_password: new_password.to_string(),
+        current_session_id: Some("session-current".to_string()),
+        revoke_other_sessions: false,
+        source_ip: None,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_change_password_happy_path() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let use_case = ChangePassword::new(&credential_repo, &password_hasher, &session_repo, CredentialPolicy::default());
+
+    let output = use_case
+        .execute(input("old-strong-password", "new-strong-password"))
+        .await
+        .unwrap();
+
+    assert_eq!(output.user_id, "user123");
+    assert_eq!(output.sessions_revoked, 0);
+    assert_eq!(credential_repo.password_of("user123").as_deref(), Some("hashed_new-strong-password"));
+    assert!(session_repo.kept_sessions.read().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_change_password_revokes_other_sessions_and_keeps_current() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let use_case = ChangePassword::new(&credential_repo, &password_hasher, &session_repo, CredentialPolicy::default());
+
+    let output = use_case
+        .execute(ChangePasswordInput {
+            revoke_other_sessions: true,
+            ..input("old-strong-password", "new-strong-password")
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(output.sessions_revoked, 2);
+    assert_eq!(*session_repo.kept_sessions.read().unwrap(), vec!["session-current".to_string()]);
+}
+
+#[tokio::test]
+async fn test_change_password_wrong_current_password_fails() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let use_case = ChangePassword::new(&credential_repo, &password_hasher, &session_repo, CredentialPolicy::default());
+
+    let result = use_case
+        .execute(input("wrong-password", "new-strong-password"))
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Authentication(_))));
+    assert_eq!(credential_repo.password_of("user123").as_deref(), Some("hashed_old-strong-password"));
+}
+
+#[tokio::test]
+async fn test_change_password_wrong_current_password_hides_policy_result() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let use_case = ChangePassword::new(&credential_repo, &password_hasher, &session_repo, CredentialPolicy::default());
+
+    // "short" would fail the policy, but the wrong current password wins
+    let result = use_case.execute(input("wrong-password", "short")).await;
+
+    assert!(matches!(result, Err(CoreError::Authentication(_))));
+}
+
+#[tokio::test]
+async fn test_change_password_wrong_current_password_counts_towards_lockout() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let use_case = ChangePassword::new(&credential_repo, &password_hasher, &session_repo, CredentialPolicy::default())
+        .with_lockout_policy(LockoutPolicy::new(2, 600, true));
+
+    for _ in 0..2 {
+        let result = use_case.execute(input("wrong-password", "new-strong-password")).await;
+        assert!(matches!(result, Err(CoreError::Authentication(ref e)) if !e.is_account_locked()));
+    }
+    assert_eq!(credential_repo.lockout.read().unwrap().0, 2);
+
+    // Locked now: even the right current password is refused
+    let result = use_case.execute(input("old-strong-password", "new-strong-password")).await;
+
+    assert!(matches!(result, Err(CoreError::Authentication(ref e)) if e.is_account_locked()));
+    assert_eq!(credential_repo.password_of("user123").as_deref(), Some("hashed_old-strong-password"));
+}
+
+#[tokio::test]
+async fn test_change_password_locked_account_still_verifies() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = CountingPasswordHasher::default();
+    let session_repo = MockSessionRepo::new();
+    let use_case = ChangePassword::new(&credential_repo, &password_hasher, &session_repo, CredentialPolicy::default())
+        .with_lockout_policy(LockoutPolicy::new(1, 600, true));
+
+    let _ = use_case.execute(input("wrong-password", "new-strong-password")).await;
+    let result = use_case.execute(input("wrong-password", "new-strong-password")).await;
+
+    // The refused attempt costs the same verification as the counted one
+    assert!(matches!(result, Err(CoreError::Authentication(ref e)) if e.is_account_locked()));
+    assert_eq!(password_hasher.verifications.load(std::sync::atomic::Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_change_password_without_lockout_policy_counts_nothing() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let use_case = ChangePassword::new(&credential_repo, &password_hasher, &session_repo, CredentialPolicy::default());
+
+    let _ = use_case.execute(input("wrong-password", "new-strong-password")).await;
+
+    assert_eq!(*credential_repo.lockout.read().unwrap(), (0, None));
+}
+
+#[tokio::test]
+async fn test_change_password_unknown_user_fails_like_wrong_password() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let use_case = ChangePassword::new(&credential_repo, &password_hasher, &session_repo, CredentialPolicy::default());
+
+    let result = use_case
+        .execute(ChangePasswordInput {
+            user_id: "user999".to_string(),
+            ..input("old-strong-password", "new-strong-password")
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Authentication(_))));
+}
+
+#[tokio::test]
+async fn test_change_password_enforces_credential_policy() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let use_case = ChangePassword::new(&credential_repo, &password_hasher, &session_repo, CredentialPolicy::default());
+
+    let result = use_case.execute(input("old-strong-password", "short")).await;
+
+    assert!(matches!(result, Err(CoreError::Credential(_))));
+    assert_eq!(credential_repo.password_of("user123").as_deref(), Some("hashed_old-strong-password"));
+}
+
+#[tokio::test]
+async fn test_change_password_reports_every_policy_violation() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let identity_repo = MockIdentityRepo;
+    let use_case = ChangePassword::new(
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        CredentialPolicy::default().with_forbid_identifier_in_password(true),
+    )
+    .with_identity_repository(&identity_repo);
+
+    let result = use_case.execute(input("old-strong-password", "Jane1")).await;
+
+    let Err(CoreError::Credential(rejected)) = result else {
+        panic!("expected a credential error, got {:?}", result);
+    };
+    assert_eq!(
+        rejected.violations(),
+        [
+            CredentialError::contains_identifier(),
+            CredentialError::insufficient_strength("minimum length is 8"),
+        ]
+    );
+    assert_eq!(credential_repo.password_of("user123").as_deref(), Some("hashed_old-strong-password"));
+}
+
+#[tokio::test]
+async fn test_change_password_rejects_password_containing_identifier() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let identity_repo = MockIdentityRepo;
+    let use_case = ChangePassword::new(
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        CredentialPolicy::default().with_forbid_identifier_in_password(true),
+    )
+    .with_identity_repository(&identity_repo);
+
+    let result = use_case.execute(input("old-strong-password", "jane-long-enough")).await;
+
+    assert!(matches!(result, Err(CoreError::Credential(CredentialError::ContainsIdentifier))));
+}
+
+#[tokio::test]
+async fn test_change_password_rejects_breached_password() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let breach_checker = MockBreachChecker;
+    let use_case = ChangePassword::new(&credential_repo, &password_hasher, &session_repo, CredentialPolicy::default())
+        .with_breach_checker(&breach_checker);
+
+    let result = use_case.execute(input("old-strong-password", "breached-password")).await;
+
+    assert!(matches!(result, Err(CoreError::Credential(CredentialError::Compromised))));
+    assert_eq!(credential_repo.password_of("user123").as_deref(), Some("hashed_old-strong-password"));
+
+    use_case
+        .execute(input("old-strong-password", "new-strong-password"))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_change_password_rejects_recent_password_and_records_history() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let use_case = ChangePassword::new(
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        CredentialPolicy::default().with_password_history(3),
+    );
+
+    let same = use_case.execute(input("old-strong-password", "old-strong-password")).await;
+    assert!(matches!(same, Err(CoreError::Credential(CredentialError::Reused))));
+
+    use_case
+        .execute(input("old-strong-password", "new-strong-password"))
+        .await
+        .unwrap();
+    assert_eq!(
+        *credential_repo.history.read().unwrap(),
+        vec!["hashed_old-strong-password".to_string()]
+    );
+
+    let back = use_case.execute(input("new-strong-password", "old-strong-password")).await;
+    assert!(matches!(back, Err(CoreError::Credential(CredentialError::Reused))));
+}
+
+#[tokio::test]
+async fn test_change_password_revoking_others_requires_current_session() {
+    let credential_repo = MockCredentialRepo::with_password("old-strong-password");
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let use_case = ChangePassword::new(&credential_repo, &password_hasher, &session_repo, CredentialPolicy::default());
+
+    let result = use_case
+        .execute(ChangePasswordInput {
+            current_session_id: None,
+            revoke_other_sessions: true,
+            ..input("old-strong-password", "new-strong-password")
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Invariant(_))));
+    assert_eq!(credential_repo.password_of("user123").as_deref(), Some("hashed_old-strong-password"));
+}
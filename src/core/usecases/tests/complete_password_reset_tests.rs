@@ -0,0 +1,691 @@
+//! Tests for CompletePasswordReset use case.
+
+use futures::future::BoxFuture;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, RwLock};
+
+use super::super::complete_password_reset::{CompletePasswordReset, CompletePasswordResetInput};
+use crate::core::credentials::{CredentialPolicy, StoredCredential};
+use crate::core::error::{CoreError, CredentialError, TokenError};
+use crate::core::identity::UserIdentity;
+use crate::core::token::Token;
+use crate::core::usecases::ports::{
+    BreachChecker, CredentialRepository, IdentityRepository, PasswordHasher, ResetTokenStore,
+    SessionRepository, TokenService,
+};
+use crate::core::usecases::ports::session_repository::Session;
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+/// Reset tokens carry their claims verbatim after a `reset::` prefix.
+struct MockTokenService;
+
+impl TokenService for MockTokenService {
+    fn issue_access_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("access_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("refresh_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn issue_reset_token(&self, _subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("reset::{}", claims));
+        Box::pin(async move { token })
+    }
+
+    fn validate_reset_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = token
+            .value()
+            .strip_prefix("reset::")
+            .map(|claims| claims.to_string())
+            .ok_or(());
+        Box::pin(async move { claims })
+    }
+}
+
+struct MockCredentialRepo {
+    passwords: RwLock<HashMap<String, String>>,
+    history: RwLock<Vec<String>>, // previous hashes of user123, newest first
+}
+
+impl MockCredentialRepo {
+    fn new() -> Self {
+        Self {
+            passwords: RwLock::new(HashMap::new()),
+            history: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// user123 currently uses `current` and used `previous` before, newest first
+    fn with_history(current: &str, previous: &[&str]) -> Self {
+        let repo = Self::new();
+        repo.passwords
+            .write()
+            .unwrap()
+            .insert("user123".to_string(), format!("hashed_{}", current));
+        *repo.history.write().unwrap() = previous.iter().map(|p| format!("hashed_{}", p)).collect();
+        repo
+    }
+}
+
+impl CredentialRepository for MockCredentialRepo {
+    fn get_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        let stored = self.passwords.read().unwrap().get(user_id).cloned();
+        Box::pin(async move { stored.map(StoredCredential::from_hash) })
+    }
+
+    fn password_history(&self, _user_id: &str, limit: usize) -> BoxFuture<'_, Vec<StoredCredential>> {
+        let history: Vec<StoredCredential> = self
+            .history
+            .read()
+            .unwrap()
+            .iter()
+            .take(limit)
+            .map(|hash| StoredCredential::from_hash(hash.clone()))
+            .collect();
+        Box::pin(async move { history })
+    }
+
+    fn push_password_history(&self, _user_id: &str, previous: StoredCredential, depth: usize) -> BoxFuture<'_, Result<(), String>> {
+        let mut history = self.history.write().unwrap();
+        history.insert(0, previous.as_hash_str().to_string());
+        history.truncate(depth);
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, user_id: &str, new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        self.passwords
+            .write()
+            .unwrap()
+            .insert(user_id.to_string(), new_credential.as_hash_str().to_string());
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+struct MockPasswordHasher;
+
+impl PasswordHasher for MockPasswordHasher {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(format!("hashed_{}", raw))
+    }
+
+    fn verify(&self, raw: &str, stored: &StoredCredential) -> bool {
+        stored.as_hash_str() == format!("hashed_{}", raw)
+    }
+}
+
+struct MockSessionRepo {
+    revoked_users: RwLock<Vec<String>>,
+}
+
+impl MockSessionRepo {
+    fn new() -> Self {
+        Self {
+            revoked_users: RwLock::new(Vec::new()),
+        }
+    }
+}
+
+impl SessionRepository for MockSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, user_id: &str) -> BoxFuture<'_, u64> {
+        self.revoked_users.write().unwrap().push(user_id.to_string());
+        Box::pin(async move { 0 })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+/// Knows `user123` as `alice.smith@example.com`
+struct MockIdentityRepo;
+
+impl IdentityRepository for MockIdentityRepo {
+    fn find_by_identifier(&self, _identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = (id == "user123").then(|| UserIdentity::new("user123"));
+        Box::pin(async move { result })
+    }
+
+    fn find_identifier_by_id(&self, id: &str) -> BoxFuture<'_, Option<String>> {
+        let result = (id == "user123").then(|| "alice.smith@example.com".to_string());
+        Box::pin(async move { result })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+/// Knows one breached password
+struct MockBreachChecker;
+
+impl BreachChecker for MockBreachChecker {
+    fn is_compromised<'a>(&'a self, secret: &'a str) -> BoxFuture<'a, Result<bool, String>> {
+        let compromised = secret == "breached-password";
+        Box::pin(async move { Ok(compromised) })
+    }
+}
+
+/// Consumed-token set guarded by a single mutex, so check-and-insert is atomic.
+struct MockResetTokenStore {
+    consumed: Mutex<HashSet<String>>,
+}
+
+impl MockResetTokenStore {
+    fn new() -> Self {
+        Self {
+            consumed: Mutex::new(HashSet::new()),
+        }
+    }
+}
+
+impl ResetTokenStore for MockResetTokenStore {
+    fn consume_reset_token(&self, jti: &str, _expires_at: i64) -> BoxFuture<'_, Result<(), CoreError>> {
+        let newly_consumed = self.consumed.lock().unwrap().insert(jti.to_string());
+        Box::pin(async move {
+            if newly_consumed {
+                Ok(())
+            } else {
+                Err(TokenError::revoked("earlier request").into())
+            }
+        })
+    }
+}
+
+fn reset_token(token_type: &str, exp_offset_secs: i64) -> Token {
+    let exp = chrono::Utc::now().timestamp() + exp_offset_secs;
+    Token::new(format!(
+        r#"reset::{{"sub":"user123","type":"{}","exp":{},"jti":"reset-jti-1"}}"#,
+        token_type, exp
+    ))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_complete_reset_happy_path() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default(),
+    );
+
+    let output = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("reset", 600),
+            new_password: "new-strong-password".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(output.user_id, "user123");
+    assert!(output.sessions_revoked);
+    assert_eq!(
+        credential_repo.passwords.read().unwrap().get("user123").map(String::as_str),
+        Some("hashed_new-strong-password")
+    );
+    assert_eq!(*session_repo.revoked_users.read().unwrap(), vec!["user123".to_string()]);
+}
+
+#[tokio::test]
+async fn test_complete_reset_with_expired_token_fails() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default(),
+    );
+
+    let result = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("reset", -60),
+            new_password: "new-strong-password".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Token(_))));
+    assert!(credential_repo.passwords.read().unwrap().is_empty());
+    assert!(session_repo.revoked_users.read().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_complete_reset_rejects_non_reset_token_type() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default(),
+    );
+
+    let result = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("access", 600),
+            new_password: "new-strong-password".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Token(_))));
+}
+
+#[tokio::test]
+async fn test_complete_reset_enforces_credential_policy() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default(),
+    );
+
+    let result = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("reset", 600),
+            new_password: "short".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Credential(_))));
+    assert!(credential_repo.passwords.read().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_complete_reset_token_is_single_use() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default(),
+    );
+
+    let token = reset_token("reset", 600);
+
+    let first = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: token.clone(),
+            new_password: "new-strong-password".to_string(),
+        })
+        .await;
+    assert!(first.is_ok());
+
+    let second = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: token,
+            new_password: "attacker-chosen-password".to_string(),
+        })
+        .await;
+
+    assert!(matches!(second, Err(CoreError::Token(TokenError::Revoked { .. }))));
+    assert_eq!(
+        credential_repo.passwords.read().unwrap().get("user123").map(String::as_str),
+        Some("hashed_new-strong-password")
+    );
+}
+
+#[tokio::test]
+async fn test_complete_reset_concurrent_uses_exactly_one_wins() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default(),
+    );
+
+    let token = reset_token("reset", 600);
+    let (a, b) = tokio::join!(
+        use_case.execute(CompletePasswordResetInput {
+            reset_token: token.clone(),
+            new_password: "first-strong-password".to_string(),
+        }),
+        use_case.execute(CompletePasswordResetInput {
+            reset_token: token,
+            new_password: "second-strong-password".to_string(),
+        }),
+    );
+
+    assert_eq!([a.is_ok(), b.is_ok()].iter().filter(|ok| **ok).count(), 1);
+    assert_eq!(session_repo.revoked_users.read().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_complete_reset_policy_failure_does_not_consume_token() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default(),
+    );
+
+    let weak = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("reset", 600),
+            new_password: "short".to_string(),
+        })
+        .await;
+    assert!(matches!(weak, Err(CoreError::Credential(_))));
+
+    let retry = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("reset", 600),
+            new_password: "new-strong-password".to_string(),
+        })
+        .await;
+    assert!(retry.is_ok());
+}
+
+#[tokio::test]
+async fn test_complete_reset_requires_jti() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default(),
+    );
+
+    let exp = chrono::Utc::now().timestamp() + 600;
+    let result = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: Token::new(format!(r#"reset::{{"sub":"user123","type":"reset","exp":{}}}"#, exp)),
+            new_password: "new-strong-password".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Token(_))));
+    assert!(credential_repo.passwords.read().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_complete_reset_rejects_immediately_previous_password() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::with_history("current-password-1", &["previous-password-1"]);
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default().with_password_history(3),
+    );
+
+    for reused in ["previous-password-1", "current-password-1"] {
+        let result = use_case
+            .execute(CompletePasswordResetInput {
+                reset_token: reset_token("reset", 600),
+                new_password: reused.to_string(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Credential(CredentialError::Reused))));
+    }
+    assert_eq!(
+        credential_repo.passwords.read().unwrap().get("user123").map(String::as_str),
+        Some("hashed_current-password-1")
+    );
+    assert!(session_repo.revoked_users.read().unwrap().is_empty());
+
+    // The rejected attempts did not burn the token
+    let retry = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("reset", 600),
+            new_password: "new-strong-password".to_string(),
+        })
+        .await;
+    assert!(retry.is_ok());
+}
+
+#[tokio::test]
+async fn test_complete_reset_allows_new_password_and_respects_history_depth() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::with_history(
+        "current-password-1",
+        &["previous-password-1", "previous-password-2", "oldest-password-1"],
+    );
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default().with_password_history(2),
+    );
+
+    // Third-most-recent password is beyond a depth of 2
+    let result = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("reset", 600),
+            new_password: "oldest-password-1".to_string(),
+        })
+        .await;
+
+    assert!(result.is_ok());
+    assert_eq!(
+        credential_repo.passwords.read().unwrap().get("user123").map(String::as_str),
+        Some("hashed_oldest-password-1")
+    );
+    assert_eq!(
+        *credential_repo.history.read().unwrap(),
+        vec!["hashed_current-password-1".to_string(), "hashed_previous-password-1".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_complete_reset_without_history_depth_allows_reuse() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::with_history("current-password-1", &[]);
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default(),
+    );
+
+    let result = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("reset", 600),
+            new_password: "current-password-1".to_string(),
+        })
+        .await;
+
+    assert!(result.is_ok());
+    assert!(credential_repo.history.read().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_complete_reset_rejects_password_containing_identifier() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let identity_repo = MockIdentityRepo;
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default().with_forbid_identifier_in_password(true),
+    )
+    .with_identity_repository(&identity_repo);
+
+    let result = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("reset", 600),
+            new_password: "Alice.Smith-2024!".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Credential(CredentialError::ContainsIdentifier))));
+    assert!(credential_repo.passwords.read().unwrap().is_empty());
+    assert!(session_repo.revoked_users.read().unwrap().is_empty());
+
+    // The rejected attempt did not burn the token
+    let output = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("reset", 600),
+            new_password: "new-strong-password".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(output.user_id, "user123");
+}
+
+#[tokio::test]
+async fn test_complete_reset_rejects_breached_password() {
+    let token_service = MockTokenService;
+    let credential_repo = MockCredentialRepo::new();
+    let password_hasher = MockPasswordHasher;
+    let session_repo = MockSessionRepo::new();
+    let reset_token_store = MockResetTokenStore::new();
+    let breach_checker = MockBreachChecker;
+    let use_case = CompletePasswordReset::new(
+        &token_service,
+        &credential_repo,
+        &password_hasher,
+        &session_repo,
+        &reset_token_store,
+        CredentialPolicy::default(),
+    )
+    .with_breach_checker(&breach_checker);
+
+    let result = use_case
+        .execute(CompletePasswordResetInput {
+            reset_token: reset_token("reset", 600),
+            new_password: "breached-password".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Credential(CredentialError::Compromised))));
+    assert!(credential_repo.passwords.read().unwrap().is_empty());
+    assert!(session_repo.revoked_users.read().unwrap().is_empty());
+}
@@ -0,0 +1,383 @@
+//! Tests for the email verification flow: IssueVerificationToken and ConfirmVerification.
+
+use futures::future::BoxFuture;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, RwLock};
+
+use super::super::confirm_verification::{ConfirmVerification, ConfirmVerificationInput};
+use super::super::issue_verification_token::{IssueVerificationToken, IssueVerificationTokenInput};
+use crate::adapters::random::SystemRandomSource;
+use crate::core::credentials::{CredentialStatus, StoredCredential};
+use crate::core::error::{CoreError, CredentialError, TokenError};
+use crate::core::identity::UserIdentity;
+use crate::core::token::Token;
+use crate::core::usecases::ports::{CredentialRepository, IdentityRepository, ResetTokenStore, TokenService};
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+struct MockIdentityRepo;
+
+impl IdentityRepository for MockIdentityRepo {
+    fn find_by_identifier(&self, _identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = (id == "user123").then(|| UserIdentity::new("user123"));
+        Box::pin(async move { result })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+/// Verification tokens carry their claims verbatim after a `verify::` prefix.
+struct MockTokenService;
+
+impl TokenService for MockTokenService {
+    fn issue_access_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("access_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("refresh_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn issue_verification_token(&self, _subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("verify::{}", claims));
+        Box::pin(async move { token })
+    }
+
+    fn validate_verification_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = token
+            .value()
+            .strip_prefix("verify::")
+            .map(|claims| claims.to_string())
+            .ok_or(());
+        Box::pin(async move { claims })
+    }
+}
+
+/// Token service whose verification token issuance fails
+struct FailingTokenService;
+
+impl TokenService for FailingTokenService {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_service_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+/// Credential repository tracking only the lifecycle status of user123
+struct MockCredentialRepo {
+    statuses: RwLock<HashMap<String, CredentialStatus>>,
+}
+
+impl MockCredentialRepo {
+    fn with_status(status: CredentialStatus) -> Self {
+        let mut statuses = HashMap::new();
+        statuses.insert("user123".to_string(), status);
+        Self {
+            statuses: RwLock::new(statuses),
+        }
+    }
+
+    fn pending() -> Self {
+        Self::with_status(CredentialStatus::NotYetValid { valid_from: None })
+    }
+
+    fn status_of(&self, user_id: &str) -> Option<CredentialStatus> {
+        self.statuses.read().unwrap().get(user_id).cloned()
+    }
+}
+
+impl CredentialRepository for MockCredentialRepo {
+    fn get_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<StoredCredential>> {
+        let stored = self
+            .status_of(user_id)
+            .map(|status| StoredCredential::from_hash("hashed_password").with_status(status));
+        Box::pin(async move { stored })
+    }
+
+    fn set_status(&self, user_id: &str, status: CredentialStatus) -> BoxFuture<'_, Result<(), String>> {
+        self.statuses.write().unwrap().insert(user_id.to_string(), status);
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn update_failed_attempts(&self, _user_id: &str, _attempts: u32) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn lock_until(&self, _user_id: &str, _until: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn update_password(&self, _user_id: &str, _new_credential: StoredCredential) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn initialize_credential_state(&self, _user_id: &str) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+struct MockTokenStore {
+    consumed: Mutex<HashSet<String>>,
+}
+
+impl MockTokenStore {
+    fn new() -> Self {
+        Self {
+            consumed: Mutex::new(HashSet::new()),
+        }
+    }
+}
+
+impl ResetTokenStore for MockTokenStore {
+    fn consume_reset_token(&self, jti: &str, _expires_at: i64) -> BoxFuture<'_, Result<(), CoreError>> {
+        let newly_consumed = self.consumed.lock().unwrap().insert(jti.to_string());
+        Box::pin(async move {
+            if newly_consumed {
+                Ok(())
+            } else {
+                Err(TokenError::revoked("earlier request").into())
+            }
+        })
+    }
+}
+
+fn verification_token(token_type: &str, exp_offset_secs: i64) -> Token {
+    let exp = chrono::Utc::now().timestamp() + exp_offset_secs;
+    Token::new(format!(
+        r#"verify::{{"sub":"user123","type":"{}","exp":{},"jti":"verify-jti-1"}}"#,
+        token_type, exp
+    ))
+}
+
+async fn issue(credential_repo: &MockCredentialRepo) -> Token {
+    let use_case = IssueVerificationToken::new(
+        &MockIdentityRepo,
+        credential_repo,
+        &MockTokenService,
+        &SystemRandomSource,
+        3600,
+    );
+
+    use_case
+        .execute(IssueVerificationTokenInput {
+            user_id: "user123".to_string(),
+        })
+        .await
+        .unwrap()
+        .verification_token
+        .expect("pending credential should get a verification token")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_issue_then_confirm_activates_credential() {
+    let credential_repo = MockCredentialRepo::pending();
+    let token_store = MockTokenStore::new();
+
+    let token = issue(&credential_repo).await;
+    assert!(token.value().contains(r#""type":"verify""#));
+    assert!(token.value().contains(r#""jti":""#));
+
+    let use_case = ConfirmVerification::new(&MockTokenService, &credential_repo, &token_store);
+    let output = use_case
+        .execute(ConfirmVerificationInput { verification_token: token })
+        .await
+        .unwrap();
+
+    assert_eq!(output.user_id, "user123");
+    assert!(!output.already_verified);
+    assert_eq!(credential_repo.status_of("user123"), Some(CredentialStatus::Active));
+    assert_eq!(token_store.consumed.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_confirm_twice_is_idempotent() {
+    let credential_repo = MockCredentialRepo::pending();
+    let token_store = MockTokenStore::new();
+    let token = issue(&credential_repo).await;
+    let use_case = ConfirmVerification::new(&MockTokenService, &credential_repo, &token_store);
+
+    use_case
+        .execute(ConfirmVerificationInput { verification_token: token.clone() })
+        .await
+        .unwrap();
+    let second = use_case
+        .execute(ConfirmVerificationInput { verification_token: token })
+        .await
+        .expect("confirming an active credential again should succeed");
+
+    assert!(second.already_verified);
+    assert_eq!(credential_repo.status_of("user123"), Some(CredentialStatus::Active));
+}
+
+#[tokio::test]
+async fn test_confirm_expired_token_is_rejected() {
+    let credential_repo = MockCredentialRepo::pending();
+    let token_store = MockTokenStore::new();
+    let use_case = ConfirmVerification::new(&MockTokenService, &credential_repo, &token_store);
+
+    let result = use_case
+        .execute(ConfirmVerificationInput {
+            verification_token: verification_token("verify", -60),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Token(TokenError::Expired { .. }))));
+    assert_eq!(
+        credential_repo.status_of("user123"),
+        Some(CredentialStatus::NotYetValid { valid_from: None })
+    );
+    assert!(token_store.consumed.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_confirm_rejects_other_token_types() {
+    let credential_repo = MockCredentialRepo::pending();
+    let token_store = MockTokenStore::new();
+    let use_case = ConfirmVerification::new(&MockTokenService, &credential_repo, &token_store);
+
+    let result = use_case
+        .execute(ConfirmVerificationInput {
+            verification_token: verification_token("access", 600),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Token(_))));
+    assert!(!credential_repo.status_of("user123").unwrap().is_active());
+}
+
+#[tokio::test]
+async fn test_confirm_does_not_reactivate_revoked_credential() {
+    let revoked = CredentialStatus::Revoked { revoked_at: Some("2026-01-01T00:00:00Z".to_string()) };
+    let credential_repo = MockCredentialRepo::with_status(revoked.clone());
+    let token_store = MockTokenStore::new();
+    let use_case = ConfirmVerification::new(&MockTokenService, &credential_repo, &token_store);
+
+    let result = use_case
+        .execute(ConfirmVerificationInput {
+            verification_token: verification_token("verify", 600),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Credential(CredentialError::Revoked { .. }))));
+    assert_eq!(credential_repo.status_of("user123"), Some(revoked));
+}
+
+#[tokio::test]
+async fn test_issue_for_verified_user_returns_no_token() {
+    let credential_repo = MockCredentialRepo::with_status(CredentialStatus::Active);
+    let use_case = IssueVerificationToken::new(
+        &MockIdentityRepo,
+        &credential_repo,
+        &MockTokenService,
+        &SystemRandomSource,
+        3600,
+    );
+
+    let output = use_case
+        .execute(IssueVerificationTokenInput {
+            user_id: "user123".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert!(output.already_verified);
+    assert!(output.verification_token.is_none());
+}
+
+#[tokio::test]
+async fn test_issue_for_unknown_user_fails() {
+    let credential_repo = MockCredentialRepo::pending();
+    let use_case = IssueVerificationToken::new(
+        &MockIdentityRepo,
+        &credential_repo,
+        &MockTokenService,
+        &SystemRandomSource,
+        3600,
+    );
+
+    let result = use_case
+        .execute(IssueVerificationTokenInput {
+            user_id: "user999".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Authentication(_))));
+}
+
+#[tokio::test]
+async fn test_issue_fails_when_token_service_returns_empty_token() {
+    let credential_repo = MockCredentialRepo::pending();
+    let use_case = IssueVerificationToken::new(
+        &MockIdentityRepo,
+        &credential_repo,
+        &FailingTokenService,
+        &SystemRandomSource,
+        3600,
+    );
+
+    let result = use_case
+        .execute(IssueVerificationTokenInput {
+            user_id: "user123".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Invariant(_))));
+}
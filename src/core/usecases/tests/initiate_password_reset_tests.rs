@@ -0,0 +1,177 @@
+//! Tests for InitiatePasswordReset use case.
+
+use futures::future::BoxFuture;
+use std::sync::RwLock;
+
+use super::super::initiate_password_reset::{InitiatePasswordReset, InitiatePasswordResetInput};
+use crate::core::identity::UserIdentity;
+use crate::core::error::CoreError;
+use crate::core::token::Token;
+use crate::core::usecases::ports::{IdentityRepository, TokenService};
+use crate::adapters::random::SystemRandomSource;
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+struct MockIdentityRepo;
+
+impl IdentityRepository for MockIdentityRepo {
+    fn find_by_identifier(&self, identifier: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = (identifier == "alice@example.com").then(|| UserIdentity::new("user123"));
+        Box::pin(async move { result })
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<UserIdentity>> {
+        let result = (id == "user123").then(|| UserIdentity::new("user123"));
+        Box::pin(async move { result })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+struct MockTokenService {
+    reset_claims: RwLock<Vec<String>>,
+}
+
+impl MockTokenService {
+    fn new() -> Self {
+        Self {
+            reset_claims: RwLock::new(Vec::new()),
+        }
+    }
+}
+
+impl TokenService for MockTokenService {
+    fn issue_access_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("access_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("refresh_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn issue_reset_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        self.reset_claims.write().unwrap().push(claims.to_string());
+        let token = Token::new(format!("reset_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+}
+
+/// Token service whose reset token issuance fails
+struct FailingTokenService;
+
+impl TokenService for FailingTokenService {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_service_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_initiate_for_known_identifier_issues_reset_token() {
+    let identity_repo = MockIdentityRepo;
+    let token_service = MockTokenService::new();
+    let use_case = InitiatePasswordReset::new(&identity_repo, &token_service, &SystemRandomSource, 900);
+
+    let output = use_case
+        .execute(InitiatePasswordResetInput {
+            identifier: "alice@example.com".to_string(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(output.reset_token.unwrap().value(), "reset_token_for_user123");
+    assert_eq!(output.expires_in, 900);
+
+    let claims = token_service.reset_claims.read().unwrap();
+    assert_eq!(claims.len(), 1);
+    assert!(claims[0].contains(r#""type":"reset""#));
+    assert!(claims[0].contains(r#""jti":""#));
+    assert!(claims[0].contains(r#""sub":"user123""#));
+}
+
+#[tokio::test]
+async fn test_initiate_for_unknown_identifier_is_enumeration_safe() {
+    let identity_repo = MockIdentityRepo;
+    let token_service = MockTokenService::new();
+    let use_case = InitiatePasswordReset::new(&identity_repo, &token_service, &SystemRandomSource, 900);
+
+    let result = use_case
+        .execute(InitiatePasswordResetInput {
+            identifier: "nobody@example.com".to_string(),
+        })
+        .await;
+
+    // Same success shape as the known-identifier case, but nothing issued
+    let output = result.expect("unknown identifier must not produce an error");
+    assert!(output.reset_token.is_none());
+    assert_eq!(output.expires_in, 900);
+    assert!(token_service.reset_claims.read().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_initiate_fails_when_token_service_returns_empty_token() {
+    let identity_repo = MockIdentityRepo;
+    let use_case = InitiatePasswordReset::new(&identity_repo, &FailingTokenService, &SystemRandomSource, 900);
+
+    let result = use_case
+        .execute(InitiatePasswordResetInput {
+            identifier: "alice@example.com".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Invariant(_))));
+}
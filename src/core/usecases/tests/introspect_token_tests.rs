@@ -0,0 +1,154 @@
+//! Tests for IntrospectToken use case.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use futures::future::BoxFuture;
+
+use super::super::introspect_token::{IntrospectToken, IntrospectTokenInput, IntrospectedClaims};
+use crate::adapters::clock::FixedClock;
+use crate::core::error::CoreError;
+use crate::core::identity::UserIdentity;
+use crate::core::token::Token;
+use crate::core::usecases::ports::session_repository::Session;
+use crate::core::usecases::ports::{SessionRepository, TokenService};
+
+const ISSUED_AT: i64 = 1_700_000_000;
+const EXPIRES_AT: i64 = ISSUED_AT + 900;
+
+fn issued_instant() -> DateTime<Utc> {
+    Utc.timestamp_opt(ISSUED_AT, 0).unwrap()
+}
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+/// Token service with a fixed table of tokens and their claims
+struct ClaimsTokenService;
+
+impl TokenService for ClaimsTokenService {
+    fn issue_access_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("access_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("refresh_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let token = token.value().to_string();
+        Box::pin(async move {
+            let (sid, token_type) = match token.as_str() {
+                "active_token" => ("session-active", "access"),
+                "revoked_token" => ("session-revoked", "access"),
+                "refresh_token" => ("session-active", "refresh"),
+                _ => return Err(()),
+            };
+            Ok(format!(
+                r#"{{"sub":"user-1","type":"{}","iat":{},"exp":{},"sid":"{}","scope":["profile:read","sessions:write"]}}"#,
+                token_type, ISSUED_AT, EXPIRES_AT, sid
+            ))
+        })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+/// Session repository where only `session-active` is still active
+struct SingleSessionRepo;
+
+impl SessionRepository for SingleSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        let result = (session_id == "session-active").then_some(Session {});
+        Box::pin(async move { result })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+async fn introspect(token: &str, clock: &FixedClock) -> Option<IntrospectedClaims> {
+    let use_case = IntrospectToken::new(&ClaimsTokenService, &SingleSessionRepo, clock);
+    let output = use_case
+        .execute(IntrospectTokenInput { token: Token::new(token.to_string()) })
+        .await
+        .unwrap();
+    assert_eq!(output.is_active(), output.claims.is_some());
+    output.claims
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_active_token_discloses_claims() {
+    let clock = FixedClock::new(issued_instant() + Duration::minutes(5));
+
+    let claims = introspect("active_token", &clock).await.expect("token should be active");
+
+    assert_eq!(claims, IntrospectedClaims {
+        sub: "user-1".to_string(),
+        exp: EXPIRES_AT,
+        iat: Some(ISSUED_AT),
+        scope: vec!["profile:read".to_string(), "sessions:write".to_string()],
+        token_type: "access".to_string(),
+    });
+}
+
+#[tokio::test]
+async fn test_expired_token_is_inactive() {
+    let clock = FixedClock::new(issued_instant() + Duration::minutes(15));
+
+    assert_eq!(introspect("active_token", &clock).await, None);
+}
+
+#[tokio::test]
+async fn test_revoked_session_token_is_inactive() {
+    let clock = FixedClock::new(issued_instant() + Duration::minutes(5));
+
+    assert_eq!(introspect("revoked_token", &clock).await, None);
+}
+
+#[tokio::test]
+async fn test_malformed_token_is_inactive() {
+    let clock = FixedClock::new(issued_instant());
+
+    assert_eq!(introspect("not-a-token", &clock).await, None);
+}
+
+#[tokio::test]
+async fn test_refresh_token_is_inactive() {
+    let clock = FixedClock::new(issued_instant());
+
+    assert_eq!(introspect("refresh_token", &clock).await, None);
+}
@@ -4,6 +4,8 @@ use std::sync::Arc;
 
 use crate::core::credentials::StoredCredential;
 use crate::core::error::CoreError;
+use futures::future::BoxFuture;
+
 use crate::core::token::Token;
 use crate::core::usecases::issue_service_token::{IssueServiceToken, IssueServiceTokenInput};
 use crate::core::usecases::ports::{PasswordHasher, ServiceRegistry, TokenService};
@@ -92,40 +94,46 @@ impl ServiceRegistry for MockServiceRegistry {
 struct MockTokenService;
 
 impl TokenService for MockTokenService {
-    fn issue_access_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(&format!("access_token_for_{}", subject))
+    fn issue_access_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(&format!("access_token_for_{}", subject));
+        Box::pin(async move { token })
     }
 
-    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(&format!("refresh_token_for_{}", subject))
+    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(&format!("refresh_token_for_{}", subject));
+        Box::pin(async move { token })
     }
 
-    fn issue_service_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(&format!("service_token_for_{}", subject))
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(&format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
     }
 
-    fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().contains("access_token_for_") {
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().contains("access_token_for_") {
             Ok("user123".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 
-    fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().contains("refresh_token_for_") {
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().contains("refresh_token_for_") {
             Ok("user123".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 
-    fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().contains("service_token_for_") {
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().contains("service_token_for_") {
             Ok("service123".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 }
 
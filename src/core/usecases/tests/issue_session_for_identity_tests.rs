@@ -10,6 +10,7 @@ use crate::core::usecases::issue_session_for_identity::{
 };
 use crate::core::usecases::ports::{IdentityRepository, SessionRepository, TokenService};
 use crate::core::usecases::ports::session_repository::Session;
+use crate::adapters::random::SystemRandomSource;
 
 // ============================================================================
 // Mock Implementations
@@ -108,9 +109,9 @@ impl SessionRepository for MockSessionRepository {
         Box::pin(async move {})
     }
 
-    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, ()> {
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
         self.sessions.write().unwrap().clear();
-        Box::pin(async move {})
+        Box::pin(async move { 0 })
     }
 
     fn delete_expired(&self) -> BoxFuture<'_, ()> {
@@ -134,42 +135,48 @@ impl MockTokenService {
 }
 
 impl TokenService for MockTokenService {
-    fn issue_access_token(&self, subject: &str, _claims: &str) -> Token {
+    fn issue_access_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
         *self.access_tokens_issued.write().unwrap() += 1;
-        Token::new(&format!("access_token_for_{}", subject))
+        let token = Token::new(&format!("access_token_for_{}", subject));
+        Box::pin(async move { token })
     }
 
-    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> Token {
+    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
         *self.refresh_tokens_issued.write().unwrap() += 1;
-        Token::new(&format!("refresh_token_for_{}", subject))
+        let token = Token::new(&format!("refresh_token_for_{}", subject));
+        Box::pin(async move { token })
     }
 
-    fn issue_service_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(&format!("service_token_for_{}", subject))
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(&format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
     }
 
-    fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().contains("access_token_for_") {
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().contains("access_token_for_") {
             Ok("user123".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 
-    fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().contains("refresh_token_for_") {
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().contains("refresh_token_for_") {
             Ok("user123".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 
-    fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().contains("service_token_for_") {
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().contains("service_token_for_") {
             Ok("service123".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 }
 
@@ -187,6 +194,7 @@ async fn test_issue_session_for_identity_success() {
         &identity_repo,
         &session_repo,
         &token_service,
+        &SystemRandomSource,
         3600,  // access_token_ttl_seconds
         30,    // refresh_token_ttl_days
     );
@@ -223,6 +231,7 @@ async fn test_issue_session_for_identity_user_not_found() {
         &identity_repo,
         &session_repo,
         &token_service,
+        &SystemRandomSource,
         3600,
         30,
     );
@@ -258,6 +267,7 @@ async fn test_issue_session_for_identity_without_service_id() {
         &identity_repo,
         &session_repo,
         &token_service,
+        &SystemRandomSource,
         3600,
         30,
     );
@@ -288,6 +298,7 @@ async fn test_issue_session_for_identity_different_users() {
         &identity_repo,
         &session_repo,
         &token_service,
+        &SystemRandomSource,
         3600,
         30,
     );
@@ -328,6 +339,7 @@ async fn test_issue_session_for_identity_token_expiration() {
             &identity_repo,
             &session_repo,
             &token_service,
+            &SystemRandomSource,
             ttl,
             30,
         );
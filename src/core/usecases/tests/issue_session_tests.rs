@@ -2,11 +2,15 @@
 
 use futures::future::BoxFuture;
 use super::super::issue_session::{IssueSession, IssueSessionInput};
-use crate::core::error::CoreError;
+use crate::core::error::{CoreError, InvariantError};
 use crate::core::identity::UserIdentity;
-use crate::core::token::Token;
-use crate::core::usecases::ports::{SessionRepository, TokenService};
+use crate::core::token::{Token, TokenClaims};
+use crate::core::usecases::ports::{
+    SessionClientDetails, SessionMetadataEnricher, SessionRepository, TokenService, UnitOfWork, UnitOfWorkScope,
+};
 use crate::core::usecases::ports::session_repository::Session;
+use crate::adapters::clock::{FixedClock, SystemClock};
+use crate::adapters::random::{SeededRandomSource, SystemRandomSource};
 
 // ============================================================================
 // Mock Implementations
@@ -14,12 +18,14 @@ use crate::core::usecases::ports::session_repository::Session;
 
 struct MockSessionRepo {
     sessions: std::sync::RwLock<std::collections::HashMap<String, String>>, // session_id -> refresh_token_hash
+    metadata: std::sync::RwLock<Vec<String>>,
 }
 
 impl MockSessionRepo {
     fn new() -> Self {
         Self {
             sessions: std::sync::RwLock::new(std::collections::HashMap::new()),
+            metadata: std::sync::RwLock::new(Vec::new()),
         }
     }
     
@@ -29,8 +35,9 @@ impl MockSessionRepo {
 }
 
 impl SessionRepository for MockSessionRepo {
-    fn create_session(&self, session_id: &str, _user: &UserIdentity, refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+    fn create_session(&self, session_id: &str, _user: &UserIdentity, refresh_token_hash: &str, metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
         self.sessions.write().unwrap().insert(session_id.to_string(), refresh_token_hash.to_string());
+        self.metadata.write().unwrap().push(metadata.to_string());
         Box::pin(async move { Ok(()) })
     }
     
@@ -49,10 +56,10 @@ impl SessionRepository for MockSessionRepo {
         Box::pin(async move {})
     }
     
-    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, ()> {
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
         // Remove all sessions for the user (simplified)
         self.sessions.write().unwrap().clear();
-        Box::pin(async move {})
+        Box::pin(async move { 0 })
     }
     
     fn delete_expired(&self) -> BoxFuture<'_, ()> {
@@ -64,6 +71,7 @@ impl SessionRepository for MockSessionRepo {
 struct MockTokenService {
     access_tokens_issued: std::sync::RwLock<u32>,
     refresh_tokens_issued: std::sync::RwLock<u32>,
+    issued_claims: std::sync::RwLock<Vec<String>>,
 }
 
 impl MockTokenService {
@@ -71,49 +79,87 @@ impl MockTokenService {
         Self {
             access_tokens_issued: std::sync::RwLock::new(0),
             refresh_tokens_issued: std::sync::RwLock::new(0),
+            issued_claims: std::sync::RwLock::new(Vec::new()),
         }
     }
 }
 
 impl TokenService for MockTokenService {
-    fn issue_access_token(&self, subject: &str, _claims: &str) -> Token {
+    fn issue_access_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
         *self.access_tokens_issued.write().unwrap() += 1;
-        Token::new(&format!("access_token_for_{}", subject))
+        self.issued_claims.write().unwrap().push(claims.to_string());
+        let token = Token::new(&format!("access_token_for_{}", subject));
+        Box::pin(async move { token })
     }
     
-    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> Token {
+    fn issue_refresh_token(&self, subject: &str, claims: &str) -> BoxFuture<'_, Token> {
         *self.refresh_tokens_issued.write().unwrap() += 1;
-        Token::new(&format!("refresh_token_for_{}", subject))
+        self.issued_claims.write().unwrap().push(claims.to_string());
+        let token = Token::new(&format!("refresh_token_for_{}", subject));
+        Box::pin(async move { token })
     }
 
-    fn issue_service_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(&format!("service_token_for_{}", subject))
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(&format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
     }
     
-    fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
-        // Simple mock validation - just check if token contains expected format
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = // Simple mock validation - just check if token contains expected format
         if token.value().contains("access_token_for_") {
             Ok("user123".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
     
-    fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
-        // Simple mock validation
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = // Simple mock validation
         if token.value().contains("refresh_token_for_") {
             Ok("user123".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 
-    fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().contains("service_token_for_") {
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().contains("service_token_for_") {
             Ok("service123".to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
+    }
+}
+
+/// Token service whose encoder silently fails, handing back empty tokens
+struct EmptyTokenService;
+
+impl TokenService for EmptyTokenService {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_service_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
     }
 }
 
@@ -129,6 +175,8 @@ async fn test_issue_session_success() {
     let use_case = IssueSession::new(
         &session_repo,
         &token_service,
+        &SystemClock,
+        &SystemRandomSource,
         3600, // access_token_ttl
         30,   // refresh_token_ttl_days
     );
@@ -138,6 +186,7 @@ async fn test_issue_session_success() {
         ip_address: "127.0.0.1".to_string(),
         user_agent: "Test".to_string(),
         scopes: vec!["user:read".to_string()],
+        workspace_id: None,
     };
     
     let result = use_case.execute(input).await;
@@ -167,6 +216,8 @@ async fn test_issue_session_different_users() {
     let use_case = IssueSession::new(
         &session_repo,
         &token_service,
+        &SystemClock,
+        &SystemRandomSource,
         3600,
         30,
     );
@@ -181,6 +232,7 @@ async fn test_issue_session_different_users() {
             ip_address: "127.0.0.1".to_string(),
             user_agent: "Test".to_string(),
             scopes: vec!["user:read".to_string()],
+            workspace_id: None,
         };
         
         let output = use_case.execute(input).await.unwrap();
@@ -203,6 +255,8 @@ async fn test_issue_session_with_metadata() {
     let use_case = IssueSession::new(
         &session_repo,
         &token_service,
+        &SystemClock,
+        &SystemRandomSource,
         3600,
         30,
     );
@@ -212,6 +266,7 @@ async fn test_issue_session_with_metadata() {
         ip_address: "203.0.113.1".to_string(),
         user_agent: "CustomApp/1.0".to_string(),
         scopes: vec!["user:read".to_string()],
+        workspace_id: None,
     };
     
     let result = use_case.execute(input).await;
@@ -233,6 +288,8 @@ async fn test_issue_session_token_expiration() {
         let use_case = IssueSession::new(
             &session_repo,
             &token_service,
+            &SystemClock,
+            &SystemRandomSource,
             ttl,
             30,
         );
@@ -242,9 +299,425 @@ async fn test_issue_session_token_expiration() {
             ip_address: "127.0.0.1".to_string(),
             user_agent: "Test".to_string(),
             scopes: vec!["user:read".to_string()],
+            workspace_id: None,
         };
         
         let output = use_case.execute(input).await.unwrap();
         assert_eq!(output.expires_in, ttl, "TTL should match configured value");
     }
 }
+
+#[tokio::test]
+async fn test_issue_session_expiry_computed_from_injected_clock() {
+    use chrono::TimeZone;
+
+    let session_repo = MockSessionRepo::new();
+    let token_service = MockTokenService::new();
+    let instant = chrono::Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+    let clock = FixedClock::new(instant);
+    
+    let use_case = IssueSession::new(&session_repo, &token_service, &clock, &SystemRandomSource, 900, 7);
+    
+    let input = IssueSessionInput {
+        user: UserIdentity::new("user123"),
+        ip_address: "127.0.0.1".to_string(),
+        user_agent: "Test".to_string(),
+        scopes: vec![],
+        workspace_id: None,
+    };
+    use_case.execute(input).await.unwrap();
+    
+    let issued = token_service.issued_claims.read().unwrap().clone();
+    let access: TokenClaims = serde_json::from_str(&issued[0]).unwrap();
+    let refresh: TokenClaims = serde_json::from_str(&issued[1]).unwrap();
+    
+    assert_eq!(access.iat, 1_700_000_000);
+    assert_eq!(access.exp, 1_700_000_900);
+    assert_eq!(refresh.iat, 1_700_000_000);
+    assert_eq!(refresh.exp, 1_700_000_000 + 7 * 86400);
+}
+
+#[tokio::test]
+async fn test_issue_session_row_expiry_matches_refresh_token_exp() {
+    use chrono::TimeZone;
+
+    let session_repo = MockSessionRepo::new();
+    let token_service = MockTokenService::new();
+    let clock = FixedClock::new(chrono::Utc.timestamp_opt(1_700_000_000, 0).unwrap());
+
+    let use_case = IssueSession::new(&session_repo, &token_service, &clock, &SystemRandomSource, 900, 7);
+
+    let input = IssueSessionInput {
+        user: UserIdentity::new("user123"),
+        ip_address: "127.0.0.1".to_string(),
+        user_agent: "Test".to_string(),
+        scopes: vec![],
+        workspace_id: None,
+    };
+    use_case.execute(input).await.unwrap();
+
+    let refresh: TokenClaims = serde_json::from_str(&token_service.issued_claims.read().unwrap()[1]).unwrap();
+    let metadata: serde_json::Value = serde_json::from_str(&session_repo.metadata.read().unwrap()[0]).unwrap();
+    let row_expiry = chrono::DateTime::parse_from_rfc3339(metadata["expires"].as_str().unwrap()).unwrap();
+
+    assert_eq!(row_expiry.timestamp(), refresh.exp);
+}
+
+#[tokio::test]
+async fn test_issue_session_id_is_deterministic_with_fixed_random_source() {
+    use chrono::TimeZone;
+
+    let session_repo = MockSessionRepo::new();
+    let token_service = MockTokenService::new();
+    let clock = FixedClock::new(chrono::Utc.timestamp_opt(1_700_000_000, 0).unwrap());
+    let random = SeededRandomSource::new(42);
+
+    let use_case = IssueSession::new(&session_repo, &token_service, &clock, &random, 900, 7);
+
+    let input = IssueSessionInput {
+        user: UserIdentity::new("user123"),
+        ip_address: "127.0.0.1".to_string(),
+        user_agent: "Test".to_string(),
+        scopes: vec![],
+        workspace_id: None,
+    };
+    let output = use_case.execute(input).await.unwrap();
+
+    assert_eq!(output.session_id, "018bcfe5-6800-756e-ab2f-2632d7bd03f1");
+}
+
+#[tokio::test]
+async fn test_issue_session_embeds_sid_in_both_tokens() {
+    let session_repo = MockSessionRepo::new();
+    let token_service = MockTokenService::new();
+    
+    let use_case = IssueSession::new(&session_repo, &token_service, &SystemClock, &SystemRandomSource, 900, 7);
+    
+    let input = IssueSessionInput {
+        user: UserIdentity::new("user123"),
+        ip_address: "127.0.0.1".to_string(),
+        user_agent: "Test".to_string(),
+        scopes: vec![],
+        workspace_id: None,
+    };
+    let output = use_case.execute(input).await.unwrap();
+    
+    let issued = token_service.issued_claims.read().unwrap().clone();
+    for claims in issued {
+        let claims: TokenClaims = serde_json::from_str(&claims).unwrap();
+        assert_eq!(claims.sid.as_deref(), Some(output.session_id.as_str()));
+    }
+}
+
+#[tokio::test]
+async fn test_issue_session_rejects_empty_token() {
+    let session_repo = MockSessionRepo::new();
+    let token_service = EmptyTokenService;
+
+    let use_case = IssueSession::new(&session_repo, &token_service, &SystemClock, &SystemRandomSource, 3600, 30);
+
+    let input = IssueSessionInput {
+        user: UserIdentity::new("user123"),
+        ip_address: "127.0.0.1".to_string(),
+        user_agent: "Test".to_string(),
+        scopes: vec![],
+        workspace_id: None,
+    };
+
+    let result = use_case.execute(input).await;
+    assert!(
+        matches!(result, Err(CoreError::Invariant(InvariantError::InconsistentState { .. }))),
+        "Empty token should be rejected as an invariant violation"
+    );
+
+    // Nothing may be persisted for a session whose tokens were never produced
+    assert_eq!(session_repo.get_session_count(), 0);
+}
+
+// ============================================================================
+// Atomic Persistence
+// ============================================================================
+
+/// Session repository whose writes always fail
+struct FailingSessionRepo;
+
+impl SessionRepository for FailingSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Err(InvariantError::dependency_unavailable("session store", "write failed").into()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+/// Unit of work that records commits and rollbacks
+#[derive(Default)]
+struct RecordingUnitOfWork {
+    fail_write: bool,
+    fail_login: bool,
+    committed: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    committed_logins: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    rolled_back: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+struct RecordingScope {
+    fail_write: bool,
+    fail_login: bool,
+    staged: Vec<String>,
+    staged_logins: Vec<String>,
+    committed: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    committed_logins: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    rolled_back: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl UnitOfWork for RecordingUnitOfWork {
+    fn begin(&self) -> BoxFuture<'_, Result<Box<dyn UnitOfWorkScope>, CoreError>> {
+        let scope = RecordingScope {
+            fail_write: self.fail_write,
+            fail_login: self.fail_login,
+            staged: Vec::new(),
+            staged_logins: Vec::new(),
+            committed: self.committed.clone(),
+            committed_logins: self.committed_logins.clone(),
+            rolled_back: self.rolled_back.clone(),
+        };
+        Box::pin(async move { Ok(Box::new(scope) as Box<dyn UnitOfWorkScope>) })
+    }
+}
+
+impl UnitOfWorkScope for RecordingScope {
+    fn create_session(&mut self, session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        let session_id = session_id.to_string();
+        Box::pin(async move {
+            if self.fail_write {
+                return Err(InvariantError::dependency_unavailable("session store", "write failed").into());
+            }
+            self.staged.push(session_id);
+            Ok(())
+        })
+    }
+
+    fn record_successful_login(&mut self, user_id: &str, _at: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        let user_id = user_id.to_string();
+        Box::pin(async move {
+            if self.fail_login {
+                return Err(InvariantError::dependency_unavailable("credential store", "write failed").into());
+            }
+            self.staged_logins.push(user_id);
+            Ok(())
+        })
+    }
+
+    fn commit(self: Box<Self>) -> BoxFuture<'static, Result<(), CoreError>> {
+        let RecordingScope { staged, staged_logins, committed, committed_logins, .. } = *self;
+        committed.lock().unwrap().extend(staged);
+        committed_logins.lock().unwrap().extend(staged_logins);
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn rollback(self: Box<Self>) -> BoxFuture<'static, ()> {
+        self.rolled_back.store(true, std::sync::atomic::Ordering::SeqCst);
+        Box::pin(async move {})
+    }
+}
+
+fn session_input() -> IssueSessionInput {
+    IssueSessionInput {
+        user: UserIdentity::new("user123"),
+        ip_address: "127.0.0.1".to_string(),
+        user_agent: "Test".to_string(),
+        scopes: vec![],
+        workspace_id: None,
+    }
+}
+
+#[tokio::test]
+async fn test_issue_session_surfaces_no_tokens_when_session_write_fails() {
+    let token_service = MockTokenService::new();
+
+    let use_case = IssueSession::new(&FailingSessionRepo, &token_service, &SystemClock, &SystemRandomSource, 3600, 30);
+
+    let result = use_case.execute(session_input()).await;
+    assert!(
+        matches!(result, Err(CoreError::Invariant(InvariantError::DependencyUnavailable { .. }))),
+        "A failed session write must fail the use case instead of returning tokens"
+    );
+}
+
+#[tokio::test]
+async fn test_issue_session_commits_session_in_unit_of_work() {
+    let session_repo = MockSessionRepo::new();
+    let token_service = MockTokenService::new();
+    let unit_of_work = RecordingUnitOfWork::default();
+
+    let use_case = IssueSession::new(&session_repo, &token_service, &SystemClock, &SystemRandomSource, 3600, 30)
+        .with_unit_of_work(&unit_of_work);
+
+    let output = use_case.execute(session_input()).await.expect("session issued");
+
+    assert_eq!(*unit_of_work.committed.lock().unwrap(), vec![output.session_id]);
+    assert_eq!(*unit_of_work.committed_logins.lock().unwrap(), vec!["user123".to_string()]);
+    assert!(!unit_of_work.rolled_back.load(std::sync::atomic::Ordering::SeqCst));
+    // The write goes through the unit of work, not the plain repository
+    assert_eq!(session_repo.get_session_count(), 0);
+}
+
+#[tokio::test]
+async fn test_issue_session_rolls_back_unit_of_work_when_write_fails() {
+    let session_repo = MockSessionRepo::new();
+    let token_service = MockTokenService::new();
+    let unit_of_work = RecordingUnitOfWork {
+        fail_write: true,
+        ..Default::default()
+    };
+
+    let use_case = IssueSession::new(&session_repo, &token_service, &SystemClock, &SystemRandomSource, 3600, 30)
+        .with_unit_of_work(&unit_of_work);
+
+    let result = use_case.execute(session_input()).await;
+
+    assert!(result.is_err(), "No tokens may be returned for an unpersisted session");
+    assert!(unit_of_work.rolled_back.load(std::sync::atomic::Ordering::SeqCst));
+    assert!(unit_of_work.committed.lock().unwrap().is_empty());
+    assert!(unit_of_work.committed_logins.lock().unwrap().is_empty());
+    assert_eq!(session_repo.get_session_count(), 0);
+}
+
+#[tokio::test]
+async fn test_issue_session_rolls_back_session_when_login_record_fails() {
+    let session_repo = MockSessionRepo::new();
+    let token_service = MockTokenService::new();
+    let unit_of_work = RecordingUnitOfWork {
+        fail_login: true,
+        ..Default::default()
+    };
+
+    let use_case = IssueSession::new(&session_repo, &token_service, &SystemClock, &SystemRandomSource, 3600, 30)
+        .with_unit_of_work(&unit_of_work);
+
+    let result = use_case.execute(session_input()).await;
+
+    assert!(result.is_err(), "The session must not outlive a failed write in its unit of work");
+    assert!(unit_of_work.rolled_back.load(std::sync::atomic::Ordering::SeqCst));
+    assert!(unit_of_work.committed.lock().unwrap().is_empty());
+}
+
+// ============================================================================
+// Session metadata enrichment
+// ============================================================================
+
+/// Enricher reporting a fixed device, or failing every lookup.
+struct MockEnricher {
+    fail: bool,
+}
+
+impl SessionMetadataEnricher for MockEnricher {
+    fn enrich<'a>(&'a self, _ip_address: &'a str, _user_agent: &'a str) -> BoxFuture<'a, Result<SessionClientDetails, String>> {
+        let result = if self.fail {
+            Err("geo database unavailable".to_string())
+        } else {
+            Ok(SessionClientDetails {
+                device_type: Some("desktop".to_string()),
+                os: Some("Linux".to_string()),
+                browser: Some("Firefox".to_string()),
+                country: Some("DE".to_string()),
+                region: None,
+                city: Some("Berlin".to_string()),
+            })
+        };
+        Box::pin(async move { result })
+    }
+}
+
+/// Enricher whose lookup never completes.
+struct HangingEnricher;
+
+impl SessionMetadataEnricher for HangingEnricher {
+    fn enrich<'a>(&'a self, _ip_address: &'a str, _user_agent: &'a str) -> BoxFuture<'a, Result<SessionClientDetails, String>> {
+        Box::pin(futures::future::pending())
+    }
+}
+
+/// Issue a session for a fresh account and return the stored row.
+async fn issue_enriched_session(
+    enricher: &(dyn SessionMetadataEnricher + Send + Sync),
+) -> crate::adapters::persistence::models::SessionRow {
+    use crate::core::usecases::ports::IdentityRepository;
+
+    let store = crate::adapters::memory::MemoryStore::new();
+    let user_id = uuid::Uuid::new_v4();
+    crate::adapters::memory::IdentityRepositoryMemory::new(store.clone())
+        .create(&user_id, "alice", "hashed_password", "", "", 0)
+        .await
+        .unwrap();
+    let session_repo = crate::adapters::memory::SessionRepositoryMemory::new(store.clone());
+    let token_service = MockTokenService::new();
+
+    let use_case = IssueSession::new(&session_repo, &token_service, &SystemClock, &SystemRandomSource, 3600, 30)
+        .with_metadata_enricher(enricher)
+        .with_enrichment_timeout(std::time::Duration::from_millis(50));
+    let output = use_case
+        .execute(IssueSessionInput {
+            user: UserIdentity::new(user_id.to_string()),
+            ip_address: "203.0.113.7".to_string(),
+            user_agent: "Mozilla/5.0 (X11; Linux x86_64) Firefox/128.0".to_string(),
+            scopes: vec![],
+            workspace_id: None,
+        })
+        .await
+        .expect("session issued");
+
+    let session_id = uuid::Uuid::parse_str(&output.session_id).unwrap();
+    store.sessions().get(&session_id).expect("session stored").row.clone()
+}
+
+#[tokio::test]
+async fn test_issue_session_stores_enriched_client_details() {
+    let row = issue_enriched_session(&MockEnricher { fail: false }).await;
+
+    assert_eq!(row.device_type.as_deref(), Some("desktop"));
+    assert_eq!(row.os.as_deref(), Some("Linux"));
+    assert_eq!(row.browser.as_deref(), Some("Firefox"));
+    assert_eq!(row.geo_country.as_deref(), Some("DE"));
+    assert_eq!(row.geo_region, None);
+    assert_eq!(row.geo_city.as_deref(), Some("Berlin"));
+    // The raw values are kept alongside
+    assert_eq!(row.ip_address, "203.0.113.7");
+    assert_eq!(row.user_agent, "Mozilla/5.0 (X11; Linux x86_64) Firefox/128.0");
+}
+
+#[tokio::test]
+async fn test_issue_session_survives_failing_enricher() {
+    let row = issue_enriched_session(&MockEnricher { fail: true }).await;
+
+    assert_eq!(row.ip_address, "203.0.113.7");
+    assert_eq!(row.user_agent, "Mozilla/5.0 (X11; Linux x86_64) Firefox/128.0");
+    assert!(row.device_type.is_none());
+    assert!(row.geo_city.is_none());
+}
+
+#[tokio::test]
+async fn test_issue_session_does_not_wait_for_slow_enricher() {
+    let row = issue_enriched_session(&HangingEnricher).await;
+
+    assert_eq!(row.ip_address, "203.0.113.7");
+    assert!(row.device_type.is_none());
+    assert!(row.geo_country.is_none());
+}
@@ -0,0 +1,226 @@
+//! Tests for ListSessionHistory use case.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use futures::future::BoxFuture;
+use std::sync::Mutex;
+
+use super::super::list_session_history::{
+    ListSessionHistory, ListSessionHistoryInput, DEFAULT_SESSION_HISTORY_LIMIT, MAX_SESSION_HISTORY_LIMIT,
+};
+use crate::core::error::CoreError;
+use crate::core::usecases::ports::{
+    SessionCursor, SessionPage, SessionRecord, SessionRepository, SessionStatus,
+};
+use crate::core::usecases::ports::session_repository::Session as SessionType;
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+/// Session history for a single user, paged by `(created_at, session_id)`
+/// the way the SQL repository does it.
+struct InMemoryHistoryRepo {
+    sessions: Mutex<Vec<SessionRecord>>,
+    requested_limits: Mutex<Vec<usize>>,
+}
+
+impl InMemoryHistoryRepo {
+    fn with_sessions(count: i64) -> Self {
+        let repo = Self {
+            sessions: Mutex::new(Vec::new()),
+            requested_limits: Mutex::new(Vec::new()),
+        };
+        for i in 0..count {
+            repo.insert(base_time() + Duration::minutes(i), SessionStatus::Active);
+        }
+        repo
+    }
+
+    fn insert(&self, created_at: DateTime<Utc>, status: SessionStatus) -> String {
+        let mut sessions = self.sessions.lock().unwrap();
+        let session_id = format!("session-{:03}", sessions.len());
+        sessions.push(SessionRecord {
+            session_id: session_id.clone(),
+            created_at,
+            expires_at: created_at + Duration::days(7),
+            revoked_at: (status == SessionStatus::Revoked).then_some(created_at + Duration::hours(1)),
+            status,
+            ip_address: "203.0.113.7".to_string(),
+            user_agent: "Mozilla/5.0".to_string(),
+        });
+        session_id
+    }
+}
+
+fn base_time() -> DateTime<Utc> {
+    Utc.timestamp_opt(1_700_000_000, 0).unwrap()
+}
+
+impl SessionRepository for InMemoryHistoryRepo {
+    fn create_session(&self, _session_id: &str, _user: &crate::core::identity::UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<SessionType>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<SessionType>> {
+        Box::pin(async move { None })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn list_for_user_paginated(
+        &self,
+        user_id: &str,
+        cursor: Option<&SessionCursor>,
+        limit: usize,
+    ) -> BoxFuture<'_, Result<SessionPage, CoreError>> {
+        self.requested_limits.lock().unwrap().push(limit);
+
+        let mut sessions = if user_id == "user123" {
+            self.sessions.lock().unwrap().clone()
+        } else {
+            Vec::new()
+        };
+        let key = |s: &SessionRecord| (s.created_at, s.session_id.clone());
+        sessions.sort_by_key(|s| std::cmp::Reverse(key(s)));
+        sessions.retain(|s| cursor.is_none_or(|c| key(s) < (c.created_at, c.session_id.clone())));
+
+        let has_more = sessions.len() > limit;
+        sessions.truncate(limit);
+        let next_cursor = if has_more { sessions.last().map(SessionCursor::after) } else { None };
+        Box::pin(async move { Ok(SessionPage { sessions, next_cursor }) })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+fn input(cursor: Option<SessionCursor>, limit: Option<usize>) -> ListSessionHistoryInput {
+    ListSessionHistoryInput {
+        user_id: "user123".to_string(),
+        cursor,
+        limit,
+    }
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_session_history_walks_every_page_newest_first() {
+    let repo = InMemoryHistoryRepo::with_sessions(7);
+    let use_case = ListSessionHistory::new(&repo);
+
+    let mut seen = Vec::new();
+    let mut cursor = None;
+    let mut pages = 0;
+    loop {
+        let output = use_case.execute(input(cursor, Some(3))).await.unwrap();
+        pages += 1;
+        assert!(output.page.sessions.len() <= 3);
+        seen.extend(output.page.sessions.iter().map(|s| s.session_id.clone()));
+        cursor = output.page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    assert_eq!(pages, 3);
+    let expected: Vec<String> = (0..7).rev().map(|i| format!("session-{:03}", i)).collect();
+    assert_eq!(seen, expected);
+}
+
+#[tokio::test]
+async fn test_session_history_last_page_has_no_cursor_on_exact_boundary() {
+    let repo = InMemoryHistoryRepo::with_sessions(4);
+    let use_case = ListSessionHistory::new(&repo);
+
+    let first = use_case.execute(input(None, Some(2))).await.unwrap();
+    let second = use_case.execute(input(first.page.next_cursor.clone(), Some(2))).await.unwrap();
+
+    assert!(first.page.next_cursor.is_some());
+    assert_eq!(second.page.sessions.len(), 2);
+    assert_eq!(second.page.next_cursor, None);
+}
+
+#[tokio::test]
+async fn test_session_history_is_stable_under_concurrent_inserts() {
+    let repo = InMemoryHistoryRepo::with_sessions(4);
+    let use_case = ListSessionHistory::new(&repo);
+
+    let first = use_case.execute(input(None, Some(2))).await.unwrap();
+    // A new login lands between the two page requests
+    repo.insert(base_time() + Duration::days(1), SessionStatus::Active);
+    let second = use_case.execute(input(first.page.next_cursor, Some(2))).await.unwrap();
+
+    let ids: Vec<&str> = second.page.sessions.iter().map(|s| s.session_id.as_str()).collect();
+    assert_eq!(ids, vec!["session-001", "session-000"]);
+}
+
+#[tokio::test]
+async fn test_session_history_includes_revoked_and_expired_sessions() {
+    let repo = InMemoryHistoryRepo::with_sessions(0);
+    repo.insert(base_time(), SessionStatus::Expired);
+    repo.insert(base_time() + Duration::minutes(1), SessionStatus::Revoked);
+    repo.insert(base_time() + Duration::minutes(2), SessionStatus::Active);
+    let use_case = ListSessionHistory::new(&repo);
+
+    let output = use_case.execute(input(None, None)).await.unwrap();
+
+    let statuses: Vec<SessionStatus> = output.page.sessions.iter().map(|s| s.status).collect();
+    assert_eq!(statuses, vec![SessionStatus::Active, SessionStatus::Revoked, SessionStatus::Expired]);
+    assert!(output.page.sessions[1].revoked_at.is_some());
+}
+
+#[tokio::test]
+async fn test_session_history_applies_default_and_maximum_limit() {
+    let repo = InMemoryHistoryRepo::with_sessions(1);
+    let use_case = ListSessionHistory::new(&repo);
+
+    let default = use_case.execute(input(None, None)).await.unwrap();
+    let clamped = use_case.execute(input(None, Some(10_000))).await.unwrap();
+
+    assert_eq!(default.limit, DEFAULT_SESSION_HISTORY_LIMIT);
+    assert_eq!(clamped.limit, MAX_SESSION_HISTORY_LIMIT);
+    assert_eq!(
+        *repo.requested_limits.lock().unwrap(),
+        vec![DEFAULT_SESSION_HISTORY_LIMIT, MAX_SESSION_HISTORY_LIMIT]
+    );
+}
+
+#[tokio::test]
+async fn test_session_history_rejects_zero_limit() {
+    let repo = InMemoryHistoryRepo::with_sessions(1);
+    let use_case = ListSessionHistory::new(&repo);
+
+    let result = use_case.execute(input(None, Some(0))).await;
+
+    assert!(matches!(result, Err(CoreError::Invariant(_))));
+}
+
+#[tokio::test]
+async fn test_session_history_missing_user_id() {
+    let repo = InMemoryHistoryRepo::with_sessions(1);
+    let use_case = ListSessionHistory::new(&repo);
+
+    let result = use_case
+        .execute(ListSessionHistoryInput {
+            user_id: String::new(),
+            cursor: None,
+            limit: None,
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Invariant(_))));
+}
@@ -0,0 +1,121 @@
+//! Tests for ListSessions use case.
+
+use chrono::{Duration, TimeZone, Utc};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+
+use super::super::list_sessions::{ListSessions, ListSessionsInput};
+use crate::core::error::CoreError;
+use crate::core::usecases::ports::{SessionRepository, SessionSummary};
+use crate::core::usecases::ports::session_repository::Session as SessionType;
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+struct MockSessionRepo {
+    sessions: HashMap<String, Vec<SessionSummary>>, // user_id -> active sessions
+}
+
+impl MockSessionRepo {
+    fn new() -> Self {
+        let created = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let summary = |id: &str| SessionSummary {
+            session_id: id.to_string(),
+            created_at: created,
+            expires_at: created + Duration::days(7),
+            ip_address: "203.0.113.7".to_string(),
+            user_agent: "Mozilla/5.0".to_string(),
+        };
+
+        let mut sessions = HashMap::new();
+        sessions.insert("user123".to_string(), vec![summary("session_phone"), summary("session_laptop")]);
+        sessions.insert("user456".to_string(), vec![summary("session_other_user")]);
+        Self { sessions }
+    }
+}
+
+impl SessionRepository for MockSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &crate::core::identity::UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<SessionType>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<SessionType>> {
+        Box::pin(async move { None })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn list_active_for_user(&self, user_id: &str) -> BoxFuture<'_, Vec<SessionSummary>> {
+        let result = self.sessions.get(user_id).cloned().unwrap_or_default();
+        Box::pin(async move { result })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_list_sessions_returns_only_users_sessions() {
+    let session_repo = MockSessionRepo::new();
+
+    let use_case = ListSessions::new(&session_repo);
+    let output = use_case
+        .execute(ListSessionsInput {
+            user_id: "user123".to_string(),
+            current_session_id: Some("session_laptop".to_string()),
+        })
+        .await
+        .unwrap();
+
+    let ids: Vec<&str> = output.sessions.iter().map(|s| s.session_id.as_str()).collect();
+    assert_eq!(ids, vec!["session_phone", "session_laptop"]);
+    assert!(!output.is_current(&output.sessions[0]));
+    assert!(output.is_current(&output.sessions[1]));
+}
+
+#[tokio::test]
+async fn test_list_sessions_for_user_without_sessions() {
+    let session_repo = MockSessionRepo::new();
+
+    let use_case = ListSessions::new(&session_repo);
+    let output = use_case
+        .execute(ListSessionsInput {
+            user_id: "user789".to_string(),
+            current_session_id: None,
+        })
+        .await
+        .unwrap();
+
+    assert!(output.sessions.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_sessions_missing_user_id() {
+    let session_repo = MockSessionRepo::new();
+
+    let use_case = ListSessions::new(&session_repo);
+    let result = use_case
+        .execute(ListSessionsInput {
+            user_id: String::new(),
+            current_session_id: None,
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Invariant(_))));
+}
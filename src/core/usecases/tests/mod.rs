@@ -3,11 +3,25 @@
 //! This module contains tests for all use cases, policies, and ports.
 
 pub mod authenticate_user_tests;
+pub mod authenticate_in_workspace_tests;
 pub mod issue_session_tests;
 pub mod issue_service_token_tests;
 pub mod issue_session_for_identity_tests;
 pub mod refresh_token_tests;
 pub mod revoke_session_tests;
+pub mod revoke_other_sessions_tests;
+pub mod revoke_all_sessions_tests;
+pub mod list_sessions_tests;
+pub mod list_session_history_tests;
 pub mod validate_access_token_tests;
+pub mod introspect_token_tests;
+pub mod revoke_access_token_tests;
+pub mod verify_totp_tests;
+pub mod register_passkey_tests;
+pub mod authenticate_with_passkey_tests;
+pub mod initiate_password_reset_tests;
+pub mod complete_password_reset_tests;
+pub mod change_password_tests;
+pub mod confirm_verification_tests;
 pub mod policies_tests;
 pub mod ports_tests;
@@ -0,0 +1,128 @@
+//! Tests for AuthPolicyConfig.
+
+use crate::core::error::InvariantError;
+use crate::core::usecases::policies::{AuthPolicyConfig, HasherParams, LockoutPolicy, TokenLifetimes};
+
+fn valid_config() -> AuthPolicyConfig {
+    AuthPolicyConfig::new(
+        LockoutPolicy::new(5, 30 * 60, true).with_exponential_backoff(24 * 60 * 60),
+        TokenLifetimes::new(15 * 60, 7, 60 * 60, true),
+        HasherParams::default(),
+    )
+}
+
+fn reason(config: &AuthPolicyConfig) -> String {
+    match config.validate() {
+        Err(InvariantError::InvalidConfiguration { reason }) => reason,
+        other => panic!("expected InvalidConfiguration, got {:?}", other),
+    }
+}
+
+#[test]
+fn auth_policy_config_valid_passes() {
+    assert!(valid_config().validate().is_ok());
+}
+
+#[test]
+fn auth_policy_config_rejects_zero_max_attempts() {
+    let mut config = valid_config();
+    config.lockout.max_attempts = 0;
+
+    assert!(reason(&config).contains("max attempts"));
+}
+
+#[test]
+fn auth_policy_config_rejects_zero_lock_duration() {
+    let mut config = valid_config();
+    config.lockout.lock_duration_secs = 0;
+
+    assert!(reason(&config).contains("lockout duration"));
+}
+
+#[test]
+fn auth_policy_config_rejects_backoff_cap_below_lock_duration() {
+    let mut config = valid_config();
+    config.lockout = LockoutPolicy::new(5, 3600, true).with_exponential_backoff(60);
+
+    let reason = reason(&config);
+    assert!(reason.contains("backoff cap (60s)"));
+    assert!(reason.contains("lock duration (3600s)"));
+}
+
+#[test]
+fn auth_policy_config_rejects_access_ttl_longer_than_refresh_ttl() {
+    let mut config = valid_config();
+    config.tokens = TokenLifetimes::new(2 * 24 * 60 * 60, 1, 3600, true);
+
+    let reason = reason(&config);
+    assert!(reason.contains("access token TTL (172800s)"));
+    assert!(reason.contains("refresh token TTL (86400s)"));
+}
+
+#[test]
+fn auth_policy_config_rejects_access_ttl_equal_to_refresh_ttl() {
+    let mut config = valid_config();
+    config.tokens = TokenLifetimes::new(24 * 60 * 60, 1, 3600, true);
+
+    assert!(reason(&config).contains("must be shorter"));
+}
+
+#[test]
+fn auth_policy_config_rejects_zero_token_ttls() {
+    let mut config = valid_config();
+    config.tokens.access_ttl_secs = 0;
+    assert!(reason(&config).contains("access token TTL"));
+
+    let mut config = valid_config();
+    config.tokens.refresh_ttl_days = 0;
+    assert!(reason(&config).contains("refresh token TTL"));
+
+    let mut config = valid_config();
+    config.tokens.service_ttl_secs = 0;
+    assert!(reason(&config).contains("service token TTL"));
+}
+
+#[test]
+fn auth_policy_config_rejects_rotation_grace_not_shorter_than_access_ttl() {
+    let mut config = valid_config();
+    config.tokens = TokenLifetimes::new(15 * 60, 7, 3600, true).with_rotation_grace(30);
+    assert!(config.validate().is_ok());
+
+    config.tokens = TokenLifetimes::new(15 * 60, 7, 3600, true).with_rotation_grace(15 * 60);
+    let reason = reason(&config);
+    assert!(reason.contains("refresh rotation grace (900s)"));
+    assert!(reason.contains("access token TTL (900s)"));
+}
+
+#[test]
+fn auth_policy_config_rejects_zero_hash_iterations() {
+    let mut config = valid_config();
+    config.hasher = HasherParams::new(65536, 0, 4);
+
+    assert!(reason(&config).contains("iterations"));
+}
+
+#[test]
+fn auth_policy_config_rejects_hash_memory_below_lane_minimum() {
+    let mut config = valid_config();
+    config.hasher = HasherParams::new(16, 3, 4);
+
+    let reason = reason(&config);
+    assert!(reason.contains("16 KiB"));
+    assert!(reason.contains("4 lanes"));
+}
+
+#[test]
+fn auth_policy_config_error_reads_as_invalid_configuration() {
+    let mut config = valid_config();
+    config.lockout.max_attempts = 0;
+
+    let message = config.validate().unwrap_err().to_string();
+    assert!(message.starts_with("Invalid configuration:"));
+}
+
+#[test]
+fn token_lifetimes_refresh_ttl_in_seconds() {
+    let tokens = TokenLifetimes::new(900, 7, 3600, false);
+    assert_eq!(tokens.refresh_ttl_secs(), 7 * 24 * 60 * 60);
+}
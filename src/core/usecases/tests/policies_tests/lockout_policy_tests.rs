@@ -17,3 +17,62 @@ fn lockout_policy_resets_on_success() {
     let policy2 = LockoutPolicy::new(5, 3600, false);
     assert!(!policy2.should_reset_on_success());
 }
+
+#[test]
+fn lockout_policy_fixed_duration_by_default() {
+    let policy = LockoutPolicy::new(3, 60, true);
+    assert_eq!(policy.lock_duration_for(2), 0);
+    assert_eq!(policy.lock_duration_for(3), 60);
+    assert_eq!(policy.lock_duration_for(4), 60);
+}
+
+#[test]
+fn lockout_policy_exponential_backoff_doubles() {
+    let policy = LockoutPolicy::new(3, 60, true).with_exponential_backoff(3600);
+    assert_eq!(policy.lock_duration_for(2), 0);
+    assert_eq!(policy.lock_duration_for(3), 60);
+    assert_eq!(policy.lock_duration_for(4), 120);
+    assert_eq!(policy.lock_duration_for(5), 240);
+}
+
+#[test]
+fn lockout_policy_exponential_backoff_honors_cap() {
+    let policy = LockoutPolicy::new(3, 60, true).with_exponential_backoff(300);
+    assert_eq!(policy.lock_duration_for(5), 240);
+    assert_eq!(policy.lock_duration_for(6), 300);
+    // Large overage must not overflow
+    assert_eq!(policy.lock_duration_for(200), 300);
+}
+
+#[test]
+fn lockout_policy_unlock_on_success_is_opt_in() {
+    let policy = LockoutPolicy::new(5, 3600, true);
+    assert!(!policy.should_unlock_on_success());
+
+    let policy = policy.with_unlock_on_successful_auth(true);
+    assert!(policy.should_unlock_on_success());
+}
+
+#[test]
+fn lockout_policy_failure_reset_window() {
+    let policy = LockoutPolicy::new(5, 3600, true);
+    assert!(!policy.is_failure_stale(i64::MAX));
+
+    let policy = policy.with_failure_reset_window(600);
+    assert!(!policy.is_failure_stale(600));
+    assert!(policy.is_failure_stale(601));
+    // A failure stamped in the future still counts
+    assert!(!policy.is_failure_stale(-3600));
+}
+
+#[test]
+fn lockout_policy_account_ceiling() {
+    let policy = LockoutPolicy::new(3, 60, true).with_exponential_backoff(3600);
+    assert!(!policy.is_account_ceiling_reached(11));
+    assert!(policy.is_account_ceiling_reached(12));
+
+    let policy = policy.with_account_ceiling(5);
+    assert_eq!(policy.account_lock_duration_for(4), 0);
+    assert_eq!(policy.account_lock_duration_for(5), 60);
+    assert_eq!(policy.account_lock_duration_for(6), 120);
+}
@@ -1,4 +1,6 @@
-//! Tests for policies (lockout and token).
+//! Tests for policies (lockout, session binding and token) and their typed configuration.
 
+pub mod auth_policy_config_tests;
 pub mod lockout_policy_tests;
+pub mod session_binding_policy_tests;
 pub mod token_policy_tests;
@@ -0,0 +1,68 @@
+//! Tests for SessionBindingPolicy.
+
+use crate::core::usecases::policies::{BindingMismatch, IpBinding, SessionBindingPolicy};
+
+#[test]
+fn disabled_policy_accepts_any_client() {
+    let policy = SessionBindingPolicy::disabled();
+
+    assert!(!policy.is_enabled());
+    assert_eq!(policy.check("10.0.0.1", "agent-a", "192.0.2.1", "agent-b"), Ok(()));
+}
+
+#[test]
+fn exact_ip_binding_requires_identical_address() {
+    assert!(IpBinding::Exact.matches("203.0.113.7", "203.0.113.7"));
+    assert!(!IpBinding::Exact.matches("203.0.113.7", "203.0.113.8"));
+}
+
+#[test]
+fn subnet_ip_binding_compares_network_prefix() {
+    let binding = IpBinding::subnet();
+
+    assert!(binding.matches("203.0.113.7", "203.0.113.250"));
+    assert!(!binding.matches("203.0.113.7", "203.0.112.7"));
+    assert!(binding.matches("2001:db8:1:2::1", "2001:db8:1:2:ffff::9"));
+    assert!(!binding.matches("2001:db8:1:2::1", "2001:db8:1:3::1"));
+}
+
+#[test]
+fn ip_binding_treats_mapped_ipv4_as_ipv4() {
+    assert!(IpBinding::Exact.matches("::ffff:203.0.113.7", "203.0.113.7"));
+}
+
+#[test]
+fn ip_binding_never_matches_across_families() {
+    let binding = IpBinding::Subnet { ipv4_prefix: 0, ipv6_prefix: 0 };
+
+    assert!(binding.matches("203.0.113.7", "198.51.100.1"));
+    assert!(!binding.matches("203.0.113.7", "2001:db8::1"));
+}
+
+#[test]
+fn ip_binding_compares_unparseable_values_literally() {
+    assert!(IpBinding::subnet().matches("unknown", "unknown"));
+    assert!(!IpBinding::subnet().matches("unknown", "203.0.113.7"));
+}
+
+#[test]
+fn user_agent_binding_reports_mismatch() {
+    let policy = SessionBindingPolicy::new(IpBinding::Off, true);
+
+    assert!(policy.is_enabled());
+    assert_eq!(policy.check("10.0.0.1", "agent-a", "192.0.2.1", "agent-a"), Ok(()));
+    assert_eq!(
+        policy.check("10.0.0.1", "agent-a", "10.0.0.1", "agent-b"),
+        Err(BindingMismatch::UserAgent)
+    );
+}
+
+#[test]
+fn ip_binding_reports_mismatch() {
+    let policy = SessionBindingPolicy::new(IpBinding::Exact, false);
+
+    assert_eq!(
+        policy.check("10.0.0.1", "agent-a", "10.0.0.2", "agent-b"),
+        Err(BindingMismatch::IpAddress)
+    );
+}
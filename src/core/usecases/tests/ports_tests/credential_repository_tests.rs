@@ -1,8 +1,9 @@
 //! Tests for CredentialRepository port.
 
 use futures::future::BoxFuture;
-use crate::core::credentials::StoredCredential;
-use crate::core::usecases::ports::CredentialRepository;
+use crate::core::credentials::{CredentialStatus, StoredCredential};
+use crate::core::error::CredentialError;
+use crate::core::usecases::ports::{CredentialRepository, PasswordHasher};
 
 struct MockCredentialRepo;
 impl CredentialRepository for MockCredentialRepo {
@@ -11,6 +12,9 @@ impl CredentialRepository for MockCredentialRepo {
         Box::pin(async move {
             if user_id == "user123" { 
                 Some(StoredCredential::from_hash("hash".to_string())) 
+            } else if user_id == "revoked_user" {
+                Some(StoredCredential::from_hash("hash".to_string())
+                    .with_status(CredentialStatus::Revoked { revoked_at: Some("2024-01-01T00:00:00Z".to_string()) }))
             } else { 
                 None 
             }
@@ -43,3 +47,49 @@ async fn credential_repository_update_failed_attempts() {
     repo.update_failed_attempts("user123", 3).await;
     // No assertion needed, just check method call
 }
+
+#[tokio::test]
+async fn credential_repository_default_status_reads_stored_credential() {
+    let repo = MockCredentialRepo;
+    assert_eq!(repo.get_status("user123").await, CredentialStatus::Active);
+    assert_eq!(
+        repo.get_status("revoked_user").await,
+        CredentialStatus::Revoked { revoked_at: Some("2024-01-01T00:00:00Z".to_string()) }
+    );
+    assert_eq!(repo.get_status("unknown").await, CredentialStatus::Active);
+}
+
+#[tokio::test]
+async fn credential_repository_default_set_status_is_unsupported() {
+    let repo = MockCredentialRepo;
+    let result = repo.set_status("user123", CredentialStatus::Revoked { revoked_at: None }).await;
+    assert!(result.is_err());
+}
+
+/// Hasher storing secrets verbatim, so a stored "hash" verifies the secret "hash".
+struct PlainHasher;
+impl PasswordHasher for PlainHasher {
+    fn hash(&self, raw: &str) -> StoredCredential {
+        StoredCredential::from_hash(raw.to_string())
+    }
+    fn verify(&self, raw: &str, stored: &StoredCredential) -> bool {
+        stored.as_hash_str() == raw
+    }
+}
+
+#[tokio::test]
+async fn credential_repository_default_history_check_rejects_current_secret() {
+    let repo = MockCredentialRepo;
+    assert_eq!(
+        repo.check_password_history("user123", "hash", &PlainHasher, 1).await,
+        Err(CredentialError::reused())
+    );
+    assert!(repo.check_password_history("user123", "fresh", &PlainHasher, 1).await.is_ok());
+}
+
+#[tokio::test]
+async fn credential_repository_default_history_check_disabled_at_depth_zero() {
+    let repo = MockCredentialRepo;
+    assert!(repo.check_password_history("user123", "hash", &PlainHasher, 0).await.is_ok());
+    assert!(repo.password_history("user123", 5).await.is_empty());
+}
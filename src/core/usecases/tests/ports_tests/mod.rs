@@ -6,6 +6,7 @@ pub mod session_repository_tests;
 pub mod password_hasher_tests;
 pub mod token_service_tests;
 pub mod clock_tests;
+pub mod random_source_tests;
 pub mod exchange_authorization_code_tests;
 pub mod external_identity_repository_tests;
 pub mod external_token_validator_tests;
@@ -0,0 +1,30 @@
+//! Tests for RandomSource port.
+
+use crate::core::usecases::ports::RandomSource;
+use chrono::{TimeZone, Utc};
+
+struct MockRandomSource;
+impl RandomSource for MockRandomSource {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        dest.fill(0xff);
+    }
+}
+
+#[test]
+fn random_source_uuid_v4_sets_version_and_variant() {
+    let id = MockRandomSource.uuid_v4();
+    assert_eq!(id.to_string(), "ffffffff-ffff-4fff-bfff-ffffffffffff");
+}
+
+#[test]
+fn random_source_uuid_v7_encodes_timestamp() {
+    let at = Utc.timestamp_opt(1672531200, 0).unwrap();
+    let id = MockRandomSource.uuid_v7(at);
+    assert_eq!(id.get_version_num(), 7);
+    assert_eq!(id.get_timestamp().unwrap().to_unix(), (1672531200, 0));
+}
+
+#[test]
+fn random_source_opaque_token_is_hex_of_requested_length() {
+    assert_eq!(MockRandomSource.opaque_token(4), "ffffffff");
+}
@@ -22,8 +22,8 @@ impl SessionRepository for MockSessionRepo {
     fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
         Box::pin(async move {})
     }
-    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, ()> {
-        Box::pin(async move {})
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
     }
     fn delete_expired(&self) -> BoxFuture<'_, ()> {
         Box::pin(async move {})
@@ -1,43 +1,50 @@
-
 //! Tests for TokenService port.
 
+use futures::future::BoxFuture;
+
 use crate::core::token::Token;
 use crate::core::usecases::ports::TokenService;
 
 struct MockTokenService;
 impl TokenService for MockTokenService {
-    fn issue_access_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(format!("access_{}", subject))
+    fn issue_access_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("access_{}", subject));
+        Box::pin(async move { token })
     }
-    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(format!("refresh_{}", subject))
+    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("refresh_{}", subject));
+        Box::pin(async move { token })
     }
-    fn issue_service_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(format!("service_{}", subject))
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_{}", subject));
+        Box::pin(async move { token })
     }
-    fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().starts_with("access_") { Ok("claims".to_string()) } else { Err(()) }
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().starts_with("access_") { Ok("claims".to_string()) } else { Err(()) };
+        Box::pin(async move { claims })
     }
-    fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().starts_with("refresh_") { Ok("claims".to_string()) } else { Err(()) }
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().starts_with("refresh_") { Ok("claims".to_string()) } else { Err(()) };
+        Box::pin(async move { claims })
     }
-    fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().starts_with("service_") { Ok("claims".to_string()) } else { Err(()) }
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().starts_with("service_") { Ok("claims".to_string()) } else { Err(()) };
+        Box::pin(async move { claims })
     }
 }
 
-#[test]
-fn token_service_issue_access_token() {
+#[tokio::test]
+async fn token_service_issue_access_token() {
     let service = MockTokenService;
-    let token = service.issue_access_token("user123", "claims");
+    let token = service.issue_access_token("user123", "claims").await;
     assert_eq!(token.value(), "access_user123");
 }
 
-#[test]
-fn token_service_validate_access_token() {
+#[tokio::test]
+async fn token_service_validate_access_token() {
     let service = MockTokenService;
     let token = Token::new("access_user123");
-    assert!(service.validate_access_token(&token).is_ok());
+    assert!(service.validate_access_token(&token).await.is_ok());
     let invalid_token = Token::new("invalid");
-    assert!(service.validate_access_token(&invalid_token).is_err());
+    assert!(service.validate_access_token(&invalid_token).await.is_err());
 }
@@ -1,11 +1,14 @@
 //! Comprehensive tests for RefreshSession use case.
 
 use futures::future::BoxFuture;
+use crate::adapters::clock::{FixedClock, SystemClock};
 use super::super::refresh_session::{RefreshSession, RefreshSessionInput};
-use crate::core::error::CoreError;
+use crate::core::error::{CoreError, InvariantError, TokenError};
 use crate::core::token::Token;
 use crate::core::usecases::ports::{SessionRepository, TokenService};
+use crate::core::usecases::policies::{IpBinding, SessionBindingPolicy};
 use crate::core::usecases::ports::session_repository::Session as SessionType;
+use crate::core::usecases::ports::SessionSummary;
 
 // ============================================================================
 // Mock Implementations
@@ -14,6 +17,8 @@ use crate::core::usecases::ports::session_repository::Session as SessionType;
 struct MockSessionRepo {
     sessions: std::sync::RwLock<std::collections::HashMap<String, SessionData>>, // session_id -> session data
     revoked_sessions: std::sync::RwLock<std::collections::HashSet<String>>,
+    clients: std::sync::RwLock<std::collections::HashMap<String, (String, String)>>, // session_id -> (ip, user agent)
+    expirations: std::sync::RwLock<std::collections::HashMap<String, chrono::DateTime<chrono::Utc>>>,
 }
 
 struct SessionData {
@@ -27,8 +32,21 @@ impl MockSessionRepo {
         Self {
             sessions: std::sync::RwLock::new(std::collections::HashMap::new()),
             revoked_sessions: std::sync::RwLock::new(std::collections::HashSet::new()),
+            clients: std::sync::RwLock::new(std::collections::HashMap::new()),
+            expirations: std::sync::RwLock::new(std::collections::HashMap::new()),
         }
     }
+
+    fn set_expires_at(&self, session_id: &str, expires_at: chrono::DateTime<chrono::Utc>) {
+        self.expirations.write().unwrap().insert(session_id.to_string(), expires_at);
+    }
+
+    fn set_client(&self, session_id: &str, ip_address: &str, user_agent: &str) {
+        self.clients.write().unwrap().insert(
+            session_id.to_string(),
+            (ip_address.to_string(), user_agent.to_string()),
+        );
+    }
     
     fn insert_session(&self, session_id: &str, user_id: &str, refresh_token: &str) {
         // Hash the token to store it (matches RefreshSession use case behavior)
@@ -73,14 +91,30 @@ impl SessionRepository for MockSessionRepo {
     fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<SessionType>> {
         Box::pin(async move { None })
     }
+
+    fn find_summary_by_id(&self, session_id: &str) -> BoxFuture<'_, Option<SessionSummary>> {
+        let result = self.clients.read().unwrap().get(session_id).map(|(ip_address, user_agent)| SessionSummary {
+            session_id: session_id.to_string(),
+            created_at: chrono::Utc::now(),
+            expires_at: chrono::Utc::now() + chrono::Duration::days(7),
+            ip_address: ip_address.clone(),
+            user_agent: user_agent.clone(),
+        });
+        Box::pin(async move { result })
+    }
     
+    fn expires_at(&self, session_id: &str) -> BoxFuture<'_, Option<chrono::DateTime<chrono::Utc>>> {
+        let result = self.expirations.read().unwrap().get(session_id).copied();
+        Box::pin(async move { result })
+    }
+
     fn revoke_session(&self, session_id: &str) -> BoxFuture<'_, ()> {
         self.revoked_sessions.write().unwrap().insert(session_id.to_string());
         Box::pin(async move {})
     }
     
-    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, ()> {
-        Box::pin(async move {})
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
     }
     
     fn delete_expired(&self) -> BoxFuture<'_, ()> {
@@ -109,48 +143,97 @@ impl MockTokenService {
 }
 
 impl TokenService for MockTokenService {
-    fn issue_access_token(&self, subject: &str, _claims: &str) -> Token {
+    fn issue_access_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
         *self.issued_access_tokens.write().unwrap() += 1;
         let token = Token::new(&format!("access_token_for_{}", subject));
         self.valid_tokens.write().unwrap().insert(token.value().to_string());
-        token
+        Box::pin(async move { token })
     }
     
-    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> Token {
-        *self.issued_refresh_tokens.write().unwrap() += 1;
-        let token = Token::new(&format!("refresh_token_for_{}", subject));
+    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let mut issued = self.issued_refresh_tokens.write().unwrap();
+        *issued += 1;
+        let token = Token::new(format!("refresh_token_for_{}_{}", subject, *issued));
         self.valid_tokens.write().unwrap().insert(token.value().to_string());
-        token
+        Box::pin(async move { token })
     }
 
-    fn issue_service_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(&format!("service_token_for_{}", subject))
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
     }
     
-    fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
-        if self.valid_tokens.read().unwrap().contains(token.value()) {
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if self.valid_tokens.read().unwrap().contains(token.value()) {
             // Return claims with proper format including sub field
             Ok(r#"{"sub":"user123","type":"access"}"#.to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
     
-    fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
-        if self.valid_tokens.read().unwrap().contains(token.value()) {
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if self.valid_tokens.read().unwrap().contains(token.value()) {
             // Return claims with proper format including sub and sid fields
             Ok(r#"{"sub":"user123","type":"refresh","sid":"session_123"}"#.to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 
-    fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().contains("service_token_for_") {
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().contains("service_token_for_") {
             Ok(r#"{"sub":"service123","type":"service"}"#.to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
+    }
+}
+
+/// Token service that accepts a refresh token but fails to encode new ones
+struct EmptyIssuingTokenService;
+
+impl TokenService for EmptyIssuingTokenService {
+    fn issue_access_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn issue_service_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Ok(r#"{"sub":"user123","type":"refresh","sid":"session_123"}"#.to_string()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+const CLIENT_IP: &str = "203.0.113.7";
+const CLIENT_UA: &str = "Mozilla/5.0 (X11; Linux x86_64)";
+
+fn refresh_input(refresh_token: Token) -> RefreshSessionInput {
+    refresh_input_from(refresh_token, CLIENT_IP, CLIENT_UA)
+}
+
+fn refresh_input_from(refresh_token: Token, ip_address: &str, user_agent: &str) -> RefreshSessionInput {
+    RefreshSessionInput {
+        refresh_token,
+        ip_address: ip_address.to_string(),
+        user_agent: user_agent.to_string(),
     }
 }
 
@@ -170,13 +253,12 @@ async fn test_refresh_session_success() {
     let use_case = RefreshSession::new(
         &session_repo,
         &token_service,
+        &SystemClock,
         3600,
         true,  // Enable rotation
     );
     
-    let input = RefreshSessionInput {
-        refresh_token: Token::new("valid_refresh_token"),
-    };
+    let input = refresh_input(Token::new("valid_refresh_token"));
     
     let result = use_case.execute(input).await;
     assert!(result.is_ok(), "Refresh should succeed with valid token");
@@ -203,13 +285,12 @@ async fn test_refresh_session_invalid_token() {
     let use_case = RefreshSession::new(
         &session_repo,
         &token_service,
+        &SystemClock,
         3600,
         true,
     );
     
-    let input = RefreshSessionInput {
-        refresh_token: Token::new("invalid_refresh_token"),
-    };
+    let input = refresh_input(Token::new("invalid_refresh_token"));
     
     let result = use_case.execute(input).await;
     assert!(result.is_err(), "Refresh should fail with invalid token");
@@ -227,13 +308,12 @@ async fn test_refresh_session_rotation() {
     let use_case = RefreshSession::new(
         &session_repo,
         &token_service,
+        &SystemClock,
         3600,
         true,  // Enable rotation
     );
     
-    let input = RefreshSessionInput {
-        refresh_token: Token::new("valid_refresh_token"),
-    };
+    let input = refresh_input(Token::new("valid_refresh_token"));
     
     let result = use_case.execute(input).await;
     assert!(result.is_ok());
@@ -260,13 +340,12 @@ async fn test_refresh_session_no_rotation() {
     let use_case = RefreshSession::new(
         &session_repo,
         &token_service,
+        &SystemClock,
         3600,
         false,  // Disable rotation
     );
     
-    let input = RefreshSessionInput {
-        refresh_token: Token::new("valid_refresh_token"),
-    };
+    let input = refresh_input(Token::new("valid_refresh_token"));
     
     let result = use_case.execute(input).await;
     assert!(result.is_ok());
@@ -293,13 +372,12 @@ async fn test_refresh_session_revoked_session() {
     let use_case = RefreshSession::new(
         &session_repo,
         &token_service,
+        &SystemClock,
         3600,
         true,
     );
     
-    let input = RefreshSessionInput {
-        refresh_token: Token::new("revoked_refresh_token"),
-    };
+    let input = refresh_input(Token::new("revoked_refresh_token"));
     
     let result = use_case.execute(input).await;
     assert!(result.is_err(), "Refresh should fail for revoked session");
@@ -320,13 +398,12 @@ async fn test_refresh_session_token_expiration_config() {
         let use_case = RefreshSession::new(
             &session_repo,
             &token_service,
+            &SystemClock,
             ttl,
             false,
         );
         
-        let input = RefreshSessionInput {
-            refresh_token: Token::new("valid_refresh_token"),
-        };
+        let input = refresh_input(Token::new("valid_refresh_token"));
         
         let result = use_case.execute(input).await;
         assert!(result.is_ok());
@@ -335,3 +412,762 @@ async fn test_refresh_session_token_expiration_config() {
         assert_eq!(output.expires_in, ttl, "TTL should match configured value");
     }
 }
+
+#[tokio::test]
+async fn test_refresh_session_rejects_empty_token() {
+    let session_repo = MockSessionRepo::new();
+    let token_service = EmptyIssuingTokenService;
+
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, true);
+
+    let input = refresh_input(Token::new("valid_refresh_token"));
+
+    let result = use_case.execute(input).await;
+    assert!(
+        matches!(result, Err(CoreError::Invariant(InvariantError::InconsistentState { .. }))),
+        "Empty token should be rejected as an invariant violation"
+    );
+}
+
+#[tokio::test]
+async fn test_refresh_session_rejects_expired_session_with_valid_token() {
+    let session_repo = MockSessionRepo::new();
+    let token_service = MockTokenService::new();
+
+    // The token still validates, but its session row has expired
+    token_service.add_valid_token("valid_refresh_token");
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+    session_repo.set_expires_at("session_123", chrono::Utc::now() - chrono::Duration::minutes(1));
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, true);
+
+    let result = use_case.execute(refresh_input(Token::new("valid_refresh_token"))).await;
+    assert!(
+        matches!(result, Err(CoreError::Token(TokenError::Expired { .. }))),
+        "Refresh should fail once the session row has expired"
+    );
+    assert_eq!(*token_service.issued_access_tokens.read().unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_refresh_session_within_session_window_succeeds() {
+    let session_repo = MockSessionRepo::new();
+    let token_service = MockTokenService::new();
+
+    token_service.add_valid_token("valid_refresh_token");
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+    session_repo.set_expires_at("session_123", chrono::Utc::now() + chrono::Duration::days(1));
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, true);
+
+    let result = use_case.execute(refresh_input(Token::new("valid_refresh_token"))).await;
+    assert!(result.is_ok(), "Refresh should succeed while the session is live");
+}
+
+// ============================================================================
+// Refresh token reuse detection
+// ============================================================================
+
+struct RotatingSession {
+    user_id: String,
+    current_hash: String,
+    previous_hash: Option<String>,
+    successor: Option<(String, chrono::DateTime<chrono::Utc>)>,
+    rotated_hashes: Vec<String>,
+    revoked: bool,
+}
+
+/// Session store that keeps rotation history like the SQL adapter does.
+struct RotatingSessionRepo {
+    sessions: std::sync::Mutex<std::collections::HashMap<String, RotatingSession>>,
+    /// Simulates another request rotating the token between lookup and swap
+    lose_rotation_race: bool,
+}
+
+impl RotatingSessionRepo {
+    fn new() -> Self {
+        Self {
+            sessions: std::sync::Mutex::new(std::collections::HashMap::new()),
+            lose_rotation_race: false,
+        }
+    }
+
+    fn losing_rotation_race() -> Self {
+        Self {
+            lose_rotation_race: true,
+            ..Self::new()
+        }
+    }
+
+    fn insert_session(&self, session_id: &str, user_id: &str, refresh_token: &str) {
+        self.sessions.lock().unwrap().insert(
+            session_id.to_string(),
+            RotatingSession {
+                user_id: user_id.to_string(),
+                current_hash: MockSessionRepo::hash_token(refresh_token),
+                previous_hash: None,
+                successor: None,
+                rotated_hashes: Vec::new(),
+                revoked: false,
+            },
+        );
+    }
+
+    fn is_revoked(&self, session_id: &str) -> bool {
+        self.sessions.lock().unwrap()[session_id].revoked
+    }
+}
+
+impl SessionRepository for RotatingSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &crate::core::identity::UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, hash: &str) -> BoxFuture<'_, Option<SessionType>> {
+        let found = self
+            .sessions
+            .lock()
+            .unwrap()
+            .values()
+            .any(|session| session.current_hash == hash && !session.revoked);
+        Box::pin(async move { found.then_some(SessionType {}) })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<SessionType>> {
+        Box::pin(async move { None })
+    }
+
+    fn rotate_refresh_token(&self, session_id: &str, current_hash: &str, new_hash: &str) -> BoxFuture<'_, bool> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let rotated = match sessions.get_mut(session_id) {
+            Some(session) if !self.lose_rotation_race && !session.revoked && session.current_hash == current_hash => {
+                let previous = std::mem::replace(&mut session.current_hash, new_hash.to_string());
+                session.rotated_hashes.push(previous.clone());
+                session.previous_hash = Some(previous);
+                session.successor = None;
+                true
+            }
+            _ => false,
+        };
+        Box::pin(async move { rotated })
+    }
+
+    fn is_rotated_refresh_token(&self, hash: &str) -> BoxFuture<'_, bool> {
+        let rotated = self
+            .sessions
+            .lock()
+            .unwrap()
+            .values()
+            .any(|session| session.rotated_hashes.iter().any(|rotated| rotated == hash));
+        Box::pin(async move { rotated })
+    }
+
+    fn record_rotation_successor(&self, previous_hash: &str, successor_hash: &str, until: chrono::DateTime<chrono::Utc>) -> BoxFuture<'_, ()> {
+        for session in self.sessions.lock().unwrap().values_mut() {
+            if session.previous_hash.as_deref() == Some(previous_hash) {
+                session.successor = Some((successor_hash.to_string(), until));
+            }
+        }
+        Box::pin(async move {})
+    }
+
+    fn rotation_successor(&self, previous_hash: &str, now: chrono::DateTime<chrono::Utc>) -> BoxFuture<'_, Option<String>> {
+        let successor = self
+            .sessions
+            .lock()
+            .unwrap()
+            .values()
+            .find(|session| session.previous_hash.as_deref() == Some(previous_hash))
+            .and_then(|session| session.successor.clone())
+            .filter(|(_, until)| now < *until)
+            .map(|(successor_hash, _)| successor_hash);
+        Box::pin(async move { successor })
+    }
+
+    fn reissue_rotation_successor(&self, previous_hash: &str, new_hash: &str, now: chrono::DateTime<chrono::Utc>) -> BoxFuture<'_, bool> {
+        let mut reissued = false;
+        for session in self.sessions.lock().unwrap().values_mut() {
+            if session.revoked || session.previous_hash.as_deref() != Some(previous_hash) {
+                continue;
+            }
+            if let Some((successor_hash, until)) = session.successor.as_ref()
+                && now < *until
+                && *successor_hash == session.current_hash
+            {
+                let displaced = std::mem::replace(&mut session.current_hash, new_hash.to_string());
+                session.rotated_hashes.push(displaced);
+                session.successor = None;
+                reissued = true;
+            }
+        }
+        Box::pin(async move { reissued })
+    }
+
+    fn revoke_session(&self, session_id: &str) -> BoxFuture<'_, ()> {
+        if let Some(session) = self.sessions.lock().unwrap().get_mut(session_id) {
+            session.revoked = true;
+        }
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, user_id: &str) -> BoxFuture<'_, u64> {
+        let mut revoked = 0;
+        for session in self.sessions.lock().unwrap().values_mut() {
+            if session.user_id == user_id && !session.revoked {
+                session.revoked = true;
+                revoked += 1;
+            }
+        }
+        Box::pin(async move { revoked })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+fn is_reuse_error(result: &Result<crate::core::usecases::refresh_session::RefreshSessionOutput, CoreError>) -> bool {
+    matches!(
+        result,
+        Err(CoreError::Authentication(crate::core::error::AuthenticationError::CredentialExpired { .. }))
+    )
+}
+
+#[tokio::test]
+async fn test_refresh_session_rotated_token_chain_stays_valid() {
+    let session_repo = RotatingSessionRepo::new();
+    let token_service = MockTokenService::new();
+
+    token_service.add_valid_token("valid_refresh_token");
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, true);
+
+    let first = use_case
+        .execute(refresh_input(Token::new("valid_refresh_token")))
+        .await
+        .expect("first refresh should succeed");
+    let rotated = first.refresh_token.expect("rotation should hand out a new refresh token");
+
+    // The freshly issued token is the one that works next
+    let second = use_case
+        .execute(refresh_input(rotated))
+        .await;
+    assert!(second.is_ok(), "rotated token should be accepted once");
+    assert!(!session_repo.is_revoked("session_123"));
+}
+
+#[tokio::test]
+async fn test_refresh_session_reused_token_revokes_session_family() {
+    let session_repo = RotatingSessionRepo::new();
+    let token_service = MockTokenService::new();
+
+    token_service.add_valid_token("stolen_refresh_token");
+    session_repo.insert_session("session_123", "user123", "stolen_refresh_token");
+    session_repo.insert_session("session_456", "user123", "other_device_token");
+    session_repo.insert_session("session_789", "someone_else", "unrelated_token");
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, true);
+
+    // Legitimate client rotates first
+    let legit = use_case
+        .execute(refresh_input(Token::new("stolen_refresh_token")))
+        .await;
+    assert!(legit.is_ok());
+
+    // Attacker replays the consumed token
+    let replay = use_case
+        .execute(refresh_input(Token::new("stolen_refresh_token")))
+        .await;
+    assert!(is_reuse_error(&replay), "replayed token should be rejected as reuse");
+
+    // Every session of the user is gone, other users are untouched
+    assert!(session_repo.is_revoked("session_123"));
+    assert!(session_repo.is_revoked("session_456"));
+    assert!(!session_repo.is_revoked("session_789"));
+
+    // Including the one the legitimate client just received
+    let after = use_case
+        .execute(refresh_input(legit.unwrap().refresh_token.unwrap()))
+        .await;
+    assert!(after.is_err());
+}
+
+#[tokio::test]
+async fn test_refresh_session_token_replayed_two_rotations_later_revokes_session_family() {
+    let session_repo = RotatingSessionRepo::new();
+    let token_service = MockTokenService::new();
+
+    token_service.add_valid_token("stolen_refresh_token");
+    session_repo.insert_session("session_123", "user123", "stolen_refresh_token");
+    session_repo.insert_session("session_456", "user123", "other_device_token");
+
+    let use_case =
+        RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, true).with_rotation_grace(30);
+
+    // The legitimate client keeps rotating
+    let first = use_case
+        .execute(refresh_input(Token::new("stolen_refresh_token")))
+        .await
+        .expect("first refresh should succeed");
+    let second = use_case
+        .execute(refresh_input(first.refresh_token.unwrap()))
+        .await
+        .expect("second refresh should succeed");
+
+    // The attacker replays a token that is no longer the previous one
+    let replay = use_case
+        .execute(refresh_input(Token::new("stolen_refresh_token")))
+        .await;
+    assert!(is_reuse_error(&replay), "a token two rotations old should be rejected as reuse");
+
+    assert!(session_repo.is_revoked("session_123"));
+    assert!(session_repo.is_revoked("session_456"));
+    assert!(use_case.execute(refresh_input(second.refresh_token.unwrap())).await.is_err());
+}
+
+#[tokio::test]
+async fn test_refresh_session_concurrent_use_of_same_token_is_treated_as_reuse() {
+    let session_repo = RotatingSessionRepo::new();
+    let token_service = MockTokenService::new();
+
+    token_service.add_valid_token("valid_refresh_token");
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, true);
+
+    let (a, b) = tokio::join!(
+        use_case.execute(refresh_input(Token::new("valid_refresh_token"))),
+        use_case.execute(refresh_input(Token::new("valid_refresh_token"))),
+    );
+
+    // Exactly one request rotates; the other looks like a replay and signs the user out
+    assert_eq!([a.is_ok(), b.is_ok()].iter().filter(|ok| **ok).count(), 1);
+    assert!(is_reuse_error(&a) || is_reuse_error(&b));
+    assert!(session_repo.is_revoked("session_123"));
+}
+
+#[tokio::test]
+async fn test_refresh_session_lost_rotation_race_revokes_session_family() {
+    let session_repo = RotatingSessionRepo::losing_rotation_race();
+    let token_service = MockTokenService::new();
+
+    token_service.add_valid_token("valid_refresh_token");
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, true);
+
+    let result = use_case
+        .execute(refresh_input(Token::new("valid_refresh_token")))
+        .await;
+
+    assert!(is_reuse_error(&result));
+    assert!(session_repo.is_revoked("session_123"));
+}
+
+#[tokio::test]
+async fn test_refresh_session_retry_within_grace_replaces_lost_successor() {
+    let session_repo = RotatingSessionRepo::new();
+    let token_service = MockTokenService::new();
+
+    token_service.add_valid_token("valid_refresh_token");
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, true).with_rotation_grace(30);
+
+    let first = use_case
+        .execute(refresh_input(Token::new("valid_refresh_token")))
+        .await
+        .expect("first refresh should succeed");
+    let lost = first.refresh_token.expect("rotation should hand out a new refresh token");
+
+    // The client never saw the response and retries with the old token
+    let retry = use_case
+        .execute(refresh_input(Token::new("valid_refresh_token")))
+        .await
+        .expect("retry within the grace window should succeed");
+    let reissued = retry.refresh_token.expect("the retry should hand out a new refresh token");
+
+    assert_ne!(reissued.value(), lost.value(), "only the successor's hash is kept");
+    assert!(!session_repo.is_revoked("session_123"));
+
+    // The successor handed out last is the live token
+    assert!(use_case.execute(refresh_input(reissued)).await.is_ok());
+    assert!(!session_repo.is_revoked("session_123"));
+}
+
+#[tokio::test]
+async fn test_refresh_session_second_retry_within_grace_revokes_session_family() {
+    let session_repo = RotatingSessionRepo::new();
+    let token_service = MockTokenService::new();
+
+    token_service.add_valid_token("valid_refresh_token");
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+
+    let use_case =
+        RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, true).with_rotation_grace(30);
+
+    use_case
+        .execute(refresh_input(Token::new("valid_refresh_token")))
+        .await
+        .expect("first refresh should succeed");
+    use_case
+        .execute(refresh_input(Token::new("valid_refresh_token")))
+        .await
+        .expect("retry within the grace window should succeed");
+
+    // A rotation is reissued at most once
+    let again = use_case
+        .execute(refresh_input(Token::new("valid_refresh_token")))
+        .await;
+
+    assert!(is_reuse_error(&again));
+    assert!(session_repo.is_revoked("session_123"));
+}
+
+#[tokio::test]
+async fn test_refresh_session_displaced_successor_is_treated_as_reuse() {
+    let session_repo = RotatingSessionRepo::new();
+    let token_service = MockTokenService::new();
+
+    token_service.add_valid_token("valid_refresh_token");
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+    session_repo.insert_session("session_456", "user123", "other_device_token");
+
+    let use_case =
+        RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, true).with_rotation_grace(30);
+
+    let first = use_case
+        .execute(refresh_input(Token::new("valid_refresh_token")))
+        .await
+        .expect("first refresh should succeed");
+    let displaced = first.refresh_token.expect("rotation should hand out a new refresh token");
+    use_case
+        .execute(refresh_input(Token::new("valid_refresh_token")))
+        .await
+        .expect("retry within the grace window should succeed");
+
+    // Whoever holds the successor the retry replaced is not the live client
+    let replay = use_case.execute(refresh_input(displaced)).await;
+
+    assert!(is_reuse_error(&replay));
+    assert!(session_repo.is_revoked("session_123"));
+    assert!(session_repo.is_revoked("session_456"));
+}
+
+#[tokio::test]
+async fn test_refresh_session_retry_after_grace_revokes_session_family() {
+    let session_repo = RotatingSessionRepo::new();
+    let token_service = MockTokenService::new();
+
+    token_service.add_valid_token("valid_refresh_token");
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+
+    let clock = FixedClock::new(chrono::Utc::now());
+    let use_case = RefreshSession::new(&session_repo, &token_service, &clock, 3600, true).with_rotation_grace(30);
+
+    use_case
+        .execute(refresh_input(Token::new("valid_refresh_token")))
+        .await
+        .expect("first refresh should succeed");
+    clock.advance(chrono::Duration::seconds(31));
+
+    let replay = use_case
+        .execute(refresh_input(Token::new("valid_refresh_token")))
+        .await;
+
+    assert!(is_reuse_error(&replay));
+    assert!(session_repo.is_revoked("session_123"));
+}
+
+// ============================================================================
+// Claims re-fetch
+// ============================================================================
+
+/// Identity store whose claims for `user123` can change between refreshes
+struct RolesIdentityRepo {
+    roles: std::sync::RwLock<Option<Vec<String>>>, // None: the user was deleted
+}
+
+impl RolesIdentityRepo {
+    fn with_roles(roles: &[&str]) -> Self {
+        Self {
+            roles: std::sync::RwLock::new(Some(roles.iter().map(|r| r.to_string()).collect())),
+        }
+    }
+
+    fn set_roles(&self, roles: &[&str]) {
+        *self.roles.write().unwrap() = Some(roles.iter().map(|r| r.to_string()).collect());
+    }
+}
+
+impl crate::core::usecases::ports::IdentityRepository for RolesIdentityRepo {
+    fn find_by_identifier(&self, _identifier: &str) -> BoxFuture<'_, Option<crate::core::identity::UserIdentity>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, id: &str) -> BoxFuture<'_, Option<crate::core::identity::UserIdentity>> {
+        let result = self.roles.read().unwrap().as_ref().map(|_| crate::core::identity::UserIdentity::new(id));
+        Box::pin(async move { result })
+    }
+
+    fn find_claims_by_id(&self, _id: &str) -> BoxFuture<'_, Option<serde_json::Map<String, serde_json::Value>>> {
+        let result = self.roles.read().unwrap().clone().map(|roles| {
+            let mut claims = serde_json::Map::new();
+            claims.insert("roles".to_string(), serde_json::json!(roles));
+            // Must not override the session-bound claims
+            claims.insert("sid".to_string(), serde_json::json!("forged_session"));
+            claims
+        });
+        Box::pin(async move { result })
+    }
+
+    fn create(
+        &self,
+        _user_id: &uuid::Uuid,
+        _identifier: &str,
+        _password_hash: &str,
+        _salt: &str,
+        _algorithm: &str,
+        _iterations: u32,
+    ) -> BoxFuture<'_, Result<(), String>> {
+        Box::pin(async move { Ok(()) })
+    }
+}
+
+/// Token service whose access tokens are the claims they were issued with
+struct ClaimsEchoTokenService;
+
+impl TokenService for ClaimsEchoTokenService {
+    fn issue_access_token(&self, _subject: &str, claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(claims);
+        Box::pin(async move { token })
+    }
+
+    fn issue_refresh_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("unused_refresh_token") })
+    }
+
+    fn issue_service_token(&self, _subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        Box::pin(async move { Token::new("") })
+    }
+
+    fn validate_access_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Ok(r#"{"sub":"user123","type":"refresh","sid":"session_123","roles":["viewer"]}"#.to_string()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+fn access_claims(output: &crate::core::usecases::refresh_session::RefreshSessionOutput) -> serde_json::Value {
+    serde_json::from_str(output.access_token.value()).expect("access token carries its claims")
+}
+
+#[tokio::test]
+async fn test_refresh_with_claims_refetch_reflects_role_change() {
+    let session_repo = MockSessionRepo::new();
+    session_repo.insert_session("session_123", "user123", "refresh_token");
+    let identity_repo = RolesIdentityRepo::with_roles(&["viewer"]);
+
+    let use_case = RefreshSession::new(&session_repo, &ClaimsEchoTokenService, &SystemClock, 3600, false)
+        .with_claims_refetch(&identity_repo);
+
+    let before = use_case
+        .execute(refresh_input(Token::new("refresh_token")))
+        .await
+        .expect("refresh should succeed");
+    assert_eq!(access_claims(&before)["roles"], serde_json::json!(["viewer"]));
+
+    identity_repo.set_roles(&["viewer", "admin"]);
+
+    let after = use_case
+        .execute(refresh_input(Token::new("refresh_token")))
+        .await
+        .expect("refresh should succeed");
+    let claims = access_claims(&after);
+    assert_eq!(claims["roles"], serde_json::json!(["viewer", "admin"]));
+    assert_eq!(claims["sub"], "user123");
+    assert_eq!(claims["sid"], "session_123");
+    assert_eq!(claims["type"], "access");
+}
+
+#[tokio::test]
+async fn test_refresh_with_claims_refetch_signs_roles_into_hmac_access_token() {
+    use crate::adapters::crypto::token::HmacTokenService;
+
+    let token_service = HmacTokenService::from_secret_key(&[7u8; 32]).expect("valid key");
+    let refresh_token = token_service.issue_refresh_token(
+        "user123",
+        r#"{"sub":"user123","type":"refresh","sid":"session_123"}"#,
+    );
+    let session_repo = MockSessionRepo::new();
+    session_repo.insert_session("session_123", "user123", refresh_token.value());
+    let identity_repo = RolesIdentityRepo::with_roles(&["viewer", "admin"]);
+
+    let output = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, false)
+        .with_claims_refetch(&identity_repo)
+        .execute(refresh_input(refresh_token))
+        .await
+        .expect("refresh should succeed");
+
+    let claims: serde_json::Value = serde_json::from_str(
+        &token_service
+            .validate_access_token(&output.access_token)
+            .expect("access token should validate"),
+    )
+    .unwrap();
+    assert_eq!(claims["roles"], serde_json::json!(["viewer", "admin"]));
+    assert_eq!(claims["sub"], "user123");
+    assert_eq!(claims["sid"], "session_123");
+    assert_eq!(claims["type"], "access");
+}
+
+#[tokio::test]
+async fn test_refresh_without_claims_refetch_copies_refresh_token_claims() {
+    let session_repo = MockSessionRepo::new();
+    session_repo.insert_session("session_123", "user123", "refresh_token");
+
+    let use_case = RefreshSession::new(&session_repo, &ClaimsEchoTokenService, &SystemClock, 3600, false);
+
+    let output = use_case
+        .execute(refresh_input(Token::new("refresh_token")))
+        .await
+        .expect("refresh should succeed");
+    let claims = access_claims(&output);
+    assert_eq!(claims["sub"], "user123");
+    assert_eq!(claims["type"], "access");
+    assert_eq!(claims["roles"], serde_json::json!(["viewer"]));
+}
+
+#[tokio::test]
+async fn test_refresh_with_claims_refetch_rejects_deleted_user() {
+    let session_repo = MockSessionRepo::new();
+    session_repo.insert_session("session_123", "user123", "refresh_token");
+    let identity_repo = RolesIdentityRepo { roles: std::sync::RwLock::new(None) };
+
+    let use_case = RefreshSession::new(&session_repo, &ClaimsEchoTokenService, &SystemClock, 3600, false)
+        .with_claims_refetch(&identity_repo);
+
+    let result = use_case
+        .execute(refresh_input(Token::new("refresh_token")))
+        .await;
+
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Session binding
+// ============================================================================
+
+/// Session issued to `CLIENT_IP` / `CLIENT_UA` with a valid refresh token
+fn bound_session() -> (MockSessionRepo, MockTokenService) {
+    let session_repo = MockSessionRepo::new();
+    let token_service = MockTokenService::new();
+    token_service.add_valid_token("valid_refresh_token");
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+    session_repo.set_client("session_123", CLIENT_IP, CLIENT_UA);
+    (session_repo, token_service)
+}
+
+fn strict_binding() -> SessionBindingPolicy {
+    SessionBindingPolicy::new(IpBinding::Exact, true)
+}
+
+#[tokio::test]
+async fn test_refresh_with_binding_accepts_matching_client() {
+    let (session_repo, token_service) = bound_session();
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, false)
+        .with_binding(strict_binding());
+
+    let result = use_case.execute(refresh_input(Token::new("valid_refresh_token"))).await;
+
+    assert!(result.is_ok(), "same client should be able to refresh");
+}
+
+#[tokio::test]
+async fn test_refresh_with_binding_rejects_different_user_agent() {
+    let (session_repo, token_service) = bound_session();
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, false)
+        .with_binding(SessionBindingPolicy::new(IpBinding::Off, true));
+
+    let result = use_case
+        .execute(refresh_input_from(Token::new("valid_refresh_token"), CLIENT_IP, "curl/8.5.0"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(CoreError::Authentication(crate::core::error::AuthenticationError::InvalidCredentials))
+    ));
+    assert_eq!(*token_service.issued_access_tokens.read().unwrap(), 0);
+    assert!(!session_repo._is_revoked("session_123"), "a mismatch must not revoke the session");
+}
+
+#[tokio::test]
+async fn test_refresh_with_binding_rejects_different_ip() {
+    let (session_repo, token_service) = bound_session();
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, false)
+        .with_binding(strict_binding());
+
+    let result = use_case
+        .execute(refresh_input_from(Token::new("valid_refresh_token"), "198.51.100.20", CLIENT_UA))
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_refresh_with_subnet_binding_accepts_ip_in_same_network() {
+    let (session_repo, token_service) = bound_session();
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, false)
+        .with_binding(SessionBindingPolicy::new(IpBinding::subnet(), true));
+
+    let same_network = use_case
+        .execute(refresh_input_from(Token::new("valid_refresh_token"), "203.0.113.201", CLIENT_UA))
+        .await;
+    let other_network = use_case
+        .execute(refresh_input_from(Token::new("valid_refresh_token"), "203.0.114.7", CLIENT_UA))
+        .await;
+
+    assert!(same_network.is_ok(), "an address in the same /24 should be accepted");
+    assert!(other_network.is_err(), "an address outside the /24 should be rejected");
+}
+
+#[tokio::test]
+async fn test_refresh_without_binding_accepts_different_client() {
+    let (session_repo, token_service) = bound_session();
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, false);
+
+    let result = use_case
+        .execute(refresh_input_from(Token::new("valid_refresh_token"), "198.51.100.20", "curl/8.5.0"))
+        .await;
+
+    assert!(result.is_ok(), "binding is off by default");
+}
+
+#[tokio::test]
+async fn test_refresh_with_binding_rejects_session_without_recorded_client() {
+    let session_repo = MockSessionRepo::new();
+    let token_service = MockTokenService::new();
+    token_service.add_valid_token("valid_refresh_token");
+    session_repo.insert_session("session_123", "user123", "valid_refresh_token");
+
+    let use_case = RefreshSession::new(&session_repo, &token_service, &SystemClock, 3600, false)
+        .with_binding(strict_binding());
+
+    let result = use_case.execute(refresh_input(Token::new("valid_refresh_token"))).await;
+
+    assert!(result.is_err(), "binding fails closed when the stored client is unknown");
+}
@@ -0,0 +1,123 @@
+//! Tests for RegisterPasskey use case.
+
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use super::super::register_passkey::{RegisterPasskey, RegisterPasskeyInput};
+use crate::core::error::CoreError;
+use crate::core::usecases::ports::{
+    AttestedPasskey, PasskeyCredential, PasskeyRepository, VerifiedAssertion, WebAuthnVerifier,
+};
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+const CHALLENGE: &str = "c2VydmVyLWNoYWxsZW5nZQ";
+
+/// Stand-in for the WebAuthn ceremony; a response is `credential_id|challenge`.
+struct MockWebAuthnVerifier;
+
+impl WebAuthnVerifier for MockWebAuthnVerifier {
+    fn verify_registration(&self, response: &str) -> Result<AttestedPasskey, String> {
+        let (credential_id, challenge) = response.split_once('|').ok_or("malformed response")?;
+        Ok(AttestedPasskey {
+            credential_id: credential_id.to_string(),
+            public_key: b"key-1".to_vec(),
+            sign_count: 0,
+            challenge: challenge.to_string(),
+        })
+    }
+
+    fn assertion_credential_id(&self, _response: &str) -> Result<String, String> {
+        Err("not an assertion".to_string())
+    }
+
+    fn verify_assertion(&self, _response: &str, _public_key: &[u8]) -> Result<VerifiedAssertion, String> {
+        Err("not an assertion".to_string())
+    }
+}
+
+#[derive(Default)]
+struct MockPasskeyRepo {
+    credentials: RwLock<HashMap<String, PasskeyCredential>>,
+}
+
+impl MockPasskeyRepo {
+    fn get(&self, credential_id: &str) -> Option<PasskeyCredential> {
+        self.credentials.read().unwrap().get(credential_id).cloned()
+    }
+}
+
+impl PasskeyRepository for MockPasskeyRepo {
+    fn find_by_credential_id(&self, credential_id: &str) -> BoxFuture<'_, Option<PasskeyCredential>> {
+        let result = self.get(credential_id);
+        Box::pin(async move { result })
+    }
+
+    fn create(&self, credential: &PasskeyCredential) -> BoxFuture<'_, Result<(), String>> {
+        self.credentials
+            .write()
+            .unwrap()
+            .insert(credential.credential_id.clone(), credential.clone());
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn update_sign_count(&self, _credential_id: &str, _expected: u32, _sign_count: u32) -> BoxFuture<'_, Result<bool, String>> {
+        Box::pin(async move { Ok(false) })
+    }
+}
+
+fn input(user_id: &str, response: &str) -> RegisterPasskeyInput {
+    RegisterPasskeyInput {
+        user_id: user_id.to_string(),
+        response: response.to_string(),
+        challenge: CHALLENGE.to_string(),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_registration_stores_public_key_and_counter() {
+    let repo = MockPasskeyRepo::default();
+    let use_case = RegisterPasskey::new(&repo, &MockWebAuthnVerifier);
+
+    let output = use_case
+        .execute(input("user123", &format!("cred-1|{}", CHALLENGE)))
+        .await
+        .unwrap();
+
+    assert_eq!(output.credential_id, "cred-1");
+    let stored = repo.get("cred-1").expect("Passkey should be stored");
+    assert_eq!(stored.user_id, "user123");
+    assert_eq!(stored.public_key, b"key-1".to_vec());
+    assert_eq!(stored.sign_count, 0);
+}
+
+#[tokio::test]
+async fn test_registration_for_other_challenge_is_rejected() {
+    let repo = MockPasskeyRepo::default();
+    let use_case = RegisterPasskey::new(&repo, &MockWebAuthnVerifier);
+
+    let result = use_case.execute(input("user123", "cred-1|b2xkLWNoYWxsZW5nZQ")).await;
+
+    assert!(matches!(result, Err(CoreError::Credential(_))));
+    assert!(repo.get("cred-1").is_none());
+}
+
+#[tokio::test]
+async fn test_already_registered_credential_is_rejected() {
+    let repo = MockPasskeyRepo::default();
+    let use_case = RegisterPasskey::new(&repo, &MockWebAuthnVerifier);
+    let response = format!("cred-1|{}", CHALLENGE);
+
+    use_case.execute(input("user123", &response)).await.unwrap();
+    let result = use_case.execute(input("user456", &response)).await;
+
+    assert!(matches!(result, Err(CoreError::Credential(_))));
+    assert_eq!(repo.get("cred-1").unwrap().user_id, "user123");
+}
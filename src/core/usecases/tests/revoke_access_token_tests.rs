@@ -0,0 +1,136 @@
+//! Tests for RevokeAccessToken use case.
+
+use std::sync::Mutex;
+
+use futures::future::BoxFuture;
+
+use super::super::revoke_access_token::{RevokeAccessToken, RevokeAccessTokenInput, RevokeAccessTokenOutput};
+use crate::core::error::{CoreError, TokenError};
+use crate::core::token::Token;
+use crate::core::usecases::ports::{TokenDenyList, TokenService};
+
+const EXPIRES_AT: i64 = 9_999_999_999;
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+/// Token service with a fixed table of tokens and their claims
+struct ClaimsTokenService;
+
+impl TokenService for ClaimsTokenService {
+    fn issue_access_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("access_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("refresh_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = match token.value() {
+            "access_token" => Ok(format!(
+                r#"{{"sub":"user123","type":"access","exp":{},"jti":"jti-1"}}"#,
+                EXPIRES_AT
+            )),
+            "token_without_jti" => Ok(format!(r#"{{"sub":"user123","type":"access","exp":{}}}"#, EXPIRES_AT)),
+            "refresh_token" => Ok(format!(
+                r#"{{"sub":"user123","type":"refresh","exp":{},"jti":"jti-2"}}"#,
+                EXPIRES_AT
+            )),
+            _ => Err(()),
+        };
+        Box::pin(async move { claims })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+/// Deny-list recording every denial
+#[derive(Default)]
+struct RecordingDenyList {
+    denied: Mutex<Vec<(String, i64)>>,
+}
+
+impl TokenDenyList for RecordingDenyList {
+    fn deny(&self, jti: &str, expires_at: i64) -> BoxFuture<'_, Result<(), CoreError>> {
+        self.denied.lock().unwrap().push((jti.to_string(), expires_at));
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn is_denied(&self, jti: &str) -> BoxFuture<'_, bool> {
+        let denied = self.denied.lock().unwrap().iter().any(|(denied, _)| denied == jti);
+        Box::pin(async move { denied })
+    }
+}
+
+async fn revoke(token: &str, deny_list: &RecordingDenyList) -> Result<RevokeAccessTokenOutput, CoreError> {
+    RevokeAccessToken::new(&ClaimsTokenService, deny_list)
+        .execute(RevokeAccessTokenInput {
+            access_token: Token::new(token),
+        })
+        .await
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_revoke_denies_jti_until_token_expiry() {
+    let deny_list = RecordingDenyList::default();
+
+    let output = revoke("access_token", &deny_list).await.unwrap();
+
+    assert_eq!(
+        output,
+        RevokeAccessTokenOutput {
+            jti: "jti-1".to_string(),
+            expires_at: EXPIRES_AT,
+        }
+    );
+    assert_eq!(*deny_list.denied.lock().unwrap(), vec![("jti-1".to_string(), EXPIRES_AT)]);
+}
+
+#[tokio::test]
+async fn test_revoke_rejects_invalid_token() {
+    let deny_list = RecordingDenyList::default();
+
+    let result = revoke("forged", &deny_list).await;
+
+    assert!(matches!(result, Err(CoreError::Token(TokenError::SignatureInvalid { .. }))));
+    assert!(deny_list.denied.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_revoke_requires_jti() {
+    let deny_list = RecordingDenyList::default();
+
+    let result = revoke("token_without_jti", &deny_list).await;
+
+    assert!(matches!(result, Err(CoreError::Token(TokenError::InvalidClaims { .. }))));
+    assert!(deny_list.denied.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_revoke_rejects_other_token_types() {
+    let deny_list = RecordingDenyList::default();
+
+    let result = revoke("refresh_token", &deny_list).await;
+
+    assert!(matches!(result, Err(CoreError::Token(TokenError::InvalidClaims { .. }))));
+    assert!(deny_list.denied.lock().unwrap().is_empty());
+}
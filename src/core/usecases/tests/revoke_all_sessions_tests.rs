@@ -0,0 +1,115 @@
+//! Tests for RevokeAllSessions use case.
+
+use futures::future::BoxFuture;
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+
+use super::super::revoke_all_sessions::{RevokeAllSessions, RevokeAllSessionsInput};
+use crate::core::error::CoreError;
+use crate::core::usecases::ports::SessionRepository;
+use crate::core::usecases::ports::session_repository::Session as SessionType;
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+struct MockSessionRepo {
+    sessions: RwLock<HashMap<String, String>>, // session_id -> user_id
+    revoked_sessions: RwLock<HashSet<String>>,
+}
+
+impl MockSessionRepo {
+    fn new() -> Self {
+        Self {
+            sessions: RwLock::new(HashMap::new()),
+            revoked_sessions: RwLock::new(HashSet::new()),
+        }
+    }
+
+    fn insert_session(&self, session_id: &str, user_id: &str) {
+        self.sessions
+            .write()
+            .unwrap()
+            .insert(session_id.to_string(), user_id.to_string());
+    }
+
+    fn active_sessions(&self, user_id: &str) -> Vec<String> {
+        let revoked = self.revoked_sessions.read().unwrap();
+        let mut active: Vec<String> = self
+            .sessions
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(id, owner)| owner.as_str() == user_id && !revoked.contains(*id))
+            .map(|(id, _)| id.clone())
+            .collect();
+        active.sort();
+        active
+    }
+}
+
+impl SessionRepository for MockSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &crate::core::identity::UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<SessionType>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<SessionType>> {
+        Box::pin(async move { None })
+    }
+
+    fn revoke_session(&self, session_id: &str) -> BoxFuture<'_, ()> {
+        self.revoked_sessions.write().unwrap().insert(session_id.to_string());
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, user_id: &str) -> BoxFuture<'_, u64> {
+        let targets = self.active_sessions(user_id);
+        let count = targets.len() as u64;
+        self.revoked_sessions.write().unwrap().extend(targets);
+        Box::pin(async move { count })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_revoke_all_sessions_revokes_every_session_of_user() {
+    let session_repo = MockSessionRepo::new();
+    session_repo.insert_session("session_laptop", "user123");
+    session_repo.insert_session("session_phone", "user123");
+    session_repo.insert_session("session_other_user", "user456");
+
+    let use_case = RevokeAllSessions::new(&session_repo);
+    let output = use_case
+        .execute(RevokeAllSessionsInput { user_id: "user123".to_string() })
+        .await
+        .unwrap();
+
+    assert_eq!(output.sessions_revoked, 2);
+    assert!(session_repo.active_sessions("user123").is_empty());
+
+    // Other users are untouched
+    assert_eq!(session_repo.active_sessions("user456"), vec!["session_other_user".to_string()]);
+}
+
+#[tokio::test]
+async fn test_revoke_all_sessions_missing_user_id() {
+    let session_repo = MockSessionRepo::new();
+
+    let use_case = RevokeAllSessions::new(&session_repo);
+    let result = use_case
+        .execute(RevokeAllSessionsInput { user_id: String::new() })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Invariant(_))));
+}
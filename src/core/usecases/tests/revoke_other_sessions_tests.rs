@@ -0,0 +1,156 @@
+//! Tests for RevokeOtherSessions use case.
+
+use futures::future::BoxFuture;
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+
+use super::super::revoke_other_sessions::{RevokeOtherSessions, RevokeOtherSessionsInput};
+use crate::core::error::CoreError;
+use crate::core::usecases::ports::SessionRepository;
+use crate::core::usecases::ports::session_repository::Session as SessionType;
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+struct MockSessionRepo {
+    sessions: RwLock<HashMap<String, String>>, // session_id -> user_id
+    revoked_sessions: RwLock<HashSet<String>>,
+}
+
+impl MockSessionRepo {
+    fn new() -> Self {
+        Self {
+            sessions: RwLock::new(HashMap::new()),
+            revoked_sessions: RwLock::new(HashSet::new()),
+        }
+    }
+
+    fn insert_session(&self, session_id: &str, user_id: &str) {
+        self.sessions
+            .write()
+            .unwrap()
+            .insert(session_id.to_string(), user_id.to_string());
+    }
+
+    fn active_sessions(&self, user_id: &str) -> Vec<String> {
+        let revoked = self.revoked_sessions.read().unwrap();
+        let mut active: Vec<String> = self
+            .sessions
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(id, owner)| owner.as_str() == user_id && !revoked.contains(*id))
+            .map(|(id, _)| id.clone())
+            .collect();
+        active.sort();
+        active
+    }
+}
+
+impl SessionRepository for MockSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &crate::core::identity::UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<SessionType>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, session_id: &str) -> BoxFuture<'_, Option<SessionType>> {
+        let exists = self.sessions.read().unwrap().contains_key(session_id);
+        let revoked = self.revoked_sessions.read().unwrap().contains(session_id);
+        let result = (exists && !revoked).then_some(SessionType {});
+        Box::pin(async move { result })
+    }
+
+    fn revoke_session(&self, session_id: &str) -> BoxFuture<'_, ()> {
+        self.revoked_sessions.write().unwrap().insert(session_id.to_string());
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn revoke_all_for_user_except(&self, user_id: &str, keep_session_id: &str) -> BoxFuture<'_, u64> {
+        let targets: Vec<String> = self
+            .active_sessions(user_id)
+            .into_iter()
+            .filter(|id| id != keep_session_id)
+            .collect();
+        let count = targets.len() as u64;
+        self.revoked_sessions.write().unwrap().extend(targets);
+        Box::pin(async move { count })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+fn input(user_id: &str, current_session_id: &str) -> RevokeOtherSessionsInput {
+    RevokeOtherSessionsInput {
+        user_id: user_id.to_string(),
+        current_session_id: current_session_id.to_string(),
+    }
+}
+
+// ============================================================================
+// Test Cases
+// ============================================================================
+
+#[tokio::test]
+async fn test_revoke_other_sessions_keeps_only_current() {
+    let session_repo = MockSessionRepo::new();
+    session_repo.insert_session("session_laptop", "user123");
+    session_repo.insert_session("session_phone", "user123");
+    session_repo.insert_session("session_tablet", "user123");
+    session_repo.insert_session("session_other_user", "user456");
+
+    let use_case = RevokeOtherSessions::new(&session_repo);
+    let output = use_case.execute(input("user123", "session_phone")).await.unwrap();
+
+    assert_eq!(output.sessions_revoked, 2);
+    assert_eq!(output.current_session_id, "session_phone");
+    assert_eq!(session_repo.active_sessions("user123"), vec!["session_phone".to_string()]);
+
+    // Other users are untouched
+    assert_eq!(session_repo.active_sessions("user456"), vec!["session_other_user".to_string()]);
+}
+
+#[tokio::test]
+async fn test_revoke_other_sessions_with_single_session() {
+    let session_repo = MockSessionRepo::new();
+    session_repo.insert_session("session_only", "user123");
+
+    let use_case = RevokeOtherSessions::new(&session_repo);
+    let output = use_case.execute(input("user123", "session_only")).await.unwrap();
+
+    assert_eq!(output.sessions_revoked, 0);
+    assert_eq!(session_repo.active_sessions("user123"), vec!["session_only".to_string()]);
+}
+
+#[tokio::test]
+async fn test_revoke_other_sessions_rejects_revoked_current_session() {
+    let session_repo = MockSessionRepo::new();
+    session_repo.insert_session("session_a", "user123");
+    session_repo.insert_session("session_b", "user123");
+    session_repo.revoke_session("session_a").await;
+
+    let use_case = RevokeOtherSessions::new(&session_repo);
+    let result = use_case.execute(input("user123", "session_a")).await;
+
+    assert!(matches!(result, Err(CoreError::Authentication(_))));
+    assert_eq!(session_repo.active_sessions("user123"), vec!["session_b".to_string()]);
+}
+
+#[tokio::test]
+async fn test_revoke_other_sessions_missing_input() {
+    let session_repo = MockSessionRepo::new();
+
+    let use_case = RevokeOtherSessions::new(&session_repo);
+    let result = use_case.execute(input("", "")).await;
+
+    assert!(matches!(result, Err(CoreError::Invariant(_))));
+}
@@ -69,7 +69,7 @@ impl SessionRepository for MockSessionRepo {
         Box::pin(async move {})
     }
     
-    fn revoke_all_for_user(&self, user_id: &str) -> BoxFuture<'_, ()> {
+    fn revoke_all_for_user(&self, user_id: &str) -> BoxFuture<'_, u64> {
         let sessions = self.sessions.read().unwrap();
         let session_ids: Vec<String> = sessions
             .iter()
@@ -80,10 +80,13 @@ impl SessionRepository for MockSessionRepo {
         drop(sessions); // Release read lock before acquiring write lock
         
         let mut revoked = self.revoked_sessions.write().unwrap();
+        let mut count = 0;
         for id in session_ids {
-            revoked.insert(id);
+            if revoked.insert(id) {
+                count += 1;
+            }
         }
-        Box::pin(async move {})
+        Box::pin(async move { count })
     }
     
     fn delete_expired(&self) -> BoxFuture<'_, ()> {
@@ -1,10 +1,13 @@
 //! Comprehensive tests for ValidateAccessToken use case.
 
 use futures::future::BoxFuture;
-use super::super::validate_access_token::{ValidateAccessToken, ValidateAccessTokenInput};
-use crate::core::error::CoreError;
+use super::super::validate_access_token::{
+    TokenValidationFailure, ValidateAccessToken, ValidateAccessTokenInput, ValidateAccessTokenOutput,
+};
+use crate::core::error::{CoreError, TokenError};
 use crate::core::token::Token;
-use crate::core::usecases::ports::{TokenService, SessionRepository};
+use crate::adapters::memory::{MemoryStore, TokenWatermarkStoreMemory};
+use crate::core::usecases::ports::{TokenDenyList, TokenService, TokenWatermarkStore, SessionRepository};
 use crate::core::usecases::ports::session_repository::Session;
 use crate::core::identity::UserIdentity;
 
@@ -37,53 +40,51 @@ impl MockTokenService {
 }
 
 impl TokenService for MockTokenService {
-    fn issue_access_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(&format!("access_token_for_{}", subject))
+    fn issue_access_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(&format!("access_token_for_{}", subject));
+        Box::pin(async move { token })
     }
     
-    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(&format!("refresh_token_for_{}", subject))
+    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(&format!("refresh_token_for_{}", subject));
+        Box::pin(async move { token })
     }
 
-    fn issue_service_token(&self, subject: &str, _claims: &str) -> Token {
-        Token::new(&format!("service_token_for_{}", subject))
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(&format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
     }
     
-    fn validate_access_token(&self, token: &Token) -> Result<String, ()> {
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
         let token_value = token.value();
-        
-        if self.revoked_tokens.read().unwrap().contains(token_value) {
-            return Err(());
-        }
-        
-        if self.expired_tokens.read().unwrap().contains(token_value) {
-            return Err(());
-        }
-        
-        if self.valid_tokens.read().unwrap().contains(token_value) {
+        let revoked_or_expired = self.revoked_tokens.read().unwrap().contains(token_value)
+            || self.expired_tokens.read().unwrap().contains(token_value);
+        let claims = if !revoked_or_expired && self.valid_tokens.read().unwrap().contains(token_value) {
             // Return claims with proper format including type and exp
             Ok(r#"{"sub":"user123","sid":"session123","type":"access","exp":9999999999}"#.to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
     
-    fn validate_refresh_token(&self, token: &Token) -> Result<String, ()> {
+    fn validate_refresh_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
         let token_value = token.value();
-        
-        if self.valid_tokens.read().unwrap().contains(token_value) {
+        let claims = if self.valid_tokens.read().unwrap().contains(token_value) {
             Ok(r#"{"sub":"user123","type":"refresh"}"#.to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 
-    fn validate_service_token(&self, token: &Token) -> Result<String, ()> {
-        if token.value().contains("service_token_for_") {
+    fn validate_service_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = if token.value().contains("service_token_for_") {
             Ok(r#"{"sub":"service123","type":"service"}"#.to_string())
         } else {
             Err(())
-        }
+        };
+        Box::pin(async move { claims })
     }
 }
 
@@ -106,8 +107,8 @@ impl SessionRepository for MockSessionRepo {
         Box::pin(async move {})
     }
     
-    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, ()> {
-        Box::pin(async move {})
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
     }
     
     fn delete_expired(&self) -> BoxFuture<'_, ()> {
@@ -131,6 +132,7 @@ async fn test_validate_access_token_success() {
     
     let input = ValidateAccessTokenInput {
         access_token: Token::new("valid_token_123"),
+        required_scopes: vec![],
     };
     
     let result = use_case.execute(input).await;
@@ -153,6 +155,7 @@ async fn test_validate_access_token_with_session() {
     
     let input = ValidateAccessTokenInput {
         access_token: Token::new("token_with_session"),
+        required_scopes: vec![],
     };
     
     let result = use_case.execute(input).await;
@@ -174,6 +177,7 @@ async fn test_validate_access_token_invalid_signature() {
     
     let input = ValidateAccessTokenInput {
         access_token: Token::new("invalid_token"),
+        required_scopes: vec![],
     };
     
     let result = use_case.execute(input).await;
@@ -196,6 +200,7 @@ async fn test_validate_access_token_expired() {
     
     let input = ValidateAccessTokenInput {
         access_token: Token::new("expired_token_123"),
+        required_scopes: vec![],
     };
     
     let result = use_case.execute(input).await;
@@ -216,6 +221,7 @@ async fn test_validate_access_token_output_structure() {
     
     let input = ValidateAccessTokenInput {
         access_token: Token::new("test_token"),
+        required_scopes: vec![],
     };
     
     let result = use_case.execute(input).await;
@@ -239,6 +245,7 @@ async fn test_validate_access_token_empty_token() {
     
     let input = ValidateAccessTokenInput {
         access_token: Token::new(""),
+        required_scopes: vec![],
     };
     
     let result = use_case.execute(input).await;
@@ -248,3 +255,475 @@ async fn test_validate_access_token_empty_token() {
     assert!(!output.valid);
     assert!(output.reason.is_some());
 }
+
+// ============================================================================
+// Session-aware validation
+// ============================================================================
+
+/// Session store where some sessions have been revoked.
+struct RevokedSessionRepo {
+    revoked: std::collections::HashSet<String>,
+    lookups: std::sync::atomic::AtomicUsize,
+}
+
+impl RevokedSessionRepo {
+    fn revoking(session_id: &str) -> Self {
+        Self {
+            revoked: std::iter::once(session_id.to_string()).collect(),
+            lookups: std::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+    
+    fn lookups(&self) -> usize {
+        self.lookups.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+impl SessionRepository for RevokedSessionRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+    
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+    
+    fn find_by_id(&self, session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        self.lookups.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        let result = (!self.revoked.contains(session_id)).then_some(Session {});
+        Box::pin(async move { result })
+    }
+    
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+    
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+    
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+#[tokio::test]
+async fn test_validate_access_token_rejects_revoked_session() {
+    let token_service = MockTokenService::new();
+    let session_repo = RevokedSessionRepo::revoking("session123");
+    token_service.add_valid_token("token_for_revoked_session");
+    
+    let use_case = ValidateAccessToken::new(&token_service, &session_repo).with_session_validation(true);
+    
+    let output = use_case
+        .execute(ValidateAccessTokenInput {
+            access_token: Token::new("token_for_revoked_session"),
+            required_scopes: vec![],
+        })
+        .await
+        .unwrap();
+    
+    assert!(!output.valid);
+    assert_eq!(output.session_id.as_deref(), Some("session123"));
+    assert_eq!(output.reason.as_deref(), Some("session revoked or expired"));
+    assert_eq!(session_repo.lookups(), 1);
+}
+
+#[tokio::test]
+async fn test_validate_access_token_skips_session_lookup_when_disabled() {
+    let token_service = MockTokenService::new();
+    let session_repo = RevokedSessionRepo::revoking("session123");
+    token_service.add_valid_token("token_for_revoked_session");
+    
+    let use_case = ValidateAccessToken::new(&token_service, &session_repo).with_session_validation(false);
+    
+    let output = use_case
+        .execute(ValidateAccessTokenInput {
+            access_token: Token::new("token_for_revoked_session"),
+            required_scopes: vec![],
+        })
+        .await
+        .unwrap();
+    
+    assert!(output.valid);
+    assert_eq!(output.session_id.as_deref(), Some("session123"));
+    assert_eq!(session_repo.lookups(), 0);
+}
+
+/// Revocation store reporting every session as revoked at a known time.
+struct RevocationStoreRepo {
+    revoked_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl SessionRepository for RevocationStoreRepo {
+    fn create_session(&self, _session_id: &str, _user: &UserIdentity, _refresh_token_hash: &str, _metadata: &str) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn find_by_refresh_token_hash(&self, _hash: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn find_by_id(&self, _session_id: &str) -> BoxFuture<'_, Option<Session>> {
+        Box::pin(async move { None })
+    }
+
+    fn revoked_at(&self, _session_id: &str) -> BoxFuture<'_, Option<chrono::DateTime<chrono::Utc>>> {
+        let revoked_at = self.revoked_at;
+        Box::pin(async move { Some(revoked_at) })
+    }
+
+    fn revoke_session(&self, _session_id: &str) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+
+    fn revoke_all_for_user(&self, _user_id: &str) -> BoxFuture<'_, u64> {
+        Box::pin(async move { 0 })
+    }
+
+    fn delete_expired(&self) -> BoxFuture<'_, ()> {
+        Box::pin(async move {})
+    }
+}
+
+#[tokio::test]
+async fn test_validate_access_token_reports_revoked_token() {
+    use chrono::TimeZone;
+
+    let token_service = MockTokenService::new();
+    let revoked_at = chrono::Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+    let session_repo = RevocationStoreRepo { revoked_at };
+    token_service.add_valid_token("token_for_revoked_session");
+
+    let use_case = ValidateAccessToken::new(&token_service, &session_repo);
+
+    let result = use_case
+        .execute(ValidateAccessTokenInput {
+            access_token: Token::new("token_for_revoked_session"),
+            required_scopes: vec![],
+        })
+        .await;
+
+    match result {
+        Err(CoreError::Token(err)) => assert_eq!(err, TokenError::revoked(revoked_at.to_rfc3339())),
+        Err(other) => panic!("Expected revoked token error, got {}", other),
+        Ok(output) => panic!("Expected revoked token error, got valid={}", output.valid),
+    }
+}
+
+// ============================================================================
+// Scope enforcement
+// ============================================================================
+
+/// Token service whose tokens carry a fixed scope claim, or none at all.
+struct ScopedTokenService;
+
+impl TokenService for ScopedTokenService {
+    fn issue_access_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("access_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_refresh_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("refresh_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn issue_service_token(&self, subject: &str, _claims: &str) -> BoxFuture<'_, Token> {
+        let token = Token::new(format!("service_token_for_{}", subject));
+        Box::pin(async move { token })
+    }
+
+    fn validate_access_token(&self, token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        let claims = match token.value() {
+            "scoped_token" => Ok(r#"{"sub":"user123","sid":"session123","type":"access","exp":9999999999,"scope":["user:read","user:write"]}"#.to_string()),
+            "space_scoped_token" => Ok(r#"{"sub":"user123","sid":"session123","type":"access","exp":9999999999,"scope":"user:read user:write"}"#.to_string()),
+            "unscoped_token" => Ok(r#"{"sub":"user123","sid":"session123","type":"access","exp":9999999999}"#.to_string()),
+            "custom_claims_token" => Ok(r#"{"sub":"user123","sid":"session123","type":"access","exp":9999999999,"tenant":"acme","roles":["billing","support"],"org":{"id":42}}"#.to_string()),
+            "workspace_token" => Ok(r#"{"sub":"user123","sid":"session123","type":"access","exp":9999999999,"workspace_id":"ws-acme"}"#.to_string()),
+            "jti_token" => Ok(r#"{"sub":"user123","sid":"session123","type":"access","exp":9999999999,"jti":"jti-1"}"#.to_string()),
+            "other_jti_token" => Ok(r#"{"sub":"user123","sid":"session123","type":"access","exp":9999999999,"jti":"jti-2"}"#.to_string()),
+            "early_token" => Ok(r#"{"sub":"user123","sid":"session123","type":"access","exp":9999999999,"iat":1000}"#.to_string()),
+            "late_token" => Ok(r#"{"sub":"user123","sid":"session123","type":"access","iat":3000,"exp":9999999999}"#.to_string()),
+            "same_second_token" => Ok(r#"{"sub":"user123","sid":"session123","type":"access","exp":9999999999,"iat":2000}"#.to_string()),
+            _ => Err(()),
+        };
+        Box::pin(async move { claims })
+    }
+
+    fn validate_refresh_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+
+    fn validate_service_token(&self, _token: &Token) -> BoxFuture<'_, Result<String, ()>> {
+        Box::pin(async move { Err(()) })
+    }
+}
+
+async fn validate_with_scopes(token: &str, required: &[&str]) -> ValidateAccessTokenOutput {
+    let token_service = ScopedTokenService;
+    let session_repo = MockSessionRepo;
+    let use_case = ValidateAccessToken::new(&token_service, &session_repo);
+
+    use_case
+        .execute(ValidateAccessTokenInput {
+            access_token: Token::new(token),
+            required_scopes: required.iter().map(|scope| scope.to_string()).collect(),
+        })
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_validate_access_token_accepts_sufficient_scopes() {
+    let output = validate_with_scopes("scoped_token", &["user:read", "user:write"]).await;
+
+    assert!(output.valid);
+    assert_eq!(output.failure, None);
+
+    // The space-separated claim form is understood too
+    let output = validate_with_scopes("space_scoped_token", &["user:write"]).await;
+    assert!(output.valid);
+}
+
+#[tokio::test]
+async fn test_validate_access_token_reports_missing_scope() {
+    let output = validate_with_scopes("scoped_token", &["user:read", "admin:write"]).await;
+
+    assert!(!output.valid);
+    assert_eq!(output.user_id.as_deref(), Some("user123"));
+    assert_eq!(
+        output.failure,
+        Some(TokenValidationFailure::InsufficientScope {
+            missing: vec!["admin:write".to_string()],
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_validate_access_token_without_scope_claim() {
+    // No requirement: a token without scopes is still valid
+    let output = validate_with_scopes("unscoped_token", &[]).await;
+    assert!(output.valid);
+
+    // Any requirement: nothing is granted
+    let output = validate_with_scopes("unscoped_token", &["user:read"]).await;
+    assert!(!output.valid);
+    assert_eq!(
+        output.failure,
+        Some(TokenValidationFailure::InsufficientScope {
+            missing: vec!["user:read".to_string()],
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_validate_access_token_invalid_token_is_not_a_scope_failure() {
+    let output = validate_with_scopes("forged_token", &["user:read"]).await;
+
+    assert!(!output.valid);
+    assert_eq!(output.failure, Some(TokenValidationFailure::InvalidToken));
+}
+
+// ============================================================================
+// Raw claims
+// ============================================================================
+
+#[tokio::test]
+async fn test_validate_access_token_returns_custom_claims() {
+    let output = validate_with_scopes("custom_claims_token", &[]).await;
+
+    assert!(output.valid);
+    assert_eq!(output.identity.as_ref().and_then(|identity| identity.user_id()), Some("user123"));
+
+    let claims = output.claims.as_ref().expect("claims map on success");
+    assert_eq!(claims["tenant"], "acme");
+    assert_eq!(claims["sub"], "user123");
+
+    let custom = output.custom_claims();
+    assert_eq!(custom.len(), 3);
+    assert_eq!(custom["tenant"], "acme");
+    assert_eq!(custom["roles"], serde_json::json!(["billing", "support"]));
+    assert_eq!(custom["org"]["id"], 42);
+
+    let reserved = output.reserved_claims();
+    let mut reserved_keys: Vec<&str> = reserved.keys().map(String::as_str).collect();
+    reserved_keys.sort();
+    assert_eq!(reserved_keys, vec!["exp", "sid", "sub", "type"]);
+    assert!(reserved.keys().all(|key| !custom.contains_key(key)));
+}
+
+#[tokio::test]
+async fn test_validate_access_token_failure_carries_no_claims() {
+    let output = validate_with_scopes("custom_claims_token", &["admin:write"]).await;
+
+    assert!(!output.valid);
+    assert!(output.identity.is_none());
+    assert!(output.claims.is_none());
+    assert!(output.custom_claims().is_empty());
+}
+
+#[tokio::test]
+async fn test_validate_access_token_scopes_identity_to_workspace() {
+    let output = validate_with_scopes("workspace_token", &[]).await;
+
+    assert!(output.valid);
+    let identity = output.identity.as_ref().expect("identity on success");
+    assert_eq!(identity.user_id(), Some("user123"));
+    assert_eq!(identity.workspace_id(), Some("ws-acme"));
+    assert!(output.custom_claims().is_empty());
+}
+
+#[tokio::test]
+async fn test_validate_access_token_without_workspace_is_global() {
+    let output = validate_with_scopes("unscoped_token", &[]).await;
+
+    assert!(output.valid);
+    assert!(output.identity.as_ref().and_then(|identity| identity.workspace_id()).is_none());
+}
+
+// ============================================================================
+// Deny-list
+// ============================================================================
+
+/// Deny-list holding a fixed set of jti values.
+struct MockDenyList {
+    denied: Vec<&'static str>,
+}
+
+impl TokenDenyList for MockDenyList {
+    fn deny(&self, _jti: &str, _expires_at: i64) -> BoxFuture<'_, Result<(), CoreError>> {
+        Box::pin(async move { Ok(()) })
+    }
+
+    fn is_denied(&self, jti: &str) -> BoxFuture<'_, bool> {
+        let denied = self.denied.contains(&jti);
+        Box::pin(async move { denied })
+    }
+}
+
+async fn validate_against_deny_list(token: &str, deny_list: &MockDenyList) -> ValidateAccessTokenOutput {
+    let token_service = ScopedTokenService;
+    let session_repo = MockSessionRepo;
+    let use_case = ValidateAccessToken::new(&token_service, &session_repo).with_deny_list(deny_list);
+
+    use_case
+        .execute(ValidateAccessTokenInput {
+            access_token: Token::new(token),
+            required_scopes: vec![],
+        })
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_validate_access_token_rejects_denied_token() {
+    let deny_list = MockDenyList { denied: vec!["jti-1"] };
+
+    let output = validate_against_deny_list("jti_token", &deny_list).await;
+
+    assert!(!output.valid);
+    assert_eq!(output.reason.as_deref(), Some("token revoked"));
+    assert_eq!(output.failure, Some(TokenValidationFailure::InvalidToken));
+    assert!(output.claims.is_none());
+}
+
+#[tokio::test]
+async fn test_validate_access_token_accepts_token_not_denied() {
+    let deny_list = MockDenyList { denied: vec!["jti-1"] };
+
+    let output = validate_against_deny_list("other_jti_token", &deny_list).await;
+
+    assert!(output.valid);
+}
+
+#[tokio::test]
+async fn test_validate_access_token_without_jti_skips_deny_list() {
+    let deny_list = MockDenyList { denied: vec![""] };
+
+    let output = validate_against_deny_list("unscoped_token", &deny_list).await;
+
+    assert!(output.valid);
+}
+
+#[tokio::test]
+async fn test_validate_access_token_ignores_jti_without_deny_list() {
+    let output = validate_with_scopes("jti_token", &[]).await;
+
+    assert!(output.valid);
+}
+
+// ============================================================================
+// Issued-at watermark
+// ============================================================================
+
+async fn validate_against_watermark(token: &str, watermark: &TokenWatermarkStoreMemory) -> ValidateAccessTokenOutput {
+    let token_service = ScopedTokenService;
+    let session_repo = MockSessionRepo;
+    let use_case = ValidateAccessToken::new(&token_service, &session_repo).with_watermark(watermark);
+
+    use_case
+        .execute(ValidateAccessTokenInput {
+            access_token: Token::new(token),
+            required_scopes: vec![],
+        })
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_validate_access_token_rejects_token_issued_before_watermark() {
+    let watermark = TokenWatermarkStoreMemory::new(MemoryStore::new());
+    watermark.advance(2000).await.unwrap();
+
+    let output = validate_against_watermark("early_token", &watermark).await;
+
+    assert!(!output.valid);
+    assert_eq!(output.reason.as_deref(), Some("token revoked"));
+    assert_eq!(output.failure, Some(TokenValidationFailure::InvalidToken));
+    assert!(output.claims.is_none());
+}
+
+#[tokio::test]
+async fn test_validate_access_token_accepts_token_issued_after_watermark() {
+    let watermark = TokenWatermarkStoreMemory::new(MemoryStore::new());
+    watermark.advance(2000).await.unwrap();
+
+    let output = validate_against_watermark("late_token", &watermark).await;
+
+    assert!(output.valid);
+}
+
+#[tokio::test]
+async fn test_validate_access_token_rejects_token_issued_in_watermark_second() {
+    let watermark = TokenWatermarkStoreMemory::new(MemoryStore::new());
+    watermark.advance(2000).await.unwrap();
+
+    let output = validate_against_watermark("same_second_token", &watermark).await;
+
+    assert!(!output.valid);
+    assert_eq!(output.reason.as_deref(), Some("token revoked"));
+}
+
+#[tokio::test]
+async fn test_validate_access_token_rejects_token_without_iat_once_watermark_set() {
+    let watermark = TokenWatermarkStoreMemory::new(MemoryStore::new());
+
+    assert!(validate_against_watermark("unscoped_token", &watermark).await.valid);
+
+    watermark.advance(2000).await.unwrap();
+
+    assert!(!validate_against_watermark("unscoped_token", &watermark).await.valid);
+}
+
+#[tokio::test]
+async fn test_validate_access_token_watermark_survives_reload() {
+    let store = MemoryStore::new();
+    TokenWatermarkStoreMemory::new(store.clone()).advance(2000).await.unwrap();
+
+    // A restarted service reads the watermark back from the same storage
+    let reloaded = TokenWatermarkStoreMemory::new(store);
+
+    assert!(!validate_against_watermark("early_token", &reloaded).await.valid);
+    assert!(validate_against_watermark("late_token", &reloaded).await.valid);
+}
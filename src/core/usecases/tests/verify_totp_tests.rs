@@ -0,0 +1,234 @@
+//! Tests for VerifyTotp use case.
+
+use chrono::{DateTime, TimeZone, Utc};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use super::super::verify_totp::{VerifyTotp, VerifyTotpInput};
+use crate::core::error::CoreError;
+use crate::core::usecases::ports::{Clock, TotpEnrollment, TotpRepository, TotpVerifier};
+
+// ============================================================================
+// Mock Implementations
+// ============================================================================
+
+/// Fixed instant: 2023-11-14T22:13:20Z
+const FIXED_TIMESTAMP: i64 = 1_700_000_000;
+const TIME_STEP: u64 = 30;
+const CURRENT_STEP: u64 = FIXED_TIMESTAMP as u64 / TIME_STEP;
+
+struct FixedClock;
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc.timestamp_opt(FIXED_TIMESTAMP, 0).unwrap()
+    }
+}
+
+/// Deterministic stand-in for the HMAC computation.
+struct MockTotpVerifier;
+
+impl MockTotpVerifier {
+    fn code_for(secret: &[u8], step: u64) -> String {
+        let seed = secret.iter().map(|b| *b as u64).sum::<u64>();
+        format!("{:06}", (step.wrapping_mul(7919) + seed) % 1_000_000)
+    }
+}
+
+impl TotpVerifier for MockTotpVerifier {
+    fn verify(&self, secret: &[u8], code: &str, step: u64) -> bool {
+        Self::code_for(secret, step) == code
+    }
+}
+
+struct MockTotpRepo {
+    enrollments: RwLock<HashMap<String, TotpEnrollment>>,
+}
+
+impl MockTotpRepo {
+    fn new() -> Self {
+        let mut enrollments = HashMap::new();
+        enrollments.insert(
+            "user123".to_string(),
+            TotpEnrollment {
+                secret: b"12345678901234567890".to_vec(),
+                last_used_step: None,
+            },
+        );
+        Self {
+            enrollments: RwLock::new(enrollments),
+        }
+    }
+
+    fn last_used_step(&self, user_id: &str) -> Option<u64> {
+        self.enrollments.read().unwrap().get(user_id).and_then(|e| e.last_used_step)
+    }
+}
+
+impl TotpRepository for MockTotpRepo {
+    fn find_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<TotpEnrollment>> {
+        let result = self.enrollments.read().unwrap().get(user_id).cloned();
+        Box::pin(async move { result })
+    }
+
+    fn record_used_step(&self, user_id: &str, step: u64) -> BoxFuture<'_, Result<bool, String>> {
+        let recorded = match self.enrollments.write().unwrap().get_mut(user_id) {
+            Some(enrollment) if enrollment.last_used_step.is_none_or(|last| last < step) => {
+                enrollment.last_used_step = Some(step);
+                true
+            }
+            _ => false,
+        };
+        Box::pin(async move { Ok(recorded) })
+    }
+}
+
+/// Repository whose lookups wait until two requests have both read the
+/// enrollment, so they race on recording the step.
+struct RacingTotpRepo {
+    inner: MockTotpRepo,
+    barrier: tokio::sync::Barrier,
+}
+
+impl TotpRepository for RacingTotpRepo {
+    fn find_by_user_id(&self, user_id: &str) -> BoxFuture<'_, Option<TotpEnrollment>> {
+        let lookup = self.inner.find_by_user_id(user_id);
+        Box::pin(async move {
+            let enrollment = lookup.await;
+            self.barrier.wait().await;
+            enrollment
+        })
+    }
+
+    fn record_used_step(&self, user_id: &str, step: u64) -> BoxFuture<'_, Result<bool, String>> {
+        self.inner.record_used_step(user_id, step)
+    }
+}
+
+fn secret() -> Vec<u8> {
+    b"12345678901234567890".to_vec()
+}
+
+fn input(code: String) -> VerifyTotpInput {
+    VerifyTotpInput {
+        user_id: "user123".to_string(),
+        code,
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_known_code_at_known_timestamp_verifies() {
+    let repo = MockTotpRepo::new();
+    let verifier = MockTotpVerifier;
+    let clock = FixedClock;
+    let use_case = VerifyTotp::new(&repo, &verifier, &clock, TIME_STEP, 1);
+
+    let code = MockTotpVerifier::code_for(&secret(), CURRENT_STEP);
+    let output = use_case.execute(input(code)).await.unwrap();
+
+    assert!(output.verified);
+    assert!(output.reason.is_none());
+    assert_eq!(repo.last_used_step("user123"), Some(CURRENT_STEP));
+}
+
+#[tokio::test]
+async fn test_adjacent_step_within_window_verifies() {
+    let repo = MockTotpRepo::new();
+    let verifier = MockTotpVerifier;
+    let clock = FixedClock;
+    let use_case = VerifyTotp::new(&repo, &verifier, &clock, TIME_STEP, 1);
+
+    let code = MockTotpVerifier::code_for(&secret(), CURRENT_STEP - 1);
+    let output = use_case.execute(input(code)).await.unwrap();
+
+    assert!(output.verified);
+}
+
+#[tokio::test]
+async fn test_stale_code_outside_window_rejected() {
+    let repo = MockTotpRepo::new();
+    let verifier = MockTotpVerifier;
+    let clock = FixedClock;
+    let use_case = VerifyTotp::new(&repo, &verifier, &clock, TIME_STEP, 1);
+
+    let code = MockTotpVerifier::code_for(&secret(), CURRENT_STEP - 2);
+    let output = use_case.execute(input(code)).await.unwrap();
+
+    assert!(!output.verified);
+    assert_eq!(output.reason.as_deref(), Some("invalid code"));
+    assert_eq!(repo.last_used_step("user123"), None);
+}
+
+#[tokio::test]
+async fn test_replayed_code_rejected() {
+    let repo = MockTotpRepo::new();
+    let verifier = MockTotpVerifier;
+    let clock = FixedClock;
+    let use_case = VerifyTotp::new(&repo, &verifier, &clock, TIME_STEP, 1);
+
+    let code = MockTotpVerifier::code_for(&secret(), CURRENT_STEP);
+    assert!(use_case.execute(input(code.clone())).await.unwrap().verified);
+
+    let replay = use_case.execute(input(code)).await.unwrap();
+    assert!(!replay.verified);
+    assert_eq!(replay.reason.as_deref(), Some("code already used"));
+}
+
+#[tokio::test]
+async fn test_concurrent_double_submit_verifies_once() {
+    let repo = RacingTotpRepo {
+        inner: MockTotpRepo::new(),
+        barrier: tokio::sync::Barrier::new(2),
+    };
+    let verifier = MockTotpVerifier;
+    let clock = FixedClock;
+    let use_case = VerifyTotp::new(&repo, &verifier, &clock, TIME_STEP, 1);
+
+    let code = MockTotpVerifier::code_for(&secret(), CURRENT_STEP);
+    let (first, second) = tokio::join!(
+        use_case.execute(input(code.clone())),
+        use_case.execute(input(code)),
+    );
+    let outcomes = [first.unwrap(), second.unwrap()];
+
+    assert_eq!(outcomes.iter().filter(|output| output.verified).count(), 1);
+    let loser = outcomes.iter().find(|output| !output.verified).unwrap();
+    assert_eq!(loser.reason.as_deref(), Some("code already used"));
+    assert_eq!(repo.inner.last_used_step("user123"), Some(CURRENT_STEP));
+}
+
+#[tokio::test]
+async fn test_malformed_code_rejected() {
+    let repo = MockTotpRepo::new();
+    let verifier = MockTotpVerifier;
+    let clock = FixedClock;
+    let use_case = VerifyTotp::new(&repo, &verifier, &clock, TIME_STEP, 1);
+
+    for code in ["12345", "1234567", "12a456", ""] {
+        let output = use_case.execute(input(code.to_string())).await.unwrap();
+        assert!(!output.verified);
+        assert_eq!(output.reason.as_deref(), Some("invalid code format"));
+    }
+}
+
+#[tokio::test]
+async fn test_user_without_enrollment_errors() {
+    let repo = MockTotpRepo::new();
+    let verifier = MockTotpVerifier;
+    let clock = FixedClock;
+    let use_case = VerifyTotp::new(&repo, &verifier, &clock, TIME_STEP, 1);
+
+    let result = use_case
+        .execute(VerifyTotpInput {
+            user_id: "unknown".to_string(),
+            code: "123456".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(CoreError::Authentication(_))));
+}
@@ -7,15 +7,27 @@
 //! - Map failure to domain error
 //! - Optionally check password version
 //! - If password_changed_at > token.issued_at → token invalid
-//! - Validate session is active in the database
+//! - Validate the session named by the `sid` claim is active (session-aware mode)
+//! - Fail with `TokenError::Revoked` when that session was explicitly revoked
+//! - Reject tokens whose `jti` is on the deny-list, when one is configured
+//! - Reject tokens issued at or before the issued-at watermark, when one is configured
+//! - Enforce the scopes the caller requires against the token's `scope` claim
+//! - Return the typed identity together with the full claims map, so callers
+//!   can read application-specific claims the crate does not model
+//! - Scope the identity to the `workspace_id` claim when present
 
-use crate::core::error::CoreError;
+use serde_json::{Map, Value};
+
+use crate::core::error::{CoreError, TokenError};
+use crate::core::identity::{ContextualIdentity, UserIdentity, WorkspaceIdentity};
 use crate::core::token::Token;
-use crate::core::usecases::ports::{TokenService, SessionRepository};
+use crate::core::usecases::ports::{TokenDenyList, TokenService, TokenWatermarkStore, SessionRepository};
 
 /// Input contract for ValidateAccessToken use case.
 pub struct ValidateAccessTokenInput {
     pub access_token: Token,
+    /// Scopes the token must carry; empty means no scope requirement.
+    pub required_scopes: Vec<String>,
 }
 
 /// Output contract for ValidateAccessToken use case.
@@ -24,12 +36,60 @@ pub struct ValidateAccessTokenOutput {
     pub user_id: Option<String>,
     pub session_id: Option<String>,
     pub reason: Option<String>,
+    pub failure: Option<TokenValidationFailure>,
+    /// Typed view of the authenticated caller; set only when `valid`.
+    pub identity: Option<ContextualIdentity>,
+    /// Every claim of the token as decoded, reserved and custom alike; set
+    /// only when `valid`. Use [`custom_claims`](Self::custom_claims) for the
+    /// application-specific part.
+    pub claims: Option<Map<String, Value>>,
+}
+
+/// Claims whose meaning is defined by JWT or by this crate.
+///
+/// Everything else in a token is an application-specific (custom) claim.
+pub const RESERVED_CLAIMS: &[&str] = &[
+    "iss", "sub", "aud", "exp", "nbf", "iat", "jti", "sid", "type", "scope", "user_id",
+    "workspace_id",
+];
+
+impl ValidateAccessTokenOutput {
+    /// Claims listed in [`RESERVED_CLAIMS`].
+    pub fn reserved_claims(&self) -> Map<String, Value> {
+        self.partition_claims(true)
+    }
+
+    /// Claims not listed in [`RESERVED_CLAIMS`].
+    pub fn custom_claims(&self) -> Map<String, Value> {
+        self.partition_claims(false)
+    }
+
+    fn partition_claims(&self, reserved: bool) -> Map<String, Value> {
+        self.claims
+            .iter()
+            .flatten()
+            .filter(|(key, _)| RESERVED_CLAIMS.contains(&key.as_str()) == reserved)
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+}
+
+/// Why a token was not accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenValidationFailure {
+    /// The token itself is unusable: bad signature, wrong type, expired or its session is gone.
+    InvalidToken,
+    /// The token is valid but lacks scopes the caller requires.
+    InsufficientScope { missing: Vec<String> },
 }
 
 /// Use case for validating an access token.
 pub struct ValidateAccessToken<'a> {
     token_service: &'a (dyn TokenService + Send + Sync),
     session_repository: &'a (dyn SessionRepository + Send + Sync),
+    deny_list: Option<&'a (dyn TokenDenyList + Send + Sync)>,
+    watermark: Option<&'a (dyn TokenWatermarkStore + Send + Sync)>,
+    session_aware: bool,
 }
 
 impl<'a> ValidateAccessToken<'a> {
@@ -38,13 +98,43 @@ impl<'a> ValidateAccessToken<'a> {
         token_service: &'a (dyn TokenService + Send + Sync),
         session_repository: &'a (dyn SessionRepository + Send + Sync),
     ) -> Self {
-        Self { token_service, session_repository }
+        Self {
+            token_service,
+            session_repository,
+            deny_list: None,
+            watermark: None,
+            session_aware: true,
+        }
+    }
+
+    /// Reject tokens whose `jti` has been denied (disabled by default).
+    pub fn with_deny_list(mut self, deny_list: &'a (dyn TokenDenyList + Send + Sync)) -> Self {
+        self.deny_list = Some(deny_list);
+        self
+    }
+
+    /// Reject tokens issued at or before the issued-at watermark (disabled by default).
+    ///
+    /// Once a watermark is set, a token without an `iat` claim is rejected too.
+    pub fn with_watermark(mut self, watermark: &'a (dyn TokenWatermarkStore + Send + Sync)) -> Self {
+        self.watermark = Some(watermark);
+        self
+    }
+
+    /// Enable or disable session-aware validation (enabled by default).
+    ///
+    /// When enabled, the session referenced by the token's `sid` claim is
+    /// looked up and the token is rejected if that session was revoked or
+    /// has expired. When disabled, only the token itself is checked.
+    pub fn with_session_validation(mut self, enabled: bool) -> Self {
+        self.session_aware = enabled;
+        self
     }
 
     /// Execute the access token validation use case.
     pub async fn execute(&self, input: ValidateAccessTokenInput) -> Result<ValidateAccessTokenOutput, CoreError> {
         // Step 1: Validate token signature via TokenService
-        let claims = match self.token_service.validate_access_token(&input.access_token) {
+        let claims = match self.token_service.validate_access_token(&input.access_token).await {
             Ok(claims) => claims,
             Err(_) => {
                 return Ok(ValidateAccessTokenOutput {
@@ -52,6 +142,9 @@ impl<'a> ValidateAccessToken<'a> {
                     user_id: None,
                     session_id: None,
                     reason: Some("token signature invalid".to_string()),
+                    failure: Some(TokenValidationFailure::InvalidToken),
+                    identity: None,
+                    claims: None,
                 });
             }
         };
@@ -68,6 +161,9 @@ impl<'a> ValidateAccessToken<'a> {
                 user_id,
                 session_id,
                 reason: Some("invalid token type".to_string()),
+                failure: Some(TokenValidationFailure::InvalidToken),
+                identity: None,
+                claims: None,
             });
         }
 
@@ -78,37 +174,121 @@ impl<'a> ValidateAccessToken<'a> {
                 user_id,
                 session_id,
                 reason: Some("token expired".to_string()),
+                failure: Some(TokenValidationFailure::InvalidToken),
+                identity: None,
+                claims: None,
+            });
+        }
+
+        // Step 5: Reject a token that was individually revoked
+        if let (Some(deny_list), Some(jti)) = (self.deny_list, self.extract_jti(&claims))
+            && deny_list.is_denied(&jti).await
+        {
+            tracing::debug!("[ValidateAccessToken] Access token {} is denied", jti);
+            return Ok(ValidateAccessTokenOutput {
+                valid: false,
+                user_id,
+                session_id,
+                reason: Some("token revoked".to_string()),
+                failure: Some(TokenValidationFailure::InvalidToken),
+                identity: None,
+                claims: None,
             });
         }
 
-        // Step 5: Validate session is active in the database
-        if let Some(ref sid) = session_id {
+        // Step 6: Reject a token issued before every token was revoked
+        if let Some(watermark) = self.watermark
+            && let Some(revoked_through) = watermark.revoked_through().await
+        {
+            let issued_at = self.extract_iat(&claims);
+            if issued_at.is_none_or(|iat| iat <= revoked_through) {
+                tracing::debug!(
+                    "[ValidateAccessToken] Access token issued at {:?} is covered by watermark {}",
+                    issued_at,
+                    revoked_through
+                );
+                return Ok(ValidateAccessTokenOutput {
+                    valid: false,
+                    user_id,
+                    session_id,
+                    reason: Some("token revoked".to_string()),
+                    failure: Some(TokenValidationFailure::InvalidToken),
+                    identity: None,
+                    claims: None,
+                });
+            }
+        }
+
+        // Step 7: Validate the session referenced by `sid` is still active
+        if !self.session_aware {
+            // Stateless mode: the token alone is authoritative
+        } else if let Some(ref sid) = session_id {
             let session = self.session_repository.find_by_id(sid).await;
             match session {
                 Some(_) => {
                     // Session is active - validation successful
                 }
                 None => {
+                    // A revoked session cannot be refreshed back to life, so
+                    // surface it distinctly rather than as a plain invalid token
+                    if let Some(revoked_at) = self.session_repository.revoked_at(sid).await {
+                        return Err(TokenError::revoked(revoked_at.to_rfc3339()).into());
+                    }
                     return Ok(ValidateAccessTokenOutput {
                         valid: false,
                         user_id,
                         session_id,
                         reason: Some("session revoked or expired".to_string()),
+                        failure: Some(TokenValidationFailure::InvalidToken),
+                        identity: None,
+                        claims: None,
                     });
                 }
             }
         } else {
-            // No session_id in token - this could be a token without session
-            // For now, we'll allow this but you might want to reject it depending on requirements
-            tracing::warn!("Access token has no session_id - allowing without session validation");
+            // No `sid` claim: the token was issued without a session binding
+            // and validates statelessly
+            tracing::debug!("[ValidateAccessToken] Access token has no sid - validating statelessly");
         }
 
-        // Step 6: Return successful validation
+        // Step 8: Check the token carries every required scope
+        let missing = missing_scopes(&extract_scopes(&claims), &input.required_scopes);
+        if !missing.is_empty() {
+            return Ok(ValidateAccessTokenOutput {
+                valid: false,
+                user_id,
+                session_id,
+                reason: Some("insufficient scope".to_string()),
+                failure: Some(TokenValidationFailure::InsufficientScope { missing }),
+                identity: None,
+                claims: None,
+            });
+        }
+
+        // Step 9: Return successful validation, scoping the identity to the
+        // workspace the token was issued for
+        let claims = serde_json::from_str::<Map<String, Value>>(&claims).ok();
+        let workspace = claims
+            .as_ref()
+            .and_then(|claims| claims.get("workspace_id"))
+            .and_then(Value::as_str)
+            .map(WorkspaceIdentity::new);
+        let identity = user_id.clone().map(|id| {
+            let identity = ContextualIdentity::from(UserIdentity::new(id));
+            match workspace {
+                Some(workspace) => identity.with_workspace(workspace),
+                None => identity,
+            }
+        });
+
         Ok(ValidateAccessTokenOutput {
             valid: true,
             user_id,
             session_id,
             reason: None,
+            failure: None,
+            identity,
+            claims,
         })
     }
 
@@ -133,6 +313,16 @@ impl<'a> ValidateAccessToken<'a> {
             .split("\"sid\":\"")
             .nth(1)
             .and_then(|s| s.split('"').next())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+    }
+
+    fn extract_jti(&self, claims: &str) -> Option<String> {
+        claims
+            .split("\"jti\":\"")
+            .nth(1)
+            .and_then(|s| s.split('"').next())
+            .filter(|s| !s.is_empty())
             .map(|s| s.to_string())
     }
 
@@ -141,7 +331,16 @@ impl<'a> ValidateAccessToken<'a> {
         claims
             .split("\"exp\":")
             .nth(1)
-            .and_then(|s| s.split(|c| c == ',' || c == '}').next())
+            .and_then(|s| s.split([',', '}']).next())
+            .and_then(|s| s.trim().parse::<i64>().ok())
+    }
+
+    /// Extract iat timestamp from claims JSON
+    fn extract_iat(&self, claims: &str) -> Option<i64> {
+        claims
+            .split("\"iat\":")
+            .nth(1)
+            .and_then(|s| s.split([',', '}']).next())
             .and_then(|s| s.trim().parse::<i64>().ok())
     }
 
@@ -162,3 +361,39 @@ impl<'a> ValidateAccessToken<'a> {
         true // If we can't parse, consider it expired
     }
 }
+
+/// Read the `scope` claim as a list.
+///
+/// Accepts both the JSON array our token services emit and the
+/// space-separated string form from RFC 8693. A missing claim grants nothing.
+pub(crate) fn extract_scopes(claims: &str) -> Vec<String> {
+    let value: serde_json::Value = match serde_json::from_str(claims) {
+        Ok(value) => value,
+        Err(_) => return Vec::new(),
+    };
+    match value.get("scope") {
+        Some(serde_json::Value::Array(items)) => items
+            .iter()
+            .filter_map(|item| item.as_str())
+            .map(str::to_string)
+            .collect(),
+        Some(serde_json::Value::String(scope)) => scope.split_whitespace().map(str::to_string).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Required scopes not covered by any granted scope.
+fn missing_scopes(granted: &[String], required: &[String]) -> Vec<String> {
+    required
+        .iter()
+        .filter(|required| !granted.iter().any(|granted| scope_grants(granted, required)))
+        .cloned()
+        .collect()
+}
+
+/// Whether one granted scope satisfies a required scope.
+///
+/// Exact match only for now; prefix or wildcard rules belong here.
+fn scope_grants(granted: &str, required: &str) -> bool {
+    granted == required
+}
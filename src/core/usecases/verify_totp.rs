@@ -0,0 +1,120 @@
+//! Use case: VerifyTotp
+//!
+//! Orchestrates TOTP second-factor verification (RFC 6238).
+//!
+//! Responsibilities:
+//! - Load the user's TOTP enrollment
+//! - Validate code format (6 digits)
+//! - Check the code against the current time step and a ±window of steps
+//! - Reject codes for steps already used (replay protection)
+//! - Record the accepted step, rejecting the code if a concurrent request
+//!   recorded it first
+
+use crate::core::error::{AuthenticationError, CoreError};
+use crate::core::usecases::ports::{Clock, TotpRepository, TotpVerifier};
+
+/// Number of digits in a TOTP code.
+pub const TOTP_CODE_DIGITS: usize = 6;
+
+/// Input contract for VerifyTotp use case.
+pub struct VerifyTotpInput {
+    pub user_id: String,
+    pub code: String,
+}
+
+/// Output contract for VerifyTotp use case.
+#[derive(Debug)]
+pub struct VerifyTotpOutput {
+    pub verified: bool,
+    pub reason: Option<String>,
+}
+
+/// Use case for verifying a TOTP second factor.
+pub struct VerifyTotp<'a> {
+    totp_repo: &'a (dyn TotpRepository + Send + Sync),
+    totp_verifier: &'a (dyn TotpVerifier + Send + Sync),
+    clock: &'a (dyn Clock + Send + Sync),
+    time_step_secs: u64,
+    window: u64,
+}
+
+impl<'a> VerifyTotp<'a> {
+    /// Create a new VerifyTotp use case with dependencies.
+    ///
+    /// `window` is the number of steps accepted on either side of the
+    /// current one to tolerate clock drift (1 means ±1 step).
+    pub fn new(
+        totp_repo: &'a (dyn TotpRepository + Send + Sync),
+        totp_verifier: &'a (dyn TotpVerifier + Send + Sync),
+        clock: &'a (dyn Clock + Send + Sync),
+        time_step_secs: u64,
+        window: u64,
+    ) -> Self {
+        Self {
+            totp_repo,
+            totp_verifier,
+            clock,
+            time_step_secs: time_step_secs.max(1),
+            window,
+        }
+    }
+
+    /// Execute the TOTP verification use case.
+    pub async fn execute(&self, input: VerifyTotpInput) -> Result<VerifyTotpOutput, CoreError> {
+        // Step 1: Load enrollment
+        let enrollment = self
+            .totp_repo
+            .find_by_user_id(&input.user_id)
+            .await
+            .ok_or_else(|| AuthenticationError::unsupported_auth_method("totp"))?;
+
+        // Step 2: Validate code format
+        if input.code.len() != TOTP_CODE_DIGITS || !input.code.chars().all(|c| c.is_ascii_digit()) {
+            return Ok(Self::rejected("invalid code format"));
+        }
+
+        // Step 3: Find a matching step within the window
+        let now = self.clock.now().timestamp().max(0) as u64;
+        let current_step = now / self.time_step_secs;
+        let first_step = current_step.saturating_sub(self.window);
+        let last_step = current_step.saturating_add(self.window);
+
+        let matched_step = (first_step..=last_step)
+            .find(|step| self.totp_verifier.verify(&enrollment.secret, &input.code, *step));
+
+        let Some(step) = matched_step else {
+            tracing::debug!("[VerifyTotp] No matching step for user {}", input.user_id);
+            return Ok(Self::rejected("invalid code"));
+        };
+
+        // Step 4: Reject replayed codes
+        if enrollment.last_used_step.is_some_and(|last| step <= last) {
+            tracing::debug!("[VerifyTotp] Replayed code for user {} at step {}", input.user_id, step);
+            return Ok(Self::rejected("code already used"));
+        }
+
+        // Step 5: Record accepted step; losing the conditional write means a
+        // concurrent request with the same code got there first
+        let recorded = self
+            .totp_repo
+            .record_used_step(&input.user_id, step)
+            .await
+            .map_err(|e| AuthenticationError::incomplete_flow(format!("failed to record totp step: {}", e)))?;
+        if !recorded {
+            tracing::debug!("[VerifyTotp] Concurrent replay for user {} at step {}", input.user_id, step);
+            return Ok(Self::rejected("code already used"));
+        }
+
+        Ok(VerifyTotpOutput {
+            verified: true,
+            reason: None,
+        })
+    }
+
+    fn rejected(reason: &str) -> VerifyTotpOutput {
+        VerifyTotpOutput {
+            verified: false,
+            reason: Some(reason.to_string()),
+        }
+    }
+}